@@ -6,19 +6,23 @@ use bumpalo::Bump;
 use roc_error_macros::{internal_error, user_error};
 use roc_fmt::def::fmt_defs;
 use roc_fmt::module::fmt_module;
-use roc_fmt::spaces::RemoveSpaces;
+use roc_fmt::spaces::{same_without_spaces, RemoveSpaces};
+use roc_fmt::width::wrap_long_lines;
 use roc_fmt::{Ast, Buf};
 use roc_parse::module::parse_module_defs;
-use roc_parse::{module, parser::SyntaxError, state::State};
+use roc_parse::{module, parser::SyntaxError, state::State, tokens::TokenTable};
 
 #[derive(Copy, Clone, Debug)]
 pub enum FormatMode {
     WriteToFile,
     WriteToStdout,
     CheckOnly,
+    /// Check that formatting every file keeps its meaning and is idempotent, without
+    /// writing anything other than the debugging files for the ones where it isn't.
+    Verify,
 }
 
-fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
+pub(crate) fn flatten_directories(files: std::vec::Vec<PathBuf>) -> std::vec::Vec<PathBuf> {
     let mut to_flatten = files;
     let mut files = vec![];
 
@@ -62,14 +66,19 @@ fn is_roc_file(path: &Path) -> bool {
     matches!(path.extension().and_then(OsStr::to_str), Some("roc"))
 }
 
-pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(), String> {
+pub fn format_files(
+    files: std::vec::Vec<PathBuf>,
+    mode: FormatMode,
+    max_width: Option<usize>,
+) -> Result<(), String> {
     let arena = Bump::new();
     let mut files_to_reformat = Vec::new(); // to track which files failed `roc format --check`
+    let mut formatting_bugs = Vec::new(); // to track which files failed `roc format --verify`
 
     for file in flatten_directories(files) {
         let src = std::fs::read_to_string(&file).unwrap();
 
-        match format_src(&arena, &src) {
+        match format_src(&arena, &src, max_width) {
             Ok(buf) => {
                 match mode {
                     FormatMode::CheckOnly => {
@@ -86,72 +95,19 @@ pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(
                     FormatMode::WriteToStdout => {
                         std::io::stdout().lock().write_all(buf.as_bytes()).unwrap()
                     }
+                    FormatMode::Verify => {}
                 }
             }
-            Err(err) => match err {
-                FormatProblem::ParsingFailed {
-                    formatted_src,
-                    parse_err,
-                } => {
-                    let fail_file = file.with_extension("roc-format-failed");
-
-                    std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatted code isn't valid\n\n\
-                        I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                        Parse error was: {:?}\n\n",
-                        fail_file.display(),
-                        parse_err
-                    );
-                }
-                FormatProblem::ReformattingChangedAst {
-                    formatted_src,
-                    ast_before,
-                    ast_after,
-                } => {
-                    let mut fail_file = file.clone();
-                    fail_file.set_extension("roc-format-failed");
-                    std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
-
-                    let mut before_file = file.clone();
-                    before_file.set_extension("roc-format-failed-ast-before");
-                    std::fs::write(&before_file, ast_before).unwrap();
-
-                    let mut after_file = file.clone();
-                    after_file.set_extension("roc-format-failed-ast-after");
-                    std::fs::write(&after_file, ast_after).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatting didn't reparse as the same tree\n\n\
-                        I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
-                        I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
-                        fail_file.display(),
-                        before_file.display(),
-                        after_file.display()
-                    );
-                }
-                FormatProblem::ReformattingUnstable {
-                    formatted_src,
-                    reformatted_src,
-                } => {
-                    let mut unstable_1_file = file.clone();
-                    unstable_1_file.set_extension("roc-format-unstable-1");
-                    std::fs::write(&unstable_1_file, formatted_src).unwrap();
-
-                    let mut unstable_2_file = file.clone();
-                    unstable_2_file.set_extension("roc-format-unstable-2");
-                    std::fs::write(&unstable_2_file, reformatted_src).unwrap();
-
-                    internal_error!(
-                        "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
-                        I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
-                        I wrote the result of double-formatting here:\n{}\n\n",
-                        unstable_1_file.display(),
-                        unstable_2_file.display()
-                    );
+            Err(err) => {
+                let message = write_problem_files(&file, err);
+
+                match mode {
+                    FormatMode::Verify => formatting_bugs.push(message),
+                    FormatMode::WriteToFile | FormatMode::WriteToStdout | FormatMode::CheckOnly => {
+                        internal_error!("{}", message)
+                    }
                 }
-            },
+            }
         }
     }
     // After processing all files, check if any files failed `format --check`
@@ -162,9 +118,95 @@ pub fn format_files(files: std::vec::Vec<PathBuf>, mode: FormatMode) -> Result<(
             file_list
         ));
     }
+    if !formatting_bugs.is_empty() {
+        return Err(format!(
+            "Formatting {} file(s) would have changed them incorrectly. Please report this as a formatter bug!\n\n{}",
+            formatting_bugs.len(),
+            formatting_bugs.join("\n")
+        ));
+    }
     Ok(())
 }
 
+/// Write what went wrong next to the file that failed to format, for debugging purposes,
+/// and describe it.
+pub(crate) fn write_problem_files(file: &Path, problem: FormatProblem) -> String {
+    match problem {
+        FormatProblem::ParsingFailed {
+            formatted_src,
+            parse_err,
+        } => {
+            let fail_file = file.with_extension("roc-format-failed");
+
+            std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
+
+            format!(
+                "Formatting bug; formatted code isn't valid\n\n\
+                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+                Parse error was: {:?}\n\n",
+                fail_file.display(),
+                parse_err
+            )
+        }
+        FormatProblem::ReformattingChangedAst {
+            formatted_src,
+            ast_before,
+            ast_after,
+        } => {
+            let fail_file = file.with_extension("roc-format-failed");
+            std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
+
+            let before_file = file.with_extension("roc-format-failed-ast-before");
+            std::fs::write(&before_file, ast_before).unwrap();
+
+            let after_file = file.with_extension("roc-format-failed-ast-after");
+            std::fs::write(&after_file, ast_after).unwrap();
+
+            format!(
+                "Formatting bug; formatting didn't reparse as the same tree\n\n\
+                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n\
+                I wrote the tree before and after formatting to these files for debugging purposes:\n{}\n{}\n\n",
+                fail_file.display(),
+                before_file.display(),
+                after_file.display()
+            )
+        }
+        FormatProblem::CommentsDropped {
+            formatted_src,
+            comments,
+        } => {
+            let fail_file = file.with_extension("roc-format-failed");
+            std::fs::write(&fail_file, formatted_src.as_str()).unwrap();
+
+            format!(
+                "Formatting bug; formatting dropped {} comment(s):\n\n{}\n\n\
+                I wrote the incorrect result to this file for debugging purposes:\n{}\n\n",
+                comments.len(),
+                comments.join("\n"),
+                fail_file.display()
+            )
+        }
+        FormatProblem::ReformattingUnstable {
+            formatted_src,
+            reformatted_src,
+        } => {
+            let unstable_1_file = file.with_extension("roc-format-unstable-1");
+            std::fs::write(&unstable_1_file, formatted_src).unwrap();
+
+            let unstable_2_file = file.with_extension("roc-format-unstable-2");
+            std::fs::write(&unstable_2_file, reformatted_src).unwrap();
+
+            format!(
+                "Formatting bug; formatting is not stable. Reformatting the formatted file changed it again.\n\n\
+                I wrote the result of formatting to this file for debugging purposes:\n{}\n\n\
+                I wrote the result of double-formatting here:\n{}\n\n",
+                unstable_1_file.display(),
+                unstable_2_file.display()
+            )
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum FormatProblem {
     ParsingFailed {
@@ -176,61 +218,84 @@ pub enum FormatProblem {
         ast_before: String,
         ast_after: String,
     },
+    CommentsDropped {
+        formatted_src: String,
+        comments: Vec<String>,
+    },
     ReformattingUnstable {
         formatted_src: String,
         reformatted_src: String,
     },
 }
 
-pub fn format_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
+/// Format the code, breaking up lines that are longer than `max_width` if there is one.
+pub fn format_src(
+    arena: &Bump,
+    src: &str,
+    max_width: Option<usize>,
+) -> Result<String, FormatProblem> {
     let ast = arena.alloc(parse_all(arena, src).unwrap_or_else(|e| {
         user_error!("Unexpected parse failure when parsing this formatting:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
     }));
-    let mut buf = Buf::new_in(arena);
-    fmt_all(&mut buf, ast);
 
-    let reparsed_ast = match arena.alloc(parse_all(arena, buf.as_str())) {
+    fmt_checked(arena, src, ast, max_width)
+}
+
+/// Format the tree parsed from `src`, checking that the result parses back into the same
+/// tree, that it has all the comments `src` had, and that formatting it again doesn't change
+/// it any further.
+pub(crate) fn fmt_checked<'a>(
+    arena: &'a Bump,
+    src: &str,
+    ast: &'a Ast<'a>,
+    max_width: Option<usize>,
+) -> Result<String, FormatProblem> {
+    let formatted = fmt_all(arena, ast, max_width);
+
+    let reparsed_ast = match arena.alloc(parse_all(arena, formatted)) {
         Ok(ast) => ast,
         Err(e) => {
             return Err(FormatProblem::ParsingFailed {
-                formatted_src: buf.as_str().to_string(),
+                formatted_src: formatted.to_string(),
                 parse_err: format!("{:?}", e),
             });
         }
     };
 
-    let ast_normalized = ast.remove_spaces(arena);
-    let reparsed_ast_normalized = reparsed_ast.remove_spaces(arena);
-
-    // HACK!
-    // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-    // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-    // I don't have the patience to debug this right now, so let's leave it for another day...
-    // TODO: fix PartialEq impl on ast types
-    if format!("{ast_normalized:?}") != format!("{reparsed_ast_normalized:?}") {
+    if !same_without_spaces(arena, &*ast, &*reparsed_ast) {
         return Err(FormatProblem::ReformattingChangedAst {
-            formatted_src: buf.as_str().to_string(),
-            ast_before: format!("{ast_normalized:#?}\n"),
-            ast_after: format!("{reparsed_ast_normalized:#?}\n"),
+            formatted_src: formatted.to_string(),
+            ast_before: format!("{:#?}\n", ast.remove_spaces(arena)),
+            ast_after: format!("{:#?}\n", reparsed_ast.remove_spaces(arena)),
         });
     }
 
-    // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
-    let mut reformatted_buf = Buf::new_in(arena);
+    let dropped_comments = TokenTable::new(src).missing_comments(&TokenTable::new(formatted));
 
-    fmt_all(&mut reformatted_buf, reparsed_ast);
+    if !dropped_comments.is_empty() {
+        return Err(FormatProblem::CommentsDropped {
+            formatted_src: formatted.to_string(),
+            comments: dropped_comments
+                .iter()
+                .map(|comment| comment.value.to_string())
+                .collect(),
+        });
+    }
 
-    if buf.as_str() != reformatted_buf.as_str() {
+    // Now verify that the resultant formatting is _stable_ - i.e. that it doesn't change again if re-formatted
+    let reformatted = fmt_all(arena, reparsed_ast, max_width);
+
+    if formatted != reformatted {
         return Err(FormatProblem::ReformattingUnstable {
-            formatted_src: buf.as_str().to_string(),
-            reformatted_src: reformatted_buf.as_str().to_string(),
+            formatted_src: formatted.to_string(),
+            reformatted_src: reformatted.to_string(),
         });
     }
 
-    Ok(buf.as_str().to_string())
+    Ok(formatted.to_string())
 }
 
-fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+pub(crate) fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
     let (module, state) = module::parse_header(arena, State::new(src.as_bytes()))
         .map_err(|e| SyntaxError::Header(e.problem))?;
 
@@ -241,12 +306,19 @@ fn parse_all<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'
     Ok(Ast { module, defs })
 }
 
-fn fmt_all<'a>(buf: &mut Buf<'a>, ast: &'a Ast) {
-    fmt_module(buf, &ast.module);
+fn fmt_all<'a>(arena: &'a Bump, ast: &'a Ast, max_width: Option<usize>) -> &'a str {
+    let mut buf = Buf::new_in(arena);
 
-    fmt_defs(buf, &ast.defs, 0);
+    fmt_module(&mut buf, &ast.module);
+
+    fmt_defs(&mut buf, &ast.defs, 0);
 
     buf.fmt_end_of_file();
+
+    match max_width {
+        Some(max_width) => wrap_long_lines(arena, buf.into_bump_str(), max_width),
+        None => buf.into_bump_str(),
+    }
 }
 
 #[cfg(test)]
@@ -293,7 +365,7 @@ main =
         let dir = tempdir().unwrap();
         let file_path = setup_test_file(dir.path(), "test1.roc", UNFORMATTED_ROC);
 
-        let result = format_files(vec![file_path.clone()], FormatMode::CheckOnly);
+        let result = format_files(vec![file_path.clone()], FormatMode::CheckOnly, None);
         assert!(result.is_err());
         assert_eq!(
             result.unwrap_err(),
@@ -312,7 +384,7 @@ main =
         let file1 = setup_test_file(dir.path(), "test1.roc", UNFORMATTED_ROC);
         let file2 = setup_test_file(dir.path(), "test2.roc", UNFORMATTED_ROC);
 
-        let result = format_files(vec![file1, file2], FormatMode::CheckOnly);
+        let result = format_files(vec![file1, file2], FormatMode::CheckOnly, None);
         assert!(result.is_err());
         let error_message = result.unwrap_err();
         assert!(error_message.contains("test1.roc") && error_message.contains("test2.roc"));
@@ -325,8 +397,23 @@ main =
         let dir = tempdir().unwrap();
         let file_path = setup_test_file(dir.path(), "formatted.roc", FORMATTED_ROC);
 
-        let result = format_files(vec![file_path], FormatMode::CheckOnly);
+        let result = format_files(vec![file_path], FormatMode::CheckOnly, None);
+        assert!(result.is_ok());
+
+        cleanup_temp_dir(dir);
+    }
+
+    #[test]
+    fn test_verify_does_not_write() {
+        let dir = tempdir().unwrap();
+        let file_path = setup_test_file(dir.path(), "test1.roc", UNFORMATTED_ROC);
+
+        let result = format_files(vec![file_path.clone()], FormatMode::Verify, None);
         assert!(result.is_ok());
+        assert_eq!(
+            std::fs::read_to_string(&file_path).unwrap(),
+            format!("{UNFORMATTED_ROC}\n")
+        );
 
         cleanup_temp_dir(dir);
     }
@@ -341,6 +428,7 @@ main =
         let result = format_files(
             vec![file_formatted, file1_unformated, file2_unformated],
             FormatMode::CheckOnly,
+            None,
         );
         assert!(result.is_err());
         let error_message = result.unwrap_err();
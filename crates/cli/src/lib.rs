@@ -13,16 +13,20 @@ use roc_build::program::{
     handle_error_module, handle_loading_problem, standard_load_config, BuildFileError,
     BuildOrdering, BuiltFile, CodeGenBackend, CodeGenOptions, DEFAULT_ROC_FILENAME,
 };
+use roc_build::size_report::SizeReportFormat;
 use roc_collections::MutMap;
 use roc_error_macros::{internal_error, user_error};
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
+use roc_gen_wasm::WasmFeatures;
 use roc_load::{ExpectMetadata, Threading};
 use roc_module::symbol::ModuleId;
 use roc_mono::ir::OptLevel;
 use roc_packaging::cache::RocCacheDir;
 use roc_packaging::tarball::Compression;
+use roc_reporting::cli::ErrorFormat;
 use roc_reporting::report::ANSI_STYLE_CODES;
+use roc_reporting::warnings::{WarningConfig, WarningLevel};
 use roc_target::{Architecture, Target};
 use std::env;
 use std::ffi::{CString, OsStr, OsString};
@@ -39,18 +43,26 @@ use tempfile::TempDir;
 mod format;
 pub use format::{format_files, format_src, FormatMode};
 
+mod upgrade;
+pub use upgrade::{upgrade_files, upgrade_src};
+
 pub const CMD_BUILD: &str = "build";
 pub const CMD_RUN: &str = "run";
 pub const CMD_DEV: &str = "dev";
 pub const CMD_REPL: &str = "repl";
 pub const CMD_DOCS: &str = "docs";
 pub const CMD_CHECK: &str = "check";
+pub const CMD_LINT: &str = "lint";
 pub const CMD_VERSION: &str = "version";
 pub const CMD_FORMAT: &str = "format";
+pub const CMD_UPGRADE: &str = "upgrade";
 pub const CMD_TEST: &str = "test";
 pub const CMD_GLUE: &str = "glue";
 pub const CMD_GEN_STUB_LIB: &str = "gen-stub-lib";
 pub const CMD_PREPROCESS_HOST: &str = "preprocess-host";
+pub const CMD_PACKAGE: &str = "package";
+pub const CMD_DIFF: &str = "diff";
+pub const CMD_EXPLAIN: &str = "explain";
 
 pub const FLAG_EMIT_LLVM_IR: &str = "emit-llvm-ir";
 pub const FLAG_PROFILING: &str = "profiling";
@@ -63,21 +75,37 @@ pub const FLAG_LIB: &str = "lib";
 pub const FLAG_NO_LINK: &str = "no-link";
 pub const FLAG_TARGET: &str = "target";
 pub const FLAG_TIME: &str = "time";
+pub const FLAG_OPT_REPORT: &str = "opt-report";
+pub const FLAG_SIZE_REPORT: &str = "size-report";
 pub const FLAG_VERBOSE: &str = "verbose";
 pub const FLAG_LINKER: &str = "linker";
 pub const FLAG_PREBUILT: &str = "prebuilt-platform";
 pub const FLAG_CHECK: &str = "check";
 pub const FLAG_STDIN: &str = "stdin";
 pub const FLAG_STDOUT: &str = "stdout";
+pub const FLAG_VERIFY: &str = "verify";
+pub const FLAG_MAX_WIDTH: &str = "max-width";
+pub const FLAG_DRY_RUN: &str = "dry-run";
 pub const FLAG_WASM_STACK_SIZE_KB: &str = "wasm-stack-size-kb";
+pub const FLAG_WASM_FEATURES: &str = "wasm-features";
 pub const FLAG_OUTPUT: &str = "output";
 pub const FLAG_FUZZ: &str = "fuzz";
+pub const FLAG_DOC_TESTS: &str = "doc-tests";
+pub const FLAG_FORMAT: &str = "format";
+pub const FLAG_ERROR_FORMAT: &str = "error-format";
+pub const FLAG_FIX: &str = "fix";
+pub const FLAG_ALLOW: &str = "allow";
+pub const FLAG_WARN: &str = "warn";
+pub const FLAG_DENY: &str = "deny";
 pub const ROC_FILE: &str = "ROC_FILE";
 pub const ROC_DIR: &str = "ROC_DIR";
 pub const GLUE_DIR: &str = "GLUE_DIR";
 pub const GLUE_SPEC: &str = "GLUE_SPEC";
 pub const DIRECTORY_OR_FILES: &str = "DIRECTORY_OR_FILES";
+pub const OLD_ROC_FILE: &str = "OLD_ROC_FILE";
+pub const NEW_ROC_FILE: &str = "NEW_ROC_FILE";
 pub const ARGS_FOR_APP: &str = "ARGS_FOR_APP";
+pub const ERROR_CODE: &str = "ERROR_CODE";
 
 const VERSION: &str = include_str!("../../../version.txt");
 const DEFAULT_GENERATED_DOCS_DIR: &str = "generated-docs";
@@ -125,6 +153,21 @@ pub fn build_app() -> Command {
         .action(ArgAction::SetTrue)
         .required(false);
 
+    let flag_opt_report = Arg::new(FLAG_OPT_REPORT)
+        .long(FLAG_OPT_REPORT)
+        .help("Print which list updates may copy (with a likely reason), which allocations get reused, and which refcounting stays inside loops\n(This only applies to the LLVM backend, and is most useful with --optimize.)")
+        .action(ArgAction::SetTrue)
+        .required(false);
+
+    let flag_size_report = Arg::new(FLAG_SIZE_REPORT)
+        .long(FLAG_SIZE_REPORT)
+        .help("Print every specialization of every function with its layout and emitted code size, totaled by function and by module\n(Prints a table by default; use --size-report=json for JSON.)")
+        .value_parser(["table", "json"])
+        .num_args(0..=1)
+        .require_equals(true)
+        .default_missing_value("table")
+        .required(false);
+
     let flag_linker = Arg::new(FLAG_LINKER)
         .long(FLAG_LINKER)
         .help("Set which linker to use\n(The surgical linker is enabled by default only when building for wasm32 or x86_64 Linux, because those are the only targets it currently supports. Otherwise the legacy linker is used by default.)")
@@ -143,6 +186,40 @@ pub fn build_app() -> Command {
         .value_parser(value_parser!(u32))
         .required(false);
 
+    let flag_wasm_features = Arg::new(FLAG_WASM_FEATURES)
+        .long(FLAG_WASM_FEATURES)
+        .help("Comma-separated Wasm proposals the wasm32 target may use: tail-call, multi-value\n(This only applies when --dev also provided.)")
+        .value_parser(|names: &str| names.parse::<WasmFeatures>())
+        .required(false);
+
+    let flag_error_format = Arg::new(FLAG_ERROR_FORMAT)
+        .long(FLAG_ERROR_FORMAT)
+        .help("How to print errors and warnings\n(`json` prints one JSON object per line and `sarif` prints a SARIF log, both to stderr.)")
+        .value_parser(["human", "json", "sarif"])
+        .default_value("human")
+        .required(false);
+
+    let flag_allow = Arg::new(FLAG_ALLOW)
+        .long(FLAG_ALLOW)
+        .help("Don't report warnings with this code (e.g. R0202), or any warnings at all with `warnings`\n(This overrides the project's .roc-warnings file, but not `# roc:` comments in the code.)")
+        .value_name("CODE")
+        .action(ArgAction::Append)
+        .required(false);
+
+    let flag_warn = Arg::new(FLAG_WARN)
+        .long(FLAG_WARN)
+        .help("Report warnings with this code (e.g. R0202), or all warnings with `warnings`, as warnings")
+        .value_name("CODE")
+        .action(ArgAction::Append)
+        .required(false);
+
+    let flag_deny = Arg::new(FLAG_DENY)
+        .long(FLAG_DENY)
+        .help("Report warnings with this code (e.g. R0202), or all warnings with `warnings`, as errors")
+        .value_name("CODE")
+        .action(ArgAction::Append)
+        .required(false);
+
     let flag_fuzz = Arg::new(FLAG_FUZZ)
         .long(FLAG_FUZZ)
         .help("Instrument the roc binary for fuzzing with roc-fuzz")
@@ -183,10 +260,17 @@ pub fn build_app() -> Command {
             .arg(flag_emit_llvm_ir.clone())
             .arg(flag_profiling.clone())
             .arg(flag_time.clone())
+            .arg(flag_opt_report)
+            .arg(flag_size_report)
             .arg(flag_linker.clone())
             .arg(flag_prebuilt.clone())
             .arg(flag_fuzz.clone())
+            .arg(flag_error_format.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
             .arg(flag_wasm_stack_size_kb)
+            .arg(flag_wasm_features)
             .arg(
                 Arg::new(FLAG_TARGET)
                     .long(FLAG_TARGET)
@@ -244,6 +328,13 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false)
             )
+            .arg(
+                Arg::new(FLAG_DOC_TESTS)
+                    .long(FLAG_DOC_TESTS)
+                    .help("Also run the `expect`s in the ```roc code blocks of the main module's doc comments")
+                    .action(ArgAction::SetTrue)
+                    .required(false)
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file for the main module")
@@ -315,14 +406,58 @@ pub fn build_app() -> Command {
                     .action(ArgAction::SetTrue)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_MAX_WIDTH)
+                    .long(FLAG_MAX_WIDTH)
+                    .value_name("COLUMNS")
+                    .help("Break up function calls, collections, pipelines and type annotations that make a line longer than this\n(Lines are only ever broken up, never joined, so formatting stays the same when run again.)")
+                    .value_parser(value_parser!(usize))
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_VERIFY)
+                    .long(FLAG_VERIFY)
+                    .help("Checks that formatting the specified files would not change their meaning, and that\nformatting them again would not change them any further, without writing anything\n(If it would, return a non-zero exit code. This catches bugs in the formatter.)")
+                    .action(ArgAction::SetTrue)
+                    .conflicts_with_all([FLAG_CHECK, FLAG_STDOUT])
+                    .required(false),
+            )
             .after_help("If DIRECTORY_OR_FILES is omitted, the .roc files in the current working\ndirectory are formatted.")
         )
+        .subcommand(Command::new(CMD_UPGRADE)
+            .about("Rewrite old syntax in a .roc file or the .roc files contained in a directory into\nthe current syntax, formatting them along the way")
+            .arg(
+                Arg::new(DIRECTORY_OR_FILES)
+                    .index(1)
+                    .num_args(0..)
+                    .required(false)
+                    .value_parser(value_parser!(OsString)))
+            .arg(
+                Arg::new(FLAG_DRY_RUN)
+                    .long(FLAG_DRY_RUN)
+                    .help("Print a diff of what would change instead of writing it")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
+            .after_help("If DIRECTORY_OR_FILES is omitted, the .roc files in the current working\ndirectory are upgraded.\n\nThis turns `interface` headers into `module` headers, the `imports` of a header into\n`import` statements, and backpassing with `Task.await` into the `!` suffix.")
+        )
         .subcommand(Command::new(CMD_VERSION)
             .about(concatcp!("Print the Roc compiler’s version, which is currently ", VERSION)))
         .subcommand(Command::new(CMD_CHECK)
             .about("Check the code for problems, but don’t build or run it")
             .arg(flag_time.clone())
             .arg(flag_max_threads.clone())
+            .arg(flag_error_format.clone())
+            .arg(flag_allow.clone())
+            .arg(flag_warn.clone())
+            .arg(flag_deny.clone())
+            .arg(
+                Arg::new(FLAG_FIX)
+                    .long(FLAG_FIX)
+                    .help("Apply the safe fixes for any problems found (e.g. removing unused imports), then report whatever is left")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
             .arg(
                 Arg::new(ROC_FILE)
                     .help("The .roc file of an app to check")
@@ -331,6 +466,22 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME),
             )
             )
+        .subcommand(Command::new(CMD_LINT)
+            .about("Check the code for problems like `roc check` does, and also for code that compiles but is probably a mistake (like a leftover `dbg`)")
+            .arg(flag_max_threads.clone())
+            .arg(flag_error_format)
+            .arg(flag_allow)
+            .arg(flag_warn)
+            .arg(flag_deny)
+            .arg(
+                Arg::new(ROC_FILE)
+                    .help("The .roc file of an app or package to lint")
+                    .value_parser(value_parser!(PathBuf))
+                    .required(false)
+                    .default_value(DEFAULT_ROC_FILENAME),
+            )
+            .after_help("Each lint has its own code, which can be passed to --allow, --warn or --deny (or used in a .roc-warnings file).\nRun `roc explain CODE` to see what a lint is about.\nA .roc-warnings file can also change lint settings, e.g. `set repeated-when-length 4`.")
+            )
         .subcommand(
             Command::new(CMD_DOCS)
                 .about("Generate documentation for a Roc package")
@@ -341,6 +492,19 @@ pub fn build_app() -> Command {
                     .required(false)
                     .default_value(DEFAULT_GENERATED_DOCS_DIR),
                 )
+                .arg(Arg::new(FLAG_FORMAT)
+                    .long(FLAG_FORMAT)
+                    .help("The format of the generated documentation\n(`markdown` writes one .md file per module; `json` writes a single docs.json file for other tools to consume.)")
+                    .value_parser(["html", "markdown", "json"])
+                    .required(false)
+                    .default_value("html"),
+                )
+                .arg(Arg::new(FLAG_CHECK)
+                    .long(FLAG_CHECK)
+                    .help("Type-check the ```roc code blocks in doc comments instead of generating documentation\n(If there are problems, return a non-zero exit code.)")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+                )
                 .arg(Arg::new(ROC_FILE)
                     .help("The package's main .roc file")
                     .value_parser(value_parser!(PathBuf))
@@ -371,6 +535,33 @@ pub fn build_app() -> Command {
                     .default_value(DEFAULT_ROC_FILENAME)
             )
         )
+        .subcommand(Command::new(CMD_PACKAGE)
+            .about("Work with Roc packages")
+            .subcommand_required(true)
+            .subcommand(Command::new(CMD_DIFF)
+                .about("Compare the public API of two versions of a package, and report whether the new version needs a major, minor, or patch version bump")
+                .arg(
+                    Arg::new(OLD_ROC_FILE)
+                        .help("The main .roc file of the old version of the package")
+                        .value_parser(value_parser!(PathBuf))
+                        .required(true)
+                )
+                .arg(
+                    Arg::new(NEW_ROC_FILE)
+                        .help("The main .roc file of the new version of the package")
+                        .value_parser(value_parser!(PathBuf))
+                        .required(true)
+                )
+            )
+        )
+        .subcommand(Command::new(CMD_EXPLAIN)
+            .about("Explain an error or warning code, like the R0300 in `── TYPE MISMATCH [R0300]`")
+            .arg(
+                Arg::new(ERROR_CODE)
+                    .help("The code to explain, e.g. R0300")
+                    .required(true)
+            )
+        )
         .subcommand(Command::new(CMD_GEN_STUB_LIB)
             .about("Generate a stubbed shared library that can be used for linking a platform binary.\nThe stubbed library has prototypes, but no function bodies.\n\nNote: This command will be removed in favor of just using `roc build` once all platforms support the surgical linker")
             .arg(
@@ -404,6 +595,20 @@ pub fn build_app() -> Command {
                     .value_parser(build_target_values_parser)
                     .required(false),
             )
+            .arg(
+                Arg::new(FLAG_LIB)
+                    .long(FLAG_LIB)
+                    .help("Preprocess a host that was built as a shared library, for apps built with `--lib`")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
+            .arg(
+                Arg::new(FLAG_VERIFY)
+                    .long(FLAG_VERIFY)
+                    .help("Check that the already preprocessed host fits the app, instead of preprocessing it again")
+                    .action(ArgAction::SetTrue)
+                    .required(false),
+            )
         )
         .arg(flag_optimize)
         .arg(flag_max_threads)
@@ -495,6 +700,7 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
     let arena = &arena;
     // TODO may need to determine this dynamically based on dev builds.
     let function_kind = FunctionKind::LambdaSet;
+    let warning_config = warning_config(matches, path);
 
     // Step 1: compile the app and generate the .o file
     let load_config = LoadConfig {
@@ -506,23 +712,55 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
         threading,
         exec_mode: ExecutionMode::Test,
     };
-    let load_result = roc_load::load_and_monomorphize(
-        arena,
-        path.to_path_buf(),
-        RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
-        load_config,
-    );
+    let load_result = if matches.get_flag(FLAG_DOC_TESTS) {
+        // Turn the code blocks in the doc comments into top-level code, so their
+        // `expect`s run along with the rest, and failures point into the doc comments.
+        let src = std::fs::read_to_string(path)?;
+        let src = roc_docs::doc_tests_to_source(&src).unwrap_or(src);
+        let src_dir = path.parent().unwrap().to_path_buf();
+
+        roc_load::load_and_monomorphize_from_str(
+            arena,
+            path.to_path_buf(),
+            arena.alloc_str(&src),
+            src_dir,
+            RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+            load_config,
+        )
+    } else {
+        roc_load::load_and_monomorphize(
+            arena,
+            path.to_path_buf(),
+            RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+            load_config,
+        )
+    };
 
     let mut loaded = match load_result {
         Ok(loaded) => loaded,
         Err(LoadMonomorphizedError::LoadingProblem(problem)) => {
-            return handle_loading_problem(problem);
+            return handle_loading_problem(problem, ErrorFormat::Human);
         }
         Err(LoadMonomorphizedError::ErrorModule(module)) => {
-            return handle_error_module(module, start_time.elapsed(), path.as_os_str(), false);
+            return handle_error_module(
+                module,
+                start_time.elapsed(),
+                path.as_os_str(),
+                false,
+                ErrorFormat::Human,
+                &warning_config,
+            );
         }
     };
-    let problems = report_problems_monomorphized(&mut loaded);
+    let problems = report_problems_monomorphized(&mut loaded, ErrorFormat::Human, &warning_config);
+
+    // Warnings the project denies are errors, so don't run the tests.
+    if problems.errors > 0 {
+        problems.print_error_warning_count(start_time.elapsed());
+        println!(".");
+
+        return Ok(problems.exit_code());
+    }
 
     let mut expectations = std::mem::take(&mut loaded.expectations);
 
@@ -541,10 +779,6 @@ pub fn test(matches: &ArgMatches, target: Target) -> io::Result<i32> {
 
     // Print warnings before running tests.
     {
-        debug_assert_eq!(
-            problems.errors, 0,
-            "if there were errors, we would have already exited."
-        );
         if problems.warnings > 0 {
             problems.print_error_warning_count(start_time.elapsed());
             println!(".\n\nRunning tests…\n\n\x1B[36m{}\x1B[39m", "─".repeat(80));
@@ -675,6 +909,7 @@ pub fn build(
     link_type: LinkType,
 ) -> io::Result<i32> {
     use roc_build::program::build_file;
+    use roc_load::LoadConfig;
     use BuildConfig::*;
 
     let path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
@@ -783,7 +1018,17 @@ pub fn build(
         if matches!(target.architecture(), Architecture::Wasm32) {
             CodeGenBackend::Wasm
         } else {
-            CodeGenBackend::Assembly(AssemblyBackendMode::Binary)
+            // Only a binary that `roc` runs in development has a parent to report failed
+            // `expect`s to; see `roc_run_native`.
+            let backend_mode = match (&config, opt_level) {
+                (
+                    BuildConfig::BuildAndRun | BuildConfig::BuildAndRunIfNoErrors,
+                    OptLevel::Development,
+                ) => AssemblyBackendMode::BinaryDev,
+                _ => AssemblyBackendMode::Binary,
+            };
+
+            CodeGenBackend::Assembly(backend_mode)
         }
     } else {
         let backend_mode = match opt_level {
@@ -799,9 +1044,35 @@ pub fn build(
         user_error!("Cannot emit llvm ir while using a dev backend.");
     }
 
+    let opt_report = matches
+        .try_get_one::<bool>(FLAG_OPT_REPORT)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or(false);
+    if opt_report && !matches!(code_gen_backend, CodeGenBackend::Llvm(_)) {
+        user_error!("Cannot print an optimization report while using a dev backend.");
+    }
+
+    let size_report = matches
+        .try_get_one::<String>(FLAG_SIZE_REPORT)
+        .ok()
+        .flatten()
+        .map(|format| match format.as_str() {
+            "json" => SizeReportFormat::Json,
+            _ => SizeReportFormat::Table,
+        });
+
     let emit_debug_info = matches.get_flag(FLAG_PROFILING)
         || matches!(opt_level, OptLevel::Development | OptLevel::Normal);
     let emit_timings = matches.get_flag(FLAG_TIME);
+    let error_format = matches
+        .try_get_one::<String>(FLAG_ERROR_FORMAT)
+        .ok()
+        .flatten()
+        .map(|s| ErrorFormat::try_from(s.as_str()).unwrap())
+        .unwrap_or_default();
+    let warning_config = warning_config(matches, path);
 
     let threading = match matches.get_one::<usize>(FLAG_MAX_THREADS) {
         None => Threading::AllAvailable,
@@ -845,6 +1116,13 @@ pub fn build(
         .flatten()
         .map(|x| x * 1024);
 
+    let wasm_features = matches
+        .try_get_one::<WasmFeatures>(FLAG_WASM_FEATURES)
+        .ok()
+        .flatten()
+        .copied()
+        .unwrap_or_default();
+
     let build_ordering = match config {
         BuildAndRunIfNoErrors => BuildOrdering::BuildIfChecks,
         _ => BuildOrdering::AlwaysBuild,
@@ -856,9 +1134,15 @@ pub fn build(
         emit_debug_info,
         emit_llvm_ir,
         fuzz,
+        opt_report,
+        size_report,
+        wasm_features,
     };
 
-    let load_config = standard_load_config(target, build_ordering, threading);
+    let load_config = LoadConfig {
+        render: error_format.render_target(),
+        ..standard_load_config(target, build_ordering, threading)
+    };
 
     let res_binary_path = build_file(
         &arena,
@@ -866,6 +1150,8 @@ pub fn build(
         path.to_owned(),
         code_gen_options,
         emit_timings,
+        error_format,
+        &warning_config,
         link_type,
         linking_strategy,
         prebuilt,
@@ -968,10 +1254,68 @@ pub fn build(
                 }
             }
         }
-        Err(BuildFileError::ErrorModule { module, total_time }) => {
-            handle_error_module(module, total_time, path.as_os_str(), true)
+        Err(BuildFileError::ErrorModule { module, total_time }) => handle_error_module(
+            module,
+            total_time,
+            path.as_os_str(),
+            true,
+            error_format,
+            &warning_config,
+        ),
+        Err(BuildFileError::LoadingProblem(problem)) => {
+            handle_loading_problem(problem, error_format)
         }
-        Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
+    }
+}
+
+/// The warning levels for the app at this path: its project's `.roc-warnings` file,
+/// overridden by any `--allow`, `--warn` and `--deny` flags (the last one for a code wins).
+pub fn warning_config(matches: &ArgMatches, roc_file_path: &Path) -> WarningConfig {
+    let project_dir = match roc_file_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let mut config = match WarningConfig::from_project_dir(project_dir) {
+        Ok(config) => config,
+        Err(err) => user_error!("Invalid warning configuration in {err}"),
+    };
+
+    let mut from_flags = Vec::new();
+
+    for (flag, level) in [
+        (FLAG_ALLOW, WarningLevel::Allow),
+        (FLAG_WARN, WarningLevel::Warn),
+        (FLAG_DENY, WarningLevel::Deny),
+    ] {
+        // Not every subcommand that builds an app takes these flags
+        if let Some(codes) = matches.try_get_many::<String>(flag).ok().flatten() {
+            let indices = matches.indices_of(flag).into_iter().flatten();
+
+            from_flags.extend(indices.zip(codes).map(|(index, code)| (index, level, code)));
+        }
+    }
+
+    from_flags.sort_by_key(|(index, _, _)| *index);
+
+    let mut cli_config = WarningConfig::default();
+
+    for (_, level, code) in from_flags {
+        if let Err(err) = cli_config.set(code, level) {
+            user_error!("Invalid --{} flag: {err}", level_flag(level));
+        }
+    }
+
+    config.extend(&cli_config);
+
+    config
+}
+
+fn level_flag(level: WarningLevel) -> &'static str {
+    match level {
+        WarningLevel::Allow => FLAG_ALLOW,
+        WarningLevel::Warn => FLAG_WARN,
+        WarningLevel::Deny => FLAG_DENY,
     }
 }
 
@@ -1,25 +1,33 @@
 //! The `roc` binary that brings together all functionality in the Roc toolset.
 use bumpalo::Bump;
 use roc_build::link::LinkType;
-use roc_build::program::{check_file, CodeGenBackend};
+use roc_build::program::{check_file, lint_file, CodeGenBackend};
 use roc_cli::{
-    build_app, format_files, format_src, test, BuildConfig, FormatMode, CMD_BUILD, CMD_CHECK,
-    CMD_DEV, CMD_DOCS, CMD_FORMAT, CMD_GEN_STUB_LIB, CMD_GLUE, CMD_PREPROCESS_HOST, CMD_REPL,
-    CMD_RUN, CMD_TEST, CMD_VERSION, DIRECTORY_OR_FILES, FLAG_CHECK, FLAG_DEV, FLAG_LIB,
-    FLAG_NO_LINK, FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, GLUE_DIR,
-    GLUE_SPEC, ROC_FILE,
+    build_app, format_files, format_src, test, upgrade_files, warning_config, BuildConfig,
+    FormatMode, CMD_BUILD, CMD_CHECK, CMD_DEV, CMD_DIFF, CMD_DOCS, CMD_EXPLAIN, CMD_FORMAT,
+    CMD_GEN_STUB_LIB, CMD_GLUE, CMD_LINT, CMD_PACKAGE, CMD_PREPROCESS_HOST, CMD_REPL, CMD_RUN,
+    CMD_TEST, CMD_UPGRADE, CMD_VERSION, DIRECTORY_OR_FILES, ERROR_CODE, FLAG_CHECK, FLAG_DEV,
+    FLAG_DRY_RUN, FLAG_ERROR_FORMAT, FLAG_FIX, FLAG_FORMAT, FLAG_LIB, FLAG_MAX_WIDTH, FLAG_NO_LINK,
+    FLAG_OUTPUT, FLAG_STDIN, FLAG_STDOUT, FLAG_TARGET, FLAG_TIME, FLAG_VERIFY, GLUE_DIR, GLUE_SPEC,
+    NEW_ROC_FILE, OLD_ROC_FILE, ROC_FILE,
+};
+use roc_docs::{
+    check_docs, diff_packages, generate_docs_html, generate_docs_json, generate_docs_markdown,
+    required_bump, DocsFormat,
 };
-use roc_docs::generate_docs_html;
 use roc_error_macros::user_error;
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::LlvmBackendMode;
 use roc_load::{FunctionKind, LoadingProblem, Threading};
 use roc_packaging::cache::{self, RocCacheDir};
+use roc_reporting::cli::{report_loading_problem, ErrorFormat};
+use roc_reporting::error_code;
 use roc_target::Target;
 use std::fs::{self, FileType};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Instant;
 use target_lexicon::Triple;
 
 #[macro_use]
@@ -150,18 +158,61 @@ fn main() -> io::Result<()> {
                 function_kind,
             );
 
-            // TODO: pipeline the executable location through here.
-            // Currently it is essentally hardcoded as platform_path/dynhost.
-            roc_linker::preprocess_host(
-                target,
-                &platform_path.with_file_name("main.roc"),
-                // The target triple string must be derived from the triple to convert from the generic
-                // `system` target to the exact specific target.
-                &platform_path.with_file_name(format!("{}.rh", target)),
-                &stub_lib,
-                &stub_dll_symbols,
-            );
-            Ok(0)
+            let (link_type, preprocessed_host_filename) = if matches.get_flag(FLAG_LIB) {
+                (
+                    LinkType::Dylib,
+                    roc_linker::preprocessed_dylib_host_filename(target),
+                )
+            } else {
+                (
+                    LinkType::Executable,
+                    roc_linker::preprocessed_host_filename(target),
+                )
+            };
+
+            if matches.get_flag(FLAG_VERIFY) {
+                let preprocessed_path = platform_path.with_file_name(preprocessed_host_filename);
+                let problems = roc_linker::verify_preprocessed_host(
+                    target,
+                    link_type,
+                    &platform_path.with_file_name("main.roc"),
+                    &preprocessed_path,
+                    &stub_dll_symbols,
+                );
+
+                if problems.is_empty() {
+                    println!(
+                        "The preprocessed host {} fits this app.",
+                        preprocessed_path.display()
+                    );
+                    Ok(0)
+                } else {
+                    eprintln!(
+                        "The preprocessed host {} does not fit this app:\n",
+                        preprocessed_path.display()
+                    );
+                    for problem in problems {
+                        eprintln!("    - {problem}");
+                    }
+                    eprintln!("\n{}", roc_linker::rebuild_host_hint());
+                    Ok(1)
+                }
+            } else {
+                // TODO: pipeline the executable location through here.
+                // Currently it is essentally hardcoded as platform_path/dynhost,
+                // or platform_path/libdynhost.so for shared libraries.
+                roc_linker::preprocess_host(
+                    target,
+                    link_type,
+                    &platform_path.with_file_name("main.roc"),
+                    // The target triple string must be derived from the triple to convert from the generic
+                    // `system` target to the exact specific target.
+                    &platform_path.with_file_name(preprocessed_host_filename),
+                    &stub_lib,
+                    &stub_dll_symbols,
+                );
+                Ok(0)
+            }
         }
         Some((CMD_BUILD, matches)) => {
             let target = matches
@@ -192,7 +243,15 @@ fn main() -> io::Result<()> {
             let arena = Bump::new();
 
             let emit_timings = matches.get_flag(FLAG_TIME);
+            let error_format = ErrorFormat::try_from(
+                matches
+                    .get_one::<String>(FLAG_ERROR_FORMAT)
+                    .unwrap()
+                    .as_str(),
+            )
+            .unwrap();
             let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+            let warning_config = warning_config(matches, roc_file_path);
             let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
                 None => Threading::AllAvailable,
                 Some(0) => user_error!("cannot build with at most 0 threads"),
@@ -204,6 +263,9 @@ fn main() -> io::Result<()> {
                 &arena,
                 roc_file_path.to_owned(),
                 emit_timings,
+                error_format,
+                &warning_config,
+                matches.get_flag(FLAG_FIX),
                 RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
                 threading,
             ) {
@@ -212,8 +274,8 @@ fn main() -> io::Result<()> {
                     Ok(problems.exit_code())
                 }
 
-                Err(LoadingProblem::FormattedReport(report)) => {
-                    print!("{report}");
+                Err(LoadingProblem::FormattedReport(report, diagnostic)) => {
+                    report_loading_problem(&report, diagnostic, error_format);
 
                     Ok(1)
                 }
@@ -222,20 +284,124 @@ fn main() -> io::Result<()> {
                 }
             }
         }
+        Some((CMD_LINT, matches)) => {
+            let arena = Bump::new();
+
+            let error_format = ErrorFormat::try_from(
+                matches
+                    .get_one::<String>(FLAG_ERROR_FORMAT)
+                    .unwrap()
+                    .as_str(),
+            )
+            .unwrap();
+            let roc_file_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
+            let warning_config = warning_config(matches, roc_file_path);
+            let threading = match matches.get_one::<usize>(roc_cli::FLAG_MAX_THREADS) {
+                None => Threading::AllAvailable,
+                Some(0) => user_error!("cannot build with at most 0 threads"),
+                Some(1) => Threading::Single,
+                Some(n) => Threading::AtMost(*n),
+            };
+
+            match lint_file(
+                &arena,
+                roc_file_path.to_owned(),
+                error_format,
+                &warning_config,
+                RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+                threading,
+            ) {
+                Ok((problems, total_time)) => {
+                    problems.print_error_warning_count(total_time);
+                    Ok(problems.exit_code())
+                }
+
+                Err(LoadingProblem::FormattedReport(report, diagnostic)) => {
+                    report_loading_problem(&report, diagnostic, error_format);
+
+                    Ok(1)
+                }
+                Err(other) => {
+                    panic!("lint_file failed with error:\n{other:?}");
+                }
+            }
+        }
         Some((CMD_REPL, _)) => Ok(roc_repl_cli::main()),
         Some((CMD_DOCS, matches)) => {
             let root_path = matches.get_one::<PathBuf>(ROC_FILE).unwrap();
-            let out_dir = matches.get_one::<OsString>(FLAG_OUTPUT).unwrap();
 
-            generate_docs_html(root_path.to_owned(), out_dir.as_ref());
+            if matches.get_flag(FLAG_CHECK) {
+                let start_time = Instant::now();
+                let problems = check_docs(root_path.to_owned());
 
-            Ok(0)
+                problems.print_error_warning_count(start_time.elapsed());
+
+                Ok(problems.exit_code())
+            } else {
+                let out_dir = matches.get_one::<OsString>(FLAG_OUTPUT).unwrap();
+
+                let format =
+                    DocsFormat::try_from(matches.get_one::<String>(FLAG_FORMAT).unwrap().as_str())
+                        .unwrap();
+
+                match format {
+                    DocsFormat::Html => generate_docs_html(root_path.to_owned(), out_dir.as_ref()),
+                    DocsFormat::Markdown => {
+                        generate_docs_markdown(root_path.to_owned(), out_dir.as_ref())
+                    }
+                    DocsFormat::Json => generate_docs_json(root_path.to_owned(), out_dir.as_ref()),
+                }
+
+                Ok(0)
+            }
+        }
+        Some((CMD_PACKAGE, matches)) => match matches.subcommand() {
+            Some((CMD_DIFF, matches)) => {
+                let old_root = matches.get_one::<PathBuf>(OLD_ROC_FILE).unwrap();
+                let new_root = matches.get_one::<PathBuf>(NEW_ROC_FILE).unwrap();
+                let changes = diff_packages(old_root.to_owned(), new_root.to_owned());
+
+                for change in changes.iter() {
+                    println!("{}  {}: {}", change.bump, change.name, change.description);
+                }
+
+                match required_bump(&changes) {
+                    Some(bump) => println!("\nThese changes require a {bump} version bump."),
+                    None => println!("The public API did not change."),
+                }
+
+                Ok(0)
+            }
+            _ => unreachable!(),
+        },
+        Some((CMD_EXPLAIN, matches)) => {
+            let code = matches.get_one::<String>(ERROR_CODE).unwrap();
+
+            match error_code::lookup(code) {
+                Some(error_code) => {
+                    println!(
+                        "{} ({})\n\n{}",
+                        error_code.code,
+                        error_code.titles.join(", "),
+                        error_code.explanation
+                    );
+
+                    Ok(0)
+                }
+                None => {
+                    eprintln!("{code} is not a Roc error code. Codes look like R0300, and are shown after the title of each error and warning.");
+
+                    Ok(1)
+                }
+            }
         }
         Some((CMD_FORMAT, matches)) => {
             let from_stdin = matches.get_flag(FLAG_STDIN);
             let to_stdout = matches.get_flag(FLAG_STDOUT);
             let format_mode = if to_stdout {
                 FormatMode::WriteToStdout
+            } else if matches.get_flag(FLAG_VERIFY) {
+                FormatMode::Verify
             } else {
                 match matches.get_flag(FLAG_CHECK) {
                     true => FormatMode::CheckOnly,
@@ -243,8 +409,10 @@ fn main() -> io::Result<()> {
                 }
             };
 
+            let max_width = matches.get_one::<usize>(FLAG_MAX_WIDTH).copied();
+
             if from_stdin && matches!(format_mode, FormatMode::WriteToFile) {
-                eprintln!("When using the --stdin flag, either the --check or the --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
+                eprintln!("When using the --stdin flag, either the --check, --verify or --stdout flag must also be specified. (Otherwise, it's unclear what filename to write to!)");
                 std::process::exit(1);
             }
 
@@ -294,7 +462,7 @@ fn main() -> io::Result<()> {
                     std::process::exit(1);
                 });
 
-                match format_src(&arena, src) {
+                match format_src(&arena, src, max_width) {
                     Ok(formatted_src) => {
                         match format_mode {
                             FormatMode::CheckOnly => {
@@ -313,6 +481,7 @@ fn main() -> io::Result<()> {
 
                                 0
                             }
+                            FormatMode::Verify => 0,
                             FormatMode::WriteToFile => {
                                 // We would have errored out already if you specified --stdin
                                 // without either --stdout or --check specified as well.
@@ -326,7 +495,7 @@ fn main() -> io::Result<()> {
                     }
                 }
             } else {
-                match format_files(roc_files, format_mode) {
+                match format_files(roc_files, format_mode, max_width) {
                     Ok(()) => 0,
                     Err(message) => {
                         eprintln!("{message}");
@@ -337,6 +506,20 @@ fn main() -> io::Result<()> {
 
             Ok(format_exit_code)
         }
+        Some((CMD_UPGRADE, matches)) => {
+            let roc_files = match matches.get_many::<OsString>(DIRECTORY_OR_FILES) {
+                Some(os_values) => os_values.map(PathBuf::from).collect(),
+                None => vec![std::env::current_dir()?],
+            };
+
+            match upgrade_files(roc_files, matches.get_flag(FLAG_DRY_RUN)) {
+                Ok(()) => Ok(0),
+                Err(message) => {
+                    eprintln!("{message}");
+                    Ok(1)
+                }
+            }
+        }
         Some((CMD_VERSION, _)) => {
             print!(
                 "{}",
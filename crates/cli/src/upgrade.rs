@@ -0,0 +1,245 @@
+use std::io::Write;
+use std::path::PathBuf;
+
+use bumpalo::Bump;
+use roc_error_macros::{internal_error, user_error};
+use roc_fmt::upgrade::upgrade_defs;
+use roc_fmt::Ast;
+
+use crate::format::{
+    flatten_directories, fmt_checked, parse_all, write_problem_files, FormatProblem,
+};
+
+/// How many unchanged lines to show around each change in a dry run.
+const DIFF_CONTEXT: usize = 3;
+
+/// Rewrite old syntax in the given files (or the .roc files in the given directories) into
+/// the current syntax, formatting them along the way.
+///
+/// In a dry run, nothing is written; a diff of what would change is printed instead.
+pub fn upgrade_files(files: Vec<PathBuf>, dry_run: bool) -> Result<(), String> {
+    let arena = Bump::new();
+
+    for file in flatten_directories(files) {
+        let src = std::fs::read_to_string(&file).unwrap();
+
+        match upgrade_src(&arena, &src) {
+            Ok(upgraded) => {
+                if upgraded == src {
+                    continue;
+                }
+
+                if dry_run {
+                    let diff = unified_diff(&file.display().to_string(), &src, &upgraded);
+
+                    std::io::stdout().lock().write_all(diff.as_bytes()).unwrap();
+                } else {
+                    std::fs::write(&file, upgraded).unwrap();
+                }
+            }
+            Err(problem) => {
+                internal_error!("{}", write_problem_files(&file, problem))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrite old syntax in the code into the current syntax, and format it.
+pub fn upgrade_src(arena: &Bump, src: &str) -> Result<String, FormatProblem> {
+    let ast = parse_all(arena, src).unwrap_or_else(|e| {
+        user_error!("Unexpected parse failure when parsing this for upgrading:\n\n{:?}\n\nParse error was:\n\n{:?}\n\n", src, e)
+    });
+
+    let upgraded = arena.alloc(Ast {
+        defs: upgrade_defs(arena, &ast.defs),
+        module: ast.module,
+    });
+
+    fmt_checked(arena, src, upgraded, None)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// The lines of `before` and `after` as a diff, based on their longest common subsequence.
+fn diff_lines<'a>(before: &'a str, after: &'a str) -> Vec<DiffLine<'a>> {
+    let before: Vec<&str> = before.lines().collect();
+    let after: Vec<&str> = after.lines().collect();
+
+    // common[i][j] is the length of the longest common subsequence of before[i..] and after[j..]
+    let mut common = vec![vec![0u32; after.len() + 1]; before.len() + 1];
+
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            common[i][j] = if before[i] == after[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(before.len().max(after.len()));
+    let (mut i, mut j) = (0, 0);
+
+    while i < before.len() && j < after.len() {
+        if before[i] == after[j] {
+            lines.push(DiffLine::Same(before[i]));
+            i += 1;
+            j += 1;
+        } else if common[i + 1][j] >= common[i][j + 1] {
+            lines.push(DiffLine::Removed(before[i]));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(after[j]));
+            j += 1;
+        }
+    }
+
+    lines.extend(before[i..].iter().map(|line| DiffLine::Removed(line)));
+    lines.extend(after[j..].iter().map(|line| DiffLine::Added(line)));
+
+    lines
+}
+
+/// A unified diff between the two versions of the file at `path`.
+fn unified_diff(path: &str, before: &str, after: &str) -> String {
+    let lines = diff_lines(before, after);
+    let mut diff = format!("--- {path}\n+++ {path}\n");
+
+    let changes: Vec<usize> = (0..lines.len())
+        .filter(|&index| !matches!(lines[index], DiffLine::Same(_)))
+        .collect();
+
+    // Group the changes into hunks, merging the ones whose context would overlap.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+
+    for &index in changes.iter() {
+        let start = index.saturating_sub(DIFF_CONTEXT);
+        let end = (index + DIFF_CONTEXT + 1).min(lines.len());
+
+        match hunks.last_mut() {
+            Some((_, hunk_end)) if start <= *hunk_end => *hunk_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    for (start, end) in hunks {
+        let is_old = |line: &DiffLine| !matches!(line, DiffLine::Added(_));
+        let is_new = |line: &DiffLine| !matches!(line, DiffLine::Removed(_));
+
+        let old_start = lines[..start].iter().filter(|line| is_old(line)).count();
+        let new_start = lines[..start].iter().filter(|line| is_new(line)).count();
+        let old_len = lines[start..end].iter().filter(|line| is_old(line)).count();
+        let new_len = lines[start..end].iter().filter(|line| is_new(line)).count();
+
+        // Line numbers start at 1, except for an empty range, which names the line before it.
+        let old_start = if old_len == 0 {
+            old_start
+        } else {
+            old_start + 1
+        };
+        let new_start = if new_len == 0 {
+            new_start
+        } else {
+            new_start + 1
+        };
+
+        diff.push_str(&format!(
+            "@@ -{old_start},{old_len} +{new_start},{new_len} @@\n"
+        ));
+
+        for line in &lines[start..end] {
+            let (prefix, text) = match line {
+                DiffLine::Same(text) => (' ', text),
+                DiffLine::Removed(text) => ('-', text),
+                DiffLine::Added(text) => ('+', text),
+            };
+
+            diff.push(prefix);
+            diff.push_str(text);
+            diff.push('\n');
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const OLD_ROC: &str = r#"interface Greeting
+    exposes [greet]
+    imports [pf.Stdout, pf.Task]
+
+# Say hello, then say how it went
+greet = \name ->
+    _ <- Task.await (Stdout.line "Hello, $(name)!")
+    # Nothing can go wrong here
+    result <- Task.await getResult
+
+    Task.ok result
+"#;
+
+    const UPGRADED_ROC: &str = r#"module [greet]
+
+import pf.Stdout
+import pf.Task
+
+# Say hello, then say how it went
+greet = \name ->
+    Stdout.line! "Hello, $(name)!"
+    # Nothing can go wrong here
+    result = getResult!
+
+    Task.ok result
+"#;
+
+    #[test]
+    fn test_upgrade_src() {
+        let arena = Bump::new();
+
+        assert_eq!(upgrade_src(&arena, OLD_ROC).unwrap(), UPGRADED_ROC);
+    }
+
+    #[test]
+    fn test_upgrade_src_is_stable() {
+        let arena = Bump::new();
+
+        assert_eq!(upgrade_src(&arena, UPGRADED_ROC).unwrap(), UPGRADED_ROC);
+    }
+
+    #[test]
+    fn test_dry_run_does_not_write() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("Greeting.roc");
+        std::fs::write(&file_path, OLD_ROC).unwrap();
+
+        upgrade_files(vec![file_path.clone()], true).unwrap();
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), OLD_ROC);
+
+        upgrade_files(vec![file_path.clone()], false).unwrap();
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), UPGRADED_ROC);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let before = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let after = "a\nB\nc\nd\ne\nf\ng\nh\ni\nj\nk\n";
+
+        assert_eq!(
+            unified_diff("File.roc", before, after),
+            "--- File.roc\n+++ File.roc\n\
+            @@ -1,5 +1,5 @@\n a\n-b\n+B\n c\n d\n e\n\
+            @@ -8,3 +8,4 @@\n h\n i\n j\n+k\n"
+        );
+    }
+}
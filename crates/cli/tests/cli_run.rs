@@ -21,8 +21,11 @@ mod cli_run {
     use roc_reporting::report::strip_colors;
     use roc_test_utils::assert_multiline_str_eq;
     use serial_test::serial;
+    use std::io::Read;
     use std::iter;
     use std::path::Path;
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant};
 
     #[cfg(all(unix, not(target_os = "macos")))]
     const ALLOW_VALGRIND: bool = true;
@@ -52,6 +55,7 @@ mod cli_run {
     }
 
     const OPTIMIZE_FLAG: &str = concatcp!("--", roc_cli::FLAG_OPTIMIZE);
+    const DEV_FLAG: &str = concatcp!("--", roc_cli::FLAG_DEV);
     const LINKER_FLAG: &str = concatcp!("--", roc_cli::FLAG_LINKER);
     const CHECK_FLAG: &str = concatcp!("--", roc_cli::FLAG_CHECK);
     #[allow(dead_code)]
@@ -645,6 +649,60 @@ mod cli_run {
             UseValgrind::Yes,
             TestCliCommands::Test,
         );
+
+        // A binary built by the dev backend has no parent `roc` process to report a failed
+        // `expect` to, so it must skip them instead of waiting for a parent to answer.
+        if !cfg!(windows) {
+            let file = file_path_from_root("crates/cli/tests/expects", "expects.roc");
+
+            run_roc_on_failure_is_panic(&file, [CMD_BUILD, DEV_FLAG], &[], &[], &[]);
+
+            let (status, stdout) =
+                run_with_deadline(&file.with_extension(""), Duration::from_secs(60));
+
+            assert!(status.success(), "The binary exited with {status:?}");
+            assert!(
+                stdout.ends_with("Program finished!\n"),
+                "Unexpected output: {stdout}"
+            );
+        }
+    }
+
+    /// Run an executable, and fail if it hasn't exited by the deadline.
+    fn run_with_deadline(
+        executable: &Path,
+        deadline: Duration,
+    ) -> (std::process::ExitStatus, String) {
+        let mut child = Command::new(executable)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .unwrap_or_else(|err| panic!("Could not run {}: {err}", executable.display()));
+        let start = Instant::now();
+
+        let status = loop {
+            if let Some(status) = child.try_wait().unwrap() {
+                break status;
+            }
+
+            if start.elapsed() > deadline {
+                child.kill().unwrap();
+
+                panic!("{} did not exit within {deadline:?}", executable.display());
+            }
+
+            std::thread::sleep(Duration::from_millis(100));
+        };
+
+        let mut stdout = String::new();
+        child
+            .stdout
+            .take()
+            .unwrap()
+            .read_to_string(&mut stdout)
+            .unwrap();
+
+        (status, stdout)
     }
 
     #[test]
@@ -683,6 +741,20 @@ mod cli_run {
         );
     }
 
+    #[test]
+    #[cfg_attr(windows, ignore)]
+    fn expects_denied_warning() {
+        // The .roc-warnings file next to Denied.roc denies its unused definition
+        let path = file_path_from_root("crates/cli/tests/expects_denied", "Denied.roc");
+        let out = run_roc([CMD_TEST, path.to_str().unwrap()], &[], &[]);
+        let stdout = strip_colors(&out.stdout);
+
+        assert_eq!(out.status.code(), Some(1), "{stdout}\n{}", out.stderr);
+        assert!(stdout.contains("UNUSED DEFINITION"), "{stdout}");
+        assert!(stdout.contains("1 error and 0 warnings found"), "{stdout}");
+        assert!(!stdout.contains("passed"), "{stdout}");
+    }
+
     #[test]
     #[cfg_attr(
         windows,
@@ -1371,7 +1443,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── TYPE MISMATCH in tests/known_bad/TypeError.roc ──────────────────────────────
+                ── TYPE MISMATCH [R0300] in tests/known_bad/TypeError.roc ──────────────────────
 
                 Something is off with the body of the main definition:
 
@@ -1407,7 +1479,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNUSED IMPORT in ...nown_bad/UnusedImportButWithALongFileNameForTesting.roc ─
+                ── UNUSED IMPORT [R0203] in .../UnusedImportButWithALongFileNameForTesting.roc ─
 
                 Symbol is imported but not used.
 
@@ -1430,7 +1502,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── MISSING DEFINITION in tests/known_bad/ExposedNotDefined.roc ─────────────────
+                ── MISSING DEFINITION [R0211] in tests/known_bad/ExposedNotDefined.roc ─────────
 
                 bar is listed as exposed, but it isn't defined in this module.
 
@@ -1451,7 +1523,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNUSED IMPORT in tests/known_bad/UnusedImport.roc ───────────────────────────
+                ── UNUSED IMPORT [R0203] in tests/known_bad/UnusedImport.roc ───────────────────
 
                 Symbol is imported but not used.
 
@@ -1474,7 +1546,7 @@ mod cli_run {
             &[],
             indoc!(
                 r#"
-                ── UNKNOWN GENERATES FUNCTION in tests/known_bad/UnknownGeneratesWith.roc ──────
+                ── UNKNOWN GENERATES FUNCTION [R0212] in ...known_bad/UnknownGeneratesWith.roc ─
 
                 I don't know how to generate the foobar function.
 
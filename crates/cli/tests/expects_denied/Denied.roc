@@ -0,0 +1,10 @@
+interface Denied
+    exposes [double]
+    imports []
+
+double = \num ->
+    unused = num + 1
+
+    num * 2
+
+expect double 2 == 4
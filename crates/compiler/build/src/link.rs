@@ -2,7 +2,7 @@ use crate::target::arch_str;
 use libloading::{Error, Library};
 use roc_command_utils::{cargo, clang, rustup, zig};
 use roc_debug_flags;
-use roc_error_macros::internal_error;
+use roc_error_macros::{internal_error, user_error};
 use roc_mono::ir::OptLevel;
 use roc_target::{Architecture, OperatingSystem, Target};
 use std::collections::HashMap;
@@ -138,6 +138,7 @@ pub fn build_zig_host_native(
     target: &str,
     opt_level: OptLevel,
     shared_lib_path: Option<&Path>,
+    link_type: LinkType,
     builtins_host_path: &Path,
 ) -> Command {
     let mut zig_cmd = zig();
@@ -147,13 +148,19 @@ pub fn build_zig_host_native(
         .env("HOME", env_home);
 
     if let Some(shared_lib_path) = shared_lib_path {
+        match link_type {
+            LinkType::Dylib => zig_cmd.args(["build-lib", "-dynamic", "-fPIC"]),
+            _ => zig_cmd.args([
+                "build-exe",
+                "-fPIE",
+                "-rdynamic", // make sure roc_alloc and friends are exposed
+            ]),
+        };
+
         // with LLVM, the builtins are already part of the roc app,
         // but with the dev backend, they are missing. To minimize work,
         // we link them as part of the host executable
         zig_cmd.args([
-            "build-exe",
-            "-fPIE",
-            "-rdynamic", // make sure roc_alloc and friends are exposed
             shared_lib_path.to_str().unwrap(),
             builtins_host_path.to_str().unwrap(),
         ]);
@@ -210,6 +217,7 @@ pub fn build_zig_host_native(
     target: &str,
     opt_level: OptLevel,
     shared_lib_path: Option<&Path>,
+    _link_type: LinkType,
     builtins_host_path: &Path,
 ) -> Command {
     // to prevent `clang failed with stderr: zig: error: unable to make temporary file: No such file or directory`
@@ -318,6 +326,7 @@ pub fn build_c_host_native(
     sources: &[&str],
     opt_level: OptLevel,
     shared_lib_path: Option<&Path>,
+    link_type: LinkType,
     builtins_host_path: &Path,
 ) -> Command {
     let mut clang_cmd = clang();
@@ -344,10 +353,16 @@ pub fn build_c_host_native(
                     "native",
                     opt_level,
                     Some(shared_lib_path),
+                    link_type,
                     builtins_host_path,
                 );
             }
             _ => {
+                match link_type {
+                    LinkType::Dylib => clang_cmd.args(["-fPIC", "-shared"]),
+                    _ => clang_cmd.args(["-fPIE", "-pie"]),
+                };
+
                 clang_cmd.args([
                     shared_lib_path.to_str().unwrap(),
                     // This line is commented out because
@@ -356,8 +371,6 @@ pub fn build_c_host_native(
                     // optimized builds. Disabling until it is needed for dev
                     // builds.
                     // builtins_host_path,
-                    "-fPIE",
-                    "-pie",
                     "-lm",
                     "-lpthread",
                     "-ldl",
@@ -434,6 +447,7 @@ pub fn rebuild_host(
     target: Target,
     platform_main_roc: &Path,
     shared_lib_path: Option<&Path>,
+    link_type: LinkType,
 ) -> PathBuf {
     let c_host_src = platform_main_roc.with_file_name("host.c");
     let c_host_dest = platform_main_roc.with_file_name("c_host.o");
@@ -456,9 +470,7 @@ pub fn rebuild_host(
             platform_main_roc.with_extension("bc")
         }
     } else if shared_lib_path.is_some() {
-        platform_main_roc
-            .with_file_name("dynhost")
-            .with_extension(executable_extension)
+        platform_main_roc.with_file_name(roc_linker::dynhost_file_name(target, link_type))
     } else {
         legacy_host_file(target, platform_main_roc)
     };
@@ -499,6 +511,7 @@ pub fn rebuild_host(
                 "x86_64-native",
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             ),
             Architecture::X86_32 => build_zig_host_native(
@@ -509,6 +522,7 @@ pub fn rebuild_host(
                 "i386-linux-musl",
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             ),
             Architecture::Aarch64 => build_zig_host_native(
@@ -519,6 +533,7 @@ pub fn rebuild_host(
                 "native",
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             ),
             _ => internal_error!("Unsupported architecture {:?}", target.architecture()),
@@ -526,6 +541,10 @@ pub fn rebuild_host(
 
         run_build_command(zig_cmd, "host.zig", 0);
     } else if cargo_host_src.exists() {
+        if shared_lib_path.is_some() && link_type == LinkType::Dylib {
+            user_error!("The surgical linker cannot build a shared library from a Cargo host yet.\nPlease use `--linker=legacy` for now.");
+        }
+
         // Compile and link Cargo.toml, if it exists
         let cargo_dir = platform_main_roc.parent().unwrap();
 
@@ -588,6 +607,7 @@ pub fn rebuild_host(
                 &[c_host_src.to_str().unwrap()],
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             );
 
@@ -644,6 +664,7 @@ pub fn rebuild_host(
                 ],
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             );
             run_build_command(clang_cmd, "host.c", 0);
@@ -657,6 +678,7 @@ pub fn rebuild_host(
                 &[c_host_src.to_str().unwrap()],
                 opt_level,
                 shared_lib_path,
+                link_type,
                 builtins_host_tempfile.path(),
             );
 
@@ -693,6 +715,7 @@ pub fn rebuild_host(
             &[c_host_src.to_str().unwrap()],
             opt_level,
             shared_lib_path,
+            link_type,
             builtins_host_tempfile.path(),
         );
 
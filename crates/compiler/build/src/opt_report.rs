@@ -0,0 +1,1033 @@
+//! The report printed by `roc build --opt-report`: which updates copy when their value is shared,
+//! which allocations get reused, and which refcount operations stayed inside loops.
+//!
+//! Whether an update can happen in place is decided by alias analysis, which only the LLVM backend
+//! runs. We walk every specialization of the app's procs with the solutions it found.
+//! Calls into builtins like `List.set` are followed into the specialization alias analysis picked
+//! for that call, so that what happens inside them is reported where the user called them.
+use morphic_lib::{
+    CalleeSpecVar, FuncName, FuncSpec, FuncSpecSolutions, ModSolutions, UpdateMode, UpdateModeVar,
+};
+use roc_collections::all::{MutMap, MutSet};
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{Interns, ModuleId, Symbol};
+use roc_mono::ir::{
+    CallType, Expr, JoinPointId, ModifyRc, OptLevel, Proc, ProcLayout, Stmt, UpdateModeId,
+};
+use roc_region::all::{LineInfo, Region};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Section {
+    Copies,
+    Reuse,
+    LoopRefcount,
+}
+
+impl Section {
+    const ALL: [Section; 3] = [Section::Copies, Section::Reuse, Section::LoopRefcount];
+
+    fn title(self) -> &'static str {
+        match self {
+            Section::Copies => "Updates that copy when the value is shared at runtime",
+            Section::Reuse => "Reused allocations",
+            Section::LoopRefcount => "Refcounting inside loops",
+        }
+    }
+}
+
+/// One thing worth reporting, found in some specialization of `proc`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Finding {
+    section: Section,
+    /// Where the user-named definition closest to where this happens is, or [Region::zero] if
+    /// there is none
+    site: Region,
+    proc: Symbol,
+    message: String,
+}
+
+/// The parts of the loaded program the report points into
+pub struct ReportSources<'r> {
+    pub sources: &'r MutMap<ModuleId, (PathBuf, Box<str>)>,
+    pub interns: &'r Interns,
+}
+
+/// Describe what alias analysis decided for these procs, grouped by section and sorted by source
+/// location. `mod_solutions` are the solutions for the app module that code generation used.
+pub fn opt_report<'a>(
+    procedures: &MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+    mod_solutions: &ModSolutions,
+    report_sources: ReportSources,
+    opt_level: OptLevel,
+) -> String {
+    let procs = procedures
+        .values()
+        .map(|proc| (roc_alias_analysis::func_name_bytes(proc), proc))
+        .collect();
+
+    let is_user_proc = |proc: &&Proc| {
+        let module_id = proc.name.name().module_id();
+
+        !module_id.is_builtin() && report_sources.sources.contains_key(&module_id)
+    };
+
+    let mut named = MutSet::default();
+    for proc in procedures.values().filter(is_user_proc) {
+        collect_named(proc, report_sources.interns, &mut named);
+    }
+
+    let mut analysis = Analysis {
+        procs,
+        mod_solutions,
+        named,
+        interns: report_sources.interns,
+        findings: MutMap::default(),
+    };
+
+    for proc in procedures.values().filter(is_user_proc) {
+        analysis.user_proc(proc);
+    }
+
+    render(
+        &report_sources,
+        opt_level,
+        &analysis.named,
+        analysis.findings,
+    )
+}
+
+struct Analysis<'r, 'a> {
+    procs: MutMap<[u8; 16], &'r Proc<'a>>,
+    mod_solutions: &'r ModSolutions,
+    /// The symbols of user procs that have a name in the source
+    named: MutSet<Symbol>,
+    interns: &'r Interns,
+    /// How many specializations of its proc each finding showed up in, out of how many there are
+    findings: MutMap<Finding, (usize, usize)>,
+}
+
+/// What we know about the values in a proc, regardless of specialization.
+/// This is what we use to guess why alias analysis couldn't prove something is unique; the
+/// solutions themselves don't say.
+#[derive(Default)]
+struct ProcFacts {
+    args: MutSet<Symbol>,
+    /// Values that were taken out of another value, and that value
+    taken_from: MutMap<Symbol, Symbol>,
+    /// Values that get an extra reference somewhere in the proc
+    incremented: MutSet<Symbol>,
+    /// The result of each `Reset`, and the value whose memory it holds on to
+    resets: MutMap<Symbol, (Symbol, UpdateModeId)>,
+    /// The values passed to each join point parameter
+    passed: MutMap<Symbol, Vec<Symbol>>,
+}
+
+impl ProcFacts {
+    fn new(proc: &Proc) -> Self {
+        let mut facts = ProcFacts {
+            args: proc.args.iter().map(|(_, symbol)| *symbol).collect(),
+            ..Default::default()
+        };
+        let mut params = MutMap::default();
+        let mut jumps = Vec::new();
+        facts.collect(&proc.body, &mut params, &mut jumps);
+
+        for (id, args) in jumps {
+            let Some(params) = params.get(&id) else {
+                continue;
+            };
+
+            for (param, arg) in params.iter().zip(args) {
+                facts.passed.entry(*param).or_default().push(*arg);
+            }
+        }
+
+        facts
+    }
+
+    fn collect<'a>(
+        &mut self,
+        stmt: &Stmt<'a>,
+        params: &mut MutMap<JoinPointId, Vec<Symbol>>,
+        jumps: &mut Vec<(JoinPointId, &'a [Symbol])>,
+    ) {
+        match stmt {
+            Stmt::Let(symbol, expr, _, rest, _) => {
+                match expr {
+                    Expr::StructAtIndex { structure, .. }
+                    | Expr::UnionAtIndex { structure, .. } => {
+                        self.taken_from.insert(*symbol, *structure);
+                    }
+                    Expr::Call(call) => {
+                        if let CallType::LowLevel {
+                            op: LowLevel::ListGetUnsafe,
+                            ..
+                        } = call.call_type
+                        {
+                            self.taken_from.insert(*symbol, call.arguments[0]);
+                        }
+                    }
+                    Expr::Reset {
+                        symbol: reset,
+                        update_mode,
+                    }
+                    | Expr::ResetRef {
+                        symbol: reset,
+                        update_mode,
+                    } => {
+                        self.resets.insert(*symbol, (*reset, *update_mode));
+                    }
+                    _ => {}
+                }
+
+                self.collect(rest, params, jumps);
+            }
+            Stmt::Refcounting(modify, rest) => {
+                if let ModifyRc::Inc(symbol, _) = modify {
+                    self.incremented.insert(*symbol);
+                }
+
+                self.collect(rest, params, jumps);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                for (_, _, branch) in branches.iter() {
+                    self.collect(branch, params, jumps);
+                }
+                self.collect(default_branch.1, params, jumps);
+            }
+            Stmt::Join {
+                id,
+                parameters,
+                body,
+                remainder,
+            } => {
+                params.insert(*id, parameters.iter().map(|param| param.symbol).collect());
+
+                self.collect(body, params, jumps);
+                self.collect(remainder, params, jumps);
+            }
+            Stmt::Expect { remainder, .. }
+            | Stmt::ExpectFx { remainder, .. }
+            | Stmt::Dbg { remainder, .. } => self.collect(remainder, params, jumps),
+            Stmt::Jump(id, args) => jumps.push((*id, args)),
+            Stmt::Ret(..) | Stmt::Crash(_, _) => {}
+        }
+    }
+}
+
+/// A user proc, in one of its specializations
+struct ProcSpec<'r> {
+    proc: Symbol,
+    facts: &'r ProcFacts,
+    solutions: &'r FuncSpecSolutions,
+}
+
+impl<'r, 'a> Analysis<'r, 'a> {
+    fn user_proc(&mut self, proc: &Proc<'a>) {
+        let name_bytes = roc_alias_analysis::func_name_bytes(proc);
+        let Ok(func_solutions) = self.mod_solutions.func_solutions(FuncName(&name_bytes)) else {
+            return;
+        };
+
+        let specs: Vec<&FuncSpec> = func_solutions.specs().collect();
+        let facts = ProcFacts::new(proc);
+        let site = if self.named.contains(&proc.name.name()) {
+            proc.region
+        } else {
+            self.first_named(&proc.body).unwrap_or_else(Region::zero)
+        };
+
+        for spec in specs.iter() {
+            let proc_spec = ProcSpec {
+                proc: proc.name.name(),
+                facts: &facts,
+                solutions: func_solutions.spec(spec).unwrap(),
+            };
+
+            let mut found = MutSet::default();
+            self.stmt(&proc_spec, &proc.body, site, false, &mut found);
+
+            for finding in found {
+                self.findings.entry(finding).or_insert((0, specs.len())).0 += 1;
+            }
+        }
+    }
+
+    fn stmt(
+        &self,
+        proc_spec: &ProcSpec,
+        stmt: &Stmt<'a>,
+        site: Region,
+        in_loop: bool,
+        found: &mut MutSet<Finding>,
+    ) {
+        let finding = |section, site, message| Finding {
+            section,
+            site,
+            proc: proc_spec.proc,
+            message,
+        };
+
+        match stmt {
+            Stmt::Let(_, expr, _, rest, _) => {
+                let here = self.named_site(stmt).unwrap_or(site);
+
+                for (section, message) in self.expr(proc_spec, expr) {
+                    found.insert(finding(section, here, message));
+                }
+
+                self.stmt(proc_spec, rest, site, in_loop, found);
+            }
+            Stmt::Refcounting(modify, rest) => {
+                if in_loop {
+                    let here = self.named_site(stmt).unwrap_or(site);
+                    let (what, symbol) = match modify {
+                        ModifyRc::Inc(symbol, _) => ("increments the refcount of", symbol),
+                        ModifyRc::Dec(symbol) | ModifyRc::DecRef(symbol) => {
+                            ("decrements the refcount of", symbol)
+                        }
+                        ModifyRc::Free(symbol) => ("frees", symbol),
+                    };
+                    let message = format!(
+                        "{what} {} on every iteration",
+                        self.describe(*symbol, &proc_spec.facts.taken_from),
+                    );
+
+                    found.insert(finding(Section::LoopRefcount, here, message));
+                }
+
+                self.stmt(proc_spec, rest, site, in_loop, found);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                for (_, _, branch) in branches.iter() {
+                    self.stmt(proc_spec, branch, site, in_loop, found);
+                }
+                self.stmt(proc_spec, default_branch.1, site, in_loop, found);
+            }
+            Stmt::Join {
+                id,
+                body,
+                remainder,
+                ..
+            } => {
+                let is_loop = in_loop || jumps_to(body, *id);
+
+                self.stmt(proc_spec, body, site, is_loop, found);
+                self.stmt(proc_spec, remainder, site, in_loop, found);
+            }
+            Stmt::Expect { remainder, .. }
+            | Stmt::ExpectFx { remainder, .. }
+            | Stmt::Dbg { remainder, .. } => self.stmt(proc_spec, remainder, site, in_loop, found),
+            Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => {}
+        }
+    }
+
+    fn expr(&self, proc_spec: &ProcSpec, expr: &Expr<'a>) -> Vec<(Section, String)> {
+        let facts = proc_spec.facts;
+        let mut messages = Vec::new();
+
+        match expr {
+            Expr::Call(call) => match call.call_type {
+                CallType::LowLevel { op, update_mode } => {
+                    if let Some(name) = update_name(op) {
+                        if mode(proc_spec.solutions, update_mode) == UpdateMode::Immutable {
+                            let message = self.copy_message(proc_spec, name, call.arguments[0]);
+                            messages.push((Section::Copies, message));
+                        }
+                    }
+                }
+                CallType::ByName {
+                    name,
+                    ret_layout,
+                    arg_layouts,
+                    specialization_id,
+                } if name.name().is_builtin() => {
+                    let bytes = specialization_id.to_bytes();
+                    let Ok(callee_spec) = proc_spec.solutions.callee_spec(CalleeSpecVar(&bytes))
+                    else {
+                        return messages;
+                    };
+                    let callee_bytes = roc_alias_analysis::func_name_bytes_help(
+                        name.name(),
+                        arg_layouts.iter().copied(),
+                        name.niche(),
+                        ret_layout,
+                    );
+                    let callee_name = format!(
+                        "{}.{}",
+                        name.name().module_string(self.interns),
+                        name.name().as_str(self.interns)
+                    );
+
+                    let mut visited = MutSet::default();
+                    let mut copied = self.builtin_copies(callee_bytes, callee_spec, &mut visited);
+                    copied.sort();
+                    copied.dedup();
+
+                    for arg_index in copied {
+                        let message = match arg_index {
+                            Some(index) => {
+                                self.copy_message(proc_spec, &callee_name, call.arguments[index])
+                            }
+                            None => format!(
+                                "`{callee_name}` copies a value it built itself if it's shared at runtime"
+                            ),
+                        };
+                        messages.push((Section::Copies, message));
+                    }
+                }
+                _ => {}
+            },
+            Expr::Tag {
+                reuse: Some(token), ..
+            } => {
+                let (reused, update_mode) = self.reused(facts, token.symbol);
+                let reused = self.describe(reused, &facts.taken_from);
+                let update_mode =
+                    update_mode.map(|update_mode| mode(proc_spec.solutions, update_mode));
+
+                let message = match update_mode {
+                    Some(UpdateMode::InPlace) => format!("reuses the memory of {reused}"),
+                    Some(UpdateMode::Immutable) | None => format!(
+                        "reuses the memory of {reused} if it's unique at runtime, and allocates otherwise"
+                    ),
+                };
+                messages.push((Section::Reuse, message));
+            }
+            _ => {}
+        }
+
+        messages
+    }
+
+    /// The updates in a builtin (and the builtins it calls) that aren't known to be in place, as
+    /// the index of the argument being updated. `None` means it updates something it built itself.
+    fn builtin_copies(
+        &self,
+        name_bytes: [u8; 16],
+        spec: FuncSpec,
+        visited: &mut MutSet<([u8; 16], FuncSpec)>,
+    ) -> Vec<Option<usize>> {
+        if !visited.insert((name_bytes, spec)) {
+            return Vec::new();
+        }
+
+        let Some(proc) = self.procs.get(&name_bytes) else {
+            return Vec::new();
+        };
+        let Ok(func_solutions) = self.mod_solutions.func_solutions(FuncName(&name_bytes)) else {
+            return Vec::new();
+        };
+        let Ok(solutions) = func_solutions.spec(&spec) else {
+            return Vec::new();
+        };
+
+        let arg_index = |symbol: Symbol| proc.args.iter().position(|(_, arg)| *arg == symbol);
+
+        let mut copies = Vec::new();
+        let mut stack = vec![&proc.body];
+
+        while let Some(stmt) = stack.pop() {
+            match stmt {
+                Stmt::Let(_, Expr::Call(call), _, rest, _) => {
+                    match call.call_type {
+                        CallType::LowLevel { op, update_mode } => {
+                            if update_name(op).is_some()
+                                && mode(solutions, update_mode) == UpdateMode::Immutable
+                            {
+                                copies.push(arg_index(call.arguments[0]));
+                            }
+                        }
+                        CallType::ByName {
+                            name,
+                            ret_layout,
+                            arg_layouts,
+                            specialization_id,
+                        } if name.name().is_builtin() => {
+                            let bytes = specialization_id.to_bytes();
+
+                            if let Ok(callee_spec) = solutions.callee_spec(CalleeSpecVar(&bytes)) {
+                                let callee_bytes = roc_alias_analysis::func_name_bytes_help(
+                                    name.name(),
+                                    arg_layouts.iter().copied(),
+                                    name.niche(),
+                                    ret_layout,
+                                );
+
+                                for index in self.builtin_copies(callee_bytes, callee_spec, visited)
+                                {
+                                    copies.push(index.and_then(|i| arg_index(call.arguments[i])));
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    stack.push(rest);
+                }
+                Stmt::Let(_, _, _, rest, _) | Stmt::Refcounting(_, rest) => stack.push(rest),
+                Stmt::Switch {
+                    branches,
+                    default_branch,
+                    ..
+                } => {
+                    stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                    stack.push(default_branch.1);
+                }
+                Stmt::Join {
+                    body, remainder, ..
+                } => {
+                    stack.push(body);
+                    stack.push(remainder);
+                }
+                Stmt::Expect { remainder, .. }
+                | Stmt::ExpectFx { remainder, .. }
+                | Stmt::Dbg { remainder, .. } => stack.push(remainder),
+                Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => {}
+            }
+        }
+
+        copies
+    }
+
+    /// The value whose memory a reuse token holds on to. The token is either the result of a
+    /// `Reset`, or a join point parameter that's passed the value when it's unique at runtime.
+    fn reused(&self, facts: &ProcFacts, token: Symbol) -> (Symbol, Option<UpdateModeId>) {
+        let passed = facts.passed.get(&token).map_or(&[][..], |passed| passed);
+        let candidates = || std::iter::once(&token).chain(passed);
+
+        if let Some((reused, update_mode)) = candidates().find_map(|c| facts.resets.get(c)) {
+            return (*reused, Some(*update_mode));
+        }
+
+        let reused = candidates()
+            .find(|c| self.named.contains(c))
+            .unwrap_or(&token);
+
+        (*reused, None)
+    }
+
+    /// Alias analysis only tells us that an update isn't known to be in place, not why. The
+    /// likely reason is a guess from the shape of the proc, and the message says so.
+    fn copy_message(&self, proc_spec: &ProcSpec, name: &str, updated: Symbol) -> String {
+        let facts = proc_spec.facts;
+        let value = self.describe(updated, &facts.taken_from);
+
+        let likely_reason = if facts.incremented.contains(&updated) {
+            Some("it's used again afterwards, so it's still shared at this point".to_string())
+        } else if let Some(parent) = facts.taken_from.get(&updated) {
+            Some(format!(
+                "it was taken out of {}, which still holds a reference to it",
+                self.describe(*parent, &facts.taken_from)
+            ))
+        } else if facts.args.contains(&updated) {
+            Some(format!(
+                "it's an argument of {}, and some caller passes a value that may be shared",
+                proc_name(&self.named, proc_spec.proc, self.interns)
+            ))
+        } else {
+            None
+        };
+
+        let mut message = format!(
+            "`{name}` copies {value} if it's shared at runtime, because alias analysis couldn't prove that it's unique here"
+        );
+        if let Some(reason) = likely_reason {
+            write!(message, " (likely reason: {reason})").unwrap();
+        }
+
+        message
+    }
+
+    /// A user-facing name for a value, or a description of where it came from
+    fn describe(&self, symbol: Symbol, taken_from: &MutMap<Symbol, Symbol>) -> String {
+        if self.named.contains(&symbol) {
+            return format!("`{}`", symbol.as_str(self.interns));
+        }
+
+        let mut parent = taken_from.get(&symbol);
+        while let Some(symbol) = parent {
+            if self.named.contains(symbol) {
+                return format!("a value taken out of `{}`", symbol.as_str(self.interns));
+            }
+            parent = taken_from.get(symbol);
+        }
+
+        "a temporary value".to_string()
+    }
+
+    /// The first user-named definition at or after this statement, before control flow branches.
+    /// Temporaries are defined before the named value they're part of, so this is where they are
+    /// in the source.
+    fn named_site(&self, mut stmt: &Stmt) -> Option<Region> {
+        loop {
+            match stmt {
+                Stmt::Let(symbol, _, _, rest, region) => {
+                    if self.named.contains(symbol) {
+                        return Some(*region);
+                    }
+                    stmt = rest;
+                }
+                Stmt::Refcounting(_, rest) => stmt = rest,
+                _ => return None,
+            }
+        }
+    }
+
+    fn first_named(&self, stmt: &Stmt) -> Option<Region> {
+        match stmt {
+            Stmt::Let(symbol, _, _, rest, region) => Some(*region)
+                .filter(|_| self.named.contains(symbol))
+                .or_else(|| self.first_named(rest)),
+            Stmt::Refcounting(_, rest)
+            | Stmt::Expect {
+                remainder: rest, ..
+            }
+            | Stmt::ExpectFx {
+                remainder: rest, ..
+            }
+            | Stmt::Dbg {
+                remainder: rest, ..
+            } => self.first_named(rest),
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => branches
+                .iter()
+                .find_map(|(_, _, branch)| self.first_named(branch))
+                .or_else(|| self.first_named(default_branch.1)),
+            Stmt::Join {
+                body, remainder, ..
+            } => self
+                .first_named(body)
+                .or_else(|| self.first_named(remainder)),
+            Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => None,
+        }
+    }
+}
+
+/// Add the symbols of this proc that the user named: its own name, its arguments and the values
+/// it defines. The compiler only makes up numbered symbols or lets without a source region.
+fn collect_named(proc: &Proc, interns: &Interns, named: &mut MutSet<Symbol>) {
+    let is_user_name = |symbol: Symbol| {
+        symbol
+            .as_str(interns)
+            .starts_with(|c: char| c.is_ascii_lowercase())
+    };
+
+    let proc_symbols = std::iter::once(proc.name.name()).chain(proc.args.iter().map(|(_, s)| *s));
+    named.extend(proc_symbols.filter(|symbol| is_user_name(*symbol)));
+
+    let mut stack = vec![&proc.body];
+    while let Some(stmt) = stack.pop() {
+        match stmt {
+            Stmt::Let(symbol, _, _, rest, region) => {
+                if *region != Region::zero() && is_user_name(*symbol) {
+                    named.insert(*symbol);
+                }
+                stack.push(rest);
+            }
+            Stmt::Refcounting(_, rest)
+            | Stmt::Expect {
+                remainder: rest, ..
+            }
+            | Stmt::ExpectFx {
+                remainder: rest, ..
+            }
+            | Stmt::Dbg {
+                remainder: rest, ..
+            } => stack.push(rest),
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                stack.extend(branches.iter().map(|(_, _, branch)| branch));
+                stack.push(default_branch.1);
+            }
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                stack.push(body);
+                stack.push(remainder);
+            }
+            Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => {}
+        }
+    }
+}
+
+fn proc_name(named: &MutSet<Symbol>, symbol: Symbol, interns: &Interns) -> String {
+    if named.contains(&symbol) {
+        format!("`{}`", symbol.as_str(interns))
+    } else {
+        "an anonymous function".to_string()
+    }
+}
+
+/// The lowlevels the LLVM backend does in place when alias analysis says it can, by the name of
+/// the builtin users know them as
+fn update_name(op: LowLevel) -> Option<&'static str> {
+    match op {
+        LowLevel::ListReplaceUnsafe => Some("List.replace"),
+        LowLevel::ListSwap => Some("List.swap"),
+        LowLevel::ListReserve => Some("List.reserve"),
+        LowLevel::ListReleaseExcessCapacity => Some("List.releaseExcessCapacity"),
+        LowLevel::ListClone => Some("List.clone"),
+        LowLevel::StrFromUtf8 => Some("Str.fromUtf8"),
+        LowLevel::StrReleaseExcessCapacity => Some("Str.releaseExcessCapacity"),
+        _ => None,
+    }
+}
+
+fn mode(solutions: &FuncSpecSolutions, update_mode: UpdateModeId) -> UpdateMode {
+    let bytes = update_mode.to_bytes();
+
+    solutions
+        .update_mode(UpdateModeVar(&bytes))
+        .unwrap_or(UpdateMode::Immutable)
+}
+
+/// Whether this join point's body can jump back to it, i.e. it's a loop
+fn jumps_to(stmt: &Stmt, id: JoinPointId) -> bool {
+    match stmt {
+        Stmt::Jump(target, _) => *target == id,
+        Stmt::Let(_, _, _, rest, _)
+        | Stmt::Refcounting(_, rest)
+        | Stmt::Expect {
+            remainder: rest, ..
+        }
+        | Stmt::ExpectFx {
+            remainder: rest, ..
+        }
+        | Stmt::Dbg {
+            remainder: rest, ..
+        } => jumps_to(rest, id),
+        Stmt::Switch {
+            branches,
+            default_branch,
+            ..
+        } => {
+            branches.iter().any(|(_, _, branch)| jumps_to(branch, id))
+                || jumps_to(default_branch.1, id)
+        }
+        Stmt::Join {
+            body, remainder, ..
+        } => jumps_to(body, id) || jumps_to(remainder, id),
+        Stmt::Ret(..) | Stmt::Crash(_, _) => false,
+    }
+}
+
+fn render(
+    report_sources: &ReportSources,
+    opt_level: OptLevel,
+    named: &MutSet<Symbol>,
+    findings: MutMap<Finding, (usize, usize)>,
+) -> String {
+    let mut line_infos: MutMap<ModuleId, LineInfo> = MutMap::default();
+    let mut rows = Vec::with_capacity(findings.len());
+
+    for (finding, (count, total)) in findings {
+        let module_id = finding.proc.module_id();
+        let path = report_sources
+            .sources
+            .get(&module_id)
+            .map(|(path, _)| path.display().to_string())
+            .unwrap_or_default();
+
+        let (line, column) = if finding.site == Region::zero() {
+            (0, 0)
+        } else {
+            let line_info = line_infos.entry(module_id).or_insert_with(|| {
+                LineInfo::new(report_sources.sources.get(&module_id).map_or("", |s| &s.1))
+            });
+            let position = line_info.convert_pos(finding.site.start());
+
+            (position.line + 1, position.column + 1)
+        };
+
+        let proc_name = proc_name(named, finding.proc, report_sources.interns);
+
+        let mut message = finding.message;
+        if count < total {
+            write!(message, " ({count} of {total} specializations)").unwrap();
+        }
+
+        rows.push((finding.section, path, line, column, proc_name, message));
+    }
+
+    rows.sort();
+
+    let mut buf = String::from("Optimization report\n");
+
+    if matches!(opt_level, OptLevel::Development | OptLevel::Normal) {
+        buf.push_str(
+            "\nAlias analysis only runs with --optimize or --opt-size, so in this build every update checks at runtime whether it can happen in place.\n",
+        );
+    }
+
+    buf.push_str(
+        "\nRecord updates build a new record without copying the lists and strings in it, so they never show up here.\n",
+    );
+
+    for section in Section::ALL {
+        write!(buf, "\n{}:\n\n", section.title()).unwrap();
+
+        let mut any = false;
+        for (_, path, line, column, proc_name, message) in
+            rows.iter().filter(|row| row.0 == section)
+        {
+            any = true;
+
+            if *line == 0 {
+                writeln!(buf, "    {path} (in {proc_name}): {message}").unwrap();
+            } else {
+                writeln!(
+                    buf,
+                    "    {path}:{line}:{column} (in {proc_name}): {message}"
+                )
+                .unwrap();
+            }
+        }
+
+        if !any {
+            buf.push_str("    none\n");
+        }
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod test {
+    use super::{opt_report, ReportSources};
+    use bumpalo::Bump;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use roc_load::{ExecutionMode, FunctionKind, LoadConfig, Threading};
+    use roc_mono::ir::OptLevel;
+    use roc_packaging::cache::RocCacheDir;
+    use std::path::PathBuf;
+
+    fn report(src: &str) -> String {
+        let arena = Bump::new();
+        let load_config = LoadConfig {
+            target: roc_target::Target::LinuxX64,
+            function_kind: FunctionKind::LambdaSet,
+            threading: Threading::Single,
+            render: roc_reporting::report::RenderTarget::Generic,
+            palette: roc_reporting::report::DEFAULT_PALETTE,
+            exec_mode: ExecutionMode::Executable,
+        };
+        let loaded = roc_load::load_and_monomorphize_from_str(
+            &arena,
+            PathBuf::from("Test.roc"),
+            src,
+            PathBuf::from("fake/test/path"),
+            RocCacheDir::Disallowed,
+            load_config,
+        )
+        .unwrap_or_else(|_| panic!("failed to load"));
+
+        let solutions = roc_alias_analysis::spec_program(
+            &arena,
+            &loaded.layout_interner,
+            OptLevel::Optimize,
+            crate::program::mono_entry_point(&loaded.entry_point),
+            loaded.procedures.values(),
+            loaded
+                .host_exposed_lambda_sets
+                .iter()
+                .map(|(_, _, hels)| hels),
+        )
+        .unwrap();
+        let mod_solutions = solutions
+            .mod_solutions(roc_alias_analysis::MOD_APP)
+            .unwrap();
+
+        let report_sources = ReportSources {
+            sources: &loaded.sources,
+            interns: &loaded.interns,
+        };
+
+        opt_report(
+            &loaded.procedures,
+            mod_solutions,
+            report_sources,
+            OptLevel::Optimize,
+        )
+    }
+
+    #[test]
+    fn update_of_shared_list_copies() {
+        let actual = report(indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                list = [1, 2, 3]
+                updated = List.set list 0 10
+
+                List.len list + List.len updated
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            indoc!(
+                r#"
+                Optimization report
+
+                Record updates build a new record without copying the lists and strings in it, so they never show up here.
+
+                Updates that copy when the value is shared at runtime:
+
+                    Test.roc:5:5 (in `main`): `List.set` copies `list` if it's shared at runtime, because alias analysis couldn't prove that it's unique here (likely reason: it's used again afterwards, so it's still shared at this point)
+
+                Reused allocations:
+
+                    none
+
+                Refcounting inside loops:
+
+                    none
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn update_of_unique_list_is_in_place() {
+        let actual = report(indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main =
+                updated = List.set (List.repeat 0 3) 0 10
+
+                List.len updated
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            indoc!(
+                r#"
+                Optimization report
+
+                Record updates build a new record without copying the lists and strings in it, so they never show up here.
+
+                Updates that copy when the value is shared at runtime:
+
+                    none
+
+                Reused allocations:
+
+                    none
+
+                Refcounting inside loops:
+
+                    none
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn tag_rebuilt_from_unique_value() {
+        let actual = report(indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            ConsList : [Cons I64 ConsList, Nil]
+
+            bumpFirst : ConsList -> ConsList
+            bumpFirst = \list ->
+                when list is
+                    Cons first rest -> Cons (first + 1) rest
+                    Nil -> Nil
+
+            main =
+                when bumpFirst (Cons 1 Nil) is
+                    Cons first _ -> first
+                    Nil -> 0
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            indoc!(
+                r#"
+                Optimization report
+
+                Record updates build a new record without copying the lists and strings in it, so they never show up here.
+
+                Updates that copy when the value is shared at runtime:
+
+                    none
+
+                Reused allocations:
+
+                    Test.roc:6:1 (in `bumpFirst`): reuses the memory of `list` if it's unique at runtime, and allocates otherwise
+
+                Refcounting inside loops:
+
+                    none
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn refcount_in_loop() {
+        let actual = report(indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            totalLength : List Str, U64, U64 -> U64
+            totalLength = \strings, index, total ->
+                when List.get strings index is
+                    Ok string -> totalLength strings (index + 1) (total + Str.countUtf8Bytes string)
+                    Err _ -> total
+
+            main =
+                totalLength ["a", "bc"] 0 0
+            "#
+        ));
+
+        assert_eq!(
+            actual,
+            indoc!(
+                r#"
+                Optimization report
+
+                Record updates build a new record without copying the lists and strings in it, so they never show up here.
+
+                Updates that copy when the value is shared at runtime:
+
+                    none
+
+                Reused allocations:
+
+                    none
+
+                Refcounting inside loops:
+
+                    Test.roc:4:1 (in `totalLength`): decrements the refcount of `strings` on every iteration
+                    Test.roc:4:1 (in `totalLength`): decrements the refcount of a temporary value on every iteration
+                    Test.roc:4:1 (in `totalLength`): increments the refcount of `strings` on every iteration
+                "#
+            )
+        );
+    }
+}
@@ -1,21 +1,29 @@
 use crate::link::{
     legacy_host_file, link, preprocess_host_wasm32, rebuild_host, LinkType, LinkingStrategy,
 };
+use crate::size_report::SizeReportFormat;
 use bumpalo::Bump;
 use inkwell::memory_buffer::MemoryBuffer;
+use roc_collections::MutMap;
 use roc_error_macros::internal_error;
 use roc_gen_dev::AssemblyBackendMode;
 use roc_gen_llvm::llvm::build::{module_from_builtins, LlvmBackendMode};
 use roc_gen_llvm::llvm::externs::add_default_roc_externs;
+use roc_lint::{lint_module, rules, LintContext};
 use roc_load::{
     EntryPoint, ExecutionMode, ExpectMetadata, FunctionKind, LoadConfig, LoadMonomorphizedError,
     LoadedModule, LoadingProblem, MonomorphizedModule, Threading,
 };
-use roc_mono::ir::{OptLevel, SingleEntryPoint};
+use roc_mono::debug_sources::DebugSources;
+use roc_mono::ir::{OptLevel, ProcSizes, SingleEntryPoint};
 use roc_packaging::cache::RocCacheDir;
 use roc_reporting::{
-    cli::{report_problems, Problems},
+    cli::{
+        fix_problems, report_loading_problem, report_problems, report_problems_and_lints,
+        ErrorFormat, Problems,
+    },
     report::{RenderTarget, DEFAULT_PALETTE},
+    warnings::WarningConfig,
 };
 use roc_target::{Architecture, Target};
 use std::ffi::OsStr;
@@ -38,21 +46,33 @@ pub struct CodeGenTiming {
     pub total: Duration,
 }
 
-pub fn report_problems_monomorphized(loaded: &mut MonomorphizedModule) -> Problems {
+pub fn report_problems_monomorphized(
+    loaded: &mut MonomorphizedModule,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
+) -> Problems {
     report_problems(
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        error_format,
+        warning_config,
     )
 }
 
-pub fn report_problems_typechecked(loaded: &mut LoadedModule) -> Problems {
+pub fn report_problems_typechecked(
+    loaded: &mut LoadedModule,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
+) -> Problems {
     report_problems(
         &loaded.sources,
         &loaded.interns,
         &mut loaded.can_problems,
         &mut loaded.type_problems,
+        error_format,
+        warning_config,
     )
 }
 
@@ -86,9 +106,15 @@ pub struct CodeGenOptions {
     pub emit_debug_info: bool,
     pub emit_llvm_ir: bool,
     pub fuzz: bool,
+    /// Print where alias analysis let updates happen in place while generating code
+    pub opt_report: bool,
+    /// Print the code size of every specialization after generating code
+    pub size_report: Option<SizeReportFormat>,
+    /// Wasm proposals the wasm32 dev backend may use
+    pub wasm_features: roc_gen_wasm::WasmFeatures,
 }
 
-type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>);
+type GenFromMono<'a> = (CodeObject, CodeGenTiming, ExpectMetadata<'a>, ProcSizes<'a>);
 
 #[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module<'a>(
@@ -113,7 +139,9 @@ pub fn gen_from_mono_module<'a>(
             target,
             preprocessed_host_path,
             wasm_dev_stack_bytes,
+            code_gen_options.wasm_features,
             AssemblyBackendMode::Binary, // dummy value, unused in practice
+            debug,
         ),
         CodeGenBackend::Assembly(backend_mode) => gen_from_mono_module_dev(
             arena,
@@ -121,7 +149,9 @@ pub fn gen_from_mono_module<'a>(
             target,
             preprocessed_host_path,
             wasm_dev_stack_bytes,
+            code_gen_options.wasm_features,
             backend_mode,
+            debug,
         ),
         CodeGenBackend::Llvm(backend_mode) => gen_from_mono_module_llvm(
             arena,
@@ -133,10 +163,28 @@ pub fn gen_from_mono_module<'a>(
             debug,
             emit_llvm_ir,
             fuzz,
+            code_gen_options.opt_report,
+            code_gen_options.size_report.is_some(),
         ),
     }
 }
 
+pub(crate) fn mono_entry_point<'a>(entry_point: &EntryPoint<'a>) -> roc_mono::ir::EntryPoint<'a> {
+    match entry_point {
+        EntryPoint::Executable {
+            exposed_to_host,
+            platform_path: _,
+        } => {
+            // TODO support multiple of these!
+            debug_assert_eq!(exposed_to_host.len(), 1);
+            let (symbol, layout) = exposed_to_host[0];
+
+            roc_mono::ir::EntryPoint::Single(SingleEntryPoint { symbol, layout })
+        }
+        EntryPoint::Test => roc_mono::ir::EntryPoint::Expects { symbols: &[] },
+    }
+}
+
 // TODO how should imported modules factor into this? What if those use builtins too?
 // TODO this should probably use more helper functions
 // TODO make this polymorphic in the llvm functions so it can be reused for another backend.
@@ -151,6 +199,8 @@ fn gen_from_mono_module_llvm<'a>(
     emit_debug_info: bool,
     emit_llvm_ir: bool,
     fuzz: bool,
+    opt_report: bool,
+    size_report: bool,
 ) -> GenFromMono<'a> {
     use crate::target::{self, convert_opt_level};
     use inkwell::attributes::{Attribute, AttributeLoc};
@@ -199,6 +249,11 @@ fn gen_from_mono_module_llvm<'a>(
     let (dibuilder, compile_unit) = roc_gen_llvm::llvm::build::Env::new_debug_info(module);
     let (mpm, _fpm) = roc_gen_llvm::llvm::build::construct_optimization_passes(module, opt_level);
 
+    // The report walks the procs with the solutions alias analysis finds while building them.
+    let report_input = opt_report.then(|| loaded.procedures.clone());
+
+    let debug_sources = emit_debug_info.then(|| DebugSources::new(&loaded.sources));
+
     // Compile and add all the Procs before adding main
     let env = roc_gen_llvm::llvm::build::Env {
         arena,
@@ -217,27 +272,16 @@ fn gen_from_mono_module_llvm<'a>(
             .keys()
             .copied()
             .collect(),
+        debug_sources: debug_sources.as_ref(),
     };
 
     // does not add any externs for this mode (we have a host) but cleans up some functions around
     // expects that would confuse the surgical linker
     add_default_roc_externs(&env);
 
-    let entry_point = match loaded.entry_point {
-        EntryPoint::Executable {
-            exposed_to_host,
-            platform_path: _,
-        } => {
-            // TODO support multiple of these!
-            debug_assert_eq!(exposed_to_host.len(), 1);
-            let (symbol, layout) = exposed_to_host[0];
+    let entry_point = mono_entry_point(&loaded.entry_point);
 
-            roc_mono::ir::EntryPoint::Single(SingleEntryPoint { symbol, layout })
-        }
-        EntryPoint::Test => roc_mono::ir::EntryPoint::Expects { symbols: &[] },
-    };
-
-    roc_gen_llvm::llvm::build::build_procedures(
+    let (function_names, mod_solutions) = roc_gen_llvm::llvm::build::build_procedures(
         &env,
         &loaded.layout_interner,
         opt_level,
@@ -248,6 +292,17 @@ fn gen_from_mono_module_llvm<'a>(
         &loaded.glue_layouts,
     );
 
+    if let Some(procedures) = report_input {
+        let report_sources = crate::opt_report::ReportSources {
+            sources: &loaded.sources,
+            interns: &env.interns,
+        };
+        let report =
+            crate::opt_report::opt_report(&procedures, mod_solutions, report_sources, opt_level);
+
+        println!("{report}");
+    }
+
     // We are now finished building the LLVM IR.
     let generate_final_ir = all_code_gen_start.elapsed();
     let code_gen_object_start = Instant::now();
@@ -410,6 +465,12 @@ fn gen_from_mono_module_llvm<'a>(
     let code_gen_object = code_gen_object_start.elapsed();
     let total = all_code_gen_start.elapsed();
 
+    let proc_sizes = if size_report {
+        crate::size_report::object_proc_sizes(memory_buffer.as_slice(), function_names)
+    } else {
+        Vec::new()
+    };
+
     (
         CodeObject::MemoryBuffer(memory_buffer),
         CodeGenTiming {
@@ -422,17 +483,21 @@ fn gen_from_mono_module_llvm<'a>(
             layout_interner: loaded.layout_interner,
             expectations: loaded.expectations,
         },
+        proc_sizes,
     )
 }
 
 #[cfg(feature = "target-wasm32")]
+#[allow(clippy::too_many_arguments)]
 fn gen_from_mono_module_dev<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     target: Target,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
+    wasm_features: roc_gen_wasm::WasmFeatures,
     backend_mode: AssemblyBackendMode,
+    emit_debug_info: bool,
 ) -> GenFromMono<'a> {
     match target.architecture() {
         Architecture::Wasm32 => gen_from_mono_module_dev_wasm32(
@@ -440,26 +505,31 @@ fn gen_from_mono_module_dev<'a>(
             loaded,
             preprocessed_host_path,
             wasm_dev_stack_bytes,
+            wasm_features,
+            emit_debug_info,
         ),
         Architecture::X86_64 | Architecture::Aarch64 => {
-            gen_from_mono_module_dev_assembly(arena, loaded, target, backend_mode)
+            gen_from_mono_module_dev_assembly(arena, loaded, target, backend_mode, emit_debug_info)
         }
         _ => todo!(),
     }
 }
 
 #[cfg(not(feature = "target-wasm32"))]
+#[allow(clippy::too_many_arguments)]
 pub fn gen_from_mono_module_dev<'a>(
     arena: &'a bumpalo::Bump,
     loaded: MonomorphizedModule<'a>,
     target: Target,
     _host_input_path: &Path,
     _wasm_dev_stack_bytes: Option<u32>,
+    _wasm_features: roc_gen_wasm::WasmFeatures,
     backend_mode: AssemblyBackendMode,
+    emit_debug_info: bool,
 ) -> GenFromMono<'a> {
     match target.architecture() {
         Architecture::X86_64 | Architecture::Aarch64 => {
-            gen_from_mono_module_dev_assembly(arena, loaded, target, backend_mode)
+            gen_from_mono_module_dev_assembly(arena, loaded, target, backend_mode, emit_debug_info)
         }
         _ => todo!(),
     }
@@ -471,6 +541,8 @@ fn gen_from_mono_module_dev_wasm32<'a>(
     loaded: MonomorphizedModule<'a>,
     preprocessed_host_path: &Path,
     wasm_dev_stack_bytes: Option<u32>,
+    wasm_features: roc_gen_wasm::WasmFeatures,
+    emit_debug_info: bool,
 ) -> GenFromMono<'a> {
     let all_code_gen_start = Instant::now();
     let MonomorphizedModule {
@@ -478,6 +550,7 @@ fn gen_from_mono_module_dev_wasm32<'a>(
         procedures,
         mut interns,
         mut layout_interner,
+        sources,
         ..
     } = loaded;
 
@@ -493,6 +566,8 @@ fn gen_from_mono_module_dev_wasm32<'a>(
         module_id,
         exposed_to_host,
         stack_bytes: wasm_dev_stack_bytes.unwrap_or(roc_gen_wasm::Env::DEFAULT_STACK_BYTES),
+        features: wasm_features,
+        debug_sources: emit_debug_info.then(|| DebugSources::new(&sources)),
     };
 
     let host_bytes = std::fs::read(preprocessed_host_path).unwrap_or_else(|_| {
@@ -511,7 +586,7 @@ fn gen_from_mono_module_dev_wasm32<'a>(
         )
     });
 
-    let final_binary_bytes = roc_gen_wasm::build_app_binary(
+    let (final_binary_bytes, proc_sizes) = roc_gen_wasm::build_app_binary(
         &env,
         &mut layout_interner,
         &mut interns,
@@ -536,6 +611,7 @@ fn gen_from_mono_module_dev_wasm32<'a>(
             layout_interner,
             expectations: loaded.expectations,
         },
+        proc_sizes,
     )
 }
 
@@ -544,6 +620,7 @@ fn gen_from_mono_module_dev_assembly<'a>(
     loaded: MonomorphizedModule<'a>,
     target: Target,
     backend_mode: AssemblyBackendMode,
+    emit_debug_info: bool,
 ) -> GenFromMono<'a> {
     let all_code_gen_start = Instant::now();
 
@@ -555,18 +632,22 @@ fn gen_from_mono_module_dev_assembly<'a>(
         mut interns,
         exposed_to_host,
         mut layout_interner,
+        sources,
         ..
     } = loaded;
 
+    let debug_sources = emit_debug_info.then(|| DebugSources::new(&sources));
+
     let env = roc_gen_dev::Env {
         arena,
         module_id,
         exposed_to_host: exposed_to_host.top_level_values.keys().copied().collect(),
         lazy_literals,
         mode: backend_mode,
+        debug_sources,
     };
 
-    let module_object =
+    let (module_object, proc_sizes) =
         roc_gen_dev::build_module(&env, &mut interns, &mut layout_interner, target, procedures);
 
     let generate_final_ir = all_code_gen_start.elapsed();
@@ -591,6 +672,7 @@ fn gen_from_mono_module_dev_assembly<'a>(
             layout_interner,
             expectations: loaded.expectations,
         },
+        proc_sizes,
     )
 }
 
@@ -649,10 +731,12 @@ pub fn handle_error_module(
     total_time: std::time::Duration,
     filename: &OsStr,
     print_run_anyway_hint: bool,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
 ) -> std::io::Result<i32> {
     debug_assert!(module.total_problems() > 0);
 
-    let problems = report_problems_typechecked(&mut module);
+    let problems = report_problems_typechecked(&mut module, error_format, warning_config);
 
     problems.print_error_warning_count(total_time);
 
@@ -671,10 +755,13 @@ pub fn handle_error_module(
     Ok(problems.exit_code())
 }
 
-pub fn handle_loading_problem(problem: LoadingProblem) -> std::io::Result<i32> {
+pub fn handle_loading_problem(
+    problem: LoadingProblem,
+    error_format: ErrorFormat,
+) -> std::io::Result<i32> {
     match problem {
-        LoadingProblem::FormattedReport(report) => {
-            print!("{report}");
+        LoadingProblem::FormattedReport(report, diagnostic) => {
+            report_loading_problem(&report, diagnostic, error_format);
             Ok(1)
         }
         _ => {
@@ -724,6 +811,8 @@ pub fn build_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
     link_type: LinkType,
     linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
@@ -745,6 +834,8 @@ pub fn build_file<'a>(
         app_module_path,
         code_gen_options,
         emit_timings,
+        error_format,
+        warning_config,
         link_type,
         linking_strategy,
         prebuilt_requested,
@@ -762,6 +853,8 @@ fn build_loaded_file<'a>(
     app_module_path: PathBuf,
     code_gen_options: CodeGenOptions,
     emit_timings: bool,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
     link_type: LinkType,
     mut linking_strategy: LinkingStrategy,
     prebuilt_requested: bool,
@@ -782,7 +875,7 @@ fn build_loaded_file<'a>(
     if is_platform_prebuilt && linking_strategy == LinkingStrategy::Surgical {
         // Fallback to legacy linking if the preprocessed host file does not exist, but a legacy host does exist.
         let preprocessed_host_path =
-            platform_main_roc.with_file_name(roc_linker::preprocessed_host_filename(target));
+            platform_main_roc.with_file_name(preprocessed_host_filename(target, link_type));
         let legacy_host_path = legacy_host_file(target, &platform_main_roc);
         if !preprocessed_host_path.exists() && legacy_host_path.exists() {
             linking_strategy = LinkingStrategy::Legacy;
@@ -799,7 +892,7 @@ fn build_loaded_file<'a>(
             legacy_host_file(target, &platform_main_roc)
         }
     } else {
-        platform_main_roc.with_file_name(roc_linker::preprocessed_host_filename(target))
+        platform_main_roc.with_file_name(preprocessed_host_filename(target, link_type))
     };
 
     let output_exe_path = match out_path {
@@ -839,7 +932,10 @@ fn build_loaded_file<'a>(
     };
 
     // We don't need to spawn a rebuild thread when using a prebuilt host.
-    let rebuild_thread = if matches!(link_type, LinkType::Dylib | LinkType::None) {
+    // Only the surgical linker needs a host to build a shared library into.
+    let rebuild_thread = if link_type == LinkType::None
+        || (link_type == LinkType::Dylib && linking_strategy != LinkingStrategy::Surgical)
+    {
         None
     } else if is_platform_prebuilt {
         if !preprocessed_host_path.exists() {
@@ -869,6 +965,7 @@ fn build_loaded_file<'a>(
         let join_handle = spawn_rebuild_thread(
             code_gen_options.opt_level,
             linking_strategy,
+            link_type,
             platform_main_roc.clone(),
             preprocessed_host_path.clone(),
             output_exe_path.clone(),
@@ -907,7 +1004,7 @@ fn build_loaded_file<'a>(
     // This only needs to be mutable for report_problems. This can't be done
     // inside a nested scope without causing a borrow error!
     let mut loaded = loaded;
-    let problems = report_problems_monomorphized(&mut loaded);
+    let problems = report_problems_monomorphized(&mut loaded, error_format, warning_config);
     let loaded = loaded;
 
     enum HostRebuildTiming {
@@ -933,7 +1030,7 @@ fn build_loaded_file<'a>(
         None
     };
 
-    let (roc_app_bytes, code_gen_timing, expect_metadata) = gen_from_mono_module(
+    let (roc_app_bytes, code_gen_timing, expect_metadata, proc_sizes) = gen_from_mono_module(
         arena,
         loaded,
         &app_module_path,
@@ -957,6 +1054,23 @@ fn build_loaded_file<'a>(
     buf.push('\n');
     report_timing(buf, "Total", code_gen_timing.total);
 
+    if let Some(format) = code_gen_options.size_report {
+        let backend = match code_gen_options.backend {
+            CodeGenBackend::Assembly(_) => "dev",
+            CodeGenBackend::Llvm(_) => "llvm",
+            CodeGenBackend::Wasm => "wasm",
+        };
+        let report = crate::size_report::size_report(
+            backend,
+            &expect_metadata.interns,
+            &expect_metadata.layout_interner,
+            proc_sizes,
+            format,
+        );
+
+        println!("{report}");
+    }
+
     let compilation_end = compilation_start.elapsed();
     let size = roc_app_bytes.len();
 
@@ -987,6 +1101,7 @@ fn build_loaded_file<'a>(
         (LinkingStrategy::Surgical, _) => {
             roc_linker::link_preprocessed_host(
                 target,
+                link_type,
                 &platform_main_roc,
                 &roc_app_bytes,
                 &output_exe_path,
@@ -1100,6 +1215,7 @@ fn invalid_prebuilt_platform(prebuilt_requested: bool, preprocessed_host_path: P
 fn spawn_rebuild_thread(
     opt_level: OptLevel,
     linking_strategy: LinkingStrategy,
+    link_type: LinkType,
     platform_main_roc: PathBuf,
     preprocessed_host_path: PathBuf,
     output_exe_path: PathBuf,
@@ -1116,7 +1232,13 @@ fn spawn_rebuild_thread(
 
         match linking_strategy {
             LinkingStrategy::Additive => {
-                let host_dest = rebuild_host(opt_level, target, platform_main_roc.as_path(), None);
+                let host_dest = rebuild_host(
+                    opt_level,
+                    target,
+                    platform_main_roc.as_path(),
+                    None,
+                    link_type,
+                );
 
                 preprocess_host_wasm32(host_dest.as_path(), &preprocessed_host_path);
             }
@@ -1124,17 +1246,24 @@ fn spawn_rebuild_thread(
                 build_and_preprocess_host_lowlevel(
                     opt_level,
                     target,
+                    link_type,
                     platform_main_roc.as_path(),
                     preprocessed_host_path.as_path(),
                     &dll_stub_symbols,
                 );
 
-                // Copy preprocessed host to executable location.
+                // Copy preprocessed host to the output location.
                 // The surgical linker will modify that copy in-place.
                 std::fs::copy(&preprocessed_host_path, output_exe_path.as_path()).unwrap();
             }
             LinkingStrategy::Legacy => {
-                rebuild_host(opt_level, target, platform_main_roc.as_path(), None);
+                rebuild_host(
+                    opt_level,
+                    target,
+                    platform_main_roc.as_path(),
+                    None,
+                    link_type,
+                );
             }
         }
 
@@ -1154,6 +1283,7 @@ pub fn build_and_preprocess_host(
     build_and_preprocess_host_lowlevel(
         opt_level,
         target,
+        LinkType::Executable,
         platform_main_roc,
         preprocessed_host_path,
         &stub_dll_symbols,
@@ -1163,6 +1293,7 @@ pub fn build_and_preprocess_host(
 fn build_and_preprocess_host_lowlevel(
     opt_level: OptLevel,
     target: Target,
+    link_type: LinkType,
     platform_main_roc: &Path,
     preprocessed_host_path: &Path,
     stub_dll_symbols: &[String],
@@ -1172,10 +1303,17 @@ fn build_and_preprocess_host_lowlevel(
 
     debug_assert!(stub_lib.exists());
 
-    rebuild_host(opt_level, target, platform_main_roc, Some(&stub_lib));
+    rebuild_host(
+        opt_level,
+        target,
+        platform_main_roc,
+        Some(&stub_lib),
+        link_type,
+    );
 
     roc_linker::preprocess_host(
         target,
+        link_type,
         platform_main_roc,
         preprocessed_host_path,
         &stub_lib,
@@ -1188,6 +1326,9 @@ pub fn check_file<'a>(
     arena: &'a Bump,
     roc_file_path: PathBuf,
     emit_timings: bool,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
+    fix: bool,
     roc_cache_dir: RocCacheDir<'_>,
     threading: Threading,
 ) -> Result<(Problems, Duration), LoadingProblem<'a>> {
@@ -1199,18 +1340,22 @@ pub fn check_file<'a>(
 
     // Step 1: compile the app and generate the .o file
 
-    let load_config = LoadConfig {
+    let load_config = || LoadConfig {
         target,
         // TODO: we may not want this for just checking.
         function_kind: FunctionKind::LambdaSet,
-        // TODO: expose this from CLI?
-        render: RenderTarget::ColorTerminal,
+        render: error_format.render_target(),
         palette: DEFAULT_PALETTE,
         threading,
         exec_mode: ExecutionMode::Check,
     };
     let mut loaded =
-        roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config)?;
+        roc_load::load_and_typecheck(arena, roc_file_path.clone(), roc_cache_dir, load_config())?;
+
+    if fix && apply_fixes(&loaded, &roc_file_path, warning_config) > 0 {
+        // Check again, so that what gets reported matches the files on disk.
+        loaded = roc_load::load_and_typecheck(arena, roc_file_path, roc_cache_dir, load_config())?;
+    }
 
     let buf = &mut String::with_capacity(1024);
 
@@ -1254,7 +1399,128 @@ pub fn check_file<'a>(
         println!("Finished checking in {} ms\n", compilation_end.as_millis(),);
     }
 
-    Ok((report_problems_typechecked(&mut loaded), compilation_end))
+    Ok((
+        report_problems_typechecked(&mut loaded, error_format, warning_config),
+        compilation_end,
+    ))
+}
+
+/// Write the safe fixes for a checked module's problems back to disk, returning how many
+/// were applied. Only modules in the same directory as the app (or below it) are changed;
+/// packages live in the cache, and fixing those would be surprising.
+fn apply_fixes(
+    loaded: &LoadedModule,
+    roc_file_path: &Path,
+    warning_config: &WarningConfig,
+) -> usize {
+    let root_dir = project_dir(roc_file_path);
+    let mut applied = 0;
+
+    for (path, fixed_src, fixes) in fix_problems(
+        &loaded.sources,
+        &loaded.interns,
+        &loaded.can_problems,
+        warning_config,
+    ) {
+        if !is_in_dir(root_dir.as_deref(), &path) {
+            continue;
+        }
+
+        match std::fs::write(&path, fixed_src) {
+            Ok(()) => {
+                println!("Applied {fixes} fix(es) to {}", path.display());
+                applied += fixes;
+            }
+            Err(err) => {
+                eprintln!("Could not write fixes to {}: {err}", path.display());
+            }
+        }
+    }
+
+    applied
+}
+
+/// The directory of the app (or package) at this path. Its own modules are the ones in this
+/// directory or below it; everything else (like packages in the cache) belongs to someone else.
+fn project_dir(roc_file_path: &Path) -> Option<PathBuf> {
+    roc_file_path
+        .canonicalize()
+        .ok()
+        .and_then(|path| path.parent().map(Path::to_path_buf))
+}
+
+fn is_in_dir(dir: Option<&Path>, path: &Path) -> bool {
+    match (dir, path.canonicalize()) {
+        (Some(dir), Ok(path)) => path.starts_with(dir),
+        _ => false,
+    }
+}
+
+/// Check the app (or package) at this path like `roc check` does, and also run every lint
+/// rule over its own modules.
+pub fn lint_file<'a>(
+    arena: &'a Bump,
+    roc_file_path: PathBuf,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
+    roc_cache_dir: RocCacheDir<'_>,
+    threading: Threading,
+) -> Result<(Problems, Duration), LoadingProblem<'a>> {
+    let lint_start = Instant::now();
+
+    let load_config = LoadConfig {
+        target: Target::LinuxX64,
+        function_kind: FunctionKind::LambdaSet,
+        render: error_format.render_target(),
+        palette: DEFAULT_PALETTE,
+        threading,
+        exec_mode: ExecutionMode::Check,
+    };
+    let mut loaded =
+        roc_load::load_and_typecheck(arena, roc_file_path.clone(), roc_cache_dir, load_config)?;
+
+    let root_dir = project_dir(&roc_file_path);
+    // Only an app (or platform) provides anything to a host; everything else is a library.
+    let is_library = loaded.exposed_to_host.is_empty();
+
+    let root_module = loaded
+        .declarations_by_id
+        .get(&loaded.module_id)
+        .map(|decls| (loaded.module_id, decls, loaded.solved.inner()));
+    let other_modules = loaded
+        .typechecked
+        .iter()
+        .map(|(module_id, checked)| (*module_id, &checked.decls, checked.solved_subs.inner()));
+
+    let rules = rules(warning_config.lint_settings());
+    let mut lints = MutMap::default();
+
+    for (module_id, decls, subs) in root_module.into_iter().chain(other_modules) {
+        let src = match loaded.sources.get(&module_id) {
+            Some((path, src)) if is_in_dir(root_dir.as_deref(), path) => src,
+            _ => continue,
+        };
+        let ctx = LintContext {
+            interns: &loaded.interns,
+            subs,
+            is_library,
+            src,
+        };
+
+        lints.insert(module_id, lint_module(&ctx, decls, &rules));
+    }
+
+    let problems = report_problems_and_lints(
+        &loaded.sources,
+        &loaded.interns,
+        &mut loaded.can_problems,
+        &mut loaded.type_problems,
+        &mut lints,
+        error_format,
+        warning_config,
+    );
+
+    Ok((problems, lint_start.elapsed()))
 }
 
 pub fn build_str_test<'a>(
@@ -1271,6 +1537,9 @@ pub fn build_str_test<'a>(
         emit_debug_info: false,
         emit_llvm_ir: false,
         fuzz: false,
+        opt_report: false,
+        size_report: None,
+        wasm_features: Default::default(),
     };
 
     let emit_timings = false;
@@ -1303,6 +1572,8 @@ pub fn build_str_test<'a>(
         app_module_path.to_path_buf(),
         code_gen_options,
         emit_timings,
+        ErrorFormat::Human,
+        &WarningConfig::default(),
         link_type,
         linking_strategy,
         assume_prebuild,
@@ -1324,6 +1595,16 @@ fn with_output_extension(
             // Additive linking and no linking both output the object file type.
             path.with_extension(target.object_file_ext())
         }
+        (LinkingStrategy::Surgical, LinkType::Dylib) => {
+            path.with_extension(target.dynamic_library_file_ext())
+        }
         _ => path.with_extension(target.executable_file_ext().unwrap_or_default()),
     }
 }
+
+fn preprocessed_host_filename(target: Target, link_type: LinkType) -> String {
+    match link_type {
+        LinkType::Dylib => roc_linker::preprocessed_dylib_host_filename(target),
+        _ => roc_linker::preprocessed_host_filename(target),
+    }
+}
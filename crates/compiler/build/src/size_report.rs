@@ -0,0 +1,460 @@
+//! The report printed by `roc build --size-report`: every specialization mono made of every
+//! function, the layout it was specialized to, and how many bytes of code the backend emitted for
+//! it, along with totals per source function and per module.
+//!
+//! Sizes are measured in the app's object (or wasm module) before it is linked with the host.
+//! A specialization without a size has no code of its own: it was inlined into its callers or
+//! eliminated as dead code.
+use object::{BinaryFormat, Object, ObjectSection, ObjectSymbol, SectionIndex, SymbolKind};
+use roc_collections::all::MutMap;
+use roc_gen_llvm::llvm::build::ProcFunctionNames;
+use roc_module::ident::ModuleName;
+use roc_module::symbol::{Interns, Symbol};
+use roc_mono::ir::{ProcLayout, ProcSizes};
+use roc_mono::layout::{LayoutInterner, STLayoutInterner};
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SizeReportFormat {
+    Table,
+    Json,
+}
+
+/// Look up the functions LLVM generated for each specialization in the object file it wrote.
+/// Alias analysis can split one specialization into several functions; their sizes are added up.
+pub fn object_proc_sizes<'a>(
+    object_bytes: &[u8],
+    function_names: ProcFunctionNames<'a>,
+) -> ProcSizes<'a> {
+    let symbol_sizes = match object::File::parse(object_bytes) {
+        Ok(object) => text_symbol_sizes(&object),
+        // e.g. the LLVM wasm32 backend emits bitcode, which has no machine code to measure
+        Err(_) => MutMap::default(),
+    };
+
+    sum_function_sizes(&symbol_sizes, function_names)
+}
+
+/// Add up the sizes of the functions generated for each specialization, keeping the order in
+/// which the specializations first appear.
+fn sum_function_sizes<'a>(
+    symbol_sizes: &MutMap<&str, u64>,
+    function_names: ProcFunctionNames<'a>,
+) -> ProcSizes<'a> {
+    let mut indices: MutMap<(Symbol, ProcLayout<'a>), usize> = MutMap::default();
+    let mut sizes: ProcSizes<'a> = Vec::with_capacity(function_names.len());
+
+    for (symbol, layout, name) in function_names {
+        let index = *indices.entry((symbol, layout)).or_insert_with(|| {
+            sizes.push((symbol, layout, None));
+            sizes.len() - 1
+        });
+
+        if let Some(size) = symbol_sizes.get(name.as_str()) {
+            let total = &mut sizes[index].2;
+            *total = Some(total.unwrap_or(0) + size);
+        }
+    }
+
+    sizes
+}
+
+fn text_symbol_sizes<'data>(object: &object::File<'data>) -> MutMap<&'data str, u64> {
+    let is_function = |symbol: &object::Symbol| {
+        symbol.is_definition()
+            && symbol.kind() == SymbolKind::Text
+            && symbol.section_index().is_some()
+    };
+
+    // Mach-O symbols have no size, so there we use the distance to whatever comes next
+    let mut starts: MutMap<SectionIndex, Vec<u64>> = MutMap::default();
+    for symbol in object.symbols().filter(is_function) {
+        let section_index = symbol.section_index().unwrap();
+        starts
+            .entry(section_index)
+            .or_insert_with(|| {
+                let section = object.section_by_index(section_index).unwrap();
+                vec![section.address() + section.size()]
+            })
+            .push(symbol.address());
+    }
+    for addresses in starts.values_mut() {
+        addresses.sort_unstable();
+    }
+
+    let mut sizes = MutMap::default();
+    for symbol in object.symbols().filter(is_function) {
+        let Ok(mut name) = symbol.name() else {
+            continue;
+        };
+        if object.format() == BinaryFormat::MachO {
+            name = name.strip_prefix('_').unwrap_or(name);
+        }
+
+        let size = match symbol.size() {
+            0 => {
+                let addresses = &starts[&symbol.section_index().unwrap()];
+                let next = addresses.partition_point(|address| *address <= symbol.address());
+
+                addresses.get(next).map_or(0, |end| end - symbol.address())
+            }
+            size => size,
+        };
+
+        sizes.insert(name, size);
+    }
+
+    sizes
+}
+
+struct Specialization {
+    function: String,
+    module: String,
+    layout: String,
+    bytes: Option<u64>,
+}
+
+#[derive(Default)]
+struct Total {
+    functions: usize,
+    specializations: usize,
+    bytes: u64,
+}
+
+pub fn size_report(
+    backend: &str,
+    interns: &Interns,
+    layout_interner: &STLayoutInterner<'_>,
+    sizes: ProcSizes<'_>,
+    format: SizeReportFormat,
+) -> String {
+    let specializations: Vec<Specialization> = sizes
+        .into_iter()
+        .map(|(symbol, proc_layout, bytes)| {
+            let module = match symbol.module_string(interns).as_str() {
+                "" | ModuleName::APP => "app".to_string(),
+                module => module.to_string(),
+            };
+
+            Specialization {
+                function: format!("{module}.{}", symbol.as_str(interns)),
+                module,
+                layout: layout_string(layout_interner, proc_layout),
+                bytes,
+            }
+        })
+        .collect();
+
+    render_size_report(backend, specializations, format)
+}
+
+fn render_size_report(
+    backend: &str,
+    mut specializations: Vec<Specialization>,
+    format: SizeReportFormat,
+) -> String {
+    // biggest first; specializations without code of their own go last
+    specializations
+        .sort_by(|a, b| (b.bytes, &a.function, &a.layout).cmp(&(a.bytes, &b.function, &b.layout)));
+
+    let mut functions: MutMap<&str, Total> = MutMap::default();
+    let mut modules: MutMap<&str, Total> = MutMap::default();
+
+    for spec in specializations.iter() {
+        let function = functions.entry(&spec.function).or_default();
+        function.specializations += 1;
+        function.bytes += spec.bytes.unwrap_or(0);
+
+        let module = modules.entry(&spec.module).or_default();
+        module.functions += (function.specializations == 1) as usize;
+        module.specializations += 1;
+        module.bytes += spec.bytes.unwrap_or(0);
+    }
+
+    let mut functions: Vec<_> = functions.into_iter().collect();
+    functions.sort_by(|(a_name, a), (b_name, b)| {
+        (b.specializations, b.bytes, a_name).cmp(&(a.specializations, a.bytes, b_name))
+    });
+
+    let mut modules: Vec<_> = modules.into_iter().collect();
+    modules.sort_by(|(a_name, a), (b_name, b)| (b.bytes, a_name).cmp(&(a.bytes, b_name)));
+
+    match format {
+        SizeReportFormat::Table => render_table(backend, &specializations, &functions, &modules),
+        SizeReportFormat::Json => {
+            render_json(backend, &specializations, &functions, &modules).to_string()
+        }
+    }
+}
+
+fn layout_string(layout_interner: &STLayoutInterner<'_>, proc_layout: ProcLayout<'_>) -> String {
+    let arguments: Vec<String> = proc_layout
+        .arguments
+        .iter()
+        .map(|argument| layout_interner.dbg(*argument))
+        .collect();
+    let layout = format!(
+        "({}) -> {}",
+        arguments.join(", "),
+        layout_interner.dbg(proc_layout.result)
+    );
+
+    // the pretty printer breaks long layouts over several lines
+    layout.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn bytes_cell(bytes: Option<u64>) -> String {
+    bytes.map_or_else(|| "-".to_string(), |bytes| bytes.to_string())
+}
+
+fn render_table(
+    backend: &str,
+    specializations: &[Specialization],
+    functions: &[(&str, Total)],
+    modules: &[(&str, Total)],
+) -> String {
+    let mut buf = format!("Size report ({backend} backend)\n");
+
+    buf.push_str(
+        "\nBytes of code emitted for the app before linking. A specialization without a size was inlined into its callers or eliminated.\n",
+    );
+
+    let total: u64 = modules.iter().map(|(_, total)| total.bytes).sum();
+    let function_width = specializations
+        .iter()
+        .map(|spec| spec.function.len())
+        .chain(["function".len()])
+        .max()
+        .unwrap();
+
+    write!(
+        buf,
+        "\n{} specializations of {} functions, {total} bytes\n",
+        specializations.len(),
+        functions.len()
+    )
+    .unwrap();
+
+    buf.push_str("\nSpecializations:\n\n");
+    writeln!(
+        buf,
+        "    {:>8}  {:function_width$}  layout",
+        "bytes", "function"
+    )
+    .unwrap();
+    for spec in specializations {
+        writeln!(
+            buf,
+            "    {:>8}  {:function_width$}  {}",
+            bytes_cell(spec.bytes),
+            spec.function,
+            spec.layout
+        )
+        .unwrap();
+    }
+
+    buf.push_str("\nBy function:\n\n");
+    writeln!(
+        buf,
+        "    {:>15}  {:>8}  function",
+        "specializations", "bytes"
+    )
+    .unwrap();
+    for (function, total) in functions {
+        writeln!(
+            buf,
+            "    {:>15}  {:>8}  {function}",
+            total.specializations, total.bytes
+        )
+        .unwrap();
+    }
+
+    buf.push_str("\nBy module:\n\n");
+    writeln!(
+        buf,
+        "    {:>9}  {:>15}  {:>8}  module",
+        "functions", "specializations", "bytes"
+    )
+    .unwrap();
+    for (module, total) in modules {
+        writeln!(
+            buf,
+            "    {:>9}  {:>15}  {:>8}  {module}",
+            total.functions, total.specializations, total.bytes
+        )
+        .unwrap();
+    }
+
+    buf
+}
+
+fn render_json(
+    backend: &str,
+    specializations: &[Specialization],
+    functions: &[(&str, Total)],
+    modules: &[(&str, Total)],
+) -> serde_json::Value {
+    use serde_json::json;
+
+    json!({
+        "backend": backend,
+        "specializations": specializations
+            .iter()
+            .map(|spec| json!({
+                "function": spec.function,
+                "module": spec.module,
+                "layout": spec.layout,
+                "bytes": spec.bytes,
+            }))
+            .collect::<Vec<_>>(),
+        "functions": functions
+            .iter()
+            .map(|(function, total)| json!({
+                "function": function,
+                "specializations": total.specializations,
+                "bytes": total.bytes,
+            }))
+            .collect::<Vec<_>>(),
+        "modules": modules
+            .iter()
+            .map(|(module, total)| json!({
+                "module": module,
+                "functions": total.functions,
+                "specializations": total.specializations,
+                "bytes": total.bytes,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+    use roc_mono::layout::{Layout, Niche};
+    use serde_json::json;
+
+    fn spec(module: &str, function: &str, layout: &str, bytes: Option<u64>) -> Specialization {
+        Specialization {
+            function: format!("{module}.{function}"),
+            module: module.to_string(),
+            layout: layout.to_string(),
+            bytes,
+        }
+    }
+
+    fn specializations() -> Vec<Specialization> {
+        vec![
+            spec("app", "helper", "(I64) -> I64", None),
+            spec("app", "double", "(U8) -> U8", Some(24)),
+            spec("app", "main", "() -> I64", Some(40)),
+            spec("List", "map", "(List I64, {}) -> List I64", Some(120)),
+            spec("app", "double", "(I64) -> I64", Some(24)),
+        ]
+    }
+
+    #[test]
+    fn split_specializations_are_added_up() {
+        let u64_layout = ProcLayout {
+            arguments: &[Layout::U64],
+            result: Layout::U64,
+            niche: Niche::NONE,
+        };
+        let u8_layout = ProcLayout {
+            arguments: &[Layout::U8],
+            result: Layout::U8,
+            niche: Niche::NONE,
+        };
+        let symbol_sizes: MutMap<&str, u64> =
+            [("mul_u64", 24), ("mul_u64_split", 8), ("mul_u8", 16)]
+                .into_iter()
+                .collect();
+        let function_names = vec![
+            (Symbol::NUM_MUL, u64_layout, "mul_u64".to_string()),
+            (Symbol::NUM_MUL, u8_layout, "mul_u8".to_string()),
+            (Symbol::NUM_MUL, u64_layout, "mul_u64_split".to_string()),
+            (Symbol::LIST_MAP, u64_layout, "inlined".to_string()),
+        ];
+
+        assert_eq!(
+            sum_function_sizes(&symbol_sizes, function_names),
+            vec![
+                (Symbol::NUM_MUL, u64_layout, Some(32)),
+                (Symbol::NUM_MUL, u8_layout, Some(16)),
+                (Symbol::LIST_MAP, u64_layout, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn table() {
+        let actual = render_size_report("dev", specializations(), SizeReportFormat::Table);
+
+        assert_eq!(
+            actual,
+            indoc!(
+                r#"
+                Size report (dev backend)
+
+                Bytes of code emitted for the app before linking. A specialization without a size was inlined into its callers or eliminated.
+
+                5 specializations of 4 functions, 208 bytes
+
+                Specializations:
+
+                       bytes  function    layout
+                         120  List.map    (List I64, {}) -> List I64
+                          40  app.main    () -> I64
+                          24  app.double  (I64) -> I64
+                          24  app.double  (U8) -> U8
+                           -  app.helper  (I64) -> I64
+
+                By function:
+
+                    specializations     bytes  function
+                                  2        48  app.double
+                                  1       120  List.map
+                                  1        40  app.main
+                                  1         0  app.helper
+
+                By module:
+
+                    functions  specializations     bytes  module
+                            1                1       120  List
+                            3                4        88  app
+                "#
+            )
+        );
+    }
+
+    #[test]
+    fn json() {
+        let actual = render_size_report("dev", specializations(), SizeReportFormat::Json);
+        let actual: serde_json::Value = serde_json::from_str(&actual).unwrap();
+
+        assert_eq!(
+            actual,
+            json!({
+                "backend": "dev",
+                "specializations": [
+                    { "function": "List.map", "module": "List", "layout": "(List I64, {}) -> List I64", "bytes": 120 },
+                    { "function": "app.main", "module": "app", "layout": "() -> I64", "bytes": 40 },
+                    { "function": "app.double", "module": "app", "layout": "(I64) -> I64", "bytes": 24 },
+                    { "function": "app.double", "module": "app", "layout": "(U8) -> U8", "bytes": 24 },
+                    { "function": "app.helper", "module": "app", "layout": "(I64) -> I64", "bytes": null },
+                ],
+                "functions": [
+                    { "function": "app.double", "specializations": 2, "bytes": 48 },
+                    { "function": "List.map", "specializations": 1, "bytes": 120 },
+                    { "function": "app.main", "specializations": 1, "bytes": 40 },
+                    { "function": "app.helper", "specializations": 1, "bytes": 0 },
+                ],
+                "modules": [
+                    { "module": "List", "functions": 1, "specializations": 1, "bytes": 120 },
+                    { "module": "app", "functions": 3, "specializations": 4, "bytes": 88 },
+                ],
+            })
+        );
+    }
+}
@@ -96,6 +96,10 @@ const FLOATS = [_]type{ f32, f64 };
 const NUMBERS = INTEGERS ++ FLOATS;
 
 comptime {
+    exportNumFn(num.shiftLeftI128, "shift_left.i128");
+    exportNumFn(num.shiftLeftU128, "shift_left.u128");
+    exportNumFn(num.shiftRightI128, "shift_right.i128");
+    exportNumFn(num.shiftRightU128, "shift_right.u128");
     exportNumFn(num.shiftRightZeroFillI128, "shift_right_zero_fill.i128");
     exportNumFn(num.shiftRightZeroFillU128, "shift_right_zero_fill.u128");
 
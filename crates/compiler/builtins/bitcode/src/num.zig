@@ -534,14 +534,39 @@ pub fn exportMulWrappedInt(comptime T: type, comptime name: []const u8) void {
     @export(f, .{ .name = name ++ @typeName(T), .linkage = .Strong });
 }
 
-pub fn shiftRightZeroFillI128(self: i128, other: u8) callconv(.C) i128 {
+pub fn shiftLeftI128(self: i128, other: u8) callconv(.C) i128 {
     if (other & 0b1000_0000 > 0) {
         return 0;
+    } else {
+        return self << @as(u7, @intCast(other));
+    }
+}
+
+pub fn shiftLeftU128(self: u128, other: u8) callconv(.C) u128 {
+    if (other & 0b1000_0000 > 0) {
+        return 0;
+    } else {
+        return self << @as(u7, @intCast(other));
+    }
+}
+
+pub fn shiftRightI128(self: i128, other: u8) callconv(.C) i128 {
+    if (other & 0b1000_0000 > 0) {
+        return if (self < 0) -1 else 0;
     } else {
         return self >> @as(u7, @intCast(other));
     }
 }
 
+// like for the other integer widths, shifting right extends the sign bit
+pub fn shiftRightU128(self: u128, other: u8) callconv(.C) u128 {
+    return @as(u128, @bitCast(shiftRightI128(@as(i128, @bitCast(self)), other)));
+}
+
+pub fn shiftRightZeroFillI128(self: i128, other: u8) callconv(.C) i128 {
+    return @as(i128, @bitCast(shiftRightZeroFillU128(@as(u128, @bitCast(self)), other)));
+}
+
 pub fn shiftRightZeroFillU128(self: u128, other: u8) callconv(.C) u128 {
     if (other & 0b1000_0000 > 0) {
         return 0;
@@ -318,6 +318,8 @@ pub const NUM_MUL_CHECKED_FLOAT: IntrinsicName =
 
 pub const NUM_IS_MULTIPLE_OF: IntrinsicName = int_intrinsic!("roc_builtins.num.is_multiple_of");
 
+pub const NUM_SHIFT_LEFT: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_left");
+pub const NUM_SHIFT_RIGHT: IntrinsicName = int_intrinsic!("roc_builtins.num.shift_right");
 pub const NUM_SHIFT_RIGHT_ZERO_FILL: IntrinsicName =
     int_intrinsic!("roc_builtins.num.shift_right_zero_fill");
 
@@ -418,6 +420,7 @@ pub const DEC_SUB_OR_PANIC: &str = "roc_builtins.dec.sub_or_panic";
 pub const DEC_SUB_SATURATED: &str = "roc_builtins.dec.sub_saturated";
 pub const DEC_SUB_WITH_OVERFLOW: &str = "roc_builtins.dec.sub_with_overflow";
 pub const DEC_TAN: &str = "roc_builtins.dec.tan";
+pub const DEC_TO_F64: &str = "roc_builtins.dec.to_f64";
 pub const DEC_TO_I128: &str = "roc_builtins.dec.to_i128";
 pub const DEC_FROM_I128: &str = "roc_builtins.dec.from_i128";
 pub const DEC_TO_STR: &str = "roc_builtins.dec.to_str";
@@ -296,6 +296,7 @@ pub fn desugar_expr<'a>(
         | AccessorFunction(_)
         | Var { .. }
         | Underscore { .. }
+        | Malformed(_)
         | MalformedIdent(_, _)
         | MalformedClosure
         | MalformedSuffixed(..)
@@ -1327,6 +1327,11 @@ pub fn canonicalize_expr<'a>(
                 Output::default(),
             )
         }
+        ast::Expr::Malformed(_) => {
+            // The syntax error was already reported when the module was parsed
+            use roc_problem::can::RuntimeError::*;
+            (RuntimeError(MalformedExpr(region)), Output::default())
+        }
         ast::Expr::MalformedClosure => {
             use roc_problem::can::RuntimeError::*;
             (RuntimeError(MalformedClosure(region)), Output::default())
@@ -1485,7 +1490,9 @@ fn canonicalize_closure_body<'a>(
     }
 
     let bound_by_argument_patterns: Vec<_> =
-        BindingsFromPattern::new_many(can_args.iter().map(|x| &x.2)).collect();
+        BindingsFromPattern::new_many(can_args.iter().map(|x| &x.2))
+            .with_kinds()
+            .collect();
 
     let (loc_body_expr, new_output) = canonicalize_expr(
         env,
@@ -1503,7 +1510,7 @@ fn canonicalize_closure_body<'a>(
         .filter(|s| *s != symbol)
         // symbols bound either in this pattern or deeper down are not captured!
         .filter(|s| !new_output.references.bound_symbols().any(|x| x == s))
-        .filter(|s| bound_by_argument_patterns.iter().all(|(k, _)| s != k))
+        .filter(|s| bound_by_argument_patterns.iter().all(|(k, _, _)| s != k))
         // filter out top-level symbols those will be globally available, and don't need to be captured
         .filter(|s| !env.top_level_symbols.contains(s))
         // filter out imported symbols those will be globally available, and don't need to be captured
@@ -1518,13 +1525,14 @@ fn canonicalize_closure_body<'a>(
 
     // Now that we've collected all the references, check to see if any of the args we defined
     // went unreferenced. If any did, report them as unused arguments.
-    for (sub_symbol, region) in bound_by_argument_patterns {
+    for (sub_symbol, region, kind) in bound_by_argument_patterns {
         if !output.references.has_value_lookup(sub_symbol) {
             // The body never referenced this argument we declared. It's an unused argument!
             env.problem(Problem::UnusedArgument(
                 symbol,
                 is_anonymous,
                 sub_symbol,
+                kind,
                 region,
             ));
         } else {
@@ -1690,11 +1698,12 @@ fn canonicalize_when_branch<'a>(
     // Now that we've collected all the references for this branch, check to see if
     // any of the new idents it defined were unused. If any were, report it.
     let mut pattern_bound_symbols_body_needs = VecSet::default();
-    for (symbol, region) in BindingsFromPattern::new_many(patterns.iter().map(|pat| &pat.pattern)) {
+    let bindings = BindingsFromPattern::new_many(patterns.iter().map(|pat| &pat.pattern));
+    for (symbol, region, kind) in bindings.with_kinds() {
         if output.references.has_value_lookup(symbol) {
             pattern_bound_symbols_body_needs.insert(symbol);
         } else {
-            env.problem(Problem::UnusedBranchDef(symbol, region));
+            env.problem(Problem::UnusedBranchDef(symbol, kind, region));
         }
     }
 
@@ -2382,6 +2391,7 @@ pub fn is_valid_interpolation(expr: &ast::Expr<'_>) -> bool {
         | ast::Expr::MalformedIdent(_, _)
         | ast::Expr::Tag(_)
         | ast::Expr::OpaqueRef(_)
+        | ast::Expr::Malformed(_)
         | ast::Expr::MalformedClosure => true,
         // Newlines are disallowed inside interpolation, and these all require newlines
         ast::Expr::Dbg(_, _)
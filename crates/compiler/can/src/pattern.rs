@@ -11,7 +11,7 @@ use roc_module::ident::{Ident, Lowercase, TagName};
 use roc_module::symbol::Symbol;
 use roc_parse::ast::{self, StrLiteral, StrSegment};
 use roc_parse::pattern::PatternType;
-use roc_problem::can::{MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
+use roc_problem::can::{BindingKind, MalformedPatternProblem, Problem, RuntimeError, ShadowKind};
 use roc_region::all::{Loc, Region};
 use roc_types::num::SingleQuoteBound;
 use roc_types::subs::{VarStore, Variable};
@@ -946,7 +946,38 @@ impl<'a> BindingsFromPattern<'a> {
         }
     }
 
-    fn next_many(stack: &mut Vec<BindingsFromPatternWork<'a>>) -> Option<(Symbol, Region)> {
+    /// Also tells how each name is bound.
+    pub fn with_kinds(mut self) -> impl Iterator<Item = (Symbol, Region, BindingKind)> + 'a {
+        std::iter::from_fn(move || self.next_binding())
+    }
+
+    fn next_binding(&mut self) -> Option<(Symbol, Region, BindingKind)> {
+        use Pattern::*;
+
+        match self {
+            BindingsFromPattern::Empty => None,
+            BindingsFromPattern::One(loc_pattern) => match &loc_pattern.value {
+                Identifier(symbol)
+                | AbilityMemberSpecialization {
+                    ident: symbol,
+                    specializes: _,
+                } => {
+                    let region = loc_pattern.region;
+                    *self = Self::Empty;
+                    Some((*symbol, region, BindingKind::Variable))
+                }
+                _ => {
+                    *self = Self::Many(vec![BindingsFromPatternWork::Pattern(loc_pattern)]);
+                    self.next_binding()
+                }
+            },
+            BindingsFromPattern::Many(stack) => Self::next_many(stack),
+        }
+    }
+
+    fn next_many(
+        stack: &mut Vec<BindingsFromPatternWork<'a>>,
+    ) -> Option<(Symbol, Region, BindingKind)> {
         use Pattern::*;
 
         while let Some(work) = stack.pop() {
@@ -960,11 +991,11 @@ impl<'a> BindingsFromPattern<'a> {
                             ident: symbol,
                             specializes: _,
                         } => {
-                            return Some((*symbol, loc_pattern.region));
+                            return Some((*symbol, loc_pattern.region, BindingKind::Variable));
                         }
                         As(pattern, symbol) => {
                             stack.push(Pattern(pattern));
-                            return Some((*symbol, loc_pattern.region));
+                            return Some((*symbol, loc_pattern.region, BindingKind::Variable));
                         }
                         AppliedTag {
                             arguments: loc_args,
@@ -1003,7 +1034,11 @@ impl<'a> BindingsFromPattern<'a> {
                 BindingsFromPatternWork::RecordDestruct(loc_destruct) => {
                     match &loc_destruct.value.typ {
                         DestructType::Required | DestructType::Optional(_, _) => {
-                            return Some((loc_destruct.value.symbol, loc_destruct.region));
+                            return Some((
+                                loc_destruct.value.symbol,
+                                loc_destruct.region,
+                                BindingKind::RecordField,
+                            ));
                         }
                         DestructType::Guard(_, inner) => {
                             // a guard does not introduce the symbol
@@ -1026,27 +1061,8 @@ impl<'a> Iterator for BindingsFromPattern<'a> {
     type Item = (Symbol, Region);
 
     fn next(&mut self) -> Option<Self::Item> {
-        use Pattern::*;
-
-        match self {
-            BindingsFromPattern::Empty => None,
-            BindingsFromPattern::One(loc_pattern) => match &loc_pattern.value {
-                Identifier(symbol)
-                | AbilityMemberSpecialization {
-                    ident: symbol,
-                    specializes: _,
-                } => {
-                    let region = loc_pattern.region;
-                    *self = Self::Empty;
-                    Some((*symbol, region))
-                }
-                _ => {
-                    *self = Self::Many(vec![BindingsFromPatternWork::Pattern(loc_pattern)]);
-                    self.next()
-                }
-            },
-            BindingsFromPattern::Many(stack) => Self::next_many(stack),
-        }
+        self.next_binding()
+            .map(|(symbol, region, _)| (symbol, region))
     }
 }
 
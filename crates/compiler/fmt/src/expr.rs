@@ -32,6 +32,7 @@ impl<'a> Formattable for Expr<'a> {
             }
 
             MalformedSuffixed(loc_expr) => loc_expr.is_multiline(),
+            Malformed(text) => text.contains('\n'),
 
             // These expressions never have newlines
             Float(..)
@@ -525,6 +526,23 @@ impl<'a> Formattable for Expr<'a> {
                 buf.indent(indent);
                 loc_expr.format_with_options(buf, parens, newlines, indent);
             }
+            Malformed(text) => {
+                // Code that didn't parse is kept exactly as it was written.
+                for (index, line) in text.lines().enumerate() {
+                    if index == 0 {
+                        buf.indent(indent);
+                    } else {
+                        buf.newline();
+                        buf.indent(0);
+                    }
+
+                    let line = line.trim_end();
+
+                    if !line.is_empty() {
+                        buf.push_str_allow_spaces(line);
+                    }
+                }
+            }
             MalformedClosure => {}
             PrecedenceConflict { .. } => {}
             MultipleRecordBuilders { .. } => {}
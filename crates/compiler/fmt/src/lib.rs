@@ -8,7 +8,10 @@ pub mod def;
 pub mod expr;
 pub mod module;
 pub mod pattern;
+pub mod range;
 pub mod spaces;
+pub mod upgrade;
+pub mod width;
 
 use bumpalo::{collections::String, Bump};
 use roc_parse::ast::Module;
@@ -0,0 +1,71 @@
+//! Formatting only part of a module, e.g. the selection in an editor.
+//!
+//! The whole module is always formatted, so that every def is formatted exactly the way
+//! `roc format` would do it. The result is then narrowed down to edits for the top-level
+//! defs that overlap the range, leaving the rest of the module as it was.
+use bumpalo::Bump;
+use roc_parse::ast::Defs;
+use roc_parse::module::{parse_header, parse_module_defs};
+use roc_parse::parser::SyntaxError;
+use roc_parse::state::State;
+use roc_region::all::Region;
+
+/// Replace `region` of the original source with `text`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeEdit<'a> {
+    pub region: Region,
+    pub text: &'a str,
+}
+
+/// The edits that turn the top-level defs of `src` that overlap `range` into their
+/// counterparts in `formatted`, which must be the result of formatting all of `src`.
+///
+/// An empty range (like a cursor) selects the def it is in. Defs that formatting leaves
+/// unchanged get no edit.
+pub fn range_edits<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    formatted: &'a str,
+    range: Region,
+) -> Result<Vec<RangeEdit<'a>>, SyntaxError<'a>> {
+    let defs = parse_defs(arena, src)?;
+    let formatted_defs = parse_defs(arena, formatted)?;
+
+    // Formatting moves the imports of an old-style header into defs of their own, so the
+    // formatted module can have extra defs at the front. Everything else lines up.
+    let Some(skipped) = formatted_defs.len().checked_sub(defs.len()) else {
+        return Ok(Vec::new());
+    };
+
+    let mut edits = Vec::new();
+
+    for (region, formatted_region) in defs.regions.iter().zip(&formatted_defs.regions[skipped..]) {
+        if region.start() > range.end() || range.start() > region.end() {
+            continue;
+        }
+
+        let original = slice(src, *region);
+        let text = slice(formatted, *formatted_region);
+
+        if original != text {
+            edits.push(RangeEdit {
+                region: *region,
+                text,
+            });
+        }
+    }
+
+    Ok(edits)
+}
+
+/// The module's defs, without turning the imports of an old-style header into defs.
+fn parse_defs<'a>(arena: &'a Bump, src: &'a str) -> Result<Defs<'a>, SyntaxError<'a>> {
+    let (_, state) = parse_header(arena, State::new(src.as_bytes()))
+        .map_err(|e| SyntaxError::Header(e.problem))?;
+
+    parse_module_defs(arena, state, Defs::default())
+}
+
+fn slice(src: &str, region: Region) -> &str {
+    &src[region.start().offset as usize..region.end().offset as usize]
+}
@@ -217,6 +217,20 @@ pub trait RemoveSpaces<'a> {
     fn remove_spaces(&self, arena: &'a Bump) -> Self;
 }
 
+/// Whether two trees are the same once their spaces and comments are removed. Formatting
+/// must never change a tree in any other way.
+pub fn same_without_spaces<'a, T>(arena: &'a Bump, before: &T, after: &T) -> bool
+where
+    T: RemoveSpaces<'a> + std::fmt::Debug,
+{
+    // HACK!
+    // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
+    // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
+    // I don't have the patience to debug this right now, so let's leave it for another day...
+    // TODO: fix PartialEq impl on ast types
+    format!("{:?}", before.remove_spaces(arena)) == format!("{:?}", after.remove_spaces(arena))
+}
+
 impl<'a> RemoveSpaces<'a> for Ast<'a> {
     fn remove_spaces(&self, arena: &'a Bump) -> Self {
         Ast {
@@ -848,6 +862,7 @@ impl<'a> RemoveSpaces<'a> for Expr<'a> {
             }
             Expr::MalformedIdent(a, b) => Expr::MalformedIdent(a, remove_spaces_bad_ident(b)),
             Expr::MalformedClosure => Expr::MalformedClosure,
+            Expr::Malformed(a) => Expr::Malformed(a),
             Expr::MalformedSuffixed(a) => Expr::MalformedSuffixed(a),
             Expr::PrecedenceConflict(a) => Expr::PrecedenceConflict(a),
             Expr::MultipleRecordBuilders(a) => Expr::MultipleRecordBuilders(a),
@@ -0,0 +1,274 @@
+//! Rewriting syntax the language has moved away from into its current form.
+//!
+//! Formatting already takes care of old-style headers: `interface` headers come out as
+//! `module` headers, and the `imports` of a header come out as `import` defs. What's left
+//! needs changes to the tree itself, which is what this module does before formatting.
+//!
+//! - Backpassing into `Task.await` becomes the `!` suffix, so
+//!   `x <- Task.await (Stdout.line "hi")` becomes `x = Stdout.line! "hi"`.
+//!   Backpassing into anything else is left alone, since it has no suffix form.
+use bumpalo::Bump;
+use roc_parse::ast::{CommentOrNewline, Defs, Expr, Pattern, ValueDef, WhenBranch};
+use roc_region::all::{Loc, Region};
+
+/// The module's defs, with every expression in them upgraded to the current syntax.
+///
+/// Comments stay where they were, since they live in the spaces around the expressions
+/// that are rewritten, which are kept as they are.
+pub fn upgrade_defs<'a>(arena: &'a Bump, defs: &Defs<'a>) -> Defs<'a> {
+    let mut upgraded = defs.clone();
+
+    for value_def in upgraded.value_defs.iter_mut() {
+        *value_def = upgrade_value_def(arena, *value_def);
+    }
+
+    upgraded
+}
+
+fn upgrade_value_def<'a>(arena: &'a Bump, value_def: ValueDef<'a>) -> ValueDef<'a> {
+    use ValueDef::*;
+
+    match value_def {
+        Body(pattern, expr) => Body(pattern, upgrade_loc_expr(arena, expr)),
+        AnnotatedBody {
+            ann_pattern,
+            ann_type,
+            comment,
+            body_pattern,
+            body_expr,
+        } => AnnotatedBody {
+            ann_pattern,
+            ann_type,
+            comment,
+            body_pattern,
+            body_expr: upgrade_loc_expr(arena, body_expr),
+        },
+        Dbg {
+            condition,
+            preceding_comment,
+        } => Dbg {
+            condition: upgrade_loc_expr(arena, condition),
+            preceding_comment,
+        },
+        Expect {
+            condition,
+            preceding_comment,
+        } => Expect {
+            condition: upgrade_loc_expr(arena, condition),
+            preceding_comment,
+        },
+        ExpectFx {
+            condition,
+            preceding_comment,
+        } => ExpectFx {
+            condition: upgrade_loc_expr(arena, condition),
+            preceding_comment,
+        },
+        Stmt(expr) => Stmt(upgrade_loc_expr(arena, expr)),
+        Annotation(..) | ModuleImport(_) | IngestedFileImport(_) => value_def,
+    }
+}
+
+fn upgrade_loc_expr<'a>(arena: &'a Bump, loc_expr: &'a Loc<Expr<'a>>) -> &'a Loc<Expr<'a>> {
+    arena.alloc(Loc::at(
+        loc_expr.region,
+        upgrade_expr(arena, &loc_expr.value),
+    ))
+}
+
+/// Only the places that can hold a block of statements are searched, since that's where
+/// backpassing is written.
+fn upgrade_expr<'a>(arena: &'a Bump, expr: &'a Expr<'a>) -> Expr<'a> {
+    match expr {
+        Expr::Defs(defs, final_expr) => {
+            let defs = upgrade_defs(arena, defs);
+            let final_expr = upgrade_loc_expr(arena, final_expr);
+
+            // Upgrading backpassing in the final expression turns it into a block of its
+            // own, which belongs at the end of this one.
+            match split_block(&final_expr.value) {
+                Some((inner_defs, inner_final, spaces)) => Expr::Defs(
+                    arena.alloc(concat_defs(&defs, inner_defs, spaces)),
+                    inner_final,
+                ),
+                None => Expr::Defs(arena.alloc(defs), final_expr),
+            }
+        }
+        Expr::Backpassing(patterns, rhs, continuation) => {
+            upgrade_backpassing(arena, patterns, rhs, continuation)
+        }
+        Expr::Closure(patterns, body) => Expr::Closure(patterns, upgrade_loc_expr(arena, body)),
+        Expr::Apply(function, args, called_via) => {
+            let args =
+                arena.alloc_slice_fill_iter(args.iter().map(|arg| upgrade_loc_expr(arena, arg)));
+
+            Expr::Apply(function, args, *called_via)
+        }
+        Expr::If(branches, final_else) => {
+            let branches = arena.alloc_slice_fill_iter(
+                branches
+                    .iter()
+                    .map(|(condition, then)| (*condition, *upgrade_loc_expr(arena, then))),
+            );
+
+            Expr::If(branches, upgrade_loc_expr(arena, final_else))
+        }
+        Expr::When(condition, branches) => {
+            let branches = arena.alloc_slice_fill_iter(branches.iter().map(|branch| {
+                &*arena.alloc(WhenBranch {
+                    value: *upgrade_loc_expr(arena, &branch.value),
+                    ..**branch
+                })
+            }));
+
+            Expr::When(condition, branches)
+        }
+        Expr::ParensAround(sub_expr) => {
+            Expr::ParensAround(arena.alloc(upgrade_expr(arena, sub_expr)))
+        }
+        Expr::SpaceBefore(sub_expr, spaces) => {
+            Expr::SpaceBefore(arena.alloc(upgrade_expr(arena, sub_expr)), spaces)
+        }
+        Expr::SpaceAfter(sub_expr, spaces) => {
+            Expr::SpaceAfter(arena.alloc(upgrade_expr(arena, sub_expr)), spaces)
+        }
+        _ => *expr,
+    }
+}
+
+/// `x <- Task.await (foo bar)` followed by `rest` becomes `x = foo! bar` followed by `rest`.
+fn upgrade_backpassing<'a>(
+    arena: &'a Bump,
+    patterns: &'a [Loc<Pattern<'a>>],
+    rhs: &'a Loc<Expr<'a>>,
+    continuation: &'a Loc<Expr<'a>>,
+) -> Expr<'a> {
+    let continuation = upgrade_loc_expr(arena, continuation);
+
+    let (pattern, awaited) = match (patterns, awaited_with_bang(arena, rhs)) {
+        ([pattern], Some(awaited)) => (pattern, awaited),
+        _ => return Expr::Backpassing(patterns, rhs, continuation),
+    };
+
+    let value_def = if is_ignored(&pattern.value) {
+        ValueDef::Stmt(awaited)
+    } else {
+        ValueDef::Body(pattern, awaited)
+    };
+
+    let mut defs = Defs::default();
+    defs.push_value_def(
+        value_def,
+        Region::span_across(&pattern.region, &rhs.region),
+        &[],
+        &[],
+    );
+
+    match split_block(&continuation.value) {
+        Some((inner_defs, inner_final, spaces)) => Expr::Defs(
+            arena.alloc(concat_defs(&defs, inner_defs, spaces)),
+            inner_final,
+        ),
+        None => Expr::Defs(arena.alloc(defs), continuation),
+    }
+}
+
+/// `Task.await foo` as `foo!`, and `Task.await (foo bar)` as `foo! bar`.
+fn awaited_with_bang<'a>(arena: &'a Bump, rhs: &Loc<Expr<'a>>) -> Option<&'a Loc<Expr<'a>>> {
+    let Expr::Apply(function, [arg], _) = rhs.value else {
+        return None;
+    };
+
+    if !matches!(
+        function.value,
+        Expr::Var {
+            module_name: "Task",
+            ident: "await"
+        }
+    ) {
+        return None;
+    }
+
+    let bang = |function: &'a Expr<'a>| Expr::TaskAwaitBang(function);
+
+    let awaited = match arg.value {
+        Expr::Var { .. } => bang(&arg.value),
+        Expr::ParensAround(sub_expr) => match sub_expr {
+            Expr::Var { .. } => bang(sub_expr),
+            Expr::Apply(
+                Loc {
+                    region,
+                    value: function @ Expr::Var { .. },
+                },
+                args,
+                called_via,
+            ) => Expr::Apply(
+                arena.alloc(Loc::at(*region, bang(function))),
+                args,
+                *called_via,
+            ),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(arena.alloc(Loc::at(arg.region, awaited)))
+}
+
+/// Patterns whose value is thrown away, which become statements rather than defs.
+fn is_ignored(pattern: &Pattern<'_>) -> bool {
+    match pattern {
+        Pattern::Underscore(_) => true,
+        Pattern::RecordDestructure(fields) => fields.is_empty(),
+        _ => false,
+    }
+}
+
+/// A block, along with the spaces in front of it.
+#[allow(clippy::type_complexity)]
+fn split_block<'a>(
+    expr: &Expr<'a>,
+) -> Option<(&'a Defs<'a>, &'a Loc<Expr<'a>>, &'a [CommentOrNewline<'a>])> {
+    match expr {
+        Expr::Defs(defs, final_expr) => Some((defs, final_expr, &[])),
+        Expr::SpaceBefore(Expr::Defs(defs, final_expr), spaces) => Some((defs, final_expr, spaces)),
+        _ => None,
+    }
+}
+
+/// The defs of `first` followed by the defs of `second`, with `spaces` between them.
+fn concat_defs<'a>(
+    first: &Defs<'a>,
+    second: &Defs<'a>,
+    spaces: &[CommentOrNewline<'a>],
+) -> Defs<'a> {
+    let mut defs = first.clone();
+
+    for (index, tag) in second.tags.iter().enumerate() {
+        let region = second.regions[index];
+        let mut space_before = if index == 0 {
+            spaces.to_vec()
+        } else {
+            Vec::new()
+        };
+        space_before.extend_from_slice(&second.spaces[second.space_before[index].indices()]);
+        let space_after = &second.spaces[second.space_after[index].indices()];
+
+        match tag.split() {
+            Ok(type_def_index) => defs.push_type_def(
+                second.type_defs[type_def_index.index()],
+                region,
+                &space_before,
+                space_after,
+            ),
+            Err(value_def_index) => defs.push_value_def(
+                second.value_defs[value_def_index.index()],
+                region,
+                &space_before,
+                space_after,
+            ),
+        }
+    }
+
+    defs
+}
@@ -0,0 +1,476 @@
+//! Breaking up lines that are longer than a maximum width.
+//!
+//! The formatter decides between laying something out on one line or on several based on
+//! how it was written, never on how long the line gets. With a maximum width, formatted code
+//! goes through one more step: on every line that is too long, the outermost function
+//! application, collection, chain of operators or type that fits on that line gets a newline
+//! inserted into it, and the code is formatted again, which lays it out the way multiline
+//! code is always laid out. That repeats until no line is left that can be broken up, so the
+//! code is parsed and formatted about as many times as constructs are nested, not once per
+//! construct. Since this only ever adds newlines, and code that was written across several
+//! lines stays that way, formatting the result again doesn't change it.
+use std::cmp::Reverse;
+
+use bumpalo::Bump;
+use roc_parse::ast::{
+    AssignedField, Collection, Defs, Expr, Tag, TypeAnnotation, TypeDef, ValueDef,
+};
+use roc_parse::module::{parse_header, parse_module_defs};
+use roc_parse::parser::SyntaxError;
+use roc_parse::state::State;
+use roc_region::all::{LineInfo, Loc, Position, Region};
+
+use crate::def::fmt_defs;
+use crate::module::fmt_module;
+use crate::spaces::{same_without_spaces, INDENT};
+use crate::{Ast, Buf};
+
+/// Somewhere a newline can be inserted to make `node` multiline.
+#[derive(Debug)]
+struct Break {
+    node: Region,
+    at: Position,
+}
+
+/// Break up the lines of already-formatted code that are longer than `max_width`, as far as
+/// possible. Lines that can't be broken up (like one with a long string on it) stay as they are.
+pub fn wrap_long_lines<'a>(arena: &'a Bump, formatted: &'a str, max_width: usize) -> &'a str {
+    let mut current = formatted;
+
+    while let Some(wrapped) = wrap_pass(arena, current, max_width) {
+        current = wrapped;
+    }
+
+    current
+}
+
+/// Break up the outermost construct on every line that is too long, and return the code
+/// formatted again.
+fn wrap_pass<'a>(arena: &'a Bump, src: &'a str, max_width: usize) -> Option<&'a str> {
+    let ast = parse(arena, src).ok()?;
+    let lines = LineInfo::new(src);
+
+    let mut breaks = Vec::new();
+    breaks_in_defs(&ast.defs, &mut breaks);
+    // Outermost first
+    breaks.sort_by_key(|brk| (brk.node.start(), Reverse(brk.node.end())));
+
+    let long_lines: Vec<_> = src
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| line.chars().count() > max_width)
+        .collect();
+
+    let lines = &lines;
+    let on_line = |line_index: usize| {
+        breaks.iter().filter(move |brk| {
+            let lc = lines.convert_region(brk.node);
+
+            lc.start.line == line_index as u32 && lc.end.line == line_index as u32
+        })
+    };
+
+    // Usually the outermost construct on each line can be broken up, so try all of them at once
+    let outermost: Vec<_> = long_lines
+        .iter()
+        .filter_map(|&(line_index, line)| Some((on_line(line_index).next()?.at, line)))
+        .collect();
+
+    if outermost.is_empty() {
+        return None;
+    }
+
+    if let Some(wrapped) = insert_breaks(arena, src, &ast, &outermost) {
+        return Some(wrapped);
+    }
+
+    // One of them couldn't be, so look for one that can, one at a time
+    for &(line_index, line) in long_lines.iter() {
+        for brk in on_line(line_index) {
+            if let Some(wrapped) = insert_breaks(arena, src, &ast, &[(brk.at, line)]) {
+                return Some(wrapped);
+            }
+        }
+    }
+
+    None
+}
+
+/// Insert a newline at each of `breaks`, which are in order and each on a different `line`,
+/// and format the code again. Returns `None` if that changes what the code means, or if the
+/// formatter ignores the newlines.
+fn insert_breaks<'a>(
+    arena: &'a Bump,
+    src: &'a str,
+    ast: &Ast<'a>,
+    breaks: &[(Position, &str)],
+) -> Option<&'a str> {
+    let mut broken = String::with_capacity(src.len() + breaks.len() * 2 * INDENT as usize);
+    let mut rest = 0;
+
+    for &(at, line) in breaks {
+        let at = at.offset as usize;
+        let indent = line.len() - line.trim_start().len() + INDENT as usize;
+
+        broken.push_str(&src[rest..at]);
+        broken.push('\n');
+        broken.extend(std::iter::repeat(' ').take(indent));
+        rest = at;
+    }
+
+    broken.push_str(&src[rest..]);
+
+    let broken = arena.alloc_str(&broken);
+    let broken_ast = parse(arena, broken).ok()?;
+
+    if !same_without_spaces(arena, ast, &broken_ast) {
+        return None;
+    }
+
+    let broken_ast = arena.alloc(broken_ast);
+    let mut buf = Buf::new_in(arena);
+    fmt_module(&mut buf, &broken_ast.module);
+    fmt_defs(&mut buf, &broken_ast.defs, 0);
+    buf.fmt_end_of_file();
+
+    let wrapped = buf.into_bump_str();
+
+    // The formatter can ignore the newline in some places, in which case that construct
+    // can't be broken up.
+    (wrapped.lines().count() > src.lines().count()).then_some(wrapped)
+}
+
+fn parse<'a>(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+    let (module, state) = parse_header(arena, State::new(src.as_bytes()))
+        .map_err(|e| SyntaxError::Header(e.problem))?;
+
+    let (module, defs) = module.upgrade_header_imports(arena);
+
+    let defs = parse_module_defs(arena, state, defs)?;
+
+    Ok(Ast { module, defs })
+}
+
+fn breaks_in_defs(defs: &Defs, breaks: &mut Vec<Break>) {
+    for def in defs.defs() {
+        match def {
+            Ok(TypeDef::Alias { ann, .. }) | Ok(TypeDef::Opaque { typ: ann, .. }) => {
+                breaks_in_type(ann, breaks)
+            }
+            Ok(TypeDef::Ability { .. }) => {}
+            Err(ValueDef::Annotation(_, ann)) => breaks_in_type(ann, breaks),
+            Err(ValueDef::Body(_, body)) => breaks_in_expr(body, breaks),
+            Err(ValueDef::AnnotatedBody {
+                ann_type,
+                body_expr,
+                ..
+            }) => {
+                breaks_in_type(ann_type, breaks);
+                breaks_in_expr(body_expr, breaks);
+            }
+            Err(ValueDef::Dbg { condition, .. })
+            | Err(ValueDef::Expect { condition, .. })
+            | Err(ValueDef::ExpectFx { condition, .. })
+            | Err(ValueDef::Stmt(condition)) => breaks_in_expr(condition, breaks),
+            Err(ValueDef::ModuleImport(_)) | Err(ValueDef::IngestedFileImport(_)) => {}
+        }
+    }
+}
+
+/// A break before the first item of a collection, which puts every item on its own line.
+fn collection_break<T>(node: Region, items: &Collection<Loc<T>>, breaks: &mut Vec<Break>) {
+    if let Some(first) = items.items.first() {
+        breaks.push(Break {
+            node,
+            at: first.region.start(),
+        });
+    }
+}
+
+fn breaks_in_expr(loc_expr: &Loc<Expr>, breaks: &mut Vec<Break>) {
+    breaks_in_expr_help(loc_expr.region, &loc_expr.value, breaks)
+}
+
+fn breaks_in_expr_help(region: Region, expr: &Expr, breaks: &mut Vec<Break>) {
+    match expr {
+        Expr::List(items) | Expr::Tuple(items) => {
+            if let Some(first) = items.items.first() {
+                breaks.push(Break {
+                    node: region,
+                    at: first.region.start(),
+                });
+            }
+
+            for item in items.iter() {
+                breaks_in_expr(item, breaks);
+            }
+        }
+        Expr::Record(fields) => {
+            collection_break(region, fields, breaks);
+            breaks_in_fields(fields, breaks);
+        }
+        Expr::RecordUpdate { update, fields } => {
+            collection_break(region, fields, breaks);
+            breaks_in_expr(update, breaks);
+            breaks_in_fields(fields, breaks);
+        }
+        Expr::Apply(loc_fn, loc_args, _) => {
+            // Puts every argument on its own line
+            if let Some(first) = loc_args.first() {
+                breaks.push(Break {
+                    node: region,
+                    at: first.region.start(),
+                });
+            }
+
+            breaks_in_expr(loc_fn, breaks);
+
+            for loc_arg in loc_args.iter() {
+                breaks_in_expr(loc_arg, breaks);
+            }
+        }
+        Expr::BinOps(lefts, loc_right) => {
+            // Puts every operator at the start of its own line
+            if let Some((_, first_op)) = lefts.first() {
+                breaks.push(Break {
+                    node: region,
+                    at: first_op.region.start(),
+                });
+            }
+
+            for (loc_left, _) in lefts.iter() {
+                breaks_in_expr(loc_left, breaks);
+            }
+
+            breaks_in_expr(loc_right, breaks);
+        }
+        Expr::Closure(_, loc_body) => breaks_in_expr(loc_body, breaks),
+        Expr::Defs(defs, loc_ret) => {
+            breaks_in_defs(defs, breaks);
+            breaks_in_expr(loc_ret, breaks);
+        }
+        Expr::Backpassing(_, loc_first, loc_second)
+        | Expr::Expect(loc_first, loc_second)
+        | Expr::Dbg(loc_first, loc_second)
+        | Expr::LowLevelDbg(_, loc_first, loc_second) => {
+            breaks_in_expr(loc_first, breaks);
+            breaks_in_expr(loc_second, breaks);
+        }
+        Expr::If(branches, loc_else) => {
+            for (loc_cond, loc_then) in branches.iter() {
+                breaks_in_expr(loc_cond, breaks);
+                breaks_in_expr(loc_then, breaks);
+            }
+
+            breaks_in_expr(loc_else, breaks);
+        }
+        Expr::When(loc_cond, branches) => {
+            breaks_in_expr(loc_cond, breaks);
+
+            for branch in branches.iter() {
+                if let Some(guard) = &branch.guard {
+                    breaks_in_expr(guard, breaks);
+                }
+
+                breaks_in_expr(&branch.value, breaks);
+            }
+        }
+        Expr::UnaryOp(loc_expr, _)
+        | Expr::MalformedSuffixed(loc_expr)
+        | Expr::MultipleRecordBuilders(loc_expr)
+        | Expr::UnappliedRecordBuilder(loc_expr) => breaks_in_expr(loc_expr, breaks),
+        Expr::SpaceBefore(inner, _)
+        | Expr::SpaceAfter(inner, _)
+        | Expr::ParensAround(inner)
+        | Expr::RecordAccess(inner, _)
+        | Expr::TupleAccess(inner, _)
+        | Expr::TaskAwaitBang(inner) => breaks_in_expr_help(region, inner, breaks),
+        Expr::Float(_)
+        | Expr::Num(_)
+        | Expr::NonBase10Int { .. }
+        | Expr::Str(_)
+        | Expr::SingleQuote(_)
+        | Expr::AccessorFunction(_)
+        | Expr::RecordBuilder(_)
+        | Expr::Var { .. }
+        | Expr::Underscore(_)
+        | Expr::Crash
+        | Expr::Tag(_)
+        | Expr::OpaqueRef(_)
+        | Expr::EmptyDefsFinal
+        | Expr::MalformedIdent(_, _)
+        | Expr::MalformedClosure
+        | Expr::Malformed(_)
+        | Expr::PrecedenceConflict(_) => {}
+    }
+}
+
+fn breaks_in_fields<'a, Val>(
+    fields: &Collection<'a, Loc<AssignedField<'a, Val>>>,
+    breaks: &mut Vec<Break>,
+) where
+    Val: BreaksIn,
+{
+    fn help<Val: BreaksIn>(field: &AssignedField<Val>, breaks: &mut Vec<Break>) {
+        match field {
+            AssignedField::RequiredValue(_, _, loc_val)
+            | AssignedField::OptionalValue(_, _, loc_val) => {
+                Val::breaks_in(loc_val.region, &loc_val.value, breaks)
+            }
+            AssignedField::SpaceBefore(inner, _) | AssignedField::SpaceAfter(inner, _) => {
+                help(inner, breaks)
+            }
+            AssignedField::LabelOnly(_) | AssignedField::Malformed(_) => {}
+        }
+    }
+
+    for field in fields.iter() {
+        help(&field.value, breaks);
+    }
+}
+
+/// Field values of records and record types are both handled by [breaks_in_fields].
+trait BreaksIn {
+    fn breaks_in(region: Region, value: &Self, breaks: &mut Vec<Break>);
+}
+
+impl BreaksIn for Expr<'_> {
+    fn breaks_in(region: Region, value: &Self, breaks: &mut Vec<Break>) {
+        breaks_in_expr_help(region, value, breaks)
+    }
+}
+
+impl BreaksIn for TypeAnnotation<'_> {
+    fn breaks_in(region: Region, value: &Self, breaks: &mut Vec<Break>) {
+        breaks_in_type_help(region, value, breaks)
+    }
+}
+
+fn breaks_in_type(loc_ann: &Loc<TypeAnnotation>, breaks: &mut Vec<Break>) {
+    breaks_in_type_help(loc_ann.region, &loc_ann.value, breaks)
+}
+
+fn breaks_in_type_help(region: Region, ann: &TypeAnnotation, breaks: &mut Vec<Break>) {
+    match ann {
+        TypeAnnotation::Function(args, ret) => {
+            // Before the first argument, which moves the whole type to a line of its own.
+            // If that isn't enough, the types inside it get broken up next.
+            if let Some(first) = args.first() {
+                breaks.push(Break {
+                    node: region,
+                    at: first.region.start(),
+                });
+            }
+
+            for arg in args.iter() {
+                breaks_in_type(arg, breaks);
+            }
+
+            breaks_in_type(ret, breaks);
+        }
+        TypeAnnotation::Apply(_, _, args) => {
+            for arg in args.iter() {
+                breaks_in_type(arg, breaks);
+            }
+        }
+        TypeAnnotation::As(inner, _, _) | TypeAnnotation::Where(inner, _) => {
+            breaks_in_type(inner, breaks)
+        }
+        TypeAnnotation::Record { fields, ext } => {
+            collection_break(region, fields, breaks);
+            breaks_in_fields(fields, breaks);
+
+            if let Some(ext) = ext {
+                breaks_in_type(ext, breaks);
+            }
+        }
+        TypeAnnotation::Tuple { elems, ext } => {
+            collection_break(region, elems, breaks);
+
+            for elem in elems.iter() {
+                breaks_in_type(elem, breaks);
+            }
+
+            if let Some(ext) = ext {
+                breaks_in_type(ext, breaks);
+            }
+        }
+        TypeAnnotation::TagUnion { tags, ext } => {
+            collection_break(region, tags, breaks);
+
+            for tag in tags.iter() {
+                breaks_in_tag(&tag.value, breaks);
+            }
+
+            if let Some(ext) = ext {
+                breaks_in_type(ext, breaks);
+            }
+        }
+        TypeAnnotation::SpaceBefore(inner, _) | TypeAnnotation::SpaceAfter(inner, _) => {
+            breaks_in_type_help(region, inner, breaks)
+        }
+        TypeAnnotation::BoundVariable(_)
+        | TypeAnnotation::Inferred
+        | TypeAnnotation::Wildcard
+        | TypeAnnotation::Malformed(_) => {}
+    }
+}
+
+fn breaks_in_tag(tag: &Tag, breaks: &mut Vec<Break>) {
+    match tag {
+        Tag::Apply { args, .. } => {
+            for arg in args.iter() {
+                breaks_in_type(arg, breaks);
+            }
+        }
+        Tag::SpaceBefore(inner, _) | Tag::SpaceAfter(inner, _) => breaks_in_tag(inner, breaks),
+        Tag::Malformed(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: &str = "module [names]\n\nnames = [\"Alice\", \"Bob\"]\n";
+
+    const WRAPPED_NAMES: &str = "module [names]\n\nnames = [\n    \"Alice\",\n    \"Bob\",\n]\n";
+
+    #[test]
+    fn line_at_the_limit_is_left_alone() {
+        let arena = Bump::new();
+        let width = "names = [\"Alice\", \"Bob\"]".len();
+
+        assert_eq!(wrap_long_lines(&arena, NAMES, width), NAMES);
+    }
+
+    #[test]
+    fn line_past_the_limit_is_wrapped() {
+        let arena = Bump::new();
+        let width = "names = [\"Alice\", \"Bob\"]".len() - 1;
+
+        assert_eq!(wrap_long_lines(&arena, NAMES, width), WRAPPED_NAMES);
+    }
+
+    #[test]
+    fn wrapping_is_idempotent() {
+        let arena = Bump::new();
+        let src = "module [a, b]\n\na = Str.joinWith [\"one\", \"two\", \"three\"] \", \"\n\nb = { name: \"Alice\", age: 42, email: \"alice@example.com\" }\n";
+
+        let wrapped = wrap_long_lines(&arena, src, 20);
+        assert_ne!(wrapped, src);
+        assert_eq!(wrap_long_lines(&arena, wrapped, 20), wrapped);
+    }
+
+    #[test]
+    fn every_long_line_is_wrapped_in_one_pass() {
+        let arena = Bump::new();
+        let src = "module [a, b]\n\na = [\"Alice\", \"Bob\"]\n\nb = [\"Carol\", \"Dave\"]\n";
+
+        let wrapped = wrap_pass(&arena, src, 16).unwrap();
+        assert_eq!(
+            wrapped,
+            "module [a, b]\n\na = [\n    \"Alice\",\n    \"Bob\",\n]\n\nb = [\n    \"Carol\",\n    \"Dave\",\n]\n"
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! The DWARF the dev backend writes into its objects when asked for debug info.
+//!
+//! Every proc with a place in the source gets a subprogram in `.debug_info`, and `.debug_line`
+//! gets a row wherever the code for a statement starts, so debuggers, profilers and backtraces
+//! can map machine code back to the `.roc` files it came from. There are no variables or types
+//! yet.
+use std::path::Path;
+
+use gimli::write::{
+    Address, AttributeValue, DwarfUnit, EndianVec, LineProgram, LineString, Range, RangeList,
+    Sections, Writer,
+};
+use gimli::{Encoding, Format, LineEncoding, RunTimeEndian};
+use object::write::{Object, SymbolId};
+use object::{RelocationEncoding, RelocationKind, SectionKind};
+use roc_collections::all::MutMap;
+use roc_error_macros::internal_error;
+use roc_module::symbol::{ModuleId, Symbol};
+use roc_mono::debug_sources::{DebugSources, SourceLocation};
+use roc_region::all::Region;
+
+/// What we need to describe one proc once its code is in the object.
+#[derive(Debug)]
+pub(crate) struct DebugProc {
+    pub symbol: Symbol,
+    /// Where the proc is in the source of its module.
+    pub region: Region,
+    /// What the user called the proc.
+    pub name: String,
+    /// The linker symbol the code was added under.
+    pub symbol_id: SymbolId,
+    pub linkage_name: String,
+    pub size: u64,
+    /// The statements in the proc that have a place in the source, with the offset their code
+    /// starts at, in code order.
+    pub statements: Vec<(u64, Region)>,
+}
+
+/// Adds `.debug_info`, `.debug_line` and the sections they refer to, describing `procs`.
+///
+/// Procs each get their own text section, so every address is relative to the proc's symbol,
+/// and the linker fills it in.
+pub(crate) fn write_debug_sections(
+    output: &mut Object,
+    debug_sources: &DebugSources,
+    module_id: ModuleId,
+    procs: &[DebugProc],
+) {
+    let procs: Vec<(&DebugProc, SourceLocation)> = procs
+        .iter()
+        .filter(|proc| proc.size > 0)
+        .filter_map(|proc| {
+            let location = debug_sources.location(proc.symbol.module_id(), proc.region)?;
+
+            Some((proc, location))
+        })
+        .collect();
+
+    let Some((comp_path, _)) = debug_sources.file(module_id) else {
+        return;
+    };
+
+    if procs.is_empty() {
+        return;
+    }
+
+    let encoding = Encoding {
+        format: Format::Dwarf32,
+        version: 4,
+        address_size: 8,
+    };
+
+    let mut dwarf = DwarfUnit::new(encoding);
+
+    let (comp_dir, comp_name) = split_path(comp_path);
+    dwarf.unit.line_program = LineProgram::new(
+        encoding,
+        LineEncoding::default(),
+        LineString::String(comp_dir.clone()),
+        LineString::String(comp_name.clone()),
+        None,
+    );
+
+    let mut files = MutMap::default();
+    let mut file_id = |dwarf: &mut DwarfUnit, module_id: ModuleId| {
+        *files.entry(module_id).or_insert_with(|| {
+            // We only get here for modules that have a location, so we have their source.
+            let (path, _) = debug_sources.file(module_id).unwrap();
+            let (dir, name) = split_path(path);
+            let program = &mut dwarf.unit.line_program;
+            let dir_id = program.add_directory(LineString::String(dir));
+
+            program.add_file(LineString::String(name), dir_id, None)
+        })
+    };
+
+    let mut ranges = Vec::with_capacity(procs.len());
+
+    for (index, (proc, location)) in procs.iter().enumerate() {
+        let start = Address::Symbol {
+            symbol: index,
+            addend: 0,
+        };
+
+        ranges.push(Range::StartLength {
+            begin: start,
+            length: proc.size,
+        });
+
+        let file = file_id(&mut dwarf, location.module_id);
+
+        let root = dwarf.unit.root();
+        let entry_id = dwarf.unit.add(root, gimli::DW_TAG_subprogram);
+        let entry = dwarf.unit.get_mut(entry_id);
+        entry.set(
+            gimli::DW_AT_name,
+            AttributeValue::String(proc.name.as_bytes().to_vec()),
+        );
+        entry.set(
+            gimli::DW_AT_linkage_name,
+            AttributeValue::String(proc.linkage_name.as_bytes().to_vec()),
+        );
+        entry.set(
+            gimli::DW_AT_decl_file,
+            AttributeValue::FileIndex(Some(file)),
+        );
+        entry.set(
+            gimli::DW_AT_decl_line,
+            AttributeValue::Udata(location.line as u64 + 1),
+        );
+        entry.set(gimli::DW_AT_low_pc, AttributeValue::Address(start));
+        entry.set(gimli::DW_AT_high_pc, AttributeValue::Udata(proc.size));
+
+        // One sequence per proc, since they can end up anywhere relative to each other.
+        dwarf.unit.line_program.begin_sequence(Some(start));
+        add_row(&mut dwarf, file, 0, *location);
+
+        let mut statements = proc.statements.iter().peekable();
+        while let Some((offset, region)) = statements.next() {
+            // When several statements didn't generate any code, the last one owns what follows.
+            let next_offset = statements.peek().map(|(next, _)| *next);
+            if next_offset == Some(*offset) || *offset >= proc.size {
+                continue;
+            }
+
+            // The statements of a proc are from the same module as the proc itself.
+            if let Some(location) = debug_sources.location(location.module_id, *region) {
+                add_row(&mut dwarf, file, *offset, location);
+            }
+        }
+
+        dwarf.unit.line_program.end_sequence(proc.size);
+    }
+
+    let range_list_id = dwarf.unit.ranges.add(RangeList(ranges));
+    let root = dwarf.unit.root();
+    let entry = dwarf.unit.get_mut(root);
+    entry.set(
+        gimli::DW_AT_producer,
+        AttributeValue::String(b"roc dev backend".to_vec()),
+    );
+    entry.set(
+        gimli::DW_AT_language,
+        AttributeValue::Language(gimli::DW_LANG_C),
+    );
+    entry.set(gimli::DW_AT_name, AttributeValue::String(comp_name));
+    entry.set(gimli::DW_AT_comp_dir, AttributeValue::String(comp_dir));
+    entry.set(
+        gimli::DW_AT_low_pc,
+        AttributeValue::Address(Address::Constant(0)),
+    );
+    entry.set(
+        gimli::DW_AT_ranges,
+        AttributeValue::RangeListRef(range_list_id),
+    );
+
+    // Both of the architectures we generate code for are little-endian.
+    let mut sections = Sections::new(RelocWriter::new(RunTimeEndian::Little));
+    if let Err(e) = dwarf.write(&mut sections) {
+        internal_error!("failed to write DWARF: {:?}", e);
+    }
+
+    add_sections(output, &sections, &procs);
+}
+
+fn add_row(
+    dwarf: &mut DwarfUnit,
+    file: gimli::write::FileId,
+    offset: u64,
+    location: SourceLocation,
+) {
+    let program = &mut dwarf.unit.line_program;
+    let row = program.row();
+
+    // DWARF lines and columns are 1-based.
+    row.address_offset = offset;
+    row.file = file;
+    row.line = location.line as u64 + 1;
+    row.column = location.column as u64 + 1;
+
+    program.generate_row();
+}
+
+fn split_path(path: &Path) -> (Vec<u8>, Vec<u8>) {
+    let name = match path.file_name() {
+        Some(name) => name.to_string_lossy(),
+        None => path.to_string_lossy(),
+    };
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_string_lossy(),
+        _ => ".".into(),
+    };
+
+    (dir.as_bytes().to_vec(), name.as_bytes().to_vec())
+}
+
+/// Copies the non-empty DWARF sections into the object, turning the addresses and section
+/// offsets gimli wrote into relocations.
+fn add_sections(
+    output: &mut Object,
+    sections: &Sections<RelocWriter>,
+    procs: &[(&DebugProc, SourceLocation)],
+) {
+    let mut section_ids = MutMap::default();
+    sections
+        .for_each(|id, writer| {
+            if !writer.data.slice().is_empty() {
+                let section_id =
+                    output.add_section(vec![], id.name().as_bytes().to_vec(), SectionKind::Debug);
+                section_ids.insert(id, section_id);
+            }
+
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+
+    sections
+        .for_each(|id, writer| {
+            let Some(section_id) = section_ids.get(&id) else {
+                return Ok(());
+            };
+
+            output.set_section_data(*section_id, writer.data.slice().to_vec(), 1);
+
+            for reloc in &writer.relocs {
+                let symbol = match reloc.target {
+                    RelocTarget::Proc(index) => procs[index].0.symbol_id,
+                    RelocTarget::Section(target) => match section_ids.get(&target) {
+                        Some(target) => output.section_symbol(*target),
+                        None => internal_error!("DWARF refers to the empty {:?}", target),
+                    },
+                };
+
+                let relocation = object::write::Relocation {
+                    offset: reloc.offset,
+                    size: reloc.size * 8,
+                    kind: RelocationKind::Absolute,
+                    encoding: RelocationEncoding::Generic,
+                    symbol,
+                    addend: reloc.addend,
+                };
+
+                if let Err(e) = output.add_relocation(*section_id, relocation) {
+                    internal_error!("{:?}", e);
+                }
+            }
+
+            Ok::<_, ()>(())
+        })
+        .unwrap();
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RelocTarget {
+    /// The start of the proc at this index.
+    Proc(usize),
+    /// The start of another DWARF section.
+    Section(gimli::SectionId),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct DebugReloc {
+    offset: u64,
+    size: u8,
+    target: RelocTarget,
+    addend: i64,
+}
+
+/// A section writer that records where it needs relocations instead of writing addresses,
+/// since we don't know where the linker will put anything.
+#[derive(Debug, Clone)]
+struct RelocWriter {
+    data: EndianVec<RunTimeEndian>,
+    relocs: Vec<DebugReloc>,
+}
+
+impl RelocWriter {
+    fn new(endian: RunTimeEndian) -> Self {
+        Self {
+            data: EndianVec::new(endian),
+            relocs: Vec::new(),
+        }
+    }
+}
+
+impl Writer for RelocWriter {
+    type Endian = RunTimeEndian;
+
+    fn endian(&self) -> Self::Endian {
+        self.data.endian()
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn write(&mut self, bytes: &[u8]) -> gimli::write::Result<()> {
+        self.data.write(bytes)
+    }
+
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) -> gimli::write::Result<()> {
+        self.data.write_at(offset, bytes)
+    }
+
+    fn write_address(&mut self, address: Address, size: u8) -> gimli::write::Result<()> {
+        match address {
+            Address::Constant(val) => self.write_udata(val, size),
+            Address::Symbol { symbol, addend } => {
+                self.relocs.push(DebugReloc {
+                    offset: self.len() as u64,
+                    size,
+                    target: RelocTarget::Proc(symbol),
+                    addend,
+                });
+
+                self.write_udata(0, size)
+            }
+        }
+    }
+
+    fn write_offset(
+        &mut self,
+        val: usize,
+        section: gimli::SectionId,
+        size: u8,
+    ) -> gimli::write::Result<()> {
+        let offset = self.len();
+        self.write_udata(0, size)?;
+        self.write_offset_at(offset, val, section, size)
+    }
+
+    fn write_offset_at(
+        &mut self,
+        offset: usize,
+        val: usize,
+        section: gimli::SectionId,
+        size: u8,
+    ) -> gimli::write::Result<()> {
+        self.relocs.push(DebugReloc {
+            offset: offset as u64,
+            size,
+            target: RelocTarget::Section(section),
+            addend: val as i64,
+        });
+
+        self.write_udata_at(offset, 0, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use object::read::{Object as _, ObjectSection as _};
+    use object::write::{StandardSection, SymbolSection};
+    use object::{Architecture, BinaryFormat, Endianness, SymbolFlags, SymbolKind, SymbolScope};
+    use roc_region::all::Position;
+    use std::path::PathBuf;
+
+    #[test]
+    fn writes_line_info_for_procs() {
+        let mut output = Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        let text = output.section_id(StandardSection::Text);
+        let symbol_id = output.add_symbol(object::write::Symbol {
+            name: b"List_map_1".to_vec(),
+            value: 0,
+            size: 0,
+            kind: SymbolKind::Text,
+            scope: SymbolScope::Linkage,
+            weak: false,
+            section: SymbolSection::Section(text),
+            flags: SymbolFlags::None,
+        });
+        output.add_symbol_data(symbol_id, text, &[0x90; 32], 16);
+
+        let src = "interface List\n\nmap = \\list, f ->\n    x = 1\n    x\n";
+        let mut sources = MutMap::default();
+        sources.insert(ModuleId::LIST, (PathBuf::from("/roc/List.roc"), src.into()));
+
+        let region = |offset: usize| {
+            let pos = Position::new(offset as u32);
+            Region::new(pos, pos)
+        };
+        let debug_sources = DebugSources::new(&sources);
+        let procs = [DebugProc {
+            symbol: Symbol::LIST_MAP,
+            region: region(src.find("map").unwrap()),
+            name: String::from("map"),
+            symbol_id,
+            linkage_name: String::from("List_map_1"),
+            size: 32,
+            statements: vec![
+                (8, region(src.find('x').unwrap())),
+                (40, region(src.find('x').unwrap())),
+            ],
+        }];
+
+        write_debug_sections(&mut output, &debug_sources, ModuleId::LIST, &procs);
+
+        let bytes = output.write().unwrap();
+        let file = object::File::parse(bytes.as_slice()).unwrap();
+
+        for name in [
+            ".debug_info",
+            ".debug_line",
+            ".debug_abbrev",
+            ".debug_ranges",
+        ] {
+            let section = file.section_by_name(name).unwrap();
+
+            assert!(!section.data().unwrap().is_empty(), "{name} is empty");
+        }
+
+        // The proc's start address, in its subprogram, line sequence and range.
+        let text_relocs = |name| {
+            file.section_by_name(name)
+                .unwrap()
+                .relocations()
+                .filter(|(_, reloc)| {
+                    reloc.target() == object::RelocationTarget::Symbol(symbol_index(&file))
+                })
+                .count()
+        };
+
+        assert_eq!(text_relocs(".debug_info"), 1);
+        assert_eq!(text_relocs(".debug_line"), 1);
+        assert_eq!(text_relocs(".debug_ranges"), 2);
+
+        let contains = |name, needle: &[u8]| {
+            let data = file.section_by_name(name).unwrap().data().unwrap();
+            data.windows(needle.len()).any(|w| w == needle)
+        };
+
+        assert!(contains(".debug_line", b"List.roc\0"));
+        assert!(contains(".debug_info", b"/roc\0"));
+    }
+
+    fn symbol_index(file: &object::File) -> object::SymbolIndex {
+        use object::read::ObjectSymbol;
+
+        file.symbols()
+            .find(|symbol| symbol.name() == Ok("List_map_1"))
+            .unwrap()
+            .index()
+    }
+
+    #[test]
+    fn nothing_without_locations() {
+        let mut output = Object::new(BinaryFormat::Elf, Architecture::X86_64, Endianness::Little);
+        let debug_sources = DebugSources::new(&MutMap::default());
+
+        write_debug_sections(&mut output, &debug_sources, ModuleId::LIST, &[]);
+
+        let bytes = output.write().unwrap();
+        let file = object::File::parse(bytes.as_slice()).unwrap();
+
+        assert!(file.section_by_name(".debug_info").is_none());
+    }
+}
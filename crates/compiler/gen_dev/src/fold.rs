@@ -0,0 +1,156 @@
+//! Constant folding of low levels whose arguments are all literals.
+//!
+//! With lazy literals, a literal is only loaded once something needs it. When that something is
+//! a simple operation on other literals, we can compute the result here instead and keep it as a
+//! lazy literal too. This only happens when lazy literals are enabled, so the tests still cover
+//! the real instructions when they run without them.
+use roc_builtins::bitcode::IntWidth;
+use roc_module::low_level::LowLevel;
+use roc_mono::ir::Literal;
+
+/// Evaluates `lowlevel` on two integer literals of the given width.
+/// Returns `None` when it can't be folded. This includes checked operations that overflow,
+/// since those have to crash at runtime.
+pub(crate) fn fold_int_literals<'a>(
+    lowlevel: LowLevel,
+    width: IntWidth,
+    lhs: i128,
+    rhs: i128,
+) -> Option<Literal<'a>> {
+    use LowLevel::*;
+
+    let (min, max) = int_range(width)?;
+    let in_range = |value: i128| (min..=max).contains(&value);
+    if !in_range(lhs) || !in_range(rhs) {
+        return None;
+    }
+
+    let int = |value: i128| Some(Literal::Int(value.to_ne_bytes()));
+    let wrapped = |value: i128| int(wrap_to_width(value, width));
+
+    match lowlevel {
+        NumAdd => lhs.checked_add(rhs).filter(|v| in_range(*v)).and_then(int),
+        NumSub => lhs.checked_sub(rhs).filter(|v| in_range(*v)).and_then(int),
+        NumMul => lhs.checked_mul(rhs).filter(|v| in_range(*v)).and_then(int),
+        NumAddWrap => wrapped(lhs.wrapping_add(rhs)),
+        NumSubWrap => wrapped(lhs.wrapping_sub(rhs)),
+        NumMulWrap => wrapped(lhs.wrapping_mul(rhs)),
+        NumBitwiseAnd => wrapped(lhs & rhs),
+        NumBitwiseOr => wrapped(lhs | rhs),
+        NumBitwiseXor => wrapped(lhs ^ rhs),
+        Eq => Some(Literal::Bool(lhs == rhs)),
+        NotEq => Some(Literal::Bool(lhs != rhs)),
+        NumLt => Some(Literal::Bool(lhs < rhs)),
+        NumLte => Some(Literal::Bool(lhs <= rhs)),
+        NumGt => Some(Literal::Bool(lhs > rhs)),
+        NumGte => Some(Literal::Bool(lhs >= rhs)),
+        _ => None,
+    }
+}
+
+/// The values an integer of this width can hold.
+/// 128-bit integers are not folded, their arithmetic could overflow an i128.
+fn int_range(width: IntWidth) -> Option<(i128, i128)> {
+    let range = match width {
+        IntWidth::U8 => (0, u8::MAX as i128),
+        IntWidth::U16 => (0, u16::MAX as i128),
+        IntWidth::U32 => (0, u32::MAX as i128),
+        IntWidth::U64 => (0, u64::MAX as i128),
+        IntWidth::I8 => (i8::MIN as i128, i8::MAX as i128),
+        IntWidth::I16 => (i16::MIN as i128, i16::MAX as i128),
+        IntWidth::I32 => (i32::MIN as i128, i32::MAX as i128),
+        IntWidth::I64 => (i64::MIN as i128, i64::MAX as i128),
+        IntWidth::U128 | IntWidth::I128 => return None,
+    };
+
+    Some(range)
+}
+
+/// Truncates `value` to the bits of `width`, sign extending it again for signed integers.
+fn wrap_to_width(value: i128, width: IntWidth) -> i128 {
+    let shift = 128 - 8 * width.stack_size();
+    if width.is_signed() {
+        (value << shift) >> shift
+    } else {
+        (((value as u128) << shift) >> shift) as i128
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fold_int(lowlevel: LowLevel, width: IntWidth, lhs: i128, rhs: i128) -> Option<i128> {
+        match fold_int_literals(lowlevel, width, lhs, rhs) {
+            Some(Literal::Int(bytes)) => Some(i128::from_ne_bytes(bytes)),
+            Some(other) => panic!("expected an int literal, got {other:?}"),
+            None => None,
+        }
+    }
+
+    #[test]
+    fn folds_arithmetic() {
+        assert_eq!(fold_int(LowLevel::NumAdd, IntWidth::I64, 40, 2), Some(42));
+        assert_eq!(
+            fold_int(LowLevel::NumSub, IntWidth::I8, -100, 28),
+            Some(-128)
+        );
+        assert_eq!(fold_int(LowLevel::NumMul, IntWidth::U32, 6, 7), Some(42));
+        assert_eq!(
+            fold_int(LowLevel::NumBitwiseXor, IntWidth::U8, 0xF0, 0xFF),
+            Some(0x0F)
+        );
+    }
+
+    #[test]
+    fn leaves_overflow_to_runtime() {
+        assert_eq!(fold_int(LowLevel::NumAdd, IntWidth::U8, 200, 100), None);
+        assert_eq!(fold_int(LowLevel::NumSub, IntWidth::U64, 0, 1), None);
+        assert_eq!(
+            fold_int(LowLevel::NumMul, IntWidth::I64, i64::MAX as i128, 2),
+            None
+        );
+    }
+
+    #[test]
+    fn wraps_to_width() {
+        assert_eq!(
+            fold_int(LowLevel::NumAddWrap, IntWidth::U8, 200, 100),
+            Some(44)
+        );
+        assert_eq!(
+            fold_int(LowLevel::NumSubWrap, IntWidth::U64, 0, 1),
+            Some(u64::MAX as i128)
+        );
+        assert_eq!(
+            fold_int(LowLevel::NumAddWrap, IntWidth::I8, 127, 1),
+            Some(-128)
+        );
+        assert_eq!(
+            fold_int(
+                LowLevel::NumMulWrap,
+                IntWidth::U64,
+                u64::MAX as i128,
+                u64::MAX as i128
+            ),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn folds_comparisons() {
+        assert!(matches!(
+            fold_int_literals(LowLevel::NumLt, IntWidth::I32, -1, 1),
+            Some(Literal::Bool(true))
+        ));
+        assert!(matches!(
+            fold_int_literals(LowLevel::Eq, IntWidth::U16, 3, 4),
+            Some(Literal::Bool(false))
+        ));
+    }
+
+    #[test]
+    fn skips_128_bit_ints() {
+        assert_eq!(fold_int(LowLevel::NumAdd, IntWidth::I128, 1, 2), None);
+    }
+}
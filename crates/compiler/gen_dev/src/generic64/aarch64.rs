@@ -927,7 +927,8 @@ impl AArch64CallLoadArgs {
                 sym,
                 lambda_set.runtime_representation(),
             ),
-            LayoutRepr::Struct { .. } | LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
+            // structs, non-recursive tag unions, and anything else that fits in two registers
+            _ => {
                 if stack_size <= 8 {
                     self.load_arg_general_64bit(
                         buf,
@@ -948,12 +949,6 @@ impl AArch64CallLoadArgs {
                     unreachable!("covered by an earlier branch")
                 }
             }
-            _ => {
-                todo!(
-                    "Loading args with layout {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -1107,7 +1102,8 @@ impl AArch64CallStoreArgs {
                 sym,
                 lambda_set.runtime_representation(),
             ),
-            LayoutRepr::Struct { .. } | LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
+            // structs, non-recursive tag unions, and anything else that fits in two registers
+            _ => {
                 let stack_size = layout_interner.stack_size(in_layout);
                 if stack_size <= 8 {
                     self.store_arg_64bit(buf, storage_manager, sym);
@@ -1117,12 +1113,6 @@ impl AArch64CallStoreArgs {
                     unreachable!("covered by earlier branch");
                 }
             }
-            _ => {
-                todo!(
-                    "calling with arg type, {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -1264,10 +1254,14 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     ) {
         if imm32 < 0 {
             Self::sub_reg64_reg64_imm32(buf, dst, src, -imm32);
-        } else if imm32 < 0xFFF {
+        } else if imm32 <= 0xFFF {
             add_reg64_reg64_imm12(buf, dst, src, imm32 as u16);
+        } else if imm32 <= 0xFF_FFFF {
+            // add the upper 12 bits (shifted by 12) first, and then the lower 12 bits
+            add_reg64_reg64_imm12_lsl12(buf, dst, src, (imm32 >> 12) as u16);
+            add_reg64_reg64_imm12(buf, dst, dst, (imm32 & 0xFFF) as u16);
         } else {
-            todo!("immediate additions with values greater than 12bits");
+            internal_error!("immediate additions with values greater than 24 bits");
         }
     }
     #[inline(always)]
@@ -1492,7 +1486,7 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         if (-(1 << 27)..(1 << 27)).contains(&offset) {
             b_imm26(buf, offset);
         } else {
-            todo!("jump offsets over 27 bits for AArch64: {:#x}", offset);
+            internal_error!("jump offsets over 27 bits for AArch64: {:#x}", offset);
         }
 
         // on aarch64, jumps are calculated from the start of the jmp instruction
@@ -1528,7 +1522,7 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
         if (-(1 << 20)..(1 << 20)).contains(&offset) {
             b_cond_imm19(buf, ConditionCode::NE, offset);
         } else {
-            todo!("jump offsets over 20 bits for AArch64: {:#x}", offset);
+            internal_error!("jump offsets over 20 bits for AArch64: {:#x}", offset);
         }
 
         // on aarch64, jumps are calculated from the start of the jmp instruction
@@ -1855,10 +1849,14 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
     ) {
         if imm32 < 0 {
             Self::add_reg64_reg64_imm32(buf, dst, src, -imm32)
-        } else if imm32 < 0xFFF {
+        } else if imm32 <= 0xFFF {
             sub_reg64_reg64_imm12(buf, dst, src, imm32 as u16);
+        } else if imm32 <= 0xFF_FFFF {
+            // subtract the upper 12 bits (shifted by 12) first, and then the lower 12 bits
+            sub_reg64_reg64_imm12_lsl12(buf, dst, src, (imm32 >> 12) as u16);
+            sub_reg64_reg64_imm12(buf, dst, dst, (imm32 & 0xFFF) as u16);
         } else {
-            todo!("immediate subtractions with values greater than 12bits");
+            internal_error!("immediate subtractions with values greater than 24 bits");
         }
     }
     #[inline(always)]
@@ -2119,7 +2117,10 @@ impl Assembler<AArch64GeneralReg, AArch64FloatReg> for AArch64Assembler {
             debug_assert!(offset % 8 == 0);
             ldr_freg64_reg64_imm12(buf, dst, src, (offset as u16) >> 3);
         } else {
-            todo!("base offsets over 32k for AArch64");
+            let tmp = AArch64GeneralReg::X15;
+            Self::mov_reg64_imm64(buf, tmp, offset as i64);
+            Self::add_reg64_reg64_reg64(buf, tmp, tmp, src);
+            ldr_freg64_reg64_imm12(buf, dst, tmp, 0);
         }
     }
 }
@@ -3216,6 +3217,26 @@ fn add_reg64_reg64_imm12(
     buf.extend(inst.bytes());
 }
 
+/// `ADD Xd, Xn, imm12, LSL 12` -> Add Xn and imm12 shifted left by 12 bits and place the result into Xd.
+#[inline(always)]
+fn add_reg64_reg64_imm12_lsl12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(ArithmeticImmediateParams {
+        op: false,
+        s: false,
+        sh: true,
+        imm12,
+        rd: dst,
+        rn: src,
+    });
+
+    buf.extend(inst.bytes());
+}
+
 /// `ADD Xd, Xm, Xn` -> Add Xm and Xn and place the result into Xd.
 #[inline(always)]
 fn add_reg64_reg64_reg64(
@@ -3787,6 +3808,26 @@ fn sub_reg64_reg64_imm12(
     buf.extend(inst.bytes());
 }
 
+/// `SUB Xd, Xn, imm12, LSL 12` -> Subtract imm12 shifted left by 12 bits from Xn and place the result into Xd.
+#[inline(always)]
+fn sub_reg64_reg64_imm12_lsl12(
+    buf: &mut Vec<'_, u8>,
+    dst: AArch64GeneralReg,
+    src: AArch64GeneralReg,
+    imm12: u16,
+) {
+    let inst = ArithmeticImmediate::new(ArithmeticImmediateParams {
+        op: true,
+        s: false,
+        rd: dst,
+        rn: src,
+        imm12,
+        sh: true,
+    });
+
+    buf.extend(inst.bytes());
+}
+
 /// `SUB Xd, Xm, Xn` -> Subtract Xm and Xn and place the result into Xd.
 #[inline(always)]
 fn sub_reg64_reg64_reg64(
@@ -4509,6 +4550,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_reg64_reg64_imm12_lsl12() {
+        disassembler_test!(
+            add_reg64_reg64_imm12_lsl12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "add {}, {}, #0x{:x}, lsl #12",
+                reg1.capstone_string(UsesSP),
+                reg2.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_add_reg64_reg64_imm32() {
+        disassembler_test!(
+            AArch64Assembler::add_reg64_reg64_imm32,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm: i32| {
+                let reg1 = reg1.capstone_string(UsesSP);
+                let reg2 = reg2.capstone_string(UsesSP);
+                match imm {
+                    0..=0xFFF => format!("add {reg1}, {reg2}, #0x{imm:x}"),
+                    _ => format!(
+                        "add {reg1}, {reg2}, #0x{:x}, lsl #12\nadd {reg1}, {reg1}, #0x{:x}",
+                        imm >> 12,
+                        imm & 0xFFF
+                    ),
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123, 0xFFF, 0x12345]
+        );
+    }
+
     #[test]
     fn test_and_reg64_reg64_reg64() {
         disassembler_test!(
@@ -5059,6 +5138,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sub_reg64_reg64_imm12_lsl12() {
+        disassembler_test!(
+            sub_reg64_reg64_imm12_lsl12,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm| format!(
+                "sub {}, {}, #0x{:x}, lsl #12",
+                reg1.capstone_string(UsesSP),
+                reg2.capstone_string(UsesSP),
+                imm
+            ),
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123]
+        );
+    }
+
+    #[test]
+    fn test_sub_reg64_reg64_imm32() {
+        disassembler_test!(
+            AArch64Assembler::sub_reg64_reg64_imm32,
+            |reg1: AArch64GeneralReg, reg2: AArch64GeneralReg, imm: i32| {
+                let reg1 = reg1.capstone_string(UsesSP);
+                let reg2 = reg2.capstone_string(UsesSP);
+                match imm {
+                    0..=0xFFF => format!("sub {reg1}, {reg2}, #0x{imm:x}"),
+                    _ => format!(
+                        "sub {reg1}, {reg2}, #0x{:x}, lsl #12\nsub {reg1}, {reg1}, #0x{:x}",
+                        imm >> 12,
+                        imm & 0xFFF
+                    ),
+                }
+            },
+            ALL_GENERAL_REGS,
+            ALL_GENERAL_REGS,
+            [0x123, 0xFFF, 0x12345]
+        );
+    }
+
     #[test]
     fn test_sub_reg64_reg64_reg64() {
         disassembler_test!(
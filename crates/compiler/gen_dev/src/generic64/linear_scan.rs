@@ -0,0 +1,280 @@
+//! Register assignment with a linear scan over the live intervals of a proc,
+//! as described by Poletto and Sarkar in "Linear Scan Register Allocation".
+//!
+//! The scan runs once per proc, before any code is generated for it.
+//! The storage manager then hands each symbol its assigned register when it claims one,
+//! and spills the symbols that the scan left on the stack before anything else.
+//! Anything the scan did not plan for, like temporaries, still gets a register on demand.
+use roc_collections::all::{MutMap, MutSet};
+use roc_module::symbol::Symbol;
+use roc_mono::liveness::LiveInterval;
+
+use super::RegTrait;
+
+/// How many of the registers that are handed out first are kept out of the scan.
+/// Temporaries of a single statement take these, so they don't evict assigned symbols.
+pub(crate) const SCRATCH_REGS: usize = 2;
+
+/// The registers that a linear scan picked for the symbols of one register class.
+#[derive(Debug, Clone)]
+pub(crate) struct Assignment<Reg> {
+    regs: MutMap<Symbol, Reg>,
+    /// Symbols that were scanned but did not get a register. They should live on the stack.
+    spilled: MutSet<Symbol>,
+}
+
+impl<Reg> Default for Assignment<Reg> {
+    fn default() -> Self {
+        Self {
+            regs: MutMap::default(),
+            spilled: MutSet::default(),
+        }
+    }
+}
+
+impl<Reg: RegTrait> Assignment<Reg> {
+    pub fn reg(&self, sym: &Symbol) -> Option<Reg> {
+        self.regs.get(sym).copied()
+    }
+
+    pub fn is_spilled(&self, sym: &Symbol) -> bool {
+        self.spilled.contains(sym)
+    }
+
+    /// Whether any symbol was assigned this register.
+    pub fn is_assigned(&self, reg: &Reg) -> bool {
+        self.regs.values().any(|assigned| assigned == reg)
+    }
+}
+
+/// The assignments for both register classes of a proc.
+#[derive(Debug, Clone)]
+pub(crate) struct RegisterAssignment<GeneralReg, FloatReg> {
+    pub general: Assignment<GeneralReg>,
+    pub float: Assignment<FloatReg>,
+}
+
+impl<GeneralReg, FloatReg> Default for RegisterAssignment<GeneralReg, FloatReg> {
+    fn default() -> Self {
+        Self {
+            general: Assignment::default(),
+            float: Assignment::default(),
+        }
+    }
+}
+
+/// Assigns registers from `pool` to the symbols of `intervals`, which must be sorted by their start.
+///
+/// The registers at the end of `pool` are preferred, like the free lists of the storage manager.
+/// Symbols that live across a call prefer callee saved registers, so they don't have to be
+/// saved around it, and all other symbols prefer caller saved registers, which are free to use.
+/// Results of calls that don't live across another call prefer `return_reg`,
+/// so they can stay where the call left them.
+/// `fixed` symbols already are in a register when the proc starts, like its arguments.
+/// Fixed symbols without a live interval keep their register for the whole proc.
+pub(crate) fn linear_scan<Reg: RegTrait>(
+    intervals: &[(Symbol, LiveInterval<'_>)],
+    pool: &[Reg],
+    callee_saved: impl Fn(&Reg) -> bool,
+    return_reg: Reg,
+    fixed: &[(Symbol, Reg)],
+) -> Assignment<Reg> {
+    let mut assignment = Assignment::default();
+    let mut free: std::vec::Vec<Reg> = pool.to_vec();
+    // The symbols that are in a register right now, with where their interval ends.
+    let mut active: std::vec::Vec<(u32, Symbol, Reg)> = std::vec::Vec::new();
+
+    let interval_of = |sym: &Symbol| {
+        intervals
+            .iter()
+            .find(|(interval_sym, _)| interval_sym == sym)
+            .map(|(_, interval)| *interval)
+    };
+
+    for (sym, reg) in fixed {
+        free.retain(|free_reg| free_reg != reg);
+        if let Some(interval) = interval_of(sym) {
+            active.push((interval.end, *sym, *reg));
+            assignment.regs.insert(*sym, *reg);
+        }
+    }
+    let is_fixed = |sym: &Symbol| fixed.iter().any(|(fixed_sym, _)| fixed_sym == sym);
+
+    for (sym, interval) in intervals {
+        if is_fixed(sym) {
+            continue;
+        }
+
+        // Registers are only reused after the statement that last uses them,
+        // because symbols are freed once their last statement is generated.
+        active.retain(|(end, _, reg)| {
+            let expired = *end < interval.start;
+            if expired {
+                free.push(*reg);
+            }
+            !expired
+        });
+
+        let preferred = if interval.crosses_call {
+            free.iter().rposition(&callee_saved)
+        } else if interval.defined_by_call && free.contains(&return_reg) {
+            free.iter().position(|reg| *reg == return_reg)
+        } else {
+            free.iter().rposition(|reg| !callee_saved(reg))
+        };
+        let index = preferred.or_else(|| free.len().checked_sub(1));
+
+        if let Some(index) = index {
+            let reg = free.remove(index);
+            active.push((interval.end, *sym, reg));
+            assignment.regs.insert(*sym, reg);
+            continue;
+        }
+
+        // Everything is taken: spill whichever symbol lives longest, freeing its register
+        // for the longest stretch of code.
+        let victim = active
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, active_sym, _))| !is_fixed(active_sym))
+            .max_by_key(|(_, (end, active_sym, _))| (*end, *active_sym))
+            .map(|(index, entry)| (index, *entry));
+
+        match victim {
+            Some((index, (end, victim_sym, reg))) if end > interval.end => {
+                active.remove(index);
+                assignment.regs.remove(&victim_sym);
+                assignment.spilled.insert(victim_sym);
+
+                active.push((interval.end, *sym, reg));
+                assignment.regs.insert(*sym, reg);
+            }
+            _ => {
+                assignment.spilled.insert(*sym);
+            }
+        }
+    }
+
+    assignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generic64::x86_64::X86_64GeneralReg::{self, *};
+    use roc_mono::layout::Layout;
+
+    fn interval(start: u32, end: u32, crosses_call: bool) -> LiveInterval<'static> {
+        LiveInterval {
+            start,
+            end,
+            layout: Layout::I64,
+            crosses_call,
+            defined_by_call: false,
+        }
+    }
+
+    fn is_callee_saved(reg: &X86_64GeneralReg) -> bool {
+        matches!(reg, RBX | R12 | R13 | R14 | R15)
+    }
+
+    const A: Symbol = Symbol::DEV_TMP;
+    const B: Symbol = Symbol::DEV_TMP2;
+    const C: Symbol = Symbol::DEV_TMP3;
+    const D: Symbol = Symbol::DEV_TMP4;
+
+    #[test]
+    fn reuses_registers_of_expired_intervals() {
+        let intervals = [
+            (A, interval(0, 1, false)),
+            (B, interval(1, 2, false)),
+            (C, interval(2, 3, false)),
+        ];
+        let assignment = linear_scan(&intervals, &[RAX, RCX], is_callee_saved, RCX, &[]);
+
+        // `A` is still used by the statement that defines `B`, so they can't share.
+        assert_ne!(assignment.reg(&A), assignment.reg(&B));
+        assert_eq!(assignment.reg(&A), assignment.reg(&C));
+        assert!(!assignment.is_spilled(&C));
+    }
+
+    #[test]
+    fn symbols_across_calls_prefer_callee_saved_registers() {
+        let intervals = [(A, interval(0, 5, true)), (B, interval(1, 2, false))];
+        let assignment = linear_scan(&intervals, &[RBX, RAX], is_callee_saved, RAX, &[]);
+
+        assert_eq!(assignment.reg(&A), Some(RBX));
+        assert_eq!(assignment.reg(&B), Some(RAX));
+    }
+
+    #[test]
+    fn call_results_prefer_the_return_register() {
+        let result = LiveInterval {
+            defined_by_call: true,
+            ..interval(1, 2, false)
+        };
+        let long_lived_result = LiveInterval {
+            defined_by_call: true,
+            ..interval(3, 6, true)
+        };
+        let intervals = [
+            (A, interval(0, 1, false)),
+            (B, result),
+            (C, long_lived_result),
+        ];
+        let assignment = linear_scan(&intervals, &[RBX, RAX, RCX], is_callee_saved, RAX, &[]);
+
+        assert_eq!(assignment.reg(&A), Some(RCX));
+        assert_eq!(assignment.reg(&B), Some(RAX));
+        // Living across another call matters more than where the value is returned.
+        assert_eq!(assignment.reg(&C), Some(RBX));
+    }
+
+    #[test]
+    fn spills_the_longest_living_symbol() {
+        let intervals = [
+            (A, interval(0, 10, false)),
+            (B, interval(1, 3, false)),
+            (C, interval(2, 4, false)),
+        ];
+        let assignment = linear_scan(&intervals, &[RAX, RCX], is_callee_saved, RCX, &[]);
+
+        assert!(assignment.is_spilled(&A));
+        assert_eq!(assignment.reg(&A), None);
+        assert!(assignment.reg(&B).is_some());
+        assert!(assignment.reg(&C).is_some());
+
+        // A symbol that outlives everything that is active is the one that gets spilled.
+        let intervals = [
+            (A, interval(0, 3, false)),
+            (B, interval(1, 3, false)),
+            (C, interval(2, 10, false)),
+        ];
+        let assignment = linear_scan(&intervals, &[RAX, RCX], is_callee_saved, RCX, &[]);
+
+        assert!(assignment.is_spilled(&C));
+        assert!(!assignment.is_spilled(&A));
+    }
+
+    #[test]
+    fn fixed_symbols_keep_their_registers() {
+        let intervals = [
+            (A, interval(0, 4, false)),
+            (B, interval(1, 2, false)),
+            (C, interval(3, 4, false)),
+        ];
+        // `D` has no interval, like the return pointer, so RDI is never handed out.
+        let assignment = linear_scan(
+            &intervals,
+            &[RDI, RSI, RAX],
+            is_callee_saved,
+            RAX,
+            &[(A, RSI), (D, RDI)],
+        );
+
+        assert_eq!(assignment.reg(&A), Some(RSI));
+        assert_eq!(assignment.reg(&B), Some(RAX));
+        assert_eq!(assignment.reg(&C), Some(RAX));
+        assert!(!assignment.is_assigned(&RDI));
+    }
+}
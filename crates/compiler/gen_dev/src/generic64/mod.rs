@@ -9,20 +9,23 @@ use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::{CallerProc, CodeGenHelp, HelperOp};
 use roc_mono::ir::{
-    BranchInfo, HigherOrderLowLevel, JoinPointId, ListLiteralElement, Literal, Param, ProcLayout,
-    SelfRecursive, Stmt,
+    BranchInfo, CrashTag, HigherOrderLowLevel, JoinPointId, ListLiteralElement, Literal,
+    LookupType, Param, ProcLayout, SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, LambdaName, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
     TagIdIntType, UnionLayout,
 };
+use roc_mono::liveness::LiveIntervals;
 use roc_mono::low_level::HigherOrder;
+use roc_region::all::Region;
 use roc_target::Target;
 use std::marker::PhantomData;
 
 pub(crate) mod aarch64;
 #[cfg(test)]
 mod disassembler_test_macro;
+pub(crate) mod linear_scan;
 pub(crate) mod storage;
 pub(crate) mod x86_64;
 
@@ -328,7 +331,11 @@ pub trait Assembler<GeneralReg: RegTrait, FloatReg: RegTrait>: Sized + Copy {
         src: GeneralReg,
     );
     fn mov_reg64_reg64(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg) {
-        Self::mov_reg_reg(buf, RegisterWidth::W64, dst, src);
+        // Moving a register onto itself would not change anything.
+        // Narrower moves are still emitted, because they zero or keep the upper bits.
+        if dst != src {
+            Self::mov_reg_reg(buf, RegisterWidth::W64, dst, src);
+        }
     }
     fn mov_reg32_reg32(buf: &mut Vec<'_, u8>, dst: GeneralReg, src: GeneralReg) {
         Self::mov_reg_reg(buf, RegisterWidth::W32, dst, src);
@@ -767,6 +774,8 @@ pub struct Backend64Bit<
     caller_procs: Vec<'a, CallerProc<'a>>,
     buf: Vec<'a, u8>,
     relocs: Vec<'a, Relocation>,
+    /// Where the code for each statement starts in `buf`, for debug info.
+    statements: Vec<'a, (u64, Region)>,
     proc_name: Option<String>,
     is_self_recursive: Option<SelfRecursive>,
 
@@ -776,6 +785,9 @@ pub struct Backend64Bit<
 
     literal_map: MutMap<Symbol, (*const Literal<'a>, *const InLayout<'a>)>,
     join_map: MutMap<JoinPointId, Vec<'a, (u64, u64)>>,
+    /// Join points whose body only jumps on to another join point.
+    /// Jumps to them are threaded straight to that join point, passing along the arguments at these indices.
+    join_forwards: MutMap<JoinPointId, (JoinPointId, Vec<'a, usize>)>,
 
     storage_manager: StorageManager<'a, 'r, GeneralReg, FloatReg, ASM, CC>,
 }
@@ -807,15 +819,73 @@ pub fn new_backend_64bit<
         is_self_recursive: None,
         buf: bumpalo::vec![in env.arena],
         relocs: bumpalo::vec![in env.arena],
+        statements: bumpalo::vec![in env.arena],
         last_seen_map: MutMap::default(),
         layout_map: MutMap::default(),
         free_map: MutMap::default(),
         literal_map: MutMap::default(),
         join_map: MutMap::default(),
+        join_forwards: MutMap::default(),
         storage_manager: storage::new_storage_manager(env, target),
     }
 }
 
+/// Checks if the body of a join point only jumps on to another join point with some of its parameters.
+/// If so, returns that join point and which parameter is passed as each of its arguments.
+fn join_forward<'a>(
+    arena: &'a bumpalo::Bump,
+    id: &JoinPointId,
+    parameters: &[Param<'a>],
+    body: &Stmt<'a>,
+) -> Option<(JoinPointId, Vec<'a, usize>)> {
+    let Stmt::Jump(target, args) = body else {
+        return None;
+    };
+    if target == id {
+        return None;
+    }
+
+    let mut positions = bumpalo::vec![in arena];
+    for arg in args.iter() {
+        let position = parameters.iter().position(|param| param.symbol == *arg)?;
+        positions.push(position);
+    }
+
+    Some((*target, positions))
+}
+
+/// Follows a jump with `arg_count` arguments through the join points that forward it.
+/// Returns the join point it ends up at, and which of the original arguments is passed as each of its arguments.
+fn thread_jump<'a>(
+    arena: &'a bumpalo::Bump,
+    join_forwards: &MutMap<JoinPointId, (JoinPointId, Vec<'a, usize>)>,
+    mut id: JoinPointId,
+    arg_count: usize,
+) -> (JoinPointId, Vec<'a, usize>) {
+    let mut positions: Vec<'a, usize> = (0..arg_count).collect_in(arena);
+    while let Some((target, forwarded)) = join_forwards.get(&id) {
+        positions = forwarded.iter().map(|i| positions[*i]).collect_in(arena);
+        id = *target;
+    }
+
+    (id, positions)
+}
+
+/// Checks if a statement always ends with an unconditional jump, by returning or jumping to a join point.
+/// Code directly after such a statement can never be reached from it.
+fn ends_in_jump(stmt: &Stmt<'_>) -> bool {
+    match stmt {
+        Stmt::Let(_, _, _, following, _) | Stmt::Refcounting(_, following) => {
+            ends_in_jump(following)
+        }
+        Stmt::Dbg { remainder, .. }
+        | Stmt::Expect { remainder, .. }
+        | Stmt::ExpectFx { remainder, .. } => ends_in_jump(remainder),
+        Stmt::Ret(..) | Stmt::Jump(_, _) => true,
+        Stmt::Switch { .. } | Stmt::Join { .. } | Stmt::Crash(_, _) => false,
+    }
+}
+
 macro_rules! quadword_and_smaller {
     () => {
         IntWidth::I64
@@ -892,8 +962,10 @@ impl<
         self.last_seen_map.clear();
         self.layout_map.clear();
         self.join_map.clear();
+        self.join_forwards.clear();
         self.free_map.clear();
         self.buf.clear();
+        self.statements.clear();
         self.storage_manager.reset();
     }
 
@@ -917,7 +989,12 @@ impl<
         &mut self.free_map
     }
 
-    fn finalize(&mut self) -> (Vec<u8>, Vec<Relocation>) {
+    fn assign_registers(&mut self, live_intervals: &LiveIntervals<'a>) {
+        self.storage_manager
+            .assign_registers(self.layout_interner, live_intervals);
+    }
+
+    fn finalize(&mut self) -> (Vec<'a, u8>, Vec<'a, Relocation>) {
         let mut out = bumpalo::vec![in self.env.arena];
 
         // Setup stack.
@@ -983,6 +1060,10 @@ impl<
         // Add function body.
         out.extend(&self.buf[..self.buf.len() - end_jmp_size]);
 
+        for (offset, _) in self.statements.iter_mut() {
+            *offset += setup_offset as u64;
+        }
+
         // Cleanup stack.
         CC::cleanup_stack(
             &mut out,
@@ -1018,6 +1099,17 @@ impl<
         (out, out_relocs)
     }
 
+    fn mark_statement(&mut self, region: Region) {
+        // The compiler gives the statements it made up no region; they belong to what came before.
+        if self.env.debug_sources.is_some() && region != Region::zero() {
+            self.statements.push((self.buf.len() as u64, region));
+        }
+    }
+
+    fn take_statements(&mut self) -> Vec<'a, (u64, Region)> {
+        std::mem::replace(&mut self.statements, bumpalo::vec![in self.env.arena])
+    }
+
     fn load_args(&mut self, args: &'a [(InLayout<'a>, Symbol)], ret_layout: &InLayout<'a>) {
         CC::load_args(
             &mut self.buf,
@@ -1201,9 +1293,12 @@ impl<
 
             // Build unconditional jump to the end of this switch.
             // Since we don't know the offset yet, set it to 0 and overwrite later.
-            let jmp_location = self.buf.len();
-            let jmp_offset = ASM::jmp_imm32(&mut self.buf, JUMP_PLACEHOLDER);
-            ret_jumps.push((jmp_location, jmp_offset));
+            // A branch that ends by jumping somewhere else already never gets here.
+            if !ends_in_jump(stmt) {
+                let jmp_location = self.buf.len();
+                let jmp_offset = ASM::jmp_imm32(&mut self.buf, JUMP_PLACEHOLDER);
+                ret_jumps.push((jmp_location, jmp_offset));
+            }
 
             // Overwrite the original jne with the correct offset.
             let end_offset = self.buf.len();
@@ -1269,13 +1364,38 @@ impl<
 
         self.join_map.insert(*id, bumpalo::vec![in self.env.arena]);
 
+        if let Some(forward) = join_forward(self.env.arena, id, parameters, body) {
+            self.join_forwards.insert(*id, forward);
+        }
+
+        // Every jump finds the symbols from before the joinpoint where they are now,
+        // so the body must be built from this state. Holding on to it also keeps their stack locations alive.
+        let join_storage = self.storage_manager.clone();
+        let join_literal_map = self.literal_map.clone();
+
         // Build remainder of function first. It is what gets run and jumps to join.
         self.build_stmt(layout_ids, remainder, ret_layout);
 
+        // The remainder may have moved symbols from before the joinpoint, and each path through it differently.
+        let remainder_storage = std::mem::replace(&mut self.storage_manager, join_storage);
+        self.literal_map = join_literal_map;
+        self.storage_manager
+            .update_stack_size(remainder_storage.stack_size());
+        self.storage_manager
+            .update_fn_call_stack_size(remainder_storage.fn_call_stack_size());
+        self.storage_manager
+            .used_callee_saved_regs
+            .extend(&remainder_storage.used_callee_saved_regs);
+
         let join_location = self.buf.len() as u64;
 
         // Build all statements in body.
-        self.build_stmt(layout_ids, body, ret_layout);
+        // If nothing jumps here, because all jumps were threaded past it or never built, it can be skipped.
+        if !self.join_map[id].is_empty() {
+            self.build_stmt(layout_ids, body, ret_layout);
+        } else {
+            self.free_unbuilt_symbols(body);
+        }
 
         // Overwrite the all jumps to the joinpoint with the correct offset.
         for (jmp_location, start_offset) in self
@@ -1298,22 +1418,146 @@ impl<
         arg_layouts: &[InLayout<'a>],
         _ret_layout: &InLayout<'a>,
     ) {
-        self.storage_manager
-            .setup_jump(self.layout_interner, &mut self.buf, id, args, arg_layouts);
+        // Thread the jump past join points that would only jump on again.
+        let (target, positions) = thread_jump(self.env.arena, &self.join_forwards, *id, args.len());
+        let threaded_args: Vec<'a, Symbol> = positions
+            .iter()
+            .map(|i| args[*i])
+            .collect_in(self.env.arena);
+        let threaded_layouts: Vec<'a, InLayout<'a>> = positions
+            .iter()
+            .map(|i| arg_layouts[*i])
+            .collect_in(self.env.arena);
+
+        // Passing the arguments in a different order can overwrite an argument that is still stored
+        // in the slot of another parameter. Going through the forwarding join points avoids that.
+        let (id, args, arg_layouts) = if self
+            .storage_manager
+            .jump_args_overlap(&target, &threaded_args)
+        {
+            (*id, args, arg_layouts)
+        } else {
+            (target, &threaded_args[..], &threaded_layouts[..])
+        };
+
+        self.storage_manager.setup_jump(
+            self.layout_interner,
+            &mut self.buf,
+            &id,
+            args,
+            arg_layouts,
+        );
 
         let jmp_location = self.buf.len();
         let start_offset = ASM::jmp_imm32(&mut self.buf, JUMP_PLACEHOLDER);
 
-        if let Some(vec) = self.join_map.get_mut(id) {
+        if let Some(vec) = self.join_map.get_mut(&id) {
             vec.push((jmp_location as u64, start_offset as u64))
         } else {
             internal_error!("Jump: unknown point specified to jump to: {:?}", id);
         }
     }
 
+    fn build_expect(
+        &mut self,
+        condition: Symbol,
+        region: Region,
+        lookups: &[Symbol],
+        variables: &[LookupType],
+        notify_parent: bool,
+    ) {
+        // The failure code is skipped most of the time, so nothing it moves around may be
+        // expected to still be in a register afterwards.
+        self.storage_manager.free_all_to_stack(&mut self.buf);
+
+        // this state is updated destructively by the failure code, which must not influence
+        // the code after the expect.
+        let mut base_storage = self.storage_manager.clone();
+        let base_literal_map = self.literal_map.clone();
+
+        let cond_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &condition);
+
+        // Jump over the failure code if the condition holds.
+        // Since we don't know the offset yet, set it to 0 and overwrite later.
+        let jne_location = self.buf.len();
+        let start_offset =
+            ASM::jne_reg64_imm64_imm32(&mut self.buf, &mut self.storage_manager, cond_reg, 0, 0);
+
+        self.expect_failed(condition, region, lookups, variables, notify_parent);
+
+        // Overwrite the original jne with the correct offset.
+        let mut tmp = bumpalo::vec![in self.env.arena];
+        let jne_offset = self.buf.len() - start_offset;
+        ASM::jne_reg64_imm64_imm32(
+            &mut tmp,
+            &mut self.storage_manager,
+            cond_reg,
+            0,
+            jne_offset as i32,
+        );
+        for (i, byte) in tmp.iter().enumerate() {
+            self.buf[jne_location + i] = *byte;
+        }
+
+        // Update important storage information to avoid overwrites.
+        base_storage.update_stack_size(self.storage_manager.stack_size());
+        base_storage.update_fn_call_stack_size(self.storage_manager.fn_call_stack_size());
+        base_storage
+            .used_callee_saved_regs
+            .extend(&self.storage_manager.used_callee_saved_regs);
+
+        self.storage_manager = base_storage;
+        self.literal_map = base_literal_map;
+    }
+
+    fn build_panic_unless(&mut self, condition: Symbol, message: &'a str) {
+        let cond_reg = self
+            .storage_manager
+            .load_to_general_reg(&mut self.buf, &condition);
+
+        // The panic never returns, so the code after it continues with the storage from before it.
+        let mut base_storage = self.storage_manager.clone();
+        let base_literal_map = self.literal_map.clone();
+
+        // Jump over the panic if the condition holds.
+        // Since we don't know the offset yet, set it to 0 and overwrite later.
+        let jne_location = self.buf.len();
+        let start_offset =
+            ASM::jne_reg64_imm64_imm32(&mut self.buf, &mut self.storage_manager, cond_reg, 0, 0);
+
+        let panic_message = self.debug_symbol("panic_message");
+        self.load_literal(&panic_message, &Layout::STR, &Literal::Str(message));
+        self.roc_panic(panic_message, CrashTag::Roc);
+
+        // Overwrite the original jne with the correct offset.
+        let mut tmp = bumpalo::vec![in self.env.arena];
+        let jne_offset = self.buf.len() - start_offset;
+        ASM::jne_reg64_imm64_imm32(
+            &mut tmp,
+            &mut self.storage_manager,
+            cond_reg,
+            0,
+            jne_offset as i32,
+        );
+        for (i, byte) in tmp.iter().enumerate() {
+            self.buf[jne_location + i] = *byte;
+        }
+
+        base_storage.update_stack_size(self.storage_manager.stack_size());
+        base_storage.update_fn_call_stack_size(self.storage_manager.fn_call_stack_size());
+        base_storage
+            .used_callee_saved_regs
+            .extend(&self.storage_manager.used_callee_saved_regs);
+
+        self.storage_manager = base_storage;
+        self.literal_map = base_literal_map;
+    }
+
     fn build_num_abs(&mut self, dst: &Symbol, src: &Symbol, layout: &InLayout<'a>) {
         match self.interner().get_repr(*layout) {
-            LayoutRepr::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
+            LayoutRepr::Builtin(Builtin::Int(IntWidth::I64)) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::abs_reg64_reg64(&mut self.buf, dst_reg, src_reg);
@@ -1328,7 +1572,86 @@ impl<
                 let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
                 ASM::abs_freg32_freg32(&mut self.buf, &mut self.relocs, dst_reg, src_reg);
             }
-            x => todo!("NumAbs: layout, {:?}", x),
+            LayoutRepr::Builtin(Builtin::Int(
+                int_width @ (IntWidth::I32 | IntWidth::I16 | IntWidth::I8),
+            )) => {
+                let register_width = match int_width {
+                    IntWidth::I32 => RegisterWidth::W32,
+                    IntWidth::I16 => RegisterWidth::W16,
+                    _ => RegisterWidth::W8,
+                };
+
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
+
+                // sign extend first, so the sign bit is where the 64-bit abs looks for it
+                self.storage_manager
+                    .with_tmp_general_reg(&mut self.buf, |_, buf, tmp_reg| {
+                        ASM::movsx_reg_reg(buf, register_width, tmp_reg, src_reg);
+                        ASM::abs_reg64_reg64(buf, dst_reg, tmp_reg);
+                    });
+            }
+            LayoutRepr::Builtin(Builtin::Int(
+                IntWidth::U64 | IntWidth::U32 | IntWidth::U16 | IntWidth::U8,
+            )) => {
+                // an unsigned integer is its own absolute value
+                let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
+                ASM::mov_reg64_reg64(&mut self.buf, dst_reg, src_reg);
+            }
+            LayoutRepr::Builtin(Builtin::Int(IntWidth::I128)) => {
+                let (src_offset, _) = self.storage_manager.stack_offset_and_size(src);
+
+                // with mask = -(x < 0), abs(x) = (x ^ mask) - mask
+                let mask_reg = self
+                    .storage_manager
+                    .claim_general_reg(&mut self.buf, &Symbol::DEV_TMP4);
+                ASM::mov_reg64_imm64(&mut self.buf, mask_reg, 0);
+                self.storage_manager
+                    .with_tmp_general_reg(&mut self.buf, |_, buf, tmp_reg| {
+                        ASM::mov_reg64_base32(buf, tmp_reg, src_offset + 8);
+                        ASM::signed_compare_reg64(
+                            buf,
+                            RegisterWidth::W64,
+                            CompareOperation::LessThan,
+                            mask_reg,
+                            tmp_reg,
+                            mask_reg,
+                        );
+                    });
+                ASM::neg_reg64_reg64(&mut self.buf, mask_reg, mask_reg);
+
+                self.sub_128bit(dst, IntWidth::I128, |buf, half, lhs_reg, rhs_reg| {
+                    ASM::mov_reg64_base32(buf, lhs_reg, src_offset + half);
+                    ASM::xor_reg64_reg64_reg64(buf, lhs_reg, lhs_reg, mask_reg);
+                    ASM::mov_reg64_reg64(buf, rhs_reg, mask_reg);
+                });
+
+                self.free_symbol(&Symbol::DEV_TMP4);
+            }
+            LayoutRepr::Builtin(Builtin::Int(IntWidth::U128)) => {
+                let (src_offset, size) = self.storage_manager.stack_offset_and_size(src);
+                let dst_offset = self.storage_manager.claim_stack_area_layout(
+                    self.layout_interner,
+                    *dst,
+                    *layout,
+                );
+
+                self.storage_manager.copy_to_stack_offset(
+                    &mut self.buf,
+                    size,
+                    src_offset,
+                    dst_offset,
+                );
+            }
+            LayoutRepr::Builtin(Builtin::Decimal) => self.build_fn_call(
+                dst,
+                bitcode::DEC_ABS.to_string(),
+                &[*src],
+                &[*layout],
+                layout,
+            ),
+            x => internal_error!("NumAbs: layout, {:?}", x),
         }
     }
 
@@ -1419,7 +1742,7 @@ impl<
         layout: InLayout<'a>,
     ) {
         match self.layout_interner.get_repr(layout) {
-            LayoutRepr::Builtin(Builtin::Int(width @ quadword_and_smaller!())) => {
+            LayoutRepr::Builtin(Builtin::Int(width)) => {
                 let intrinsic = bitcode::NUM_ADD_SATURATED_INT[width].to_string();
                 self.build_fn_call(&dst, intrinsic, &[src1, src2], &[layout, layout], &layout);
             }
@@ -1439,7 +1762,7 @@ impl<
                 let intrinsic = bitcode::DEC_ADD_SATURATED.to_string();
                 self.build_fn_call(&dst, intrinsic, &[src1, src2], &[layout, layout], &layout);
             }
-            x => todo!("NumAddSaturated: layout, {:?}", x),
+            x => internal_error!("NumAddSaturated: layout, {:?}", x),
         }
     }
 
@@ -1594,7 +1917,7 @@ impl<
                 let src2_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src2);
                 ASM::mul_freg32_freg32_freg32(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumMulWrap: layout, {:?}", x),
+            x => internal_error!("NumMulWrap: layout, {:?}", x),
         }
     }
 
@@ -1606,7 +1929,7 @@ impl<
         layout: InLayout<'a>,
     ) {
         match self.layout_interner.get_repr(layout) {
-            LayoutRepr::Builtin(Builtin::Int(width @ quadword_and_smaller!())) => {
+            LayoutRepr::Builtin(Builtin::Int(width)) => {
                 let intrinsic = bitcode::NUM_MUL_SATURATED_INT[width].to_string();
                 self.build_fn_call(&dst, intrinsic, &[src1, src2], &[layout, layout], &layout);
             }
@@ -1626,7 +1949,7 @@ impl<
                 let intrinsic = bitcode::DEC_MUL_SATURATED.to_string();
                 self.build_fn_call(&dst, intrinsic, &[src1, src2], &[layout, layout], &layout);
             }
-            x => todo!("NumMulSaturated: layout, {:?}", x),
+            x => internal_error!("NumMulSaturated: layout, {:?}", x),
         }
     }
 
@@ -1715,7 +2038,22 @@ impl<
                     layout,
                 );
             }
-            x => todo!("NumDiv: layout, {:?}", x),
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                // from compiler_rt
+                let function_name = match int_width {
+                    IntWidth::I128 => "__divti3",
+                    _ => "__udivti3",
+                };
+
+                self.build_fn_call(
+                    dst,
+                    function_name.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            x => internal_error!("NumDiv: layout, {:?}", x),
         }
     }
 
@@ -1734,7 +2072,7 @@ impl<
                 &[*layout, *layout],
                 layout,
             ),
-            x => todo!("NumDivCeilUnchecked: layout, {:?}", x),
+            x => internal_error!("NumDivCeilUnchecked: layout, {:?}", x),
         }
     }
 
@@ -1778,18 +2116,65 @@ impl<
                     src2_reg,
                 );
             }
-            x => todo!("NumDiv: layout, {:?}", x),
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                // from compiler_rt
+                let function_name = match int_width {
+                    IntWidth::I128 => "__modti3",
+                    _ => "__umodti3",
+                };
+
+                self.build_fn_call(
+                    dst,
+                    function_name.to_string(),
+                    &[*src1, *src2],
+                    &[*layout, *layout],
+                    layout,
+                );
+            }
+            x => internal_error!("NumRem: layout, {:?}", x),
         }
     }
 
     fn build_num_neg(&mut self, dst: &Symbol, src: &Symbol, layout: &InLayout<'a>) {
         match self.layout_interner.get_repr(*layout) {
-            LayoutRepr::Builtin(Builtin::Int(IntWidth::I64 | IntWidth::U64)) => {
+            LayoutRepr::Builtin(Builtin::Int(quadword_and_smaller!())) => {
                 let dst_reg = self.storage_manager.claim_general_reg(&mut self.buf, dst);
                 let src_reg = self.storage_manager.load_to_general_reg(&mut self.buf, src);
                 ASM::neg_reg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
-            x => todo!("NumNeg: layout, {:?}", x),
+            LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                // multiplying by -1 flips just the sign, also for zeroes and NaN
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self.storage_manager.load_to_float_reg(&mut self.buf, src);
+                self.storage_manager
+                    .with_tmp_float_reg(&mut self.buf, |_, buf, tmp_reg| match float_width {
+                        FloatWidth::F64 => {
+                            ASM::mov_freg64_imm64(buf, &mut self.relocs, tmp_reg, -1.0);
+                            ASM::mul_freg64_freg64_freg64(buf, dst_reg, src_reg, tmp_reg);
+                        }
+                        FloatWidth::F32 => {
+                            ASM::mov_freg32_imm32(buf, &mut self.relocs, tmp_reg, -1.0);
+                            ASM::mul_freg32_freg32_freg32(buf, dst_reg, src_reg, tmp_reg);
+                        }
+                    });
+            }
+            LayoutRepr::Builtin(Builtin::Decimal) => self.build_fn_call(
+                dst,
+                bitcode::DEC_NEGATE.to_string(),
+                &[*src],
+                &[*layout],
+                layout,
+            ),
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                let (src_offset, _) = self.storage_manager.stack_offset_and_size(src);
+
+                // -x = 0 - x
+                self.sub_128bit(dst, int_width, |buf, half, lhs_reg, rhs_reg| {
+                    ASM::mov_reg64_imm64(buf, lhs_reg, 0);
+                    ASM::mov_reg64_base32(buf, rhs_reg, src_offset + half);
+                });
+            }
+            x => internal_error!("NumNeg: layout, {:?}", x),
         }
     }
 
@@ -1846,7 +2231,16 @@ impl<
                     .load_to_general_reg(&mut self.buf, src2);
                 ASM::sub_reg64_reg64_reg64(&mut self.buf, dst_reg, src1_reg, src2_reg);
             }
-            x => todo!("NumSubWrap: layout, {:?}", x),
+            LayoutRepr::Builtin(Builtin::Int(int_width @ (IntWidth::I128 | IntWidth::U128))) => {
+                let (src1_offset, _) = self.storage_manager.stack_offset_and_size(src1);
+                let (src2_offset, _) = self.storage_manager.stack_offset_and_size(src2);
+
+                self.sub_128bit(dst, int_width, |buf, half, lhs_reg, rhs_reg| {
+                    ASM::mov_reg64_base32(buf, lhs_reg, src1_offset + half);
+                    ASM::mov_reg64_base32(buf, rhs_reg, src2_offset + half);
+                });
+            }
+            x => internal_error!("NumSubWrap: layout, {:?}", x),
         }
     }
 
@@ -2064,7 +2458,7 @@ impl<
 
                 ASM::mov_reg64_reg64(&mut self.buf, dst_reg, src_reg);
             }
-            x => todo!("Not: layout, {:?}", x),
+            x => internal_error!("Not: layout, {:?}", x),
         }
     }
 
@@ -2080,7 +2474,7 @@ impl<
             Layout::F64 => self.num_to_f64(dst, src, arg_layout),
             Layout::DEC => self.num_to_dec(dst, src, arg_layout),
 
-            other => todo!("NumToFrac: layout {other:?} is not Frac"),
+            other => internal_error!("NumToFrac: layout {other:?} is not Frac"),
         }
     }
 
@@ -3803,6 +4197,92 @@ impl<
         );
     }
 
+    fn build_copy_bytes(&mut self, dst: Symbol, src: Symbol, len: Symbol) {
+        // The loop moves the pointers along and counts down the length, so it works on copies.
+        let dst_cursor = self.debug_symbol("dst_cursor");
+        let src_cursor = self.debug_symbol("src_cursor");
+        let remaining = self.debug_symbol("remaining");
+        let byte = self.debug_symbol("byte");
+
+        for (copy, original) in [(dst_cursor, dst), (src_cursor, src), (remaining, len)] {
+            let original_reg = self
+                .storage_manager
+                .load_to_general_reg(&mut self.buf, &original);
+            let copy_reg = self.storage_manager.claim_general_reg(&mut self.buf, &copy);
+            ASM::mov_reg64_reg64(&mut self.buf, copy_reg, original_reg);
+        }
+
+        let buf = &mut self.buf;
+        let dst_reg = self.storage_manager.load_to_general_reg(buf, &dst_cursor);
+        let src_reg = self.storage_manager.load_to_general_reg(buf, &src_cursor);
+        let remaining_reg = self.storage_manager.load_to_general_reg(buf, &remaining);
+        let byte_reg = self.storage_manager.claim_general_reg(buf, &byte);
+
+        // Start at the check, so nothing is copied when the length is zero.
+        let jmp_location = buf.len();
+        let jmp_offset = ASM::jmp_imm32(buf, JUMP_PLACEHOLDER);
+
+        let loop_start = buf.len();
+        ASM::mov_reg8_mem8_offset32(buf, byte_reg, src_reg, 0);
+        ASM::mov_mem8_offset32_reg8(buf, dst_reg, 0, byte_reg);
+        ASM::add_reg64_reg64_imm32(buf, src_reg, src_reg, 1);
+        ASM::add_reg64_reg64_imm32(buf, dst_reg, dst_reg, 1);
+        ASM::sub_reg64_reg64_imm32(buf, remaining_reg, remaining_reg, 1);
+
+        let check = buf.len();
+        ASM::update_jmp_imm32_offset(buf, jmp_location as u64, jmp_offset as u64, check as u64);
+
+        // Jumps are relative to a point in the jne, which we find by first generating it into a tmp buffer.
+        let mut tmp = bumpalo::vec![in self.env.arena];
+        let base =
+            ASM::jne_reg64_imm64_imm32(&mut tmp, &mut self.storage_manager, remaining_reg, 0, 0);
+        let jne_offset = loop_start as i32 - (check + base) as i32;
+        ASM::jne_reg64_imm64_imm32(
+            &mut self.buf,
+            &mut self.storage_manager,
+            remaining_reg,
+            0,
+            jne_offset,
+        );
+
+        for sym in [dst_cursor, src_cursor, remaining, byte] {
+            self.free_symbol(&sym);
+        }
+    }
+
+    fn build_unreachable(&mut self, sym: &Symbol, layout: &InLayout<'a>) {
+        match self.layout_interner.get_repr(*layout) {
+            single_register_integers!() | pointer_layouts!() => {
+                let reg = self.storage_manager.claim_general_reg(&mut self.buf, sym);
+                ASM::mov_reg64_imm64(&mut self.buf, reg, 0);
+            }
+            single_register_floats!() => {
+                let reg = self.storage_manager.claim_float_reg(&mut self.buf, sym);
+                ASM::mov_freg64_imm64(&mut self.buf, &mut self.relocs, reg, 0.0);
+            }
+            _ if self.layout_interner.stack_size(*layout) == 0 => {
+                self.storage_manager.no_data(sym);
+            }
+            _ => {
+                let size = self.layout_interner.stack_size(*layout) as i32;
+                let base_offset = self.storage_manager.claim_stack_area_layout(
+                    self.layout_interner,
+                    *sym,
+                    *layout,
+                );
+
+                // stack areas are a multiple of 8 bytes wide
+                self.storage_manager
+                    .with_tmp_general_reg(&mut self.buf, |_, buf, reg| {
+                        ASM::mov_reg64_imm64(buf, reg, 0);
+                        for offset in (0..size).step_by(8) {
+                            ASM::mov_base32_reg64(buf, base_offset + offset, reg);
+                        }
+                    });
+            }
+        }
+    }
+
     fn build_ptr_clear_tag_id(&mut self, sym: Symbol, ptr: Symbol) {
         let buf = &mut self.buf;
 
@@ -4577,7 +5057,7 @@ impl<
                     self.create_array(sym, &Layout::U8, elements.into_bump_slice())
                 }
             }
-            _ => todo!("loading literal {:?} with layout {:?}", lit, layout),
+            _ => internal_error!("loading literal {:?} with layout {:?}", lit, layout),
         }
     }
 
@@ -4648,7 +5128,9 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.bitwise_128bit(dst, src1, src2, int_width, ASM::and_reg64_reg64_reg64)
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4668,7 +5150,9 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.bitwise_128bit(dst, src1, src2, int_width, ASM::or_reg64_reg64_reg64)
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4688,7 +5172,9 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => {
+                self.bitwise_128bit(dst, src1, src2, int_width, ASM::xor_reg64_reg64_reg64)
+            }
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4708,7 +5194,13 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => self.shift_128bit(
+                dst,
+                src1,
+                src2,
+                int_width,
+                &bitcode::NUM_SHIFT_LEFT[int_width],
+            ),
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4735,7 +5227,13 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => todo!(),
+            IntWidth::U128 | IntWidth::I128 => self.shift_128bit(
+                dst,
+                src1,
+                src2,
+                int_width,
+                &bitcode::NUM_SHIFT_RIGHT[int_width],
+            ),
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4802,21 +5300,13 @@ impl<
         let buf = &mut self.buf;
 
         match int_width {
-            IntWidth::U128 | IntWidth::I128 => {
-                let layout = match int_width {
-                    IntWidth::I128 => Layout::I128,
-                    IntWidth::U128 => Layout::U128,
-                    _ => unreachable!(),
-                };
-
-                self.build_fn_call(
-                    dst,
-                    bitcode::NUM_SHIFT_RIGHT_ZERO_FILL[int_width].to_string(),
-                    &[*src1, *src2],
-                    &[layout, layout],
-                    &layout,
-                );
-            }
+            IntWidth::U128 | IntWidth::I128 => self.shift_128bit(
+                dst,
+                src1,
+                src2,
+                int_width,
+                &bitcode::NUM_SHIFT_RIGHT_ZERO_FILL[int_width],
+            ),
             _ => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
                 let src1_reg = self.storage_manager.load_to_general_reg(buf, src1);
@@ -4857,34 +5347,54 @@ impl<
         let buf = &mut self.buf;
 
         match (source, target) {
-            (U128, U64) => {
+            (U128 | I128, U64 | I64 | U32 | I32 | U16 | I16 | U8 | I8) => {
                 let dst_reg = self.storage_manager.claim_general_reg(buf, dst);
 
                 let (offset, _size) = self.storage_manager.stack_offset_and_size(src);
 
-                ASM::mov_reg64_base32(buf, dst_reg, offset + 8);
+                // the lower 8 bytes hold every bit that survives the cast
+                ASM::mov_reg64_base32(buf, dst_reg, offset);
 
                 return;
             }
-            (U64, U128) => {
-                let src_reg = self.storage_manager.load_to_general_reg(buf, src);
+            (U64 | I64 | U32 | I32 | U16 | I16 | U8 | I8, U128 | I128) => {
+                // first widen to 64 bits, so only the upper 8 bytes are left to fill in
+                let lower = self.debug_symbol("lower");
+                let lower_width = if source.is_signed() { I64 } else { U64 };
+                self.build_num_int_cast(&lower, src, source, lower_width);
+
+                let lower_reg = self
+                    .storage_manager
+                    .load_to_general_reg(&mut self.buf, &lower);
 
                 let base_offset = self.storage_manager.claim_stack_area_layout(
                     self.layout_interner,
                     *dst,
-                    Layout::U128,
+                    Layout::from_int_width(target),
                 );
 
                 let tmp = Symbol::DEV_TMP;
-                let tmp_reg = self.storage_manager.claim_general_reg(buf, &tmp);
+                let tmp_reg = self.storage_manager.claim_general_reg(&mut self.buf, &tmp);
 
-                // move a zero into the lower 8 bytes
-                ASM::mov_reg64_imm64(buf, tmp_reg, 0x0);
-                ASM::mov_base32_reg64(buf, base_offset + 8, tmp_reg);
+                ASM::mov_reg64_imm64(&mut self.buf, tmp_reg, 0x0);
+                if source.is_signed() {
+                    // the upper 8 bytes are all ones for a negative number: -(lower < 0)
+                    ASM::signed_compare_reg64(
+                        &mut self.buf,
+                        RegisterWidth::W64,
+                        CompareOperation::LessThan,
+                        tmp_reg,
+                        lower_reg,
+                        tmp_reg,
+                    );
+                    ASM::neg_reg64_reg64(&mut self.buf, tmp_reg, tmp_reg);
+                }
 
-                ASM::mov_base32_reg64(buf, base_offset, src_reg);
+                ASM::mov_base32_reg64(&mut self.buf, base_offset, lower_reg);
+                ASM::mov_base32_reg64(&mut self.buf, base_offset + 8, tmp_reg);
 
                 self.free_symbol(&tmp);
+                self.free_symbol(&lower);
 
                 return;
             }
@@ -4915,51 +5425,27 @@ impl<
                 4 => ASM::mov_reg32_reg32(buf, dst_reg, src_reg),
                 2 => ASM::mov_reg16_reg16(buf, dst_reg, src_reg),
                 1 => ASM::mov_reg8_reg8(buf, dst_reg, src_reg),
-                _ => todo!("int cast from {source:?} to {target:?}"),
+                _ => internal_error!("128-bit int casts are handled above"),
             }
         } else {
-            match (source, target) {
-                // -- CASTING UP --
-                (I8 | U8, U16 | U32 | U64) => {
-                    // zero  out the register
-                    ASM::xor_reg64_reg64_reg64(buf, dst_reg, dst_reg, dst_reg);
-
-                    // move the 8-bit integer
-                    ASM::mov_reg_reg(buf, RegisterWidth::W8, dst_reg, src_reg);
-                }
-                (U16, U32 | U64) => {
-                    // zero  out the register
-                    ASM::xor_reg64_reg64_reg64(buf, dst_reg, dst_reg, dst_reg);
-
-                    // move the 16-bit integer
-                    ASM::mov_reg_reg(buf, RegisterWidth::W16, dst_reg, src_reg);
-                }
-                (U32, U64) => {
-                    // zero  out the register
-                    ASM::xor_reg64_reg64_reg64(buf, dst_reg, dst_reg, dst_reg);
+            let register_width = |width: IntWidth| match width.stack_size() {
+                4 => RegisterWidth::W32,
+                2 => RegisterWidth::W16,
+                _ => RegisterWidth::W8,
+            };
 
-                    // move the 32-bit integer
-                    ASM::mov_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg);
-                }
-                (I8, I16 | I32 | I64) => {
-                    ASM::movsx_reg_reg(buf, RegisterWidth::W8, dst_reg, src_reg)
+            if target.stack_size() > source.stack_size() {
+                // -- CASTING UP --
+                // like LLVM's intcast, the signedness of the source decides how to extend
+                if source.is_signed() {
+                    ASM::movsx_reg_reg(buf, register_width(source), dst_reg, src_reg);
+                } else {
+                    ASM::movzx_reg_reg(buf, register_width(source), dst_reg, src_reg);
                 }
-                (I16, I32 | I64) => ASM::movsx_reg_reg(buf, RegisterWidth::W16, dst_reg, src_reg),
-                (I32, I64) => ASM::movsx_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg),
+            } else {
                 // -- CASTING DOWN --
-                (U64 | I64, I32 | U32) => {
-                    // move as a 32-bit integer (leaving any other bits behind)
-                    ASM::mov_reg_reg(buf, RegisterWidth::W32, dst_reg, src_reg);
-                }
-                (U64 | I64 | U32 | I32, I16 | U16) => {
-                    // move as a 16-bit integer (leaving any other bits behind)
-                    ASM::mov_reg_reg(buf, RegisterWidth::W16, dst_reg, src_reg);
-                }
-                (U64 | I64 | U32 | I32 | U16 | I16, I8 | U8) => {
-                    // move as an 8-bit integer (leaving any other bits behind)
-                    ASM::mov_reg_reg(buf, RegisterWidth::W8, dst_reg, src_reg);
-                }
-                _ => todo!("int cast from {source:?} to {target:?}"),
+                // move as the smaller integer (leaving any other bits behind)
+                ASM::mov_reg_reg(buf, register_width(target), dst_reg, src_reg);
             }
         }
     }
@@ -5045,7 +5531,20 @@ impl<
                 let int_width = arg_layout.to_int_width();
                 self.build_int_to_float_cast(dst, src, int_width, FloatWidth::F32);
             }
-            arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F32),
+            LayoutRepr::Builtin(Builtin::Decimal) => {
+                // there is no direct conversion to F32, so go through F64
+                let f64_sym = self.debug_symbol("dec_as_f64");
+                self.num_to_f64(&f64_sym, src, arg_layout);
+
+                let dst_reg = self.storage_manager.claim_float_reg(&mut self.buf, dst);
+                let src_reg = self
+                    .storage_manager
+                    .load_to_float_reg(&mut self.buf, &f64_sym);
+                ASM::to_float_freg32_freg64(&mut self.buf, dst_reg, src_reg);
+
+                self.free_symbol(&f64_sym);
+            }
+            arg => internal_error!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F32),
         }
     }
 
@@ -5070,7 +5569,16 @@ impl<
                 let int_width = arg_layout.to_int_width();
                 self.build_int_to_float_cast(dst, src, int_width, FloatWidth::F64);
             }
-            arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F64),
+            LayoutRepr::Builtin(Builtin::Decimal) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_TO_F64.to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    &Layout::F64,
+                );
+            }
+            arg => internal_error!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::F64),
         }
     }
 
@@ -5085,9 +5593,142 @@ impl<
                     &Layout::DEC,
                 );
             }
+            LayoutRepr::Builtin(Builtin::Float(float_width)) => {
+                self.build_fn_call(
+                    dst,
+                    bitcode::DEC_FROM_FLOAT[float_width].to_string(),
+                    &[*src],
+                    &[*arg_layout],
+                    &Layout::DEC,
+                );
+            }
+            LayoutRepr::Builtin(Builtin::Decimal) => {
+                let (src_offset, size) = self.storage_manager.stack_offset_and_size(src);
+                let dst_offset = self.storage_manager.claim_stack_area_layout(
+                    self.layout_interner,
+                    *dst,
+                    Layout::DEC,
+                );
+
+                self.storage_manager.copy_to_stack_offset(
+                    &mut self.buf,
+                    size,
+                    src_offset,
+                    dst_offset,
+                );
+            }
+            arg => internal_error!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::DEC),
+        }
+    }
+
+    /// Applies a bitwise operation to the lower and upper halves of two 128-bit integers.
+    fn bitwise_128bit(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+        operation: fn(&mut Vec<'a, u8>, GeneralReg, GeneralReg, GeneralReg),
+    ) {
+        let buf = &mut self.buf;
+
+        let (src1_offset, _) = self.storage_manager.stack_offset_and_size(src1);
+        let (src2_offset, _) = self.storage_manager.stack_offset_and_size(src2);
+        let dst_offset = self.storage_manager.claim_stack_area_layout(
+            self.layout_interner,
+            *dst,
+            Layout::from_int_width(int_width),
+        );
+
+        let tmp1_reg = self
+            .storage_manager
+            .claim_general_reg(buf, &Symbol::DEV_TMP);
+        let tmp2_reg = self
+            .storage_manager
+            .claim_general_reg(buf, &Symbol::DEV_TMP2);
 
-            arg => todo!("NumToFrac: layout, arg {arg:?}, ret {:?}", Layout::DEC),
+        for half in [0, 8] {
+            ASM::mov_reg64_base32(buf, tmp1_reg, src1_offset + half);
+            ASM::mov_reg64_base32(buf, tmp2_reg, src2_offset + half);
+            operation(buf, tmp1_reg, tmp1_reg, tmp2_reg);
+            ASM::mov_base32_reg64(buf, dst_offset + half, tmp1_reg);
         }
+
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
+    /// Subtracts two 128-bit integers one 64-bit half at a time, wrapping on overflow.
+    /// `load_halves` puts the lower (offset 0) or upper (offset 8) halves of the left and
+    /// right hand side into the two registers it is given.
+    fn sub_128bit<F>(&mut self, dst: &Symbol, int_width: IntWidth, load_halves: F)
+    where
+        F: Fn(&mut Vec<'a, u8>, i32, GeneralReg, GeneralReg),
+    {
+        let dst_offset = self.storage_manager.claim_stack_area_layout(
+            self.layout_interner,
+            *dst,
+            Layout::from_int_width(int_width),
+        );
+
+        let buf = &mut self.buf;
+
+        let lhs_reg = self
+            .storage_manager
+            .claim_general_reg(buf, &Symbol::DEV_TMP);
+        let rhs_reg = self
+            .storage_manager
+            .claim_general_reg(buf, &Symbol::DEV_TMP2);
+        let borrow_reg = self
+            .storage_manager
+            .claim_general_reg(buf, &Symbol::DEV_TMP3);
+
+        // the lower halves borrow one from the upper halves when they underflow
+        load_halves(buf, 0, lhs_reg, rhs_reg);
+        ASM::unsigned_compare_reg64(
+            buf,
+            RegisterWidth::W64,
+            CompareOperation::LessThan,
+            borrow_reg,
+            lhs_reg,
+            rhs_reg,
+        );
+        ASM::sub_reg64_reg64_reg64(buf, lhs_reg, lhs_reg, rhs_reg);
+        ASM::mov_base32_reg64(buf, dst_offset, lhs_reg);
+
+        load_halves(buf, 8, lhs_reg, rhs_reg);
+        ASM::sub_reg64_reg64_reg64(buf, lhs_reg, lhs_reg, rhs_reg);
+        ASM::sub_reg64_reg64_reg64(buf, lhs_reg, lhs_reg, borrow_reg);
+        ASM::mov_base32_reg64(buf, dst_offset + 8, lhs_reg);
+
+        self.free_symbol(&Symbol::DEV_TMP);
+        self.free_symbol(&Symbol::DEV_TMP2);
+        self.free_symbol(&Symbol::DEV_TMP3);
+    }
+
+    /// Shifts a 128-bit integer with a zig builtin. The shift amount is a U8, which we
+    /// zero-extend first because zig expects that of the caller.
+    fn shift_128bit(
+        &mut self,
+        dst: &Symbol,
+        src1: &Symbol,
+        src2: &Symbol,
+        int_width: IntWidth,
+        intrinsic: &str,
+    ) {
+        let amount = self.debug_symbol("shift_amount");
+        self.build_num_int_cast(&amount, src2, IntWidth::U8, IntWidth::U32);
+
+        let layout = Layout::from_int_width(int_width);
+        self.build_fn_call(
+            dst,
+            intrinsic.to_string(),
+            &[*src1, amount],
+            &[layout, Layout::U8],
+            &layout,
+        );
+
+        self.free_symbol(&amount);
     }
 
     fn compare_128bit(
@@ -5171,7 +5812,7 @@ impl<
             LayoutRepr::Builtin(Builtin::Decimal) => {
                 self.compare_128bit(op, dst, src1, src2, IntWidth::I128);
             }
-            x => todo!("NumLt: layout, {:?}", x),
+            x => internal_error!("NumLt: layout, {:?}", x),
         }
     }
 
@@ -5682,3 +6323,62 @@ macro_rules! pointer_layouts {
             | LayoutRepr::FunctionPointer(_)
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params<'a>(arena: &'a bumpalo::Bump, symbols: &[Symbol]) -> &'a [Param<'a>] {
+        arena.alloc_slice_fill_iter(symbols.iter().map(|symbol| Param {
+            symbol: *symbol,
+            layout: Layout::U64,
+        }))
+    }
+
+    #[test]
+    fn join_forward_with_reordered_arguments() {
+        let arena = bumpalo::Bump::new();
+        let outer = JoinPointId(Symbol::DEV_TMP);
+        let inner = JoinPointId(Symbol::DEV_TMP2);
+        let (a, b, c) = (Symbol::DEV_TMP3, Symbol::DEV_TMP4, Symbol::DEV_TMP5);
+
+        // join inner a b c = jump outer c a
+        let parameters = params(&arena, &[a, b, c]);
+        let body = Stmt::Jump(outer, arena.alloc([c, a]));
+        let (target, positions) = join_forward(&arena, &inner, parameters, &body).unwrap();
+        assert_eq!(target, outer);
+        assert_eq!(positions.as_slice(), &[2, 0]);
+
+        // Jumps that pass anything other than parameters can't be threaded.
+        let body = Stmt::Jump(outer, arena.alloc([c, Symbol::RET_POINTER]));
+        assert!(join_forward(&arena, &inner, parameters, &body).is_none());
+
+        // Neither can a join point that jumps to itself.
+        let body = Stmt::Jump(inner, arena.alloc([b, a, c]));
+        assert!(join_forward(&arena, &inner, parameters, &body).is_none());
+    }
+
+    #[test]
+    fn thread_jump_through_reordering_join_points() {
+        let arena = bumpalo::Bump::new();
+        let first = JoinPointId(Symbol::DEV_TMP);
+        let second = JoinPointId(Symbol::DEV_TMP2);
+        let last = JoinPointId(Symbol::DEV_TMP3);
+
+        let mut join_forwards = MutMap::default();
+        // join first x y z = jump second z x y
+        join_forwards.insert(first, (second, bumpalo::vec![in &arena; 2, 0, 1]));
+        // join second x y z = jump last y x
+        join_forwards.insert(second, (last, bumpalo::vec![in &arena; 1, 0]));
+
+        // jump first a b c = jump second c a b = jump last a c
+        let (target, positions) = thread_jump(&arena, &join_forwards, first, 3);
+        assert_eq!(target, last);
+        assert_eq!(positions.as_slice(), &[0, 2]);
+
+        // Join points that aren't forwarded are jumped to directly.
+        let (target, positions) = thread_jump(&arena, &join_forwards, last, 2);
+        assert_eq!(target, last);
+        assert_eq!(positions.as_slice(), &[0, 1]);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    generic64::{Assembler, CallConv, RegTrait},
+    generic64::{
+        linear_scan::{linear_scan, RegisterAssignment, SCRATCH_REGS},
+        Assembler, CallConv, RegTrait,
+    },
     pointer_layouts, sign_extended_int_builtins, single_register_floats,
     single_register_int_builtins, single_register_integers, single_register_layouts, Env,
 };
@@ -13,6 +16,7 @@ use roc_mono::{
     layout::{
         Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner, UnionLayout,
     },
+    liveness::LiveIntervals,
 };
 use roc_target::Target;
 use std::cmp::max;
@@ -113,11 +117,19 @@ pub struct StorageManager<
     float_free_regs: Vec<'a, FloatReg>,
 
     // The last major thing we need is a way to decide what reg to free when all of them are full.
-    // Theoretically we want a basic lru cache for the currently loaded symbols.
-    // For now just a vec of used registers and the symbols they contain.
+    // These are the used registers and the symbols they contain, from least to most recently used.
+    // When we run out of registers, the one to spill is picked with `spill_candidate`.
     general_used_regs: Vec<'a, (GeneralReg, Symbol)>,
     float_used_regs: Vec<'a, (FloatReg, Symbol)>,
 
+    // Where the live interval of each symbol in the current procedure ends.
+    // Shared between the copies of the storage made for branches.
+    live_until: Rc<MutMap<Symbol, u32>>,
+
+    // The registers that a linear scan over the current procedure picked for its symbols.
+    // Shared between the copies of the storage made for branches.
+    assignment: Rc<RegisterAssignment<GeneralReg, FloatReg>>,
+
     pub(crate) used_callee_saved_regs: UsedCalleeRegisters<GeneralReg, FloatReg>,
 
     free_stack_chunks: Vec<'a, (i32, u32)>,
@@ -153,6 +165,8 @@ pub fn new_storage_manager<
         used_callee_saved_regs: UsedCalleeRegisters::default(),
         float_free_regs: bumpalo::vec![in env.arena],
         float_used_regs: bumpalo::vec![in env.arena],
+        live_until: Rc::default(),
+        assignment: Rc::default(),
         free_stack_chunks: bumpalo::vec![in env.arena],
         stack_size: 0,
         fn_call_stack_size: 0,
@@ -227,12 +241,80 @@ impl<
         self.float_used_regs.clear();
         self.float_free_regs
             .extend_from_slice(CC::FLOAT_DEFAULT_FREE_REGS);
+        self.live_until = Rc::default();
+        self.assignment = Rc::default();
         self.used_callee_saved_regs.clear();
         self.free_stack_chunks.clear();
         self.stack_size = 0;
         self.fn_call_stack_size = 0;
     }
 
+    /// Assigns registers to the symbols of the current procedure with a linear scan over their live intervals.
+    /// This must be called once the arguments are loaded, so that they keep the registers they are passed in.
+    pub fn assign_registers(
+        &mut self,
+        layout_interner: &STLayoutInterner<'a>,
+        live_intervals: &LiveIntervals<'a>,
+    ) {
+        let mut general_intervals = std::vec::Vec::new();
+        let mut float_intervals = std::vec::Vec::new();
+        for (sym, interval) in live_intervals.by_start() {
+            let mut layout = interval.layout;
+            while let LayoutRepr::LambdaSet(lambda_set) = layout_interner.get_repr(layout) {
+                layout = lambda_set.runtime_representation();
+            }
+
+            match layout_interner.get_repr(layout) {
+                single_register_integers!() | pointer_layouts!() => {
+                    general_intervals.push((sym, interval))
+                }
+                single_register_floats!() => float_intervals.push((sym, interval)),
+                _ => {}
+            }
+        }
+
+        // The registers that are handed out first are left to temporaries.
+        let general_pool = CC::GENERAL_DEFAULT_FREE_REGS;
+        let general_pool = &general_pool[..general_pool.len().saturating_sub(SCRATCH_REGS)];
+        let float_pool = CC::FLOAT_DEFAULT_FREE_REGS;
+        let float_pool = &float_pool[..float_pool.len().saturating_sub(SCRATCH_REGS)];
+
+        let general_fixed: std::vec::Vec<_> = self
+            .general_used_regs
+            .iter()
+            .map(|(reg, sym)| (*sym, *reg))
+            .collect();
+        let float_fixed: std::vec::Vec<_> = self
+            .float_used_regs
+            .iter()
+            .map(|(reg, sym)| (*sym, *reg))
+            .collect();
+
+        self.assignment = Rc::new(RegisterAssignment {
+            general: linear_scan(
+                &general_intervals,
+                general_pool,
+                CC::general_callee_saved,
+                CC::GENERAL_RETURN_REGS[0],
+                &general_fixed,
+            ),
+            float: linear_scan(
+                &float_intervals,
+                float_pool,
+                CC::float_callee_saved,
+                CC::FLOAT_RETURN_REGS[0],
+                &float_fixed,
+            ),
+        });
+
+        let live_until = live_intervals
+            .by_start()
+            .into_iter()
+            .map(|(sym, interval)| (sym, interval.end))
+            .collect();
+        self.live_until = Rc::new(live_until);
+    }
+
     pub fn stack_size(&self) -> u32 {
         self.stack_size
     }
@@ -249,16 +331,30 @@ impl<
         )
     }
 
-    /// Get a general register from the free list.
+    /// Get a general register from the free list, preferably the one assigned to `sym`.
+    /// Other requests prefer registers that were not assigned to any symbol.
     /// Will free data to the stack if necessary to get the register.
-    fn get_general_reg(&mut self, buf: &mut Vec<'a, u8>) -> GeneralReg {
-        if let Some(reg) = self.general_free_regs.pop() {
+    fn get_general_reg(&mut self, buf: &mut Vec<'a, u8>, sym: Option<&Symbol>) -> GeneralReg {
+        let assignment = &self.assignment.general;
+        let index = pick_free_reg(
+            &self.general_free_regs,
+            sym.and_then(|sym| assignment.reg(sym)),
+            |reg| assignment.is_assigned(reg),
+        );
+
+        if let Some(index) = index {
+            let reg = self.general_free_regs.remove(index);
             if CC::general_callee_saved(&reg) {
                 self.used_callee_saved_regs.insert_general(reg);
             }
             reg
         } else if !self.general_used_regs.is_empty() {
-            let (reg, sym) = self.general_used_regs.remove(0);
+            let index = spill_candidate(
+                &self.live_until,
+                |sym| self.assignment.general.is_spilled(sym),
+                &self.general_used_regs,
+            );
+            let (reg, sym) = self.general_used_regs.remove(index);
             self.free_to_stack(buf, &sym, General(reg));
             reg
         } else {
@@ -266,16 +362,30 @@ impl<
         }
     }
 
-    /// Get a float register from the free list.
+    /// Get a float register from the free list, preferably the one assigned to `sym`.
+    /// Other requests prefer registers that were not assigned to any symbol.
     /// Will free data to the stack if necessary to get the register.
-    fn get_float_reg(&mut self, buf: &mut Vec<'a, u8>) -> FloatReg {
-        if let Some(reg) = self.float_free_regs.pop() {
+    fn get_float_reg(&mut self, buf: &mut Vec<'a, u8>, sym: Option<&Symbol>) -> FloatReg {
+        let assignment = &self.assignment.float;
+        let index = pick_free_reg(
+            &self.float_free_regs,
+            sym.and_then(|sym| assignment.reg(sym)),
+            |reg| assignment.is_assigned(reg),
+        );
+
+        if let Some(index) = index {
+            let reg = self.float_free_regs.remove(index);
             if CC::float_callee_saved(&reg) {
                 self.used_callee_saved_regs.insert_float(reg);
             }
             reg
         } else if !self.float_used_regs.is_empty() {
-            let (reg, sym) = self.float_used_regs.remove(0);
+            let index = spill_candidate(
+                &self.live_until,
+                |sym| self.assignment.float.is_spilled(sym),
+                &self.float_used_regs,
+            );
+            let (reg, sym) = self.float_used_regs.remove(index);
             self.free_to_stack(buf, &sym, Float(reg));
             reg
         } else {
@@ -291,7 +401,7 @@ impl<
             None,
             "Symbol {sym:?} is already in the storage map!"
         );
-        let reg = self.get_general_reg(buf);
+        let reg = self.get_general_reg(buf, Some(sym));
         self.general_used_regs.push((reg, *sym));
         self.symbol_storage_map.insert(*sym, Reg(General(reg)));
         reg
@@ -301,7 +411,7 @@ impl<
     /// They symbol should not already have storage.
     pub fn claim_float_reg(&mut self, buf: &mut Vec<'a, u8>, sym: &Symbol) -> FloatReg {
         debug_assert_eq!(self.symbol_storage_map.get(sym), None);
-        let reg = self.get_float_reg(buf);
+        let reg = self.get_float_reg(buf, Some(sym));
         self.float_used_regs.push((reg, *sym));
         self.symbol_storage_map.insert(*sym, Reg(Float(reg)));
         reg
@@ -314,12 +424,11 @@ impl<
         buf: &mut Vec<'a, u8>,
         callback: F,
     ) {
-        let reg = self.get_general_reg(buf);
+        let reg = self.get_general_reg(buf, None);
         callback(self, buf, reg);
         self.general_free_regs.push(reg);
     }
 
-    #[allow(dead_code)]
     /// This claims a temporary float register and enables is used in the passed in function.
     /// Temporary registers are not safe across call instructions.
     pub fn with_tmp_float_reg<F: FnOnce(&mut Self, &mut Vec<'a, u8>, FloatReg)>(
@@ -327,7 +436,7 @@ impl<
         buf: &mut Vec<'a, u8>,
         callback: F,
     ) {
-        let reg = self.get_float_reg(buf);
+        let reg = self.get_float_reg(buf, None);
         callback(self, buf, reg);
         self.float_free_regs.push(reg);
     }
@@ -345,6 +454,7 @@ impl<
                 ..
             }) => {
                 self.symbol_storage_map.insert(*sym, storage);
+                mark_recently_used(&mut self.general_used_regs, reg);
                 reg
             }
             Reg(Float(_))
@@ -359,7 +469,7 @@ impl<
                 base_offset,
             }) => {
                 debug_assert_eq!(base_offset % 8, 0);
-                let reg = self.get_general_reg(buf);
+                let reg = self.get_general_reg(buf, Some(sym));
                 ASM::mov_reg64_base32(buf, reg, base_offset);
                 self.general_used_regs.push((reg, *sym));
                 self.symbol_storage_map.insert(
@@ -376,7 +486,7 @@ impl<
                 size,
                 sign_extend,
             }) => {
-                let reg = self.get_general_reg(buf);
+                let reg = self.get_general_reg(buf, Some(sym));
 
                 let register_width = match size {
                     8 => RegisterWidth::W64,
@@ -420,6 +530,7 @@ impl<
                 ..
             }) => {
                 self.symbol_storage_map.insert(*sym, storage);
+                mark_recently_used(&mut self.float_used_regs, reg);
                 reg
             }
             Reg(General(_))
@@ -434,7 +545,7 @@ impl<
                 base_offset,
             }) => {
                 debug_assert_eq!(base_offset % 8, 0);
-                let reg = self.get_float_reg(buf);
+                let reg = self.get_float_reg(buf, Some(sym));
                 ASM::mov_freg64_base32(buf, reg, base_offset);
                 self.float_used_regs.push((reg, *sym));
                 self.symbol_storage_map.insert(
@@ -449,25 +560,16 @@ impl<
             Stack(ReferencedPrimitive {
                 base_offset, size, ..
             }) => {
-                if base_offset % 8 == 0 && size == 8 {
-                    // The primitive is aligned and the data is exactly 8 bytes, treat it like regular stack.
-                    let reg = self.get_float_reg(buf);
-                    ASM::mov_freg64_base32(buf, reg, base_offset);
-                    self.float_used_regs.push((reg, *sym));
-                    self.symbol_storage_map.insert(*sym, Reg(Float(reg)));
-                    self.free_reference(sym);
-                    reg
-                } else if base_offset % 4 == 0 && size == 4 {
-                    // The primitive is aligned and the data is exactly 8 bytes, treat it like regular stack.
-                    let reg = self.get_float_reg(buf);
-                    ASM::mov_freg32_base32(buf, reg, base_offset);
-                    self.float_used_regs.push((reg, *sym));
-                    self.symbol_storage_map.insert(*sym, Reg(Float(reg)));
-                    self.free_reference(sym);
-                    reg
-                } else {
-                    todo!("loading referenced primitives")
+                let reg = self.get_float_reg(buf, Some(sym));
+                match size {
+                    8 => ASM::mov_freg64_base32(buf, reg, base_offset),
+                    4 => ASM::mov_freg32_base32(buf, reg, base_offset),
+                    _ => internal_error!("Invalid float size: {size}"),
                 }
+                self.float_used_regs.push((reg, *sym));
+                self.symbol_storage_map.insert(*sym, Reg(Float(reg)));
+                self.free_reference(sym);
+                reg
             }
             Stack(Complex { .. }) => {
                 internal_error!("Cannot load large values into float registers: {}", sym)
@@ -579,16 +681,11 @@ impl<
             }
             Stack(ReferencedPrimitive {
                 base_offset, size, ..
-            }) => {
-                if base_offset % 8 == 0 && *size == 8 {
-                    // The primitive is aligned and the data is exactly 8 bytes, treat it like regular stack.
-                    ASM::mov_freg64_base32(buf, reg, *base_offset);
-                } else if base_offset % 4 == 0 && *size == 4 {
-                    ASM::mov_freg32_base32(buf, reg, *base_offset);
-                } else {
-                    todo!("loading referenced primitives")
-                }
-            }
+            }) => match size {
+                8 => ASM::mov_freg64_base32(buf, reg, *base_offset),
+                4 => ASM::mov_freg32_base32(buf, reg, *base_offset),
+                _ => internal_error!("Invalid float size: {size}"),
+            },
             Stack(Complex { .. }) => {
                 internal_error!("Cannot load large values into float registers: {}", sym)
             }
@@ -795,6 +892,10 @@ impl<
         sym: &Symbol,
         layout: &InLayout<'a>,
     ) {
+        if self.is_in_stack_slot(sym, to_offset) {
+            return;
+        }
+
         match layout_interner.get_repr(*layout) {
             LayoutRepr::Builtin(builtin) => match builtin {
                 Builtin::Int(int_width) => match int_width {
@@ -902,6 +1003,11 @@ impl<
         from_offset: i32,
         to_offset: i32,
     ) {
+        if from_offset == to_offset {
+            // Copying a stack area onto itself would not change anything.
+            return;
+        }
+
         let mut copied = 0;
         let size = size as i32;
 
@@ -1097,6 +1203,15 @@ impl<
         }
     }
 
+    /// Checks if a primitive symbol is already stored in the stack slot at `base_offset`.
+    /// Storing it there again would not change anything, so callers skip the move.
+    fn is_in_stack_slot(&self, sym: &Symbol, base_offset: i32) -> bool {
+        matches!(
+            self.symbol_storage_map.get(sym),
+            Some(Stack(Primitive { base_offset: offset, .. })) if *offset == base_offset
+        )
+    }
+
     /// gets the stack offset and size of the specified symbol.
     /// the symbol must already be stored on the stack.
     pub fn stack_offset_and_size(&self, sym: &Symbol) -> (i32, u32) {
@@ -1242,6 +1357,10 @@ impl<
         layout: InLayout<'a>,
         base_offset: i32,
     ) {
+        if self.is_in_stack_slot(&symbol, base_offset) {
+            return;
+        }
+
         match layout_interner.get_repr(layout) {
             single_register_integers!() | pointer_layouts!() => {
                 let reg = self.load_to_general_reg(buf, &symbol);
@@ -1289,9 +1408,8 @@ impl<
         let it = args.iter().zip(arg_layouts).zip(param_storage.iter());
         for ((sym, layout), wanted_storage) in it {
             // Note: it is possible that the storage we want to move to is in use by one of the args we want to pass.
-            if self.get_storage_for_sym(sym) == wanted_storage {
-                continue;
-            }
+            // Arguments that are already in the wanted stack slot, like loops passing a parameter along unchanged,
+            // are not moved at all, see `is_in_stack_slot` and `copy_to_stack_offset`.
             match wanted_storage {
                 Reg(_) => {
                     internal_error!("Register storage is not allowed for jumping to joinpoint")
@@ -1337,6 +1455,28 @@ impl<
         self.join_param_map.insert(*id, param_storage);
     }
 
+    /// Checks if an argument of a jump is stored in the stack slot of another parameter of the joinpoint.
+    /// setup_jump moves the arguments one at a time, so such an argument could be overwritten before it is moved.
+    pub fn jump_args_overlap(&self, id: &JoinPointId, args: &[Symbol]) -> bool {
+        let param_storage = match self.join_param_map.get(id) {
+            Some(storages) => storages,
+            None => internal_error!("Jump: unknown point specified to jump to: {:?}", id),
+        };
+
+        args.iter().enumerate().any(|(i, sym)| {
+            let Some((start, end)) = stack_range(self.get_storage_for_sym(sym)) else {
+                return false;
+            };
+
+            param_storage.iter().enumerate().any(|(j, wanted)| {
+                i != j
+                    && stack_range(wanted).map_or(false, |(wanted_start, wanted_end)| {
+                        start < wanted_end && wanted_start < end
+                    })
+            })
+        })
+    }
+
     /// Claim space on the stack for a certain layout. Size and alignment are handled
     ///
     /// This function:
@@ -1624,6 +1764,71 @@ impl<
     }
 }
 
+/// The most recently used registers are never spilled.
+/// They likely hold the operands of the instruction that is being generated right now.
+const RECENTLY_USED_REGS: usize = 4;
+
+/// Picks the index of the used register to spill when no registers are free.
+/// Symbols that the linear scan left without a register are meant to live on the stack, so they go first.
+/// Otherwise, like the linear scan, this spills the symbol whose live interval ends last.
+/// That register is then free for the longest stretch of code.
+/// Symbols without an interval are temporaries of the current statement and are spilled last.
+fn spill_candidate<Reg>(
+    live_until: &MutMap<Symbol, u32>,
+    is_spilled: impl Fn(&Symbol) -> bool,
+    used_regs: &[(Reg, Symbol)],
+) -> usize {
+    let candidates = used_regs.len().saturating_sub(RECENTLY_USED_REGS);
+
+    let mut best = 0;
+    let mut best_key = None;
+    for (index, (_, sym)) in used_regs[..candidates].iter().enumerate() {
+        let key = Some((is_spilled(sym), live_until.get(sym).copied()));
+        if key > best_key {
+            best = index;
+            best_key = key;
+        }
+    }
+
+    best
+}
+
+/// Picks the index of the free register to hand out next.
+/// This is the `assigned` register if it is free.
+/// Otherwise it is the last free register that no symbol was assigned, or failing that, the last free register.
+fn pick_free_reg<Reg: PartialEq>(
+    free_regs: &[Reg],
+    assigned: Option<Reg>,
+    is_assigned: impl Fn(&Reg) -> bool,
+) -> Option<usize> {
+    assigned
+        .and_then(|assigned| free_regs.iter().position(|reg| *reg == assigned))
+        .or_else(|| free_regs.iter().rposition(|reg| !is_assigned(reg)))
+        .or_else(|| free_regs.len().checked_sub(1))
+}
+
+/// Moves `reg` to the back of the used registers, making it the most recently used.
+fn mark_recently_used<Reg: PartialEq>(used_regs: &mut Vec<'_, (Reg, Symbol)>, reg: Reg) {
+    if let Some(index) = used_regs.iter().position(|(used, _)| *used == reg) {
+        let entry = used_regs.remove(index);
+        used_regs.push(entry);
+    }
+}
+
+/// The range of stack offsets that a storage occupies, if it is on the stack.
+fn stack_range<GeneralReg: RegTrait, FloatReg: RegTrait>(
+    storage: &Storage<GeneralReg, FloatReg>,
+) -> Option<(i32, i32)> {
+    match storage {
+        Stack(Primitive { base_offset, .. }) => Some((*base_offset, base_offset + 8)),
+        Stack(ReferencedPrimitive {
+            base_offset, size, ..
+        })
+        | Stack(Complex { base_offset, size }) => Some((*base_offset, base_offset + *size as i32)),
+        Reg(_) | NoData => None,
+    }
+}
+
 fn is_primitive(layout_interner: &mut STLayoutInterner<'_>, layout: InLayout<'_>) -> bool {
     match layout_interner.get_repr(layout) {
         single_register_layouts!() => true,
@@ -1697,4 +1902,64 @@ mod tests {
             (16, -8, vec![in &arena; ])
         );
     }
+
+    #[test]
+    fn spill_longest_living_symbol() {
+        use X86_64GeneralReg::*;
+
+        let syms = [
+            Symbol::DEV_TMP,
+            Symbol::DEV_TMP2,
+            Symbol::DEV_TMP3,
+            Symbol::DEV_TMP4,
+            Symbol::DEV_TMP5,
+            Symbol::RET_POINTER,
+        ];
+        let regs = [RAX, RBX, RCX, RDX, RSI, RDI];
+        let used_regs: std::vec::Vec<_> = regs.into_iter().zip(syms).collect();
+
+        let not_spilled = |_: &Symbol| false;
+
+        let mut live_until = MutMap::default();
+        live_until.insert(Symbol::DEV_TMP, 3);
+        live_until.insert(Symbol::DEV_TMP2, 10);
+        // The most recently used registers are never spilled, no matter how long they live.
+        live_until.insert(Symbol::DEV_TMP3, 20);
+        assert_eq!(spill_candidate(&live_until, not_spilled, &used_regs), 1);
+
+        // Symbols that the linear scan spilled go first, even if they live shorter.
+        let scan_spilled = |sym: &Symbol| *sym == Symbol::DEV_TMP;
+        assert_eq!(spill_candidate(&live_until, scan_spilled, &used_regs), 0);
+
+        // Temporaries without a live interval are spilled last.
+        live_until.clear();
+        live_until.insert(Symbol::DEV_TMP2, 10);
+        assert_eq!(spill_candidate(&live_until, not_spilled, &used_regs), 1);
+
+        // Without any intervals, the least recently used register is spilled.
+        live_until.clear();
+        assert_eq!(spill_candidate(&live_until, not_spilled, &used_regs), 0);
+        assert_eq!(
+            spill_candidate(&live_until, not_spilled, &used_regs[..2]),
+            0
+        );
+    }
+
+    #[test]
+    fn free_registers_go_to_their_assigned_symbol() {
+        use X86_64GeneralReg::*;
+
+        let free_regs = [RBX, RAX, RCX, RDX];
+        let is_assigned = |reg: &X86_64GeneralReg| matches!(reg, RAX | RDX);
+
+        // The assigned register, wherever it is in the free list.
+        assert_eq!(pick_free_reg(&free_regs, Some(RAX), is_assigned), Some(1));
+        // Others take the last register that nobody was assigned.
+        assert_eq!(pick_free_reg(&free_regs, None, is_assigned), Some(2));
+        // An assigned register that is not free is like having none.
+        assert_eq!(pick_free_reg(&free_regs, Some(R8), is_assigned), Some(2));
+        // When every free register is assigned to some symbol, the last one is used.
+        assert_eq!(pick_free_reg(&[RAX, RDX], None, is_assigned), Some(1));
+        assert_eq!(pick_free_reg(&[], None, is_assigned), None);
+    }
 }
@@ -358,28 +358,26 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
             _ if !Self::returns_via_arg_pointer(layout_interner, layout) => {
                 let (base_offset, size) = storage_manager.stack_offset_and_size(sym);
 
-                if size <= 8 {
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[0],
-                        base_offset,
-                    );
-                } else if size <= 16 {
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[0],
-                        base_offset,
-                    );
-                    X86_64Assembler::mov_reg64_base32(
-                        buf,
-                        Self::GENERAL_RETURN_REGS[1],
-                        base_offset + 8,
-                    );
-                } else {
+                if size > 16 {
                     internal_error!(
                         "types that don't return via arg pointer must be less than 16 bytes"
                     );
                 }
+
+                let classes = Self::eightbyte_is_float(layout_interner, *layout);
+                let (mut general_i, mut float_i) = (0, 0);
+                for (i, is_float) in classes.iter().enumerate().take(size.div_ceil(8) as usize) {
+                    let offset = base_offset + 8 * i as i32;
+                    if *is_float {
+                        let reg = Self::FLOAT_RETURN_REGS[float_i];
+                        X86_64Assembler::mov_freg64_base32(buf, reg, offset);
+                        float_i += 1;
+                    } else {
+                        let reg = Self::GENERAL_RETURN_REGS[general_i];
+                        X86_64Assembler::mov_reg64_base32(buf, reg, offset);
+                        general_i += 1;
+                    }
+                }
             }
             _ => {
                 // This is a large type returned via the arg pointer.
@@ -419,20 +417,27 @@ impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64Syste
                 let size = layout_interner.stack_size(*layout);
                 let offset =
                     storage_manager.claim_stack_area_layout(layout_interner, *sym, *layout);
-                if size <= 8 {
-                    X86_64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
-                } else if size <= 16 {
-                    X86_64Assembler::mov_base32_reg64(buf, offset, Self::GENERAL_RETURN_REGS[0]);
-                    X86_64Assembler::mov_base32_reg64(
-                        buf,
-                        offset + 8,
-                        Self::GENERAL_RETURN_REGS[1],
-                    );
-                } else {
+
+                if size > 16 {
                     internal_error!(
                         "types that don't return via arg pointer must be less than 16 bytes"
                     );
                 }
+
+                let classes = Self::eightbyte_is_float(layout_interner, *layout);
+                let (mut general_i, mut float_i) = (0, 0);
+                for (i, is_float) in classes.iter().enumerate().take(size.div_ceil(8) as usize) {
+                    let offset = offset + 8 * i as i32;
+                    if *is_float {
+                        let reg = Self::FLOAT_RETURN_REGS[float_i];
+                        X86_64Assembler::mov_base32_freg64(buf, offset, reg);
+                        float_i += 1;
+                    } else {
+                        let reg = Self::GENERAL_RETURN_REGS[general_i];
+                        X86_64Assembler::mov_base32_reg64(buf, offset, reg);
+                        general_i += 1;
+                    }
+                }
             }
             _ => {
                 // This should have been received via an arg pointer.
@@ -739,7 +744,8 @@ impl X64_64SystemVStoreArgs {
                     unreachable!("covered by earlier branch");
                 }
             }
-            LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
+            _ => {
+                // e.g. non-recursive tag unions; for now, just copy these onto the stack
                 let stack_offset = self.tmp_stack_offset;
 
                 let size =
@@ -747,12 +753,6 @@ impl X64_64SystemVStoreArgs {
 
                 self.tmp_stack_offset += size as i32;
             }
-            _ => {
-                todo!(
-                    "calling with arg type, {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -895,7 +895,7 @@ impl X64_64WindowsFastCallStoreArgs {
             single_register_integers!() => self.store_arg_general(buf, storage_manager, sym),
             pointer_layouts!() => self.store_arg_general(buf, storage_manager, sym),
             single_register_floats!() => self.store_arg_float(buf, storage_manager, sym),
-            LayoutRepr::I128 | LayoutRepr::U128 => {
+            LayoutRepr::I128 | LayoutRepr::U128 | LayoutRepr::DEC => {
                 let (offset, _) = storage_manager.stack_offset_and_size(&sym);
 
                 if self.general_i + 1 < Self::GENERAL_PARAM_REGS.len() {
@@ -963,7 +963,8 @@ impl X64_64WindowsFastCallStoreArgs {
 
                 self.tmp_stack_offset += size as i32;
             }
-            LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
+            _ => {
+                // e.g. non-recursive tag unions; for now, just copy these onto the stack
                 let stack_offset = self.tmp_stack_offset;
 
                 let size =
@@ -971,12 +972,6 @@ impl X64_64WindowsFastCallStoreArgs {
 
                 self.tmp_stack_offset += size as i32;
             }
-            _ => {
-                todo!(
-                    "calling with arg type, {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -1092,17 +1087,11 @@ impl X64_64SystemVLoadArgs {
             LayoutRepr::Builtin(Builtin::Decimal) => {
                 self.load_arg_general_128bit(buf, storage_manager, layout_interner, sym, in_layout);
             }
-            LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
-                // for now, just also store this on the stack
+            _ => {
+                // e.g. non-recursive tag unions; for now, just also store these on the stack
                 storage_manager.complex_stack_arg(&sym, self.argument_offset, stack_size);
                 self.argument_offset += stack_size as i32;
             }
-            _ => {
-                todo!(
-                    "Loading args with layout {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -1266,17 +1255,11 @@ impl X64_64WindowsFastCallLoadArgs {
             LayoutRepr::Builtin(Builtin::Decimal) => {
                 self.load_arg_general_128bit(buf, storage_manager, sym);
             }
-            LayoutRepr::Union(UnionLayout::NonRecursive(_)) => {
-                // for now, just also store this on the stack
+            _ => {
+                // e.g. non-recursive tag unions; for now, just also store these on the stack
                 storage_manager.complex_stack_arg(&sym, self.argument_offset, stack_size);
                 self.argument_offset += stack_size as i32;
             }
-            _ => {
-                todo!(
-                    "Loading args with layout {:?}",
-                    layout_interner.dbg(in_layout)
-                );
-            }
         }
     }
 
@@ -1345,6 +1328,52 @@ impl X86_64SystemV {
         // details here: https://github.com/hjl-tools/x86-psABI/wiki/x86-64-psABI-1.0.pdf
         interner.stack_size(*ret_layout) > 16
     }
+
+    /// Classify the (at most two) eightbytes of a small struct that is returned in registers.
+    /// An eightbyte that only contains floats is returned in an SSE register, otherwise it is
+    /// returned in a general register. Anything that is not a struct of builtins is treated as
+    /// integer data.
+    fn eightbyte_is_float<'a>(interner: &STLayoutInterner<'a>, layout: InLayout<'a>) -> [bool; 2] {
+        fn leaves<'a>(
+            interner: &STLayoutInterner<'a>,
+            layout: InLayout<'a>,
+            offset: u32,
+            out: &mut std::vec::Vec<(u32, bool)>,
+        ) {
+            match interner.get_repr(layout) {
+                LayoutRepr::LambdaSet(lambda_set) => {
+                    leaves(interner, lambda_set.runtime_representation(), offset, out)
+                }
+                LayoutRepr::Struct(field_layouts) => {
+                    let mut field_offset = offset;
+                    for field_layout in field_layouts {
+                        leaves(interner, *field_layout, field_offset, out);
+                        field_offset += interner.stack_size(*field_layout);
+                    }
+                }
+                LayoutRepr::Builtin(Builtin::Float(_)) => out.push((offset, true)),
+                _ => {
+                    // Zero-sized values don't influence the classification.
+                    if interner.stack_size(layout) > 0 {
+                        out.push((offset, false));
+                    }
+                }
+            }
+        }
+
+        let mut fields = std::vec::Vec::new();
+        leaves(interner, layout, 0, &mut fields);
+
+        let mut classes = [false; 2];
+        for (i, class) in classes.iter_mut().enumerate() {
+            let mut in_eightbyte = fields
+                .iter()
+                .filter(|(offset, _)| *offset / 8 == i as u32)
+                .peekable();
+            *class = in_eightbyte.peek().is_some() && in_eightbyte.all(|(_, is_float)| *is_float);
+        }
+        classes
+    }
 }
 
 impl CallConv<X86_64GeneralReg, X86_64FloatReg, X86_64Assembler> for X86_64WindowsFastcall {
@@ -2350,7 +2379,9 @@ impl Assembler<X86_64GeneralReg, X86_64FloatReg> for X86_64Assembler {
     }
     #[inline(always)]
     fn mov_freg64_freg64(buf: &mut Vec<'_, u8>, dst: X86_64FloatReg, src: X86_64FloatReg) {
-        movsd_freg64_freg64(buf, dst, src);
+        if dst != src {
+            movsd_freg64_freg64(buf, dst, src);
+        }
     }
 
     #[inline(always)]
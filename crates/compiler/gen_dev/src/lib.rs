@@ -15,16 +15,21 @@ use roc_module::ident::ModuleName;
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::{CallerProc, CodeGenHelp};
+use roc_mono::debug_sources::DebugSources;
 use roc_mono::ir::{
     BranchInfo, CallType, CrashTag, Expr, HigherOrderLowLevel, JoinPointId, ListLiteralElement,
-    Literal, ModifyRc, Param, Proc, ProcLayout, SelfRecursive, Stmt,
+    Literal, LookupType, ModifyRc, Param, Proc, ProcLayout, SelfRecursive, Stmt,
 };
 use roc_mono::layout::{
     Builtin, InLayout, LambdaName, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
     TagIdIntType, UnionLayout,
 };
 use roc_mono::list_element_layout;
+use roc_mono::liveness::LiveIntervals;
+use roc_region::all::Region;
 
+mod debug_info;
+mod fold;
 mod generic64;
 mod object_builder;
 pub use object_builder::build_module;
@@ -35,34 +40,66 @@ mod run_roc;
 pub enum AssemblyBackendMode {
     /// Assumes primitives (roc_alloc, roc_panic, etc) are provided by the host
     Binary,
+    /// Like `Binary`, but also runs `expect`s, reporting failures to the parent `roc` process
+    /// that started it (as `roc dev` and `roc run` do)
+    BinaryDev,
     /// Provides a testing implementation of primitives (roc_alloc, roc_panic, etc)
     Test,
     /// Provides a testing implementation of primitives (roc_alloc, roc_panic, etc)
     Repl,
+    /// Like `Test`, but also runs `expect`s, writing failures into the buffer given to `set_shared_buffer`
+    CliTest,
 }
 
 impl AssemblyBackendMode {
     fn generate_allocators(self) -> bool {
         match self {
             AssemblyBackendMode::Binary => false,
+            AssemblyBackendMode::BinaryDev => false,
             AssemblyBackendMode::Test => true,
             AssemblyBackendMode::Repl => true,
+            AssemblyBackendMode::CliTest => true,
         }
     }
 
     fn generate_roc_panic(self) -> bool {
         match self {
             AssemblyBackendMode::Binary => false,
+            AssemblyBackendMode::BinaryDev => false,
             AssemblyBackendMode::Test => true,
             AssemblyBackendMode::Repl => true,
+            AssemblyBackendMode::CliTest => true,
         }
     }
 
     fn generate_roc_dbg(self) -> bool {
         match self {
             AssemblyBackendMode::Binary => false,
+            AssemblyBackendMode::BinaryDev => false,
             AssemblyBackendMode::Test => true,
             AssemblyBackendMode::Repl => true,
+            AssemblyBackendMode::CliTest => true,
+        }
+    }
+
+    fn runs_expects(self) -> bool {
+        match self {
+            AssemblyBackendMode::Binary => false,
+            AssemblyBackendMode::BinaryDev => true,
+            AssemblyBackendMode::Test => false,
+            AssemblyBackendMode::Repl => false,
+            AssemblyBackendMode::CliTest => true,
+        }
+    }
+
+    /// Failed expects go to the shared file of the parent `roc` process instead of a shared buffer.
+    fn reports_expects_to_parent(self) -> bool {
+        match self {
+            AssemblyBackendMode::Binary => false,
+            AssemblyBackendMode::BinaryDev => true,
+            AssemblyBackendMode::Test => false,
+            AssemblyBackendMode::Repl => false,
+            AssemblyBackendMode::CliTest => false,
         }
     }
 }
@@ -73,6 +110,8 @@ pub struct Env<'a> {
     pub exposed_to_host: MutSet<Symbol>,
     pub lazy_literals: bool,
     pub mode: AssemblyBackendMode,
+    /// Where to find the source of the procs, if we should emit debug info for them.
+    pub debug_sources: Option<DebugSources>,
 }
 
 // These relocations likely will need a length.
@@ -150,7 +189,7 @@ impl<'a> LastSeenMap<'a> {
 
     fn scan_ast_help(&mut self, stmt: &'a Stmt<'a>) {
         match stmt {
-            Stmt::Let(sym, expr, _, following) => {
+            Stmt::Let(sym, expr, _, following, _) => {
                 self.set_last_seen(*sym, stmt);
                 match expr {
                     Expr::Literal(_) => {}
@@ -227,7 +266,7 @@ impl<'a> LastSeenMap<'a> {
                 }
                 self.scan_ast_help(default_branch.1);
             }
-            Stmt::Ret(sym) => {
+            Stmt::Ret(sym, _) => {
                 self.set_last_seen(*sym, stmt);
             }
             Stmt::Refcounting(modify, following) => {
@@ -276,9 +315,30 @@ impl<'a> LastSeenMap<'a> {
                 }
             }
 
-            Stmt::Dbg { .. } => todo!("dbg not implemented in the dev backend"),
-            Stmt::Expect { .. } => todo!("expect is not implemented in the dev backend"),
-            Stmt::ExpectFx { .. } => todo!("expect-fx is not implemented in the dev backend"),
+            Stmt::Dbg {
+                symbol, remainder, ..
+            } => {
+                self.set_last_seen(*symbol, stmt);
+                self.scan_ast_help(remainder);
+            }
+            Stmt::Expect {
+                condition,
+                lookups,
+                remainder,
+                ..
+            }
+            | Stmt::ExpectFx {
+                condition,
+                lookups,
+                remainder,
+                ..
+            } => {
+                self.set_last_seen(*condition, stmt);
+                for sym in *lookups {
+                    self.set_last_seen(*sym, stmt);
+                }
+                self.scan_ast_help(remainder);
+            }
 
             Stmt::Crash(msg, _crash_tag) => {
                 self.set_last_seen(*msg, stmt);
@@ -470,7 +530,15 @@ trait Backend<'a> {
     /// finalize does setup because things like stack size and jump locations are not know until the function is written.
     /// For example, this can store the frame pointer and setup stack space.
     /// finalize is run at the end of build_proc when all internal code is finalized.
-    fn finalize(&mut self) -> (Vec<u8>, Vec<Relocation>);
+    fn finalize(&mut self) -> (Vec<'a, u8>, Vec<'a, Relocation>);
+
+    /// mark_statement records that the code generated from here on is for the statement at `region`.
+    /// It only needs to do anything when we are emitting debug info.
+    fn mark_statement(&mut self, region: Region);
+
+    /// take_statements returns the statements marked in the last procedure,
+    /// with the offset in the finalized procedure that the code for each one starts at.
+    fn take_statements(&mut self) -> Vec<'a, (u64, Region)>;
 
     // load_args is used to let the backend know what the args are.
     // The backend should track these args so it can use them as needed.
@@ -490,7 +558,7 @@ trait Backend<'a> {
         &mut self,
         proc: Proc<'a>,
         layout_ids: &mut LayoutIds<'a>,
-    ) -> (Vec<u8>, Vec<Relocation>, Vec<'a, (Symbol, String)>) {
+    ) -> (Vec<'a, u8>, Vec<'a, Relocation>, Vec<'a, (Symbol, String)>) {
         let proc_name = self.lambda_name_to_string(
             proc.name,
             proc.args.iter().map(|t| t.0),
@@ -506,6 +574,7 @@ trait Backend<'a> {
             self.set_layout_map(*sym, layout);
         }
         self.scan_ast(body);
+        self.assign_registers(&LiveIntervals::from_body(proc.args, body));
         self.create_free_map();
         self.build_stmt(layout_ids, body, &proc.ret_layout);
 
@@ -541,13 +610,15 @@ trait Backend<'a> {
         ret_layout: &InLayout<'a>,
     ) {
         match stmt {
-            Stmt::Let(sym, expr, layout, following) => {
+            Stmt::Let(sym, expr, layout, following, region) => {
+                self.mark_statement(*region);
                 self.build_expr(sym, expr, layout);
                 self.set_layout_map(*sym, layout);
                 self.free_symbols(stmt);
                 self.build_stmt(layout_ids, following, ret_layout);
             }
-            Stmt::Ret(sym) => {
+            Stmt::Ret(sym, region) => {
+                self.mark_statement(*region);
                 self.load_literal_symbols(&[*sym]);
                 self.return_symbol(sym, ret_layout);
                 self.free_symbols(stmt);
@@ -608,16 +679,38 @@ trait Backend<'a> {
                 branches,
                 default_branch,
                 ret_layout,
+                region,
             } => {
-                self.load_literal_symbols(&[*cond_symbol]);
-                self.build_switch(
-                    layout_ids,
-                    cond_symbol,
-                    cond_layout,
-                    branches,
-                    default_branch,
-                    ret_layout,
-                );
+                self.mark_statement(*region);
+
+                if let Some(value) = self.literal_switch_value(cond_symbol) {
+                    // The condition is known, so only the branch that is taken has to be built.
+                    let branch = branches
+                        .iter()
+                        .find(|(branch_value, _, _)| *branch_value == value)
+                        .map_or(default_branch.1, |(_, _, branch)| branch);
+                    self.build_stmt(layout_ids, branch, ret_layout);
+
+                    // Symbols last seen in the other branches would otherwise never be freed.
+                    let untaken = branches
+                        .iter()
+                        .map(|(_, _, branch)| branch)
+                        .chain([default_branch.1])
+                        .filter(|untaken| !std::ptr::eq(*untaken, branch));
+                    for untaken in untaken {
+                        self.free_unbuilt_symbols(untaken);
+                    }
+                } else {
+                    self.load_literal_symbols(&[*cond_symbol]);
+                    self.build_switch(
+                        layout_ids,
+                        cond_symbol,
+                        cond_layout,
+                        branches,
+                        default_branch,
+                        ret_layout,
+                    );
+                }
                 self.free_symbols(stmt);
             }
             Stmt::Join {
@@ -648,8 +741,50 @@ trait Backend<'a> {
                 self.build_jump(id, args, arg_layouts.into_bump_slice(), ret_layout);
                 self.free_symbols(stmt);
             }
+            Stmt::Dbg {
+                source_location,
+                source,
+                symbol,
+                variable: _,
+                remainder,
+            } => {
+                self.load_literal_symbols(&[*symbol]);
+                self.roc_dbg(source_location, source, *symbol);
+                self.free_symbols(stmt);
+                self.build_stmt(layout_ids, remainder, ret_layout);
+            }
+            Stmt::Expect {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                if self.env().mode.runs_expects() {
+                    self.load_literal_symbols(&[*condition]);
+                    self.load_literal_symbols(lookups);
+                    let notify_parent = self.env().mode.reports_expects_to_parent();
+                    self.build_expect(*condition, *region, lookups, variables, notify_parent);
+                }
+                self.free_symbols(stmt);
+                self.build_stmt(layout_ids, remainder, ret_layout);
+            }
+            Stmt::ExpectFx {
+                condition,
+                region,
+                lookups,
+                variables,
+                remainder,
+            } => {
+                if self.env().mode.runs_expects() {
+                    self.load_literal_symbols(&[*condition]);
+                    self.load_literal_symbols(lookups);
+                    self.build_expect(*condition, *region, lookups, variables, false);
+                }
+                self.free_symbols(stmt);
+                self.build_stmt(layout_ids, remainder, ret_layout);
+            }
             Stmt::Crash(msg, crash_tag) => self.roc_panic(*msg, *crash_tag),
-            x => todo!("the statement, {:?}", x),
         }
     }
 
@@ -685,6 +820,279 @@ trait Backend<'a> {
         self.free_symbol(&Symbol::DEV_TMP2);
     }
 
+    fn roc_dbg(&mut self, source_location: &'a str, source: &'a str, msg: Symbol) {
+        let location = self.debug_symbol("dbg_location");
+        let source_str = self.debug_symbol("dbg_source");
+        self.load_literal(&location, &Layout::STR, &Literal::Str(source_location));
+        self.load_literal(&source_str, &Layout::STR, &Literal::Str(source));
+
+        // roc_dbg expects a `*RocStr` for every argument, so put copies of them on the stack
+        let location_ptr = self.debug_symbol("dbg_location_ptr");
+        let msg_ptr = self.debug_symbol("dbg_msg_ptr");
+        let source_ptr = self.debug_symbol("dbg_source_ptr");
+        self.build_alloca(location_ptr, Some(location), Layout::STR);
+        self.build_alloca(msg_ptr, Some(msg), Layout::STR);
+        self.build_alloca(source_ptr, Some(source_str), Layout::STR);
+
+        // TODO: at some point it will be a breaking change, but flip order to (loc, src, msg)
+        self.build_fn_call(
+            &Symbol::DEV_TMP2,
+            String::from("roc_dbg"),
+            &[location_ptr, msg_ptr, source_ptr],
+            &[Layout::U64, Layout::U64, Layout::U64],
+            &Layout::UNIT,
+        );
+
+        for sym in [location, source_str, location_ptr, msg_ptr, source_ptr] {
+            self.free_symbol(&sym);
+        }
+        self.free_symbol(&Symbol::DEV_TMP2);
+    }
+
+    /// build_expect checks the condition of an expect, and calls `expect_failed` when it does not hold.
+    fn build_expect(
+        &mut self,
+        condition: Symbol,
+        region: Region,
+        lookups: &[Symbol],
+        variables: &[LookupType],
+        notify_parent: bool,
+    );
+
+    /// build_panic_unless calls roc_panic with `message` when the boolean `condition` does not hold.
+    fn build_panic_unless(&mut self, condition: Symbol, message: &'a str);
+
+    /// Panics with `message` when the signed integer `src` is the minimum value of its width,
+    /// which has no positive counterpart.
+    fn panic_if_min_int(&mut self, src: &Symbol, layout: &InLayout<'a>, message: &'a str) {
+        let int_width = match self.interner().get_repr(*layout) {
+            LayoutRepr::Builtin(Builtin::Int(int_width)) if int_width.is_signed() => int_width,
+            _ => return,
+        };
+
+        let min_value = i128::MIN >> (128 - 8 * int_width.stack_size());
+        let min_int = self.debug_symbol("min_int");
+        self.load_literal(&min_int, layout, &Literal::Int(min_value.to_ne_bytes()));
+
+        let not_min_int = self.debug_symbol("not_min_int");
+        self.build_neq(&not_min_int, src, &min_int, layout);
+        self.build_panic_unless(not_min_int, message);
+
+        self.free_symbol(&min_int);
+        self.free_symbol(&not_min_int);
+    }
+
+    /// expect_failed writes a frame for a failed expect into the shared memory, in the same format as the LLVM backend:
+    /// the region and module of the expect, where each looked up value starts and its variable, and then the values.
+    fn expect_failed(
+        &mut self,
+        condition: Symbol,
+        region: Region,
+        lookups: &[Symbol],
+        variables: &[LookupType],
+        notify_parent: bool,
+    ) {
+        let start_function = if self.env().mode.reports_expects_to_parent() {
+            bitcode::UTILS_EXPECT_FAILED_START_SHARED_FILE
+        } else {
+            bitcode::UTILS_EXPECT_FAILED_START_SHARED_BUFFER
+        };
+        let shared_memory = self.debug_symbol("shared_memory");
+        self.build_fn_call(
+            &shared_memory,
+            start_function.to_string(),
+            &[],
+            &[],
+            &Layout::U64,
+        );
+
+        // The shared memory starts with the number of frames, and the offset of the next frame.
+        let count = self.debug_symbol("count");
+        let offset = self.debug_symbol("offset");
+        let offset_ptr = self.add_to_u64(shared_memory, 8);
+        self.build_ptr_load(count, shared_memory, Layout::U64);
+        self.build_ptr_load(offset, offset_ptr, Layout::U64);
+
+        let module_id: u32 = condition.module_id().to_raw_u32();
+        let frame_ptr = self.add_u64(shared_memory, offset);
+        for (i, value) in [region.start().offset, region.end().offset, module_id]
+            .into_iter()
+            .enumerate()
+        {
+            let value_ptr = self.add_to_u64(frame_ptr, 4 * i as u64);
+            let value_sym = self.debug_symbol("header_value");
+            self.load_literal_i32(&value_sym, value as i32);
+            self.store_at(value_ptr, value_sym, Layout::U32);
+        }
+
+        // Every lookup gets the offset its value starts at, and its variable.
+        let table_entry_size = 8 + 4;
+        let table_ptr = self.add_to_u64(frame_ptr, 12);
+        let mut cursor = self.add_to_u64(offset, 12 + table_entry_size * lookups.len() as u64);
+
+        for (i, (lookup, variable)) in lookups.iter().zip(variables).enumerate() {
+            let entry_ptr = self.add_to_u64(table_ptr, table_entry_size * i as u64);
+            self.store_at(entry_ptr, cursor, Layout::U64);
+
+            let variable_ptr = self.add_to_u64(entry_ptr, 8);
+            let variable_sym = self.debug_symbol("variable");
+            self.load_literal_i32(&variable_sym, variable.index() as i32);
+            self.store_at(variable_ptr, variable_sym, Layout::U32);
+
+            let layout = *self.layout_map().get(lookup).unwrap();
+            let stack_size = self.interner().stack_size(layout) as u64;
+            let extra = self.add_to_u64(cursor, stack_size);
+            cursor = self.clone_to_shared_memory(shared_memory, cursor, extra, *lookup, layout);
+        }
+
+        let one = self.debug_symbol("one");
+        self.load_literal_i64(&one, 1);
+        let new_count = self.debug_symbol("new_count");
+        self.build_num_add_wrap(&new_count, &count, &one, &Layout::U64);
+        self.store_at(shared_memory, new_count, Layout::U64);
+        self.store_at(offset_ptr, cursor, Layout::U64);
+
+        if notify_parent {
+            self.build_fn_call(
+                &Symbol::DEV_TMP2,
+                bitcode::NOTIFY_PARENT_EXPECT.to_string(),
+                &[shared_memory],
+                &[Layout::U64],
+                &Layout::UNIT,
+            );
+            self.free_symbol(&Symbol::DEV_TMP2);
+        }
+    }
+
+    /// clone_to_shared_memory writes `value` at `offset` into the shared memory.
+    /// Anything the value points to is written at `extra`, and the new end of the written data is returned.
+    fn clone_to_shared_memory(
+        &mut self,
+        shared_memory: Symbol,
+        offset: Symbol,
+        extra: Symbol,
+        value: Symbol,
+        layout: InLayout<'a>,
+    ) -> Symbol {
+        let value_ptr = self.add_u64(shared_memory, offset);
+
+        match self.interner().get_repr(layout) {
+            LayoutRepr::LambdaSet(_) => extra,
+            LayoutRepr::Builtin(Builtin::Str) => {
+                let new_extra = self.debug_symbol("extra");
+                self.build_fn_call(
+                    &new_extra,
+                    bitcode::STR_CLONE_TO.to_string(),
+                    &[value, shared_memory, offset, extra],
+                    &[Layout::STR, Layout::U64, Layout::U64, Layout::U64],
+                    &Layout::U64,
+                );
+                new_extra
+            }
+            LayoutRepr::Builtin(Builtin::List(element_layout))
+                if self.interner().safe_to_memcpy(element_layout) =>
+            {
+                // The elements go at `extra`, and we only copy the elements we actually have.
+                let len = self.debug_symbol("len");
+                self.build_list_len_u64(&len, &value);
+                self.store_at(value_ptr, extra, Layout::U64);
+                for field_offset in [8, 16] {
+                    let field_ptr = self.add_to_u64(value_ptr, field_offset);
+                    self.store_at(field_ptr, len, Layout::U64);
+                }
+
+                let element_width = self.debug_symbol("element_width");
+                let element_size = self.interner().stack_size(element_layout);
+                self.load_literal_i64(&element_width, element_size as i64);
+                let elements_width = self.debug_symbol("elements_width");
+                self.build_num_mul_wrap(&elements_width, &len, &element_width, &Layout::U64);
+
+                // The elements pointer is the first field of the list.
+                let list_ptr = self.debug_symbol("list_ptr");
+                let elements = self.debug_symbol("elements");
+                self.build_alloca(list_ptr, Some(value), layout);
+                self.build_ptr_load(elements, list_ptr, Layout::U64);
+
+                let destination = self.add_u64(shared_memory, extra);
+                self.build_copy_bytes(destination, elements, elements_width);
+
+                self.add_u64(extra, elements_width)
+            }
+            LayoutRepr::Struct(field_layouts) => {
+                let mut extra = extra;
+                let mut field_offset = 0;
+                for (i, field_layout) in field_layouts.iter().enumerate() {
+                    let field = self.debug_symbol("field");
+                    self.load_struct_at_index(&field, &value, i as u64, field_layouts);
+
+                    let at = self.add_to_u64(offset, field_offset);
+                    extra =
+                        self.clone_to_shared_memory(shared_memory, at, extra, field, *field_layout);
+                    field_offset += self.interner().stack_size(*field_layout) as u64;
+                }
+                extra
+            }
+            _ if self.interner().safe_to_memcpy(layout) => {
+                self.store_at(value_ptr, value, layout);
+                extra
+            }
+            _ => {
+                // Tag unions (including boxes) and lists whose elements point to other data
+                // need branches and loops, so they are cloned by a generated helper proc.
+                let arguments = self
+                    .env()
+                    .arena
+                    .alloc([value, shared_memory, offset, extra]);
+                let (clone_expr, new_specializations) = {
+                    let (module_id, layout_interner, interns, helper_proc_gen, _) =
+                        self.module_interns_helpers_mut();
+                    let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
+
+                    helper_proc_gen.call_clone_to_shared_memory(
+                        ident_ids,
+                        layout_interner,
+                        layout,
+                        arguments,
+                    )
+                };
+
+                for spec in new_specializations.into_iter() {
+                    self.helper_proc_symbols_mut().push(spec);
+                }
+
+                let new_extra = self.debug_symbol("extra");
+                self.build_expr(&new_extra, &clone_expr, &Layout::U64);
+                new_extra
+            }
+        }
+    }
+
+    /// add_u64 returns a new symbol holding the sum of two `U64` symbols.
+    fn add_u64(&mut self, left: Symbol, right: Symbol) -> Symbol {
+        let sum = self.debug_symbol("sum");
+        self.build_num_add_wrap(&sum, &left, &right, &Layout::U64);
+        sum
+    }
+
+    /// add_to_u64 returns a new symbol holding a `U64` symbol plus a constant.
+    fn add_to_u64(&mut self, left: Symbol, right: u64) -> Symbol {
+        let constant = self.debug_symbol("constant");
+        self.load_literal_i64(&constant, right as i64);
+        self.add_u64(left, constant)
+    }
+
+    /// store_at writes `value` to the address in `ptr`.
+    fn store_at(&mut self, ptr: Symbol, value: Symbol, layout: InLayout<'a>) {
+        let ignored = self.debug_symbol("ignored");
+        self.build_ptr_store(ignored, ptr, value, layout);
+    }
+
+    /// build_unreachable gives `sym` a zeroed value. It is used for values that can never be observed.
+    fn build_unreachable(&mut self, sym: &Symbol, layout: &InLayout<'a>);
+
+    /// build_copy_bytes copies `len` bytes from the address in `src` to the address in `dst`.
+    fn build_copy_bytes(&mut self, dst: Symbol, src: Symbol, len: Symbol);
+
     // build_switch generates a instructions for a switch statement.
     fn build_switch(
         &mut self,
@@ -942,7 +1350,15 @@ trait Backend<'a> {
             } => {
                 self.build_alloca(*sym, *initializer, *element_layout);
             }
-            Expr::RuntimeErrorFunction(_) => todo!(),
+            Expr::RuntimeErrorFunction(msg) => {
+                let error_message = self.debug_symbol("runtime_error_message");
+                self.load_literal(&error_message, &Layout::STR, &Literal::Str(msg));
+                self.roc_panic(error_message, CrashTag::Roc);
+                self.free_symbol(&error_message);
+
+                // We never get here, but later code still needs a value for the symbol.
+                self.build_unreachable(sym, layout);
+            }
         }
     }
 
@@ -956,6 +1372,10 @@ trait Backend<'a> {
         arg_layouts: &[InLayout<'a>],
         ret_layout: &InLayout<'a>,
     ) {
+        if self.fold_literals(sym, lowlevel, args, arg_layouts, ret_layout) {
+            return;
+        }
+
         // Now that the arguments are needed, load them if they are literals.
         self.load_literal_symbols(args);
         match lowlevel {
@@ -969,6 +1389,11 @@ trait Backend<'a> {
                     arg_layouts[0], *ret_layout,
                     "NumAbs: expected to have the same argument and return layout"
                 );
+                self.panic_if_min_int(
+                    &args[0],
+                    ret_layout,
+                    "Integer absolute overflowed because its argument is the minimum value",
+                );
                 self.build_num_abs(sym, &args[0], ret_layout)
             }
             LowLevel::NumAdd => {
@@ -1072,6 +1497,11 @@ trait Backend<'a> {
                     arg_layouts[0], *ret_layout,
                     "NumNeg: expected to have the same argument and return layout"
                 );
+                self.panic_if_min_int(
+                    &args[0],
+                    ret_layout,
+                    "Integer negation overflowed because its argument is the minimum value",
+                );
                 self.build_num_neg(sym, &args[0], ret_layout)
             }
             LowLevel::NumPowInt => {
@@ -1195,10 +1625,13 @@ trait Backend<'a> {
                     // saturated sub is just normal sub
                     self.build_num_sub(sym, &args[0], &args[1], ret_layout)
                 }
-                LayoutRepr::Builtin(Builtin::Decimal) => {
-                    // self.load_args_and_call_zig(backend, bitcode::DEC_SUB_SATURATED)
-                    todo!()
-                }
+                LayoutRepr::Builtin(Builtin::Decimal) => self.build_fn_call(
+                    sym,
+                    bitcode::DEC_SUB_SATURATED.to_string(),
+                    args,
+                    arg_layouts,
+                    ret_layout,
+                ),
                 _ => internal_error!("invalid return type"),
             },
             LowLevel::NumBitwiseAnd => {
@@ -2059,7 +2492,119 @@ trait Backend<'a> {
                 self.build_fn_call(sym, intrinsic, args, arg_layouts, ret_layout)
             }
 
-            x => todo!("low level, {:?}", x),
+            LowLevel::StrFromInt => {
+                let int_width = arg_layouts[0].try_int_width().unwrap();
+                let intrinsic = bitcode::STR_FROM_INT[int_width].to_string();
+                self.build_fn_call(sym, intrinsic, args, arg_layouts, ret_layout)
+            }
+
+            LowLevel::StrFromFloat => {
+                let float_width = match self.interner().get_repr(arg_layouts[0]) {
+                    LayoutRepr::Builtin(Builtin::Float(float_width)) => float_width,
+                    x => internal_error!("StrFromFloat is not defined for {:?}", x),
+                };
+                let intrinsic = bitcode::STR_FROM_FLOAT[float_width].to_string();
+                self.build_fn_call(sym, intrinsic, args, arg_layouts, ret_layout)
+            }
+
+            LowLevel::StrReleaseExcessCapacity => self.build_fn_call(
+                sym,
+                bitcode::STR_RELEASE_EXCESS_CAPACITY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+
+            LowLevel::ListGetCapacity => self.build_fn_call(
+                sym,
+                bitcode::LIST_CAPACITY.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+
+            LowLevel::ListIsUnique => self.build_fn_call(
+                sym,
+                bitcode::LIST_IS_UNIQUE.to_string(),
+                args,
+                arg_layouts,
+                ret_layout,
+            ),
+
+            LowLevel::NumToIntChecked => {
+                let arg_width = arg_layouts[0].try_int_width().unwrap();
+
+                // return_layout : Result N [OutOfBounds]* ~ { result: N, out_of_bounds: bool }
+                let target_width = match self.interner().get_repr(*ret_layout) {
+                    LayoutRepr::Struct(&[target, ..]) => target.try_int_width().unwrap(),
+                    x => internal_error!("NumToIntChecked has an unexpected return layout {:?}", x),
+                };
+
+                let intrinsic = if arg_width.is_signed() {
+                    &bitcode::NUM_INT_TO_INT_CHECKING_MAX_AND_MIN[target_width][arg_width]
+                } else {
+                    &bitcode::NUM_INT_TO_INT_CHECKING_MAX[target_width][arg_width]
+                };
+
+                self.build_fn_call(sym, intrinsic.to_string(), args, arg_layouts, ret_layout)
+            }
+
+            LowLevel::Unreachable => self.build_unreachable(sym, ret_layout),
+
+            LowLevel::ListMap
+            | LowLevel::ListMap2
+            | LowLevel::ListMap3
+            | LowLevel::ListMap4
+            | LowLevel::ListSortWith => {
+                internal_error!("{:?} is higher order, and is handled elsewhere", lowlevel)
+            }
+
+            LowLevel::BoxExpr | LowLevel::UnboxExpr => {
+                internal_error!("The {:?} operation is turned into mono Expr", lowlevel)
+            }
+
+            LowLevel::NumToFloatChecked => {
+                // return_layout : Result F [OutOfBounds]* ~ { result: F, out_of_bounds: bool }
+                let target = match self.interner().get_repr(*ret_layout) {
+                    LayoutRepr::Struct(&[target, ..]) => target,
+                    x => {
+                        internal_error!("NumToFloatChecked has an unexpected return layout {:?}", x)
+                    }
+                };
+
+                let converted = self.debug_symbol("converted");
+                self.build_num_to_frac(&converted, &args[0], &arg_layouts[0], &target);
+
+                // out of bounds means a finite number became infinite
+                let out_of_bounds = self.debug_symbol("out_of_bounds");
+                self.build_num_is_infinite(&out_of_bounds, &converted, &target);
+
+                let out_of_bounds = if let LayoutRepr::Builtin(Builtin::Float(_)) =
+                    self.interner().get_repr(arg_layouts[0])
+                {
+                    let infinite = out_of_bounds;
+                    let finite = self.debug_symbol("finite");
+                    self.build_num_is_finite(&finite, &args[0], &arg_layouts[0]);
+
+                    let out_of_bounds = self.debug_symbol("out_of_bounds");
+                    self.build_int_bitwise_and(&out_of_bounds, &infinite, &finite, IntWidth::U8);
+                    self.free_symbol(&infinite);
+                    self.free_symbol(&finite);
+
+                    out_of_bounds
+                } else {
+                    out_of_bounds
+                };
+
+                let fields = self.env().arena.alloc([converted, out_of_bounds]);
+                self.create_struct(sym, ret_layout, fields);
+                self.free_symbol(&converted);
+                self.free_symbol(&out_of_bounds);
+            }
+
+            LowLevel::Hash => {
+                internal_error!("{:?} is not tied to any symbol", lowlevel)
+            }
         }
     }
 
@@ -2522,6 +3067,66 @@ trait Backend<'a> {
     /// literal_map gets the map from symbol to literal and layout, used for lazy loading and literal folding.
     fn literal_map(&mut self) -> &mut MutMap<Symbol, (*const Literal<'a>, *const InLayout<'a>)>;
 
+    /// fold_literals computes a low level at compile time if all of its arguments are lazy literals.
+    /// The result is stored as a lazy literal as well. Returns false if nothing was folded.
+    fn fold_literals(
+        &mut self,
+        sym: &Symbol,
+        lowlevel: &LowLevel,
+        args: &[Symbol],
+        arg_layouts: &[InLayout<'a>],
+        ret_layout: &InLayout<'a>,
+    ) -> bool {
+        if !self.env().lazy_literals || args.len() != 2 {
+            return false;
+        }
+
+        let width = match self.interner().get_repr(arg_layouts[0]) {
+            LayoutRepr::Builtin(Builtin::Int(width)) => width,
+            _ => return false,
+        };
+
+        let mut values = [0; 2];
+        for (value, arg) in values.iter_mut().zip(args) {
+            match self.literal_map().get(arg) {
+                // Safe for the same reason as in load_literal_symbols.
+                Some((lit, _)) => match unsafe { **lit } {
+                    Literal::Int(bytes) => *value = i128::from_ne_bytes(bytes),
+                    _ => return false,
+                },
+                None => return false,
+            }
+        }
+
+        match fold::fold_int_literals(*lowlevel, width, values[0], values[1]) {
+            Some(lit) => {
+                let arena = self.env().arena;
+                let lit: &'a Literal<'a> = arena.alloc(lit);
+                let layout: &'a InLayout<'a> = arena.alloc(*ret_layout);
+                self.literal_map().insert(*sym, (lit, layout));
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// literal_switch_value gets the value a switch would compare against, if its condition is a lazy literal.
+    fn literal_switch_value(&mut self, cond_symbol: &Symbol) -> Option<u64> {
+        if !self.env().lazy_literals {
+            return None;
+        }
+
+        let (lit, _) = self.literal_map().get(cond_symbol)?;
+        // Safe for the same reason as in load_literal_symbols.
+        match unsafe { **lit } {
+            // Literals are loaded as 64-bit immediates, so this matches what build_switch compares.
+            Literal::Int(bytes) => Some(i128::from_ne_bytes(bytes) as i64 as u64),
+            Literal::Bool(x) => Some(x as u64),
+            Literal::Byte(x) => Some(x as u64),
+            _ => None,
+        }
+    }
+
     fn load_literal_symbols(&mut self, syms: &[Symbol]) {
         if self.env().lazy_literals {
             for sym in syms {
@@ -2644,6 +3249,38 @@ trait Backend<'a> {
         }
     }
 
+    /// free_unbuilt_symbols frees the symbols last seen anywhere in a statement that is not built.
+    fn free_unbuilt_symbols(&mut self, stmt: &Stmt<'a>) {
+        self.free_symbols(stmt);
+        match stmt {
+            Stmt::Let(_, _, _, following, _) | Stmt::Refcounting(_, following) => {
+                self.free_unbuilt_symbols(following);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                ..
+            } => {
+                for (_, _, branch) in branches.iter() {
+                    self.free_unbuilt_symbols(branch);
+                }
+                self.free_unbuilt_symbols(default_branch.1);
+            }
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                self.free_unbuilt_symbols(remainder);
+                self.free_unbuilt_symbols(body);
+            }
+            Stmt::Dbg { remainder, .. }
+            | Stmt::Expect { remainder, .. }
+            | Stmt::ExpectFx { remainder, .. } => {
+                self.free_unbuilt_symbols(remainder);
+            }
+            Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => {}
+        }
+    }
+
     /// free_symbol frees any registers or stack space used to hold a symbol.
     fn free_symbol(&mut self, sym: &Symbol);
 
@@ -2692,4 +3329,9 @@ trait Backend<'a> {
     fn scan_ast(&mut self, stmt: &'a Stmt<'a>) {
         *self.last_seen_map() = LastSeenMap::scan_ast(stmt);
     }
+
+    /// assign_registers decides which register each symbol of the proc should live in,
+    /// with a linear scan over the live intervals that mono computes for it.
+    /// The arguments must already be loaded.
+    fn assign_registers(&mut self, live_intervals: &LiveIntervals<'a>);
 }
@@ -1,3 +1,4 @@
+use crate::debug_info::{write_debug_sections, DebugProc};
 use crate::generic64::{aarch64, new_backend_64bit, x86_64};
 use crate::{AssemblyBackendMode, Backend, Env, Relocation};
 use bumpalo::collections::Vec;
@@ -7,13 +8,15 @@ use object::{
     Architecture, BinaryFormat, Endianness, RelocationEncoding, RelocationKind, SectionKind,
     SymbolFlags, SymbolKind, SymbolScope,
 };
+use roc_builtins::bitcode;
 use roc_collections::all::MutMap;
 use roc_error_macros::internal_error;
 use roc_module::symbol;
 use roc_module::symbol::Interns;
 use roc_mono::ir::{Call, CallSpecId, Expr, UpdateModeId};
-use roc_mono::ir::{Proc, ProcLayout, Stmt};
+use roc_mono::ir::{Proc, ProcLayout, ProcSizes, Stmt};
 use roc_mono::layout::{LambdaName, Layout, LayoutIds, LayoutInterner, STLayoutInterner};
+use roc_region::all::Region;
 use roc_target::Target;
 
 // This is used by some code below which is currently commented out.
@@ -21,15 +24,17 @@ use roc_target::Target;
 // const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// build_module is the high level builder/delegator.
-/// It takes the request to build a module and output the object file for the module.
+/// It takes the request to build a module and output the object file for the module,
+/// along with the size of each procedure in it.
 pub fn build_module<'a, 'r>(
     env: &'r Env<'a>,
     interns: &'r mut Interns,
     layout_interner: &'r mut STLayoutInterner<'a>,
     target: Target,
     procedures: MutMap<(symbol::Symbol, ProcLayout<'a>), Proc<'a>>,
-) -> Object<'a> {
-    let module_object = build_module_help(env, interns, layout_interner, target, procedures);
+) -> (Object<'a>, ProcSizes<'a>) {
+    let (module_object, proc_sizes) =
+        build_module_help(env, interns, layout_interner, target, procedures);
 
     if std::env::var("ROC_DEV_WRITE_OBJ").is_ok() {
         let module_out = module_object
@@ -41,7 +46,7 @@ pub fn build_module<'a, 'r>(
         std::fs::write(&file_path, module_out).expect("failed to write object to file");
     }
 
-    module_object
+    (module_object, proc_sizes)
 }
 
 fn build_module_help<'a, 'r>(
@@ -50,7 +55,7 @@ fn build_module_help<'a, 'r>(
     layout_interner: &'r mut STLayoutInterner<'a>,
     target: Target,
     procedures: MutMap<(symbol::Symbol, ProcLayout<'a>), Proc<'a>>,
-) -> Object<'a> {
+) -> (Object<'a>, ProcSizes<'a>) {
     match target {
         Target::LinuxX64 if cfg!(feature = "target-x86_64") => {
             let backend = new_backend_64bit::<
@@ -319,23 +324,6 @@ fn generate_roc_panic<'a, B: Backend<'a>>(backend: &mut B, output: &mut Object)
     }
 }
 
-fn generate_roc_dbg<'a, B: Backend<'a>>(_backend: &mut B, output: &mut Object) {
-    let text_section = output.section_id(StandardSection::Text);
-    let proc_symbol = Symbol {
-        name: "roc_dbg".as_bytes().to_vec(),
-        value: 0,
-        size: 0,
-        kind: SymbolKind::Text,
-        scope: SymbolScope::Dynamic,
-        weak: false,
-        section: SymbolSection::Section(text_section),
-        flags: SymbolFlags::None,
-    };
-    let _proc_id = output.add_symbol(proc_symbol);
-    // TODO: Actually generate an impl instead of just an empty symbol.
-    // At a minimum, it should just be a ret.
-}
-
 fn generate_wrapper<'a, B: Backend<'a>>(
     backend: &mut B,
     output: &mut Object,
@@ -384,7 +372,6 @@ fn generate_wrapper<'a, B: Backend<'a>>(
 
 fn create_relocation(target: Target, symbol: SymbolId, offset: u64) -> write::Relocation {
     let (encoding, size, addend, kind) = match target.architecture() {
-        roc_target::Architecture::Aarch32 => todo!(),
         roc_target::Architecture::Aarch64 => {
             if cfg!(target_os = "macos") {
                 (
@@ -405,8 +392,11 @@ fn create_relocation(target: Target, symbol: SymbolId, offset: u64) -> write::Re
                 )
             }
         }
-        roc_target::Architecture::Wasm32 => todo!(),
-        roc_target::Architecture::X86_32 => todo!(),
+        arch @ (roc_target::Architecture::Aarch32
+        | roc_target::Architecture::Wasm32
+        | roc_target::Architecture::X86_32) => {
+            internal_error!("the dev backend does not support {:?}", arch)
+        }
         roc_target::Architecture::X86_64 => (
             RelocationEncoding::X86Branch,
             32,
@@ -429,7 +419,7 @@ fn build_object<'a, B: Backend<'a>>(
     procedures: MutMap<(symbol::Symbol, ProcLayout<'a>), Proc<'a>>,
     mut backend: B,
     mut output: Object<'a>,
-) -> Object<'a> {
+) -> (Object<'a>, ProcSizes<'a>) {
     let data_section = output.section_id(StandardSection::Data);
 
     let arena = backend.env().arena;
@@ -454,7 +444,12 @@ fn build_object<'a, B: Backend<'a>>(
     }
 
     if backend.env().mode.generate_roc_dbg() {
-        generate_roc_dbg(&mut backend, &mut output);
+        generate_wrapper(
+            &mut backend,
+            &mut output,
+            "roc_dbg".into(),
+            bitcode::UTILS_DBG_IMPL.into(),
+        );
     }
 
     if backend.env().mode.generate_allocators() {
@@ -507,6 +502,7 @@ fn build_object<'a, B: Backend<'a>>(
     // Setup layout_ids for procedure calls.
     let mut layout_ids = LayoutIds::default();
     let mut procs = Vec::with_capacity_in(procedures.len(), arena);
+    let mut proc_symbols = std::vec::Vec::with_capacity(procedures.len());
 
     // Names and linker data for user procedures
     for ((sym, layout), proc) in procedures {
@@ -524,7 +520,7 @@ fn build_object<'a, B: Backend<'a>>(
             let ident_ids = interns.all_ident_ids.get_mut(&module_id).unwrap();
 
             match mode {
-                AssemblyBackendMode::Test => {
+                AssemblyBackendMode::Test | AssemblyBackendMode::CliTest => {
                     let test_helper = roc_mono::code_gen_help::test_helper(
                         code_gen_help,
                         ident_ids,
@@ -586,7 +582,7 @@ fn build_object<'a, B: Backend<'a>>(
                         Exposed::TestMain,
                     );
                 }
-                AssemblyBackendMode::Binary => { /* do nothing */ }
+                AssemblyBackendMode::Binary | AssemblyBackendMode::BinaryDev => { /* do nothing */ }
             }
 
             build_proc_symbol(
@@ -618,16 +614,21 @@ fn build_object<'a, B: Backend<'a>>(
             layout,
             proc,
             Exposed::NotExposed,
-        )
+        );
+
+        let (_, _, proc_id, _) = procs.last().unwrap();
+        proc_symbols.push((sym, layout, *proc_id));
     }
 
     // Build procedures from user code
     let mut relocations = bumpalo::vec![in arena];
+    let mut debug_procs = std::vec::Vec::new();
     for (fn_name, section_id, proc_id, proc) in procs {
         build_proc(
             &mut output,
             &mut backend,
             &mut relocations,
+            &mut debug_procs,
             &mut layout_ids,
             data_section,
             fn_name,
@@ -637,6 +638,11 @@ fn build_object<'a, B: Backend<'a>>(
         )
     }
 
+    let proc_sizes = proc_symbols
+        .into_iter()
+        .map(|(sym, layout, proc_id)| (sym, layout, Some(output.symbol(proc_id).size)))
+        .collect();
+
     // Generate IR for specialized helper procs (refcounting & equality)
     let empty = bumpalo::collections::Vec::new_in(arena);
     let mut helper_symbols_and_layouts =
@@ -719,6 +725,7 @@ fn build_object<'a, B: Backend<'a>>(
             &mut output,
             &mut backend,
             &mut relocations,
+            &mut debug_procs,
             &mut layout_ids,
             data_section,
             fn_name,
@@ -735,7 +742,20 @@ fn build_object<'a, B: Backend<'a>>(
             Err(e) => internal_error!("{:?}", e),
         }
     }
-    output
+
+    // Debug info for all procedures (user code & helpers)
+    if let Some(debug_sources) = &backend.env().debug_sources {
+        if output.format() == BinaryFormat::Elf {
+            write_debug_sections(
+                &mut output,
+                debug_sources,
+                backend.env().module_id,
+                &debug_procs,
+            );
+        }
+    }
+
+    (output, proc_sizes)
 }
 
 fn build_exposed_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'a>) -> Proc<'a> {
@@ -766,7 +786,8 @@ fn build_exposed_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'a>) -> P
         s4,
         Expr::Call(call),
         proc.ret_layout,
-        arena.alloc(Stmt::Ret(s4)),
+        arena.alloc(Stmt::Ret(s4, Region::zero())),
+        Region::zero(),
     );
 
     Proc {
@@ -777,6 +798,7 @@ fn build_exposed_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'a>) -> P
         ret_layout: proc.ret_layout,
         is_self_recursive: roc_mono::ir::SelfRecursive::NotSelfRecursive,
         is_erased: proc.is_erased,
+        region: Region::zero(),
     }
 }
 
@@ -842,12 +864,15 @@ fn build_exposed_generic_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'
                         Layout::UNIT,
                         arena.alloc(
                             //
-                            Stmt::Ret(s3),
+                            Stmt::Ret(s3, Region::zero()),
                         ),
+                        Region::zero(),
                     ),
                 ),
+                Region::zero(),
             ),
         ),
+        Region::zero(),
     );
 
     Proc {
@@ -858,6 +883,7 @@ fn build_exposed_generic_proc<'a, B: Backend<'a>>(backend: &mut B, proc: &Proc<'
         ret_layout: roc_mono::layout::Layout::UNIT,
         is_self_recursive: roc_mono::ir::SelfRecursive::NotSelfRecursive,
         is_erased: proc.is_erased,
+        region: Region::zero(),
     }
 }
 
@@ -926,6 +952,7 @@ fn build_proc<'a, B: Backend<'a>>(
     output: &mut Object,
     backend: &mut B,
     relocations: &mut Vec<'a, (SectionId, object::write::Relocation)>,
+    debug_procs: &mut std::vec::Vec<DebugProc>,
     layout_ids: &mut LayoutIds<'a>,
     data_section: SectionId,
     fn_name: String,
@@ -935,8 +962,12 @@ fn build_proc<'a, B: Backend<'a>>(
 ) {
     let mut local_data_index = 0;
     let target = backend.target();
+    let proc_symbol = proc.name.name();
+    let proc_region = proc.region;
     let (proc_data, relocs, rc_proc_names) = backend.build_proc(proc, layout_ids);
     let proc_offset = output.add_symbol_data(proc_id, section_id, &proc_data, 16);
+    let proc_size = proc_data.len() as u64;
+
     for reloc in relocs.iter() {
         let elfreloc = match reloc {
             Relocation::LocalData { offset, data } => {
@@ -1037,8 +1068,10 @@ fn build_proc<'a, B: Backend<'a>>(
                 }
             }
             Relocation::LinkedFunction { offset, name } => {
-                // If the symbol is an undefined roc function, we need to add it here.
-                if output.symbol_id(name.as_bytes()).is_none() && name.starts_with("roc_") {
+                // If the symbol is an undefined roc function, or a compiler_rt function (which
+                // is bundled with the builtins), we need to add it here.
+                let is_external = name.starts_with("roc_") || name.starts_with("__");
+                if output.symbol_id(name.as_bytes()).is_none() && is_external {
                     let builtin_symbol = Symbol {
                         name: name.as_bytes().to_vec(),
                         value: 0,
@@ -1064,6 +1097,19 @@ fn build_proc<'a, B: Backend<'a>>(
         };
         relocations.push((section_id, elfreloc));
     }
+
+    let statements = backend.take_statements();
+    if backend.env().debug_sources.is_some() {
+        debug_procs.push(DebugProc {
+            symbol: proc_symbol,
+            region: proc_region,
+            name: proc_symbol.as_str(backend.interns()).to_string(),
+            symbol_id: proc_id,
+            linkage_name: fn_name,
+            size: proc_size,
+            statements: statements.into_iter().collect(),
+        });
+    }
 }
 
 fn add_undefined_rc_proc(
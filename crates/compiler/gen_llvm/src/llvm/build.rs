@@ -3,6 +3,7 @@ use crate::llvm::build_list::{self, allocate_list, empty_polymorphic_list};
 use crate::llvm::convert::{
     argument_type_from_layout, basic_type_from_builtin, basic_type_from_layout, zig_str_type,
 };
+use crate::llvm::debug_info;
 use crate::llvm::expect::{clone_to_shared_memory, SharedMemoryPointer};
 use crate::llvm::memcpy::build_memcpy;
 use crate::llvm::refcounting::{
@@ -43,6 +44,7 @@ use roc_debug_flags::dbg_do;
 use roc_debug_flags::ROC_PRINT_LLVM_FN_VERIFICATION;
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
+use roc_mono::debug_sources::DebugSources;
 use roc_mono::ir::{
     BranchInfo, CallType, CrashTag, EntryPoint, GlueLayouts, HostExposedLambdaSet,
     HostExposedLambdaSets, ListLiteralElement, ModifyRc, OptLevel, ProcLayout, SingleEntryPoint,
@@ -739,6 +741,9 @@ pub struct Env<'a, 'ctx, 'env> {
     pub target: Target,
     pub mode: LlvmBackendMode,
     pub exposed_to_host: MutSet<Symbol>,
+    /// Where procs and their variables are in the source. Without these, debug info
+    /// only has the names of the functions.
+    pub debug_sources: Option<&'env DebugSources>,
 }
 
 impl<'a, 'ctx, 'env> Env<'a, 'ctx, 'env> {
@@ -3224,25 +3229,27 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
     use roc_mono::ir::Stmt::*;
 
     match stmt {
-        Let(first_symbol, first_expr, first_layout, mut cont) => {
+        Let(first_symbol, first_expr, first_layout, mut cont, first_region) => {
             let mut queue = Vec::new_in(env.arena);
 
-            queue.push((first_symbol, first_expr, first_layout));
+            queue.push((first_symbol, first_expr, first_layout, first_region));
 
-            while let Let(symbol, expr, layout, new_cont) = cont {
-                queue.push((symbol, expr, layout));
+            while let Let(symbol, expr, layout, new_cont, region) = cont {
+                queue.push((symbol, expr, layout, region));
 
                 cont = new_cont;
             }
 
             let mut stack = Vec::with_capacity_in(queue.len(), env.arena);
 
-            for (symbol, expr, layout) in queue {
+            for (symbol, expr, layout, region) in queue {
                 debug_assert!(!matches!(
                     layout_interner.get_repr(*layout),
                     LayoutRepr::RecursivePointer(_)
                 ));
 
+                debug_info::enter_statement(env, scope.debug_scope(), *region);
+
                 let val = build_exp_expr(
                     env,
                     layout_interner,
@@ -3254,6 +3261,17 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
                     expr,
                 );
 
+                debug_info::declare_local(
+                    env,
+                    layout_interner,
+                    parent,
+                    scope.debug_scope(),
+                    *symbol,
+                    *layout,
+                    val,
+                    *region,
+                );
+
                 // Make a new scope which includes the binding we just encountered.
                 // This should be done *after* compiling the bound expr, since any
                 // recursive (in the LetRec sense) bindings should already have
@@ -3281,7 +3299,9 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
 
             result
         }
-        Ret(symbol) => {
+        Ret(symbol, region) => {
+            debug_info::enter_statement(env, scope.debug_scope(), *region);
+
             let (value, layout) = scope.load_symbol_and_layout(symbol);
 
             build_return(
@@ -3301,7 +3321,10 @@ pub(crate) fn build_exp_stmt<'a, 'ctx>(
             ret_layout,
             cond_layout,
             cond_symbol,
+            region,
         } => {
+            debug_info::enter_statement(env, scope.debug_scope(), *region);
+
             let ret_type =
                 basic_type_from_layout(env, layout_interner, layout_interner.get_repr(*ret_layout));
 
@@ -5495,6 +5518,7 @@ pub(crate) fn build_proc_headers<'a, 'r, 'ctx>(
     layout_ids: &mut LayoutIds<'a>,
     // alias_analysis_solutions: AliasAnalysisSolutions,
 ) -> std::vec::Vec<(
+    ProcLayout<'a>,
     roc_mono::ir::Proc<'a>,
     std::vec::Vec<(&'a FuncSpecSolutions, FunctionValue<'ctx>)>,
 )> {
@@ -5532,7 +5556,7 @@ pub(crate) fn build_proc_headers<'a, 'r, 'ctx>(
 
             function_values.push((func_spec_solutions, fn_val));
         }
-        headers.push((proc, function_values));
+        headers.push((layout, proc, function_values));
     }
 
     headers
@@ -5547,8 +5571,8 @@ pub fn build_procedures<'a>(
     entry_point: EntryPoint<'a>,
     debug_output_file: Option<&Path>,
     glue_layouts: &GlueLayouts<'a>,
-) {
-    let mod_solutions = build_procedures_help(
+) -> (ProcFunctionNames<'a>, &'a ModSolutions) {
+    let (mod_solutions, function_names) = build_procedures_help(
         env,
         layout_interner,
         opt_level,
@@ -5593,6 +5617,8 @@ pub fn build_procedures<'a>(
             getter_name,
         );
     }
+
+    (function_names, mod_solutions)
 }
 
 pub fn build_wasm_test_wrapper<'a, 'ctx>(
@@ -5602,7 +5628,7 @@ pub fn build_wasm_test_wrapper<'a, 'ctx>(
     procedures: MutMap<(Symbol, ProcLayout<'a>), roc_mono::ir::Proc<'a>>,
     entry_point: SingleEntryPoint<'a>,
 ) -> (&'static str, FunctionValue<'ctx>) {
-    let mod_solutions = build_procedures_help(
+    let (mod_solutions, _) = build_procedures_help(
         env,
         layout_interner,
         opt_level,
@@ -5629,7 +5655,7 @@ pub fn build_procedures_return_main<'a, 'ctx>(
     host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
     entry_point: SingleEntryPoint<'a>,
 ) -> (&'static str, FunctionValue<'ctx>) {
-    let mod_solutions = build_procedures_help(
+    let (mod_solutions, _) = build_procedures_help(
         env,
         layout_interner,
         opt_level,
@@ -5663,7 +5689,7 @@ pub fn build_procedures_expose_expects<'a>(
         symbols: &flattened_symbols,
     };
 
-    let mod_solutions = build_procedures_help(
+    let (mod_solutions, _) = build_procedures_help(
         env,
         layout_interner,
         opt_level,
@@ -5746,7 +5772,7 @@ fn build_procedures_help<'a>(
     host_exposed_lambda_sets: HostExposedLambdaSets<'a>,
     entry_point: EntryPoint<'a>,
     debug_output_file: Option<&Path>,
-) -> &'a ModSolutions {
+) -> (&'a ModSolutions, ProcFunctionNames<'a>) {
     let mut layout_ids = roc_mono::layout::LayoutIds::default();
     let mut scope = Scope::default();
 
@@ -5785,8 +5811,13 @@ fn build_procedures_help<'a>(
 
     let (_, function_pass) = construct_optimization_passes(env.module, opt_level);
 
-    for (proc, fn_vals) in headers {
+    let mut function_names = std::vec::Vec::with_capacity(headers.len());
+
+    for (layout, proc, fn_vals) in headers {
         for (func_spec_solutions, fn_val) in fn_vals {
+            let name = fn_val.get_name().to_str().unwrap().to_string();
+            function_names.push((proc.name.name(), layout, name));
+
             let mut current_scope = scope.clone();
 
             // only have top-level thunks for this proc's module in scope
@@ -5861,9 +5892,13 @@ fn build_procedures_help<'a>(
         }
     }
 
-    mod_solutions
+    (mod_solutions, function_names)
 }
 
+/// The LLVM function names given to each specialization of each procedure,
+/// so their code can be found in the emitted object file.
+pub type ProcFunctionNames<'a> = std::vec::Vec<(Symbol, ProcLayout<'a>, String)>;
+
 pub enum FuncBorrowSpec {
     /// This function has an specialization due to alias analysis.
     Some(FuncSpec),
@@ -5937,7 +5972,8 @@ fn build_proc_header<'a, 'ctx>(
         Linkage::Internal,
     );
 
-    let subprogram = env.new_subprogram(&fn_name);
+    let subprogram = debug_info::source_subprogram(env, layout_interner, &fn_name, proc)
+        .unwrap_or_else(|| env.new_subprogram(&fn_name));
     fn_val.set_subprogram(subprogram);
 
     debug_info_init!(env, fn_val);
@@ -6252,6 +6288,9 @@ fn build_proc<'a, 'ctx>(
         scope.insert(*arg_symbol, *layout, arg_val);
     }
 
+    let debug_scope = debug_info::enter_proc(env, layout_interner, fn_val, proc, args);
+    scope.set_debug_scope(debug_scope);
+
     let body = build_exp_stmt(
         env,
         layout_interner,
@@ -0,0 +1,602 @@
+//! The DWARF the LLVM backend emits when asked for debug info.
+//!
+//! Every proc with a place in the source gets a subprogram typed with the layouts of its
+//! arguments and return value. Every statement gets a debug location, so debuggers can step
+//! through Roc code line by line, and the arguments and let-bindings the user named become local
+//! variables, with DWARF types describing their layouts.
+use bumpalo::collections::Vec;
+use inkwell::debug_info::{
+    AsDIScope, DIFile, DIFlags, DIFlagsConstants, DILocalVariable, DISubprogram, DIType,
+};
+use inkwell::values::{BasicValueEnum, FunctionValue};
+use inkwell::AddressSpace;
+use roc_module::symbol::{ModuleId, Symbol};
+use roc_mono::ir::Proc;
+use roc_mono::layout::{
+    Builtin, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner, UnionLayout,
+};
+use roc_region::all::Region;
+
+use super::build::{create_entry_block_alloca, BuilderExt, Env};
+
+// DWARF base type encodings
+const DW_ATE_BOOLEAN: u32 = 0x02;
+const DW_ATE_FLOAT: u32 = 0x04;
+const DW_ATE_SIGNED: u32 = 0x05;
+const DW_ATE_UNSIGNED: u32 = 0x08;
+
+#[derive(Debug, Clone, Copy)]
+struct SourceLocation<'ctx> {
+    file: DIFile<'ctx>,
+    /// 1-based, like DWARF wants it.
+    line: u32,
+    /// 1-based, like DWARF wants it.
+    column: u32,
+}
+
+/// Where `region` of `module_id` starts, if we are emitting debug info and have its source.
+fn source_location<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    module_id: ModuleId,
+    region: Region,
+) -> Option<SourceLocation<'ctx>> {
+    let debug_sources = env.debug_sources?;
+    let location = debug_sources.location(module_id, region)?;
+    let (path, _) = debug_sources.file(module_id)?;
+
+    // LLVM uniques file metadata, so this is the same file for every location in a module.
+    let filename = path.file_name().unwrap_or_default().to_string_lossy();
+    let directory = match path.parent() {
+        Some(parent) => parent.to_string_lossy(),
+        None => ".".into(),
+    };
+    let file = env.dibuilder.create_file(&filename, &directory);
+
+    Some(SourceLocation {
+        file,
+        line: location.line + 1,
+        column: location.column + 1,
+    })
+}
+
+/// Whether the user gave `symbol` its name. The compiler numbers the values it makes up, and
+/// those aren't worth showing in a debugger.
+fn is_user_named(env: &Env, symbol: Symbol) -> bool {
+    symbol
+        .as_str(&env.interns)
+        .starts_with(|c: char| c.is_ascii_lowercase())
+}
+
+/// The subprogram of the proc being built and the module it is from, which all the statements
+/// in the proc are from as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DebugScope<'ctx> {
+    subprogram: DISubprogram<'ctx>,
+    module_id: ModuleId,
+}
+
+/// A subprogram for `proc`, placed where the user defined it, with the layouts of its arguments
+/// and return value. [None] if we don't know where that is, e.g. for procs the compiler
+/// generated.
+pub(crate) fn source_subprogram<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    fn_name: &str,
+    proc: &Proc<'a>,
+) -> Option<DISubprogram<'ctx>> {
+    let symbol = proc.name.name();
+    let location = source_location(env, symbol.module_id(), proc.region)?;
+
+    let return_type = debug_type(env, layout_interner, proc.ret_layout);
+    let parameter_types = Vec::from_iter_in(
+        proc.args
+            .iter()
+            .map(|(layout, _)| debug_type(env, layout_interner, *layout)),
+        env.arena,
+    );
+
+    let subroutine_type = env.dibuilder.create_subroutine_type(
+        location.file,
+        Some(return_type),
+        &parameter_types,
+        DIFlags::PUBLIC,
+    );
+
+    Some(env.dibuilder.create_function(
+        /* scope */ location.file.as_debug_info_scope(),
+        /* func name */ symbol.as_str(&env.interns),
+        /* linkage_name */ Some(fn_name),
+        /* file */ location.file,
+        /* line_no */ location.line,
+        /* DIType */ subroutine_type,
+        /* is_local_to_unit */ true,
+        /* is_definition */ true,
+        /* scope_line */ location.line,
+        /* flags */ DIFlags::PUBLIC,
+        /* is_optimized */ false,
+    ))
+}
+
+/// The debug scope of `fn_val`, if it is the body of `proc` and that has a place in the source.
+/// Its arguments are declared as variables at the start of the proc, and the builder is moved
+/// there.
+pub(crate) fn enter_proc<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    fn_val: FunctionValue<'ctx>,
+    proc: &Proc<'a>,
+    args: &[(InLayout<'a>, Symbol)],
+) -> Option<DebugScope<'ctx>> {
+    let module_id = proc.name.name().module_id();
+    let location = source_location(env, module_id, proc.region)?;
+    let subprogram = fn_val.get_subprogram()?;
+    let scope = subprogram.as_debug_info_scope();
+
+    let di_location = env.dibuilder.create_debug_location(
+        env.context,
+        location.line,
+        location.column,
+        scope,
+        None,
+    );
+    env.builder.set_current_debug_location(di_location);
+
+    for (arg_no, (arg_val, (layout, arg_symbol))) in fn_val.get_param_iter().zip(args).enumerate() {
+        if !is_user_named(env, *arg_symbol) {
+            continue;
+        }
+
+        let variable = env.dibuilder.create_parameter_variable(
+            scope,
+            arg_symbol.as_str(&env.interns),
+            arg_no as u32 + 1,
+            location.file,
+            location.line,
+            value_debug_type(env, layout_interner, *layout),
+            true,
+            DIFlags::ZERO,
+        );
+
+        declare(
+            env,
+            fn_val,
+            subprogram,
+            *arg_symbol,
+            location,
+            variable,
+            arg_val,
+        );
+    }
+
+    Some(DebugScope {
+        subprogram,
+        module_id,
+    })
+}
+
+/// Move the builder to where the statement at `region` is, so the code for it is attributed to
+/// that line. Statements the compiler made up keep the location of the code before them.
+pub(crate) fn enter_statement<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    debug_scope: Option<DebugScope<'ctx>>,
+    region: Region,
+) {
+    let Some(debug_scope) = debug_scope else {
+        return;
+    };
+    let Some(location) = source_location(env, debug_scope.module_id, region) else {
+        return;
+    };
+
+    let di_location = env.dibuilder.create_debug_location(
+        env.context,
+        location.line,
+        location.column,
+        debug_scope.subprogram.as_debug_info_scope(),
+        None,
+    );
+    env.builder.set_current_debug_location(di_location);
+}
+
+/// Declare `symbol`, which was just computed to be `value` by the let at `region`, as a local
+/// variable, if the user named it.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn declare_local<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    parent: FunctionValue<'ctx>,
+    debug_scope: Option<DebugScope<'ctx>>,
+    symbol: Symbol,
+    layout: InLayout<'a>,
+    value: BasicValueEnum<'ctx>,
+    region: Region,
+) {
+    let Some(DebugScope {
+        subprogram,
+        module_id,
+    }) = debug_scope
+    else {
+        return;
+    };
+    let Some(location) = source_location(env, module_id, region) else {
+        return;
+    };
+    if !is_user_named(env, symbol) {
+        return;
+    }
+
+    let variable = env.dibuilder.create_auto_variable(
+        subprogram.as_debug_info_scope(),
+        symbol.as_str(&env.interns),
+        location.file,
+        location.line,
+        value_debug_type(env, layout_interner, layout),
+        true,
+        DIFlags::ZERO,
+        0,
+    );
+
+    declare(env, parent, subprogram, symbol, location, variable, value);
+}
+
+/// Spill `value` to the stack so the debugger can find it there as `variable`.
+fn declare<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    parent: FunctionValue<'ctx>,
+    subprogram: DISubprogram<'ctx>,
+    symbol: Symbol,
+    location: SourceLocation<'ctx>,
+    variable: DILocalVariable<'ctx>,
+    value: BasicValueEnum<'ctx>,
+) {
+    // Nothing can come after a value that crashes.
+    let Some(block) = env.builder.get_insert_block() else {
+        return;
+    };
+    if block.get_terminator().is_some() {
+        return;
+    }
+
+    let name = symbol.as_str(&env.interns);
+    let storage = create_entry_block_alloca(env, parent, value.get_type(), name);
+    env.builder.new_build_store(storage, value);
+
+    let di_location = env.dibuilder.create_debug_location(
+        env.context,
+        location.line,
+        location.column,
+        subprogram.as_debug_info_scope(),
+        None,
+    );
+
+    env.dibuilder
+        .insert_declare_at_end(storage, Some(variable), None, di_location, block);
+}
+
+/// The type of a value of this layout as we pass it around, which is a pointer to the data
+/// for the layouts that are passed by reference.
+fn value_debug_type<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> DIType<'ctx> {
+    let ty = debug_type(env, layout_interner, layout);
+
+    if layout_interner.is_passed_by_reference(layout) {
+        pointer_type(env, ty)
+    } else {
+        ty
+    }
+}
+
+/// A DWARF description of the data of a layout.
+///
+/// Record fields and tags don't have names anymore at this point, so they are numbered.
+pub(crate) fn debug_type<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> DIType<'ctx> {
+    let (size, alignment) = layout_interner.stack_size_and_alignment(layout);
+
+    match layout_interner.get_repr(layout) {
+        LayoutRepr::Builtin(builtin) => match builtin {
+            Builtin::Int(width) => {
+                let encoding = if width.is_signed() {
+                    DW_ATE_SIGNED
+                } else {
+                    DW_ATE_UNSIGNED
+                };
+
+                basic_type(env, &format!("{width:?}"), width.stack_size(), encoding)
+            }
+            Builtin::Float(width) => {
+                basic_type(env, &format!("{width:?}"), width.stack_size(), DW_ATE_FLOAT)
+            }
+            Builtin::Bool => basic_type(env, "Bool", 1, DW_ATE_BOOLEAN),
+            // A fixed-point number, scaled by 10^18
+            Builtin::Decimal => basic_type(env, "Dec", 16, DW_ATE_SIGNED),
+            Builtin::Str => {
+                let bytes = pointer_type(env, debug_type(env, layout_interner, Layout::U8));
+
+                list_like_type(env, layout_interner, "Str", layout, bytes, "bytes")
+            }
+            Builtin::List(element) => {
+                let elements = pointer_type(env, debug_type(env, layout_interner, element));
+
+                list_like_type(env, layout_interner, "List", layout, elements, "elements")
+            }
+        },
+        LayoutRepr::Struct(fields) => {
+            let members = Vec::from_iter_in(
+                fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| (format!("field_{index}"), *field)),
+                env.arena,
+            );
+
+            struct_type(env, layout_interner, "Struct", size, alignment, &members)
+        }
+        LayoutRepr::Union(UnionLayout::NonRecursive(tags)) => {
+            let union_layout = UnionLayout::NonRecursive(tags);
+            let file = env.compile_unit.get_file();
+            let scope = env.compile_unit.as_debug_info_scope();
+
+            let payloads = Vec::from_iter_in(
+                tags.iter().enumerate().map(|(tag_id, fields)| {
+                    let members = Vec::from_iter_in(
+                        fields
+                            .iter()
+                            .enumerate()
+                            .map(|(index, field)| (format!("field_{index}"), *field)),
+                        env.arena,
+                    );
+                    let (size, alignment) =
+                        Layout::stack_size_and_alignment_slices(layout_interner, &[*fields]);
+                    let ty = struct_type(
+                        env,
+                        layout_interner,
+                        &format!("Tag{tag_id}"),
+                        size,
+                        alignment,
+                        &members,
+                    );
+
+                    env.dibuilder
+                        .create_member_type(
+                            scope,
+                            &format!("tag_{tag_id}"),
+                            file,
+                            0,
+                            8 * size as u64,
+                            8 * alignment,
+                            0,
+                            DIFlags::PUBLIC,
+                            ty,
+                        )
+                        .as_type()
+                }),
+                env.arena,
+            );
+
+            let payload = env.dibuilder.create_union_type(
+                scope,
+                "Payload",
+                file,
+                0,
+                8 * size as u64,
+                8 * alignment,
+                DIFlags::PUBLIC,
+                &payloads,
+                0,
+                "",
+            );
+
+            let tag_id_layout = union_layout.tag_id_layout();
+            let tag_id_offset = union_layout.tag_id_offset(layout_interner).unwrap_or(0);
+
+            let mut members = Vec::with_capacity_in(2, env.arena);
+            members.push(member_type(
+                env,
+                "payload",
+                payload.as_type(),
+                size,
+                alignment,
+                0,
+            ));
+            members.push(member_type(
+                env,
+                "tag_id",
+                debug_type(env, layout_interner, tag_id_layout),
+                layout_interner.stack_size(tag_id_layout),
+                layout_interner.alignment_bytes(tag_id_layout),
+                tag_id_offset,
+            ));
+
+            env.dibuilder
+                .create_struct_type(
+                    scope,
+                    "TagUnion",
+                    file,
+                    0,
+                    8 * size as u64,
+                    8 * alignment,
+                    DIFlags::PUBLIC,
+                    None,
+                    &members,
+                    0,
+                    None,
+                    "",
+                )
+                .as_type()
+        }
+        LayoutRepr::Union(_) => pointer_type(env, opaque_type(env, "TagUnion")),
+        LayoutRepr::LambdaSet(lambda_set) => {
+            debug_type(env, layout_interner, lambda_set.runtime_representation())
+        }
+        LayoutRepr::Ptr(inner) => pointer_type(env, debug_type(env, layout_interner, inner)),
+        LayoutRepr::RecursivePointer(_) => pointer_type(env, opaque_type(env, "TagUnion")),
+        LayoutRepr::FunctionPointer(_) => pointer_type(env, opaque_type(env, "Function")),
+        LayoutRepr::Erased(_) => pointer_type(env, opaque_type(env, "Erased")),
+    }
+}
+
+/// `Str` and `List`: a pointer to the data, a length, and a capacity.
+fn list_like_type<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    name: &str,
+    layout: InLayout<'a>,
+    pointer: DIType<'ctx>,
+    pointer_name: &str,
+) -> DIType<'ctx> {
+    let ptr_bytes = env.target.ptr_width() as u32;
+    let usize_type = debug_type(env, layout_interner, Layout::usize(env.target));
+
+    let members = [
+        member_type(env, pointer_name, pointer, ptr_bytes, ptr_bytes, 0),
+        member_type(env, "length", usize_type, ptr_bytes, ptr_bytes, ptr_bytes),
+        member_type(
+            env,
+            "capacity",
+            usize_type,
+            ptr_bytes,
+            ptr_bytes,
+            2 * ptr_bytes,
+        ),
+    ];
+
+    let (size, alignment) = layout_interner.stack_size_and_alignment(layout);
+
+    env.dibuilder
+        .create_struct_type(
+            env.compile_unit.as_debug_info_scope(),
+            name,
+            env.compile_unit.get_file(),
+            0,
+            8 * size as u64,
+            8 * alignment,
+            DIFlags::PUBLIC,
+            None,
+            &members,
+            0,
+            None,
+            "",
+        )
+        .as_type()
+}
+
+/// A struct with these fields, laid out in order like the LLVM struct types we generate.
+fn struct_type<'a, 'ctx>(
+    env: &Env<'a, 'ctx, '_>,
+    layout_interner: &STLayoutInterner<'a>,
+    name: &str,
+    size: u32,
+    alignment: u32,
+    fields: &[(String, InLayout<'a>)],
+) -> DIType<'ctx> {
+    let mut offset = 0;
+
+    let members = Vec::from_iter_in(
+        fields.iter().map(|(field_name, field)| {
+            let (field_size, field_alignment) = layout_interner.stack_size_and_alignment(*field);
+            offset = offset.next_multiple_of(field_alignment.max(1));
+
+            let member = member_type(
+                env,
+                field_name,
+                debug_type(env, layout_interner, *field),
+                field_size,
+                field_alignment,
+                offset,
+            );
+
+            offset += field_size;
+
+            member
+        }),
+        env.arena,
+    );
+
+    env.dibuilder
+        .create_struct_type(
+            env.compile_unit.as_debug_info_scope(),
+            name,
+            env.compile_unit.get_file(),
+            0,
+            8 * size as u64,
+            8 * alignment,
+            DIFlags::PUBLIC,
+            None,
+            &members,
+            0,
+            None,
+            "",
+        )
+        .as_type()
+}
+
+fn member_type<'ctx>(
+    env: &Env<'_, 'ctx, '_>,
+    name: &str,
+    ty: DIType<'ctx>,
+    size: u32,
+    alignment: u32,
+    offset: u32,
+) -> DIType<'ctx> {
+    env.dibuilder
+        .create_member_type(
+            env.compile_unit.as_debug_info_scope(),
+            name,
+            env.compile_unit.get_file(),
+            0,
+            8 * size as u64,
+            8 * alignment,
+            8 * offset as u64,
+            DIFlags::PUBLIC,
+            ty,
+        )
+        .as_type()
+}
+
+fn basic_type<'ctx>(env: &Env<'_, 'ctx, '_>, name: &str, size: u32, encoding: u32) -> DIType<'ctx> {
+    env.dibuilder
+        .create_basic_type(name, 8 * size as u64, encoding, DIFlags::PUBLIC)
+        .unwrap()
+        .as_type()
+}
+
+fn pointer_type<'ctx>(env: &Env<'_, 'ctx, '_>, pointee: DIType<'ctx>) -> DIType<'ctx> {
+    let ptr_bits = 8 * env.target.ptr_width() as u32;
+
+    env.dibuilder
+        .create_pointer_type(
+            "",
+            pointee,
+            ptr_bits as u64,
+            ptr_bits,
+            AddressSpace::default(),
+        )
+        .as_type()
+}
+
+/// A type the debugger can only show the address of.
+fn opaque_type<'ctx>(env: &Env<'_, 'ctx, '_>, name: &str) -> DIType<'ctx> {
+    env.dibuilder
+        .create_struct_type(
+            env.compile_unit.as_debug_info_scope(),
+            name,
+            env.compile_unit.get_file(),
+            0,
+            0,
+            8,
+            DIFlags::FWD_DECL,
+            None,
+            &[],
+            0,
+            None,
+            "",
+        )
+        .as_type()
+}
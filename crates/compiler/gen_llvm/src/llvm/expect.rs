@@ -82,7 +82,7 @@ fn write_header<'ctx>(
         .i32_type()
         .const_int(region.end().offset as _, false);
 
-    let module_id: u32 = unsafe { std::mem::transmute(condition.module_id()) };
+    let module_id: u32 = condition.module_id().to_raw_u32();
     let module_id = env.context.i32_type().const_int(module_id as _, false);
 
     offset = build_copy(env, ptr, offset, region_start.into());
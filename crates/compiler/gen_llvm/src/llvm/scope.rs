@@ -1,3 +1,4 @@
+use crate::llvm::debug_info::DebugScope;
 use inkwell::{
     basic_block::BasicBlock,
     values::{BasicValueEnum, FunctionValue, PhiValue},
@@ -14,6 +15,8 @@ pub(crate) struct Scope<'a, 'ctx> {
     symbols: ImMap<Symbol, (InLayout<'a>, BasicValueEnum<'ctx>)>,
     top_level_thunks: ImMap<Symbol, (ProcLayout<'a>, FunctionValue<'ctx>)>,
     join_points: ImMap<JoinPointId, (BasicBlock<'ctx>, Vec<PhiValue<'ctx>>)>,
+    /// The debug scope of the proc we're building, if it has a place in the source.
+    debug_scope: Option<DebugScope<'ctx>>,
 }
 
 #[derive(Debug)]
@@ -67,6 +70,14 @@ impl<'a, 'ctx> Scope<'a, 'ctx> {
         self.join_points.insert(join_point_id, (bb, phis));
     }
 
+    pub fn set_debug_scope(&mut self, debug_scope: Option<DebugScope<'ctx>>) {
+        self.debug_scope = debug_scope;
+    }
+
+    pub fn debug_scope(&self) -> Option<DebugScope<'ctx>> {
+        self.debug_scope
+    }
+
     pub fn remove_join_point(&mut self, join_point_id: JoinPointId) {
         self.join_points.remove(&join_point_id);
     }
@@ -5,7 +5,7 @@ use roc_builtins::bitcode::{self, FloatWidth, IntWidth};
 use roc_collections::all::MutMap;
 use roc_error_macros::{internal_error, todo_lambda_erasure};
 use roc_module::low_level::{LowLevel, LowLevelWrapperType};
-use roc_module::symbol::{Interns, Symbol};
+use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::{CodeGenHelp, HelperOp, REFCOUNT_MAX};
 use roc_mono::ir::{
     BranchInfo, CallType, CrashTag, Expr, JoinPointId, ListLiteralElement, Literal, ModifyRc,
@@ -15,6 +15,7 @@ use roc_mono::layout::{
     Builtin, InLayout, Layout, LayoutIds, LayoutInterner, LayoutRepr, STLayoutInterner,
     TagIdIntType, UnionLayout,
 };
+use roc_region::all::Region;
 use roc_std::RocDec;
 
 use roc_wasm_module::linking::{DataSymbol, WasmObjectSymbol};
@@ -27,9 +28,10 @@ use roc_wasm_module::{
 };
 
 use crate::code_builder::CodeBuilder;
-use crate::layout::{ReturnMethod, WasmLayout};
+use crate::layout::{stack_memory_multi_value_types, ReturnMethod, WasmLayout};
 use crate::low_level::{call_higher_order_lowlevel, LowLevelCall};
-use crate::storage::{AddressValue, Storage, StoredValue, StoredVarKind};
+use crate::source_map;
+use crate::storage::{AddressValue, StackMemoryLocation, Storage, StoredValue, StoredVarKind};
 use crate::{
     copy_memory, CopyMemoryConfig, Env, DEBUG_SETTINGS, MEMORY_NAME, PTR_SIZE, PTR_TYPE, TARGET,
 };
@@ -65,6 +67,8 @@ pub struct WasmBackend<'a, 'r> {
     host_lookup: Vec<'a, (&'a str, u32)>,
     helper_proc_gen: CodeGenHelp<'a>,
     can_relocate_heap: bool,
+    /// Index of each module's source file in the source map
+    source_map_files: MutMap<ModuleId, u32>,
 
     // Function-level data
     pub code_builder: CodeBuilder<'a>,
@@ -73,6 +77,9 @@ pub struct WasmBackend<'a, 'r> {
     /// how many blocks deep are we (used for jumps)
     block_depth: u32,
     joinpoint_label_map: MutMap<JoinPointId, (u32, Vec<'a, StoredValue>)>,
+    return_method: ReturnMethod,
+    /// The symbol defined by a call whose result is returned straight away
+    tail_call_symbol: Option<Symbol>,
 }
 
 impl<'a, 'r> WasmBackend<'a, 'r> {
@@ -135,10 +142,13 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             host_lookup,
             helper_proc_gen,
             can_relocate_heap: has_heap_base && has_heap_end,
+            source_map_files: MutMap::default(),
 
             // Function-level data
             block_depth: 0,
             joinpoint_label_map: MutMap::default(),
+            return_method: ReturnMethod::NoReturnValue,
+            tail_call_symbol: None,
             code_builder: CodeBuilder::new(env.arena),
             storage: Storage::new(env.arena),
         }
@@ -339,7 +349,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena],
-            ret_type: None,
+            ret_types: bumpalo::vec![in self.env.arena],
         });
 
         self.module.export.append(Export {
@@ -409,11 +419,14 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.append_proc_debug_name(proc.name.name());
 
+        self.mark_statement(proc.region);
+
         self.start_proc(proc);
 
         self.stmt(&proc.body);
 
         self.finalize_proc();
+        self.append_proc_debug_info(proc.name.name());
         self.reset();
 
         if DEBUG_SETTINGS.proc_start_end {
@@ -423,15 +436,14 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
     fn start_proc(&mut self, proc: &Proc<'a>) {
         use ReturnMethod::*;
-        let ret_layout = WasmLayout::new(self.layout_interner, proc.ret_layout);
-
-        let ret_type = match ret_layout.return_method() {
-            Primitive(ty, _) => Some(ty),
-            NoReturnValue => None,
-            WriteToPointerArg => {
-                self.storage.arg_types.push(PTR_TYPE);
-                None
-            }
+        self.return_method = self.proc_return_method(proc.name.name(), proc.ret_layout);
+
+        let mut ret_types = Vec::with_capacity_in(4, self.env.arena);
+        match self.return_method {
+            Primitive(ty, _) => ret_types.push(ty),
+            MultiValue(types) => ret_types.extend_from_slice(types),
+            NoReturnValue => {}
+            WriteToPointerArg => self.storage.arg_types.push(PTR_TYPE),
         };
 
         // Create a block so we can exit the function without skipping stack frame "pop" code.
@@ -445,14 +457,14 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             self.env.arena,
         );
 
-        if let Some(ty) = ret_type {
-            let ret_var = self.storage.create_anonymous_local(ty);
-            self.storage.return_var = Some(ret_var);
+        for ty in ret_types.iter() {
+            let ret_var = self.storage.create_anonymous_local(*ty);
+            self.storage.return_vars.push(ret_var);
         }
 
         self.module.add_function_signature(Signature {
             param_types: self.storage.arg_types.clone(),
-            ret_type,
+            ret_types,
         });
     }
 
@@ -460,8 +472,8 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         // end the block from start_proc, to ensure all paths pop stack memory (if any)
         self.end_block();
 
-        if let Some(ret_var) = self.storage.return_var {
-            self.code_builder.get_local(ret_var);
+        for ret_var in self.storage.return_vars.iter() {
+            self.code_builder.get_local(*ret_var);
         }
 
         // Write local declarations and stack frame push/pop code
@@ -479,18 +491,138 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         }
     }
 
-    fn append_proc_debug_name(&mut self, sym: Symbol) {
+    fn proc_fn_index(&self, sym: Symbol) -> u32 {
         let proc_index = self
             .proc_lookup
             .iter()
             .position(|ProcLookupData { name, .. }| *name == sym)
             .unwrap();
-        let wasm_fn_index = self.fn_index_offset + proc_index as u32;
+        self.fn_index_offset + proc_index as u32
+    }
 
+    fn append_proc_debug_name(&mut self, sym: Symbol) {
+        let wasm_fn_index = self.proc_fn_index(sym);
         let name = String::from_str_in(sym.as_str(self.interns), self.env.arena).into_bump_str();
         self.module.names.append_function(wasm_fn_index, name);
     }
 
+    /// Note that the code generated from here on is for the statement at `region`, if we're
+    /// making a source map. The compiler gives the statements it made up no region; their code
+    /// belongs to what came before.
+    fn mark_statement(&mut self, region: Region) {
+        if self.env.debug_sources.is_some() && region != Region::zero() {
+            self.code_builder.mark_statement(region);
+        }
+    }
+
+    /// Name the proc's local variables after the symbols stored in them,
+    /// and add the statements we marked in it to the source map.
+    /// Must be called after `finalize_proc`, while we still have the proc's storage.
+    fn append_proc_debug_info(&mut self, sym: Symbol) {
+        let arena = self.env.arena;
+        let wasm_fn_index = self.proc_fn_index(sym);
+
+        let mut locals = Vec::with_capacity_in(self.storage.symbol_storage_map.len() + 2, arena);
+        if let ReturnMethod::WriteToPointerArg = self.return_method {
+            locals.push((0, "return_pointer"));
+        }
+        if let Some(frame_pointer) = self.storage.stack_frame_pointer {
+            locals.push((frame_pointer.0, "frame_pointer"));
+        }
+        let reserved_count = locals.len();
+
+        for (symbol, storage) in self.storage.symbol_storage_map.iter() {
+            let local_id = match storage {
+                StoredValue::Local { local_id, .. } => *local_id,
+                StoredValue::StackMemory {
+                    location: StackMemoryLocation::PointerArg(local_id),
+                    ..
+                } => *local_id,
+                StoredValue::StackMemory { .. } => continue,
+            };
+            if locals[..reserved_count]
+                .iter()
+                .any(|(index, _)| *index == local_id.0)
+            {
+                continue;
+            }
+            let name = String::from_str_in(symbol.as_str(self.interns), arena).into_bump_str();
+            locals.push((local_id.0, name));
+        }
+
+        // Several symbols can share a local. Sort by name too, so we pick the same one every time.
+        locals.sort_by_key(|(index, name)| (*index, *name));
+        locals.dedup_by_key(|(index, _)| *index);
+        self.module.names.append_locals(wasm_fn_index, locals);
+
+        if let Some(debug_sources) = &self.env.debug_sources {
+            // The statements of a proc are from the same module as the proc itself.
+            source_map::append_statements(
+                arena,
+                &mut self.module.source_map,
+                &mut self.source_map_files,
+                debug_sources,
+                wasm_fn_index,
+                sym.module_id(),
+                self.code_builder.statement_offsets(),
+            );
+        }
+    }
+
+    /// How a Roc proc returns its value.
+    /// With the multi-value feature, procs that are only ever called from Roc code can return
+    /// 128-bit numbers and small data structures as several Wasm values instead of writing them
+    /// to memory. The host, Zig builtins, and our own wrappers always use the C calling convention.
+    fn proc_return_method(&self, proc_name: Symbol, ret_layout: InLayout<'a>) -> ReturnMethod {
+        let wasm_layout = WasmLayout::new(self.layout_interner, ret_layout);
+
+        if self.env.features.multi_value && !self.env.exposed_to_host.contains(&proc_name) {
+            let is_roc_proc = self
+                .proc_lookup
+                .iter()
+                .any(|lookup| lookup.name == proc_name && matches!(lookup.source, ProcSource::Roc));
+            if let (true, WasmLayout::StackMemory { size, format, .. }) =
+                (is_roc_proc, &wasm_layout)
+            {
+                if let Some(types) = stack_memory_multi_value_types(*size, *format) {
+                    return ReturnMethod::MultiValue(types);
+                }
+            }
+        }
+
+        wasm_layout.return_method()
+    }
+
+    /// Store the values returned by a multi-value call to memory.
+    /// They're on the VM stack in order, so we have to pop them into locals first.
+    fn store_multi_value_return(&mut self, types: &[ValueType], to_ptr: LocalId, to_offset: u32) {
+        use Align::*;
+
+        let mut locals = Vec::with_capacity_in(types.len(), self.env.arena);
+        for ty in types.iter().rev() {
+            let local_id = self.storage.create_anonymous_local(*ty);
+            self.code_builder.set_local(local_id);
+            locals.push(local_id);
+        }
+
+        let mut offset = to_offset;
+        for (ty, local_id) in types.iter().zip(locals.iter().rev()) {
+            self.code_builder.get_local(to_ptr);
+            self.code_builder.get_local(*local_id);
+            match ty {
+                ValueType::I32 => {
+                    self.code_builder.i32_store(Bytes4, offset);
+                    offset += 4;
+                }
+                ValueType::I64 => {
+                    self.code_builder.i64_store(Bytes8, offset);
+                    offset += 8;
+                }
+                _ => internal_error!("Unexpected multi-value return type {:?}", ty),
+            }
+        }
+    }
+
     /// Build a wrapper around a Roc procedure so that it can be called from Zig builtins List.map*
     ///
     /// The generic Zig code passes *pointers* to all of the argument values (e.g. on the heap in a List).
@@ -520,12 +652,19 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             .last()
             .map(|l| self.layout_interner.get_repr(*l))
         {
-            Some(LayoutRepr::Ptr(inner)) => WasmLayout::new(self.layout_interner, inner),
+            Some(LayoutRepr::Ptr(inner)) => inner,
             x => internal_error!("Higher-order wrapper: invalid return layout {:?}", x),
         };
 
-        let ret_type_and_size = match inner_ret_layout.return_method() {
-            ReturnMethod::NoReturnValue => None,
+        // Any locals we need come after the wrapper's arguments
+        self.storage
+            .arg_types
+            .extend(std::iter::repeat(I32).take(wrapper_arg_layouts.len()));
+
+        let inner_name = self.proc_lookup[inner_lookup_idx].name;
+        let inner_return_method = self.proc_return_method(inner_name, inner_ret_layout);
+        let ret_type_and_size = match inner_return_method {
+            ReturnMethod::NoReturnValue | ReturnMethod::MultiValue(_) => None,
             ReturnMethod::Primitive(ty, size) => {
                 // If the inner function returns a primitive, load the address to store it at
                 // After the call, it will be under the call result in the value stack
@@ -599,12 +738,17 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             }
         }
 
-        // Write empty function header (local variables array with zero length)
-        self.code_builder.build_fn_header_and_footer(&[], 0, None);
+        // If the inner function returns several values, store them all to the heap return pointer
+        if let ReturnMethod::MultiValue(types) = inner_return_method {
+            self.store_multi_value_return(types, heap_return_ptr_id, 0);
+        }
+
+        self.code_builder
+            .build_fn_header_and_footer(&self.storage.local_types, 0, None);
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena; I32; wrapper_arg_layouts.len()],
-            ret_type: None,
+            ret_types: bumpalo::vec![in self.env.arena],
         });
 
         self.append_proc_debug_name(wrapper_name);
@@ -654,7 +798,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         self.module.add_function_signature(Signature {
             param_types: bumpalo::vec![in self.env.arena; I32; 3],
-            ret_type: Some(ValueType::I32),
+            ret_types: bumpalo::vec![in self.env.arena; I32],
         });
 
         self.append_proc_debug_name(wrapper_name);
@@ -700,9 +844,12 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
     fn stmt(&mut self, stmt: &Stmt<'a>) {
         match stmt {
-            Stmt::Let(_, _, _, _) => self.stmt_let(stmt),
+            Stmt::Let(..) => self.stmt_let(stmt),
 
-            Stmt::Ret(sym) => self.stmt_ret(*sym),
+            Stmt::Ret(sym, region) => {
+                self.mark_statement(*region);
+                self.stmt_ret(*sym)
+            }
 
             Stmt::Switch {
                 cond_symbol,
@@ -710,7 +857,11 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
                 branches,
                 default_branch,
                 ret_layout: _,
-            } => self.stmt_switch(*cond_symbol, *cond_layout, branches, default_branch),
+                region,
+            } => {
+                self.mark_statement(*region);
+                self.stmt_switch(*cond_symbol, *cond_layout, branches, default_branch)
+            }
 
             Stmt::Join {
                 id,
@@ -755,16 +906,30 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
     fn stmt_let(&mut self, stmt: &Stmt<'a>) {
         let mut current_stmt = stmt;
-        while let Stmt::Let(sym, expr, layout, following) = current_stmt {
+        while let Stmt::Let(sym, expr, layout, following, region) = current_stmt {
             if DEBUG_SETTINGS.let_stmt_ir {
                 print!("\nlet {:?} = {}", sym, expr.to_pretty(200, true));
             }
 
-            let kind = match following {
-                Stmt::Ret(ret_sym) if *sym == *ret_sym => StoredVarKind::ReturnValue,
+            self.mark_statement(*region);
+
+            let is_returned = matches!(following, Stmt::Ret(ret_sym, _) if *sym == *ret_sym);
+
+            // Multi-value procs have no return pointer to write to, so they need a variable
+            let kind = match self.return_method {
+                ReturnMethod::MultiValue(_) => StoredVarKind::Variable,
+                _ if is_returned => StoredVarKind::ReturnValue,
                 _ => StoredVarKind::Variable,
             };
 
+            self.tail_call_symbol = match expr {
+                Expr::Call(roc_mono::ir::Call {
+                    call_type: CallType::ByName { .. },
+                    ..
+                }) if is_returned => Some(*sym),
+                _ => None,
+            };
+
             self.stmt_let_store_expr(*sym, *layout, expr, kind);
 
             current_stmt = *following;
@@ -796,13 +961,38 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
     fn stmt_ret(&mut self, sym: Symbol) {
         use crate::storage::StoredValue::*;
 
-        match self.storage.get(&sym) {
-            StackMemory {
-                location,
-                size,
-                alignment_bytes,
-                ..
-            } => {
+        match (self.storage.get(&sym), self.return_method) {
+            (StackMemory { location, .. }, ReturnMethod::MultiValue(types)) => {
+                // Load each word of the value into its own return variable
+                let (from_ptr, from_offset) =
+                    location.local_and_offset(self.storage.stack_frame_pointer);
+                let mut offset = from_offset;
+                for (ty, ret_var) in types.iter().zip(self.storage.return_vars.iter()) {
+                    self.code_builder.get_local(from_ptr);
+                    match ty {
+                        ValueType::I32 => {
+                            self.code_builder.i32_load(Align::Bytes4, offset);
+                            offset += 4;
+                        }
+                        ValueType::I64 => {
+                            self.code_builder.i64_load(Align::Bytes8, offset);
+                            offset += 8;
+                        }
+                        ty => internal_error!("Unexpected multi-value return type {:?}", ty),
+                    }
+                    self.code_builder.set_local(*ret_var);
+                }
+            }
+
+            (
+                StackMemory {
+                    location,
+                    size,
+                    alignment_bytes,
+                    ..
+                },
+                _,
+            ) => {
                 let (from_ptr, from_offset) =
                     location.local_and_offset(self.storage.stack_frame_pointer);
                 copy_memory(
@@ -823,8 +1013,8 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
                 // If we have a return value, store it to the return variable
                 // This avoids complications with block result types when returning from nested blocks
-                if let Some(ret_var) = self.storage.return_var {
-                    self.code_builder.set_local(ret_var);
+                if let Some(ret_var) = self.storage.return_vars.first() {
+                    self.code_builder.set_local(*ret_var);
                 }
             }
         }
@@ -1351,6 +1541,7 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
         ret_storage: &StoredValue,
     ) {
         let wasm_layout = WasmLayout::new(self.layout_interner, ret_layout);
+        let is_tail_call = self.tail_call_symbol.take() == Some(ret_sym);
 
         // If this function is just a lowlevel wrapper, then inline it
         if let LowLevelWrapperType::CanBeReplacedBy(lowlevel) =
@@ -1359,12 +1550,17 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
             return self.expr_call_low_level(lowlevel, arguments, ret_sym, ret_layout, ret_storage);
         }
 
-        self.storage.load_symbols_for_call(
-            &mut self.code_builder,
-            arguments,
-            ret_sym,
-            &wasm_layout,
-        );
+        let return_method = self.proc_return_method(func_sym, ret_layout);
+        if let ReturnMethod::MultiValue(_) = return_method {
+            self.storage.load_symbols(&mut self.code_builder, arguments);
+        } else {
+            self.storage.load_symbols_for_call(
+                &mut self.code_builder,
+                arguments,
+                ret_sym,
+                &wasm_layout,
+            );
+        }
 
         let roc_proc_index = self
             .proc_lookup
@@ -1381,7 +1577,23 @@ impl<'a, 'r> WasmBackend<'a, 'r> {
 
         let wasm_fn_index = self.fn_index_offset + roc_proc_index as u32;
 
-        self.code_builder.call(wasm_fn_index);
+        // `return_call` requires the callee to return exactly what we return
+        if is_tail_call && self.env.features.tail_call && return_method == self.return_method {
+            self.code_builder.tail_call(wasm_fn_index);
+        } else {
+            self.code_builder.call(wasm_fn_index);
+        }
+
+        if let ReturnMethod::MultiValue(types) = return_method {
+            match ret_storage {
+                StoredValue::StackMemory { location, .. } => {
+                    let (to_ptr, to_offset) =
+                        location.local_and_offset(self.storage.stack_frame_pointer);
+                    self.store_multi_value_return(types, to_ptr, to_offset);
+                }
+                _ => internal_error!("Multi-value return to {:?}", ret_storage),
+            }
+        }
     }
 
     fn expr_call_low_level(
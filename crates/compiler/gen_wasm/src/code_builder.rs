@@ -5,6 +5,7 @@ use core::panic;
 use roc_wasm_module::linking::IndexRelocType;
 
 use roc_error_macros::internal_error;
+use roc_region::all::Region;
 use roc_wasm_module::opcodes::{OpCode, OpCode::*};
 use roc_wasm_module::serialize::SerialBuffer;
 use roc_wasm_module::{
@@ -76,6 +77,12 @@ pub struct CodeBuilder<'a> {
 
     /// Keep track of which local variables have been set
     set_locals: BitVec<u32>,
+
+    /// Code locations of calls whose result is returned straight away
+    tail_calls: Vec<'a, usize>,
+
+    /// Code locations where the code for each statement starts, for the source map
+    statements: Vec<'a, (usize, Region)>,
 }
 
 #[allow(clippy::new_without_default)]
@@ -90,6 +97,8 @@ impl<'a> CodeBuilder<'a> {
             inner_length: Vec::with_capacity_in(5, arena),
             import_relocations: Vec::with_capacity_in(0, arena),
             set_locals: BitVec::with_capacity(64),
+            tail_calls: Vec::with_capacity_in(8, arena),
+            statements: Vec::with_capacity_in(32, arena),
         }
     }
 
@@ -101,6 +110,8 @@ impl<'a> CodeBuilder<'a> {
         self.inner_length.clear();
         self.import_relocations.clear();
         self.set_locals.clear();
+        self.tail_calls.clear();
+        self.statements.clear();
     }
 
     /**********************************************************
@@ -183,12 +194,19 @@ impl<'a> CodeBuilder<'a> {
     ) {
         self.build_local_declarations(local_types);
 
-        if frame_size != 0 {
-            if let Some(frame_ptr_id) = frame_pointer {
+        match frame_pointer {
+            Some(frame_ptr_id) if frame_size != 0 => {
                 let aligned_size = round_up_to_alignment!(frame_size, FRAME_ALIGNMENT_BYTES);
                 self.build_stack_frame_push(aligned_size, frame_ptr_id);
                 self.build_stack_frame_pop(aligned_size, frame_ptr_id); // footer
             }
+            _ => {
+                // Nothing to do on the way out, so tail calls don't need to come back here.
+                // Opcodes are patched in place, so the code locations stay valid.
+                for pos in self.tail_calls.iter() {
+                    self.code[*pos] = RETURNCALL as u8;
+                }
+            }
         }
 
         self.code.push(END as u8);
@@ -200,6 +218,27 @@ impl<'a> CodeBuilder<'a> {
         self.insertions.sort_by_key(|ins| ins.at);
     }
 
+    /// Note that the code generated from here on is for the statement at `region`
+    pub fn mark_statement(&mut self, region: Region) {
+        self.statements.push((self.code.len(), region));
+    }
+
+    /// The statements marked in this function, with their offsets from the start of the function
+    /// as it will be inserted into the module. Only valid after `build_fn_header_and_footer`.
+    pub fn statement_offsets(&self) -> impl Iterator<Item = (u32, Region)> + '_ {
+        let header_len = self.inner_length.len() + self.preamble.len();
+        self.statements.iter().map(move |(code_pos, region)| {
+            // Inserted instructions at this position finish off the code before it
+            let insertion_bytes: usize = self
+                .insertions
+                .iter()
+                .take_while(|ins| ins.at <= *code_pos)
+                .map(|ins| ins.end - ins.start)
+                .sum();
+            ((header_len + code_pos + insertion_bytes) as u32, *region)
+        })
+    }
+
     /**********************************************************
 
         SERIALIZE
@@ -348,6 +387,13 @@ impl<'a> CodeBuilder<'a> {
         log_instruction!("{:10}\t{}", format!("{CALL:?}"), function_index);
     }
 
+    /// A call whose result is returned straight away.
+    /// It becomes a `return_call` if the function turns out not to have a stack frame to pop.
+    pub fn tail_call(&mut self, function_index: u32) {
+        self.tail_calls.push(self.code.len());
+        self.call(function_index);
+    }
+
     pub fn call_import(&mut self, function_index: u32) {
         self.import_relocations
             .push((self.code.len(), function_index));
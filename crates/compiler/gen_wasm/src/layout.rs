@@ -13,6 +13,8 @@ pub enum ReturnMethod {
     WriteToPointerArg,
     /// This layout is empty and requires no return value or argument (e.g. refcount helpers)
     NoReturnValue,
+    /// This layout is returned as several Wasm values (only between Roc procs, with the multi-value feature)
+    MultiValue(&'static [ValueType]),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -163,3 +165,21 @@ pub fn stack_memory_return_method(size: u32, format: StackMemoryFormat) -> Retur
         }
     }
 }
+
+/// The Wasm values to return a stack memory value as, if it can be split into a few words
+pub fn stack_memory_multi_value_types(
+    size: u32,
+    format: StackMemoryFormat,
+) -> Option<&'static [ValueType]> {
+    use StackMemoryFormat::*;
+    use ValueType::*;
+
+    match (format, size) {
+        (Int128 | Decimal, _) => Some(&[I64, I64]),
+        (DataStructure, 4) => Some(&[I32]),
+        (DataStructure, 8) => Some(&[I32, I32]),
+        (DataStructure, 12) => Some(&[I32, I32, I32]),
+        (DataStructure, 16) => Some(&[I32, I32, I32, I32]),
+        (DataStructure, _) => None,
+    }
+}
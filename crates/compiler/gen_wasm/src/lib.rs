@@ -3,6 +3,7 @@ mod backend;
 mod code_builder;
 mod layout;
 mod low_level;
+mod source_map;
 mod storage;
 
 // Helpers for interfacing to a Wasm module from outside
@@ -16,7 +17,8 @@ use bumpalo::{self, Bump};
 use roc_collections::all::{MutMap, MutSet};
 use roc_module::symbol::{Interns, ModuleId, Symbol};
 use roc_mono::code_gen_help::CodeGenHelp;
-use roc_mono::ir::{Proc, ProcLayout};
+use roc_mono::debug_sources::DebugSources;
+use roc_mono::ir::{Proc, ProcLayout, ProcSizes};
 use roc_mono::layout::{LayoutIds, STLayoutInterner};
 use roc_target::Target;
 use roc_wasm_module::parse::ParseError;
@@ -46,12 +48,55 @@ pub struct Env<'a> {
     pub module_id: ModuleId,
     pub exposed_to_host: MutSet<Symbol>,
     pub stack_bytes: u32,
+    pub features: WasmFeatures,
+    /// Where to find the source of the procs, if we should emit a source map for them
+    pub debug_sources: Option<DebugSources>,
 }
 
 impl Env<'_> {
     pub const DEFAULT_STACK_BYTES: u32 = 1024 * 1024;
 }
 
+/// Wasm proposals beyond the MVP that the generated code may use.
+/// They are all off by default, since not every runtime supports them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WasmFeatures {
+    /// Use `return_call` for calls whose result is returned straight away
+    pub tail_call: bool,
+    /// Return 128-bit numbers and small data structures from Roc procs as several values
+    pub multi_value: bool,
+}
+
+impl WasmFeatures {
+    pub const NAMES: [&'static str; 2] = ["tail-call", "multi-value"];
+}
+
+impl std::str::FromStr for WasmFeatures {
+    type Err = String;
+
+    /// Parse a comma-separated list like `tail-call,multi-value`
+    fn from_str(names: &str) -> Result<Self, Self::Err> {
+        let mut features = WasmFeatures::default();
+        for name in names
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        {
+            match name {
+                "tail-call" => features.tail_call = true,
+                "multi-value" => features.multi_value = true,
+                _ => {
+                    return Err(format!(
+                        "Unknown Wasm feature `{name}`. The supported features are: {}",
+                        Self::NAMES.join(", ")
+                    ))
+                }
+            }
+        }
+        Ok(features)
+    }
+}
+
 /// Parse the preprocessed host binary
 /// If successful, the module can be passed to build_app_binary
 pub fn parse_host<'a>(arena: &'a Bump, host_bytes: &[u8]) -> Result<WasmModule<'a>, ParseError> {
@@ -59,26 +104,41 @@ pub fn parse_host<'a>(arena: &'a Bump, host_bytes: &[u8]) -> Result<WasmModule<'
     WasmModule::preload(arena, host_bytes, require_relocatable)
 }
 
+type ProcFnIndices<'a> = std::vec::Vec<(Symbol, ProcLayout<'a>, u32)>;
+
 /// Generate a Wasm module in binary form, ready to write to a file. Entry point from roc_build.
 ///   env            environment data from previous compiler stages
 ///   interns        names of functions and variables (as memory-efficient interned strings)
 ///   host_module    parsed module from a Wasm object file containing all of the non-Roc code
 ///   procedures     Roc code in monomorphized intermediate representation
+/// Also returns the size in bytes of the code for each procedure, or `None` if dead code
+/// elimination removed it.
 pub fn build_app_binary<'a, 'r>(
     env: &'r Env<'a>,
     layout_interner: &'r mut STLayoutInterner<'a>,
     interns: &'r mut Interns,
     host_module: WasmModule<'a>,
     procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
-) -> std::vec::Vec<u8> {
-    let (mut wasm_module, called_fns, _) =
-        build_app_module(env, layout_interner, interns, host_module, procedures);
+) -> (std::vec::Vec<u8>, ProcSizes<'a>) {
+    let (mut wasm_module, called_fns, _, proc_fn_indices) =
+        build_app_module_help(env, layout_interner, interns, host_module, procedures);
 
     wasm_module.eliminate_dead_code(env.arena, called_fns);
 
+    let first_code_fn_index =
+        wasm_module.import.function_count() as u32 + wasm_module.code.dead_import_dummy_count;
+    let fn_sizes = wasm_module.code.function_sizes();
+    let proc_sizes = proc_fn_indices
+        .into_iter()
+        .map(|(sym, proc_layout, fn_index)| {
+            let size = fn_sizes[(fn_index - first_code_fn_index) as usize];
+            (sym, proc_layout, size.map(u64::from))
+        })
+        .collect();
+
     let mut buffer = std::vec::Vec::with_capacity(wasm_module.size());
     wasm_module.serialize(&mut buffer);
-    buffer
+    (buffer, proc_sizes)
 }
 
 /// Generate an unserialized Wasm module
@@ -92,11 +152,26 @@ pub fn build_app_module<'a, 'r>(
     host_module: WasmModule<'a>,
     procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
 ) -> (WasmModule<'a>, BitVec<usize>, u32) {
+    let (module, called_fns, main_fn_index, _) =
+        build_app_module_help(env, layout_interner, interns, host_module, procedures);
+
+    (module, called_fns, main_fn_index)
+}
+
+/// Like [build_app_module], but also returns the function index of each procedure
+fn build_app_module_help<'a, 'r>(
+    env: &'r Env<'a>,
+    layout_interner: &'r mut STLayoutInterner<'a>,
+    interns: &'r mut Interns,
+    host_module: WasmModule<'a>,
+    procedures: MutMap<(Symbol, ProcLayout<'a>), Proc<'a>>,
+) -> (WasmModule<'a>, BitVec<usize>, u32, ProcFnIndices<'a>) {
     let mut layout_ids = LayoutIds::default();
     let mut procs = Vec::with_capacity_in(procedures.len(), env.arena);
     let mut proc_lookup = Vec::with_capacity_in(procedures.len() * 2, env.arena);
     let mut host_to_app_map = Vec::with_capacity_in(env.exposed_to_host.len(), env.arena);
     let mut maybe_main_fn_index = None;
+    let mut proc_fn_indices = std::vec::Vec::with_capacity(procedures.len());
 
     // Adjust Wasm function indices to account for functions from the object file
     let fn_index_offset: u32 =
@@ -124,6 +199,7 @@ pub fn build_app_module<'a, 'r>(
             layout: proc_layout,
             source: ProcSource::Roc,
         });
+        proc_fn_indices.push((sym, proc_layout, fn_index));
     }
 
     let mut backend = WasmBackend::new(
@@ -187,7 +263,7 @@ pub fn build_app_module<'a, 'r>(
     let main_function_index =
         maybe_main_fn_index.expect("The app must expose at least one value to the host");
 
-    (module, called_fns, main_function_index)
+    (module, called_fns, main_function_index, proc_fn_indices)
 }
 
 pub struct CopyMemoryConfig {
@@ -0,0 +1,53 @@
+//! The source map the Wasm backend emits when asked for debug info.
+//!
+//! Every statement with a place in the source gets a mapping from the offset its code starts at
+//! to its line and column, so browser devtools can step through the `.roc` files. The sources
+//! are embedded in the map, and lines and columns are 0-based, like source maps want them.
+use bumpalo::Bump;
+
+use roc_collections::all::MutMap;
+use roc_module::symbol::ModuleId;
+use roc_mono::debug_sources::DebugSources;
+use roc_region::all::Region;
+use roc_wasm_module::sections::SourceMapSection;
+use roc_wasm_module::SourceMapping;
+
+/// Map the statements of one function, given the offsets their code starts at.
+/// `files` remembers which source files are already in the map.
+pub(crate) fn append_statements<'a>(
+    arena: &'a Bump,
+    source_map: &mut SourceMapSection<'a>,
+    files: &mut MutMap<ModuleId, u32>,
+    debug_sources: &DebugSources,
+    fn_index: u32,
+    module_id: ModuleId,
+    statements: impl Iterator<Item = (u32, Region)>,
+) {
+    for (offset, region) in statements {
+        let location = match debug_sources.location(module_id, region) {
+            Some(location) => location,
+            None => continue,
+        };
+        let source_index = match files.get(&location.module_id) {
+            Some(source_index) => *source_index,
+            None => {
+                let (path, src) = match debug_sources.file(location.module_id) {
+                    Some(file) => file,
+                    None => continue,
+                };
+                let path = arena.alloc_str(&path.to_string_lossy());
+                let src = arena.alloc_str(src);
+                let source_index = source_map.add_source(path, Some(src));
+                files.insert(location.module_id, source_index);
+                source_index
+            }
+        };
+        source_map.append(SourceMapping {
+            fn_index,
+            offset,
+            source_index,
+            line: location.line,
+            column: location.column,
+        });
+    }
+}
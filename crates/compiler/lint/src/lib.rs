@@ -0,0 +1,191 @@
+//! Looks for code that compiles, but is probably not what was meant.
+//!
+//! Lints run after type checking, over a module's canonical [Declarations]. Each [Rule] walks
+//! them with the [Visitor](roc_can::traverse::Visitor) from `roc_can`, and records a [Lint]
+//! for anything suspicious. `roc_reporting` turns lints into warnings, and each kind of lint
+//! has its own error code, so every rule can be allowed or denied on its own.
+#![warn(clippy::dbg_macro)]
+mod rules;
+
+use roc_can::expr::Declarations;
+use roc_module::ident::TagName;
+use roc_module::symbol::{Interns, Symbol};
+use roc_region::all::Region;
+use roc_types::subs::Subs;
+
+pub use rules::{
+    CatchAllOnTagUnion, CrashInLibrary, LeftoverDbg, RedundantTry, RepeatedWhen, ShadowedBuiltin,
+    TryChain,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// A value named after a builtin that isn't in scope unqualified, e.g. `true = 1`.
+    ShadowedBuiltin {
+        symbol: Symbol,
+        builtin: Symbol,
+        region: Region,
+    },
+    /// A `dbg`, which was probably only meant to be there while debugging.
+    LeftoverDbg { region: Region },
+    /// A `crash` in a module that other people's code depends on.
+    CrashInLibrary { region: Region },
+    /// A `Result.try` whose callback only wraps what it's given in `Ok` again.
+    RedundantTry {
+        region: Region,
+        callback: TryCallback,
+    },
+    /// `Result.try` (or `Task.await`) calls chained through their callbacks, which read more
+    /// easily as defs that end in `?` (or `!`).
+    TryChain {
+        region: Region,
+        kind: TryKind,
+        steps: usize,
+        /// The chain written as defs, if every part of it fits on one line
+        rewrite: Option<TryRewrite>,
+    },
+    /// A `when` whose catch-all branch is another `when` on the same value.
+    RepeatedWhen {
+        region: Region,
+        symbol: Symbol,
+        whens: usize,
+    },
+    /// A `_` branch on a closed tag union. It matches these tags, and will also match any
+    /// tags that are added to the union later on.
+    CatchAllOnTagUnion { region: Region, tags: Vec<TagName> },
+}
+
+/// A [Lint::TryChain] written as defs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryRewrite {
+    /// A def for each call, then what the last callback returns
+    pub lines: Vec<String>,
+    /// The region to replace to make the rewrite in place. This is the chain's region,
+    /// unless the defs need to start on a new line.
+    pub region: Region,
+    pub replacement: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryKind {
+    /// `Result.try`, which `?` stands for
+    Result,
+    /// `Task.await`, which `!` stands for
+    Task,
+}
+
+impl TryKind {
+    /// The suffix that does the same thing as the call
+    pub fn suffix(self) -> char {
+        match self {
+            TryKind::Result => '?',
+            TryKind::Task => '!',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryCallback {
+    /// `Result.try result Ok`, which is the same as `result`
+    Identity,
+    /// `Result.try result \x -> Ok (f x)`, which is the same as `Result.map result \x -> f x`
+    Map,
+}
+
+impl Lint {
+    pub fn region(&self) -> Region {
+        match self {
+            Lint::ShadowedBuiltin { region, .. }
+            | Lint::LeftoverDbg { region }
+            | Lint::CrashInLibrary { region }
+            | Lint::RedundantTry { region, .. }
+            | Lint::TryChain { region, .. }
+            | Lint::RepeatedWhen { region, .. }
+            | Lint::CatchAllOnTagUnion { region, .. } => *region,
+        }
+    }
+}
+
+/// What rules can know about the module they're checking.
+pub struct LintContext<'a> {
+    pub interns: &'a Interns,
+    /// The module's solved types
+    pub subs: &'a Subs,
+    /// Whether the module belongs to a package or other library, rather than to an app
+    pub is_library: bool,
+    /// The module's source, for rules that suggest rewrites
+    pub src: &'a str,
+}
+
+pub trait Rule {
+    fn check(&self, ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>);
+}
+
+/// Settings for the rules that have them, e.g. from `set` lines in a `.roc-warnings` file.
+/// Settings that aren't set keep the rule's default.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LintSettings {
+    repeated_when_length: Option<usize>,
+}
+
+impl LintSettings {
+    /// Change the setting with this name, like `repeated-when-length` to `4`.
+    pub fn set(&mut self, name: &str, value: &str) -> Result<(), String> {
+        match name {
+            "repeated-when-length" => match value.parse() {
+                Ok(length) if length >= 2 => {
+                    self.repeated_when_length = Some(length);
+
+                    Ok(())
+                }
+                _ => Err(format!(
+                    "`{name}` is how many `when`s a chain needs to be reported, so it has to be a number that's at least 2, but it was `{value}`"
+                )),
+            },
+            _ => Err(format!(
+                "`{name}` is not a lint setting. The only one is `repeated-when-length`."
+            )),
+        }
+    }
+
+    /// Layer other settings on top of these; the ones it sets win.
+    pub fn extend(&mut self, other: &LintSettings) {
+        if let Some(length) = other.repeated_when_length {
+            self.repeated_when_length = Some(length);
+        }
+    }
+}
+
+/// Every rule, with these settings, which is what `roc lint` runs.
+pub fn rules(settings: &LintSettings) -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(ShadowedBuiltin),
+        Box::new(LeftoverDbg),
+        Box::new(CrashInLibrary),
+        Box::new(RedundantTry),
+        Box::new(TryChain),
+        Box::new(RepeatedWhen {
+            min_whens: settings
+                .repeated_when_length
+                .unwrap_or(RepeatedWhen::DEFAULT_MIN_WHENS),
+        }),
+        Box::new(CatchAllOnTagUnion),
+    ]
+}
+
+/// Run these rules over a module's declarations, returning their lints in source order.
+pub fn lint_module(
+    ctx: &LintContext<'_>,
+    decls: &Declarations,
+    rules: &[Box<dyn Rule>],
+) -> Vec<Lint> {
+    let mut lints = Vec::new();
+
+    for rule in rules {
+        rule.check(ctx, decls, &mut lints);
+    }
+
+    lints.sort_by_key(Lint::region);
+
+    lints
+}
@@ -0,0 +1,588 @@
+//! The rules that `roc lint` runs. Each one is a [Rule] with its own [Visitor].
+use roc_can::expr::{ClosureData, Declarations, Expr, WhenBranch};
+use roc_can::pattern::Pattern;
+use roc_can::traverse::{walk_expr, walk_pattern, Visitor};
+use roc_module::called_via::CalledVia;
+use roc_module::ident::{ModuleName, TagName};
+use roc_module::symbol::Symbol;
+use roc_region::all::{Loc, Position, Region};
+use roc_types::subs::{Content, FlatType, Subs, Variable};
+
+use crate::{Lint, LintContext, Rule, TryCallback, TryKind, TryRewrite};
+
+/// Values named after one of the values in the `Bool` module, like `true` or `not`.
+///
+/// Those builtins are only in scope qualified (as `Bool.true`), so a value with the same
+/// name looks like the builtin to readers, but isn't.
+pub struct ShadowedBuiltin;
+
+const LOOKALIKE_BUILTINS: &[(&str, Symbol)] = &[
+    ("true", Symbol::BOOL_TRUE),
+    ("false", Symbol::BOOL_FALSE),
+    ("and", Symbol::BOOL_AND),
+    ("or", Symbol::BOOL_OR),
+    ("not", Symbol::BOOL_NOT),
+    ("xor", Symbol::BOOL_XOR),
+    ("isEq", Symbol::BOOL_IS_EQ),
+    ("isNotEq", Symbol::BOOL_NEQ),
+];
+
+struct ShadowedBuiltinVisitor<'a> {
+    ctx: &'a LintContext<'a>,
+    lints: &'a mut Vec<Lint>,
+}
+
+impl Visitor for ShadowedBuiltinVisitor<'_> {
+    fn visit_pattern(&mut self, pattern: &Pattern, region: Region, _opt_var: Option<Variable>) {
+        if let Pattern::Identifier(symbol) | Pattern::As(_, symbol) = pattern {
+            let name = symbol.as_str(self.ctx.interns);
+
+            if let Some((_, builtin)) = LOOKALIKE_BUILTINS.iter().find(|(n, _)| *n == name) {
+                self.lints.push(Lint::ShadowedBuiltin {
+                    symbol: *symbol,
+                    builtin: *builtin,
+                    region,
+                });
+            }
+        }
+
+        walk_pattern(self, pattern);
+    }
+}
+
+impl Rule for ShadowedBuiltin {
+    fn check(&self, ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        ShadowedBuiltinVisitor { ctx, lints }.visit_decls(decls);
+    }
+}
+
+/// Every `dbg`. They're for debugging, and usually aren't meant to be committed.
+pub struct LeftoverDbg;
+
+struct LeftoverDbgVisitor<'a> {
+    lints: &'a mut Vec<Lint>,
+}
+
+impl Visitor for LeftoverDbgVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, _region: Region, var: Variable) {
+        if let Expr::Dbg { loc_message, .. } = expr {
+            self.lints.push(Lint::LeftoverDbg {
+                region: loc_message.region,
+            });
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+impl Rule for LeftoverDbg {
+    fn check(&self, _ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        LeftoverDbgVisitor { lints }.visit_decls(decls);
+    }
+}
+
+/// Every `crash` in a library module. A library can't know how bad a crash would be for the
+/// apps that use it, so it should return an error and let them decide.
+pub struct CrashInLibrary;
+
+struct CrashInLibraryVisitor<'a> {
+    lints: &'a mut Vec<Lint>,
+}
+
+impl Visitor for CrashInLibraryVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        if let Expr::Crash { .. } = expr {
+            self.lints.push(Lint::CrashInLibrary { region });
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+impl Rule for CrashInLibrary {
+    fn check(&self, ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        if ctx.is_library {
+            CrashInLibraryVisitor { lints }.visit_decls(decls);
+        }
+    }
+}
+
+/// `Result.try` calls whose callback wraps what it's given in `Ok` again, like
+/// `Result.try result Ok` or `Result.try result \x -> Ok (f x)`. Neither can fail, so
+/// they're the same as `result` and `Result.map result f`.
+pub struct RedundantTry;
+
+struct RedundantTryVisitor<'a> {
+    lints: &'a mut Vec<Lint>,
+}
+
+impl Visitor for RedundantTryVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        if let Expr::Call(call, args, _called_via) = expr {
+            let (_fn_var, loc_fn, _closure_var, _ret_var) = &**call;
+
+            if let (Expr::Var(Symbol::RESULT_TRY, _), [_, (_, loc_callback)]) =
+                (&loc_fn.value, args.as_slice())
+            {
+                if let Some(callback) = try_callback(&loc_callback.value) {
+                    self.lints.push(Lint::RedundantTry { region, callback });
+                }
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+fn is_ok(tag_name: &TagName) -> bool {
+    tag_name.0.as_str() == "Ok"
+}
+
+fn try_callback(callback: &Expr) -> Option<TryCallback> {
+    match callback {
+        Expr::ZeroArgumentTag { name, .. } if is_ok(name) => Some(TryCallback::Identity),
+        Expr::Closure(ClosureData {
+            arguments,
+            loc_body,
+            ..
+        }) => {
+            let [(_, _, loc_argument)] = arguments.as_slice() else {
+                return None;
+            };
+
+            match &loc_body.value {
+                Expr::Tag {
+                    name, arguments, ..
+                } if is_ok(name) => match (&loc_argument.value, arguments.as_slice()) {
+                    (Pattern::Identifier(argument), [(_, loc_payload)]) if matches!(loc_payload.value, Expr::Var(payload, _) if payload == *argument) => {
+                        Some(TryCallback::Identity)
+                    }
+                    (_, [_]) => Some(TryCallback::Map),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+impl Rule for RedundantTry {
+    fn check(&self, _ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        RedundantTryVisitor { lints }.visit_decls(decls);
+    }
+}
+
+/// `Result.try` (or `Task.await`) calls that are chained, either by calling one in the
+/// callback of another, or by passing one as the value for another to try. Written as defs
+/// that use `?` (or `!`), each step gets its own line:
+///
+/// ```roc
+/// Result.try (parse a) \x ->
+///     Result.try (parse b) \y ->
+///         Ok (x + y)
+/// ```
+///
+/// is the same as
+///
+/// ```roc
+/// x = parse? a
+/// y = parse? b
+/// Ok (x + y)
+/// ```
+pub struct TryChain;
+
+struct TryChainVisitor<'a> {
+    ctx: &'a LintContext<'a>,
+    lints: &'a mut Vec<Lint>,
+    /// The calls in chains that were already reported
+    reported: Vec<Region>,
+}
+
+/// One call in a chain: the pattern its callback binds, and the value it tries.
+struct TryStep<'e> {
+    pattern: Region,
+    value: &'e Loc<Expr>,
+}
+
+/// The calls of a chain, and what the last callback returns.
+struct Chain<'e> {
+    steps: Vec<TryStep<'e>>,
+    body: &'e Loc<Expr>,
+}
+
+impl TryChainVisitor<'_> {
+    /// What kind of call this is, if it's one that a suffix could replace. Calls that `!`
+    /// desugared to are already using it.
+    fn try_kind(&self, expr: &Expr) -> Option<TryKind> {
+        let Expr::Call(call, args, called_via) = expr else {
+            return None;
+        };
+
+        if args.len() != 2 || *called_via == CalledVia::BangSuffix {
+            return None;
+        }
+
+        match call.1.value {
+            Expr::Var(Symbol::RESULT_TRY, _) => Some(TryKind::Result),
+            Expr::Var(symbol, _)
+                if symbol.as_str(self.ctx.interns) == "await"
+                    && symbol.module_string(self.ctx.interns).as_str() == ModuleName::TASK =>
+            {
+                Some(TryKind::Task)
+            }
+            _ => None,
+        }
+    }
+
+    /// The chain that starts with this call, if it's a `kind` call whose callback is a
+    /// lambda with one argument.
+    fn chain<'e>(&mut self, expr: &'e Expr, region: Region, kind: TryKind) -> Option<Chain<'e>> {
+        if self.try_kind(expr) != Some(kind) {
+            return None;
+        }
+
+        let Expr::Call(_, args, _) = expr else {
+            return None;
+        };
+        let [(_, loc_value), (_, loc_callback)] = args.as_slice() else {
+            return None;
+        };
+        let Expr::Closure(ClosureData {
+            arguments,
+            loc_body,
+            ..
+        }) = &loc_callback.value
+        else {
+            return None;
+        };
+        let [(_, _, loc_pattern)] = arguments.as_slice() else {
+            return None;
+        };
+
+        self.reported.push(region);
+
+        // If the value is a chain of its own, its steps come first, and this call tries
+        // whatever its last callback returns.
+        let mut steps = Vec::new();
+        let value = match self.chain(&loc_value.value, loc_value.region, kind) {
+            Some(inner) => {
+                steps.extend(inner.steps);
+                inner.body
+            }
+            None => loc_value,
+        };
+
+        steps.push(TryStep {
+            pattern: loc_pattern.region,
+            value,
+        });
+
+        match self.chain(&loc_body.value, loc_body.region, kind) {
+            Some(inner) => {
+                steps.extend(inner.steps);
+
+                Some(Chain {
+                    steps,
+                    body: inner.body,
+                })
+            }
+            None => Some(Chain {
+                steps,
+                body: loc_body,
+            }),
+        }
+    }
+}
+
+/// The source of a region, if it's all on one line.
+fn one_line(src: &str, region: Region) -> Option<&str> {
+    let text = src.get(region.start().offset as usize..region.end().offset as usize)?;
+
+    (!text.contains('\n')).then_some(text)
+}
+
+/// A value with the suffix after the function it calls, like `parse? a`, or after the
+/// value itself if it's a variable. Other values would need more than a suffix.
+fn suffixed(src: &str, loc_value: &Loc<Expr>, kind: TryKind) -> Option<String> {
+    let suffix = kind.suffix();
+
+    match &loc_value.value {
+        Expr::Var(..) => Some(format!("{}{suffix}", one_line(src, loc_value.region)?)),
+        Expr::Call(call, args, CalledVia::Space) if matches!(call.1.value, Expr::Var(..)) => {
+            let loc_fn = &call.1;
+            let (_, last_arg) = args.last()?;
+            let args_region = Region::span_across(&loc_fn.region, &last_arg.region);
+            let text = one_line(src, args_region)?;
+            let (name, rest) = text.split_at(one_line(src, loc_fn.region)?.len());
+
+            Some(format!("{name}{suffix}{rest}"))
+        }
+        _ => None,
+    }
+}
+
+/// The chain written as defs that use the suffix, along with how to make that edit in
+/// place, if every part of it fits on one line.
+fn rewrite(src: &str, region: Region, chain: &Chain, kind: TryKind) -> Option<TryRewrite> {
+    let mut lines = Vec::with_capacity(chain.steps.len() + 1);
+
+    for step in chain.steps.iter() {
+        let pattern = one_line(src, step.pattern)?;
+        let value = suffixed(src, step.value, kind)?;
+
+        lines.push(format!("{pattern} = {value}"));
+    }
+
+    lines.push(one_line(src, chain.body.region)?.to_string());
+
+    // Defs have to start their own lines. If the chain starts its line, they can go where it
+    // is; otherwise (e.g. after `a =`), they go in an indented block on the lines after it.
+    let start = region.start().offset as usize;
+    let line_start = src[..start].rfind('\n').map_or(0, |newline| newline + 1);
+    let before = &src[line_start..start];
+    let line_indent = before.len() - before.trim_start().len();
+
+    let (region, replacement) = if before.trim().is_empty() {
+        let separator = format!("\n{before}");
+
+        (region, lines.join(&separator))
+    } else {
+        // Drop the spaces between `=` and the chain, so the line doesn't end with them.
+        let spaces = before.len() - before.trim_end().len();
+        let start = Position::new((start - spaces) as u32);
+        let separator = format!("\n{}", " ".repeat(line_indent + 4));
+
+        (
+            Region::new(start, region.end()),
+            format!("{separator}{}", lines.join(&separator)),
+        )
+    };
+
+    Some(TryRewrite {
+        region,
+        replacement,
+        lines,
+    })
+}
+
+impl Visitor for TryChainVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, region: Region, var: Variable) {
+        if let Some(kind) = self.try_kind(expr) {
+            if !self.reported.contains(&region) {
+                if let Some(chain) = self.chain(expr, region, kind) {
+                    if chain.steps.len() > 1 {
+                        self.lints.push(Lint::TryChain {
+                            region,
+                            kind,
+                            steps: chain.steps.len(),
+                            rewrite: rewrite(self.ctx.src, region, &chain, kind),
+                        });
+                    }
+                }
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+impl Rule for TryChain {
+    fn check(&self, ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        TryChainVisitor {
+            ctx,
+            lints,
+            reported: Vec::new(),
+        }
+        .visit_decls(decls);
+    }
+}
+
+/// A `when` whose catch-all branch is another `when` on the same value (and so on, for at
+/// least [RepeatedWhen::min_whens] `when`s), which is easier to follow as a single `when`:
+///
+/// ```roc
+/// when x is
+///     A -> 1
+///     _ ->
+///         when x is
+///             B -> 2
+///             _ -> 3
+/// ```
+pub struct RepeatedWhen {
+    /// How many `when`s a chain needs to be reported, counting the outer one
+    pub min_whens: usize,
+}
+
+impl RepeatedWhen {
+    pub const DEFAULT_MIN_WHENS: usize = 3;
+}
+
+struct RepeatedWhenVisitor<'a> {
+    min_whens: usize,
+    lints: &'a mut Vec<Lint>,
+    /// The inner `when`s of chains that were already reported
+    reported: Vec<Region>,
+}
+
+/// If the last branch catches everything and is another `when` on `symbol`, that `when`.
+fn catch_all_when(branches: &[WhenBranch], symbol: Symbol) -> Option<(Region, &[WhenBranch])> {
+    let last = branches.last()?;
+
+    let catches_all = last.guard.is_none()
+        && matches!(
+            last.patterns.as_slice(),
+            [pattern] if matches!(pattern.pattern.value, Pattern::Underscore | Pattern::Identifier(_))
+        );
+
+    match &last.value.value {
+        Expr::When {
+            loc_cond,
+            branches,
+            region,
+            ..
+        } if catches_all && matches!(loc_cond.value, Expr::Var(cond, _) if cond == symbol) => {
+            Some((*region, branches))
+        }
+        _ => None,
+    }
+}
+
+impl Visitor for RepeatedWhenVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, _region: Region, var: Variable) {
+        if let Expr::When {
+            loc_cond,
+            branches,
+            region: when_region,
+            ..
+        } = expr
+        {
+            if let Expr::Var(symbol, _) = loc_cond.value {
+                if !self.reported.contains(when_region) {
+                    let mut whens = 1;
+                    let mut branches = branches.as_slice();
+
+                    while let Some((inner_region, inner_branches)) =
+                        catch_all_when(branches, symbol)
+                    {
+                        self.reported.push(inner_region);
+                        whens += 1;
+                        branches = inner_branches;
+                    }
+
+                    if whens >= self.min_whens {
+                        self.lints.push(Lint::RepeatedWhen {
+                            region: *when_region,
+                            symbol,
+                            whens,
+                        });
+                    }
+                }
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+impl Rule for RepeatedWhen {
+    fn check(&self, _ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        RepeatedWhenVisitor {
+            min_whens: self.min_whens,
+            lints,
+            reported: Vec::new(),
+        }
+        .visit_decls(decls);
+    }
+}
+
+/// A `_` branch in a `when` on a closed tag union that the module defines, which also
+/// matches some of the union's tags. If a tag is added to the union later, it silently ends
+/// up in that branch too, when it would be better for `roc check` to point out every `when`
+/// that needs to handle it.
+pub struct CatchAllOnTagUnion;
+
+struct CatchAllOnTagUnionVisitor<'a> {
+    subs: &'a Subs,
+    lints: &'a mut Vec<Lint>,
+}
+
+/// The tags of a closed tag union. Builtin unions (like `Result`) are left out, since
+/// they don't grow.
+fn closed_tags(subs: &Subs, mut var: Variable) -> Option<Vec<TagName>> {
+    let mut tags = Vec::new();
+
+    loop {
+        match subs.get_content_without_compacting(var) {
+            Content::Alias(symbol, _, actual, _) => {
+                if symbol.is_builtin() {
+                    return None;
+                }
+
+                var = *actual;
+            }
+            Content::Structure(FlatType::TagUnion(union_tags, ext))
+            | Content::Structure(FlatType::RecursiveTagUnion(_, union_tags, ext)) => {
+                tags.extend(
+                    union_tags
+                        .iter_from_subs(subs)
+                        .map(|(tag_name, _)| tag_name.clone()),
+                );
+
+                var = ext.var();
+            }
+            Content::Structure(FlatType::EmptyTagUnion) => {
+                tags.sort();
+
+                return Some(tags);
+            }
+            _ => return None,
+        }
+    }
+}
+
+impl Visitor for CatchAllOnTagUnionVisitor<'_> {
+    fn visit_expr(&mut self, expr: &Expr, _region: Region, var: Variable) {
+        if let Expr::When {
+            cond_var, branches, ..
+        } = expr
+        {
+            let patterns = || branches.iter().flat_map(|branch| branch.patterns.iter());
+            let catch_all = branches
+                .iter()
+                .filter(|branch| branch.guard.is_none())
+                .flat_map(|branch| branch.patterns.iter())
+                .find(|pattern| matches!(pattern.pattern.value, Pattern::Underscore));
+
+            if let (Some(catch_all), Some(tags)) = (catch_all, closed_tags(self.subs, *cond_var)) {
+                let caught: Vec<TagName> = tags
+                    .into_iter()
+                    .filter(|tag| {
+                        !patterns().any(|pattern| {
+                            matches!(&pattern.pattern.value, Pattern::AppliedTag { tag_name, .. } if tag_name == tag)
+                        })
+                    })
+                    .collect();
+
+                if !caught.is_empty() {
+                    self.lints.push(Lint::CatchAllOnTagUnion {
+                        region: catch_all.pattern.region,
+                        tags: caught,
+                    });
+                }
+            }
+        }
+
+        walk_expr(self, expr, var);
+    }
+}
+
+impl Rule for CatchAllOnTagUnion {
+    fn check(&self, ctx: &LintContext<'_>, decls: &Declarations, lints: &mut Vec<Lint>) {
+        CatchAllOnTagUnionVisitor {
+            subs: ctx.subs,
+            lints,
+        }
+        .visit_decls(decls);
+    }
+}
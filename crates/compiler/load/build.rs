@@ -62,7 +62,8 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
     use roc_can::module::TypeState;
     use roc_load_internal::file::{LoadingProblem, Threading};
     use roc_packaging::cache::RocCacheDir;
-    use roc_reporting::cli::report_problems;
+    use roc_reporting::cli::{report_problems, ErrorFormat};
+    use roc_reporting::warnings::WarningConfig;
 
     let arena = Bump::new();
     let cwd = std::env::current_dir().unwrap();
@@ -87,7 +88,7 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
 
     let mut module = match res_module {
         Ok(v) => v,
-        Err(LoadingProblem::FormattedReport(report)) => {
+        Err(LoadingProblem::FormattedReport(report, _)) => {
             internal_error!("{}", report);
         }
         Err(other) => {
@@ -100,6 +101,8 @@ fn write_types_for_module_real(module_id: ModuleId, filename: &str, output_path:
         &module.interns,
         &mut module.can_problems,
         &mut module.type_problems,
+        ErrorFormat::Human,
+        &WarningConfig::default(),
     );
 
     if problems.errors + problems.warnings > 0 {
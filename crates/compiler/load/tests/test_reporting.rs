@@ -13,6 +13,7 @@ mod test_reporting {
     use indoc::indoc;
     use roc_can::abilities::AbilitiesStore;
     use roc_can::expr::PendingDerives;
+    use roc_lint::{lint_module, rules, Lint, LintContext, LintSettings};
     use roc_load::{self, ExecutionMode, LoadConfig, LoadedModule, LoadingProblem, Threading};
     use roc_module::symbol::{Interns, ModuleId};
     use roc_packaging::cache::RocCacheDir;
@@ -21,9 +22,11 @@ mod test_reporting {
     use roc_parse::test_helpers::parse_expr_with;
     use roc_problem::Severity;
     use roc_region::all::LineInfo;
+    use roc_reporting::error::canonicalize::safe_fixes;
+    use roc_reporting::fix::apply_safe_fixes;
     use roc_reporting::report::{
-        can_problem, parse_problem, type_problem, RenderTarget, Report, ANSI_STYLE_CODES,
-        DEFAULT_PALETTE,
+        can_problem, lint_problem, parse_problem, type_problem, RenderTarget, Report,
+        ANSI_STYLE_CODES, DEFAULT_PALETTE,
     };
     use roc_reporting::report::{RocDocAllocator, RocDocBuilder};
     use roc_solve::FunctionKind;
@@ -42,9 +45,11 @@ mod test_reporting {
     fn to_simple_report(doc: RocDocBuilder) -> Report {
         Report {
             title: "".to_string(),
+            error_code: None,
             doc,
             filename: filename_from_string(r"/code/proj/Main.roc"),
             severity: Severity::RuntimeError,
+            fixes: Vec::new(),
         }
     }
 
@@ -160,18 +165,22 @@ mod test_reporting {
         ),
         LoadingProblem<'a>,
     > {
-        let (module_src, result) = run_load_and_infer(subdir, arena, expr_src);
+        let (_, result) = run_load_and_infer(subdir, arena, expr_src);
         let LoadedModule {
             module_id: home,
             mut can_problems,
             mut type_problems,
             interns,
+            sources,
             ..
         } = result?;
 
         let can_problems = can_problems.remove(&home).unwrap_or_default();
         let type_problems = type_problems.remove(&home).unwrap_or_default();
 
+        // Report with the source as it was loaded, since syntax errors are found again in it
+        let module_src = sources[&home].1.to_string();
+
         Ok((module_src, type_problems, can_problems, home, interns))
     }
 
@@ -186,7 +195,7 @@ mod test_reporting {
         let mut buf = String::new();
 
         match infer_expr_help_new(subdir, arena, src) {
-            Err(LoadingProblem::FormattedReport(fail)) => fail,
+            Err(LoadingProblem::FormattedReport(fail, _)) => fail,
             Ok((module_src, type_problems, can_problems, home, interns)) => {
                 let lines = LineInfo::new(&module_src);
                 let src_lines: Vec<&str> = module_src.split('\n').collect();
@@ -450,6 +459,100 @@ mod test_reporting {
         }
     }
 
+    /// The module's source after applying every safe fix for its problems.
+    fn __new_fixed_source(test_name: &str, src: &str, check_fixed: impl FnOnce(&str)) {
+        let arena = Bump::new();
+
+        let (module_src, _, can_problems, home, interns) =
+            infer_expr_help_new(test_name, &arena, src).expect("failed to load");
+        let lines = LineInfo::new(&module_src);
+        let src_lines: Vec<&str> = module_src.split('\n').collect();
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+        let mut fixes = Vec::new();
+
+        for problem in can_problems.iter() {
+            if let Some((_, problem_fixes)) = safe_fixes(&alloc, &lines, problem) {
+                fixes.extend(problem_fixes);
+            }
+        }
+
+        let (fixed, _) = apply_safe_fixes(&module_src, &fixes);
+
+        check_fixed(fixed.as_str());
+    }
+
+    macro_rules! test_fix {
+        ($(#[$meta:meta])* $test_name:ident, $program:expr, @$output:literal) => {
+            #[test]
+            $(#[$meta])*
+            fn $test_name() {
+                __new_fixed_source(std::stringify!($test_name), $program, |fixed| insta::assert_snapshot!(fixed, @$output))
+            }
+        }
+    }
+
+    /// The reports for every lint in the module. Only the lints are rendered, not any
+    /// other problems.
+    fn __new_lint_report(test_name: &str, src: &str, check_render: impl FnOnce(&str)) {
+        __new_lint_report_with(test_name, src, &LintSettings::default(), check_render)
+    }
+
+    fn __new_lint_report_with(
+        test_name: &str,
+        src: &str,
+        settings: &LintSettings,
+        check_render: impl FnOnce(&str),
+    ) {
+        use ven_pretty::DocAllocator;
+
+        let arena = Bump::new();
+        let filename = filename_from_string(r"/code/proj/Main.roc");
+
+        let (module_src, result) = run_load_and_infer(test_name, &arena, src);
+        let LoadedModule {
+            module_id: home,
+            declarations_by_id,
+            solved,
+            interns,
+            ..
+        } = result.expect("failed to load");
+
+        let ctx = LintContext {
+            interns: &interns,
+            subs: solved.inner(),
+            is_library: true,
+            src: &module_src,
+        };
+        let lints = lint_module(&ctx, &declarations_by_id[&home], &rules(settings));
+
+        let lines = LineInfo::new(&module_src);
+        let src_lines: Vec<&str> = module_src.split('\n').collect();
+        let alloc = RocDocAllocator::new(&src_lines, home, &interns);
+        let reports = lints
+            .into_iter()
+            .map(|lint| lint_problem(&alloc, &lines, filename.clone(), lint).pretty(&alloc));
+
+        let mut buf = String::new();
+        alloc
+            .stack(reports)
+            .append(alloc.line())
+            .1
+            .render_raw(70, &mut roc_reporting::report::CiWrite::new(&mut buf))
+            .expect("list_reports");
+
+        check_render(buf.as_str());
+    }
+
+    macro_rules! test_lint {
+        ($(#[$meta:meta])* $test_name:ident, $program:expr, @$output:literal) => {
+            #[test]
+            $(#[$meta])*
+            fn $test_name() {
+                __new_lint_report(std::stringify!($test_name), $program, |golden| insta::assert_snapshot!(golden, @$output))
+            }
+        }
+    }
+
     macro_rules! test_no_problem {
         ($(#[$meta:meta])* $test_name: ident, $program:expr) => {
             #[test]
@@ -479,7 +582,7 @@ mod test_reporting {
         "
         ),
         @r"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [R0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The List module does not expose `isempty`:
 
@@ -506,7 +609,7 @@ mod test_reporting {
         "
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `y` is not used anywhere in your code.
 
@@ -531,7 +634,7 @@ mod test_reporting {
        "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `i` name is first defined here:
 
@@ -563,7 +666,7 @@ mod test_reporting {
        "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `Booly` name is first defined here:
 
@@ -595,7 +698,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     Using != and == together requires parentheses, to clarify how they
     should be grouped.
@@ -624,7 +727,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-        ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+        ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
         Nothing is named `bar` in this scope.
 
@@ -648,7 +751,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `true` in this scope.
 
@@ -680,7 +783,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     Using more than one == like this requires parentheses, to clarify how
     things should be grouped.
@@ -706,7 +809,7 @@ mod test_reporting {
          "#
         ),
         @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [R0210] in /code/proj/Main.roc ──────────────────────────────
 
     `box` doesn't use `htmlChildren`.
 
@@ -719,7 +822,7 @@ mod test_reporting {
     at the start of a variable name is a way of saying that the variable
     is not used.
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `y` is not used anywhere in your code.
 
@@ -803,7 +906,7 @@ mod test_reporting {
             ),
             indoc!(
                 r"
-                <cyan>── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────<reset>
+                <cyan>── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────<reset>
 
                 Nothing is named `theAdmin` in this scope.
 
@@ -829,7 +932,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -855,7 +958,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` guard condition needs to be a Bool:
 
@@ -880,7 +983,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -907,7 +1010,7 @@ mod test_reporting {
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 3rd branch of this `if` does not match all the previous branches:
 
@@ -937,7 +1040,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd branch of this `when` does not match all the previous branches:
 
@@ -975,7 +1078,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -1019,7 +1122,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This list contains elements with different types:
 
@@ -1046,7 +1149,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This list contains elements with different types:
 
@@ -1079,7 +1182,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     I cannot update the `.foo` field like this:
 
@@ -1109,7 +1212,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1134,7 +1237,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1160,7 +1263,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1173,7 +1276,7 @@ mod test_reporting {
 
         List ∞ -> *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1200,7 +1303,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -1236,7 +1339,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -1270,7 +1373,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1296,7 +1399,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1334,7 +1437,7 @@ mod test_reporting {
         // against that extra variable, rather than possibly having to translate a `Type`
         // again.
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1347,7 +1450,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1374,7 +1477,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `f`:
 
@@ -1387,7 +1490,7 @@ mod test_reporting {
 
         List ∞ -> List *
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `g`:
 
@@ -1415,7 +1518,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1449,7 +1552,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1486,7 +1589,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -1523,7 +1626,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -1557,7 +1660,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -1590,7 +1693,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -1622,7 +1725,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     The `x` value is not a function, but it was given 1 argument:
 
@@ -1644,7 +1747,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     The `f` function expects 1 argument, but it got 2 instead:
 
@@ -1666,7 +1769,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [R0305] in /code/proj/Main.roc ─────────────────────────────────
 
     The `f` function expects 2 arguments, but it got only 1:
 
@@ -1687,7 +1790,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1716,7 +1819,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -1742,7 +1845,7 @@ mod test_reporting {
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1770,7 +1873,7 @@ mod test_reporting {
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -1799,7 +1902,7 @@ mod test_reporting {
              "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `foo` in this scope.
 
@@ -1855,7 +1958,7 @@ mod test_reporting {
         ),
         // Just putting this here. We should probably handle or-patterns better
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this branch does not match the previous ones:
 
@@ -1883,7 +1986,7 @@ mod test_reporting {
         ),
         // Maybe this should specifically say the pattern doesn't work?
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -1911,7 +2014,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of this definition:
 
@@ -1942,7 +2045,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer pattern is malformed:
 
@@ -1963,7 +2066,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This float pattern is malformed:
 
@@ -1984,7 +2087,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer pattern is malformed:
 
@@ -2005,7 +2108,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer pattern is malformed:
 
@@ -2026,7 +2129,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer pattern is malformed:
 
@@ -2048,7 +2151,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -2098,7 +2201,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -2132,7 +2235,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2167,7 +2270,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2203,7 +2306,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `ok` in this scope.
 
@@ -2234,7 +2337,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `ok` is not used anywhere in your code.
 
@@ -2244,7 +2347,7 @@ mod test_reporting {
     If you didn't intend on using `ok` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2276,7 +2379,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [R0216] in /code/proj/Main.roc ──────────────────────────
 
     `f` is defined directly in terms of itself:
 
@@ -2303,7 +2406,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [R0216] in /code/proj/Main.roc ──────────────────────────
 
     The `foo` definition is causing a very tricky infinite loop:
 
@@ -2331,7 +2434,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2353,7 +2456,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2386,7 +2489,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `r` record doesn’t have a `foo` field:
 
@@ -2414,7 +2517,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `x` record doesn’t have a `foo` field:
 
@@ -2444,7 +2547,7 @@ mod test_reporting {
         ),
         // TODO also suggest fields with the correct type
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2469,7 +2572,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2497,7 +2600,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -2525,7 +2628,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2559,7 +2662,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2602,7 +2705,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This pattern does not cover all the possibilities:
 
@@ -2617,7 +2720,7 @@ mod test_reporting {
     matching in function arguments, put a `when` in the function body to
     account for all possibilities.
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -2654,7 +2757,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -2685,7 +2788,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2712,7 +2815,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2740,7 +2843,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2769,7 +2872,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2798,7 +2901,7 @@ mod test_reporting {
         ),
         // Tip: Looks like a record field guard is not exhaustive. Learn more about record pattern matches at TODO.
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2827,7 +2930,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2853,7 +2956,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -2880,7 +2983,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -2908,7 +3011,7 @@ mod test_reporting {
         ),
         // de-aliases the alias to give a better error message
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `f` has an unexpected type:
 
@@ -2946,7 +3049,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2972,7 +3075,7 @@ mod test_reporting {
         ),
         // should not report Bar as unused!
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is self-recursive in an invalid way:
 
@@ -2992,7 +3095,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [R0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3016,7 +3119,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [R0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3044,7 +3147,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [R0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3079,7 +3182,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [R0213] in /code/proj/Main.roc ─────────────────────────
 
     This record defines the `.x` field twice!
 
@@ -3112,7 +3215,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── DUPLICATE FIELD NAME in /code/proj/Main.roc ─────────────────────────────────
+    ── DUPLICATE FIELD NAME [R0213] in /code/proj/Main.roc ─────────────────────────
 
     This record type defines the `.foo` field twice!
 
@@ -3140,7 +3243,7 @@ mod test_reporting {
             "#
         ),
         @r"
-    ── DUPLICATE TAG NAME in /code/proj/Main.roc ───────────────────────────────────
+    ── DUPLICATE TAG NAME [R0214] in /code/proj/Main.roc ───────────────────────────
 
     This tag union type defines the `Foo` tag twice!
 
@@ -3169,7 +3272,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── NAMING PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── NAMING PROBLEM [R0200] in /code/proj/Main.roc ───────────────────────────────
 
     This annotation does not match the definition immediately following
     it:
@@ -3205,7 +3308,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This definition of `MyAlias` has an unexpected pattern:
 
@@ -3214,7 +3317,7 @@ mod test_reporting {
 
     Only type variables like `a` or `value` can occur in this position.
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `MyAlias` is not used anywhere in your code.
 
@@ -3237,7 +3340,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This definition of `Age` has an unexpected pattern:
 
@@ -3259,7 +3362,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [R0235] in /code/proj/Main.roc ──────────────────────
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3281,7 +3384,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [R0235] in /code/proj/Main.roc ──────────────────────
 
     The `Num` opaque expects 1 type argument, but it got 2 instead:
 
@@ -3305,7 +3408,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO FEW TYPE ARGUMENTS in /code/proj/Main.roc ───────────────────────────────
+    ── TOO FEW TYPE ARGUMENTS [R0236] in /code/proj/Main.roc ───────────────────────
 
     The `Pair` alias expects 2 type arguments, but it got 1 instead:
 
@@ -3329,7 +3432,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [R0235] in /code/proj/Main.roc ──────────────────────
 
     The `Pair` alias expects 2 type arguments, but it got 3 instead:
 
@@ -3352,7 +3455,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNUSED TYPE ALIAS PARAMETER in /code/proj/Main.roc ──────────────────────────
+    ── UNUSED TYPE ALIAS PARAMETER [R0206] in /code/proj/Main.roc ──────────────────
 
     The `a` type parameter is not used in the `Foo` alias definition:
 
@@ -3374,7 +3477,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── ARGUMENTS BEFORE EQUALS in tmp/elm_function_syntax/Test.roc ─────────────────
+    ── ARGUMENTS BEFORE EQUALS [R0103] in /code/proj/Main.roc ──────────────────────
 
     I am partway through parsing a definition, but I got stuck here:
 
@@ -3402,7 +3505,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -3451,7 +3554,7 @@ mod test_reporting {
         // TODO render tag unions across multiple lines
         // TODO do not show recursion var if the recursion var does not render on the surface of a type
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -3505,7 +3608,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too big:
 
@@ -3517,7 +3620,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too small:
 
@@ -3529,7 +3632,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too big:
 
@@ -3541,7 +3644,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal is too small:
 
@@ -3553,7 +3656,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -3582,7 +3685,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal is too big:
 
@@ -3594,7 +3697,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal is too small:
 
@@ -3627,7 +3730,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -3639,7 +3742,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer literal contains an invalid digit:
 
@@ -3651,7 +3754,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer literal contains an invalid digit:
 
@@ -3663,7 +3766,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer literal contains an invalid digit:
 
@@ -3693,7 +3796,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This hex integer literal contains no digits:
 
@@ -3705,7 +3808,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This octal integer literal contains no digits:
 
@@ -3717,7 +3820,7 @@ mod test_reporting {
 
     Tip: Learn more about number literals at TODO
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This binary integer literal contains no digits:
 
@@ -3741,7 +3844,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal contains an invalid digit:
 
@@ -3772,7 +3875,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This expression cannot be updated:
 
@@ -3781,7 +3884,7 @@ mod test_reporting {
 
     Only variables can be updated with record update syntax.
 
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [R0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Test` module is not imported:
 
@@ -3796,7 +3899,7 @@ mod test_reporting {
         Dict
         Hash
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This expression cannot be updated:
 
@@ -3815,7 +3918,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [R0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Foo` module is not imported:
 
@@ -3840,7 +3943,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to + has an unexpected type:
 
@@ -3868,7 +3971,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -3897,7 +4000,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of this definition:
 
@@ -3928,7 +4031,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -3961,7 +4064,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -3994,7 +4097,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -4025,7 +4128,7 @@ mod test_reporting {
                 "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to this function has an unexpected type:
 
@@ -4059,7 +4162,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -4093,7 +4196,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -4121,7 +4224,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── BAD OPTIONAL VALUE in /code/proj/Main.roc ───────────────────────────────────
+    ── BAD OPTIONAL VALUE [R0239] in /code/proj/Main.roc ───────────────────────────
 
     This record uses an optional value for the `.y` field in an incorrect
     context!
@@ -4158,7 +4261,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -4203,7 +4306,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [R0210] in /code/proj/Main.roc ──────────────────────────────
 
     `f` doesn't use `foo`.
 
@@ -4225,7 +4328,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -4246,7 +4349,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -4266,7 +4369,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a record field access here:
 
@@ -4288,7 +4391,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/type_annotation_double_colon/Test.roc ───────────────
+    ── UNKNOWN OPERATOR [R0104] in /code/proj/Main.roc ─────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -4320,7 +4423,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 3 arguments:
 
@@ -4339,7 +4442,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TAG UNION TYPE in tmp/tag_union_open/Test.roc ────────────────────
+    ── UNFINISHED TAG UNION TYPE [R0152] in /code/proj/Main.roc ────────────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4361,7 +4464,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TAG UNION TYPE in tmp/tag_union_end/Test.roc ─────────────────────
+    ── UNFINISHED TAG UNION TYPE [R0152] in /code/proj/Main.roc ────────────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4383,7 +4486,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── WEIRD TAG NAME in tmp/tag_union_lowercase_tag_name/Test.roc ─────────────────
+    ── WEIRD TAG NAME [R0153] in /code/proj/Main.roc ───────────────────────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4404,7 +4507,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── WEIRD TAG NAME in tmp/tag_union_second_lowercase_tag_name/Test.roc ──────────
+    ── WEIRD TAG NAME [R0153] in /code/proj/Main.roc ───────────────────────────────
 
     I am partway through parsing a tag union type, but I got stuck here:
 
@@ -4425,7 +4528,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_open/Test.roc ─────────────────────
+    ── UNFINISHED RECORD TYPE [R0149] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4448,7 +4551,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_open_indent/Test.roc ──────────────
+    ── UNFINISHED RECORD TYPE [R0149] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4471,7 +4574,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_end/Test.roc ──────────────────────
+    ── UNFINISHED RECORD TYPE [R0149] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4493,7 +4596,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_keyword_field_name/Test.roc ───────
+    ── UNFINISHED RECORD TYPE [R0149] in /code/proj/Main.roc ───────────────────────
 
     I just started parsing a record type, but I got stuck on this field
     name:
@@ -4515,7 +4618,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED RECORD TYPE in tmp/record_type_missing_comma/Test.roc ────────────
+    ── UNFINISHED RECORD TYPE [R0149] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a record type, but I got stuck here:
 
@@ -4532,7 +4635,7 @@ mod test_reporting {
         record_type_tab,
         "f : { foo \t }",
         @r###"
-    ── TAB CHARACTER in tmp/record_type_tab/Test.roc ───────────────────────────────
+    ── TAB CHARACTER [R0178] in /code/proj/Main.roc ────────────────────────────────
 
     I encountered a tab character:
 
@@ -4547,7 +4650,7 @@ mod test_reporting {
         comment_with_tab,
         "# comment with a \t char\n4",
         @r###"
-    ── TAB CHARACTER in tmp/comment_with_tab/Test.roc ──────────────────────────────
+    ── TAB CHARACTER [R0178] in /code/proj/Main.roc ────────────────────────────────
 
     I encountered a tab character:
 
@@ -4562,7 +4665,7 @@ mod test_reporting {
         comment_with_control_character,
         "# comment with a \x07 char\n",
         @r###"
-    ── ASCII CONTROL CHARACTER in tmp/comment_with_control_character/Test.roc ──────
+    ── ASCII CONTROL CHARACTER [R0179] in /code/proj/Main.roc ──────────────────────
 
     I encountered an ASCII control character:
 
@@ -4577,7 +4680,7 @@ mod test_reporting {
         record_type_carriage_return,
         "f : { \r foo }",
         @r"
-    ── MISPLACED CARRIAGE RETURN in tmp/record_type_carriage_return/Test.roc ───────
+    ── MISPLACED CARRIAGE RETURN [R0180] in /code/proj/Main.roc ────────────────────
 
     I encountered a stray carriage return (\r):
 
@@ -4597,7 +4700,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/type_in_parens_start/Test.roc ─────────────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4620,7 +4723,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/type_in_parens_end/Test.roc ───────────────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a type in parentheses, but I got stuck
     here:
@@ -4645,7 +4748,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4656,7 +4759,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── DOUBLE DOT ──────────────────────────────────────────────────────────────────
+    //                ── DOUBLE DOT [R0154] ──────────────────────────────────────────────────────────
     //
     //                I encountered two dots in a row:
     //
@@ -4675,7 +4778,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4686,7 +4789,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── TRAILING DOT ────────────────────────────────────────────────────────────────
+    //                ── TRAILING DOT [R0155] ────────────────────────────────────────────────────────
     //
     //                I encountered a dot with nothing after it:
     //
@@ -4704,7 +4807,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_apply_stray_dot/Test.roc ────────────────────────
+    ── UNFINISHED TYPE [R0146] in /code/proj/Main.roc ──────────────────────────────
 
     I just started parsing a type, but I got stuck here:
 
@@ -4725,7 +4828,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4736,7 +4839,7 @@ mod test_reporting {
     qualified by a module name, like Bool or Http.Request.Request.
     "
     );
-    //                ── WEIRD QUALIFIED NAME ────────────────────────────────────────────────────────
+    //                ── WEIRD QUALIFIED NAME [R0156] ────────────────────────────────────────────────
     //
     //                I encountered a number at the start of a qualified name segment:
     //
@@ -4756,7 +4859,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am confused by this type name:
 
@@ -4781,7 +4884,7 @@ mod test_reporting {
     //         "
     //     ),
     //     @r#"
-    // ── MISSING FINAL EXPRESSION in tmp/def_missing_final_expression/Test.roc ───────
+    // ── MISSING FINAL EXPRESSION [R0107] in ...ef_missing_final_expression/Test.roc ─
 
     // I am partway through parsing a definition, but I got stuck here:
 
@@ -4809,7 +4912,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in tmp/expression_indentation_end/Test.roc ─────
+    ── INDENT ENDS AFTER EXPRESSION [R0111] in /code/proj/Main.roc ─────────────────
 
     I am partway through parsing an expression, but I got stuck here:
 
@@ -4835,7 +4938,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED INLINE ALIAS in tmp/type_inline_alias/Test.roc ───────────────────
+    ── UNFINISHED INLINE ALIAS [R0147] in /code/proj/Main.roc ──────────────────────
 
     I just started parsing an inline type alias, but I got stuck here:
 
@@ -4857,7 +4960,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── DOUBLE COMMA in tmp/type_double_comma/Test.roc ──────────────────────────────
+    ── DOUBLE COMMA [R0145] in /code/proj/Main.roc ─────────────────────────────────
 
     I just started parsing a function argument type, but I encountered two
     commas in a row:
@@ -4880,7 +4983,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_argument_no_arrow/Test.roc ──────────────────────
+    ── UNFINISHED TYPE [R0146] in /code/proj/Main.roc ──────────────────────────────
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -4903,7 +5006,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED TYPE in tmp/type_argument_arrow_then_nothing/Test.roc ────────────
+    ── UNFINISHED TYPE [R0146] in /code/proj/Main.roc ──────────────────────────────
 
     I just started parsing a type, but I got stuck here:
 
@@ -4922,7 +5025,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import/Test.roc ─────────────────────────
+    ── UNFINISHED IMPORT [R0134] in /code/proj/Main.roc ────────────────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -4950,7 +5053,7 @@ mod test_reporting {
             import Menu { x = 4 }
             "
         ),@r###"
-    ── RECORD PARSE PROBLEM in tmp/weird_import_params_record/Test.roc ─────────────
+    ── RECORD PARSE PROBLEM [R0114] in /code/proj/Main.roc ─────────────────────────
 
     I am partway through parsing a record, but I got stuck here:
 
@@ -4971,7 +5074,7 @@ mod test_reporting {
             }
             "
         ),@r###"
-    ── RECORD BUILDER IN MODULE PARAMS in ...ord_builder_in_module_params/Test.roc ─
+    ── RECORD BUILDER IN MODULE PARAMS [R0130] in /code/proj/Main.roc ──────────────
 
     I was partway through parsing module params, but I got stuck here:
 
@@ -4992,7 +5095,7 @@ mod test_reporting {
             import Menu { myParams & echo: echoFn }
             "
         ),@r###"
-    ── RECORD UPDATE IN MODULE PARAMS in ...ecord_update_in_module_params/Test.roc ─
+    ── RECORD UPDATE IN MODULE PARAMS [R0131] in /code/proj/Main.roc ───────────────
 
     I was partway through parsing module params, but I got stuck here:
 
@@ -5012,7 +5115,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_as_or_exposing/Test.roc ──────────
+    ── UNFINISHED IMPORT [R0134] in /code/proj/Main.roc ────────────────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5041,7 +5144,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_alias/Test.roc ───────────────────
+    ── UNFINISHED IMPORT [R0134] in /code/proj/Main.roc ────────────────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5060,7 +5163,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── LOWERCASE ALIAS in tmp/lowercase_import_alias/Test.roc ──────────────────────
+    ── LOWERCASE ALIAS [R0132] in /code/proj/Main.roc ──────────────────────────────
 
     This import is using a lowercase alias:
 
@@ -5079,7 +5182,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_import_exposing/Test.roc ────────────────
+    ── UNFINISHED IMPORT [R0134] in /code/proj/Main.roc ────────────────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5097,7 +5200,7 @@ mod test_reporting {
             "
         ),
         @r###"
-    ── WEIRD EXPOSING in tmp/unfinished_import_exposing_name/Test.roc ──────────────
+    ── WEIRD EXPOSING [R0133] in /code/proj/Main.roc ───────────────────────────────
 
     I'm partway through parsing an exposing list, but I got stuck here:
 
@@ -5117,7 +5220,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED IMPORT in tmp/unfinished_ingested_file_name/Test.roc ─────────────
+    ── UNFINISHED IMPORT [R0134] in /code/proj/Main.roc ────────────────────────────
 
     I was partway through parsing an `import`, but I got stuck here:
 
@@ -5138,7 +5241,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── UNFINISHED TYPE in tmp/ingested_file_import_ann_syntax_err/Test.roc ─────────
+    ── UNFINISHED TYPE [R0146] in /code/proj/Main.roc ──────────────────────────────
 
     I am partway through parsing a type, but I got stuck here:
 
@@ -5163,7 +5266,7 @@ mod test_reporting {
             "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `myDict` definition:
 
@@ -5198,7 +5301,7 @@ mod test_reporting {
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `myDict` definition:
 
@@ -5230,7 +5333,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── IF GUARD NO CONDITION in tmp/if_guard_without_condition/Test.roc ────────────
+    ── IF GUARD NO CONDITION [R0136] in /code/proj/Main.roc ────────────────────────
 
     I just started parsing an if guard, but there is no guard condition:
 
@@ -5255,7 +5358,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED PATTERN in tmp/empty_or_pattern/Test.roc ─────────────────────────
+    ── UNFINISHED PATTERN [R0139] in /code/proj/Main.roc ───────────────────────────
 
     I just started parsing a pattern, but I got stuck here:
 
@@ -5280,7 +5383,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── MISSING ARROW in tmp/pattern_binds_keyword/Test.roc ─────────────────────────
+    ── MISSING ARROW [R0116] in /code/proj/Main.roc ────────────────────────────────
 
     I am partway through parsing a `when` expression, but got stuck here:
 
@@ -5316,7 +5419,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED WHEN in tmp/when_missing_arrow/Test.roc ──────────────────────────
+    ── UNFINISHED WHEN [R0137] in /code/proj/Main.roc ──────────────────────────────
 
     I was partway through parsing a `when` expression, but I got stuck here:
 
@@ -5349,7 +5452,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED ARGUMENT LIST in tmp/lambda_double_comma/Test.roc ────────────────
+    ── UNFINISHED ARGUMENT LIST [R0117] in /code/proj/Main.roc ─────────────────────
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5370,7 +5473,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED ARGUMENT LIST in tmp/lambda_leading_comma/Test.roc ───────────────
+    ── UNFINISHED ARGUMENT LIST [R0117] in /code/proj/Main.roc ─────────────────────
 
     I am partway through parsing a function argument list, but I got stuck
     at this comma:
@@ -5386,7 +5489,7 @@ mod test_reporting {
     // this should get better with time
     // TODO this formerly gave
     //
-    //                ── UNFINISHED WHEN ─────────────────────────────────────────────────────────────
+    //                ── UNFINISHED WHEN [R0137] ─────────────────────────────────────────────────────
     //
     //                I was partway through parsing a `when` expression, but I got stuck here:
     //
@@ -5417,15 +5520,90 @@ mod test_reporting {
             "
         ),
         @r"
-    ── NOT END OF FILE in tmp/when_outdented_branch/Test.roc ───────────────────────
+    ── NOT END OF FILE [R0102] in /code/proj/Main.roc ──────────────────────────────
 
     I expected to reach the end of the file, but got stuck here:
 
     6│       2 -> 2
              ^
+
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
+
+    This `when` does not cover all the possibilities:
+
+    4│>      when 4 is
+    5│>          5 -> 2
+
+    Other possibilities include:
+
+        _
+
+    I would have to crash if I saw one of those! Add branches for them!
     "
     );
 
+    test_report!(
+        syntax_errors_in_several_defs,
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            increment = \n -> n +
+
+            numbers = [1, 2 *, 3]
+
+            main : Str
+            main = List.len numbers
+            "#
+        ),
+        @r#"
+    ── INDENT ENDS AFTER EXPRESSION [R0111] in /code/proj/Main.roc ─────────────────
+
+    I am partway through parsing an expression, but I got stuck here:
+
+    3│  increment = \n -> n +
+                             ^
+
+    Looks like the indentation ends prematurely here. Did you mean to have
+    another expression after this line?
+
+    ── TRAILING OPERATOR [R0112] in /code/proj/Main.roc ────────────────────────────
+
+    I am partway through parsing an expression, but I got stuck here:
+
+    5│  numbers = [1, 2 *, 3]
+                         ^
+
+    TODO provide more context.
+
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
+
+    `increment` is not used anywhere in your code.
+
+    3│  increment = \n -> n +
+        ^^^^^^^^^
+
+    If you didn't intend on using `increment` then remove it so future
+    readers of your code don't wonder why it is there.
+
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
+
+    Something is off with the body of the `main` definition:
+
+    7│  main : Str
+    8│  main = List.len numbers
+               ^^^^^^^^^^^^^^^^
+
+    This `len` call produces:
+
+        U64
+
+    But the type annotation on `main` says it should be:
+
+        Str
+    "#
+    );
+
     test_report!(
         when_over_indented_underscore,
         indoc!(
@@ -5436,7 +5614,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNEXPECTED ARROW in tmp/when_over_indented_underscore/Test.roc ──────────────
+    ── UNEXPECTED ARROW [R0138] in /code/proj/Main.roc ─────────────────────────────
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5473,7 +5651,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNEXPECTED ARROW in tmp/when_over_indented_int/Test.roc ─────────────────────
+    ── UNEXPECTED ARROW [R0138] in /code/proj/Main.roc ─────────────────────────────
 
     I am parsing a `when` expression right now, but this arrow is confusing
     me:
@@ -5513,7 +5691,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED IF in tmp/if_outdented_then/Test.roc ─────────────────────────────
+    ── UNFINISHED IF [R0135] in /code/proj/Main.roc ────────────────────────────────
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5533,7 +5711,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED IF in tmp/if_missing_else/Test.roc ───────────────────────────────
+    ── UNFINISHED IF [R0135] in /code/proj/Main.roc ────────────────────────────────
 
     I was partway through parsing an `if` expression, but I got stuck here:
 
@@ -5552,7 +5730,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED LIST in tmp/list_double_comma/Test.roc ───────────────────────────
+    ── UNFINISHED LIST [R0129] in /code/proj/Main.roc ──────────────────────────────
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5572,7 +5750,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── UNFINISHED LIST in tmp/list_without_end/Test.roc ────────────────────────────
+    ── UNFINISHED LIST [R0129] in /code/proj/Main.roc ──────────────────────────────
 
     I am partway through started parsing a list, but I got stuck here:
 
@@ -5598,7 +5776,7 @@ mod test_reporting {
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This float literal contains an invalid digit:
 
@@ -5616,7 +5794,7 @@ mod test_reporting {
         unicode_not_hex,
         r#""abc\u(zzzz)def""#,
         @r#"
-    ── WEIRD CODE POINT in tmp/unicode_not_hex/Test.roc ────────────────────────────
+    ── WEIRD CODE POINT [R0120] in /code/proj/Main.roc ─────────────────────────────
 
     I am partway through parsing a unicode code point, but I got stuck
     here:
@@ -5634,7 +5812,7 @@ mod test_reporting {
         unicode_too_large,
         r#""abc\u(110000)def""#,
         @r#"
-    ── INVALID UNICODE in /code/proj/Main.roc ──────────────────────────────────────
+    ── INVALID UNICODE [R0215] in /code/proj/Main.roc ──────────────────────────────
 
     This unicode code point is invalid:
 
@@ -5649,7 +5827,7 @@ mod test_reporting {
         weird_escape,
         r#""abc\qdef""#,
         @r###"
-    ── WEIRD ESCAPE in tmp/weird_escape/Test.roc ───────────────────────────────────
+    ── WEIRD ESCAPE [R0119] in /code/proj/Main.roc ─────────────────────────────────
 
     I was partway through parsing a  string literal, but I got stuck here:
 
@@ -5673,7 +5851,7 @@ mod test_reporting {
         single_quote_too_long,
         r"'abcdef'",
         @r#"
-    ── INVALID SCALAR in tmp/single_quote_too_long/Test.roc ────────────────────────
+    ── INVALID SCALAR [R0123] in /code/proj/Main.roc ───────────────────────────────
 
     I am part way through parsing this scalar literal (character literal),
     but it's too long to fit in a U32 so it's not a valid scalar.
@@ -5690,7 +5868,7 @@ mod test_reporting {
         single_no_end,
         r#""there is no end"#,
         @r#"
-    ── ENDLESS STRING in tmp/single_no_end/Test.roc ────────────────────────────────
+    ── ENDLESS STRING [R0124] in /code/proj/Main.roc ───────────────────────────────
 
     I cannot find the end of this string:
 
@@ -5706,7 +5884,7 @@ mod test_reporting {
         multi_no_end,
         r#""""there is no end"#,
         @r#"
-    ── ENDLESS STRING in tmp/multi_no_end/Test.roc ─────────────────────────────────
+    ── ENDLESS STRING [R0124] in /code/proj/Main.roc ───────────────────────────────
 
     I cannot find the end of this block string:
 
@@ -5722,7 +5900,7 @@ mod test_reporting {
         multi_insufficient_indent,
         "    \"\"\"\n  testing\n    \"\"\"", // 4 space indent on the start, 2 space on the `testing` line
         @r#"
-    ── INSUFFICIENT INDENT IN MULTI-LINE STRING in ...insufficient_indent/Test.roc ─
+    ── INSUFFICIENT INDENT IN MULTI-LINE STRING [R0126] in /code/proj/Main.roc ─────
 
     This multiline string is not sufficiently indented:
 
@@ -5744,7 +5922,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in tmp/dbg_without_final_expression/Test.roc ───
+    ── INDENT ENDS AFTER EXPRESSION [R0111] in /code/proj/Main.roc ─────────────────
 
     I am partway through parsing a dbg statement, but I got stuck here:
 
@@ -5766,7 +5944,7 @@ mod test_reporting {
             "
         ),
         @r#"
-    ── INDENT ENDS AFTER EXPRESSION in ...expect_without_final_expression/Test.roc ─
+    ── INDENT ENDS AFTER EXPRESSION [R0111] in /code/proj/Main.roc ─────────────────
 
     I am partway through parsing an expect statement, but I got stuck
     here:
@@ -5792,7 +5970,7 @@ mod test_reporting {
             "#,
             ),
             @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5818,7 +5996,7 @@ mod test_reporting {
                 $name,
                 &format!(r#"if Bool.true then "abc" else 1 {} 2"#, $op),
                 |golden| assert_eq!(golden, format!(
-r#"── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+r#"── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
 This `if` has an `else` branch with a different type from its `then` branch:
 
@@ -5861,7 +6039,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `foo` record doesn’t have a `if` field:
 
@@ -5880,7 +6058,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r###"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [R0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Num module does not expose `if`:
 
@@ -5904,7 +6082,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a record field access here:
 
@@ -5923,7 +6101,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am very confused by this field access:
 
@@ -5942,7 +6120,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am very confused by this field access
 
@@ -5963,7 +6141,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r"
-    ── NAMING PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── NAMING PROBLEM [R0200] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse an identifier here:
 
@@ -5997,7 +6175,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/case_of/Test.roc ────────────────────────────────────
+    ── UNKNOWN OPERATOR [R0104] in tmp/case_of/Test.roc ────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6030,7 +6208,7 @@ All branches in an `if` must have the same type!
             "#
         ),
         @r###"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `bar` in this scope.
 
@@ -6055,7 +6233,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/invalid_operator/Test.roc ───────────────────────────
+    ── UNKNOWN OPERATOR [R0104] in /code/proj/Main.roc ─────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6080,7 +6258,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/double_plus/Test.roc ────────────────────────────────
+    ── UNKNOWN OPERATOR [R0104] in /code/proj/Main.roc ─────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6107,7 +6285,7 @@ All branches in an `if` must have the same type!
             "
         ),
         @r#"
-    ── UNKNOWN OPERATOR in tmp/inline_hastype/Test.roc ─────────────────────────────
+    ── UNKNOWN OPERATOR [R0104] in /code/proj/Main.roc ─────────────────────────────
 
     This looks like an operator, but it's not one I recognize!
 
@@ -6138,7 +6316,7 @@ All branches in an `if` must have the same type!
         |golden| pretty_assertions::assert_eq!(
             golden,
             &format!(
-                r#"── UNKNOWN OPERATOR in tmp/wild_case_arrow/Test.roc ────────────────────────────
+                r#"── UNKNOWN OPERATOR [R0104] in /code/proj/Main.roc ─────────────────────────────
 
 This looks like an operator, but it's not one I recognize!
 
@@ -6152,7 +6330,8 @@ Looks like you are trying to define a function.{}
 
 In roc, functions are always written as a lambda, like{}
 
-    increment = \n -> n + 1"#,
+    increment = \n -> n + 1
+"#,
                 ' ', ' '
             )
         )
@@ -6171,7 +6350,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [R0169] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a provides list, but I got stuck here:
 
@@ -6206,7 +6385,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [R0169] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6232,7 +6411,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [R0169] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6259,7 +6438,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD PROVIDES in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD PROVIDES [R0169] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6285,7 +6464,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD MODULE PARAMS in /code/proj/Main.roc ──────────────────────────────────
+                ── WEIRD MODULE PARAMS [R0170] in /code/proj/Main.roc ──────────────────────────
 
                 I am partway through parsing a module header, but I got stuck here:
 
@@ -6321,7 +6500,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── BAD REQUIRES in /code/proj/Main.roc ─────────────────────────────────────────
+                ── BAD REQUIRES [R0175] in /code/proj/Main.roc ─────────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6349,7 +6528,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r"
-                ── WEIRD IMPORTS in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD IMPORTS [R0172] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing a header, but I got stuck here:
 
@@ -6374,7 +6553,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r"
-                ── WEIRD EXPOSES in /code/proj/Main.roc ────────────────────────────────────────
+                ── WEIRD EXPOSES [R0171] in /code/proj/Main.roc ────────────────────────────────
 
                 I am partway through parsing an `exposes` list, but I got stuck here:
 
@@ -6401,7 +6580,7 @@ In roc, functions are always written as a lambda, like{}
             ),
             indoc!(
                 r#"
-                ── WEIRD APP NAME in /code/proj/Main.roc ───────────────────────────────────────
+                ── WEIRD APP NAME [R0164] in /code/proj/Main.roc ───────────────────────────────
 
                 I am partway through parsing a header, but got stuck here:
 
@@ -6425,7 +6604,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6446,7 +6625,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 2 arguments:
 
@@ -6468,7 +6647,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -6494,7 +6673,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_open/Test.roc ───────────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6517,7 +6696,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_end_comma/Test.roc ──────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6540,7 +6719,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_end/Test.roc ────────────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6564,7 +6743,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED FUNCTION in tmp/unfinished_closure_pattern_in_parens/Test.roc ────
+    ── UNFINISHED FUNCTION [R0118] in /code/proj/Main.roc ──────────────────────────
 
     I was partway through parsing a  function, but I got stuck here:
 
@@ -6584,7 +6763,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED PARENTHESES in tmp/pattern_in_parens_indent_open/Test.roc ────────
+    ── UNFINISHED PARENTHESES [R0128] in /code/proj/Main.roc ───────────────────────
 
     I am partway through parsing a pattern in parentheses, but I got stuck
     here:
@@ -6609,7 +6788,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -6637,7 +6816,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `expect` condition needs to be a Bool:
 
@@ -6664,7 +6843,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to * has an unexpected type:
 
@@ -6679,7 +6858,7 @@ In roc, functions are always written as a lambda, like{}
 
         Num *
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `mult` definition:
 
@@ -6708,7 +6887,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to * has an unexpected type:
 
@@ -6723,7 +6902,7 @@ In roc, functions are always written as a lambda, like{}
 
         Num a
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `mult` definition:
 
@@ -6758,7 +6937,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -6768,7 +6947,7 @@ In roc, functions are always written as a lambda, like{}
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO FEW TYPE ARGUMENTS in /code/proj/Main.roc ───────────────────────────────
+    ── TOO FEW TYPE ARGUMENTS [R0236] in /code/proj/Main.roc ───────────────────────
 
     The `Result` alias expects 2 type arguments, but it got 1 instead:
 
@@ -6796,7 +6975,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -6806,7 +6985,7 @@ In roc, functions are always written as a lambda, like{}
     All builtin aliases are in scope by default, so I need this alias to
     have a different name!
 
-    ── TOO MANY TYPE ARGUMENTS in /code/proj/Main.roc ──────────────────────────────
+    ── TOO MANY TYPE ARGUMENTS [R0235] in /code/proj/Main.roc ──────────────────────
 
     The `Result` alias expects 2 type arguments, but it got 3 instead:
 
@@ -6828,7 +7007,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: We should tell the user that we inferred `_` as `a`
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -6862,7 +7041,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -6894,7 +7073,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -6934,7 +7113,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `inner` definition:
 
@@ -6963,7 +7142,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── NOT AN INLINE ALIAS in tmp/error_inline_alias_not_an_alias/Test.roc ─────────
+    ── NOT AN INLINE ALIAS [R0158] in /code/proj/Main.roc ──────────────────────────
 
     The inline type after this `as` is not a type alias:
 
@@ -6983,7 +7162,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── QUALIFIED ALIAS NAME in tmp/error_inline_alias_qualified/Test.roc ───────────
+    ── QUALIFIED ALIAS NAME [R0159] in /code/proj/Main.roc ─────────────────────────
 
     This type alias has a qualified name:
 
@@ -7003,7 +7182,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE ARGUMENT NOT LOWERCASE in ..._inline_alias_argument_uppercase/Test.roc ─
+    ── TYPE ARGUMENT NOT LOWERCASE [R0160] in /code/proj/Main.roc ──────────────────
 
     This alias type argument is not lowercase:
 
@@ -7027,7 +7206,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `isEmpty` has an unexpected type:
 
@@ -7063,7 +7242,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `c` has an unexpected type:
 
@@ -7096,7 +7275,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7119,7 +7298,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7141,7 +7320,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `F` alias is self-recursive in an invalid way:
 
@@ -7166,7 +7345,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `job` is weird:
 
@@ -7201,7 +7380,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `job` definition:
 
@@ -7232,7 +7411,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── NESTED DATATYPE in /code/proj/Main.roc ──────────────────────────────────────
+    ── NESTED DATATYPE [R0221] in /code/proj/Main.roc ──────────────────────────────
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -7260,7 +7439,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── NESTED DATATYPE in /code/proj/Main.roc ──────────────────────────────────────
+    ── NESTED DATATYPE [R0221] in /code/proj/Main.roc ──────────────────────────────
 
     `Nested` is a nested datatype. Here is one recursive usage of it:
 
@@ -7308,7 +7487,7 @@ In roc, functions are always written as a lambda, like{}
 
                     let real = format!(indoc!(
                         r"
-                        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+                        ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
                         This 1st argument to `use` has an unexpected type:
 
@@ -7374,7 +7553,7 @@ In roc, functions are always written as a lambda, like{}
 
                     let real = format!(indoc!(
                         r"
-                        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+                        ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
                         The branches of this `when` expression don't match the condition:
 
@@ -7425,7 +7604,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: link to number suffixes
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -7448,7 +7627,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: link to number suffixes
         @r"
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     This integer literal contains an invalid digit:
 
@@ -7470,7 +7649,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CONFLICTING NUMBER SUFFIX in /code/proj/Main.roc ────────────────────────────
+    ── CONFLICTING NUMBER SUFFIX [R0222] in /code/proj/Main.roc ────────────────────
 
     This number literal is an integer, but it has a float suffix:
 
@@ -7487,7 +7666,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CONFLICTING NUMBER SUFFIX in /code/proj/Main.roc ────────────────────────────
+    ── CONFLICTING NUMBER SUFFIX [R0222] in /code/proj/Main.roc ────────────────────
 
     This number literal is a float, but it has an integer suffix:
 
@@ -7500,7 +7679,7 @@ In roc, functions are always written as a lambda, like{}
         u8_overflow,
         "256u8",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7516,7 +7695,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u8,
         "-1u8",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7532,7 +7711,7 @@ In roc, functions are always written as a lambda, like{}
         u16_overflow,
         "65536u16",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7548,7 +7727,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u16,
         "-1u16",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7564,7 +7743,7 @@ In roc, functions are always written as a lambda, like{}
         u32_overflow,
         "4_294_967_296u32",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7580,7 +7759,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u32,
         "-1u32",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7596,7 +7775,7 @@ In roc, functions are always written as a lambda, like{}
         u64_overflow,
         "18_446_744_073_709_551_616u64",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7612,7 +7791,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u64,
         "-1u64",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7628,7 +7807,7 @@ In roc, functions are always written as a lambda, like{}
         negative_u128,
         "-1u128",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7644,7 +7823,7 @@ In roc, functions are always written as a lambda, like{}
         i8_overflow,
         "128i8",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7660,7 +7839,7 @@ In roc, functions are always written as a lambda, like{}
         i8_underflow,
         "-129i8",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7676,7 +7855,7 @@ In roc, functions are always written as a lambda, like{}
         i16_overflow,
         "32768i16",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7692,7 +7871,7 @@ In roc, functions are always written as a lambda, like{}
         i16_underflow,
         "-32769i16",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7708,7 +7887,7 @@ In roc, functions are always written as a lambda, like{}
         i32_overflow,
         "2_147_483_648i32",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7724,7 +7903,7 @@ In roc, functions are always written as a lambda, like{}
         i32_underflow,
         "-2_147_483_649i32",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7740,7 +7919,7 @@ In roc, functions are always written as a lambda, like{}
         i64_overflow,
         "9_223_372_036_854_775_808i64",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7756,7 +7935,7 @@ In roc, functions are always written as a lambda, like{}
         i64_underflow,
         "-9_223_372_036_854_775_809i64",
         @r"
-    ── NUMBER UNDERFLOWS SUFFIX in /code/proj/Main.roc ─────────────────────────────
+    ── NUMBER UNDERFLOWS SUFFIX [R0224] in /code/proj/Main.roc ─────────────────────
 
     This integer literal underflows the type indicated by its suffix:
 
@@ -7772,7 +7951,7 @@ In roc, functions are always written as a lambda, like{}
         i128_overflow,
         "170_141_183_460_469_231_731_687_303_715_884_105_728i128",
         @r"
-    ── NUMBER OVERFLOWS SUFFIX in /code/proj/Main.roc ──────────────────────────────
+    ── NUMBER OVERFLOWS SUFFIX [R0223] in /code/proj/Main.roc ──────────────────────
 
     This integer literal overflows the type indicated by its suffix:
 
@@ -7794,7 +7973,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO: this error message could be improved, e.g. something like "This argument can
         // be used as ... because of its literal value"
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7820,7 +7999,7 @@ In roc, functions are always written as a lambda, like{}
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7847,7 +8026,7 @@ In roc, functions are always written as a lambda, like{}
              "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `get` has an unexpected type:
 
@@ -7874,7 +8053,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -7905,7 +8084,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7928,7 +8107,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `R` alias is self-recursive in an invalid way:
 
@@ -7952,7 +8131,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Foo` alias is recursive in an invalid way:
 
@@ -7987,7 +8166,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     This alias has the same name as a builtin:
 
@@ -8007,7 +8186,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [R0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8028,7 +8207,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [R0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8042,7 +8221,7 @@ In roc, functions are always written as a lambda, like{}
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `Age` is not used anywhere in your code.
 
@@ -8065,7 +8244,7 @@ In roc, functions are always written as a lambda, like{}
         // and checking it during can. The reason the error appears is because it is parsed as
         // Apply(Error(OtherModule), [@Age, 21])
         @r"
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [R0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8074,7 +8253,7 @@ In roc, functions are always written as a lambda, like{}
 
     Note: It looks like there are no opaque types declared in this scope yet!
 
-    ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+    ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
     I am trying to parse a qualified name here:
 
@@ -8101,7 +8280,7 @@ In roc, functions are always written as a lambda, like{}
         // `@Age` can be linked to the declaration of `Age` inside `age`, and a suggestion to
         // raise that declaration to the outer scope.
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `Age` is not used anywhere in your code.
 
@@ -8111,7 +8290,7 @@ In roc, functions are always written as a lambda, like{}
     If you didn't intend on using `Age` then remove it so future readers of
     your code don't wonder why it is there.
 
-    ── OPAQUE TYPE NOT DEFINED in /code/proj/Main.roc ──────────────────────────────
+    ── OPAQUE TYPE NOT DEFINED [R0225] in /code/proj/Main.roc ──────────────────────
 
     The opaque type Age referenced here is not defined:
 
@@ -8132,7 +8311,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── MODULE NOT IMPORTED in /code/proj/Main.roc ──────────────────────────────────
+    ── MODULE NOT IMPORTED [R0219] in /code/proj/Main.roc ──────────────────────────
 
     The `Task` module is not imported:
 
@@ -8164,7 +8343,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO(opaques): error could be improved by saying that the opaque definition demands
         // that the argument be a U8, and linking to the definitin!
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -8193,7 +8372,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -8223,7 +8402,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `v` definition:
 
@@ -8261,7 +8440,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO(opaques): error could be improved by saying that the user-provided pattern
         // probably wants to change "Age" to "@Age"!
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `f` is weird:
 
@@ -8296,7 +8475,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 2nd pattern in this `when` does not match the previous ones:
 
@@ -8327,7 +8506,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -8365,7 +8544,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -8398,7 +8577,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `y` has an unexpected type:
 
@@ -8431,7 +8610,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -8455,7 +8634,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── INVALID_EXTENSION_TYPE in /code/proj/Main.roc ───────────────────────────────
+    ── INVALID_EXTENSION_TYPE [R0229] in /code/proj/Main.roc ───────────────────────
 
     This record extension type is invalid:
 
@@ -8476,7 +8655,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── INVALID_EXTENSION_TYPE in /code/proj/Main.roc ───────────────────────────────
+    ── INVALID_EXTENSION_TYPE [R0229] in /code/proj/Main.roc ───────────────────────
 
     This tag union extension type is invalid:
 
@@ -8503,7 +8682,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8517,7 +8696,7 @@ In roc, functions are always written as a lambda, like{}
         Unsigned16
         Unsigned64
 
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `UnknownType` in this scope.
 
@@ -8544,7 +8723,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED ABILITY in tmp/ability_first_demand_not_indented_enough/Test.roc ─
+    ── UNFINISHED ABILITY [R0181] in /code/proj/Main.roc ───────────────────────────
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8569,7 +8748,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-        ── UNFINISHED ABILITY in tmp/ability_demands_not_indented_with_first/Test.roc ──
+        ── UNFINISHED ABILITY [R0181] in /code/proj/Main.roc ───────────────────────────
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8592,7 +8771,7 @@ In roc, functions are always written as a lambda, like{}
                 "
         ),
         @r"
-        ── UNFINISHED ABILITY in tmp/ability_demand_value_has_args/Test.roc ────────────
+        ── UNFINISHED ABILITY [R0181] in /code/proj/Main.roc ───────────────────────────
 
         I was partway through parsing an ability definition, but I got stuck
         here:
@@ -8616,7 +8795,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNFINISHED ABILITY in tmp/ability_non_signature_expression/Test.roc ─────────
+    ── UNFINISHED ABILITY [R0181] in /code/proj/Main.roc ───────────────────────────
 
     I was partway through parsing an ability definition, but I got stuck
     here:
@@ -8639,7 +8818,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [R0208] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes a wildcard (`*`) type variable:
 
@@ -8661,7 +8840,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── UNDERSCORE NOT ALLOWED HERE in /code/proj/Main.roc ──────────────────────────
+    ── UNDERSCORE NOT ALLOWED HERE [R0209] in /code/proj/Main.roc ──────────────────
 
     The definition of `I` includes an inferred (`_`) type:
 
@@ -8682,7 +8861,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [R0208] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes a wildcard (`*`) type variable:
 
@@ -8704,7 +8883,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── WILDCARD NOT ALLOWED HERE in /code/proj/Main.roc ────────────────────────────
+    ── WILDCARD NOT ALLOWED HERE [R0208] in /code/proj/Main.roc ────────────────────
 
     The definition of `I` includes 2 wildcard (`*`) type variables. Here is
     one of them:
@@ -8727,7 +8906,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── UNDERSCORE NOT ALLOWED HERE in /code/proj/Main.roc ──────────────────────────
+    ── UNDERSCORE NOT ALLOWED HERE [R0209] in /code/proj/Main.roc ──────────────────
 
     The definition of `I` includes an inferred (`_`) type:
 
@@ -8748,7 +8927,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r###"
-    ── UNDECLARED TYPE VARIABLE in /code/proj/Main.roc ─────────────────────────────
+    ── UNDECLARED TYPE VARIABLE [R0207] in /code/proj/Main.roc ─────────────────────
 
     The definition of `I` includes an undeclared type variable:
 
@@ -8773,7 +8952,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── ABILITY HAS TYPE VARIABLES in /code/proj/Main.roc ───────────────────────────
+    ── ABILITY HAS TYPE VARIABLES [R0250] in /code/proj/Main.roc ───────────────────
 
     The definition of the `MHash` ability includes type variables:
 
@@ -8783,7 +8962,7 @@ In roc, functions are always written as a lambda, like{}
     Abilities cannot depend on type variables, but their member values
     can!
 
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `MHash` is not used anywhere in your code.
 
@@ -8805,7 +8984,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY in /code/proj/Main.roc ──────────────────
+    ── IMPLEMENTS CLAUSE IS NOT AN ABILITY [R0251] in /code/proj/Main.roc ──────────
 
     The type referenced in this "implements" clause is not an ability:
 
@@ -8824,7 +9003,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+        ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
         The `a` name is first defined here:
 
@@ -8853,7 +9032,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+        ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
         The `Ability` name is first defined here:
 
@@ -8880,7 +9059,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
+        ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [R0254] in /code/proj/Main.roc ─────
 
         The definition of the ability member `ab` does not include an `implements`
         clause binding a type variable to the ability `Ability`:
@@ -8895,7 +9074,7 @@ In roc, functions are always written as a lambda, like{}
 
         Otherwise, the function does not need to be part of the ability!
 
-        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+        ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
         `Ability` is not used anywhere in your code.
 
@@ -8917,7 +9096,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES in /code/proj/Main.roc ──────────────
+        ── ABILITY MEMBER BINDS MULTIPLE VARIABLES [R0255] in /code/proj/Main.roc ──────
 
         The definition of the ability member `eq` includes multiple variables
         bound to the `MEq`` ability:`
@@ -8945,7 +9124,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── ILLEGAL IMPLEMENTS CLAUSE in /code/proj/Main.roc ────────────────────────────
+    ── ILLEGAL IMPLEMENTS CLAUSE [R0252] in /code/proj/Main.roc ────────────────────
 
     An `implements` clause is not allowed here:
 
@@ -8955,7 +9134,7 @@ In roc, functions are always written as a lambda, like{}
     `implements` clauses can only be specified on the top-level type
     annotations.
 
-    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE in /code/proj/Main.roc ─────────────
+    ── ABILITY MEMBER MISSING IMPLEMENTS CLAUSE [R0254] in /code/proj/Main.roc ─────
 
     The definition of the ability member `hash` does not include an
     `implements` clause binding a type variable to the ability `MHash`:
@@ -8986,7 +9165,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+        ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
         Something is off with this specialization of `hash`:
 
@@ -9019,7 +9198,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9045,7 +9224,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `hash` is not used anywhere in your code.
 
@@ -9074,7 +9253,7 @@ In roc, functions are always written as a lambda, like{}
         ),
         // TODO: the error message here could be seriously improved!
         @r"
-    ── OVERLOADED SPECIALIZATION in /code/proj/Main.roc ────────────────────────────
+    ── OVERLOADED SPECIALIZATION [R0269] in /code/proj/Main.roc ────────────────────
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -9087,7 +9266,7 @@ In roc, functions are always written as a lambda, like{}
     Ability specializations can only provide implementations for one
     opaque type, since all opaque types are different!
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This specialization of `hash` is overly general:
 
@@ -9127,7 +9306,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── OVERLOADED SPECIALIZATION in /code/proj/Main.roc ────────────────────────────
+    ── OVERLOADED SPECIALIZATION [R0269] in /code/proj/Main.roc ────────────────────
 
     This ability member specialization is already claimed to specialize
     another opaque type:
@@ -9158,7 +9337,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with this specialization of `eq`:
 
@@ -9193,7 +9372,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `hash` definition:
 
@@ -9234,7 +9413,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9247,7 +9426,7 @@ In roc, functions are always written as a lambda, like{}
 
     Only builtin abilities can have generated implementations!
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9272,7 +9451,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── ABILITY NOT ON TOP-LEVEL in /code/proj/Main.roc ─────────────────────────────
+        ── ABILITY NOT ON TOP-LEVEL [R0256] in /code/proj/Main.roc ─────────────────────
 
         This ability definition is not on the top-level of a module:
 
@@ -9300,7 +9479,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `hashable` definition:
 
@@ -9346,7 +9525,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── ABILITY USED AS TYPE in /code/proj/Main.roc ─────────────────────────────────
+    ── ABILITY USED AS TYPE [R0258] in /code/proj/Main.roc ─────────────────────────
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9360,7 +9539,7 @@ In roc, functions are always written as a lambda, like{}
 
         a implements MHash
 
-    ── ABILITY USED AS TYPE in /code/proj/Main.roc ─────────────────────────────────
+    ── ABILITY USED AS TYPE [R0258] in /code/proj/Main.roc ─────────────────────────
 
     You are attempting to use the ability `MHash` as a type directly:
 
@@ -9390,7 +9569,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -9443,7 +9622,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── WEIRD IMPORTS in tmp/imports_missing_comma/Test.roc ─────────────────────────
+        ── WEIRD IMPORTS [R0172] in tmp/imports_missing_comma/Test.roc ─────────────────
 
         I am partway through parsing a imports list, but I got stuck here:
 
@@ -9468,7 +9647,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+        ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
         This `when` does not cover all the possibilities:
 
@@ -9515,7 +9694,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-        ── SPECIALIZATION NOT ON TOP-LEVEL in /code/proj/Main.roc ──────────────────────
+        ── SPECIALIZATION NOT ON TOP-LEVEL [R0257] in /code/proj/Main.roc ──────────────
 
         This specialization of the `default` ability member is in a nested
         scope:
@@ -9540,7 +9719,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -9576,7 +9755,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `remove` has an unexpected type:
 
@@ -9597,7 +9776,7 @@ In roc, functions are always written as a lambda, like{}
     change the type annotation to be more specific? Maybe change the code
     to be more general?
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `new`:
 
@@ -9610,7 +9789,7 @@ In roc, functions are always written as a lambda, like{}
 
         { set : Set ∞ }
 
-    ── CIRCULAR TYPE in /code/proj/Main.roc ────────────────────────────────────────
+    ── CIRCULAR TYPE [R0306] in /code/proj/Main.roc ────────────────────────────────
 
     I'm inferring a weird self-referential type for `goal`:
 
@@ -9640,7 +9819,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-        ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+        ── CIRCULAR DEFINITION [R0216] in /code/proj/Main.roc ──────────────────────────
 
         The `t1` definition is causing a very tricky infinite loop:
 
@@ -9668,7 +9847,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9696,7 +9875,7 @@ In roc, functions are always written as a lambda, like{}
         // TODO: this error message is quite unfortunate. We should remove the duplication, and
         // also support regions that point to things in other modules. See also https://github.com/roc-lang/roc/issues/3056.
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -9733,7 +9912,7 @@ In roc, functions are always written as a lambda, like{}
                 "#
         ),
         @r"
-            ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+            ── CIRCULAR DEFINITION [R0216] in /code/proj/Main.roc ──────────────────────────
 
             The `t1` definition is causing a very tricky infinite loop:
 
@@ -9763,7 +9942,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── IMPLEMENTATION NOT FOUND in /code/proj/Main.roc ─────────────────────────────
+    ── IMPLEMENTATION NOT FOUND [R0260] in /code/proj/Main.roc ─────────────────────
 
     An implementation of `eq` could not be found in this scope:
 
@@ -9774,7 +9953,7 @@ In roc, functions are always written as a lambda, like{}
     another variable that implements this ability member, like
     { eq: myeq }
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9801,7 +9980,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+    ── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
     Nothing is named `aMEq` in this scope.
 
@@ -9815,7 +9994,7 @@ In roc, functions are always written as a lambda, like{}
         myMEq
         eq
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9842,7 +10021,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
+    ── OPTIONAL ABILITY IMPLEMENTATION [R0263] in /code/proj/Main.roc ──────────────
 
     Ability implementations cannot be optional:
 
@@ -9853,7 +10032,7 @@ In roc, functions are always written as a lambda, like{}
 
 
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9880,7 +10059,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── OPTIONAL ABILITY IMPLEMENTATION in /code/proj/Main.roc ──────────────────────
+    ── OPTIONAL ABILITY IMPLEMENTATION [R0263] in /code/proj/Main.roc ──────────────
 
     Ability implementations cannot be optional:
 
@@ -9893,7 +10072,7 @@ In roc, functions are always written as a lambda, like{}
     record of implementations. For example,    implements [Encoding] will
     attempt to derive `Encoding`
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `Encoding` ability:
 
@@ -9918,7 +10097,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── QUALIFIED ABILITY IMPLEMENTATION in /code/proj/Main.roc ─────────────────────
+    ── QUALIFIED ABILITY IMPLEMENTATION [R0264] in /code/proj/Main.roc ─────────────
 
     This ability implementation is qualified:
 
@@ -9928,7 +10107,7 @@ In roc, functions are always written as a lambda, like{}
     Custom implementations must be defined in the local scope, and
     unqualified.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9953,7 +10132,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── ABILITY IMPLEMENTATION NOT IDENTIFIER in /code/proj/Main.roc ────────────────
+    ── ABILITY IMPLEMENTATION NOT IDENTIFIER [R0265] in /code/proj/Main.roc ────────
 
     This ability implementation is not an identifier:
 
@@ -9965,7 +10144,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: consider defining this expression as a variable.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     This type does not fully implement the `MEq` ability:
 
@@ -9992,7 +10171,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── DUPLICATE IMPLEMENTATION in /code/proj/Main.roc ─────────────────────────────
+    ── DUPLICATE IMPLEMENTATION [R0266] in /code/proj/Main.roc ─────────────────────
 
     This ability member implementation is duplicate:
 
@@ -10020,7 +10199,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── NOT AN ABILITY in /code/proj/Main.roc ───────────────────────────────────────
+    ── NOT AN ABILITY [R0262] in /code/proj/Main.roc ───────────────────────────────
 
     This identifier is not an ability in scope:
 
@@ -10043,7 +10222,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── ILLEGAL DERIVE in /code/proj/Main.roc ───────────────────────────────────────
+    ── ILLEGAL DERIVE [R0259] in /code/proj/Main.roc ───────────────────────────────
 
     This ability cannot be derived:
 
@@ -10066,7 +10245,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -10091,7 +10270,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `A`:
 
@@ -10143,7 +10322,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     The `main` name is first defined here:
 
@@ -10158,7 +10337,7 @@ In roc, functions are always written as a lambda, like{}
     Since these variables have the same name, it's easy to use the wrong
     one by accident. Give one of them a new name.
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10192,7 +10371,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-        ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+        ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
         Something is off with the body of the `withOpen` definition:
 
@@ -10227,7 +10406,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression is used in an unexpected way:
 
@@ -10274,7 +10453,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `foo` has an unexpected type:
 
@@ -10319,7 +10498,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -10344,7 +10523,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to == has an unexpected type:
 
@@ -10381,7 +10560,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the 2nd branch of this `when` expression:
 
@@ -10418,7 +10597,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `map` has an unexpected type:
 
@@ -10444,7 +10623,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [R0231] in /code/proj/Main.roc ───────────────
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -10455,7 +10634,7 @@ In roc, functions are always written as a lambda, like{}
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [R0231] in /code/proj/Main.roc ───────────────
 
         `y` is not bound in all patterns of this `when` branch
 
@@ -10466,7 +10645,7 @@ In roc, functions are always written as a lambda, like{}
         of the branch. Otherwise, the program would crash when it tries to use
         an identifier that wasn't bound!
 
-        ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+        ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
         `y` is not used in this `when` branch.
 
@@ -10487,7 +10666,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-        ── NAME NOT BOUND IN ALL PATTERNS in /code/proj/Main.roc ───────────────────────
+        ── NAME NOT BOUND IN ALL PATTERNS [R0231] in /code/proj/Main.roc ───────────────
 
         `x` is not bound in all patterns of this `when` branch
 
@@ -10510,7 +10689,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -10536,7 +10715,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── UNRECOGNIZED NAME in /code/proj/Main.roc ────────────────────────────────────
+                r"── UNRECOGNIZED NAME [R0201] in /code/proj/Main.roc ────────────────────────────
 
                 Nothing is named `foo` in this scope.
 
@@ -10573,7 +10752,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                r"── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
                 An underscore is being used as a variable here:
 
@@ -10599,7 +10778,7 @@ In roc, functions are always written as a lambda, like{}
         |golden| pretty_assertions::assert_eq!(
             golden,
             indoc!(
-                r"── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                r"── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
                 This variable's name starts with an underscore:
 
@@ -10632,7 +10811,7 @@ In roc, functions are always written as a lambda, like{}
             golden,
             indoc!(
                 r"
-                ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
                 This variable's name starts with an underscore:
 
@@ -10659,7 +10838,7 @@ In roc, functions are always written as a lambda, like{}
             golden,
             indoc!(
                 r"
-                ── SYNTAX PROBLEM in /code/proj/Main.roc ───────────────────────────────────────
+                ── SYNTAX PROBLEM [R0100] in /code/proj/Main.roc ───────────────────────────────
 
                 Underscores are not allowed in identifier names:
 
@@ -10686,7 +10865,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── BAD RECORD BUILDER in tmp/optional_field_in_record_builder/Test.roc ─────────
+    ── BAD RECORD BUILDER [R0109] in /code/proj/Main.roc ───────────────────────────
 
     I am partway through parsing a record builder, and I found an optional
     field:
@@ -10715,7 +10894,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── BAD RECORD UPDATE in tmp/record_update_builder/Test.roc ─────────────────────
+    ── BAD RECORD UPDATE [R0110] in /code/proj/Main.roc ────────────────────────────
 
     I am partway through parsing a record update, and I found a record
     builder field:
@@ -10741,7 +10920,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── MULTIPLE RECORD BUILDERS in /code/proj/Main.roc ─────────────────────────────
+    ── MULTIPLE RECORD BUILDERS [R0241] in /code/proj/Main.roc ─────────────────────
 
     This function is applied to multiple record builders:
 
@@ -10764,7 +10943,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── UNAPPLIED RECORD BUILDER in /code/proj/Main.roc ─────────────────────────────
+    ── UNAPPLIED RECORD BUILDER [R0242] in /code/proj/Main.roc ─────────────────────
 
     This record builder was not applied to a function:
 
@@ -10789,7 +10968,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     This value is not a function, but it was given 1 argument:
 
@@ -10821,7 +11000,7 @@ In roc, functions are always written as a lambda, like{}
     //         "#
     //     ),
     //     @r#"
-    // ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    // ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     // This value is an opaque type, so it cannot be called with an argument:
 
@@ -10848,7 +11027,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10860,7 +11039,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10872,7 +11051,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10884,7 +11063,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10914,7 +11093,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10926,7 +11105,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10938,7 +11117,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10950,7 +11129,7 @@ In roc, functions are always written as a lambda, like{}
     functional, assignments that don't introduce variables cannot affect a
     program's behavior!
 
-    ── UNNECESSARY DEFINITION in /code/proj/Main.roc ───────────────────────────────
+    ── UNNECESSARY DEFINITION [R0232] in /code/proj/Main.roc ───────────────────────
 
     This destructure assignment doesn't introduce any new variables:
 
@@ -10978,7 +11157,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `hash` is not used anywhere in your code.
 
@@ -11005,7 +11184,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── WRONG SPECIALIZATION TYPE in /code/proj/Main.roc ────────────────────────────
+    ── WRONG SPECIALIZATION TYPE [R0271] in /code/proj/Main.roc ────────────────────
 
     This specialization of `hash` is not for the expected type:
 
@@ -11028,7 +11207,7 @@ In roc, functions are always written as a lambda, like{}
                 "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `x` definition:
 
@@ -11058,7 +11237,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── CYCLIC ALIAS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── CYCLIC ALIAS [R0303] in /code/proj/Main.roc ─────────────────────────────────
 
     The `Recursive` opaque is self-recursive in an invalid way:
 
@@ -11080,7 +11259,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -11105,7 +11284,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Decoding` ability for `A`:
 
@@ -11162,7 +11341,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11195,7 +11374,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11225,7 +11404,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r#"
-    ── UNUSED ARGUMENT in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNUSED ARGUMENT [R0210] in /code/proj/Main.roc ──────────────────────────────
 
     This function doesn't use `x`.
 
@@ -11257,7 +11436,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -11290,7 +11469,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -11327,7 +11506,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `then` branch of this `if` expression:
 
@@ -11359,7 +11538,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNUSED DEFINITION in /code/proj/Main.roc ────────────────────────────────────
+    ── UNUSED DEFINITION [R0202] in /code/proj/Main.roc ────────────────────────────
 
     `foo` is not used in this `when` branch.
 
@@ -11387,7 +11566,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11418,7 +11597,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11484,7 +11663,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -11512,7 +11691,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -11539,7 +11718,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [R0309] in /code/proj/Main.roc ──────────────────────────
 
     The 2nd pattern will never be matched:
 
@@ -11566,7 +11745,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [R0309] in /code/proj/Main.roc ──────────────────────────
 
     The 2nd pattern will never be matched:
 
@@ -11578,7 +11757,7 @@ In roc, functions are always written as a lambda, like{}
     It's impossible to create a value of this shape, so this pattern can
     be safely removed!
 
-    ── UNMATCHABLE PATTERN in /code/proj/Main.roc ──────────────────────────────────
+    ── UNMATCHABLE PATTERN [R0309] in /code/proj/Main.roc ──────────────────────────
 
     The 3rd pattern will never be matched:
 
@@ -11603,7 +11782,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── DUPLICATE NAME in /code/proj/Main.roc ───────────────────────────────────────
+    ── DUPLICATE NAME [R0217] in /code/proj/Main.roc ───────────────────────────────
 
     This opaque type has the same name as a builtin:
 
@@ -11627,7 +11806,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r###"
-    ── UNUSED IMPORT in /code/proj/Main.roc ────────────────────────────────────────
+    ── UNUSED IMPORT [R0203] in /code/proj/Main.roc ────────────────────────────────
 
     List is imported but not used.
 
@@ -11681,7 +11860,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── CIRCULAR DEFINITION in /code/proj/Main.roc ──────────────────────────────────
+    ── CIRCULAR DEFINITION [R0216] in /code/proj/Main.roc ──────────────────────────
 
     `main` is defined directly in terms of itself:
 
@@ -11703,7 +11882,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -11729,7 +11908,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This `if` condition needs to be a Bool:
 
@@ -11757,7 +11936,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -11782,7 +11961,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `A`:
 
@@ -11865,7 +12044,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11892,7 +12071,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11938,7 +12117,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -11974,7 +12153,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shiftRightZfBy` has an unexpected type:
 
@@ -11989,7 +12168,7 @@ In roc, functions are always written as a lambda, like{}
 
         U8
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shiftRightBy` has an unexpected type:
 
@@ -12004,7 +12183,7 @@ In roc, functions are always written as a lambda, like{}
 
         U8
 
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `shiftLeftBy` has an unexpected type:
 
@@ -12032,7 +12211,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `contains` has an unexpected type:
 
@@ -12059,7 +12238,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12084,7 +12263,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -12114,7 +12293,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12140,7 +12319,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12168,7 +12347,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `A`:
 
@@ -12251,7 +12430,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12278,7 +12457,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12307,7 +12486,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12330,7 +12509,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12370,7 +12549,7 @@ In roc, functions are always written as a lambda, like{}
              "#
         ),
         @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12408,7 +12587,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [R0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Bool module does not expose `structuralEq`:
 
@@ -12422,7 +12601,7 @@ In roc, functions are always written as a lambda, like{}
         Bool.false
         Bool.isEq
 
-    ── NOT EXPOSED in /code/proj/Main.roc ──────────────────────────────────────────
+    ── NOT EXPOSED [R0218] in /code/proj/Main.roc ──────────────────────────────────
 
     The Bool module does not expose `structuralNotEq`:
 
@@ -12450,7 +12629,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -12475,7 +12654,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The 1st argument to `foo` is weird:
 
@@ -12504,7 +12683,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Hash` ability for `F`:
 
@@ -12515,7 +12694,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: You can define a custom implementation of `Hash` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Eq` ability for `F`:
 
@@ -12526,7 +12705,7 @@ In roc, functions are always written as a lambda, like{}
 
     Tip: You can define a custom implementation of `Eq` for `F`.
 
-    ── INCOMPLETE ABILITY IMPLEMENTATION in /code/proj/Main.roc ────────────────────
+    ── INCOMPLETE ABILITY IMPLEMENTATION [R0268] in /code/proj/Main.roc ────────────
 
     I can't derive an implementation of the `Encoding` ability for `F`:
 
@@ -12549,7 +12728,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── DUPLICATE BOUND ABILITY in /code/proj/Main.roc ──────────────────────────────
+    ── DUPLICATE BOUND ABILITY [R0253] in /code/proj/Main.roc ──────────────────────
 
     I already saw that this type variable is bound to the `Hash` ability
     once before:
@@ -12575,7 +12754,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12612,7 +12791,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12650,7 +12829,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 1st argument to `g` has an unexpected type:
 
@@ -12683,7 +12862,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNFINISHED LIST PATTERN in tmp/list_pattern_not_terminated/Test.roc ─────────
+    ── UNFINISHED LIST PATTERN [R0142] in /code/proj/Main.roc ──────────────────────
 
     I am partway through parsing a list pattern, but I got stuck here:
 
@@ -12704,7 +12883,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── INCORRECT REST PATTERN in tmp/list_pattern_weird_rest_pattern/Test.roc ──────
+    ── INCORRECT REST PATTERN [R0143] in /code/proj/Main.roc ───────────────────────
 
     It looks like you may trying to write a list rest pattern, but it's
     not the form I expect:
@@ -12726,7 +12905,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── UNNECESSARY WILDCARD in /code/proj/Main.roc ─────────────────────────────────
+    ── UNNECESSARY WILDCARD [R0233] in /code/proj/Main.roc ─────────────────────────
 
     This type annotation has a wildcard type variable (`*`) that isn't
     needed.
@@ -12753,7 +12932,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── MULTIPLE LIST REST PATTERNS in /code/proj/Main.roc ──────────────────────────
+    ── MULTIPLE LIST REST PATTERNS [R0234] in /code/proj/Main.roc ──────────────────
 
     This list pattern match has multiple rest patterns:
 
@@ -12763,7 +12942,7 @@ In roc, functions are always written as a lambda, like{}
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12787,7 +12966,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── MULTIPLE LIST REST PATTERNS in /code/proj/Main.roc ──────────────────────────
+    ── MULTIPLE LIST REST PATTERNS [R0234] in /code/proj/Main.roc ──────────────────
 
     This list pattern match has multiple rest patterns:
 
@@ -12797,7 +12976,7 @@ In roc, functions are always written as a lambda, like{}
     I only support compiling list patterns with one .. pattern! Can you
     remove this additional one?
 
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12821,7 +13000,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This list element doesn't match the types of other elements in the
     pattern:
@@ -12848,7 +13027,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     The branches of this `when` expression don't match the condition:
 
@@ -12881,7 +13060,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12921,7 +13100,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -12965,7 +13144,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -13031,7 +13210,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13058,7 +13237,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13101,7 +13280,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13128,7 +13307,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13177,7 +13356,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13208,7 +13387,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13240,7 +13419,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13289,7 +13468,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -13323,7 +13502,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13351,7 +13530,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13379,7 +13558,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 3rd pattern is redundant:
 
@@ -13407,7 +13586,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── REDUNDANT PATTERN in /code/proj/Main.roc ────────────────────────────────────
+    ── REDUNDANT PATTERN [R0308] in /code/proj/Main.roc ────────────────────────────
 
     The 2nd pattern is redundant:
 
@@ -13446,7 +13625,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13477,7 +13656,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This value passed to `crash` is not a string:
 
@@ -13502,7 +13681,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
     @r"
-    ── UNAPPLIED CRASH in /code/proj/Main.roc ──────────────────────────────────────
+    ── UNAPPLIED CRASH [R0237] in /code/proj/Main.roc ──────────────────────────────
 
     This `crash` doesn't have a message given to it:
 
@@ -13523,7 +13702,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── OVERAPPLIED CRASH in /code/proj/Main.roc ────────────────────────────────────
+    ── OVERAPPLIED CRASH [R0238] in /code/proj/Main.roc ────────────────────────────
 
     This `crash` has too many values given to it:
 
@@ -13559,7 +13738,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13601,7 +13780,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── DEFINITION ONLY USED IN RECURSION in /code/proj/Main.roc ────────────────────
+    ── DEFINITION ONLY USED IN RECURSION [R0230] in /code/proj/Main.roc ────────────
 
     This definition is only used in recursion with itself:
 
@@ -13634,7 +13813,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── DEFINITIONs ONLY USED IN RECURSION in /code/proj/Main.roc ───────────────────
+    ── DEFINITIONs ONLY USED IN RECURSION [R0230] in /code/proj/Main.roc ───────────
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13670,7 +13849,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── DEFINITION ONLY USED IN RECURSION in /code/proj/Main.roc ────────────────────
+    ── DEFINITION ONLY USED IN RECURSION [R0230] in /code/proj/Main.roc ────────────
 
     This definition is only used in recursion with itself:
 
@@ -13706,7 +13885,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r#"
-    ── DEFINITIONs ONLY USED IN RECURSION in /code/proj/Main.roc ───────────────────
+    ── DEFINITIONs ONLY USED IN RECURSION [R0230] in /code/proj/Main.roc ───────────
 
     These 2 definitions are only used in mutual recursion with themselves:
 
@@ -13745,7 +13924,7 @@ In roc, functions are always written as a lambda, like{}
             {one, str}
         "#),
     @r#"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This 2nd argument to `concat` has an unexpected type:
 
@@ -13776,7 +13955,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -13813,7 +13992,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the `else` branch of this `if` expression:
 
@@ -13849,7 +14028,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `main` definition:
 
@@ -13887,7 +14066,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
     @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `main` definition:
 
@@ -13957,7 +14136,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r###"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -13997,7 +14176,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     This expression has a type that does not implement the abilities it's expected to:
 
@@ -14037,7 +14216,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── UNSAFE PATTERN in /code/proj/Main.roc ───────────────────────────────────────
+    ── UNSAFE PATTERN [R0307] in /code/proj/Main.roc ───────────────────────────────
 
     This `when` does not cover all the possibilities:
 
@@ -14085,7 +14264,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r#"
-    ── TOO MANY ARGS in /code/proj/Main.roc ────────────────────────────────────────
+    ── TOO MANY ARGS [R0304] in /code/proj/Main.roc ────────────────────────────────
 
     The `parser` value is an opaque type, so it cannot be called with an
     argument:
@@ -14109,7 +14288,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14140,7 +14319,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14174,7 +14353,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14208,7 +14387,7 @@ In roc, functions are always written as a lambda, like{}
             "#
         ),
         @r"
-    ── TYPE MISMATCH in /code/proj/Main.roc ────────────────────────────────────────
+    ── TYPE MISMATCH [R0300] in /code/proj/Main.roc ────────────────────────────────
 
     Something is off with the body of the `f` definition:
 
@@ -14236,7 +14415,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [R0305] in /code/proj/Main.roc ─────────────────────────────────
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -14256,7 +14435,7 @@ In roc, functions are always written as a lambda, like{}
             "
         ),
         @r"
-    ── TOO FEW ARGS in /code/proj/Main.roc ─────────────────────────────────────────
+    ── TOO FEW ARGS [R0305] in /code/proj/Main.roc ─────────────────────────────────
 
     The `sub` function expects 2 arguments, but it got only 1:
 
@@ -14267,4 +14446,467 @@ In roc, functions are always written as a lambda, like{}
     make partial application explicit.
     "
     );
+
+    test_fix!(
+        fix_unused_argument,
+        indoc!(
+            r"
+            f = \x -> 1
+
+            f 2
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        f = \_x -> 1
+        
+        f 2
+    "#
+    );
+
+    test_fix!(
+        fix_unused_record_field_argument,
+        indoc!(
+            r"
+            f = \{ x } -> 1
+
+            f { x: 2 }
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        f = \{ x } -> 1
+        
+        f { x: 2 }
+    "#
+    );
+
+    test_fix!(
+        fix_unused_renamed_record_field_argument,
+        indoc!(
+            r"
+            f = \{ x: y } -> 1
+
+            f { x: 2 }
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        f = \{ x: _y } -> 1
+        
+        f { x: 2 }
+    "#
+    );
+
+    test_fix!(
+        fix_unused_when_branch_def,
+        indoc!(
+            r"
+            when Ok 1 is
+                Ok x -> 1
+                Err _ -> 2
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        when Ok 1 is
+            Ok _x -> 1
+            Err _ -> 2
+    "#
+    );
+
+    test_fix!(
+        fix_unnecessary_extension_variable,
+        indoc!(
+            r"
+            f : {} -> [A, B]*
+            f = \{} -> A
+
+            f {}
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        f : {} -> [A, B]
+        f = \{} -> A
+        
+        f {}
+    "#
+    );
+
+    test_fix!(
+        fix_unused_module_import,
+        indoc!(
+            r#"
+            app "test" imports [] provides [main] to "./platform"
+
+            import List exposing [concat]
+
+            main = ""
+            "#
+        ),
+        @r#"
+    app "test" imports [] provides [main] to "./platform"
+
+
+    main = ""
+    "#
+    );
+
+    test_fix!(
+        fix_unused_exposed_value,
+        indoc!(
+            r#"
+            app "test" imports [] provides [main] to "./platform"
+
+            import List exposing [concat, map]
+
+            main = map [1] Num.toStr
+            "#
+        ),
+        @r#"
+    app "test" imports [] provides [main] to "./platform"
+
+    import List exposing [map]
+
+    main = map [1] Num.toStr
+    "#
+    );
+
+    test_fix!(
+        typo_suggestions_are_not_applied,
+        indoc!(
+            r"
+            foo = 1
+
+            fop
+            "
+        ),
+        @r#"
+    app "test" provides [main] to "./platform"
+
+    main =
+        foo = 1
+        
+        fop
+    "#
+    );
+
+    test_lint!(
+        lint_leftover_dbg,
+        indoc!(
+            r"
+            x = 1
+
+            dbg x
+
+            x + 1
+            "
+        ),
+        @r#"
+    ── LEFTOVER DBG [R0501] in /code/proj/Main.roc ─────────────────────────────────
+
+    This `dbg` is still in the code:
+
+    6│      dbg x
+                ^
+
+    `dbg` is meant for debugging. Remove it once you're done, so it doesn't
+    print every time this code runs.
+    "#
+    );
+
+    test_lint!(
+        lint_shadowed_builtin,
+        indoc!(
+            r"
+            true = 1
+
+            true + 1
+            "
+        ),
+        @r#"
+    ── SHADOWED BUILTIN [R0500] in /code/proj/Main.roc ─────────────────────────────
+
+    This `true` has the same name as `Bool.true`:
+
+    4│      true = 1
+            ^^^^
+
+    Since `Bool.true` is only in scope with its module name, this is a
+    different value that just looks like the builtin. Give it a name that
+    doesn't, so nobody mixes the two up.
+    "#
+    );
+
+    test_lint!(
+        lint_crash_in_library,
+        indoc!(
+            r#"
+            crash "not yet"
+            "#
+        ),
+        @r#"
+    ── CRASH IN LIBRARY [R0502] in /code/proj/Main.roc ─────────────────────────────
+
+    This `crash` is in a library module:
+
+    4│      crash "not yet"
+            ^^^^^^^^^^^^^^^
+
+    A library can't know whether crashing is acceptable for the apps that
+    use it. Return an error instead, and let each app decide what to do
+    about it.
+    "#
+    );
+
+    test_lint!(
+        lint_redundant_try,
+        indoc!(
+            r"
+            a = Result.try (Ok 1) Ok
+            b = Result.try (Ok 1) \x -> Ok (x + 1)
+
+            (a, b)
+            "
+        ),
+        @r#"
+    ── REDUNDANT TRY [R0503] in /code/proj/Main.roc ────────────────────────────────
+
+    This `Result.try` can never fail in its callback:
+
+    4│      a = Result.try (Ok 1) Ok
+                ^^^^^^^^^^^^^^^^^^^^
+
+    Its callback only wraps what it's given in an `Ok` again, so this is
+    the same as the `Result` it's called with. Use that instead.
+
+    ── REDUNDANT TRY [R0503] in /code/proj/Main.roc ────────────────────────────────
+
+    This `Result.try` can never fail in its callback:
+
+    5│      b = Result.try (Ok 1) \x -> Ok (x + 1)
+                ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    Its callback always returns an `Ok`, so this is the same as calling
+    `Result.map` with a callback that doesn't wrap its answer in `Ok`.
+    "#
+    );
+
+    test_lint!(
+        lint_repeated_when,
+        indoc!(
+            r"
+            x = 1
+
+            when x is
+                1 -> A
+                _ ->
+                    when x is
+                        2 -> B
+                        _ ->
+                            when x is
+                                3 -> C
+                                _ -> D
+            "
+        ),
+        @r#"
+    ── REPEATED WHEN [R0504] in /code/proj/Main.roc ────────────────────────────────
+
+    This `when` on `x` has 2 more nested in its catch-all branch:
+
+     6│>      when x is
+     7│>          1 -> A
+     8│>          _ ->
+     9│>              when x is
+    10│>                  2 -> B
+    11│>                  _ ->
+    12│>                      when x is
+    13│>                          3 -> C
+    14│>                          _ -> D
+
+    These can all be a single `when`, which keeps every case in one place.
+    "#
+    );
+
+    #[test]
+    fn lint_repeated_when_length() {
+        let src = indoc!(
+            r"
+            x = 1
+
+            when x is
+                1 -> A
+                _ ->
+                    when x is
+                        2 -> B
+                        _ -> C
+            "
+        );
+
+        // Two `when`s aren't enough to report by default, but can be made to be.
+        __new_lint_report("lint_repeated_when_length", src, |golden| {
+            assert_eq!(golden.trim(), "")
+        });
+
+        let mut settings = LintSettings::default();
+        settings.set("repeated-when-length", "2").unwrap();
+        __new_lint_report_with("lint_repeated_when_length", src, &settings, |golden| {
+            assert!(golden.contains("REPEATED WHEN [R0504]"), "{golden}")
+        });
+    }
+
+    test_lint!(
+        lint_try_chain,
+        indoc!(
+            r#"
+            parse = \str -> Str.toU64 str
+
+            sum =
+                Result.try (parse "1") \x ->
+                    Result.try (parse "2") \y ->
+                        parse (Num.toStr (x + y))
+
+            total = Result.try (Result.try (parse "3") \z -> parse "4") \w -> parse (Num.toStr w)
+
+            (sum, total)
+            "#
+        ),
+        @r#"
+    ── TRY CHAIN [R0506] in /code/proj/Main.roc ────────────────────────────────────
+
+    These 2 `Result.try` calls are chained together:
+
+    7│>          Result.try (parse "1") \x ->
+    8│>              Result.try (parse "2") \y ->
+    9│>                  parse (Num.toStr (x + y))
+
+    Each call can be a def that uses `?` instead:
+
+        x = parse? "1"
+        y = parse? "2"
+        parse (Num.toStr (x + y))
+
+    ── TRY CHAIN [R0506] in /code/proj/Main.roc ────────────────────────────────────
+
+    These 2 `Result.try` calls are chained together:
+
+    11│      total = Result.try (Result.try (parse "3") \z -> parse "4") \w -> parse (Num.toStr w)
+                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+
+    Each call can be a def that uses `?` instead:
+
+        z = parse? "3"
+        w = parse? "4"
+        parse (Num.toStr w)
+    "#
+    );
+
+    #[test]
+    fn lint_try_chain_rewrite() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+            parse = \str -> Str.toU64 str
+
+            total = Result.try (parse "1") \x -> Result.try (parse "2") \y -> parse (Num.toStr (x + y))
+
+            total
+            "#
+        );
+        let (module_src, result) = run_load_and_infer("lint_try_chain_rewrite", &arena, src);
+        let LoadedModule {
+            module_id: home,
+            declarations_by_id,
+            solved,
+            interns,
+            ..
+        } = result.expect("failed to load");
+        let ctx = LintContext {
+            interns: &interns,
+            subs: solved.inner(),
+            is_library: true,
+            src: &module_src,
+        };
+        let lints = lint_module(
+            &ctx,
+            &declarations_by_id[&home],
+            &rules(&LintSettings::default()),
+        );
+
+        let Some(Lint::TryChain {
+            rewrite: Some(rewrite),
+            ..
+        }) = lints.into_iter().next()
+        else {
+            panic!("expected a try chain with a rewrite");
+        };
+        let start = rewrite.region.start().offset as usize;
+        let end = rewrite.region.end().offset as usize;
+        let fixed = format!(
+            "{}{}{}",
+            &module_src[..start],
+            rewrite.replacement,
+            &module_src[end..]
+        );
+
+        // The defs can't follow `total =` on its line, so they go in a block after it.
+        assert!(
+            fixed.contains(
+                "    total =\n        x = parse? \"1\"\n        y = parse? \"2\"\n        parse (Num.toStr (x + y))\n"
+            ),
+            "{fixed}"
+        );
+    }
+
+    test_lint!(
+        lint_catch_all_on_tag_union,
+        indoc!(
+            r#"
+            Color : [Red, Green, Blue]
+
+            f : Color -> Str
+            f = \color ->
+                when color is
+                    Red -> "red"
+                    _ -> "other"
+
+            f Red
+            "#
+        ),
+        @r#"
+    ── CATCH-ALL ON TAG UNION [R0505] in /code/proj/Main.roc ───────────────────────
+
+    This `_` matches `Blue`, `Green`:
+
+    10│              _ -> "other"
+                     ^
+
+    If a tag is added to this union later, it will end up in this branch
+    too, without `roc check` pointing out that this `when` might need to
+    handle it. Consider matching each tag explicitly instead.
+    "#
+    );
+
+    test_lint!(
+        lint_nothing_to_report,
+        indoc!(
+            r"
+            when Ok 1 is
+                Ok x -> x
+                _ -> 0
+            "
+        ),
+        @""
+    );
 }
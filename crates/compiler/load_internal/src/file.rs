@@ -52,13 +52,17 @@ use roc_parse::header::{
     self, ExposedName, HeaderType, ImportsKeywordItem, PackageEntry, PackageHeader, PlatformHeader,
     To, TypedIdent,
 };
-use roc_parse::module::parse_module_defs;
+use roc_parse::module::parse_module_defs_recovering;
 use roc_parse::parser::{FileError, SourceError, SyntaxError};
 use roc_problem::Severity;
 use roc_region::all::{LineInfo, Loc, Region};
+use roc_reporting::diagnostic::Diagnostic;
+use roc_reporting::error_code;
 #[cfg(not(target_family = "wasm"))]
 use roc_reporting::report::to_https_problem_report_string;
-use roc_reporting::report::{to_file_problem_report_string, Palette, RenderTarget};
+use roc_reporting::report::{
+    to_file_problem_diagnostic, to_file_problem_report_string, Palette, RenderTarget,
+};
 use roc_solve::module::{extract_module_owned_implementations, SolveConfig, Solved, SolvedModule};
 use roc_solve::FunctionKind;
 use roc_solve_problem::TypeError;
@@ -964,8 +968,8 @@ pub enum LoadingProblem<'a> {
     ErrJoiningWorkerThreads,
     TriedToImportAppModule,
 
-    /// a formatted report
-    FormattedReport(String),
+    /// a formatted report, along with its machine-readable form (unless it was only ever text)
+    FormattedReport(String, Option<Box<Diagnostic>>),
 
     ImportCycle(PathBuf, Vec<ModuleId>),
     IncorrectModuleName(FileError<'a, IncorrectModuleName<'a>>),
@@ -1140,11 +1144,12 @@ impl<'a> LoadStart<'a> {
                         .into_inner()
                         .into_module_ids();
 
-                    let report = report_loading_problem(problem, module_ids, render, palette);
+                    let (report, diagnostic) =
+                        report_loading_problem(problem, module_ids, render, palette);
 
                     // TODO try to gracefully recover and continue
                     // instead of changing the control flow to exit.
-                    return Err(LoadingProblem::FormattedReport(report));
+                    return Err(LoadingProblem::FormattedReport(report, diagnostic));
                 }
             }
         };
@@ -1526,27 +1531,34 @@ fn state_thread_step<'a>(
                     Ok(ControlFlow::Break(LoadResult::Monomorphized(monomorphized)))
                 }
                 Msg::FailedToReadFile { filename, error } => {
+                    let diagnostic = to_file_problem_diagnostic(filename.clone(), error);
                     let buf = to_file_problem_report_string(filename, error);
-                    Err(LoadingProblem::FormattedReport(buf))
+                    Err(LoadingProblem::FormattedReport(
+                        buf,
+                        Some(Box::new(diagnostic)),
+                    ))
                 }
 
                 Msg::FailedToParse(problem) => {
                     let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
-                    let buf = to_parse_problem_report(
+                    let (buf, diagnostic) = to_parse_problem_report(
                         problem,
                         module_ids,
                         state.constrained_ident_ids,
                         state.render,
                         state.palette,
                     );
-                    Err(LoadingProblem::FormattedReport(buf))
+                    Err(LoadingProblem::FormattedReport(
+                        buf,
+                        Some(Box::new(diagnostic)),
+                    ))
                 }
                 Msg::IncorrectModuleName(FileError {
                     problem: SourceError { problem, bytes },
                     filename,
                 }) => {
                     let module_ids = (*state.arc_modules).lock().clone().into_module_ids();
-                    let buf = to_incorrect_module_name_report(
+                    let (buf, diagnostic) = to_incorrect_module_name_report(
                         module_ids,
                         state.constrained_ident_ids,
                         problem,
@@ -1554,7 +1566,10 @@ fn state_thread_step<'a>(
                         bytes,
                         state.render,
                     );
-                    Err(LoadingProblem::FormattedReport(buf))
+                    Err(LoadingProblem::FormattedReport(
+                        buf,
+                        Some(Box::new(diagnostic)),
+                    ))
                 }
                 msg => {
                     // This is where most of the main thread's work gets done.
@@ -1589,27 +1604,33 @@ fn state_thread_step<'a>(
 
                             // if parsing failed, this module did not add anything to IdentIds
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_parse_problem_report(
+                            let (buf, diagnostic) = to_parse_problem_report(
                                 problem,
                                 module_ids,
                                 root_exposed_ident_ids,
                                 render,
                                 palette,
                             );
-                            Err(LoadingProblem::FormattedReport(buf))
+                            Err(LoadingProblem::FormattedReport(
+                                buf,
+                                Some(Box::new(diagnostic)),
+                            ))
                         }
                         Err(LoadingProblem::ImportCycle(filename, cycle)) => {
                             let module_ids = arc_modules.lock().clone().into_module_ids();
 
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_import_cycle_report(
+                            let (buf, diagnostic) = to_import_cycle_report(
                                 module_ids,
                                 root_exposed_ident_ids,
                                 cycle,
                                 filename,
                                 render,
                             );
-                            return Err(LoadingProblem::FormattedReport(buf));
+                            return Err(LoadingProblem::FormattedReport(
+                                buf,
+                                Some(Box::new(diagnostic)),
+                            ));
                         }
                         Err(LoadingProblem::IncorrectModuleName(FileError {
                             problem: SourceError { problem, bytes },
@@ -1618,7 +1639,7 @@ fn state_thread_step<'a>(
                             let module_ids = arc_modules.lock().clone().into_module_ids();
 
                             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
-                            let buf = to_incorrect_module_name_report(
+                            let (buf, diagnostic) = to_incorrect_module_name_report(
                                 module_ids,
                                 root_exposed_ident_ids,
                                 problem,
@@ -1626,7 +1647,10 @@ fn state_thread_step<'a>(
                                 bytes,
                                 render,
                             );
-                            return Err(LoadingProblem::FormattedReport(buf));
+                            return Err(LoadingProblem::FormattedReport(
+                                buf,
+                                Some(Box::new(diagnostic)),
+                            ));
                         }
                         Err(e) => Err(e),
                     }
@@ -1647,8 +1671,8 @@ pub fn report_loading_problem(
     module_ids: ModuleIds,
     render: RenderTarget,
     palette: Palette,
-) -> String {
-    match problem {
+) -> (String, Option<Box<Diagnostic>>) {
+    let (report, diagnostic) = match problem {
         LoadingProblem::ParsingFailed(problem) => {
             // if parsing failed, this module did not add anything to IdentIds
             let root_exposed_ident_ids = IdentIds::exposed_builtins(0);
@@ -1675,10 +1699,11 @@ pub fn report_loading_problem(
                 render,
             )
         }
-        LoadingProblem::FormattedReport(report) => report,
-        LoadingProblem::FileProblem { filename, error } => {
-            to_file_problem_report_string(filename, error)
-        }
+        LoadingProblem::FormattedReport(report, diagnostic) => return (report, diagnostic),
+        LoadingProblem::FileProblem { filename, error } => (
+            to_file_problem_report_string(filename.clone(), error),
+            to_file_problem_diagnostic(filename, error),
+        ),
         LoadingProblem::NoPlatformPackage {
             filename,
             module_id,
@@ -1708,7 +1733,9 @@ pub fn report_loading_problem(
             render,
         ),
         err => todo!("Loading error: {:?}", err),
-    }
+    };
+
+    (report, Some(Box::new(diagnostic)))
 }
 
 fn load_multi_threaded<'a>(
@@ -1952,6 +1979,7 @@ fn load_multi_threaded<'a>(
                     "command can sometimes give a more helpful error report than other commands.\n\n"
                 )
                 .to_string(),
+                None,
             ))
         })
     }
@@ -2250,12 +2278,15 @@ fn update<'a>(
                                     }
                                 }
                                 Err(url_err) => {
-                                    let buf = to_https_problem_report_string(
+                                    let (buf, diagnostic) = to_https_problem_report_string(
                                         url,
                                         Problem::InvalidUrl(url_err),
                                         header.module_path,
                                     );
-                                    return Err(LoadingProblem::FormattedReport(buf));
+                                    return Err(LoadingProblem::FormattedReport(
+                                        buf,
+                                        Some(Box::new(diagnostic)),
+                                    ));
                                 }
                             }
                         }
@@ -3165,8 +3196,12 @@ fn finish_specialization<'a>(
                     }
                     Valid(To::NewPackage(p_or_p)) => PathBuf::from(p_or_p.as_str()),
                     other => {
-                        let buf = report_cannot_run(state.root_id, state.root_path, other);
-                        return Err(LoadingProblem::FormattedReport(buf));
+                        let (buf, diagnostic) =
+                            report_cannot_run(state.root_id, state.root_path, other);
+                        return Err(LoadingProblem::FormattedReport(
+                            buf,
+                            Some(Box::new(diagnostic)),
+                        ));
                     }
                 };
 
@@ -4087,9 +4122,13 @@ fn load_packages<'a>(
                         }
                     }
                     Err(problem) => {
-                        let buf = to_https_problem_report_string(src, problem, filename);
+                        let (buf, diagnostic) =
+                            to_https_problem_report_string(src, problem, filename);
 
-                        load_messages.push(Msg::FailedToLoad(LoadingProblem::FormattedReport(buf)));
+                        load_messages.push(Msg::FailedToLoad(LoadingProblem::FormattedReport(
+                            buf,
+                            Some(Box::new(diagnostic)),
+                        )));
                         return;
                     }
                 }
@@ -4959,6 +4998,7 @@ fn canonicalize_and_constrain<'a>(
         header_type,
         exposed_ident_ids,
         parsed_defs,
+        syntax_problems,
         initial_scope,
         available_modules,
         mut module_timing,
@@ -5119,7 +5159,10 @@ fn canonicalize_and_constrain<'a>(
 
     CanAndCon {
         constrained_module,
-        canonicalization_problems: module_output.problems,
+        canonicalization_problems: syntax_problems
+            .into_iter()
+            .chain(module_output.problems)
+            .collect(),
         module_docs,
     }
 }
@@ -5138,14 +5181,18 @@ fn parse<'a>(
     let header_import_defs =
         roc_parse::ast::Module::header_imports_to_defs(arena, header.header_imports);
 
-    let parsed_defs = match parse_module_defs(arena, parse_state.clone(), header_import_defs) {
-        Ok(success) => success,
-        Err(fail) => {
-            return Err(LoadingProblem::ParsingFailed(
-                fail.into_file_error(header.module_path, &parse_state),
-            ));
-        }
-    };
+    // Syntax errors in the body don't stop the module from loading: the defs around them
+    // are still canonicalized and type checked, and the errors are reported alongside
+    // any other problems in the module.
+    let (parsed_defs, recovered_errors) =
+        parse_module_defs_recovering(arena, parse_state, header_import_defs);
+
+    let syntax_problems = recovered_errors
+        .into_iter()
+        .map(|recovered| roc_problem::can::Problem::SyntaxError {
+            region: recovered.region,
+        })
+        .collect();
 
     // Record the parse end time once, to avoid checking the time a second time
     // immediately afterward (for the beginning of canonicalization).
@@ -5386,6 +5433,7 @@ fn parse<'a>(
         initial_scope: scope,
         exposes: exposed,
         parsed_defs,
+        syntax_problems,
         symbols_from_requires,
         header_type,
         header_comments: header_docs,
@@ -5427,6 +5475,7 @@ fn make_specializations<'a>(
         exposed_by_module,
         derived_module: &derived_module,
         struct_indexing: UsageTrackingMap::default(),
+        region: Region::zero(),
     };
 
     let mut procs = Procs::new_in(arena);
@@ -5530,6 +5579,7 @@ fn build_pending_specializations<'a>(
         exposed_by_module,
         derived_module: &derived_module,
         struct_indexing: UsageTrackingMap::default(),
+        region: Region::zero(),
     };
 
     let layout_cache_snapshot = layout_cache.snapshot();
@@ -5553,6 +5603,8 @@ fn build_pending_specializations<'a>(
 
         let body = declarations.expressions[index].clone();
 
+        let def_region = Region::span_across(&declarations.symbols[index].region, &body.region);
+
         let tag = declarations.declarations[index];
         match tag {
             Value => {
@@ -5605,6 +5657,7 @@ fn build_pending_specializations<'a>(
                             closure_data.return_type,
                             *closure_data.loc_body,
                             false,
+                            def_region,
                         );
                     }
                     _ => {
@@ -5621,6 +5674,7 @@ fn build_pending_specializations<'a>(
                             body_var: expr_var,
                             // This is a 0-arity thunk, so it cannot be recursive
                             is_self_recursive: false,
+                            region: def_region,
                         };
 
                         procs_base.partial_procs.insert(symbol, proc);
@@ -5683,6 +5737,7 @@ fn build_pending_specializations<'a>(
                     function_def.return_type,
                     body,
                     is_recursive,
+                    def_region,
                 );
             }
             Destructure(d_index) => {
@@ -5757,6 +5812,7 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: def_region,
                 };
 
                 procs_base.partial_procs.insert(symbol, proc);
@@ -5825,6 +5881,7 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: def_region,
                 };
 
                 // extend the region of the expect expression with the region of the preceding
@@ -5897,6 +5954,7 @@ fn build_pending_specializations<'a>(
                     body_var: expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: def_region,
                 };
 
                 // extend the region of the expect expression with the region of the preceding
@@ -5944,6 +6002,7 @@ fn register_toplevel_function_into_procs_base<'a>(
     return_type: Variable,
     body: Loc<roc_can::expr::Expr>,
     is_recursive: bool,
+    def_region: Region,
 ) {
     let partial_proc = PartialProc::from_named_function(
         mono_env,
@@ -5955,6 +6014,12 @@ fn register_toplevel_function_into_procs_base<'a>(
         return_type,
     );
 
+    // point at the whole definition, not just the lambda
+    let partial_proc = PartialProc {
+        region: def_region,
+        ..partial_proc
+    };
+
     procs_base.partial_procs.insert(symbol, partial_proc);
 }
 
@@ -6011,6 +6076,7 @@ fn load_derived_partial_procs<'a>(
             exposed_by_module,
             derived_module,
             struct_indexing: UsageTrackingMap::default(),
+            region: Region::zero(),
         };
 
         let partial_proc = match derived_expr {
@@ -6048,6 +6114,7 @@ fn load_derived_partial_procs<'a>(
                     body_var: derived_expr_var,
                     // This is a 0-arity thunk, so it cannot be recursive
                     is_self_recursive: false,
+                    region: Region::zero(),
                 }
             }
         };
@@ -6233,7 +6300,7 @@ fn to_import_cycle_report(
     import_cycle: Vec<ModuleId>,
     filename: PathBuf,
     render: RenderTarget,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6277,13 +6344,16 @@ fn to_import_cycle_report(
         filename,
         doc,
         title: "IMPORT CYCLE".to_string(),
+        error_code: Some(error_code::R0006),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     };
 
+    let diagnostic = Diagnostic::from_report(&report, &LineInfo::new(""), None, &[]);
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, diagnostic)
 }
 
 fn to_incorrect_module_name_report<'a>(
@@ -6293,7 +6363,7 @@ fn to_incorrect_module_name_report<'a>(
     filename: PathBuf,
     src: &'a [u8],
     render: RenderTarget,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6326,13 +6396,16 @@ fn to_incorrect_module_name_report<'a>(
         filename,
         doc,
         title: "INCORRECT MODULE NAME".to_string(),
+        error_code: Some(error_code::R0007),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     };
 
+    let diagnostic = Diagnostic::from_report(&report, &lines, Some(found.region), &[]);
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, diagnostic)
 }
 
 fn to_no_platform_package_report(
@@ -6343,7 +6416,7 @@ fn to_no_platform_package_report(
     region: Region,
     src: &[u8],
     render: RenderTarget,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6373,13 +6446,16 @@ fn to_no_platform_package_report(
         filename,
         doc,
         title: "UNSPECIFIED PLATFORM".to_string(),
+        error_code: Some(error_code::R0008),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     };
 
+    let diagnostic = Diagnostic::from_report(&report, &lines, Some(region), &[]);
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, diagnostic)
 }
 
 fn to_multiple_platform_packages_report(
@@ -6390,7 +6466,7 @@ fn to_multiple_platform_packages_report(
     region: Region,
     src: &[u8],
     render: RenderTarget,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -6416,13 +6492,16 @@ fn to_multiple_platform_packages_report(
         filename,
         doc,
         title: "MULTIPLE PLATFORMS".to_string(),
+        error_code: Some(error_code::R0009),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     };
 
+    let diagnostic = Diagnostic::from_report(&report, &lines, Some(region), &[]);
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     report.render(render, &mut buf, &alloc, &palette);
-    buf
+    (buf, diagnostic)
 }
 
 fn to_parse_problem_report<'a>(
@@ -6431,7 +6510,7 @@ fn to_parse_problem_report<'a>(
     all_ident_ids: IdentIdsByModule,
     render: RenderTarget,
     palette: Palette,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{parse_problem, RocDocAllocator};
 
     // TODO this is not in fact safe
@@ -6453,6 +6532,7 @@ fn to_parse_problem_report<'a>(
     let starting_line = 0;
 
     let lines = LineInfo::new(src);
+    let region = problem.problem.problem.get_region();
 
     let report = parse_problem(
         &alloc,
@@ -6461,19 +6541,20 @@ fn to_parse_problem_report<'a>(
         starting_line,
         problem,
     );
+    let diagnostic = Diagnostic::from_report(&report, &lines, region, &[]);
 
     let mut buf = String::new();
 
     report.render(render, &mut buf, &alloc, &palette);
 
-    buf
+    (buf, diagnostic)
 }
 
 fn report_cannot_run(
     module_id: ModuleId,
     filename: PathBuf,
     platform_path: &PlatformPath,
-) -> String {
+) -> (String, Diagnostic) {
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
     use PlatformPath::*;
@@ -6499,7 +6580,9 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    error_code: Some(error_code::R0010),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             RootIsModule => {
@@ -6514,7 +6597,9 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    error_code: Some(error_code::R0010),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             RootIsHosted => {
@@ -6529,7 +6614,9 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    error_code: Some(error_code::R0010),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             RootIsPlatformModule => {
@@ -6544,15 +6631,18 @@ fn report_cannot_run(
                     filename,
                     doc,
                     title: "NO PLATFORM".to_string(),
+                    error_code: Some(error_code::R0010),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         }
     };
 
+    let diagnostic = Diagnostic::from_report(&report, &LineInfo::new(""), None, &[]);
     let palette = DEFAULT_PALETTE;
     let mut buf = String::new();
     report.render_color_terminal(&mut buf, &alloc, &palette);
 
-    buf
+    (buf, diagnostic)
 }
@@ -13,7 +13,7 @@ fn report_missing_package_shorthand2<'a>(
             {
                 Some(
                     LoadingProblem::FormattedReport(
-                        format!("The package shorthand '{shorthand}' that you are importing the module '{name}' from in '{shorthand}.{name}', doesn't exist in this module.\nImport it in the \"packages\" section of the header.")))
+                        format!("The package shorthand '{shorthand}' that you are importing the module '{name}' from in '{shorthand}.{name}', doesn't exist in this module.\nImport it in the \"packages\" section of the header."), None))
             } else {
                 None
             }
@@ -199,6 +199,9 @@ pub struct ParsedModule<'a> {
     pub deps_by_name: MutMap<PQModuleName<'a>, ModuleId>,
     pub exposed_ident_ids: IdentIds,
     pub parsed_defs: Defs<'a>,
+    /// Syntax errors in `parsed_defs`, which are reported along with the problems found
+    /// while canonicalizing them.
+    pub syntax_problems: Vec<roc_problem::can::Problem>,
     pub symbols_from_requires: Vec<(Loc<Symbol>, Loc<TypeAnnotation<'a>>)>,
     pub header_type: HeaderType<'a>,
     pub header_comments: &'a [CommentOrNewline<'a>],
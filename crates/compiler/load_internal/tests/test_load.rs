@@ -112,7 +112,7 @@ fn multiple_modules(subdir: &str, files: Vec<(&str, &str)>) -> Result<LoadedModu
 
     match multiple_modules_help(subdir, arena, files) {
         Err(io_error) => panic!("IO trouble: {io_error:?}"),
-        Ok(Err(LoadingProblem::FormattedReport(buf))) => Err(buf),
+        Ok(Err(LoadingProblem::FormattedReport(buf, _))) => Err(buf),
         Ok(Err(loading_problem)) => Err(format!("{loading_problem:?}")),
         Ok(Ok(mut loaded_module)) => {
             let home = loaded_module.module_id;
@@ -210,7 +210,7 @@ fn load_fixture(
     );
     let mut loaded_module = match loaded {
         Ok(x) => x,
-        Err(roc_load_internal::file::LoadingProblem::FormattedReport(report)) => {
+        Err(roc_load_internal::file::LoadingProblem::FormattedReport(report, _)) => {
             println!("{report}");
             panic!("{}", report);
         }
@@ -704,7 +704,7 @@ fn parse_problem() {
             report,
             indoc!(
                 "
-                    ── UNFINISHED LIST in tmp/parse_problem/Main.roc ───────────────────────────────
+                    ── UNFINISHED LIST [R0129] in tmp/parse_problem/Main.roc ───────────────────────
 
                     I am partway through started parsing a list, but I got stuck here:
 
@@ -718,13 +718,48 @@ fn parse_problem() {
 
                     Note: When I get stuck like this, it usually means that there is a
                     missing parenthesis or bracket somewhere earlier. It could also be a
-                    stray keyword or operator."
+                    stray keyword or operator.
+                "
             )
         ),
         Ok(_) => unreachable!("we expect failure here"),
     }
 }
 
+#[test]
+fn header_parse_problem_region() {
+    let modules = vec![(
+        "Main.roc",
+        indoc!(
+            r"
+                module [main, 1]
+
+                main = 1
+                "
+        ),
+    )];
+
+    let arena = Bump::new();
+
+    match multiple_modules_help("header_parse_problem_region", &arena, modules) {
+        Ok(Err(LoadingProblem::FormattedReport(_, diagnostic))) => {
+            let diagnostic = *diagnostic.expect("a parse problem should have a diagnostic");
+            let span = diagnostic
+                .region
+                .expect("a header parse problem should know where it is");
+
+            assert_eq!(diagnostic.title, "WEIRD EXPOSES");
+            assert_eq!(diagnostic.code.as_deref(), Some("R0171"));
+            assert_eq!(
+                (span.start_line, span.start_column),
+                (1, 15),
+                "span={span:?}"
+            );
+        }
+        other => panic!("expected a formatted report, got {other:?}"),
+    }
+}
+
 #[test]
 #[should_panic(expected = "FILE NOT FOUND")]
 fn file_not_found() {
@@ -830,12 +865,45 @@ fn platform_parse_error() {
         ),
     ];
 
-    match multiple_modules("platform_parse_error", modules) {
-        Err(report) => {
-            assert!(report.contains("NOT END OF FILE"));
-            assert!(report.contains("blah 1 2 3 # causing a parse error on purpose"));
+    let arena = Bump::new();
+
+    // The platform's syntax error doesn't stop it from loading, so it's reported along with
+    // the platform's other problems.
+    match multiple_modules_help("platform_parse_error", &arena, modules) {
+        Ok(Ok(loaded_module)) => {
+            let (platform_id, syntax_errors) = loaded_module
+                .can_problems
+                .iter()
+                .find_map(|(module_id, problems)| {
+                    let syntax_errors: Vec<_> = problems
+                        .iter()
+                        .filter(|problem| matches!(problem, Problem::SyntaxError { .. }))
+                        .cloned()
+                        .collect();
+
+                    (!syntax_errors.is_empty()).then_some((*module_id, syntax_errors))
+                })
+                .expect("expected a syntax error in the platform");
+
+            assert_eq!(syntax_errors.len(), 1, "{syntax_errors:?}");
+
+            let (filename, src) = loaded_module.sources.get(&platform_id).unwrap();
+            let report = format_can_problems(
+                syntax_errors,
+                platform_id,
+                &loaded_module.interns,
+                filename.clone(),
+                src,
+            );
+
+            assert!(report.contains("NOT END OF FILE"), "report=({report})");
+            assert!(
+                report.contains("blah 1 2 3 # causing a parse error on purpose"),
+                "report=({report})"
+            );
+            assert!(report.contains('^'), "report=({report})");
         }
-        Ok(_) => unreachable!("we expect failure here"),
+        other => panic!("expected the modules to load, got {other:?}"),
     }
 }
 
@@ -912,7 +980,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
         err,
         indoc!(
             r"
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...d_outside_defining_module/Main.roc ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [R0226] in ...e_defining_module/Main.roc ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -926,7 +994,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE in ...d_outside_defining_module/Main.roc ─
+                ── OPAQUE TYPE DECLARED OUTSIDE SCOPE [R0226] in ...e_defining_module/Main.roc ─
 
                 The unwrapped opaque type Age referenced here:
 
@@ -940,7 +1008,7 @@ fn opaque_wrapped_unwrapped_outside_defining_module() {
 
                 Note: Opaque types can only be wrapped and unwrapped in the module they are defined in!
 
-                ── UNUSED IMPORT in ...aque_wrapped_unwrapped_outside_defining_module/Main.roc ─
+                ── UNUSED IMPORT [R0203] in ...pped_unwrapped_outside_defining_module/Main.roc ─
 
                 Age is imported but not used.
 
@@ -1027,7 +1095,7 @@ fn unused_imports() {
         err,
         indoc!(
             r"
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1036,7 +1104,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1045,7 +1113,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep2 is imported but not used.
 
@@ -1054,7 +1122,7 @@ fn unused_imports() {
 
             Since Dep2 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/unused_imports/Main.roc ────────────────────────
 
             Dep1 is imported but not used.
 
@@ -1063,7 +1131,7 @@ fn unused_imports() {
 
             Since Dep1 isn't used, you don't need to import it.
 
-            ── UNUSED IMPORT in tmp/unused_imports/Main.roc ────────────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/unused_imports/Main.roc ────────────────────────
 
             `Dep3.Three` is not used in this module.
 
@@ -1129,7 +1197,7 @@ fn explicit_builtin_import() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/explicit_builtin_import/Main.roc ─────────────
+            ── EXPLICIT BUILTIN IMPORT [R0205] in tmp/explicit_builtin_import/Main.roc ─────
             
             The builtin Bool was imported here:
             
@@ -1164,7 +1232,7 @@ fn explicit_builtin_import_empty_exposing() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/empty_exposing_builtin_import/Main.roc ───────
+            ── EXPLICIT BUILTIN IMPORT [R0205] in ...mpty_exposing_builtin_import/Main.roc ─
             
             The builtin Bool was imported here:
             
@@ -1203,7 +1271,7 @@ fn explicit_builtin_type_import() {
         err,
         indoc!(
             r"
-            ── EXPLICIT BUILTIN IMPORT in tmp/explicit_builtin_type_import/Main.roc ────────
+            ── EXPLICIT BUILTIN IMPORT [R0205] in ...explicit_builtin_type_import/Main.roc ─
             
             `Dict.Dict` was imported here:
             
@@ -1253,7 +1321,7 @@ fn import_shadows_symbol() {
         err,
         indoc!(
             r"
-            ── DUPLICATE NAME in tmp/import_shadows_symbol/Main.roc ────────────────────────
+            ── DUPLICATE NAME [R0217] in tmp/import_shadows_symbol/Main.roc ────────────────
 
             This import exposes `One.one`:
 
@@ -1267,7 +1335,7 @@ fn import_shadows_symbol() {
 
             You can rename it, or use the qualified name: `One.one`
 
-            ── UNUSED IMPORT in tmp/import_shadows_symbol/Main.roc ─────────────────────────
+            ── UNUSED IMPORT [R0203] in tmp/import_shadows_symbol/Main.roc ─────────────────
 
             One is imported but not used.
 
@@ -1301,7 +1369,7 @@ fn ingested_file_import_shadows_symbol() {
         err,
         indoc!(
             r#"
-            ── DUPLICATE NAME in tmp/ingested_import_shadows_symbol/Main.roc ───────────────
+            ── DUPLICATE NAME [R0217] in tmp/ingested_import_shadows_symbol/Main.roc ───────
 
             The `name` name is first defined here:
 
@@ -1394,7 +1462,7 @@ fn duplicate_alias() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/duplicate_alias/Main.roc ────────────────────────
+            ── IMPORT NAME CONFLICT [R0204] in tmp/duplicate_alias/Main.roc ────────────────
 
             Two was imported as D:
 
@@ -1459,7 +1527,7 @@ fn alias_using_module_name() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/alias_using_module_name/Main.roc ────────────────
+            ── IMPORT NAME CONFLICT [R0204] in tmp/alias_using_module_name/Main.roc ────────
 
             Two was imported as One:
 
@@ -1514,7 +1582,7 @@ fn alias_using_builtin_name() {
         err,
         indoc!(
             r"
-            ── IMPORT NAME CONFLICT in tmp/alias_using_builtin_name/Main.roc ───────────────
+            ── IMPORT NAME CONFLICT [R0204] in tmp/alias_using_builtin_name/Main.roc ───────
 
             BoolExtra was imported as Bool:
 
@@ -1602,7 +1670,7 @@ fn issue_2863_module_type_does_not_exist() {
                 report,
                 indoc!(
                     "
-                        ── UNRECOGNIZED NAME in tmp/issue_2863_module_type_does_not_exist/main.roc ─────
+                        ── UNRECOGNIZED NAME [R0201] in ...ue_2863_module_type_does_not_exist/main.roc ─
 
                         Nothing is named `DoesNotExist` in this scope.
 
@@ -1680,7 +1748,7 @@ fn module_cyclic_import_itself() {
         err,
         indoc!(
             r"
-            ── IMPORT CYCLE in tmp/module_cyclic_import_itself/Age.roc ─────────────────────
+            ── IMPORT CYCLE [R0006] in tmp/module_cyclic_import_itself/Age.roc ─────────────
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -1729,7 +1797,7 @@ fn module_cyclic_import_transitive() {
         err,
         indoc!(
             r"
-            ── IMPORT CYCLE in tmp/module_cyclic_import_transitive/Age.roc ─────────────────
+            ── IMPORT CYCLE [R0006] in tmp/module_cyclic_import_transitive/Age.roc ─────────
 
             I can't compile Age because it depends on itself through the following
             chain of module imports:
@@ -1764,7 +1832,7 @@ fn non_roc_file_extension() {
 
     let expected = indoc!(
         r"
-        ── NOT A ROC FILE in tmp/non_roc_file_extension/main.md ────────────────────────
+        ── NOT A ROC FILE [R0003] in tmp/non_roc_file_extension/main.md ────────────────
 
         I expected a file with extension `.roc` or without extension.
         Instead I received a file with extension `.md`."
@@ -1794,7 +1862,7 @@ fn roc_file_no_extension() {
 
     let expected = indoc!(
         r"
-        ── NOT A ROC FILE in tmp/roc_file_no_extension/main ────────────────────────────
+        ── NOT A ROC FILE [R0003] in tmp/roc_file_no_extension/main ────────────────────
 
         I expected a file with either:
         - extension `.roc`
@@ -375,6 +375,12 @@ impl ModuleId {
         (self.0.get() - 1) as usize
     }
 
+    /// The raw representation of this id, e.g. to write it into the memory that an `expect` shares
+    /// with the process reporting it.
+    pub const fn to_raw_u32(self) -> u32 {
+        self.0.get()
+    }
+
     #[cfg(any(debug_assertions, feature = "debug-symbols"))]
     pub fn register_debug_idents(self, ident_ids: &IdentIds) {
         let mut all = DEBUG_IDENT_IDS_BY_MODULE_ID.lock().expect("Failed to acquire lock for Debug interning into DEBUG_MODULE_ID_NAMES, presumably because a thread panicked.");
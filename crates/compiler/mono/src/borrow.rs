@@ -62,7 +62,7 @@ impl<'a> State<'a> {
 
     fn inspect_stmt(&mut self, stmt: &'a Stmt<'a>) {
         match stmt {
-            Stmt::Let(_, expr, _, stmt) => {
+            Stmt::Let(_, expr, _, stmt, _) => {
                 self.inspect_expr(expr);
                 self.inspect_stmt(stmt);
             }
@@ -76,7 +76,7 @@ impl<'a> State<'a> {
                 }
                 self.inspect_stmt(default_branch.1);
             }
-            Stmt::Ret(_) => todo!(),
+            Stmt::Ret(_, _) => todo!(),
             Stmt::Refcounting(_, _) => todo!(),
             Stmt::Expect { .. } | Stmt::ExpectFx { .. } => {
                 // TODO do we rely on values being passed by-value here?
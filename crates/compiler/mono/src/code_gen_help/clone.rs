@@ -0,0 +1,842 @@
+use bumpalo::collections::vec::Vec;
+use roc_builtins::bitcode;
+use roc_module::ident::ForeignSymbol;
+use roc_module::low_level::LowLevel;
+use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
+
+use crate::ir::{BranchInfo, Call, CallType, Expr, JoinPointId, Literal, Param, Stmt};
+use crate::layout::{
+    Discriminant, InLayout, Layout, LayoutInterner, LayoutRepr, STLayoutInterner, TagIdIntType,
+    UnionLayout,
+};
+
+use super::{let_lowlevel, CodeGenHelp, Context, LAYOUT_BOOL};
+
+const ARG_1: Symbol = Symbol::ARG_1;
+const ARG_2: Symbol = Symbol::ARG_2;
+const ARG_3: Symbol = Symbol::ARG_3;
+const ARG_4: Symbol = Symbol::ARG_4;
+
+/// Clone a value into the shared memory of a failed `expect`, in the format that the LLVM backend
+/// writes and `roc_repl_expect` reads.
+///
+/// The helper proc takes the value, the address of the shared memory, the offset to write the value at,
+/// and the offset of the first free byte (the "extra" offset), where anything the value points to is written.
+/// Pointers are written as offsets into the shared memory. The new extra offset is returned.
+pub fn clone_generic<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+) -> Stmt<'a> {
+    use crate::layout::Builtin::*;
+    use LayoutRepr::*;
+
+    match layout_interner.get_repr(layout) {
+        // We will never display functions, so we write nothing for them
+        LambdaSet(_) => Stmt::Ret(ARG_4, Region::zero()),
+        _ if layout_interner.safe_to_memcpy(layout) => write_at(
+            root,
+            ident_ids,
+            layout_interner,
+            layout,
+            ARG_1,
+            ARG_3,
+            Stmt::Ret(ARG_4, Region::zero()),
+        ),
+        Builtin(Str) => clone_str(root, ident_ids),
+        Builtin(List(elem_layout)) => {
+            clone_list(root, ident_ids, ctx, layout_interner, elem_layout)
+        }
+        Struct(field_layouts) => clone_struct(root, ident_ids, ctx, layout_interner, field_layouts),
+        Union(union_layout) => clone_tag_union(root, ident_ids, ctx, layout_interner, union_layout),
+        Builtin(Int(_) | Float(_) | Bool | Decimal) => {
+            unreachable!("{:?} is safe to memcpy", layout)
+        }
+        Ptr(_) | FunctionPointer(_) | Erased(_) => {
+            unreachable!("{:?} can not be looked up in an `expect`", layout)
+        }
+        RecursivePointer(_) => {
+            unreachable!(
+                "Can't clone a RecursivePointer. Should have been replaced by a tag union."
+            )
+        }
+    }
+}
+
+/// Write `value` at `offset` into the shared memory, then continue with `following`.
+fn write_at<'a>(
+    root: &CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    layout_interner: &mut STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+    value: Symbol,
+    offset: Symbol,
+    following: Stmt<'a>,
+) -> Stmt<'a> {
+    let arena = root.arena;
+    let layout_isize = root.layout_isize;
+    let ptr_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Ptr(layout));
+
+    let addr = root.create_symbol(ident_ids, "addr");
+    let ptr = root.create_symbol(ident_ids, "ptr");
+    let ignored = root.create_symbol(ident_ids, "ignored");
+
+    use LowLevel::*;
+    let_lowlevel(
+        arena,
+        layout_isize,
+        addr,
+        NumAdd,
+        &[ARG_2, offset],
+        arena.alloc(
+            //
+            let_lowlevel(
+                arena,
+                ptr_layout,
+                ptr,
+                PtrCast,
+                &[addr],
+                arena.alloc(
+                    //
+                    let_lowlevel(
+                        arena,
+                        ptr_layout,
+                        ignored,
+                        PtrStore,
+                        &[ptr, value],
+                        arena.alloc(following),
+                    ),
+                ),
+            ),
+        ),
+    )
+}
+
+/// Bind `symbol` to `base + constant`, then continue with `following`.
+fn let_add_constant<'a>(
+    root: &CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    symbol: Symbol,
+    base: Symbol,
+    constant: u64,
+    following: Stmt<'a>,
+) -> Stmt<'a> {
+    let arena = root.arena;
+    let layout_isize = root.layout_isize;
+    let constant_sym = root.create_symbol(ident_ids, "constant");
+
+    Stmt::Let(
+        constant_sym,
+        Expr::Literal(Literal::Int((constant as i128).to_ne_bytes())),
+        layout_isize,
+        arena.alloc(
+            //
+            let_lowlevel(
+                arena,
+                layout_isize,
+                symbol,
+                LowLevel::NumAdd,
+                &[base, constant_sym],
+                arena.alloc(following),
+            ),
+        ),
+        Region::zero(),
+    )
+}
+
+/// Clone `value` at `offset`, and bind the new extra offset to `new_extra`.
+#[allow(clippy::too_many_arguments)]
+fn let_clone<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    layout: InLayout<'a>,
+    [value, offset, extra]: [Symbol; 3],
+    new_extra: Symbol,
+    following: Stmt<'a>,
+) -> Stmt<'a> {
+    let clone_expr = root
+        .call_specialized_op(
+            ident_ids,
+            ctx,
+            layout_interner,
+            layout,
+            root.arena.alloc([value, ARG_2, offset, extra]),
+        )
+        .unwrap();
+
+    Stmt::Let(
+        new_extra,
+        clone_expr,
+        root.layout_isize,
+        root.arena.alloc(following),
+        Region::zero(),
+    )
+}
+
+fn clone_str<'a>(root: &CodeGenHelp<'a>, ident_ids: &mut IdentIds) -> Stmt<'a> {
+    let new_extra = root.create_symbol(ident_ids, "new_extra");
+
+    let clone_expr = Expr::Call(Call {
+        call_type: CallType::Foreign {
+            foreign_symbol: ForeignSymbol::from(bitcode::STR_CLONE_TO.to_string()),
+            ret_layout: root.layout_isize,
+        },
+        arguments: root.arena.alloc([ARG_1, ARG_2, ARG_3, ARG_4]),
+    });
+
+    Stmt::Let(
+        new_extra,
+        clone_expr,
+        root.layout_isize,
+        root.arena.alloc(Stmt::Ret(new_extra, Region::zero())),
+        Region::zero(),
+    )
+}
+
+/// Clone the fields of a struct (or of a tag's payload) one after another, starting at `offset`.
+/// `load_field` gives the expression that loads the field with the given index.
+#[allow(clippy::too_many_arguments)]
+fn clone_fields<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    field_layouts: &'a [InLayout<'a>],
+    load_field: impl Fn(u64) -> Expr<'a>,
+    offset: Symbol,
+    extra: Symbol,
+    following: impl FnOnce(
+        &mut CodeGenHelp<'a>,
+        &mut IdentIds,
+        &mut STLayoutInterner<'a>,
+        Symbol,
+    ) -> Stmt<'a>,
+) -> Stmt<'a> {
+    // Create all the symbols first, so we can build the statements from the inside out
+    let mut field_offset = 0;
+    let mut fields = Vec::with_capacity_in(field_layouts.len(), root.arena);
+    let mut extras = Vec::with_capacity_in(field_layouts.len() + 1, root.arena);
+    extras.push(extra);
+    for (i, field_layout) in field_layouts.iter().enumerate() {
+        let field = root.create_symbol(ident_ids, &format!("field_{i}"));
+        let at = root.create_symbol(ident_ids, &format!("field_offset_{i}"));
+        let new_extra = root.create_symbol(ident_ids, &format!("extra_{i}"));
+        fields.push((field, at, field_offset, *field_layout));
+        extras.push(new_extra);
+        field_offset += layout_interner.stack_size(*field_layout) as u64;
+    }
+
+    let mut stmt = following(root, ident_ids, layout_interner, *extras.last().unwrap());
+    for (i, (field, at, field_offset, field_layout)) in fields.into_iter().enumerate().rev() {
+        stmt = let_clone(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            field_layout,
+            [field, at, extras[i]],
+            extras[i + 1],
+            stmt,
+        );
+        stmt = let_add_constant(root, ident_ids, at, offset, field_offset, stmt);
+        stmt = Stmt::Let(
+            field,
+            load_field(i as u64),
+            field_layout,
+            root.arena.alloc(stmt),
+            Region::zero(),
+        );
+    }
+
+    stmt
+}
+
+fn clone_struct<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    field_layouts: &'a [InLayout<'a>],
+) -> Stmt<'a> {
+    clone_fields(
+        root,
+        ident_ids,
+        ctx,
+        layout_interner,
+        field_layouts,
+        |index| Expr::StructAtIndex {
+            index,
+            field_layouts,
+            structure: ARG_1,
+        },
+        ARG_3,
+        ARG_4,
+        |_, _, _, new_extra| Stmt::Ret(new_extra, Region::zero()),
+    )
+}
+
+/// Only the elements we actually have are cloned, so the capacity of the clone is its length.
+/// The elements are written at the extra offset, followed by anything they point to.
+fn clone_list<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    elem_layout: InLayout<'a>,
+) -> Stmt<'a> {
+    use LowLevel::*;
+    let layout_isize = root.layout_isize;
+    let arena = root.arena;
+    let ptr_width = root.target.ptr_width() as u64;
+
+    // A pointer layout (heap pointer to a single list element)
+    let ptr_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Ptr(elem_layout));
+
+    let len = root.create_symbol(ident_ids, "len");
+    let len_offset = root.create_symbol(ident_ids, "len_offset");
+    let capacity_offset = root.create_symbol(ident_ids, "capacity_offset");
+
+    let elements = root.create_symbol(ident_ids, "elements");
+    let start = root.create_symbol(ident_ids, "start");
+    let size = root.create_symbol(ident_ids, "size");
+    let elements_width = root.create_symbol(ident_ids, "elements_width");
+    let rest_start = root.create_symbol(ident_ids, "rest_start");
+
+    //
+    // Loop name & parameters
+    //
+
+    let elems_loop = JoinPointId(root.create_symbol(ident_ids, "elems_loop"));
+    let addr = root.create_symbol(ident_ids, "addr");
+    let offset = root.create_symbol(ident_ids, "offset");
+    let rest = root.create_symbol(ident_ids, "rest");
+    let params = [addr, offset, rest].map(|symbol| Param {
+        symbol,
+        layout: layout_isize,
+    });
+
+    //
+    // if we haven't cloned all the elements yet...
+    //
+
+    let ptr = root.create_symbol(ident_ids, "ptr");
+    let elem = root.create_symbol(ident_ids, "elem");
+    let new_rest = root.create_symbol(ident_ids, "new_rest");
+    let next_addr = root.create_symbol(ident_ids, "next_addr");
+    let next_offset = root.create_symbol(ident_ids, "next_offset");
+    let is_end = root.create_symbol(ident_ids, "is_end");
+
+    let jump_back = Stmt::Jump(elems_loop, arena.alloc([next_addr, next_offset, new_rest]));
+
+    let clone_elem = let_clone(
+        root,
+        ident_ids,
+        ctx,
+        layout_interner,
+        elem_layout,
+        [elem, offset, rest],
+        new_rest,
+        let_lowlevel(
+            arena,
+            layout_isize,
+            next_addr,
+            NumAdd,
+            &[addr, size],
+            arena.alloc(
+                //
+                let_lowlevel(
+                    arena,
+                    layout_isize,
+                    next_offset,
+                    NumAdd,
+                    &[offset, size],
+                    arena.alloc(jump_back),
+                ),
+            ),
+        ),
+    );
+
+    let if_end_of_list = Stmt::if_then_else(
+        arena,
+        is_end,
+        layout_isize,
+        Stmt::Ret(rest, Region::zero()),
+        arena.alloc(
+            //
+            let_lowlevel(
+                arena,
+                ptr_layout,
+                ptr,
+                PtrCast,
+                &[addr],
+                arena.alloc(
+                    //
+                    Stmt::Let(
+                        elem,
+                        Expr::ptr_load(arena.alloc(ptr)),
+                        elem_layout,
+                        arena.alloc(clone_elem),
+                        Region::zero(),
+                    ),
+                ),
+            ),
+        ),
+    );
+
+    let joinpoint_loop = Stmt::Join {
+        id: elems_loop,
+        parameters: arena.alloc(params),
+        body: arena.alloc(
+            //
+            let_lowlevel(
+                arena,
+                LAYOUT_BOOL,
+                is_end,
+                NumGte,
+                &[offset, rest_start],
+                arena.alloc(if_end_of_list),
+            ),
+        ),
+        remainder: arena.alloc(Stmt::Jump(
+            elems_loop,
+            arena.alloc([start, ARG_4, rest_start]),
+        )),
+    };
+
+    //
+    // Loop initialisation
+    //
+
+    let size_expr = Expr::Literal(Literal::Int(
+        (layout_interner.stack_size(elem_layout) as i128).to_ne_bytes(),
+    ));
+
+    let init = Stmt::Let(
+        elements,
+        Expr::StructAtIndex {
+            index: 0,
+            field_layouts: arena.alloc([ptr_layout, layout_isize]),
+            structure: ARG_1,
+        },
+        ptr_layout,
+        arena.alloc(
+            //
+            let_lowlevel(
+                arena,
+                layout_isize,
+                start,
+                PtrCast,
+                &[elements],
+                arena.alloc(
+                    //
+                    Stmt::Let(
+                        size,
+                        size_expr,
+                        layout_isize,
+                        arena.alloc(
+                            //
+                            let_lowlevel(
+                                arena,
+                                layout_isize,
+                                elements_width,
+                                NumMul,
+                                &[len, size],
+                                arena.alloc(
+                                    //
+                                    let_lowlevel(
+                                        arena,
+                                        layout_isize,
+                                        rest_start,
+                                        NumAdd,
+                                        &[ARG_4, elements_width],
+                                        arena.alloc(joinpoint_loop),
+                                    ),
+                                ),
+                            ),
+                        ),
+                        Region::zero(),
+                    ),
+                ),
+            ),
+        ),
+        Region::zero(),
+    );
+
+    // The header of the list: the elements "pointer", the length, and the capacity
+    let mut stmt = init;
+    stmt = write_at(
+        root,
+        ident_ids,
+        layout_interner,
+        layout_isize,
+        len,
+        capacity_offset,
+        stmt,
+    );
+    stmt = let_add_constant(root, ident_ids, capacity_offset, ARG_3, 2 * ptr_width, stmt);
+    stmt = write_at(
+        root,
+        ident_ids,
+        layout_interner,
+        layout_isize,
+        len,
+        len_offset,
+        stmt,
+    );
+    stmt = let_add_constant(root, ident_ids, len_offset, ARG_3, ptr_width, stmt);
+    stmt = write_at(
+        root,
+        ident_ids,
+        layout_interner,
+        layout_isize,
+        ARG_4,
+        ARG_3,
+        stmt,
+    );
+
+    let_lowlevel(
+        arena,
+        layout_isize,
+        len,
+        ListLenUsize,
+        &[ARG_1],
+        arena.alloc(stmt),
+    )
+}
+
+fn clone_tag_union<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+) -> Stmt<'a> {
+    use UnionLayout::*;
+
+    let parent_rec_ptr_layout = ctx.recursive_union;
+    if !matches!(union_layout, NonRecursive(_)) {
+        ctx.recursive_union = Some(union_layout);
+    }
+
+    let body = match union_layout {
+        NonRecursive(&[]) => {
+            // cannot be reached at runtime, but we need to generate valid code
+            Stmt::Ret(ARG_4, Region::zero())
+        }
+        NonRecursive(tags) => {
+            let mut branches = Vec::with_capacity_in(tags.len(), root.arena);
+            for (tag_id, field_layouts) in (0..).zip(tags.iter()) {
+                let stmt = clone_tag_fields(
+                    root,
+                    ident_ids,
+                    ctx,
+                    layout_interner,
+                    union_layout,
+                    tag_id,
+                    field_layouts,
+                    ARG_3,
+                    ARG_4,
+                );
+                branches.push((tag_id, stmt));
+            }
+            switch_on_tag_id(root, ident_ids, union_layout, branches)
+        }
+        Recursive(tags) => {
+            let mut branches = Vec::with_capacity_in(tags.len(), root.arena);
+            for (tag_id, field_layouts) in (0..).zip(tags.iter()) {
+                let stmt = clone_tag_data(
+                    root,
+                    ident_ids,
+                    ctx,
+                    layout_interner,
+                    union_layout,
+                    tag_id,
+                    field_layouts,
+                );
+                branches.push((tag_id, stmt));
+            }
+            switch_on_tag_id(root, ident_ids, union_layout, branches)
+        }
+        NonNullableUnwrapped(field_layouts) => clone_tag_data(
+            root,
+            ident_ids,
+            ctx,
+            layout_interner,
+            union_layout,
+            0,
+            field_layouts,
+        ),
+        NullableWrapped {
+            nullable_id,
+            other_tags,
+        } => {
+            let mut branches = Vec::with_capacity_in(other_tags.len() + 1, root.arena);
+            branches.push((nullable_id, clone_null(root, ident_ids, layout_interner)));
+
+            let tag_ids = (0..).filter(|tag_id| *tag_id != nullable_id);
+            for (tag_id, field_layouts) in tag_ids.zip(other_tags.iter()) {
+                let stmt = clone_tag_data(
+                    root,
+                    ident_ids,
+                    ctx,
+                    layout_interner,
+                    union_layout,
+                    tag_id,
+                    field_layouts,
+                );
+                branches.push((tag_id, stmt));
+            }
+
+            switch_on_tag_id(root, ident_ids, union_layout, branches)
+        }
+        NullableUnwrapped {
+            nullable_id,
+            other_fields,
+        } => {
+            let null_stmt = clone_null(root, ident_ids, layout_interner);
+            let other_stmt = clone_tag_data(
+                root,
+                ident_ids,
+                ctx,
+                layout_interner,
+                union_layout,
+                !nullable_id as TagIdIntType,
+                other_fields,
+            );
+
+            let mut branches = Vec::with_capacity_in(2, root.arena);
+            branches.push((nullable_id as TagIdIntType, null_stmt));
+            branches.push((!nullable_id as TagIdIntType, other_stmt));
+            switch_on_tag_id(root, ident_ids, union_layout, branches)
+        }
+    };
+
+    ctx.recursive_union = parent_rec_ptr_layout;
+
+    body
+}
+
+fn switch_on_tag_id<'a>(
+    root: &CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    union_layout: UnionLayout<'a>,
+    mut branches: Vec<'a, (TagIdIntType, Stmt<'a>)>,
+) -> Stmt<'a> {
+    let tag_id_layout = union_layout.tag_id_layout();
+    let tag_id = root.create_symbol(ident_ids, "tag_id");
+
+    let (_, default_stmt) = branches.pop().unwrap();
+    let branches = branches
+        .into_iter()
+        .map(|(id, stmt)| (id as u64, BranchInfo::None, stmt));
+
+    Stmt::Let(
+        tag_id,
+        Expr::GetTagId {
+            structure: ARG_1,
+            union_layout,
+        },
+        tag_id_layout,
+        root.arena.alloc(Stmt::Switch {
+            cond_symbol: tag_id,
+            cond_layout: tag_id_layout,
+            branches: root.arena.alloc_slice_fill_iter(branches),
+            default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
+            ret_layout: root.layout_isize,
+            region: Region::zero(),
+        }),
+        Region::zero(),
+    )
+}
+
+/// Clone the payload of a tag at `offset`, then write its tag id (if it is stored as data).
+#[allow(clippy::too_many_arguments)]
+fn clone_tag_fields<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+    tag_id: TagIdIntType,
+    field_layouts: &'a [InLayout<'a>],
+    offset: Symbol,
+    extra: Symbol,
+) -> Stmt<'a> {
+    let target = root.target;
+    let tag_id_offset = union_layout.data_size_without_tag_id(layout_interner);
+    let stores_tag_id = union_layout.stores_tag_id_as_data(target)
+        && !matches!(union_layout.discriminant(), Discriminant::U0);
+
+    // The tag id goes after the data of the largest tag
+    let write_tag_id = move |root: &mut CodeGenHelp<'a>,
+                             ident_ids: &mut IdentIds,
+                             layout_interner: &mut STLayoutInterner<'a>,
+                             new_extra| {
+        let done = Stmt::Ret(new_extra, Region::zero());
+        match tag_id_offset {
+            Some(tag_id_offset) if stores_tag_id => {
+                let tag_id_layout = union_layout.tag_id_layout();
+                let tag_id_sym = root.create_symbol(ident_ids, "tag_id");
+                let tag_id_at = root.create_symbol(ident_ids, "tag_id_offset");
+
+                let stmt = write_at(
+                    root,
+                    ident_ids,
+                    layout_interner,
+                    tag_id_layout,
+                    tag_id_sym,
+                    tag_id_at,
+                    done,
+                );
+                let stmt =
+                    let_add_constant(root, ident_ids, tag_id_at, offset, tag_id_offset as _, stmt);
+                Stmt::Let(
+                    tag_id_sym,
+                    Expr::Literal(Literal::Int((tag_id as i128).to_ne_bytes())),
+                    tag_id_layout,
+                    root.arena.alloc(stmt),
+                    Region::zero(),
+                )
+            }
+            _ => done,
+        }
+    };
+
+    clone_fields(
+        root,
+        ident_ids,
+        ctx,
+        layout_interner,
+        field_layouts,
+        |index| Expr::UnionAtIndex {
+            structure: ARG_1,
+            tag_id,
+            union_layout,
+            index,
+        },
+        offset,
+        extra,
+        write_tag_id,
+    )
+}
+
+/// Write the "pointer" to the data of a recursive tag, and clone the data at the extra offset.
+fn clone_tag_data<'a>(
+    root: &mut CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    ctx: &mut Context<'a>,
+    layout_interner: &mut STLayoutInterner<'a>,
+    union_layout: UnionLayout<'a>,
+    tag_id: TagIdIntType,
+    field_layouts: &'a [InLayout<'a>],
+) -> Stmt<'a> {
+    let arena = root.arena;
+    let layout_isize = root.layout_isize;
+    let (data_width, _) = union_layout.data_size_and_alignment(layout_interner);
+
+    let data_extra = root.create_symbol(ident_ids, "data_extra");
+    let data_stmt = clone_tag_fields(
+        root,
+        ident_ids,
+        ctx,
+        layout_interner,
+        union_layout,
+        tag_id,
+        field_layouts,
+        ARG_4,
+        data_extra,
+    );
+    let data_stmt = let_add_constant(
+        root,
+        ident_ids,
+        data_extra,
+        ARG_4,
+        data_width as _,
+        data_stmt,
+    );
+
+    if union_layout.stores_tag_id_in_pointer(root.target) {
+        // The offset can't hold the tag id in its low bits, so we write the tag id
+        // and then the offset, both as u32
+        let tag_id_sym = root.create_symbol(ident_ids, "tag_id");
+        let extra_u32 = root.create_symbol(ident_ids, "extra_u32");
+        let extra_at = root.create_symbol(ident_ids, "extra_offset");
+
+        let stmt = write_at(
+            root,
+            ident_ids,
+            layout_interner,
+            Layout::U32,
+            extra_u32,
+            extra_at,
+            data_stmt,
+        );
+        let stmt = let_add_constant(root, ident_ids, extra_at, ARG_3, 4, stmt);
+        let stmt = let_lowlevel(
+            arena,
+            Layout::U32,
+            extra_u32,
+            LowLevel::NumIntCast,
+            &[ARG_4],
+            arena.alloc(stmt),
+        );
+        let stmt = write_at(
+            root,
+            ident_ids,
+            layout_interner,
+            Layout::U32,
+            tag_id_sym,
+            ARG_3,
+            stmt,
+        );
+
+        Stmt::Let(
+            tag_id_sym,
+            Expr::Literal(Literal::Int((tag_id as i128).to_ne_bytes())),
+            Layout::U32,
+            arena.alloc(stmt),
+            Region::zero(),
+        )
+    } else {
+        write_at(
+            root,
+            ident_ids,
+            layout_interner,
+            layout_isize,
+            ARG_4,
+            ARG_3,
+            data_stmt,
+        )
+    }
+}
+
+/// The null tag of a nullable union is written as a null "pointer".
+fn clone_null<'a>(
+    root: &CodeGenHelp<'a>,
+    ident_ids: &mut IdentIds,
+    layout_interner: &mut STLayoutInterner<'a>,
+) -> Stmt<'a> {
+    let zero = root.create_symbol(ident_ids, "zero");
+    let stmt = write_at(
+        root,
+        ident_ids,
+        layout_interner,
+        root.layout_isize,
+        zero,
+        ARG_3,
+        Stmt::Ret(ARG_4, Region::zero()),
+    );
+
+    Stmt::Let(
+        zero,
+        Expr::Literal(Literal::Int(0i128.to_ne_bytes())),
+        root.layout_isize,
+        root.arena.alloc(stmt),
+        Region::zero(),
+    )
+}
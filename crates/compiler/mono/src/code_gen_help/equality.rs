@@ -1,6 +1,7 @@
 use bumpalo::collections::vec::Vec;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
 
 use crate::ir::{
     BranchInfo, Call, CallType, Expr, JoinPointId, Literal, Param, Stmt, UpdateModeId,
@@ -55,7 +56,9 @@ pub fn eq_generic<'a>(
             Expr::Literal(Literal::Bool(false)),
             LAYOUT_BOOL,
             root.arena.alloc(main_body),
+            Region::zero(),
         )),
+        Region::zero(),
     )
 }
 
@@ -105,13 +108,17 @@ fn if_pointers_equal_return_true<'a>(
                     branches: root.arena.alloc([(
                         1,
                         BranchInfo::None,
-                        Stmt::Ret(Symbol::BOOL_TRUE),
+                        Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
                     )]),
                     default_branch: (BranchInfo::None, following),
                     ret_layout: LAYOUT_BOOL,
+                    region: Region::zero(),
                 }),
+                Region::zero(),
             )),
+            Region::zero(),
         )),
+        Region::zero(),
     )
 }
 
@@ -125,7 +132,8 @@ fn if_false_return_false<'a>(
         symbol,
         Layout::BOOL,
         following,
-        root.arena.alloc(Stmt::Ret(Symbol::BOOL_FALSE)),
+        root.arena
+            .alloc(Stmt::Ret(Symbol::BOOL_FALSE, Region::zero())),
     )
 }
 
@@ -136,7 +144,7 @@ fn eq_struct<'a>(
     layout_interner: &mut STLayoutInterner<'a>,
     field_layouts: &'a [InLayout<'a>],
 ) -> Stmt<'a> {
-    let mut else_stmt = Stmt::Ret(Symbol::BOOL_TRUE);
+    let mut else_stmt = Stmt::Ret(Symbol::BOOL_TRUE, Region::zero());
     for (i, layout) in field_layouts.iter().enumerate().rev() {
         let field1_sym = root.create_symbol(ident_ids, &format!("field_1_{i}"));
         let field1_expr = Expr::StructAtIndex {
@@ -144,7 +152,7 @@ fn eq_struct<'a>(
             field_layouts,
             structure: ARG_1,
         };
-        let field1_stmt = |next| Stmt::Let(field1_sym, field1_expr, *layout, next);
+        let field1_stmt = |next| Stmt::Let(field1_sym, field1_expr, *layout, next, Region::zero());
 
         let field2_sym = root.create_symbol(ident_ids, &format!("field_2_{i}"));
         let field2_expr = Expr::StructAtIndex {
@@ -152,7 +160,7 @@ fn eq_struct<'a>(
             field_layouts,
             structure: ARG_2,
         };
-        let field2_stmt = |next| Stmt::Let(field2_sym, field2_expr, *layout, next);
+        let field2_stmt = |next| Stmt::Let(field2_sym, field2_expr, *layout, next, Region::zero());
 
         let eq_call_expr = root
             .call_specialized_op(
@@ -166,7 +174,8 @@ fn eq_struct<'a>(
 
         let eq_call_name = format!("eq_call_{i}");
         let eq_call_sym = root.create_symbol(ident_ids, &eq_call_name);
-        let eq_call_stmt = |next| Stmt::Let(eq_call_sym, eq_call_expr, LAYOUT_BOOL, next);
+        let eq_call_stmt =
+            |next| Stmt::Let(eq_call_sym, eq_call_expr, LAYOUT_BOOL, next, Region::zero());
 
         else_stmt = field1_stmt(root.arena.alloc(
             //
@@ -200,7 +209,7 @@ fn eq_tag_union<'a>(
     let body = match union_layout {
         NonRecursive(&[]) => {
             // cannot be reached at runtime, but we need to generate valid code
-            Stmt::Ret(Symbol::BOOL_TRUE)
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero())
         }
         NonRecursive(tags) => eq_tag_union_help(
             root,
@@ -305,6 +314,7 @@ fn eq_tag_union_help<'a>(
             },
             tag_id_layout,
             next,
+            Region::zero(),
         )
     };
 
@@ -318,6 +328,7 @@ fn eq_tag_union_help<'a>(
             },
             tag_id_layout,
             next,
+            Region::zero(),
         )
     };
 
@@ -329,11 +340,14 @@ fn eq_tag_union_help<'a>(
         },
         arguments: root.arena.alloc([tag_id_a, tag_id_b]),
     });
-    let tag_ids_eq_stmt = |next| Stmt::Let(tag_ids_eq, tag_ids_expr, LAYOUT_BOOL, next);
+    let tag_ids_eq_stmt =
+        |next| Stmt::Let(tag_ids_eq, tag_ids_expr, LAYOUT_BOOL, next, Region::zero());
 
-    let if_equal_ids_branches =
-        root.arena
-            .alloc([(0, BranchInfo::None, Stmt::Ret(Symbol::BOOL_FALSE))]);
+    let if_equal_ids_branches = root.arena.alloc([(
+        0,
+        BranchInfo::None,
+        Stmt::Ret(Symbol::BOOL_FALSE, Region::zero()),
+    )]);
 
     //
     // Switch statement by tag ID
@@ -343,13 +357,15 @@ fn eq_tag_union_help<'a>(
 
     // If there's a null tag, check it first. We might not need to load any data from memory.
     match nullable_id {
-        NullableId::Wrapped(id) => {
-            tag_branches.push((id as u64, BranchInfo::None, Stmt::Ret(Symbol::BOOL_TRUE)))
-        }
+        NullableId::Wrapped(id) => tag_branches.push((
+            id as u64,
+            BranchInfo::None,
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
+        )),
         NullableId::Unwrapped(id) => tag_branches.push((
             id as TagIdIntType as u64,
             BranchInfo::None,
-            Stmt::Ret(Symbol::BOOL_TRUE),
+            Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
         )),
         _ => (),
     }
@@ -404,6 +420,7 @@ fn eq_tag_union_help<'a>(
             )),
         ),
         ret_layout: LAYOUT_BOOL,
+        region: Region::zero(),
     };
 
     let if_equal_ids_stmt = Stmt::Switch {
@@ -412,6 +429,7 @@ fn eq_tag_union_help<'a>(
         branches: if_equal_ids_branches,
         default_branch: (BranchInfo::None, root.arena.alloc(tag_switch_stmt)),
         ret_layout: LAYOUT_BOOL,
+        region: Region::zero(),
     };
 
     //
@@ -481,7 +499,10 @@ fn eq_tag_fields<'a>(
     let (tailrec_index, innermost_stmt) = match rec_ptr_index {
         None => {
             // This tag has no RecursivePointers. Set tailrec_index out of range.
-            (field_layouts.len(), Stmt::Ret(Symbol::BOOL_TRUE))
+            (
+                field_layouts.len(),
+                Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
+            )
         }
 
         Some(i) => {
@@ -518,8 +539,10 @@ fn eq_tag_fields<'a>(
                             //
                             Stmt::Jump(tailrec_loop, root.arena.alloc([field1_sym, field2_sym])),
                         ),
+                        Region::zero(),
                     ),
                 ),
+                Region::zero(),
             );
 
             (i, inner)
@@ -587,10 +610,13 @@ fn eq_tag_fields<'a>(
                                     stmt,
                                 ),
                             ),
+                            Region::zero(),
                         ),
                     ),
+                    Region::zero(),
                 ),
             ),
+            Region::zero(),
         )
     }
     stmt
@@ -635,11 +661,14 @@ fn eq_boxed<'a>(
                         result,
                         eq_call_expr,
                         LAYOUT_BOOL,
-                        root.arena.alloc(Stmt::Ret(result)),
+                        root.arena.alloc(Stmt::Ret(result, Region::zero())),
+                        Region::zero(),
                     ),
                 ),
+                Region::zero(),
             ),
         ),
+        Region::zero(),
     )
 }
 
@@ -689,8 +718,24 @@ fn eq_list<'a>(
         field_layouts: root.arena.alloc([ptr_layout, layout_isize]),
         structure: ARG_2,
     };
-    let elements_1_stmt = |next| Stmt::Let(elements_1, elements_1_expr, ptr_layout, next);
-    let elements_2_stmt = |next| Stmt::Let(elements_2, elements_2_expr, ptr_layout, next);
+    let elements_1_stmt = |next| {
+        Stmt::Let(
+            elements_1,
+            elements_1_expr,
+            ptr_layout,
+            next,
+            Region::zero(),
+        )
+    };
+    let elements_2_stmt = |next| {
+        Stmt::Let(
+            elements_2,
+            elements_2_expr,
+            ptr_layout,
+            next,
+            Region::zero(),
+        )
+    };
 
     // Cast to integers
     let start_1 = root.create_symbol(ident_ids, "start_1");
@@ -712,7 +757,7 @@ fn eq_list<'a>(
             .stack_size(layout_interner) as i128)
             .to_ne_bytes(),
     ));
-    let size_stmt = |next| Stmt::Let(size, size_expr, layout_isize, next);
+    let size_stmt = |next| Stmt::Let(size, size_expr, layout_isize, next, Region::zero());
 
     // let list_size = len_1 * size
     let list_size = root.create_symbol(ident_ids, "list_size");
@@ -765,8 +810,8 @@ fn eq_list<'a>(
     let elem2 = root.create_symbol(ident_ids, "elem2");
     let elem1_expr = Expr::ptr_load(arena.alloc(ptr1));
     let elem2_expr = Expr::ptr_load(arena.alloc(ptr2));
-    let elem1_stmt = |next| Stmt::Let(elem1, elem1_expr, elem_layout, next);
-    let elem2_stmt = |next| Stmt::Let(elem2, elem2_expr, elem_layout, next);
+    let elem1_stmt = |next| Stmt::Let(elem1, elem1_expr, elem_layout, next, Region::zero());
+    let elem2_stmt = |next| Stmt::Let(elem2, elem2_expr, elem_layout, next, Region::zero());
 
     // Compare the two current elements
     let eq_elems = root.create_symbol(ident_ids, "eq_elems");
@@ -775,7 +820,8 @@ fn eq_list<'a>(
         .call_specialized_op(ident_ids, ctx, layout_interner, elem_layout, eq_elems_args)
         .unwrap();
 
-    let eq_elems_stmt = |next| Stmt::Let(eq_elems, eq_elems_expr, LAYOUT_BOOL, next);
+    let eq_elems_stmt =
+        |next| Stmt::Let(eq_elems, eq_elems_expr, LAYOUT_BOOL, next, Region::zero());
 
     // If current elements are equal, loop back again
     let next_1 = root.create_symbol(ident_ids, "next_1");
@@ -812,7 +858,7 @@ fn eq_list<'a>(
         arena,
         is_end,
         Layout::BOOL,
-        Stmt::Ret(Symbol::BOOL_TRUE),
+        Stmt::Ret(Symbol::BOOL_TRUE, Region::zero()),
         root.arena.alloc(
             //
             ptr1_stmt(root.arena.alloc(
@@ -3,6 +3,7 @@ use bumpalo::collections::CollectIn;
 use bumpalo::Bump;
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 use roc_target::Target;
 
 use crate::ir::{
@@ -14,6 +15,7 @@ use crate::layout::{
     STLayoutInterner, UnionLayout,
 };
 
+mod clone;
 mod equality;
 mod refcount;
 
@@ -41,6 +43,7 @@ pub enum HelperOp {
     Reset,
     ResetRef,
     Eq,
+    Clone,
 }
 
 impl HelperOp {
@@ -76,7 +79,7 @@ pub struct Context<'a> {
 /// ----------------------------------------------
 ///
 /// Some low level operations need specialized helper procs to traverse data structures at runtime.
-/// This includes refcounting, hashing, and equality checks.
+/// This includes refcounting, hashing, equality checks, and cloning values for failed `expect`s.
 ///
 /// For example, when checking List equality, we need to visit each element and compare them.
 /// Depending on the type of the list elements, we may need to recurse deeper into each element.
@@ -270,6 +273,29 @@ impl<'a> CodeGenHelp<'a> {
         (expr, ctx.new_linker_data)
     }
 
+    /// Clone a value into the shared memory of a failed `expect` with a specialized helper proc.
+    /// The arguments are the value, the address of the shared memory, the offset to write the value at,
+    /// and the offset to write any data it points to at. The call returns the new end of the written data.
+    pub fn call_clone_to_shared_memory(
+        &mut self,
+        ident_ids: &mut IdentIds,
+        layout_interner: &mut STLayoutInterner<'a>,
+        layout: InLayout<'a>,
+        arguments: &'a [Symbol],
+    ) -> (Expr<'a>, Vec<'a, (Symbol, ProcLayout<'a>)>) {
+        let mut ctx = Context {
+            new_linker_data: Vec::new_in(self.arena),
+            recursive_union: None,
+            op: HelperOp::Clone,
+        };
+
+        let expr = self
+            .call_specialized_op(ident_ids, &mut ctx, layout_interner, layout, arguments)
+            .unwrap();
+
+        (expr, ctx.new_linker_data)
+    }
+
     // ============================================================================
     //
     //              CALL SPECIALIZED OP
@@ -314,6 +340,10 @@ impl<'a> CodeGenHelp<'a> {
                     IndirectDec => (LAYOUT_UNIT, arena.alloc([ptr_arg])),
                     IndirectInc => (LAYOUT_UNIT, arena.alloc([ptr_arg, self.layout_isize])),
                     Eq => (LAYOUT_BOOL, self.arena.alloc([arg, arg])),
+                    Clone => {
+                        let isize = self.layout_isize;
+                        (isize, self.arena.alloc([arg, isize, isize, isize]))
+                    }
                 }
             };
 
@@ -423,6 +453,10 @@ impl<'a> CodeGenHelp<'a> {
                 LAYOUT_BOOL,
                 equality::eq_generic(self, ident_ids, ctx, layout_interner, layout),
             ),
+            Clone => (
+                self.layout_isize,
+                clone::clone_generic(self, ident_ids, ctx, layout_interner, layout),
+            ),
         };
 
         let args: &'a [(InLayout<'a>, Symbol)] = {
@@ -445,6 +479,11 @@ impl<'a> CodeGenHelp<'a> {
                     self.arena.alloc([(ptr_layout, ARG_1)])
                 }
                 Eq => self.arena.alloc([roc_value, (layout, ARG_2)]),
+                Clone => {
+                    let isize = self.layout_isize;
+                    self.arena
+                        .alloc([roc_value, (isize, ARG_2), (isize, ARG_3), (isize, ARG_4)])
+                }
             }
         };
 
@@ -456,6 +495,7 @@ impl<'a> CodeGenHelp<'a> {
             ret_layout,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         });
 
         proc_symbol
@@ -517,6 +557,15 @@ impl<'a> CodeGenHelp<'a> {
                 result: LAYOUT_BOOL,
                 niche: Niche::NONE,
             },
+            HelperOp::Clone => {
+                let isize = self.layout_isize;
+
+                ProcLayout {
+                    arguments: self.arena.alloc([layout, isize, isize, isize]),
+                    result: isize,
+                    niche: Niche::NONE,
+                }
+            }
         };
 
         (proc_symbol, proc_layout)
@@ -781,9 +830,12 @@ impl<'a> CallerProc<'a> {
                     unit_symbol,
                     Expr::Struct(&[]),
                     Layout::UNIT,
-                    arena.alloc(Stmt::Ret(unit_symbol)),
+                    arena.alloc(Stmt::Ret(unit_symbol, Region::zero())),
+                    Region::zero(),
                 )),
+                Region::zero(),
             )),
+            Region::zero(),
         );
 
         let it = loaded_arguments
@@ -800,6 +852,7 @@ impl<'a> CallerProc<'a> {
                 load_argument,
                 *argument_layout,
                 arena.alloc(body),
+                Region::zero(),
             );
         }
 
@@ -809,6 +862,7 @@ impl<'a> CallerProc<'a> {
                 load_capture,
                 capture_layout,
                 arena.alloc(body),
+                Region::zero(),
             );
         }
 
@@ -829,6 +883,7 @@ impl<'a> CallerProc<'a> {
             ret_layout: Layout::UNIT,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         if false {
@@ -926,7 +981,8 @@ impl<'a> CallerProc<'a> {
             call_result,
             call,
             passed_function.return_layout,
-            arena.alloc(Stmt::Ret(call_result)),
+            arena.alloc(Stmt::Ret(call_result, Region::zero())),
+            Region::zero(),
         );
 
         let it = loaded_arguments
@@ -943,6 +999,7 @@ impl<'a> CallerProc<'a> {
                 load_argument,
                 *argument_layout,
                 arena.alloc(body),
+                Region::zero(),
             );
         }
 
@@ -952,6 +1009,7 @@ impl<'a> CallerProc<'a> {
                 load_capture,
                 capture_layout,
                 arena.alloc(body),
+                Region::zero(),
             );
         }
 
@@ -971,6 +1029,7 @@ impl<'a> CallerProc<'a> {
             ret_layout: Layout::BOOL,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         if false {
@@ -1005,6 +1064,7 @@ fn let_lowlevel<'a>(
         }),
         result_layout,
         next,
+        Region::zero(),
     )
 }
 
@@ -1016,12 +1076,16 @@ fn layout_needs_helper_proc<'a>(
     match layout_interner.get_repr(layout) {
         LayoutRepr::Builtin(
             Builtin::Int(_) | Builtin::Float(_) | Builtin::Bool | Builtin::Decimal,
-        ) => false,
+        ) => matches!(op, HelperOp::Clone),
         LayoutRepr::Builtin(Builtin::Str) => {
             // Str type can use either Zig functions or generated IR, since it's not generic.
             // Eq uses a Zig function, refcount uses generated IR.
             // Both are fine, they were just developed at different times.
-            matches!(op, HelperOp::Inc | HelperOp::Dec | HelperOp::DecRef(_))
+            // Clone calls a Zig function from generated IR.
+            matches!(
+                op,
+                HelperOp::Inc | HelperOp::Dec | HelperOp::DecRef(_) | HelperOp::Clone
+            )
         }
         LayoutRepr::Builtin(Builtin::List(_)) => true,
         LayoutRepr::Struct { .. } => true, // note: we do generate a helper for Unit, with just a Stmt::Ret
@@ -1075,6 +1139,7 @@ pub fn test_helper<'a>(
         ret_layout: output_layout,
         is_self_recursive: main_proc.is_self_recursive,
         is_erased: false,
+        region: Region::zero(),
     }
 }
 
@@ -1095,7 +1160,15 @@ fn test_helper_body<'a>(
         },
         arguments: &[],
     });
-    let buffer_stmt = |next| Stmt::Let(buffer_symbol, buffer_expr, Layout::U64, next);
+    let buffer_stmt = |next| {
+        Stmt::Let(
+            buffer_symbol,
+            buffer_expr,
+            Layout::U64,
+            next,
+            Region::zero(),
+        )
+    };
 
     let field_layouts = env.arena.alloc([Layout::U64, Layout::U64]);
     let ret_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Struct(field_layouts));
@@ -1108,7 +1181,8 @@ fn test_helper_body<'a>(
         },
         arguments: env.arena.alloc([buffer_symbol]),
     });
-    let setjmp_stmt = |next| Stmt::Let(setjmp_symbol, setjmp_expr, ret_layout, next);
+    let setjmp_stmt =
+        |next| Stmt::Let(setjmp_symbol, setjmp_expr, ret_layout, next, Region::zero());
 
     let is_longjmp_symbol = env.create_symbol(ident_ids, "is_longjmp");
     let is_longjmp_expr = Expr::StructAtIndex {
@@ -1116,7 +1190,15 @@ fn test_helper_body<'a>(
         field_layouts,
         structure: setjmp_symbol,
     };
-    let is_longjmp_stmt = |next| Stmt::Let(is_longjmp_symbol, is_longjmp_expr, Layout::U64, next);
+    let is_longjmp_stmt = |next| {
+        Stmt::Let(
+            is_longjmp_symbol,
+            is_longjmp_expr,
+            Layout::U64,
+            next,
+            Region::zero(),
+        )
+    };
 
     let tag_symbol = env.create_symbol(ident_ids, "tag");
     let tag_expr = Expr::StructAtIndex {
@@ -1124,7 +1206,7 @@ fn test_helper_body<'a>(
         field_layouts,
         structure: setjmp_symbol,
     };
-    let tag_stmt = |next| Stmt::Let(tag_symbol, tag_expr, Layout::U64, next);
+    let tag_stmt = |next| Stmt::Let(tag_symbol, tag_expr, Layout::U64, next, Region::zero());
 
     // normal path, no panics
     let if_zero_stmt = {
@@ -1141,21 +1223,53 @@ fn test_helper_body<'a>(
             },
             arguments,
         });
-        let result = |next| Stmt::Let(result_symbol, result_expr, main_proc.ret_layout, next);
+        let result = |next| {
+            Stmt::Let(
+                result_symbol,
+                result_expr,
+                main_proc.ret_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let ok_tag_symbol = env.create_symbol(ident_ids, "ok_tag");
         let ok_tag_expr = Expr::Literal(Literal::Int((0i128).to_ne_bytes()));
-        let ok_tag = |next| Stmt::Let(ok_tag_symbol, ok_tag_expr, Layout::U64, next);
+        let ok_tag = |next| {
+            Stmt::Let(
+                ok_tag_symbol,
+                ok_tag_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         let msg_ptr_symbol = env.create_symbol(ident_ids, "msg_ptr");
         let msg_ptr_expr = Expr::Literal(Literal::Int((0i128).to_ne_bytes()));
-        let msg_ptr = |next| Stmt::Let(msg_ptr_symbol, msg_ptr_expr, Layout::U64, next);
+        let msg_ptr = |next| {
+            Stmt::Let(
+                msg_ptr_symbol,
+                msg_ptr_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         // construct the record
         let output_symbol = env.create_symbol(ident_ids, "output_ok");
         let fields = [ok_tag_symbol, msg_ptr_symbol, result_symbol];
         let output_expr = Expr::Struct(env.arena.alloc(fields));
-        let output = |next| Stmt::Let(output_symbol, output_expr, output_layout, next);
+        let output = |next| {
+            Stmt::Let(
+                output_symbol,
+                output_expr,
+                output_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let arena = env.arena;
         result(arena.alloc(
@@ -1166,7 +1280,7 @@ fn test_helper_body<'a>(
                     //
                     output(arena.alloc(
                         //
-                        Stmt::Ret(output_symbol),
+                        Stmt::Ret(output_symbol, Region::zero()),
                     )),
                 )),
             )),
@@ -1180,7 +1294,15 @@ fn test_helper_body<'a>(
             element_layout: main_proc.ret_layout,
             initializer: None,
         };
-        let alloca = |next| Stmt::Let(alloca_symbol, alloca_expr, Layout::U64, next);
+        let alloca = |next| {
+            Stmt::Let(
+                alloca_symbol,
+                alloca_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         let load_symbol = env.create_symbol(ident_ids, "load");
         let load_expr = Expr::Call(Call {
@@ -1190,14 +1312,30 @@ fn test_helper_body<'a>(
             },
             arguments: env.arena.alloc([alloca_symbol]),
         });
-        let load = |next| Stmt::Let(load_symbol, load_expr, main_proc.ret_layout, next);
+        let load = |next| {
+            Stmt::Let(
+                load_symbol,
+                load_expr,
+                main_proc.ret_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         // construct the record
         let output_symbol = env.create_symbol(ident_ids, "output_err");
         // is_longjmp_symbol is a pointer to the error message
         let fields = [tag_symbol, is_longjmp_symbol, load_symbol];
         let output_expr = Expr::Struct(env.arena.alloc(fields));
-        let output = |next| Stmt::Let(output_symbol, output_expr, output_layout, next);
+        let output = |next| {
+            Stmt::Let(
+                output_symbol,
+                output_expr,
+                output_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let arena = env.arena;
         arena.alloc(alloca(arena.alloc(
@@ -1206,7 +1344,7 @@ fn test_helper_body<'a>(
                 //
                 output(arena.alloc(
                     //
-                    Stmt::Ret(output_symbol),
+                    Stmt::Ret(output_symbol, Region::zero()),
                 )),
             )),
         )))
@@ -1275,6 +1413,7 @@ pub fn repl_helper<'a>(
         ret_layout: Layout::UNIT,
         is_self_recursive: main_proc.is_self_recursive,
         is_erased: false,
+        region: Region::zero(),
     }
 }
 
@@ -1295,7 +1434,15 @@ fn repl_helper_body<'a>(
         },
         arguments: &[],
     });
-    let buffer_stmt = |next| Stmt::Let(buffer_symbol, buffer_expr, Layout::U64, next);
+    let buffer_stmt = |next| {
+        Stmt::Let(
+            buffer_symbol,
+            buffer_expr,
+            Layout::U64,
+            next,
+            Region::zero(),
+        )
+    };
 
     let field_layouts = env.arena.alloc([Layout::U64, Layout::U64]);
     let ret_layout = layout_interner.insert_direct_no_semantic(LayoutRepr::Struct(field_layouts));
@@ -1308,7 +1455,8 @@ fn repl_helper_body<'a>(
         },
         arguments: env.arena.alloc([buffer_symbol]),
     });
-    let setjmp_stmt = |next| Stmt::Let(setjmp_symbol, setjmp_expr, ret_layout, next);
+    let setjmp_stmt =
+        |next| Stmt::Let(setjmp_symbol, setjmp_expr, ret_layout, next, Region::zero());
 
     let is_longjmp_symbol = env.create_symbol(ident_ids, "is_longjmp");
     let is_longjmp_expr = Expr::StructAtIndex {
@@ -1316,7 +1464,15 @@ fn repl_helper_body<'a>(
         field_layouts,
         structure: setjmp_symbol,
     };
-    let is_longjmp_stmt = |next| Stmt::Let(is_longjmp_symbol, is_longjmp_expr, Layout::U64, next);
+    let is_longjmp_stmt = |next| {
+        Stmt::Let(
+            is_longjmp_symbol,
+            is_longjmp_expr,
+            Layout::U64,
+            next,
+            Region::zero(),
+        )
+    };
 
     let tag_symbol = env.create_symbol(ident_ids, "tag");
     let tag_expr = Expr::StructAtIndex {
@@ -1324,7 +1480,7 @@ fn repl_helper_body<'a>(
         field_layouts,
         structure: setjmp_symbol,
     };
-    let tag_stmt = |next| Stmt::Let(tag_symbol, tag_expr, Layout::U64, next);
+    let tag_stmt = |next| Stmt::Let(tag_symbol, tag_expr, Layout::U64, next, Region::zero());
 
     // normal path, no panics
     let if_zero_stmt = {
@@ -1341,25 +1497,57 @@ fn repl_helper_body<'a>(
             },
             arguments: &[],
         });
-        let result = |next| Stmt::Let(result_symbol, result_expr, main_proc.ret_layout, next);
+        let result = |next| {
+            Stmt::Let(
+                result_symbol,
+                result_expr,
+                main_proc.ret_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let ok_tag_symbol = env.create_symbol(ident_ids, "ok_tag");
         let ok_tag_expr = Expr::Literal(Literal::Int((0i128).to_ne_bytes()));
-        let ok_tag = |next| Stmt::Let(ok_tag_symbol, ok_tag_expr, Layout::U64, next);
+        let ok_tag = |next| {
+            Stmt::Let(
+                ok_tag_symbol,
+                ok_tag_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         let msg_ptr_symbol = env.create_symbol(ident_ids, "msg_ptr");
         let msg_ptr_expr = Expr::Literal(Literal::Int((0i128).to_ne_bytes()));
-        let msg_ptr = |next| Stmt::Let(msg_ptr_symbol, msg_ptr_expr, Layout::U64, next);
+        let msg_ptr = |next| {
+            Stmt::Let(
+                msg_ptr_symbol,
+                msg_ptr_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         // construct the record
         let result_symbol1 = env.create_symbol(ident_ids, "output_ok");
         let fields = [ok_tag_symbol, msg_ptr_symbol, result_symbol];
         let result_expr = Expr::Struct(env.arena.alloc(fields));
-        let output = |next| Stmt::Let(result_symbol1, result_expr, output_layout, next);
+        let output = |next| {
+            Stmt::Let(
+                result_symbol1,
+                result_expr,
+                output_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let unit_symbol = env.create_symbol(ident_ids, "unit");
         let unit_expr = Expr::ptr_store(env.arena.alloc([output_symbol, result_symbol1]));
-        let unit = |next| Stmt::Let(unit_symbol, unit_expr, Layout::UNIT, next);
+        let unit = |next| Stmt::Let(unit_symbol, unit_expr, Layout::UNIT, next, Region::zero());
 
         let arena = env.arena;
         result(arena.alloc(
@@ -1372,7 +1560,7 @@ fn repl_helper_body<'a>(
                         //
                         unit(arena.alloc(
                             //
-                            Stmt::Ret(unit_symbol),
+                            Stmt::Ret(unit_symbol, Region::zero()),
                         )),
                     )),
                 )),
@@ -1387,7 +1575,15 @@ fn repl_helper_body<'a>(
             element_layout: main_proc.ret_layout,
             initializer: None,
         };
-        let alloca = |next| Stmt::Let(alloca_symbol, alloca_expr, Layout::U64, next);
+        let alloca = |next| {
+            Stmt::Let(
+                alloca_symbol,
+                alloca_expr,
+                Layout::U64,
+                next,
+                Region::zero(),
+            )
+        };
 
         let load_symbol = env.create_symbol(ident_ids, "load");
         let load_expr = Expr::Call(Call {
@@ -1397,18 +1593,34 @@ fn repl_helper_body<'a>(
             },
             arguments: env.arena.alloc([alloca_symbol]),
         });
-        let load = |next| Stmt::Let(load_symbol, load_expr, main_proc.ret_layout, next);
+        let load = |next| {
+            Stmt::Let(
+                load_symbol,
+                load_expr,
+                main_proc.ret_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         // construct the record
         let result_symbol1 = env.create_symbol(ident_ids, "output_err");
         // is_longjmp_symbol is a pointer to the error message
         let fields = [tag_symbol, is_longjmp_symbol, load_symbol];
         let output_expr = Expr::Struct(env.arena.alloc(fields));
-        let output = |next| Stmt::Let(result_symbol1, output_expr, output_layout, next);
+        let output = |next| {
+            Stmt::Let(
+                result_symbol1,
+                output_expr,
+                output_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let unit_symbol = env.create_symbol(ident_ids, "unit");
         let unit_expr = Expr::ptr_store(env.arena.alloc([output_symbol, result_symbol1]));
-        let unit = |next| Stmt::Let(unit_symbol, unit_expr, Layout::UNIT, next);
+        let unit = |next| Stmt::Let(unit_symbol, unit_expr, Layout::UNIT, next, Region::zero());
 
         let arena = env.arena;
         arena.alloc(alloca(arena.alloc(
@@ -1419,7 +1631,7 @@ fn repl_helper_body<'a>(
                     //
                     unit(arena.alloc(
                         //
-                        Stmt::Ret(unit_symbol),
+                        Stmt::Ret(unit_symbol, Region::zero()),
                     )),
                 )),
             )),
@@ -1463,5 +1675,6 @@ fn switch_if_zero_else<'a>(
         branches: &*arena.alloc([then_branch]),
         default_branch: else_branch,
         ret_layout: return_layout,
+        region: Region::zero(),
     }
 }
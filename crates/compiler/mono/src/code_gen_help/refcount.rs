@@ -5,6 +5,7 @@ use bumpalo::collections::CollectIn;
 use roc_error_macros::todo_lambda_erasure;
 use roc_module::low_level::{LowLevel, LowLevel::*};
 use roc_module::symbol::{IdentIds, Symbol};
+use roc_region::all::Region;
 use roc_target::PtrWidth;
 
 use crate::code_gen_help::let_lowlevel;
@@ -40,7 +41,8 @@ pub fn refcount_stmt<'a>(
             // Define a constant for the amount to increment
             let amount_sym = root.create_symbol(ident_ids, "amount");
             let amount_expr = Expr::Literal(Literal::Int((*amount as i128).to_ne_bytes()));
-            let amount_stmt = |next| Stmt::Let(amount_sym, amount_expr, layout_isize, next);
+            let amount_stmt =
+                |next| Stmt::Let(amount_sym, amount_expr, layout_isize, next, Region::zero());
 
             // Call helper proc, passing the Roc structure and constant amount
             let call_result_empty = root.create_symbol(ident_ids, "call_result_empty");
@@ -54,7 +56,13 @@ pub fn refcount_stmt<'a>(
                 )
                 .unwrap();
 
-            let call_stmt = Stmt::Let(call_result_empty, call_expr, LAYOUT_UNIT, following);
+            let call_stmt = Stmt::Let(
+                call_result_empty,
+                call_expr,
+                LAYOUT_UNIT,
+                following,
+                Region::zero(),
+            );
             arena.alloc(amount_stmt(arena.alloc(call_stmt)))
         }
 
@@ -70,7 +78,13 @@ pub fn refcount_stmt<'a>(
                     arena.alloc([*structure]),
                 )
                 .unwrap();
-            let call_stmt = Stmt::Let(call_result_empty, call_expr, LAYOUT_UNIT, following);
+            let call_stmt = Stmt::Let(
+                call_result_empty,
+                call_expr,
+                LAYOUT_UNIT,
+                following,
+                Region::zero(),
+            );
             arena.alloc(call_stmt)
         }
 
@@ -167,10 +181,12 @@ pub fn refcount_indirect<'a>(
                     Layout::UNIT,
                     arena.alloc(
                         //
-                        Stmt::Ret(unit),
+                        Stmt::Ret(unit, Region::zero()),
                     ),
+                    Region::zero(),
                 ),
             ),
+            Region::zero(),
         )
     } else {
         rc_return_stmt(root, ident_ids, ctx)
@@ -312,6 +328,7 @@ pub fn refcount_reset_proc_body<'a>(
                 },
                 tag_id_layout,
                 next,
+                Region::zero(),
             )
         };
 
@@ -326,7 +343,7 @@ pub fn refcount_reset_proc_body<'a>(
             structure,
             tag_id_sym,
             tag_id_layout,
-            Stmt::Ret(addr),
+            Stmt::Ret(addr, Region::zero()),
         );
 
         tag_id_stmt(root.arena.alloc(
@@ -347,17 +364,25 @@ pub fn refcount_reset_proc_body<'a>(
                 root.arena.alloc([structure]),
             )
             .unwrap();
-        let decrement_stmt = |next| Stmt::Let(decrement_unit, decrement_expr, LAYOUT_UNIT, next);
+        let decrement_stmt = |next| {
+            Stmt::Let(
+                decrement_unit,
+                decrement_expr,
+                LAYOUT_UNIT,
+                next,
+                Region::zero(),
+            )
+        };
 
         // Null pointer with union layout
         let null = root.create_symbol(ident_ids, "null");
-        let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, layout, next);
+        let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, layout, next, Region::zero());
 
         decrement_stmt(root.arena.alloc(
             //
             null_stmt(root.arena.alloc(
                 //
-                Stmt::Ret(null),
+                Stmt::Ret(null, Region::zero()),
             )),
         ))
     };
@@ -368,6 +393,7 @@ pub fn refcount_reset_proc_body<'a>(
         branches: root.arena.alloc([(1, BranchInfo::None, then_stmt)]),
         default_branch: (BranchInfo::None, root.arena.alloc(else_stmt)),
         ret_layout: layout,
+        region: Region::zero(),
     };
 
     // Uniqueness test
@@ -394,6 +420,7 @@ pub fn refcount_reset_proc_body<'a>(
         refcount_1_expr,
         root.layout_isize,
         root.arena.alloc(is_unique_stmt),
+        Region::zero(),
     );
 
     // Refcount value
@@ -404,6 +431,7 @@ pub fn refcount_reset_proc_body<'a>(
         rc_expr,
         root.layout_isize,
         root.arena.alloc(refcount_1_stmt),
+        Region::zero(),
     );
 
     let mask_lower_bits = match layout_interner.get_repr(layout) {
@@ -454,7 +482,7 @@ pub fn refcount_resetref_proc_body<'a>(
         layout_interner.insert_direct_no_semantic(LayoutRepr::RecursivePointer(layout));
 
     // Reset structure is unique. Return a pointer to the allocation.
-    let then_stmt = Stmt::Ret(addr);
+    let then_stmt = Stmt::Ret(addr, Region::zero());
 
     // Reset structure is not unique. Decrement it and return a NULL pointer.
     let else_stmt = {
@@ -467,7 +495,7 @@ pub fn refcount_resetref_proc_body<'a>(
 
         // Null pointer with union layout
         let null = root.create_symbol(ident_ids, "null");
-        let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, layout, next);
+        let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, layout, next, Region::zero());
 
         // Inline the refcounting code instead of making a function. Don't iterate fields,
         // and replace any return statements with jumps to the `following` statement.
@@ -478,7 +506,7 @@ pub fn refcount_resetref_proc_body<'a>(
                 //
                 null_stmt(root.arena.alloc(
                     //
-                    Stmt::Ret(null),
+                    Stmt::Ret(null, Region::zero()),
                 )),
             )),
             remainder: root.arena.alloc(rc_stmt),
@@ -519,6 +547,7 @@ pub fn refcount_resetref_proc_body<'a>(
         refcount_1_expr,
         root.layout_isize,
         root.arena.alloc(is_unique_stmt),
+        Region::zero(),
     );
 
     // Refcount value
@@ -529,6 +558,7 @@ pub fn refcount_resetref_proc_body<'a>(
         rc_expr,
         root.layout_isize,
         root.arena.alloc(refcount_1_stmt),
+        Region::zero(),
     );
 
     let mask_lower_bits = match layout_interner.get_repr(layout) {
@@ -562,8 +592,14 @@ fn rc_return_stmt<'a>(
         Stmt::Jump(jp_decref, &[])
     } else {
         let unit = root.create_symbol(ident_ids, "unit");
-        let ret_stmt = root.arena.alloc(Stmt::Ret(unit));
-        Stmt::Let(unit, Expr::Struct(&[]), LAYOUT_UNIT, ret_stmt)
+        let ret_stmt = root.arena.alloc(Stmt::Ret(unit, Region::zero()));
+        Stmt::Let(
+            unit,
+            Expr::Struct(&[]),
+            LAYOUT_UNIT,
+            ret_stmt,
+            Region::zero(),
+        )
     }
 }
 
@@ -600,8 +636,15 @@ fn rc_ptr_from_data_ptr_help<'a>(
         },
         arguments: root.arena.alloc([structure]),
     });
-    let clear_tag_id_stmt =
-        |next| Stmt::Let(cleared_sym, clear_tag_id_expr, root.layout_isize, next);
+    let clear_tag_id_stmt = |next| {
+        Stmt::Let(
+            cleared_sym,
+            clear_tag_id_expr,
+            root.layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     // Typecast the structure pointer to an integer
     // Backends expect a number Layout to choose the right "subtract" instruction
@@ -612,14 +655,30 @@ fn rc_ptr_from_data_ptr_help<'a>(
         },
         arguments: root.arena.alloc([cleared_sym]),
     });
-    let as_int_stmt = |next| Stmt::Let(addr_sym, as_int_expr, root.layout_isize, next);
+    let as_int_stmt = |next| {
+        Stmt::Let(
+            addr_sym,
+            as_int_expr,
+            root.layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     // Pointer size constant
     let ptr_size_sym = root.create_symbol(ident_ids, "ptr_size");
     let ptr_size_expr = Expr::Literal(Literal::Int(
         (root.target.ptr_width() as i128).to_ne_bytes(),
     ));
-    let ptr_size_stmt = |next| Stmt::Let(ptr_size_sym, ptr_size_expr, root.layout_isize, next);
+    let ptr_size_stmt = |next| {
+        Stmt::Let(
+            ptr_size_sym,
+            ptr_size_expr,
+            root.layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     // Refcount address
     let rc_addr_sym = root.create_symbol(ident_ids, "rc_addr");
@@ -630,7 +689,15 @@ fn rc_ptr_from_data_ptr_help<'a>(
         },
         arguments: root.arena.alloc([addr_sym, ptr_size_sym]),
     });
-    let sub_stmt = |next| Stmt::Let(rc_addr_sym, sub_expr, Layout::usize(root.target), next);
+    let sub_stmt = |next| {
+        Stmt::Let(
+            rc_addr_sym,
+            sub_expr,
+            Layout::usize(root.target),
+            next,
+            Region::zero(),
+        )
+    };
 
     // Typecast the refcount address from integer to pointer
     let cast_expr = Expr::Call(Call {
@@ -640,7 +707,7 @@ fn rc_ptr_from_data_ptr_help<'a>(
         },
         arguments: root.arena.alloc([rc_addr_sym]),
     });
-    let cast_stmt = |next| Stmt::Let(rc_ptr_sym, cast_expr, recursion_ptr, next);
+    let cast_stmt = |next| Stmt::Let(rc_ptr_sym, cast_expr, recursion_ptr, next, Region::zero());
 
     let body = as_int_stmt(root.arena.alloc(
         //
@@ -693,7 +760,13 @@ fn modify_refcount<'a>(
                 },
                 arguments: root.arena.alloc([ptr, Symbol::ARG_2]),
             });
-            Stmt::Let(zig_call_result, zig_call_expr, LAYOUT_UNIT, following)
+            Stmt::Let(
+                zig_call_result,
+                zig_call_expr,
+                LAYOUT_UNIT,
+                following,
+                Region::zero(),
+            )
         }
 
         HelperOp::Dec | HelperOp::DecRef(_) => {
@@ -706,7 +779,15 @@ fn modify_refcount<'a>(
 
             let alignment_sym = root.create_symbol(ident_ids, "alignment");
             let alignment_expr = Expr::Literal(Literal::Int((alignment as i128).to_ne_bytes()));
-            let alignment_stmt = |next| Stmt::Let(alignment_sym, alignment_expr, LAYOUT_U32, next);
+            let alignment_stmt = |next| {
+                Stmt::Let(
+                    alignment_sym,
+                    alignment_expr,
+                    LAYOUT_U32,
+                    next,
+                    Region::zero(),
+                )
+            };
 
             let zig_call_expr = Expr::Call(Call {
                 call_type: CallType::LowLevel {
@@ -715,7 +796,13 @@ fn modify_refcount<'a>(
                 },
                 arguments: root.arena.alloc([ptr, alignment_sym]),
             });
-            let zig_call_stmt = Stmt::Let(zig_call_result, zig_call_expr, LAYOUT_UNIT, following);
+            let zig_call_stmt = Stmt::Let(
+                zig_call_result,
+                zig_call_expr,
+                LAYOUT_UNIT,
+                following,
+                Region::zero(),
+            );
 
             alignment_stmt(root.arena.alloc(
                 //
@@ -745,12 +832,20 @@ fn refcount_str<'a>(
         field_layouts,
         structure: string,
     };
-    let last_word_stmt = |next| Stmt::Let(last_word, last_word_expr, layout_isize, next);
+    let last_word_stmt = |next| {
+        Stmt::Let(
+            last_word,
+            last_word_expr,
+            layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     // Zero
     let zero = root.create_symbol(ident_ids, "zero");
     let zero_expr = Expr::Literal(Literal::Int(0i128.to_ne_bytes()));
-    let zero_stmt = |next| Stmt::Let(zero, zero_expr, layout_isize, next);
+    let zero_stmt = |next| Stmt::Let(zero, zero_expr, layout_isize, next, Region::zero());
 
     // is_big_str = (last_word >= 0);
     // Treat last word as isize so that the small string flag is the same as the sign bit
@@ -778,7 +873,7 @@ fn refcount_str<'a>(
         field_layouts,
         structure: string,
     };
-    let length_stmt = |next| Stmt::Let(length, length_expr, layout_isize, next);
+    let length_stmt = |next| Stmt::Let(length, length_expr, layout_isize, next, Region::zero());
 
     let alignment = root.target.ptr_width() as u32;
 
@@ -795,7 +890,7 @@ fn refcount_str<'a>(
 
     let one = root.create_symbol(ident_ids, "one");
     let one_expr = Expr::Literal(Literal::Int(1i128.to_ne_bytes()));
-    let one_stmt = |next| Stmt::Let(one, one_expr, layout_isize, next);
+    let one_stmt = |next| Stmt::Let(one, one_expr, layout_isize, next, Region::zero());
 
     let data_ptr_int = root.create_symbol(ident_ids, "data_ptr_int");
     let data_ptr_int_stmt = |next| {
@@ -847,7 +942,15 @@ fn refcount_str<'a>(
         field_layouts,
         structure: string,
     };
-    let string_chars_stmt = |next| Stmt::Let(string_chars, string_chars_expr, layout_isize, next);
+    let string_chars_stmt = |next| {
+        Stmt::Let(
+            string_chars,
+            string_chars_expr,
+            layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     let modify_refcount_stmt = modify_refcount(
         root,
@@ -931,7 +1034,7 @@ fn refcount_list<'a>(
     // let zero = 0
     let zero = root.create_symbol(ident_ids, "zero");
     let zero_expr = Expr::Literal(Literal::Int(0i128.to_ne_bytes()));
-    let zero_stmt = |next| Stmt::Let(zero, zero_expr, layout_isize, next);
+    let zero_stmt = |next| Stmt::Let(zero, zero_expr, layout_isize, next, Region::zero());
 
     // let is_empty = lowlevel Eq len zero
     let is_empty = root.create_symbol(ident_ids, "is_empty");
@@ -949,7 +1052,8 @@ fn refcount_list<'a>(
         field_layouts: list_field_layouts,
         structure,
     };
-    let capacity_stmt = |next| Stmt::Let(capacity, capacity_expr, layout_isize, next);
+    let capacity_stmt =
+        |next| Stmt::Let(capacity, capacity_expr, layout_isize, next, Region::zero());
 
     // let is_slice = lowlevel NumLt capacity zero
     let is_slice = root.create_symbol(ident_ids, "is_slice");
@@ -967,7 +1071,15 @@ fn refcount_list<'a>(
         field_layouts: list_field_layouts,
         structure,
     };
-    let first_element_stmt = |next| Stmt::Let(first_element, first_element_expr, ptr_layout, next);
+    let first_element_stmt = |next| {
+        Stmt::Let(
+            first_element,
+            first_element_expr,
+            ptr_layout,
+            next,
+            Region::zero(),
+        )
+    };
 
     let jp_elements = JoinPointId(root.create_symbol(ident_ids, "jp_elements"));
     let data_pointer = root.create_symbol(ident_ids, "data_pointer");
@@ -985,7 +1097,7 @@ fn refcount_list<'a>(
     // one = 1
     let one = root.create_symbol(ident_ids, "one");
     let one_expr = Expr::Literal(Literal::Int(1i128.to_ne_bytes()));
-    let one_stmt = |next| Stmt::Let(one, one_expr, layout_isize, next);
+    let one_stmt = |next| Stmt::Let(one, one_expr, layout_isize, next, Region::zero());
 
     let slice_data_pointer = root.create_symbol(ident_ids, "slice_data_pointer");
     let slice_data_pointer_stmt = move |next| {
@@ -1135,7 +1247,15 @@ fn refcount_list_elems<'a>(
     let elem_size_expr = Expr::Literal(Literal::Int(
         (layout_interner.stack_size(elem_layout) as i128).to_ne_bytes(),
     ));
-    let elem_size_stmt = |next| Stmt::Let(elem_size, elem_size_expr, layout_isize, next);
+    let elem_size_stmt = |next| {
+        Stmt::Let(
+            elem_size,
+            elem_size_expr,
+            layout_isize,
+            next,
+            Region::zero(),
+        )
+    };
 
     // let list_size = len * size
     let list_size = root.create_symbol(ident_ids, "list_size");
@@ -1177,7 +1297,7 @@ fn refcount_list_elems<'a>(
     // Dereference the pointer to get the current element
     let elem = root.create_symbol(ident_ids, "elem");
     let elem_expr = Expr::ptr_load(arena.alloc(ptr_symbol));
-    let elem_stmt = |next| Stmt::Let(elem, elem_expr, elem_layout, next);
+    let elem_stmt = |next| Stmt::Let(elem, elem_expr, elem_layout, next, Region::zero());
 
     //
     // Modify element refcount
@@ -1188,7 +1308,15 @@ fn refcount_list_elems<'a>(
     let mod_elem_expr = root
         .call_specialized_op(ident_ids, ctx, layout_interner, elem_layout, mod_elem_args)
         .unwrap();
-    let mod_elem_stmt = |next| Stmt::Let(mod_elem_unit, mod_elem_expr, LAYOUT_UNIT, next);
+    let mod_elem_stmt = |next| {
+        Stmt::Let(
+            mod_elem_unit,
+            mod_elem_expr,
+            LAYOUT_UNIT,
+            next,
+            Region::zero(),
+        )
+    };
 
     //
     // Next loop iteration
@@ -1282,14 +1410,22 @@ fn refcount_struct<'a>(
                 field_layouts,
                 structure,
             };
-            let field_val_stmt = |next| Stmt::Let(field_val, field_val_expr, *field_layout, next);
+            let field_val_stmt = |next| {
+                Stmt::Let(
+                    field_val,
+                    field_val_expr,
+                    *field_layout,
+                    next,
+                    Region::zero(),
+                )
+            };
 
             let mod_unit = root.create_symbol(ident_ids, &format!("mod_field_{i}"));
             let mod_args = refcount_args(root, ctx, field_val);
             let mod_expr = root
                 .call_specialized_op(ident_ids, ctx, layout_interner, *field_layout, mod_args)
                 .unwrap();
-            let mod_stmt = |next| Stmt::Let(mod_unit, mod_expr, LAYOUT_UNIT, next);
+            let mod_stmt = |next| Stmt::Let(mod_unit, mod_expr, LAYOUT_UNIT, next, Region::zero());
 
             stmt = field_val_stmt(root.arena.alloc(
                 //
@@ -1469,6 +1605,7 @@ fn refcount_union_nonrec<'a>(
             },
             tag_id_layout,
             next,
+            Region::zero(),
         )
     };
 
@@ -1557,6 +1694,7 @@ fn refcount_union_contents<'a>(
         branches: tag_branches.into_bump_slice(),
         default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
         ret_layout: LAYOUT_UNIT,
+        region: Region::zero(),
     };
 
     if let UnionLayout::NonRecursive(_) = union_layout {
@@ -1617,6 +1755,7 @@ fn refcount_union_rec<'a>(
             },
             tag_id_layout,
             next,
+            Region::zero(),
         )
     };
 
@@ -1691,6 +1830,7 @@ fn refcount_union_tailrec<'a>(
             },
             tag_id_layout,
             next,
+            Region::zero(),
         )
     };
 
@@ -1709,6 +1849,7 @@ fn refcount_union_tailrec<'a>(
             branches: root.arena.alloc([(0, BranchInfo::None, exit_stmt)]),
             default_branch: (BranchInfo::None, root.arena.alloc(jump_to_loop)),
             ret_layout: LAYOUT_UNIT,
+            region: Region::zero(),
         };
         let loop_or_exit_based_on_next_addr = {
             let_lowlevel(
@@ -1767,14 +1908,21 @@ fn refcount_union_tailrec<'a>(
                             };
                             let jump_params = root.arena.alloc([field_val]);
                             let jump = root.arena.alloc(Stmt::Jump(jp_modify_union, jump_params));
-                            tail_stmt = Some(Stmt::Let(field_val, field_val_expr, *field, jump));
+                            tail_stmt = Some(Stmt::Let(
+                                field_val,
+                                field_val_expr,
+                                *field,
+                                jump,
+                                Region::zero(),
+                            ));
                         }
                     }
 
                     (filtered.into_bump_slice(), tail_stmt.unwrap())
                 } else {
                     let null = root.create_symbol(ident_ids, "null");
-                    let null_stmt = |next| Stmt::Let(null, Expr::NullPointer, layout, next);
+                    let null_stmt =
+                        |next| Stmt::Let(null, Expr::NullPointer, layout, next, Region::zero());
 
                     let tail_stmt = null_stmt(root.arena.alloc(
                         //
@@ -1814,6 +1962,7 @@ fn refcount_union_tailrec<'a>(
             branches: tag_branches.into_bump_slice(),
             default_branch: (BranchInfo::None, root.arena.alloc(default_stmt)),
             ret_layout: LAYOUT_UNIT,
+            region: Region::zero(),
         };
 
         let is_unique = root.create_symbol(ident_ids, "is_unique");
@@ -1827,6 +1976,7 @@ fn refcount_union_tailrec<'a>(
                 jp_modify_union,
                 root.arena.alloc([null_pointer]),
             )),
+            Region::zero(),
         );
 
         let switch_with_unique_check = Stmt::if_then_else(
@@ -1901,14 +2051,22 @@ fn refcount_tag_fields<'a>(
                 index: *i as u64,
                 structure,
             };
-            let field_val_stmt = |next| Stmt::Let(field_val, field_val_expr, *field_layout, next);
+            let field_val_stmt = |next| {
+                Stmt::Let(
+                    field_val,
+                    field_val_expr,
+                    *field_layout,
+                    next,
+                    Region::zero(),
+                )
+            };
 
             let mod_unit = root.create_symbol(ident_ids, &format!("mod_field_{tag_id}_{i}"));
             let mod_args = refcount_args(root, ctx, field_val);
             let mod_expr = root
                 .call_specialized_op(ident_ids, ctx, layout_interner, *field_layout, mod_args)
                 .unwrap();
-            let mod_stmt = |next| Stmt::Let(mod_unit, mod_expr, LAYOUT_UNIT, next);
+            let mod_stmt = |next| Stmt::Let(mod_unit, mod_expr, LAYOUT_UNIT, next, Region::zero());
 
             stmt = field_val_stmt(root.arena.alloc(
                 //
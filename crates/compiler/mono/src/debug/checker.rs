@@ -295,7 +295,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
         self.line += 1;
 
         match body {
-            Stmt::Let(x, e, x_layout, rest) => {
+            Stmt::Let(x, e, x_layout, rest, _) => {
                 if let Some(e_layout) = self.check_expr(e, *x_layout) {
                     if self.not_equiv(e_layout, *x_layout) {
                         self.problem(ProblemKind::SymbolDefMismatch {
@@ -314,6 +314,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 branches,
                 default_branch,
                 ret_layout: _,
+                region: _,
             } => {
                 self.check_sym_layout(*cond_symbol, *cond_layout, UseKind::SwitchCond);
                 let layout = self.resolve(*cond_layout);
@@ -337,7 +338,7 @@ impl<'a, 'r> Ctx<'a, 'r> {
                 let (_branch_info, default_branch) = default_branch;
                 self.in_scope(|ctx| ctx.check_stmt(default_branch));
             }
-            &Stmt::Ret(sym) => self.check_sym_layout(sym, self.ret_layout, UseKind::Ret),
+            &Stmt::Ret(sym, _) => self.check_sym_layout(sym, self.ret_layout, UseKind::Ret),
             &Stmt::Refcounting(rc, rest) => {
                 self.check_modify_rc(rc);
                 self.check_stmt(rest);
@@ -0,0 +1,102 @@
+//! The source files of the modules being compiled, for the backends that emit debug info.
+//!
+//! Procs and statements carry the [Region] of the source they were lowered from. This is where
+//! those regions get turned into lines and columns of the `.roc` files they came from.
+use std::path::{Path, PathBuf};
+
+use roc_collections::all::MutMap;
+use roc_module::symbol::ModuleId;
+use roc_region::all::{LineInfo, Region};
+
+#[derive(Debug)]
+pub struct DebugSources {
+    files: MutMap<ModuleId, SourceFile>,
+}
+
+#[derive(Debug)]
+struct SourceFile {
+    path: PathBuf,
+    src: Box<str>,
+    line_info: LineInfo,
+}
+
+/// Where some code is in the source. Lines and columns are 0-based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub module_id: ModuleId,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl DebugSources {
+    pub fn new(sources: &MutMap<ModuleId, (PathBuf, Box<str>)>) -> Self {
+        let files = sources
+            .iter()
+            .map(|(module_id, (path, src))| {
+                let file = SourceFile {
+                    path: path.clone(),
+                    src: src.clone(),
+                    line_info: LineInfo::new(src),
+                };
+
+                (*module_id, file)
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// Where `region` of `module_id` starts. [None] if we don't have the source of that module,
+    /// or for [Region::zero], which the compiler gives to the code it made up.
+    pub fn location(&self, module_id: ModuleId, region: Region) -> Option<SourceLocation> {
+        if region == Region::zero() {
+            return None;
+        }
+
+        let file = self.files.get(&module_id)?;
+        let line_column = file.line_info.convert_pos(region.start());
+
+        Some(SourceLocation {
+            module_id,
+            line: line_column.line,
+            column: line_column.column,
+        })
+    }
+
+    /// The path and contents of a module's source file
+    pub fn file(&self, module_id: ModuleId) -> Option<(&Path, &str)> {
+        self.files
+            .get(&module_id)
+            .map(|file| (file.path.as_path(), &*file.src))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use roc_region::all::Position;
+
+    #[test]
+    fn locations_of_regions() {
+        let src = "interface List\n\nmap = \\list, f ->\n    x = 1\n    x\n";
+        let mut sources = MutMap::default();
+        sources.insert(ModuleId::LIST, (PathBuf::from("List.roc"), src.into()));
+        let debug_sources = DebugSources::new(&sources);
+
+        let at = |offset: usize| {
+            let pos = Position::new(offset as u32);
+            Region::new(pos, pos)
+        };
+
+        assert_eq!(
+            debug_sources.location(ModuleId::LIST, at(src.find('x').unwrap())),
+            Some(SourceLocation {
+                module_id: ModuleId::LIST,
+                line: 3,
+                column: 4,
+            })
+        );
+        assert_eq!(debug_sources.location(ModuleId::LIST, Region::zero()), None);
+        assert_eq!(debug_sources.location(ModuleId::STR, at(0)), None);
+    }
+}
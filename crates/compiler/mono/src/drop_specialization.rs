@@ -15,6 +15,7 @@ use bumpalo::collections::CollectIn;
 
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 
 use crate::ir::{
     BranchInfo, Call, CallType, ErasedField, Expr, JoinPointId, ListLiteralElement, Literal,
@@ -70,7 +71,7 @@ fn specialize_drops_stmt<'a, 'i>(
     stmt: &Stmt<'a>,
 ) -> &'a Stmt<'a> {
     match stmt {
-        Stmt::Let(binding, expr @ Expr::Call(call), layout, continuation) => {
+        Stmt::Let(binding, expr @ Expr::Call(call), layout, continuation, region) => {
             environment.add_symbol_layout(*binding, *layout);
 
             macro_rules! alloc_let_with_continuation {
@@ -82,7 +83,13 @@ fn specialize_drops_stmt<'a, 'i>(
                         $environment,
                         continuation,
                     );
-                    arena.alloc(Stmt::Let(*binding, expr.clone(), *layout, new_continuation))
+                    arena.alloc(Stmt::Let(
+                        *binding,
+                        expr.clone(),
+                        *layout,
+                        new_continuation,
+                        *region,
+                    ))
                 }};
             }
 
@@ -143,7 +150,7 @@ fn specialize_drops_stmt<'a, 'i>(
                 }
             }
         }
-        Stmt::Let(_, _, _, _) => {
+        Stmt::Let(_, _, _, _, _) => {
             use Expr::*;
 
             // to prevent stack overflows, try to use an explicit stack to accumulate a bunch of
@@ -152,7 +159,7 @@ fn specialize_drops_stmt<'a, 'i>(
 
             let mut stmt = stmt;
 
-            while let Stmt::Let(binding, expr, layout, continuation) = stmt {
+            while let Stmt::Let(binding, expr, layout, continuation, region) = stmt {
                 environment.add_symbol_layout(*binding, *layout);
 
                 // update the environment based on the expr
@@ -253,7 +260,7 @@ fn specialize_drops_stmt<'a, 'i>(
                 }
 
                 // now store the let binding for later
-                stack.push((*binding, expr.clone(), *layout));
+                stack.push((*binding, expr.clone(), *layout, *region));
 
                 // and "recurse" down the statement chain
                 stmt = continuation;
@@ -261,7 +268,9 @@ fn specialize_drops_stmt<'a, 'i>(
 
             stack.into_iter().rev().fold(
                 specialize_drops_stmt(arena, layout_interner, ident_ids, environment, stmt),
-                |acc, (binding, expr, layout)| arena.alloc(Stmt::Let(binding, expr, layout, acc)),
+                |acc, (binding, expr, layout, region)| {
+                    arena.alloc(Stmt::Let(binding, expr, layout, acc, region))
+                },
             )
         }
         Stmt::Switch {
@@ -270,6 +279,7 @@ fn specialize_drops_stmt<'a, 'i>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             macro_rules! insert_branch_info {
                 ($branch_env:expr,$info:expr ) => {
@@ -434,9 +444,10 @@ fn specialize_drops_stmt<'a, 'i>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
-        Stmt::Ret(symbol) => arena.alloc(Stmt::Ret(*symbol)),
+        Stmt::Ret(symbol, region) => arena.alloc(Stmt::Ret(*symbol, *region)),
         Stmt::Refcounting(rc, continuation) => match rc {
             ModifyRc::Inc(symbol, count) => {
                 let inc_before = environment.incremented_symbols.contains(symbol);
@@ -842,6 +853,7 @@ fn specialize_struct<'a, 'i>(
                                     ModifyRc::Dec(field_symbol),
                                     new_continuation,
                                 )),
+                                Region::zero(),
                             ))
                         }
                     };
@@ -980,6 +992,7 @@ fn specialize_union<'a, 'i>(
                                                 field_val_expr,
                                                 layout_interner.chase_recursive_in(*field_layout),
                                                 rc(arena, field_symbol, new_continuation),
+                                                Region::zero(),
                                             ))
                                         }
                                         None => new_continuation,
@@ -1176,6 +1189,7 @@ fn specialize_list<'a, 'i>(
                                     }),
                                     item_layout,
                                     dec,
+                                    Region::zero(),
                                 ));
 
                                 newer_continuation = arena.alloc(Stmt::Let(
@@ -1183,6 +1197,7 @@ fn specialize_list<'a, 'i>(
                                     Expr::Literal(Literal::Int(i128::to_ne_bytes(i as i128))),
                                     Layout::isize(layout_interner.target()),
                                     index,
+                                    Region::zero(),
                                 ));
                             }
                         };
@@ -1282,7 +1297,7 @@ where
 {
     match continutation {
         // The continuation is a single stmt. So we can insert it inline and skip creating a joinpoint.
-        Stmt::Ret(_) | Stmt::Jump(_, _) => {
+        Stmt::Ret(..) | Stmt::Jump(_, _) => {
             let u = unique(layout_interner, ident_ids, continutation);
             let n = not_unique(layout_interner, ident_ids, continutation);
 
@@ -1293,6 +1308,7 @@ where
                     branches: &*arena.alloc([(1, BranchInfo::None, u.clone())]),
                     default_branch: (BranchInfo::None, n),
                     ret_layout: environment.layout,
+                    region: Region::zero(),
                 })
             };
 
@@ -1327,6 +1343,7 @@ where
                         n,
                     ),
                     ret_layout: environment.layout,
+                    region: Region::zero(),
                 })
             };
 
@@ -1362,6 +1379,7 @@ fn unique_symbol<'a, 'i>(
         }),
         Layout::BOOL,
         continuation(is_unique),
+        Region::zero(),
     ))
 }
 
@@ -13,6 +13,7 @@ use roc_collections::{all::WyHash, MutMap, MutSet};
 use roc_error_macros::internal_error;
 use roc_module::low_level::LowLevel;
 use roc_module::{low_level::LowLevelWrapperType, symbol::Symbol};
+use roc_region::all::Region;
 
 use crate::ir::ErasedField;
 use crate::{
@@ -144,6 +145,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                 _expr,
                 layout,
                 continuation,
+                _region,
             ) => {
                 self.insert_symbol_layout_rc_type(binding, layout);
                 self.insert_symbols_rc_type_stmt(continuation);
@@ -155,6 +157,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                 branches,
                 default_branch,
                 ret_layout: _,
+                region: _,
             } => {
                 // Collect the types of the symbols in all the branches, including the default one.
                 for (info, stmt) in branches
@@ -174,7 +177,7 @@ impl<'a, 'i> SymbolRcTypesEnv<'a, 'i> {
                     self.insert_symbols_rc_type_stmt(stmt);
                 }
             }
-            Stmt::Ret(_symbol) => {
+            Stmt::Ret(_symbol, _region) => {
                 // The return does not introduce new symbols.
             }
             Stmt::Refcounting(_, _) => unreachable!(
@@ -455,36 +458,36 @@ fn insert_refcount_operations_stmt<'v, 'a>(
 ) -> &'a Stmt<'a> {
     match &stmt {
         // The expression borrows the values owned (used) by the continuation.
-        Stmt::Let(_, _, _, _) => {
+        Stmt::Let(..) => {
             // Collect all the subsequent let bindings (including the current one).
             // To prevent the stack from overflowing when there are many let bindings.
-            let mut triples = vec![];
+            let mut quadruples = vec![];
             let mut current_stmt = stmt;
-            while let Stmt::Let(binding, expr, layout, next_stmt) = current_stmt {
-                triples.push((binding, expr, layout));
+            while let Stmt::Let(binding, expr, layout, next_stmt, region) = current_stmt {
+                quadruples.push((binding, expr, layout, region));
                 current_stmt = next_stmt
             }
 
             debug_assert!(
-                !triples.is_empty(),
+                !quadruples.is_empty(),
                 "Expected at least one let binding in the vector"
             );
             debug_assert!(
-                !matches!(current_stmt, Stmt::Let(_, _, _, _)),
+                !matches!(current_stmt, Stmt::Let(..)),
                 "All let bindings should be in the vector"
             );
 
-            for (binding, _, _) in triples.iter() {
+            for (binding, _, _, _) in quadruples.iter() {
                 environment.add_symbol(**binding); // Add the bound symbol to the environment. As it can be used in the continuation.
             }
 
-            triples
+            quadruples
                 .into_iter()
                 .rev()
                 // First evaluate the continuation and let it consume it's free symbols.
                 .fold(
                     insert_refcount_operations_stmt(arena, environment, current_stmt),
-                    |new_stmt, (binding, expr, layout)| {
+                    |new_stmt, (binding, expr, layout, region)| {
                         // If the binding is still owned in the environment, it is not used in the continuation and we can drop it right away.
                         let new_stmt_without_unused = match environment
                             .get_symbol_ownership(binding)
@@ -502,6 +505,7 @@ fn insert_refcount_operations_stmt<'v, 'a>(
                             binding,
                             expr,
                             layout,
+                            *region,
                             new_stmt_without_unused,
                         )
                     },
@@ -513,6 +517,7 @@ fn insert_refcount_operations_stmt<'v, 'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let new_branches = branches
                 .iter()
@@ -632,12 +637,13 @@ fn insert_refcount_operations_stmt<'v, 'a>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
-        Stmt::Ret(s) => {
+        Stmt::Ret(s, region) => {
             let ownership = environment.consume_symbol(s);
             debug_assert!(matches!(ownership, None | Some(Ownership::Owned))); // the return value should be owned or not reference counted at the return.
-            return arena.alloc(Stmt::Ret(*s));
+            return arena.alloc(Stmt::Ret(*s, *region));
         }
         Stmt::Refcounting(_, _) => unreachable!("refcounting should not be in the AST yet"),
         Stmt::Expect {
@@ -859,6 +865,7 @@ fn insert_refcount_operations_binding<'a>(
     binding: &Symbol,
     expr: &Expr<'a>,
     layout: &InLayout<'a>,
+    region: Region,
     stmt: &'a Stmt<'a>,
 ) -> &'a Stmt<'a> {
     macro_rules! dec_borrowed {
@@ -875,7 +882,7 @@ fn insert_refcount_operations_binding<'a>(
 
     macro_rules! new_let {
         ($stmt:expr) => {
-            arena.alloc(Stmt::Let(*binding, expr.clone(), *layout, $stmt))
+            arena.alloc(Stmt::Let(*binding, expr.clone(), *layout, $stmt, region))
         };
     }
 
@@ -71,14 +71,14 @@ pub fn pretty_print_ir_symbols() -> bool {
 // if it went up, maybe check that the change is really required
 roc_error_macros::assert_sizeof_wasm!(Literal, 24);
 roc_error_macros::assert_sizeof_wasm!(Expr, 48);
-roc_error_macros::assert_sizeof_wasm!(Stmt, 64);
+roc_error_macros::assert_sizeof_wasm!(Stmt, 72);
 roc_error_macros::assert_sizeof_wasm!(ProcLayout, 20);
 roc_error_macros::assert_sizeof_wasm!(Call, 44);
 roc_error_macros::assert_sizeof_wasm!(CallType, 36);
 
 roc_error_macros::assert_sizeof_non_wasm!(Literal, 3 * 8);
 roc_error_macros::assert_sizeof_non_wasm!(Expr, 9 * 8);
-roc_error_macros::assert_sizeof_non_wasm!(Stmt, 12 * 8);
+roc_error_macros::assert_sizeof_non_wasm!(Stmt, 13 * 8);
 roc_error_macros::assert_sizeof_non_wasm!(ProcLayout, 5 * 8);
 roc_error_macros::assert_sizeof_non_wasm!(Call, 9 * 8);
 roc_error_macros::assert_sizeof_non_wasm!(CallType, 7 * 8);
@@ -90,6 +90,7 @@ fn runtime_error<'a>(env: &mut Env<'a, '_>, msg: &'a str) -> Stmt<'a> {
         Expr::Literal(Literal::Str(msg)),
         Layout::STR,
         env.arena.alloc(Stmt::Crash(sym, CrashTag::Roc)),
+        env.region,
     )
 }
 
@@ -210,6 +211,8 @@ pub struct PartialProc<'a> {
     pub body: roc_can::expr::Expr,
     pub body_var: Variable,
     pub is_self_recursive: bool,
+    /// Where the function is in the source, from its first argument to the end of its body.
+    pub region: Region,
 }
 
 impl<'a> PartialProc<'a> {
@@ -224,6 +227,7 @@ impl<'a> PartialProc<'a> {
         ret_var: Variable,
     ) -> PartialProc<'a> {
         let number_of_arguments = loc_args.len();
+        let region = function_region(&loc_args, &loc_body);
 
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
             Ok((_, pattern_symbols, body)) => {
@@ -239,6 +243,7 @@ impl<'a> PartialProc<'a> {
                     body: body.value,
                     body_var: ret_var,
                     is_self_recursive,
+                    region,
                 }
             }
 
@@ -256,6 +261,7 @@ impl<'a> PartialProc<'a> {
                     body: roc_can::expr::Expr::RuntimeError(error.value),
                     body_var: ret_var,
                     is_self_recursive: false,
+                    region,
                 }
             }
         }
@@ -308,6 +314,9 @@ pub struct Proc<'a> {
     pub ret_layout: InLayout<'a>,
     pub is_self_recursive: SelfRecursive,
     pub is_erased: bool,
+    /// Where the proc was defined in the source of its module.
+    /// [Region::zero] for the procs the compiler generates.
+    pub region: Region,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -1047,6 +1056,8 @@ impl<'a> Procs<'a> {
             _ => false,
         };
 
+        let region = function_region(&loc_args, &loc_body);
+
         match patterns_to_when(env, loc_args, ret_var, loc_body) {
             Ok((_, pattern_symbols, body)) => {
                 // an anonymous closure. These will always be specialized already
@@ -1099,6 +1110,7 @@ impl<'a> Procs<'a> {
                                         body: body.value,
                                         body_var: ret_var,
                                         is_self_recursive,
+                                        region,
                                     };
 
                                     self.partial_procs.insert(name.name(), partial_proc);
@@ -1127,6 +1139,7 @@ impl<'a> Procs<'a> {
                                     body: body.value,
                                     body_var: ret_var,
                                     is_self_recursive,
+                                    region,
                                 };
 
                                 self.partial_procs.insert(name.name(), partial_proc)
@@ -1362,9 +1375,23 @@ pub struct Env<'a, 'i> {
     pub exposed_by_module: &'i ExposedByModule,
     pub derived_module: &'i SharedDerivedModule,
     pub struct_indexing: UsageTrackingMap<(Symbol, u64), Symbol>,
+    /// The region of the source that the statements being built come from.
+    pub region: Region,
 }
 
 impl<'a, 'i> Env<'a, 'i> {
+    /// Build the statements that follow from the source at `region`, and return the region to
+    /// restore afterwards. Code the compiler rewrote has [Region::zero], which keeps the current one.
+    fn enter_region(&mut self, region: Region) -> Region {
+        let previous = self.region;
+
+        if region != Region::zero() {
+            self.region = region;
+        }
+
+        previous
+    }
+
     pub fn unique_symbol(&mut self) -> Symbol {
         let ident_id = self.ident_ids.gen_unique();
 
@@ -1487,6 +1514,7 @@ pub fn cond<'a>(
         ret_layout,
         branches,
         default_branch,
+        region: env.region,
     }
 }
 
@@ -1495,9 +1523,12 @@ pub type Stores<'a> = &'a [(Symbol, Layout<'a>, Expr<'a>)];
 /// The specialized type of a lookup. Represented as a type-variable.
 pub type LookupType = Variable;
 
+/// [Stmt::Let], [Stmt::Switch] and [Stmt::Ret] carry the region of the source they were made from,
+/// so backends can map the code they generate back to it. Statements the compiler makes up
+/// on its own have [Region::zero].
 #[derive(Clone, Debug, PartialEq)]
 pub enum Stmt<'a> {
-    Let(Symbol, Expr<'a>, InLayout<'a>, &'a Stmt<'a>),
+    Let(Symbol, Expr<'a>, InLayout<'a>, &'a Stmt<'a>, Region),
     Switch {
         /// This *must* stand for an integer, because Switch potentially compiles to a jump table.
         cond_symbol: Symbol,
@@ -1509,8 +1540,9 @@ pub enum Stmt<'a> {
         default_branch: (BranchInfo<'a>, &'a Stmt<'a>),
         /// Each branch must return a value of this type.
         ret_layout: InLayout<'a>,
+        region: Region,
     },
-    Ret(Symbol),
+    Ret(Symbol, Region),
     Refcounting(ModifyRc, &'a Stmt<'a>),
     Expect {
         condition: Symbol,
@@ -2232,7 +2264,7 @@ impl<'a> Stmt<'a> {
         use Stmt::*;
 
         match self {
-            Let(symbol, expr, layout, cont) => alloc
+            Let(symbol, expr, layout, cont, _) => alloc
                 .text("let ")
                 .append(symbol_to_doc(alloc, *symbol, pretty))
                 .append(" : ")
@@ -2279,7 +2311,7 @@ impl<'a> Stmt<'a> {
                 .append(alloc.hardline())
                 .append(remainder.to_doc(alloc, interner, pretty)),
 
-            Ret(symbol) => alloc
+            Ret(symbol, _) => alloc
                 .text("ret ")
                 .append(symbol_to_doc(alloc, *symbol, pretty))
                 .append(";"),
@@ -2421,6 +2453,7 @@ impl<'a> Stmt<'a> {
             branches: &*arena.alloc([then_branch]),
             default_branch: else_branch,
             ret_layout: return_layout,
+            region: Region::zero(),
         }
     }
 }
@@ -2433,15 +2466,46 @@ fn from_can_let<'a>(
     cont: Box<Loc<roc_can::expr::Expr>>,
     variable: Variable,
     opt_assigned_and_hole: Option<(Symbol, &'a Stmt<'a>)>,
+) -> Stmt<'a> {
+    let def_region = region_across([&def.loc_pattern.region, &def.loc_expr.region]);
+    let region = env.enter_region(def_region);
+
+    let stmt = from_can_let_help(
+        env,
+        procs,
+        layout_cache,
+        def,
+        cont,
+        variable,
+        opt_assigned_and_hole,
+    );
+
+    env.region = region;
+
+    stmt
+}
+
+fn from_can_let_help<'a>(
+    env: &mut Env<'a, '_>,
+    procs: &mut Procs<'a>,
+    layout_cache: &mut LayoutCache<'a>,
+    def: Box<roc_can::def::Def>,
+    cont: Box<Loc<roc_can::expr::Expr>>,
+    variable: Variable,
+    opt_assigned_and_hole: Option<(Symbol, &'a Stmt<'a>)>,
 ) -> Stmt<'a> {
     use roc_can::expr::Expr::*;
 
+    let cont_region = cont.region;
+
     macro_rules! lower_rest {
         ($variable:expr, $expr:expr) => {
             lower_rest!(env, procs, layout_cache, $variable, $expr)
         };
-        ($env:expr, $procs:expr, $layout_cache:expr, $variable:expr, $expr:expr) => {
-            match opt_assigned_and_hole {
+        ($env:expr, $procs:expr, $layout_cache:expr, $variable:expr, $expr:expr) => {{
+            let def_region = $env.enter_region(cont_region);
+
+            let stmt = match opt_assigned_and_hole {
                 None => from_can($env, $variable, $expr, $procs, $layout_cache),
                 Some((assigned, hole)) => with_hole(
                     $env,
@@ -2452,8 +2516,12 @@ fn from_can_let<'a>(
                     assigned,
                     hole,
                 ),
-            }
-        };
+            };
+
+            $env.region = def_region;
+
+            stmt
+        }};
     }
 
     if let roc_can::pattern::Pattern::Identifier(symbol) = &def.loc_pattern.value {
@@ -2714,6 +2782,7 @@ fn from_can_let<'a>(
                         Expr::Literal(literal.to_expr_literal()),
                         layout,
                         env.arena.alloc(stmt),
+                        env.region,
                     );
                 }
 
@@ -2773,6 +2842,28 @@ fn from_can_let<'a>(
     }
 }
 
+/// The region spanning all of these, leaving out the [Region::zero] of code the compiler made up.
+fn region_across<'r>(regions: impl IntoIterator<Item = &'r Region>) -> Region {
+    Region::across_all(
+        regions
+            .into_iter()
+            .filter(|region| **region != Region::zero()),
+    )
+}
+
+/// Where a function is in the source, from its first argument to the end of its body.
+fn function_region(
+    loc_args: &[(Variable, AnnotatedMark, Loc<roc_can::pattern::Pattern>)],
+    loc_body: &Loc<roc_can::expr::Expr>,
+) -> Region {
+    region_across(
+        loc_args
+            .iter()
+            .map(|(_, _, loc_pattern)| &loc_pattern.region)
+            .chain([&loc_body.region]),
+    )
+}
+
 /// turn record/tag patterns into a when expression, e.g.
 ///
 /// foo = \{ x } -> body
@@ -3317,6 +3408,7 @@ fn generate_runtime_error_function<'a>(
         ret_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased,
+        region: Region::zero(),
     }
 }
 
@@ -3400,7 +3492,7 @@ fn generate_host_exposed_function<'a>(
         }
         RawFunctionLayout::ZeroArgumentThunk(result) => {
             let assigned = env.unique_symbol();
-            let hole = env.arena.alloc(Stmt::Ret(assigned));
+            let hole = env.arena.alloc(Stmt::Ret(assigned, env.region));
             let forced = force_thunk(env, function_name, result, assigned, hole);
 
             let lambda_name = LambdaName::no_niche(function_name);
@@ -3412,6 +3504,7 @@ fn generate_host_exposed_function<'a>(
                 ret_layout: result,
                 is_self_recursive: SelfRecursive::NotSelfRecursive,
                 is_erased: false,
+                region: Region::zero(),
             };
 
             let top_level = ProcLayout::from_raw_named(env.arena, lambda_name, layout);
@@ -3453,7 +3546,7 @@ fn generate_host_exposed_lambda_set<'a>(
     // this should also be reflected in the TopLevel signature
     top_level_arguments.push(lambda_set_layout);
 
-    let hole = env.arena.alloc(Stmt::Ret(assigned));
+    let hole = env.arena.alloc(Stmt::Ret(assigned, env.region));
 
     let body = match_on_lambda_set(
         env,
@@ -3476,6 +3569,7 @@ fn generate_host_exposed_lambda_set<'a>(
         ret_layout: return_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased: false,
+        region: Region::zero(),
     };
 
     let top_level = ProcLayout::new(
@@ -3537,6 +3631,10 @@ fn specialize_proc_help<'a>(
 
     let body = partial_proc.body.clone();
     let body_var = partial_proc.body_var;
+    let region = partial_proc.region;
+
+    // Specializations can start while another one is being built, so put its region back after.
+    let outer_region = std::mem::replace(&mut env.region, region);
 
     let mut specialized_body = from_can(env, body_var, body, procs, layout_cache);
 
@@ -3570,6 +3668,7 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                region,
             }
         }
         SpecializedLayout::FunctionBody {
@@ -3669,6 +3768,7 @@ fn specialize_proc_help<'a>(
                                     expr,
                                     layout,
                                     env.arena.alloc(specialized_body),
+                                    env.region,
                                 );
 
                                 // the same symbol may be used where
@@ -3733,6 +3833,7 @@ fn specialize_proc_help<'a>(
                                     expr,
                                     **layout,
                                     env.arena.alloc(specialized_body),
+                                    env.region,
                                 );
                             }
                         }
@@ -3785,10 +3886,13 @@ fn specialize_proc_help<'a>(
                 ret_layout,
                 is_self_recursive: recursivity,
                 is_erased,
+                region,
             }
         }
     };
 
+    env.region = outer_region;
+
     Ok(specialized_proc)
 }
 
@@ -4150,6 +4254,10 @@ impl<'a> ProcLayout<'a> {
     }
 }
 
+/// How many bytes of code a backend emitted for each specialization, for `--size-report`,
+/// or `None` if it emitted no code of its own for it (it was inlined or eliminated)
+pub type ProcSizes<'a> = std::vec::Vec<(Symbol, ProcLayout<'a>, Option<u64>)>;
+
 pub struct DbgProcLayout<'a, 'r, I: LayoutInterner<'a>> {
     layout: ProcLayout<'a>,
     interner: &'r I,
@@ -4325,6 +4433,7 @@ pub fn with_hole<'a>(
             Expr::Literal(Literal::Str(arena.alloc(string))),
             Layout::STR,
             hole,
+            env.region,
         ),
 
         IngestedFile(_, bytes, var) => {
@@ -4342,7 +4451,7 @@ pub fn with_hole<'a>(
                         elems: elements.into_bump_slice(),
                     };
 
-                    Stmt::Let(assigned, expr, interned, hole)
+                    Stmt::Let(assigned, expr, interned, hole, env.region)
                 }
                 LayoutRepr::Builtin(Builtin::Str) => Stmt::Let(
                     assigned,
@@ -4354,6 +4463,7 @@ pub fn with_hole<'a>(
                     )),
                     Layout::STR,
                     hole,
+                    env.region,
                 ),
                 _ => {
                     // This will not manifest as a real runtime error and is just returned to have a value here.
@@ -4372,6 +4482,7 @@ pub fn with_hole<'a>(
                 Expr::Literal(Literal::Int((character as i128).to_ne_bytes())),
                 layout,
                 hole,
+                env.region,
             )
         }
         LetNonRec(def, cont) => from_can_let(
@@ -4396,7 +4507,8 @@ pub fn with_hole<'a>(
                 unreachable!("recursive value does not have Identifier pattern")
             }
 
-            with_hole(
+            let region = env.enter_region(cont.region);
+            let stmt = with_hole(
                 env,
                 cont.value,
                 variable,
@@ -4404,7 +4516,10 @@ pub fn with_hole<'a>(
                 layout_cache,
                 assigned,
                 hole,
-            )
+            );
+            env.region = region;
+
+            stmt
         }
         Var(mut symbol, _) => {
             // If this symbol is a raw value, find the real name we gave to its specialized usage.
@@ -4594,7 +4709,7 @@ pub fn with_hole<'a>(
             )
         }
 
-        EmptyRecord => let_empty_struct(assigned, hole),
+        EmptyRecord => let_empty_struct(assigned, hole, env.region),
 
         Expect { .. } => unreachable!("I think this is unreachable"),
         ExpectFx { .. } => unreachable!("I think this is unreachable"),
@@ -4642,11 +4757,12 @@ pub fn with_hole<'a>(
                 (Ok(ret_layout), Ok(cond_layout)) => {
                     // if the hole is a return, then we don't need to merge the two
                     // branches together again, we can just immediately return
-                    let is_terminated = matches!(hole, Stmt::Ret(_));
+                    let is_terminated = matches!(hole, Stmt::Ret(..));
 
                     if is_terminated {
                         let terminator = hole;
 
+                        let region = env.enter_region(final_else.region);
                         let mut stmt = with_hole(
                             env,
                             final_else.value,
@@ -4656,10 +4772,12 @@ pub fn with_hole<'a>(
                             assigned,
                             terminator,
                         );
+                        env.region = region;
 
                         for (loc_cond, loc_then) in branches.into_iter().rev() {
                             let branching_symbol = env.unique_symbol();
 
+                            let region = env.enter_region(loc_then.region);
                             let then = with_hole(
                                 env,
                                 loc_then.value,
@@ -4669,6 +4787,7 @@ pub fn with_hole<'a>(
                                 assigned,
                                 terminator,
                             );
+                            env.region = region;
 
                             stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
 
@@ -4692,6 +4811,7 @@ pub fn with_hole<'a>(
                             .arena
                             .alloc(Stmt::Jump(id, env.arena.alloc([assigned_in_jump])));
 
+                        let region = env.enter_region(final_else.region);
                         let mut stmt = with_hole(
                             env,
                             final_else.value,
@@ -4701,6 +4821,7 @@ pub fn with_hole<'a>(
                             assigned_in_jump,
                             terminator,
                         );
+                        env.region = region;
 
                         for (loc_cond, loc_then) in branches.into_iter().rev() {
                             let branching_symbol = possible_reuse_symbol_or_specialize(
@@ -4711,6 +4832,7 @@ pub fn with_hole<'a>(
                                 cond_var,
                             );
 
+                            let region = env.enter_region(loc_then.region);
                             let then = with_hole(
                                 env,
                                 loc_then.value,
@@ -4720,6 +4842,7 @@ pub fn with_hole<'a>(
                                 assigned_in_jump,
                                 terminator,
                             );
+                            env.region = region;
 
                             stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
 
@@ -4831,14 +4954,14 @@ pub fn with_hole<'a>(
                     let expr = Expr::EmptyArray;
                     let list_layout = layout_cache
                         .put_in_direct_no_semantic(LayoutRepr::Builtin(Builtin::List(elem_layout)));
-                    Stmt::Let(assigned, expr, list_layout, hole)
+                    Stmt::Let(assigned, expr, list_layout, hole, env.region)
                 }
                 Err(LayoutProblem::UnresolvedTypeVar(_)) => {
                     let expr = Expr::EmptyArray;
                     let list_layout = layout_cache.put_in_direct_no_semantic(LayoutRepr::Builtin(
                         Builtin::List(Layout::VOID),
                     ));
-                    Stmt::Let(assigned, expr, list_layout, hole)
+                    Stmt::Let(assigned, expr, list_layout, hole, env.region)
                 }
                 Err(LayoutProblem::Erroneous) => panic!("list element is error type"),
             }
@@ -4887,7 +5010,7 @@ pub fn with_hole<'a>(
             let list_layout = layout_cache
                 .put_in_direct_no_semantic(LayoutRepr::Builtin(Builtin::List(elem_layout)));
 
-            let stmt = Stmt::Let(assigned, expr, list_layout, hole);
+            let stmt = Stmt::Let(assigned, expr, list_layout, hole, env.region);
 
             let iter = symbol_exprs
                 .into_iter()
@@ -5234,7 +5357,7 @@ pub fn with_hole<'a>(
                 stmt
             } else {
                 let expr = Expr::Struct(new_struct_symbols);
-                let mut stmt = Stmt::Let(assigned, expr, record_layout, hole);
+                let mut stmt = Stmt::Let(assigned, expr, record_layout, hole, env.region);
 
                 for (new_struct_symbol, what_to_do) in new_struct_symbols.iter().zip(fields) {
                     match what_to_do {
@@ -5277,7 +5400,8 @@ pub fn with_hole<'a>(
                             index: record_index.1,
                             field_layouts,
                         };
-                        stmt = Stmt::Let(symbol, access_expr, layout, arena.alloc(stmt));
+                        stmt =
+                            Stmt::Let(symbol, access_expr, layout, arena.alloc(stmt), env.region);
                     };
                 }
 
@@ -5839,7 +5963,7 @@ pub fn with_hole<'a>(
 
                     let expr = boxed::box_(arena.alloc(x), element_layout);
 
-                    Stmt::Let(assigned, expr, layout, hole)
+                    Stmt::Let(assigned, expr, layout, hole, env.region)
                 }
                 UnboxExpr => {
                     debug_assert_eq!(arg_symbols.len(), 1);
@@ -5847,7 +5971,7 @@ pub fn with_hole<'a>(
 
                     let expr = boxed::unbox(x, arena.alloc(layout));
 
-                    Stmt::Let(assigned, expr, layout, hole)
+                    Stmt::Let(assigned, expr, layout, hole, env.region)
                 }
                 _ => {
                     let call = self::Call {
@@ -5976,7 +6100,7 @@ fn compile_struct_like_access<'a>(
                 .from_var(env.arena, elem_var, env.subs)
                 .unwrap_or_else(|err| panic!("TODO turn fn_var into a RuntimeError {err:?}"));
 
-            Stmt::Let(assigned, expr, layout, hole)
+            Stmt::Let(assigned, expr, layout, hole, env.region)
         }
     };
 
@@ -6068,7 +6192,13 @@ fn compile_struct_like<'a, L, UnusedLayout>(
         substitute_in_exprs(env.arena, &mut hole, assigned, *only_field);
         hole
     } else {
-        Stmt::Let(assigned, Expr::Struct(elem_symbols), layout, hole)
+        Stmt::Let(
+            assigned,
+            Expr::Struct(elem_symbols),
+            layout,
+            hole,
+            env.region,
+        )
     };
 
     for (opt_field, symbol) in can_elems.into_iter().rev().zip(elem_symbols.iter().rev()) {
@@ -6256,7 +6386,13 @@ where
                 reuse: None,
             };
 
-            Stmt::Let(assigned, expr, lambda_set_layout, env.arena.alloc(hole))
+            Stmt::Let(
+                assigned,
+                expr,
+                lambda_set_layout,
+                env.arena.alloc(hole),
+                env.region,
+            )
         }
         ClosureRepresentation::AlphabeticOrderStruct(field_layouts) => {
             debug_assert_eq!(field_layouts.len(), symbols.len());
@@ -6291,7 +6427,7 @@ where
 
             let expr = Expr::Struct(symbols);
 
-            Stmt::Let(assigned, expr, lambda_set_layout, hole)
+            Stmt::Let(assigned, expr, lambda_set_layout, hole, env.region)
         }
         ClosureRepresentation::UnwrappedCapture(_layout) => {
             debug_assert_eq!(symbols.len(), 1);
@@ -6320,7 +6456,7 @@ where
                 let tag_id = name.name() != lambda_set.iter_set().next().unwrap().name();
                 let expr = Expr::Literal(Literal::Bool(tag_id));
 
-                Stmt::Let(assigned, expr, lambda_set_layout, hole)
+                Stmt::Let(assigned, expr, lambda_set_layout, hole, env.region)
             }
             EnumDispatch::U8 => {
                 debug_assert_eq!(symbols.len(), 0);
@@ -6333,7 +6469,7 @@ where
 
                 let expr = Expr::Literal(Literal::Byte(tag_id));
 
-                Stmt::Let(assigned, expr, lambda_set_layout, hole)
+                Stmt::Let(assigned, expr, lambda_set_layout, hole, env.region)
             }
         },
     };
@@ -6385,12 +6521,13 @@ fn convert_tag_union<'a>(
             "The `[]` type has no constructors, source var {:?}",
             variant_var
         ),
-        Unit => Stmt::Let(assigned, Expr::Struct(&[]), Layout::UNIT, hole),
+        Unit => Stmt::Let(assigned, Expr::Struct(&[]), Layout::UNIT, hole, env.region),
         BoolUnion { ttrue, .. } => Stmt::Let(
             assigned,
             Expr::Literal(Literal::Bool(&tag_name == ttrue.expect_tag_ref())),
             Layout::BOOL,
             hole,
+            env.region,
         ),
         ByteUnion(tag_names) => {
             let opt_tag_id = tag_names
@@ -6403,6 +6540,7 @@ fn convert_tag_union<'a>(
                     Expr::Literal(Literal::Byte(tag_id as u8)),
                     Layout::U8,
                     hole,
+                    env.region,
                 ),
                 None => runtime_error(env, "tag must be in its own type"),
             }
@@ -6429,7 +6567,13 @@ fn convert_tag_union<'a>(
                 substitute_in_exprs(env.arena, &mut hole, assigned, *only_field);
                 hole
             } else {
-                Stmt::Let(assigned, Expr::Struct(field_symbols), layout, hole)
+                Stmt::Let(
+                    assigned,
+                    Expr::Struct(field_symbols),
+                    layout,
+                    hole,
+                    env.region,
+                )
             };
 
             let iter = field_symbols_temp.into_iter().map(|(_, _, data)| data);
@@ -6463,7 +6607,13 @@ fn convert_tag_union<'a>(
                     substitute_in_exprs(env.arena, &mut hole, assigned, *only_field);
                     hole
                 } else {
-                    Stmt::Let(assigned, Expr::Struct(field_symbols), layout, hole)
+                    Stmt::Let(
+                        assigned,
+                        Expr::Struct(field_symbols),
+                        layout,
+                        hole,
+                        env.region,
+                    )
                 };
 
                 let iter = field_symbols_temp.into_iter().map(|(_, _, data)| data);
@@ -6625,7 +6775,7 @@ fn convert_tag_union<'a>(
             let union_layout =
                 layout_cache.put_in_direct_no_semantic(LayoutRepr::Union(union_layout));
 
-            let stmt = Stmt::Let(assigned, tag, union_layout, hole);
+            let stmt = Stmt::Let(assigned, tag, union_layout, hole, env.region);
             let iter = field_symbols_temp
                 .into_iter()
                 .map(|x| x.2 .0)
@@ -6973,7 +7123,9 @@ pub fn from_can<'a>(
                 "invalid condition type in if expression"
             );
 
+            let region = env.enter_region(final_else.region);
             let mut stmt = from_can(env, branch_var, final_else.value, procs, layout_cache);
+            env.region = region;
 
             for (loc_cond, loc_then) in branches.into_iter().rev() {
                 let branching_symbol = possible_reuse_symbol_or_specialize(
@@ -6983,7 +7135,9 @@ pub fn from_can<'a>(
                     &loc_cond.value,
                     cond_var,
                 );
+                let region = env.enter_region(loc_then.region);
                 let then = from_can(env, branch_var, loc_then.value, procs, layout_cache);
+                env.region = region;
 
                 stmt = cond(env, branching_symbol, cond_layout, then, stmt, ret_layout);
 
@@ -7184,12 +7338,16 @@ pub fn from_can<'a>(
                 unreachable!("recursive value does not have Identifier pattern")
             }
 
-            from_can(env, variable, cont.value, procs, layout_cache)
+            let region = env.enter_region(cont.region);
+            let stmt = from_can(env, variable, cont.value, procs, layout_cache);
+            env.region = region;
+
+            stmt
         }
         LetNonRec(def, cont) => from_can_let(env, procs, layout_cache, def, cont, variable, None),
         _ => {
             let symbol = env.unique_symbol();
-            let hole = env.arena.alloc(Stmt::Ret(symbol));
+            let hole = env.arena.alloc(Stmt::Ret(symbol, env.region));
             with_hole(env, can_expr, variable, procs, layout_cache, symbol, hole)
         }
     }
@@ -7219,7 +7377,7 @@ fn to_opt_branches<'a>(
 ) -> std::vec::Vec<(
     Pattern<'a>,
     Option<Loc<roc_can::expr::Expr>>,
-    roc_can::expr::Expr,
+    Loc<roc_can::expr::Expr>,
 )> {
     debug_assert!(!branches.is_empty());
 
@@ -7264,14 +7422,14 @@ fn to_opt_branches<'a>(
                     };
 
                     // TODO remove clone?
-                    opt_branches.push((mono_pattern, when_branch.guard.clone(), loc_expr.value));
+                    opt_branches.push((mono_pattern, when_branch.guard.clone(), loc_expr));
                 }
                 Err(runtime_error) => {
                     // TODO remove clone?
                     opt_branches.push((
                         Pattern::Underscore,
                         when_branch.guard.clone(),
-                        roc_can::expr::Expr::RuntimeError(runtime_error),
+                        Loc::at_zero(roc_can::expr::Expr::RuntimeError(runtime_error)),
                     ));
                 }
             }
@@ -7284,7 +7442,9 @@ fn to_opt_branches<'a>(
         opt_branches.push((
             Pattern::Underscore,
             None,
-            roc_can::expr::Expr::RuntimeError(roc_problem::can::RuntimeError::NonExhaustivePattern),
+            Loc::at_zero(roc_can::expr::Expr::RuntimeError(
+                roc_problem::can::RuntimeError::NonExhaustivePattern,
+            )),
         ));
     }
 
@@ -7325,7 +7485,7 @@ fn from_can_when<'a>(
     let arena = env.arena;
     let it = opt_branches
         .into_iter()
-        .filter_map(|(pattern, opt_guard, can_expr)| {
+        .filter_map(|(pattern, opt_guard, loc_expr)| {
             // If the pattern has a void layout we can drop it; however, we must still perform the
             // work of building the body, because that may contain specializations we must
             // discover for use elsewhere. See
@@ -7342,16 +7502,26 @@ fn from_can_when<'a>(
                 None
             };
 
+            let region = env.enter_region(loc_expr.region);
             let branch_stmt = match join_point {
-                None => from_can(env, expr_var, can_expr, procs, layout_cache),
+                None => from_can(env, expr_var, loc_expr.value, procs, layout_cache),
                 Some(id) => {
                     let symbol = env.unique_symbol();
                     let arguments = bumpalo::vec![in env.arena; symbol].into_bump_slice();
                     let jump = env.arena.alloc(Stmt::Jump(id, arguments));
 
-                    with_hole(env, can_expr, expr_var, procs, layout_cache, symbol, jump)
+                    with_hole(
+                        env,
+                        loc_expr.value,
+                        expr_var,
+                        procs,
+                        layout_cache,
+                        symbol,
+                        jump,
+                    )
                 }
             };
+            env.region = region;
 
             use decision_tree::Guard;
             let result = if let Some(loc_expr) = opt_guard {
@@ -7512,7 +7682,7 @@ fn substitute_in_stmt_help<'a>(
     use Stmt::*;
 
     match stmt {
-        Let(symbol, expr, layout, cont) => {
+        Let(symbol, expr, layout, cont, region) => {
             let opt_cont = substitute_in_stmt_help(arena, cont, subs);
             let opt_expr = substitute_in_expr(arena, expr, subs);
 
@@ -7520,7 +7690,7 @@ fn substitute_in_stmt_help<'a>(
                 let cont = opt_cont.unwrap_or(cont);
                 let expr = opt_expr.unwrap_or_else(|| expr.clone());
 
-                Some(arena.alloc(Let(*symbol, expr, *layout, cont)))
+                Some(arena.alloc(Let(*symbol, expr, *layout, cont, *region)))
             } else {
                 None
             }
@@ -7554,6 +7724,7 @@ fn substitute_in_stmt_help<'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let mut did_change = false;
 
@@ -7608,13 +7779,14 @@ fn substitute_in_stmt_help<'a>(
                     default_branch,
                     branches,
                     ret_layout: *ret_layout,
+                    region: *region,
                 }))
             } else {
                 None
             }
         }
-        Ret(s) => match substitute(subs, *s) {
-            Some(s) => Some(arena.alloc(Ret(s))),
+        Ret(s, region) => match substitute(subs, *s) {
+            Some(s) => Some(arena.alloc(Ret(s, *region))),
             None => None,
         },
         Refcounting(modify, cont) => {
@@ -8161,7 +8333,7 @@ where
         add_needed_external(procs, env, variable, LambdaName::no_niche(right));
 
         // then we must construct its closure; since imported symbols have no closure, we use the empty struct
-        let_empty_struct(left, env.arena.alloc(result))
+        let_empty_struct(left, env.arena.alloc(result), env.region)
     } else {
         let mut result = result;
         substitute_in_exprs(env.arena, &mut result, left, right);
@@ -8189,8 +8361,8 @@ fn force_thunk<'a>(
     build_call(env, call, assigned, layout, env.arena.alloc(hole))
 }
 
-fn let_empty_struct<'a>(assigned: Symbol, hole: &'a Stmt<'a>) -> Stmt<'a> {
-    Stmt::Let(assigned, Expr::Struct(&[]), Layout::UNIT, hole)
+fn let_empty_struct<'a>(assigned: Symbol, hole: &'a Stmt<'a>, region: Region) -> Stmt<'a> {
+    Stmt::Let(assigned, Expr::Struct(&[]), Layout::UNIT, hole, region)
 }
 
 /// If the symbol is a function or polymorphic value, make sure it is properly specialized
@@ -8532,13 +8704,13 @@ fn add_needed_external<'a>(
 }
 
 fn build_call<'a>(
-    _env: &mut Env<'a, '_>,
+    env: &mut Env<'a, '_>,
     call: Call<'a>,
     assigned: Symbol,
     return_layout: InLayout<'a>,
     hole: &'a Stmt<'a>,
 ) -> Stmt<'a> {
-    Stmt::Let(assigned, Expr::Call(call), return_layout, hole)
+    Stmt::Let(assigned, Expr::Call(call), return_layout, hole, env.region)
 }
 
 /// See https://github.com/roc-lang/roc/issues/1549
@@ -9297,7 +9469,13 @@ fn assign_num_literal_expr<'a>(
     let literal =
         make_num_literal(&layout_cache.interner, layout, num_str, num_value).to_expr_literal();
 
-    Ok(Stmt::Let(assigned, Expr::Literal(literal), layout, hole))
+    Ok(Stmt::Let(
+        assigned,
+        Expr::Literal(literal),
+        layout,
+        hole,
+        env.region,
+    ))
 }
 
 type ToLowLevelCallArguments<'a> = (
@@ -9353,6 +9531,7 @@ where
                 expr,
                 union_layout.tag_id_layout(),
                 env.arena.alloc(result),
+                env.region,
             )
         }
         ClosureCallOptions::Struct { .. } => match lambda_set.iter_set().next() {
@@ -9487,6 +9666,7 @@ where
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: env.region,
     };
 
     let param = Param {
@@ -9550,6 +9730,7 @@ fn match_on_lambda_set<'a>(
                 expr,
                 union_layout.tag_id_layout(),
                 env.arena.alloc(result),
+                env.region,
             )
         }
         ClosureCallOptions::Struct(field_layouts) => {
@@ -9670,7 +9851,7 @@ fn union_lambda_set_to_switch<'a>(
     }
 
     let (opt_join, branch_assigned, branch_hole) = match hole {
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // No need to jump to a joinpoint, inline the return in each statement as-is.
             // This makes further analyses, like TCO, easier as well.
             (None, assigned, hole)
@@ -9721,6 +9902,7 @@ fn union_lambda_set_to_switch<'a>(
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: env.region,
     };
 
     match opt_join {
@@ -9846,7 +10028,7 @@ fn enum_lambda_set_to_switch<'a>(
     debug_assert_ne!(lambda_set.len(), 0);
 
     let (opt_join, branch_assigned, branch_hole) = match hole {
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // No need to jump to a joinpoint, inline the return in each statement as-is.
             // This makes further analyses, like TCO, easier as well.
             (None, assigned, hole)
@@ -9887,6 +10069,7 @@ fn enum_lambda_set_to_switch<'a>(
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: env.region,
     };
 
     match opt_join {
@@ -9989,6 +10172,7 @@ where
         branches: branches.into_bump_slice(),
         default_branch,
         ret_layout: return_layout,
+        region: env.region,
     };
 
     let param = Param {
@@ -10333,7 +10517,7 @@ where
 
         home.register_debug_idents(ident_ids);
 
-        let ret_stmt = arena.alloc(Stmt::Ret(result));
+        let ret_stmt = arena.alloc(Stmt::Ret(result, Region::zero()));
 
         let field_get_expr = Expr::StructAtIndex {
             index: index as u64,
@@ -10341,7 +10525,7 @@ where
             structure: unboxed,
         };
 
-        let field_get_stmt = Stmt::Let(result, field_get_expr, *field, ret_stmt);
+        let field_get_stmt = Stmt::Let(result, field_get_expr, *field, ret_stmt, Region::zero());
 
         let unbox_expr = boxed::unbox(argument, arena.alloc(interned_unboxed_struct_layout));
 
@@ -10350,6 +10534,7 @@ where
             unbox_expr,
             interned_unboxed_struct_layout,
             arena.alloc(field_get_stmt),
+            Region::zero(),
         );
 
         let proc = Proc {
@@ -10360,6 +10545,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         answer.push(GlueProc {
@@ -10433,7 +10619,7 @@ where
 
         home.register_debug_idents(ident_ids);
 
-        let ret_stmt = arena.alloc(Stmt::Ret(result));
+        let ret_stmt = arena.alloc(Stmt::Ret(result, Region::zero()));
 
         let field_get_expr = Expr::UnionAtIndex {
             structure: unboxed,
@@ -10442,10 +10628,16 @@ where
             index: index as u64,
         };
 
-        let field_get_stmt = Stmt::Let(result, field_get_expr, *field, ret_stmt);
+        let field_get_stmt = Stmt::Let(result, field_get_expr, *field, ret_stmt, Region::zero());
 
         let unbox_expr = boxed::unbox(argument, arena.alloc(interned));
-        let unbox_stmt = Stmt::Let(unboxed, unbox_expr, interned, arena.alloc(field_get_stmt));
+        let unbox_stmt = Stmt::Let(
+            unboxed,
+            unbox_expr,
+            interned,
+            arena.alloc(field_get_stmt),
+            Region::zero(),
+        );
 
         let proc = Proc {
             name: LambdaName::no_niche(symbol),
@@ -10455,6 +10647,7 @@ where
             ret_layout: *field,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: false,
+            region: Region::zero(),
         };
 
         answer.push(GlueProc {
@@ -1934,6 +1934,7 @@ fn compile_test_help<'a>(
         ret_layout,
         branches,
         default_branch,
+        region: env.region,
     };
 
     let op = match cmp {
@@ -1949,11 +1950,17 @@ fn compile_test_help<'a>(
     });
 
     // write to the test symbol
-    cond = Stmt::Let(test_symbol, test, Layout::BOOL, arena.alloc(cond));
+    cond = Stmt::Let(
+        test_symbol,
+        test,
+        Layout::BOOL,
+        arena.alloc(cond),
+        env.region,
+    );
 
     // stores are in top-to-bottom order, so we have to add them in reverse
     for (symbol, layout, expr) in stores.into_iter().rev() {
-        cond = Stmt::Let(symbol, expr, layout, arena.alloc(cond));
+        cond = Stmt::Let(symbol, expr, layout, arena.alloc(cond), env.region);
     }
 
     cond
@@ -2320,6 +2327,7 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region: env.region,
                 };
 
                 let expr = Expr::GetTagId {
@@ -2332,6 +2340,7 @@ fn decide_to_branching<'a>(
                     expr,
                     union_layout.tag_id_layout(),
                     env.arena.alloc(temp),
+                    env.region,
                 )
             } else if let LayoutRepr::Builtin(Builtin::List(_)) = inner_cond_layout_raw {
                 let len_symbol = env.unique_symbol();
@@ -2342,6 +2351,7 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region: env.region,
                 };
 
                 let len_expr = Expr::Call(Call {
@@ -2357,6 +2367,7 @@ fn decide_to_branching<'a>(
                     len_expr,
                     Layout::usize(env.target),
                     env.arena.alloc(switch),
+                    env.region,
                 )
             } else {
                 Stmt::Switch {
@@ -2365,11 +2376,12 @@ fn decide_to_branching<'a>(
                     branches: branches.into_bump_slice(),
                     default_branch: (default_branch_info, env.arena.alloc(default_branch)),
                     ret_layout,
+                    region: env.region,
                 }
             };
 
             for (symbol, layout, expr) in cond_stores_vec.into_iter().rev() {
-                switch = Stmt::Let(symbol, expr, layout, env.arena.alloc(switch));
+                switch = Stmt::Let(symbol, expr, layout, env.arena.alloc(switch), env.region);
             }
 
             // make a jump table based on the tests
@@ -1,5 +1,6 @@
 use bumpalo::{collections::Vec as AVec, Bump};
 use roc_module::{low_level::LowLevel, symbol::Symbol};
+use roc_region::all::Region;
 use roc_types::subs::Variable;
 
 use crate::layout::{FunctionPointer, InLayout, LambdaName, Layout, LayoutCache, LayoutRepr};
@@ -15,6 +16,7 @@ fn index_erased_function<'a>(
     erased_function: Symbol,
     field: ErasedField,
     layout: InLayout<'a>,
+    region: Region,
 ) -> impl FnOnce(Stmt<'a>) -> Stmt<'a> {
     move |rest| {
         Stmt::Let(
@@ -25,6 +27,7 @@ fn index_erased_function<'a>(
             },
             layout,
             arena.alloc(rest),
+            region,
         )
     }
 }
@@ -36,6 +39,7 @@ fn call_callee<'a>(
     fn_ptr_symbol: Symbol,
     fn_arg_layouts: &'a [InLayout<'a>],
     fn_arguments: &'a [Symbol],
+    region: Region,
 ) -> impl FnOnce(Stmt<'a>) -> Stmt<'a> {
     move |rest| {
         Stmt::Let(
@@ -50,6 +54,7 @@ fn call_callee<'a>(
             }),
             result,
             arena.alloc(rest),
+            region,
         )
     }
 }
@@ -62,6 +67,7 @@ fn is_null<'a>(
     layout: InLayout<'a>,
 ) -> impl FnOnce(Stmt<'a>) -> Stmt<'a> {
     let null_symbol = env.unique_symbol();
+    let region = env.region;
     move |rest| {
         Stmt::Let(
             null_symbol,
@@ -78,7 +84,9 @@ fn is_null<'a>(
                 }),
                 Layout::BOOL,
                 arena.alloc(rest),
+                region,
             )),
+            region,
         )
     }
 }
@@ -161,8 +169,14 @@ pub fn call_erased_function<'a>(
 
     // f_value = ErasedLoad(f, .value)
     let f_value = env.unique_symbol();
-    let let_f_value =
-        index_erased_function(arena, f_value, f, ErasedField::ValuePtr, Layout::OPAQUE_PTR);
+    let let_f_value = index_erased_function(
+        arena,
+        f_value,
+        f,
+        ErasedField::ValuePtr,
+        Layout::OPAQUE_PTR,
+        env.region,
+    );
 
     let mut build_closure_data_branch = |env: &mut Env, pass_closure| {
         // f_callee = Cast(f_callee, (..params) -> ret);
@@ -176,8 +190,14 @@ pub fn call_erased_function<'a>(
 
         // f_callee = ErasedLoad(f, .callee)
         let f_callee = env.unique_symbol();
-        let let_f_callee =
-            index_erased_function(arena, f_callee, f, ErasedField::Callee, function_pointer);
+        let let_f_callee = index_erased_function(
+            arena,
+            f_callee,
+            f,
+            ErasedField::Callee,
+            function_pointer,
+            env.region,
+        );
 
         let function_argument_symbols = if pass_closure {
             // function_argument_symbols = ...args, f.value
@@ -196,6 +216,7 @@ pub fn call_erased_function<'a>(
             f_callee,
             f_args,
             function_argument_symbols,
+            env.region,
         );
 
         let_f_callee(
@@ -223,6 +244,7 @@ pub fn call_erased_function<'a>(
                 arena.alloc(build_closure_data_branch(env, true)),
             ),
             ret_layout: hole_layout,
+            region: env.region,
         },
     );
 
@@ -312,6 +334,7 @@ pub fn build_erased_function<'a>(
         Expr::ErasedMake { value, callee },
         Layout::ERASED,
         hole,
+        env.region,
     );
 
     let BuiltFunctionPointer {
@@ -325,6 +348,7 @@ pub fn build_erased_function<'a>(
         Expr::FunctionPointer { lambda_name },
         function_pointer,
         env.arena.alloc(result),
+        env.region,
     );
 
     // value = Expr::Box({s})
@@ -349,6 +373,7 @@ pub fn build_erased_function<'a>(
                 boxed::box_nullable(env.arena.alloc(stack_captures), stack_captures_layout),
                 boxed_captures_layout,
                 env.arena.alloc(result),
+                env.region,
             );
 
             let result = Stmt::Let(
@@ -356,6 +381,7 @@ pub fn build_erased_function<'a>(
                 Expr::Struct(symbols),
                 *stack_captures_layout,
                 env.arena.alloc(result),
+                env.region,
             );
 
             result
@@ -475,6 +501,7 @@ pub fn unpack_closure_data<'a>(
             },
             capture_layout,
             env.arena.alloc(hole),
+            env.region,
         );
     }
 
@@ -483,6 +510,7 @@ pub fn unpack_closure_data<'a>(
         boxed::unbox_nullable(heap_captures, stack_captures_layout),
         *stack_captures_layout,
         env.arena.alloc(hole),
+        env.region,
     );
 
     let let_loaded_captures = index_erased_function(
@@ -491,6 +519,7 @@ pub fn unpack_closure_data<'a>(
         captures_symbol,
         ErasedField::Value,
         heap_captures_layout,
+        env.region,
     );
 
     let_loaded_captures(hole)
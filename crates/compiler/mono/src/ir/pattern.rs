@@ -1492,7 +1492,13 @@ fn store_list_pattern<'a>(
 
                 // store immediately in the given symbol
                 (
-                    Stmt::Let(*symbol, load, element_layout, env.arena.alloc(stmt)),
+                    Stmt::Let(
+                        *symbol,
+                        load,
+                        element_layout,
+                        env.arena.alloc(stmt),
+                        env.region,
+                    ),
                     needed_stores,
                 )
             }
@@ -1519,7 +1525,13 @@ fn store_list_pattern<'a>(
                         // only if we bind one of its (sub)fields to a used name should we
                         // extract the field
                         (
-                            Stmt::Let(symbol, load, element_layout, env.arena.alloc(stmt)),
+                            Stmt::Let(
+                                symbol,
+                                load,
+                                element_layout,
+                                env.arena.alloc(stmt),
+                                env.region,
+                            ),
                             needed_stores,
                         )
                     }
@@ -1536,7 +1548,7 @@ fn store_list_pattern<'a>(
 
         stmt = store_loaded;
         for (sym, lay, expr) in needed_stores.rev() {
-            stmt = Stmt::Let(sym, expr, lay, env.arena.alloc(stmt));
+            stmt = Stmt::Let(sym, expr, lay, env.arena.alloc(stmt), env.region);
         }
     }
 
@@ -1610,7 +1622,7 @@ fn store_list_rest<'a>(
             (*rest_sym, rest_expr, list_layout),
         ];
         for (sym, expr, lay) in needed_stores.into_iter().rev() {
-            stmt = Stmt::Let(sym, expr, lay, env.arena.alloc(stmt));
+            stmt = Stmt::Let(sym, expr, lay, env.arena.alloc(stmt), env.region);
         }
     }
 
@@ -1654,7 +1666,7 @@ fn store_tag_pattern<'a>(
         match argument {
             Identifier(symbol) => {
                 // store immediately in the given symbol
-                stmt = Stmt::Let(*symbol, load, arg_layout, env.arena.alloc(stmt));
+                stmt = Stmt::Let(*symbol, load, arg_layout, env.arena.alloc(stmt), env.region);
                 is_productive = true;
             }
             Underscore => {
@@ -1677,7 +1689,8 @@ fn store_tag_pattern<'a>(
                         stmt = new;
                         // only if we bind one of its (sub)fields to a used name should we
                         // extract the field
-                        stmt = Stmt::Let(symbol, load, arg_layout, env.arena.alloc(stmt));
+                        stmt =
+                            Stmt::Let(symbol, load, arg_layout, env.arena.alloc(stmt), env.region);
                     }
                     StorePattern::NotProductive(new) => {
                         // do nothing
@@ -1729,7 +1742,7 @@ fn store_newtype_pattern<'a>(
 
         match argument {
             Identifier(symbol) => {
-                stmt = Stmt::Let(*symbol, load, arg_layout, env.arena.alloc(stmt));
+                stmt = Stmt::Let(*symbol, load, arg_layout, env.arena.alloc(stmt), env.region);
                 is_productive = true;
             }
             Underscore => {
@@ -1752,7 +1765,8 @@ fn store_newtype_pattern<'a>(
                         stmt = new;
                         // only if we bind one of its (sub)fields to a used name should we
                         // extract the field
-                        stmt = Stmt::Let(symbol, load, arg_layout, env.arena.alloc(stmt));
+                        stmt =
+                            Stmt::Let(symbol, load, arg_layout, env.arena.alloc(stmt), env.region);
                     }
                     StorePattern::NotProductive(new) => {
                         // do nothing
@@ -1791,7 +1805,13 @@ fn store_tuple_destruct<'a>(
 
     match &destruct.pat {
         Identifier(symbol) => {
-            stmt = Stmt::Let(*symbol, load, destruct.layout, env.arena.alloc(stmt));
+            stmt = Stmt::Let(
+                *symbol,
+                load,
+                destruct.layout,
+                env.arena.alloc(stmt),
+                env.region,
+            );
         }
         Underscore => {
             // important that this is special-cased to do nothing: mono record patterns will extract all the
@@ -1825,7 +1845,13 @@ fn store_tuple_destruct<'a>(
             match store_pattern_help(env, procs, layout_cache, &destruct.pat, symbol, stmt) {
                 StorePattern::Productive(new) => {
                     stmt = new;
-                    stmt = Stmt::Let(symbol, load, destruct.layout, env.arena.alloc(stmt));
+                    stmt = Stmt::Let(
+                        symbol,
+                        load,
+                        destruct.layout,
+                        env.arena.alloc(stmt),
+                        env.region,
+                    );
                 }
                 StorePattern::NotProductive(stmt) => return StorePattern::NotProductive(stmt),
             }
@@ -1856,11 +1882,23 @@ fn store_record_destruct<'a>(
 
     match &destruct.typ {
         DestructType::Required(symbol) => {
-            stmt = Stmt::Let(*symbol, load, destruct.layout, env.arena.alloc(stmt));
+            stmt = Stmt::Let(
+                *symbol,
+                load,
+                destruct.layout,
+                env.arena.alloc(stmt),
+                env.region,
+            );
         }
         DestructType::Guard(guard_pattern) => match &guard_pattern {
             Identifier(symbol) => {
-                stmt = Stmt::Let(*symbol, load, destruct.layout, env.arena.alloc(stmt));
+                stmt = Stmt::Let(
+                    *symbol,
+                    load,
+                    destruct.layout,
+                    env.arena.alloc(stmt),
+                    env.region,
+                );
             }
             Underscore => {
                 // important that this is special-cased to do nothing: mono record patterns will extract all the
@@ -1891,7 +1929,13 @@ fn store_record_destruct<'a>(
                 match store_pattern_help(env, procs, layout_cache, guard_pattern, symbol, stmt) {
                     StorePattern::Productive(new) => {
                         stmt = new;
-                        stmt = Stmt::Let(symbol, load, destruct.layout, env.arena.alloc(stmt));
+                        stmt = Stmt::Let(
+                            symbol,
+                            load,
+                            destruct.layout,
+                            env.arena.alloc(stmt),
+                            env.region,
+                        );
                     }
                     StorePattern::NotProductive(stmt) => return StorePattern::NotProductive(stmt),
                 }
@@ -11,10 +11,12 @@
 
 pub mod borrow;
 pub mod code_gen_help;
+pub mod debug_sources;
 pub mod drop_specialization;
 pub mod inc_dec;
 pub mod ir;
 pub mod layout;
+pub mod liveness;
 pub mod low_level;
 pub mod reset_reuse;
 pub mod tail_recursion;
@@ -0,0 +1,484 @@
+//! Live intervals of the symbols in a procedure, for backends that allocate registers.
+//!
+//! The statements of a procedure are numbered in the order that a backend emitting
+//! straight-line code visits them: a `Let` comes before the statement that follows it,
+//! a `Switch` comes before its branches, which are numbered one after the other, and the
+//! remainder of a `Join` comes before its body, which comes before the `Join`.
+//!
+//! The arguments of the procedure are defined at position 0, before its first statement.
+//! A symbol is live from the statement that defines it until the last statement that uses it.
+//! Symbols that are used in the body of a join point, but defined outside of it, stay live
+//! until the `Join`, because the body can be jumped to again.
+use roc_collections::all::MutMap;
+use roc_module::symbol::Symbol;
+
+use crate::ir::{CallType, Expr, ListLiteralElement, Param, Proc, Stmt};
+use crate::layout::InLayout;
+
+/// The statements during which a symbol is live, numbered in visiting order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveInterval<'a> {
+    /// The statement that defines the symbol.
+    pub start: u32,
+    /// The last statement that uses the symbol.
+    pub end: u32,
+    pub layout: InLayout<'a>,
+    /// Whether a call to another procedure happens while the symbol is live.
+    /// Such symbols are better kept in callee saved registers.
+    pub crosses_call: bool,
+    /// Whether the symbol is the result of a call to another procedure.
+    /// Such symbols are best kept in the register that the call returns in.
+    pub defined_by_call: bool,
+}
+
+impl<'a> LiveInterval<'a> {
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct LiveIntervals<'a> {
+    intervals: MutMap<Symbol, LiveInterval<'a>>,
+}
+
+impl<'a> LiveIntervals<'a> {
+    pub fn from_proc(proc: &Proc<'a>) -> Self {
+        Self::from_body(proc.args, &proc.body)
+    }
+
+    pub fn from_body(args: &[(InLayout<'a>, Symbol)], body: &Stmt<'a>) -> Self {
+        let mut builder = Builder {
+            position: 1,
+            ..Default::default()
+        };
+
+        for (layout, symbol) in args {
+            builder.define(*symbol, *layout, 0);
+        }
+        builder.visit(body);
+
+        let Builder {
+            defs,
+            last_use,
+            calls,
+            ..
+        } = builder;
+        let is_call = |position: u32| calls.binary_search(&position).is_ok();
+
+        let intervals = defs
+            .into_iter()
+            .map(|(symbol, (start, layout))| {
+                let end = last_use.get(&symbol).copied().unwrap_or(start).max(start);
+                let crosses_call = calls.iter().any(|call| start < *call && *call < end);
+
+                let interval = LiveInterval {
+                    start,
+                    end,
+                    layout,
+                    crosses_call,
+                    defined_by_call: is_call(start),
+                };
+
+                (symbol, interval)
+            })
+            .collect();
+
+        Self { intervals }
+    }
+
+    pub fn get(&self, symbol: &Symbol) -> Option<&LiveInterval<'a>> {
+        self.intervals.get(symbol)
+    }
+
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The intervals ordered by their start, which is the order a linear scan visits them in.
+    pub fn by_start(&self) -> std::vec::Vec<(Symbol, LiveInterval<'a>)> {
+        let mut intervals: std::vec::Vec<_> = self
+            .intervals
+            .iter()
+            .map(|(symbol, interval)| (*symbol, *interval))
+            .collect();
+
+        // Break ties on the symbol, so that the order does not depend on the hash map.
+        intervals.sort_by_key(|(symbol, interval)| (interval.start, interval.end, *symbol));
+
+        intervals
+    }
+}
+
+#[derive(Default)]
+struct Builder<'a> {
+    position: u32,
+    defs: MutMap<Symbol, (u32, InLayout<'a>)>,
+    last_use: MutMap<Symbol, u32>,
+    /// The statements that call another procedure, in increasing order.
+    calls: std::vec::Vec<u32>,
+}
+
+impl<'a> Builder<'a> {
+    fn next_position(&mut self) -> u32 {
+        let position = self.position;
+        self.position += 1;
+        position
+    }
+
+    fn define(&mut self, symbol: Symbol, layout: InLayout<'a>, position: u32) {
+        self.defs.entry(symbol).or_insert((position, layout));
+    }
+
+    fn use_symbol(&mut self, symbol: Symbol, position: u32) {
+        let last_use = self.last_use.entry(symbol).or_insert(position);
+        *last_use = (*last_use).max(position);
+    }
+
+    fn use_symbols(&mut self, symbols: &[Symbol], position: u32) {
+        for symbol in symbols {
+            self.use_symbol(*symbol, position);
+        }
+    }
+
+    fn visit(&mut self, stmt: &Stmt<'a>) {
+        match stmt {
+            Stmt::Let(symbol, expr, layout, following, _) => {
+                let position = self.next_position();
+                self.visit_expr(expr, position);
+                self.define(*symbol, *layout, position);
+                self.visit(following);
+            }
+            Stmt::Switch {
+                cond_symbol,
+                branches,
+                default_branch,
+                ..
+            } => {
+                // The condition is only needed to pick a branch.
+                let position = self.next_position();
+                self.use_symbol(*cond_symbol, position);
+
+                for (_, _, branch) in *branches {
+                    self.visit(branch);
+                }
+                self.visit(default_branch.1);
+            }
+            Stmt::Ret(symbol, _) => {
+                let position = self.next_position();
+                self.use_symbol(*symbol, position);
+            }
+            Stmt::Refcounting(modify, following) => {
+                // Changing a reference count calls a helper procedure.
+                let position = self.next_position();
+                self.calls.push(position);
+                self.use_symbol(modify.get_symbol(), position);
+                self.visit(following);
+            }
+            Stmt::Expect {
+                condition,
+                lookups,
+                remainder,
+                ..
+            }
+            | Stmt::ExpectFx {
+                condition,
+                lookups,
+                remainder,
+                ..
+            } => {
+                let position = self.next_position();
+                self.calls.push(position);
+                self.use_symbol(*condition, position);
+                self.use_symbols(lookups, position);
+                self.visit(remainder);
+            }
+            Stmt::Dbg {
+                symbol, remainder, ..
+            } => {
+                let position = self.next_position();
+                self.calls.push(position);
+                self.use_symbol(*symbol, position);
+                self.visit(remainder);
+            }
+            Stmt::Join {
+                parameters,
+                body,
+                remainder,
+                ..
+            } => self.visit_join(parameters, body, remainder),
+            Stmt::Jump(_, arguments) => {
+                let position = self.next_position();
+                self.use_symbols(arguments, position);
+            }
+            Stmt::Crash(message, _) => {
+                let position = self.next_position();
+                self.calls.push(position);
+                self.use_symbol(*message, position);
+            }
+        }
+    }
+
+    fn visit_join(&mut self, parameters: &[Param<'a>], body: &Stmt<'a>, remainder: &Stmt<'a>) {
+        // The parameters are written by the jumps in the remainder and in the body,
+        // so they are live for the whole join.
+        let join_start = self.position;
+        for param in parameters {
+            self.define(param.symbol, param.layout, join_start);
+        }
+
+        self.visit(remainder);
+
+        let body_start = self.position;
+        self.visit(body);
+
+        let position = self.next_position();
+        for param in parameters {
+            self.use_symbol(param.symbol, position);
+        }
+
+        // Symbols from outside of the join that are used in its body must survive until
+        // the body can no longer be jumped to.
+        let defs = &self.defs;
+        for (symbol, last_use) in self.last_use.iter_mut() {
+            let defined_outside = defs
+                .get(symbol)
+                .map_or(true, |(start, _)| *start < join_start);
+
+            if *last_use >= body_start && defined_outside {
+                *last_use = position;
+            }
+        }
+    }
+
+    fn visit_expr(&mut self, expr: &Expr<'a>, position: u32) {
+        match expr {
+            Expr::Literal(_)
+            | Expr::NullPointer
+            | Expr::EmptyArray
+            | Expr::RuntimeErrorFunction(_)
+            | Expr::FunctionPointer { .. } => {}
+            Expr::Call(call) => {
+                self.use_symbols(call.arguments, position);
+
+                match &call.call_type {
+                    CallType::ByName { .. }
+                    | CallType::Foreign { .. }
+                    | CallType::HigherOrder(_) => self.calls.push(position),
+                    CallType::ByPointer { pointer, .. } => {
+                        self.calls.push(position);
+                        self.use_symbol(*pointer, position);
+                    }
+                    CallType::LowLevel { .. } => {}
+                }
+            }
+            Expr::Tag {
+                arguments, reuse, ..
+            } => {
+                if let Some(reuse) = reuse {
+                    self.use_symbol(reuse.symbol, position);
+                }
+                self.use_symbols(arguments, position);
+            }
+            Expr::Struct(fields) => self.use_symbols(fields, position),
+            Expr::StructAtIndex { structure, .. }
+            | Expr::GetTagId { structure, .. }
+            | Expr::UnionAtIndex { structure, .. }
+            | Expr::GetElementPointer { structure, .. } => self.use_symbol(*structure, position),
+            Expr::Array { elems, .. } => {
+                for elem in *elems {
+                    if let ListLiteralElement::Symbol(symbol) = elem {
+                        self.use_symbol(*symbol, position);
+                    }
+                }
+            }
+            Expr::ErasedMake { value, callee } => {
+                if let Some(value) = value {
+                    self.use_symbol(*value, position);
+                }
+                self.use_symbol(*callee, position);
+            }
+            Expr::ErasedLoad { symbol, .. }
+            | Expr::Reset { symbol, .. }
+            | Expr::ResetRef { symbol, .. } => self.use_symbol(*symbol, position),
+            Expr::Alloca { initializer, .. } => {
+                if let Some(initializer) = initializer {
+                    self.use_symbol(*initializer, position);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ir::{BranchInfo, Call, JoinPointId, Literal, ModifyRc};
+    use crate::layout::Layout;
+    use bumpalo::Bump;
+    use roc_module::low_level::LowLevel;
+    use roc_region::all::Region;
+
+    fn int<'a>(arena: &'a Bump, symbol: Symbol, following: Stmt<'a>) -> Stmt<'a> {
+        let literal = Expr::Literal(Literal::Int(1i128.to_ne_bytes()));
+        Stmt::Let(
+            symbol,
+            literal,
+            Layout::I64,
+            arena.alloc(following),
+            Region::zero(),
+        )
+    }
+
+    fn add<'a>(arena: &'a Bump, symbol: Symbol, args: &[Symbol], following: Stmt<'a>) -> Stmt<'a> {
+        let call = Call {
+            call_type: CallType::LowLevel {
+                op: LowLevel::NumAdd,
+                update_mode: crate::ir::UpdateModeId::BACKEND_DUMMY,
+            },
+            arguments: arena.alloc_slice_copy(args),
+        };
+        Stmt::Let(
+            symbol,
+            Expr::Call(call),
+            Layout::I64,
+            arena.alloc(following),
+            Region::zero(),
+        )
+    }
+
+    fn span(intervals: &LiveIntervals, symbol: Symbol) -> (u32, u32) {
+        let interval = intervals.get(&symbol).unwrap();
+        (interval.start, interval.end)
+    }
+
+    #[test]
+    fn straight_line() {
+        let arena = Bump::new();
+        let (a, b, c) = (Symbol::DEV_TMP, Symbol::DEV_TMP2, Symbol::DEV_TMP3);
+
+        // a = 1; b = 1; c = a + b; ret c
+        let body = int(
+            &arena,
+            a,
+            int(
+                &arena,
+                b,
+                add(&arena, c, &[a, b], Stmt::Ret(c, Region::zero())),
+            ),
+        );
+        let intervals = LiveIntervals::from_body(&[], &body);
+
+        assert_eq!(span(&intervals, a), (1, 3));
+        assert_eq!(span(&intervals, b), (2, 3));
+        assert_eq!(span(&intervals, c), (3, 4));
+        assert!(!intervals.get(&a).unwrap().crosses_call);
+
+        let order: std::vec::Vec<_> = intervals.by_start().into_iter().map(|(s, _)| s).collect();
+        assert_eq!(order, [a, b, c]);
+    }
+
+    #[test]
+    fn switch_before_its_branches() {
+        let arena = Bump::new();
+        let (a, b, cond) = (Symbol::DEV_TMP, Symbol::DEV_TMP2, Symbol::DEV_TMP3);
+
+        // a = 1; cond = 1; switch cond { 0 -> b = 1; ret b, _ -> ret a }
+        let branches = arena.alloc_slice_fill_iter([(
+            0,
+            BranchInfo::None,
+            int(&arena, b, Stmt::Ret(b, Region::zero())),
+        )]);
+        let switch = Stmt::Switch {
+            cond_symbol: cond,
+            cond_layout: Layout::I64,
+            branches,
+            default_branch: (BranchInfo::None, arena.alloc(Stmt::Ret(a, Region::zero()))),
+            ret_layout: Layout::I64,
+            region: Region::zero(),
+        };
+        let body = int(&arena, a, int(&arena, cond, switch));
+        let intervals = LiveIntervals::from_body(&[], &body);
+
+        // 1: a = 1, 2: cond = 1, 3: switch, 4: b = 1, 5: ret b, 6: ret a
+        assert_eq!(span(&intervals, cond), (2, 3));
+        assert_eq!(span(&intervals, b), (4, 5));
+        // `a` is used by a later branch, so it stays live through the earlier ones.
+        assert_eq!(span(&intervals, a), (1, 6));
+    }
+
+    #[test]
+    fn calls_are_crossed() {
+        let arena = Bump::new();
+        let (a, b, c) = (Symbol::DEV_TMP, Symbol::DEV_TMP2, Symbol::DEV_TMP3);
+
+        // a = 1; inc b; c = foreign_call a; ret c
+        let call = Call {
+            call_type: CallType::Foreign {
+                foreign_symbol: "foreign_call".into(),
+                ret_layout: Layout::I64,
+            },
+            arguments: arena.alloc_slice_copy(&[a]),
+        };
+        let ret = Stmt::Let(
+            c,
+            Expr::Call(call),
+            Layout::I64,
+            arena.alloc(Stmt::Ret(c, Region::zero())),
+            Region::zero(),
+        );
+        let body = int(
+            &arena,
+            a,
+            Stmt::Refcounting(ModifyRc::Inc(b, 1), arena.alloc(ret)),
+        );
+        let intervals = LiveIntervals::from_body(&[(Layout::I64, b)], &body);
+
+        assert!(intervals.get(&a).unwrap().crosses_call);
+        assert!(!intervals.get(&a).unwrap().defined_by_call);
+        // The call uses `b` for the last time, so it does not need to survive it.
+        assert_eq!(span(&intervals, b), (0, 2));
+        assert!(!intervals.get(&b).unwrap().crosses_call);
+        assert!(intervals.get(&c).unwrap().defined_by_call);
+    }
+
+    #[test]
+    fn join_body_keeps_outer_symbols_alive() {
+        let arena = Bump::new();
+        let (outer, param, sum) = (Symbol::DEV_TMP, Symbol::DEV_TMP2, Symbol::DEV_TMP3);
+        let id = JoinPointId(Symbol::DEV_TMP4);
+
+        // outer = 1
+        // join id param =
+        //     sum = param + outer
+        //     jump id sum
+        // in
+        //     jump id outer
+        let body_stmt = add(
+            &arena,
+            sum,
+            &[param, outer],
+            Stmt::Jump(id, arena.alloc_slice_copy(&[sum])),
+        );
+        let join = Stmt::Join {
+            id,
+            parameters: arena.alloc_slice_copy(&[Param {
+                symbol: param,
+                layout: Layout::I64,
+            }]),
+            body: arena.alloc(body_stmt),
+            remainder: arena.alloc(Stmt::Jump(id, arena.alloc_slice_copy(&[outer]))),
+        };
+        let body = int(&arena, outer, join);
+        let intervals = LiveIntervals::from_body(&[], &body);
+
+        // 1: outer = 1, 2: jump in the remainder, 3: sum = .., 4: jump in the body, 5: join
+        assert_eq!(span(&intervals, outer), (1, 5));
+        assert_eq!(span(&intervals, param), (2, 5));
+        assert_eq!(span(&intervals, sum), (3, 4));
+    }
+}
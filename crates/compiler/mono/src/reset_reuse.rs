@@ -20,6 +20,7 @@ use bumpalo::collections::CollectIn;
 use roc_collections::{MutMap, MutSet};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 use roc_target::Target;
 
 /**
@@ -106,13 +107,13 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
     stmt: &'a Stmt<'a>,
 ) -> &'a Stmt<'a> {
     match stmt {
-        Stmt::Let(_, _, _, _) => {
+        Stmt::Let(..) => {
             // Collect all the subsequent let bindings (including the current one).
             // To prevent the stack from overflowing when there are many let bindings.
             let mut triples = vec![];
             let mut current_stmt = stmt;
-            while let Stmt::Let(binding, expr, layout, next_stmt) = current_stmt {
-                triples.push((binding, expr, layout));
+            while let Stmt::Let(binding, expr, layout, next_stmt, region) = current_stmt {
+                triples.push((binding, expr, layout, region));
                 current_stmt = next_stmt
             }
 
@@ -121,13 +122,13 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 "Expected at least one let binding in the vector"
             );
             debug_assert!(
-                !matches!(current_stmt, Stmt::Let(_, _, _, _)),
+                !matches!(current_stmt, Stmt::Let(..)),
                 "All let bindings should be in the vector"
             );
 
             // Update the triplets with reuse operations. Making sure to update the environment before the next let binding.
             let mut new_triplets = vec![];
-            for (binding, expr, layout) in triples {
+            for (binding, expr, layout, region) in triples {
                 let new_expr = match expr {
                     Expr::Tag {
                         tag_layout,
@@ -177,6 +178,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                                                     create_ptr_cast(arena, reuse_token.symbol),
                                                     *layout,
                                                     new_let,
+                                                    *region,
                                                 ))
                                             };
 
@@ -207,7 +209,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 };
 
                 environment.add_symbol_layout(*binding, layout);
-                new_triplets.push((binding, new_expr, layout))
+                new_triplets.push((binding, new_expr, layout, region))
             }
 
             let new_continuation = insert_reset_reuse_operations_stmt(
@@ -222,9 +224,14 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
 
             new_triplets.into_iter().rev().fold(
                 new_continuation,
-                |new_continuation, (binding, (opt_ptr_cast, new_expr), layout)| {
-                    let new_let =
-                        arena.alloc(Stmt::Let(*binding, new_expr, *layout, new_continuation));
+                |new_continuation, (binding, (opt_ptr_cast, new_expr), layout, region)| {
+                    let new_let = arena.alloc(Stmt::Let(
+                        *binding,
+                        new_expr,
+                        *layout,
+                        new_continuation,
+                        *region,
+                    ));
 
                     // if the layout for the reuse does not match that of the reset, use PtrCast to convert the layout.
                     match opt_ptr_cast {
@@ -240,6 +247,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             macro_rules! update_env_with_constructor {
                 ($branch_env:expr, $info:expr) => {{
@@ -403,6 +411,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                 branches: newer_branches,
                 default_branch: newer_default_branch,
                 ret_layout: *ret_layout,
+                region: *region,
             })
         }
         Stmt::Refcounting(rc, continuation) => {
@@ -551,6 +560,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                                     reset_expr,
                                     layout,
                                     new_continuation,
+                                    Region::zero(),
                                 ));
                             }
                             ResetOperation::ResetRef => {
@@ -565,6 +575,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                                     reset_expr,
                                     layout,
                                     new_continuation,
+                                    Region::zero(),
                                 ));
                             }
                             ResetOperation::ClearTagId => {
@@ -581,6 +592,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                                     reset_expr,
                                     layout,
                                     new_continuation,
+                                    Region::zero(),
                                 ));
                             }
                             ResetOperation::Nothing => {
@@ -596,7 +608,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
 
             arena.alloc(Stmt::Refcounting(*rc, new_continuation))
         }
-        Stmt::Ret(_) => {
+        Stmt::Ret(..) => {
             // The return statement just doesn't consume any tokens. Dropping these tokens will be handled before.
             stmt
         }
@@ -1045,6 +1057,7 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                                 create_ptr_cast(arena, old_symbol),
                                 *layout,
                                 child,
+                                Region::zero(),
                             ))
                         },
                     );
@@ -1053,7 +1066,13 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                     void_pointer_layout_symbols.into_iter().fold(
                         casted_tokens,
                         |child, (param_layout, symbol)| {
-                            arena.alloc(Stmt::Let(symbol, Expr::NullPointer, *param_layout, child))
+                            arena.alloc(Stmt::Let(
+                                symbol,
+                                Expr::NullPointer,
+                                *param_layout,
+                                child,
+                                Region::zero(),
+                            ))
                         },
                     )
                 }
@@ -1091,7 +1110,13 @@ fn insert_reset_reuse_operations_stmt<'a, 'i>(
                     void_pointer_layout_symbols.into_iter().fold(
                         arena.alloc(Stmt::Jump(*id, extended_arguments)),
                         |child, (layout, symbol)| {
-                            arena.alloc(Stmt::Let(symbol, Expr::NullPointer, *layout, child))
+                            arena.alloc(Stmt::Let(
+                                symbol,
+                                Expr::NullPointer,
+                                *layout,
+                                child,
+                                Region::zero(),
+                            ))
                         },
                     )
                 }
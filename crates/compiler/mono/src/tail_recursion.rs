@@ -12,6 +12,7 @@ use bumpalo::Bump;
 use roc_collections::{MutMap, VecMap};
 use roc_module::low_level::LowLevel;
 use roc_module::symbol::{IdentIds, ModuleId, Symbol};
+use roc_region::all::Region;
 
 pub struct Env<'a, 'i> {
     arena: &'a Bump,
@@ -179,7 +180,8 @@ fn insert_jumps<'a>(
                 arguments,
             }),
             _,
-            Stmt::Ret(rsym),
+            Stmt::Ret(rsym, _),
+            _,
         ) if symbol == rsym && is_equal_function(*fsym, arg_layouts, *ret_layout) => {
             // replace the call and return with a jump
 
@@ -188,7 +190,7 @@ fn insert_jumps<'a>(
             Some(arena.alloc(jump))
         }
 
-        Let(symbol, expr, layout, cont) => {
+        Let(symbol, expr, layout, cont, region) => {
             let opt_cont = insert_jumps(
                 arena,
                 cont,
@@ -201,7 +203,7 @@ fn insert_jumps<'a>(
             if opt_cont.is_some() {
                 let cont = opt_cont.unwrap_or(cont);
 
-                Some(arena.alloc(Let(*symbol, expr.clone(), *layout, cont)))
+                Some(arena.alloc(Let(*symbol, expr.clone(), *layout, cont, *region)))
             } else {
                 None
             }
@@ -250,6 +252,7 @@ fn insert_jumps<'a>(
             branches,
             default_branch,
             ret_layout,
+            region,
         } => {
             let opt_default = insert_jumps(
                 arena,
@@ -310,6 +313,7 @@ fn insert_jumps<'a>(
                     default_branch,
                     branches,
                     ret_layout: *ret_layout,
+                    region: *region,
                 }))
             } else {
                 None
@@ -401,7 +405,7 @@ fn insert_jumps<'a>(
             None => None,
         },
 
-        Ret(_) => None,
+        Ret(_, _) => None,
         Jump(_, _) => None,
         Crash(..) => None,
     }
@@ -527,7 +531,7 @@ fn trmc_candidates_help(
     candidates.retain(|recursive_call| !stmt_contains_symbol_nonrec(stmt, *recursive_call));
 
     match stmt {
-        Stmt::Let(symbol, expr, _, next) => {
+        Stmt::Let(symbol, expr, _, next, _) => {
             // find a new recursive call if we currently have none
             // that means we generally pick the first recursive call we find
             if TrmcEnv::is_recursive_expr(expr, function_name).is_some() {
@@ -560,7 +564,7 @@ fn trmc_candidates_help(
             trmc_candidates_help(function_name, body, candidates);
             trmc_candidates_help(function_name, remainder, candidates);
         }
-        Stmt::Ret(_) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => { /* terminal */ }
+        Stmt::Ret(..) | Stmt::Jump(_, _) | Stmt::Crash(_, _) => { /* terminal */ }
     }
 }
 
@@ -628,7 +632,7 @@ impl<'a> TrmcEnv<'a> {
     #[inline(always)]
     fn is_terminal_constructor(stmt: &Stmt<'a>) -> Option<ConstructorInfo<'a>> {
         match stmt {
-            Stmt::Let(s1, expr, _layout, Stmt::Ret(s2)) if s1 == s2 => {
+            Stmt::Let(s1, expr, _layout, Stmt::Ret(s2, _), _) if s1 == s2 => {
                 Self::get_contructor_info(expr)
             }
 
@@ -686,7 +690,7 @@ impl<'a> TrmcEnv<'a> {
         next: &Stmt<'a>,
     ) -> Option<Call<'a>> {
         match next {
-            Stmt::Ret(s) if *s == symbol => Self::is_recursive_expr(expr, lambda_name),
+            Stmt::Ret(s, _) if *s == symbol => Self::is_recursive_expr(expr, lambda_name),
             _ => None,
         }
     }
@@ -710,6 +714,7 @@ impl<'a> TrmcEnv<'a> {
             Expr::Call(ptr_write),
             Layout::UNIT,
             next,
+            Region::zero(),
         )
     }
 
@@ -739,7 +744,15 @@ impl<'a> TrmcEnv<'a> {
         jump_arguments.push(initial_ptr_symbol);
 
         let null_symbol = env.named_unique_symbol("null");
-        let let_null = |next| Stmt::Let(null_symbol, Expr::NullPointer, return_layout, next);
+        let let_null = |next| {
+            Stmt::Let(
+                null_symbol,
+                Expr::NullPointer,
+                return_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let ptr_return_layout = env
             .interner
@@ -749,7 +762,15 @@ impl<'a> TrmcEnv<'a> {
             initializer: Some(null_symbol),
             element_layout: return_layout,
         };
-        let let_ptr = |next| Stmt::Let(initial_ptr_symbol, ptr_null, ptr_return_layout, next);
+        let let_ptr = |next| {
+            Stmt::Let(
+                initial_ptr_symbol,
+                ptr_null,
+                ptr_return_layout,
+                next,
+                Region::zero(),
+            )
+        };
 
         let joinpoint_id = JoinPointId(env.named_unique_symbol("trmc"));
         let hole_symbol = env.named_unique_symbol("hole");
@@ -807,6 +828,7 @@ impl<'a> TrmcEnv<'a> {
             ret_layout: proc.ret_layout,
             is_self_recursive: SelfRecursive::NotSelfRecursive,
             is_erased: proc.is_erased,
+            region: proc.region,
         }
     }
 
@@ -814,7 +836,7 @@ impl<'a> TrmcEnv<'a> {
         let arena = env.arena;
 
         match stmt {
-            Stmt::Let(symbol, expr, layout, next) => {
+            Stmt::Let(symbol, expr, layout, next, region) => {
                 // if this is a TRMC call, remember what the call looks like, so we can turn it
                 // into a jump later. The call is then removed from the Stmt
                 if let Some(opt_call) = self.trmc_calls.get_mut(symbol) {
@@ -861,11 +883,12 @@ impl<'a> TrmcEnv<'a> {
                             // this control flow path did not encounter a recursive call. Just
                             // write the end result into the hole and we're done.
 
-                            let define_tag = |next| Stmt::Let(*symbol, expr.clone(), *layout, next);
+                            let define_tag =
+                                |next| Stmt::Let(*symbol, expr.clone(), *layout, next, *region);
 
                             let output = define_tag(arena.alloc(
                                 //
-                                self.non_trmc_return(env, *symbol),
+                                self.non_trmc_return(env, *symbol, *region),
                             ));
 
                             return output;
@@ -885,6 +908,7 @@ impl<'a> TrmcEnv<'a> {
                                         expr.clone(),
                                         *layout,
                                         arena.alloc(next),
+                                        *region,
                                     );
                                 }
                                 Some(v) => v,
@@ -897,6 +921,7 @@ impl<'a> TrmcEnv<'a> {
                                     Expr::NullPointer,
                                     self.return_layout,
                                     next,
+                                    Region::zero(),
                                 )
                             };
 
@@ -914,7 +939,8 @@ impl<'a> TrmcEnv<'a> {
                             let indices = arena
                                 .alloc([cons_info.tag_id as u64, recursive_field_index as u64]);
 
-                            let let_tag = |next| Stmt::Let(*symbol, tag_expr, *layout, next);
+                            let let_tag =
+                                |next| Stmt::Let(*symbol, tag_expr, *layout, next, *region);
                             let get_reference_expr = Expr::GetElementPointer {
                                 structure: *symbol,
                                 union_layout: cons_info.tag_layout,
@@ -928,6 +954,7 @@ impl<'a> TrmcEnv<'a> {
                                     get_reference_expr,
                                     self.ptr_return_layout,
                                     next,
+                                    Region::zero(),
                                 )
                             };
 
@@ -961,7 +988,7 @@ impl<'a> TrmcEnv<'a> {
                 }
 
                 let next = self.walk_stmt(env, next);
-                Stmt::Let(*symbol, expr.clone(), *layout, arena.alloc(next))
+                Stmt::Let(*symbol, expr.clone(), *layout, arena.alloc(next), *region)
             }
             Stmt::Switch {
                 cond_symbol,
@@ -969,6 +996,7 @@ impl<'a> TrmcEnv<'a> {
                 branches,
                 default_branch,
                 ret_layout,
+                region,
             } => {
                 let mut new_branches = Vec::with_capacity_in(branches.len(), arena);
 
@@ -986,12 +1014,13 @@ impl<'a> TrmcEnv<'a> {
                     branches: arena.alloc(new_branches.into_bump_slice()),
                     default_branch: (default_branch.0.clone(), new_default_branch),
                     ret_layout: *ret_layout,
+                    region: *region,
                 }
             }
-            Stmt::Ret(symbol) => {
+            Stmt::Ret(symbol, region) => {
                 // write the symbol we're supposed to return into the hole
                 // then read initial_symbol and return its contents
-                self.non_trmc_return(env, *symbol)
+                self.non_trmc_return(env, *symbol, *region)
             }
             Stmt::Refcounting(op, next) => {
                 let new_next = self.walk_stmt(env, next);
@@ -1057,7 +1086,12 @@ impl<'a> TrmcEnv<'a> {
         }
     }
 
-    fn non_trmc_return(&mut self, env: &mut Env<'a, '_>, value_symbol: Symbol) -> Stmt<'a> {
+    fn non_trmc_return(
+        &mut self,
+        env: &mut Env<'a, '_>,
+        value_symbol: Symbol,
+        region: Region,
+    ) -> Stmt<'a> {
         let arena = env.arena;
         let layout = self.return_layout;
 
@@ -1071,7 +1105,8 @@ impl<'a> TrmcEnv<'a> {
             arguments: &*arena.alloc([self.head_symbol]),
         };
 
-        let ptr_load = |next| Stmt::Let(final_symbol, Expr::Call(call), layout, next);
+        let ptr_load =
+            |next| Stmt::Let(final_symbol, Expr::Call(call), layout, next, Region::zero());
 
         Self::ptr_write(
             env,
@@ -1079,7 +1114,7 @@ impl<'a> TrmcEnv<'a> {
             value_symbol,
             arena.alloc(
                 //
-                ptr_load(arena.alloc(Stmt::Ret(final_symbol))),
+                ptr_load(arena.alloc(Stmt::Ret(final_symbol, region))),
             ),
         )
     }
@@ -1120,9 +1155,9 @@ fn stmt_contains_symbol_nonrec(stmt: &Stmt, needle: Symbol) -> bool {
     use crate::ir::ModifyRc::*;
 
     match stmt {
-        Stmt::Let(_, expr, _, _) => expr_contains_symbol(expr, needle),
+        Stmt::Let(_, expr, _, _, _) => expr_contains_symbol(expr, needle),
         Stmt::Switch { cond_symbol, .. } => needle == *cond_symbol,
-        Stmt::Ret(symbol) => needle == *symbol,
+        Stmt::Ret(symbol, _) => needle == *symbol,
         Stmt::Refcounting(modify, _) => {
             matches!( modify, Inc(symbol, _) | Dec(symbol) | DecRef(symbol)  if needle == *symbol  )
         }
@@ -499,6 +499,8 @@ pub enum Expr<'a> {
     ParensAround(&'a Expr<'a>),
 
     // Problems
+    /// Code that didn't parse, which the parser skipped over to carry on after it.
+    Malformed(&'a str),
     MalformedIdent(&'a str, crate::ident::BadIdent),
     MalformedClosure,
     MalformedSuffixed(&'a Loc<Expr<'a>>),
@@ -626,6 +628,7 @@ pub fn is_expr_suffixed(expr: &Expr) -> bool {
         }
         Expr::SpaceBefore(a, _) => is_expr_suffixed(a),
         Expr::SpaceAfter(a, _) => is_expr_suffixed(a),
+        Expr::Malformed(_) => false,
         Expr::MalformedIdent(_, _) => false,
         Expr::MalformedClosure => false,
         Expr::MalformedSuffixed(_) => false,
@@ -973,6 +976,7 @@ impl<'a, 'b> RecursiveValueDefIter<'a, 'b> {
                 | Crash
                 | Tag(_)
                 | OpaqueRef(_)
+                | Malformed(_)
                 | MalformedIdent(_, _)
                 | MalformedClosure
                 | PrecedenceConflict(_)
@@ -2428,6 +2432,7 @@ impl<'a> Malformed for Expr<'a> {
             SpaceAfter(expr, _) |
             ParensAround(expr) => expr.is_malformed(),
 
+            Malformed(_) |
             MalformedIdent(_, _) |
             MalformedClosure |
             MalformedSuffixed(..) |
@@ -20,7 +20,7 @@ use crate::parser::{
     EPattern, ERecord, EString, EType, EWhen, Either, ParseResult, Parser,
 };
 use crate::pattern::{closure_param, loc_implements_parser};
-use crate::state::State;
+use crate::state::{RecoveredError, State};
 use crate::string_literal::{self, StrLikeLiteral};
 use crate::{header, keyword};
 use crate::{module, type_annotation};
@@ -86,7 +86,12 @@ fn loc_expr_in_parens_help<'a>() -> impl Parser<'a, Loc<Expr<'a>>, EInParens<'a>
     then(
         loc!(collection_trailing_sep_e!(
             byte(b'(', EInParens::Open),
-            specialize_err_ref(EInParens::Expr, loc_expr(false)),
+            specialize_err_ref(
+                EInParens::Expr,
+                recovering_item(loc_expr(false), |fail, pos| {
+                    EExpr::InParens(EInParens::Expr(fail, pos), pos)
+                })
+            ),
             byte(b',', EInParens::End),
             byte(b')', EInParens::End),
             Expr::SpaceBefore
@@ -1353,98 +1358,93 @@ fn parse_defs_end<'a>(
 
         global_state = match parse_single_def(options, min_indent, arena, state) {
             Ok((_, Some(single_def), next_state)) => {
-                let region = single_def.region;
-                let spaces_before_current = single_def.spaces_before;
-                let spaces_after_current = single_def.spaces_after;
-
-                match single_def.type_or_value {
-                    Either::First(type_def) => {
-                        defs.push_type_def(
-                            type_def,
-                            region,
-                            spaces_before_current,
-                            spaces_after_current,
-                        );
-                    }
-                    Either::Second(value_def) => {
-                        // If we got a ValueDef::Body, check if a type annotation preceded it.
-                        // If so, we may need to combine them into an AnnotatedBody.
-                        let joined = match value_def {
-                            ValueDef::Body(loc_pattern, loc_def_expr)
-                                if spaces_before_current.len() <= 1 =>
-                            {
-                                let region =
-                                    Region::span_across(&loc_pattern.region, &loc_def_expr.region);
-
-                                match defs.last() {
-                                    Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
-                                        let (value_def, region) = join_ann_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            ann_pattern,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
+                push_single_def(arena, &mut defs, single_def);
 
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
-
-                                        true
-                                    }
-                                    Some(Ok(TypeDef::Alias {
-                                        header,
-                                        ann: ann_type,
-                                    })) => {
-                                        let (value_def, region) = join_alias_to_body!(
-                                            arena,
-                                            loc_pattern,
-                                            loc_def_expr,
-                                            header,
-                                            ann_type,
-                                            spaces_before_current,
-                                            region
-                                        );
+                next_state
+            }
+            Ok((progress, None, s)) => return Ok((progress, defs, s)),
+            Err((MadeProgress, EExpr::DefMissingFinalExpr(..)))
+            | Err((MadeProgress, EExpr::DefMissingFinalExpr2(..))) => {
+                return Ok((MadeProgress, defs, state_before))
+            }
+            Err((progress, err)) => return Err((progress, err)),
+        };
+    }
+}
 
-                                        defs.replace_with_value_def(
-                                            defs.tags.len() - 1,
-                                            value_def,
-                                            region,
-                                        );
+/// Add a def to the end of `defs`, joining it up with the annotation before it if there
+/// is one.
+fn push_single_def<'a>(arena: &'a Bump, defs: &mut Defs<'a>, single_def: SingleDef<'a>) {
+    let region = single_def.region;
+    let spaces_before_current = single_def.spaces_before;
+    let spaces_after_current = single_def.spaces_after;
+
+    match single_def.type_or_value {
+        Either::First(type_def) => {
+            defs.push_type_def(
+                type_def,
+                region,
+                spaces_before_current,
+                spaces_after_current,
+            );
+        }
+        Either::Second(value_def) => {
+            // If we got a ValueDef::Body, check if a type annotation preceded it.
+            // If so, we may need to combine them into an AnnotatedBody.
+            let joined = match value_def {
+                ValueDef::Body(loc_pattern, loc_def_expr) if spaces_before_current.len() <= 1 => {
+                    let region = Region::span_across(&loc_pattern.region, &loc_def_expr.region);
+
+                    match defs.last() {
+                        Some(Err(ValueDef::Annotation(ann_pattern, ann_type))) => {
+                            let (value_def, region) = join_ann_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                ann_pattern,
+                                ann_type,
+                                spaces_before_current,
+                                region
+                            );
 
-                                        true
-                                    }
-                                    _ => false,
-                                }
-                            }
-                            _ => false,
-                        };
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
 
-                        if !joined {
-                            // the previous and current def can't be joined up
-                            defs.push_value_def(
-                                value_def,
-                                region,
+                            true
+                        }
+                        Some(Ok(TypeDef::Alias {
+                            header,
+                            ann: ann_type,
+                        })) => {
+                            let (value_def, region) = join_alias_to_body!(
+                                arena,
+                                loc_pattern,
+                                loc_def_expr,
+                                header,
+                                ann_type,
                                 spaces_before_current,
-                                spaces_after_current,
+                                region
                             );
+
+                            defs.replace_with_value_def(defs.tags.len() - 1, value_def, region);
+
+                            true
                         }
+                        _ => false,
                     }
                 }
+                _ => false,
+            };
 
-                next_state
-            }
-            Ok((progress, None, s)) => return Ok((progress, defs, s)),
-            Err((MadeProgress, EExpr::DefMissingFinalExpr(..)))
-            | Err((MadeProgress, EExpr::DefMissingFinalExpr2(..))) => {
-                return Ok((MadeProgress, defs, state_before))
+            if !joined {
+                // the previous and current def can't be joined up
+                defs.push_value_def(
+                    value_def,
+                    region,
+                    spaces_before_current,
+                    spaces_after_current,
+                );
             }
-            Err((progress, err)) => return Err((progress, err)),
-        };
+        }
     }
 }
 
@@ -2474,6 +2474,7 @@ fn expr_to_pattern_help<'a>(arena: &'a Bump, expr: &Expr<'a>) -> Result<Pattern<
 
         Expr::Str(string) => Pattern::StrLiteral(string),
         Expr::SingleQuote(string) => Pattern::SingleQuote(string),
+        Expr::Malformed(string) => Pattern::Malformed(string),
         Expr::MalformedIdent(string, problem) => Pattern::MalformedIdent(string, problem),
     };
 
@@ -2574,6 +2575,175 @@ pub fn parse_top_level_defs<'a>(
     Ok((MadeProgress, output, state))
 }
 
+/// Like [parse_top_level_defs], but rather than stopping at a def that doesn't parse, skip
+/// over it and carry on with the next one. The syntax errors end up in the returned state.
+///
+/// Where a broken def is clearly `name = ...`, it's kept as a def of `name` whose body is
+/// [Expr::Malformed], so that the rest of the module can still refer to it.
+pub fn parse_top_level_defs_recovering<'a>(
+    arena: &'a bumpalo::Bump,
+    mut state: State<'a>,
+    mut output: Defs<'a>,
+) -> (Defs<'a>, State<'a>) {
+    let options = ExprParseOptions {
+        accept_multi_backpassing: true,
+        check_for_arrow: true,
+    };
+
+    let existing_len = output.tags.len();
+
+    loop {
+        let state_before = state.clone();
+
+        let error = match parse_single_def(options, 0, arena, state) {
+            Ok((_, Some(single_def), next_state)) => {
+                push_single_def(arena, &mut output, single_def);
+                state = next_state;
+
+                continue;
+            }
+            Ok((_, None, _))
+            | Err((MadeProgress, EExpr::DefMissingFinalExpr(..)))
+            | Err((MadeProgress, EExpr::DefMissingFinalExpr2(..))) => {
+                match space0_e(EExpr::IndentEnd).parse(arena, state_before.clone(), 0) {
+                    Ok((_, final_space, end_state)) if end_state.has_reached_end() => {
+                        if output.tags.len() > existing_len {
+                            let after =
+                                Slice::extend_new(&mut output.spaces, final_space.iter().copied());
+                            let last = output.tags.len() - 1;
+                            output.space_after[last] = after;
+                        }
+
+                        return (output, end_state);
+                    }
+                    Ok((_, _, stuck)) => parser::SyntaxError::NotEndOfFile(stuck.pos()),
+                    Err((_, fail)) => parser::SyntaxError::Expr(fail, state_before.pos()),
+                }
+            }
+            Err((_, fail)) => parser::SyntaxError::Expr(fail, state_before.pos()),
+        };
+
+        state = skip_broken_def(arena, &mut output, state_before, error);
+    }
+}
+
+/// Record `error` for the top-level def at `state`, and skip to the start of the next one.
+fn skip_broken_def<'a>(
+    arena: &'a Bump,
+    output: &mut Defs<'a>,
+    state: State<'a>,
+    error: parser::SyntaxError<'a>,
+) -> State<'a> {
+    let (spaces_before, state) = match space0_e(EExpr::IndentStart).parse(arena, state.clone(), 0) {
+        Ok((_, spaces, state)) => (spaces, state),
+        Err(_) => (&[] as &[_], state),
+    };
+
+    let bytes = state.original_bytes();
+    let start = state.pos().offset as usize;
+    let next = crate::recover::next_top_level_def(bytes, start)
+        .max(start + 1)
+        .min(bytes.len());
+    let end = crate::recover::trim_end(bytes, start, next);
+    let region = Region::new(state.pos(), Position::new(end as u32));
+
+    if let Some(single_def) = malformed_def(arena, bytes, region, spaces_before) {
+        push_single_def(arena, output, single_def);
+    }
+
+    let mut state = state.skip_to(next);
+    state.push_recovered(arena, RecoveredError { error, region });
+
+    state
+}
+
+/// `name = ...` as a def of `name` with a malformed body, if that's what `region` holds.
+fn malformed_def<'a>(
+    arena: &'a Bump,
+    bytes: &'a [u8],
+    region: Region,
+    spaces_before: &'a [CommentOrNewline<'a>],
+) -> Option<SingleDef<'a>> {
+    let start = region.start().offset as usize;
+    let end = region.end().offset as usize;
+    let text = std::str::from_utf8(&bytes[start..end]).ok()?;
+
+    let ident_len = text
+        .char_indices()
+        .find(|(_, c)| !(c.is_ascii_alphanumeric() || *c == '_'))
+        .map_or(text.len(), |(index, _)| index);
+    let ident = &text[..ident_len];
+
+    if !ident.starts_with(|c: char| c.is_ascii_lowercase()) || keyword::KEYWORDS.contains(&ident) {
+        return None;
+    }
+
+    let rest = text[ident_len..].trim_start_matches(' ');
+    let body = rest
+        .strip_prefix('=')
+        .filter(|body| !body.starts_with('='))?;
+    let body_text = body.trim_start();
+    let body_start = (end - body_text.len()) as u32;
+
+    let pattern_region = Region::new(region.start(), region.start().bump_column(ident_len as u32));
+    let body_region = Region::new(Position::new(body_start), region.end());
+
+    let value_def = ValueDef::Body(
+        arena.alloc(Loc::at(pattern_region, Pattern::Identifier { ident })),
+        arena.alloc(Loc::at(body_region, Expr::Malformed(body_text))),
+    );
+
+    Some(SingleDef {
+        type_or_value: Either::Second(value_def),
+        region,
+        spaces_before,
+        spaces_after: &[],
+    })
+}
+
+/// An item of a collection. When recovering from syntax errors, an item that doesn't parse
+/// is skipped (up to the next `,` or closing bracket) and becomes [Expr::Malformed].
+///
+/// `in_collection` puts the item's error in the context of its collection, for reporting.
+fn recovering_item<'a>(
+    item: impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>>,
+    in_collection: fn(&'a EExpr<'a>, Position) -> EExpr<'a>,
+) -> impl Parser<'a, Loc<Expr<'a>>, EExpr<'a>> {
+    move |arena: &'a Bump, state: State<'a>, min_indent: u32| {
+        let state_before = state.clone();
+
+        match item.parse(arena, state, min_indent) {
+            Err((MadeProgress, fail)) if state_before.recover => {
+                let bytes = state_before.original_bytes();
+                let start = state_before.pos().offset as usize;
+
+                let Some(next) = crate::recover::end_of_collection_item(bytes, start) else {
+                    return Err((MadeProgress, fail));
+                };
+
+                let end = crate::recover::trim_end(bytes, start, next);
+                let region = Region::new(state_before.pos(), Position::new(end as u32));
+                let text = std::str::from_utf8(&bytes[start..end]).unwrap_or_default();
+
+                let mut state = state_before.clone().skip_to(end);
+                state.push_recovered(
+                    arena,
+                    RecoveredError {
+                        error: parser::SyntaxError::Expr(
+                            in_collection(arena.alloc(fail), state_before.pos()),
+                            state_before.pos(),
+                        ),
+                        region,
+                    },
+                );
+
+                Ok((MadeProgress, Loc::at(region, Expr::Malformed(text)), state))
+            }
+            result => result,
+        }
+    }
+}
+
 // PARSER HELPERS
 
 fn closure_help<'a>(options: ExprParseOptions) -> impl Parser<'a, Expr<'a>, EClosure<'a>> {
@@ -3073,7 +3243,13 @@ fn list_literal_help<'a>() -> impl Parser<'a, Expr<'a>, EList<'a>> {
     map_with_arena!(
         collection_trailing_sep_e!(
             byte(b'[', EList::Open),
-            specialize_err_ref(EList::Expr, loc_expr(false)),
+            specialize_err_ref(
+                EList::Expr,
+                recovering_item(loc_expr(false), |fail, pos| EExpr::List(
+                    EList::Expr(fail, pos),
+                    pos
+                ))
+            ),
             byte(b',', EList::End),
             byte(b']', EList::End),
             Expr::SpaceBefore
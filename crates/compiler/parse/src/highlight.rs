@@ -94,7 +94,7 @@ fn highlight_inner<'a>(
 ) {
     loop {
         let start = state.pos();
-        if let Ok((b, _width)) = char::from_utf8_slice_start(state.bytes()) {
+        if let Ok((b, width)) = char::from_utf8_slice_start(state.bytes()) {
             match b {
                 ' ' | '\n' | '\t' | '\r' | '#' => {
                     let res: ParseResult<'a, _, EExpr<'a>> =
@@ -340,7 +340,7 @@ fn highlight_inner<'a>(
                     tokens.push(Loc::at(Region::between(start, state.pos()), Token::Paren));
                 }
                 _ => {
-                    state.advance_mut(1);
+                    state.advance_mut(width);
                     tokens.push(Loc::at(Region::between(start, state.pos()), Token::Other));
                 }
             }
@@ -356,6 +356,10 @@ fn fast_forward_to(
     start: roc_region::all::Position,
     end: impl Fn(u8) -> bool,
 ) {
+    // Always skip the byte where the problem is, even if it would end the error (like the
+    // newline in front of a tab), so that this keeps making progress.
+    state.advance_mut(1);
+
     while let Some(b) = state.bytes().first() {
         if end(*b) {
             break;
@@ -17,8 +17,10 @@ pub mod module;
 pub mod number_literal;
 pub mod pattern;
 pub mod problems;
+mod recover;
 pub mod src64;
 pub mod state;
 pub mod string_literal;
 pub mod test_helpers;
+pub mod tokens;
 pub mod type_annotation;
@@ -16,7 +16,7 @@ use crate::parser::{
     EProvides, ERequires, ETypedIdent, Parser, SourceError, SpaceProblem, SyntaxError,
 };
 use crate::pattern::record_pattern_fields;
-use crate::state::State;
+use crate::state::{RecoveredError, State};
 use crate::string_literal::{self, parse_str_literal};
 use crate::type_annotation;
 use roc_region::all::{Loc, Position, Region};
@@ -46,6 +46,38 @@ pub fn parse_module_defs<'a>(
     }
 }
 
+/// Like [parse_module_defs], but rather than stopping at the first syntax error, skip over
+/// the top-level def or collection item it's in and carry on. The code that was skipped
+/// becomes [Expr::Malformed](crate::ast::Expr::Malformed), so the rest of the module can
+/// still be used, and every syntax error found along the way is returned.
+pub fn parse_module_defs_recovering<'a>(
+    arena: &'a bumpalo::Bump,
+    state: State<'a>,
+    defs: Defs<'a>,
+) -> (Defs<'a>, Vec<RecoveredError<'a>>) {
+    let (defs, state) =
+        crate::expr::parse_top_level_defs_recovering(arena, state.recovering(), defs);
+
+    (defs, state.recovered_errors())
+}
+
+/// The syntax error that [parse_module_defs_recovering] skipped over at `region`, when
+/// parsing the module in `bytes`. Problems that outlive the parser's arena only keep the
+/// region, and use this to get the error back when it's time to report it.
+pub fn recovered_error_at<'a>(
+    arena: &'a bumpalo::Bump,
+    bytes: &'a [u8],
+    region: Region,
+) -> Option<SyntaxError<'a>> {
+    let (_, state) = parse_header(arena, State::new(bytes)).ok()?;
+    let (_, recovered) = parse_module_defs_recovering(arena, state, Defs::default());
+
+    recovered
+        .into_iter()
+        .find(|error| error.region == region)
+        .map(|error| error.error)
+}
+
 pub fn parse_header<'a>(
     arena: &'a bumpalo::Bump,
     state: State<'a>,
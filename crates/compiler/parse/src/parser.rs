@@ -136,6 +136,29 @@ pub enum EHeader<'a> {
     InconsistentModuleName(Region),
 }
 
+impl<'a> EHeader<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EHeader::Provides(inner, _) => inner.get_region(),
+            EHeader::Params(inner, _) => inner.get_region(),
+            EHeader::Exposes(inner, _) => inner.get_region(),
+            EHeader::Imports(inner, _) => inner.get_region(),
+            EHeader::Requires(inner, _) => inner.get_region(),
+            EHeader::Packages(inner, _) => inner.get_region(),
+            EHeader::Generates(inner, _) => inner.get_region(),
+            EHeader::GeneratesWith(inner, _) => inner.get_region(),
+            EHeader::AppName(inner, _) => inner.get_region(),
+            EHeader::PackageName(inner, _) => inner.get_region(),
+            EHeader::PlatformName(inner, _) => inner.get_region(),
+            EHeader::InconsistentModuleName(region) => *region,
+            EHeader::Space(_, pos)
+            | EHeader::Start(pos)
+            | EHeader::ModuleName(pos)
+            | EHeader::IndentStart(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EProvides<'a> {
     Provides(Position),
@@ -152,6 +175,25 @@ pub enum EProvides<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EProvides<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EProvides::Package(inner, _) => inner.get_region(),
+            EProvides::Provides(pos)
+            | EProvides::Open(pos)
+            | EProvides::To(pos)
+            | EProvides::IndentProvides(pos)
+            | EProvides::IndentTo(pos)
+            | EProvides::IndentListStart(pos)
+            | EProvides::IndentPackage(pos)
+            | EProvides::ListStart(pos)
+            | EProvides::ListEnd(pos)
+            | EProvides::Identifier(pos)
+            | EProvides::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EParams<'a> {
     Pattern(PRecord<'a>, Position),
@@ -161,6 +203,18 @@ pub enum EParams<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EParams<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EParams::Pattern(inner, _) => inner.get_region(),
+            EParams::BeforeArrow(pos)
+            | EParams::Arrow(pos)
+            | EParams::AfterArrow(pos)
+            | EParams::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EExposes {
     Exposes(Position),
@@ -173,6 +227,21 @@ pub enum EExposes {
     Space(BadInputError, Position),
 }
 
+impl EExposes {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExposes::Exposes(pos)
+            | EExposes::Open(pos)
+            | EExposes::IndentExposes(pos)
+            | EExposes::IndentListStart(pos)
+            | EExposes::ListStart(pos)
+            | EExposes::ListEnd(pos)
+            | EExposes::Identifier(pos)
+            | EExposes::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ERequires<'a> {
     Requires(Position),
@@ -186,6 +255,22 @@ pub enum ERequires<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ERequires<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERequires::TypedIdent(inner, _) => inner.get_region(),
+            ERequires::Requires(pos)
+            | ERequires::Open(pos)
+            | ERequires::IndentRequires(pos)
+            | ERequires::IndentListStart(pos)
+            | ERequires::ListStart(pos)
+            | ERequires::ListEnd(pos)
+            | ERequires::Rigid(pos)
+            | ERequires::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypedIdent<'a> {
     Space(BadInputError, Position),
@@ -197,6 +282,20 @@ pub enum ETypedIdent<'a> {
     Identifier(Position),
 }
 
+impl<'a> ETypedIdent<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypedIdent::Type(inner, _) => inner.get_region(),
+            ETypedIdent::Space(_, pos)
+            | ETypedIdent::HasType(pos)
+            | ETypedIdent::IndentHasType(pos)
+            | ETypedIdent::Name(pos)
+            | ETypedIdent::IndentType(pos)
+            | ETypedIdent::Identifier(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackages<'a> {
     Open(Position),
@@ -210,6 +309,22 @@ pub enum EPackages<'a> {
     PackageEntry(EPackageEntry<'a>, Position),
 }
 
+impl<'a> EPackages<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackages::PackageEntry(inner, _) => inner.get_region(),
+            EPackages::Open(pos)
+            | EPackages::Space(_, pos)
+            | EPackages::Packages(pos)
+            | EPackages::IndentPackages(pos)
+            | EPackages::ListStart(pos)
+            | EPackages::ListEnd(pos)
+            | EPackages::IndentListStart(pos)
+            | EPackages::IndentListEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageName<'a> {
     BadPath(EString<'a>, Position),
@@ -217,6 +332,15 @@ pub enum EPackageName<'a> {
     Multiline(Position),
 }
 
+impl<'a> EPackageName<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageName::BadPath(inner, _) => inner.get_region(),
+            EPackageName::Escapes(pos) | EPackageName::Multiline(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPackageEntry<'a> {
     BadPackage(EPackageName<'a>, Position),
@@ -228,6 +352,20 @@ pub enum EPackageEntry<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EPackageEntry<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPackageEntry::BadPackage(inner, _) => inner.get_region(),
+            EPackageEntry::Shorthand(pos)
+            | EPackageEntry::Colon(pos)
+            | EPackageEntry::IndentPackage(pos)
+            | EPackageEntry::IndentPlatform(pos)
+            | EPackageEntry::Platform(pos)
+            | EPackageEntry::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EImports {
     Open(Position),
@@ -251,6 +389,32 @@ pub enum EImports {
     StrLiteral(Position),
 }
 
+impl EImports {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EImports::Open(pos)
+            | EImports::Imports(pos)
+            | EImports::IndentImports(pos)
+            | EImports::IndentListStart(pos)
+            | EImports::IndentListEnd(pos)
+            | EImports::ListStart(pos)
+            | EImports::ListEnd(pos)
+            | EImports::Identifier(pos)
+            | EImports::ExposingDot(pos)
+            | EImports::ShorthandDot(pos)
+            | EImports::Shorthand(pos)
+            | EImports::ModuleName(pos)
+            | EImports::Space(_, pos)
+            | EImports::IndentSetStart(pos)
+            | EImports::SetStart(pos)
+            | EImports::SetEnd(pos)
+            | EImports::TypedIdent(pos)
+            | EImports::AsKeyword(pos)
+            | EImports::StrLiteral(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGenerates {
     Open(Position),
@@ -262,6 +426,20 @@ pub enum EGenerates {
     IndentTypeEnd(Position),
 }
 
+impl EGenerates {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGenerates::Open(pos)
+            | EGenerates::Generates(pos)
+            | EGenerates::IndentGenerates(pos)
+            | EGenerates::Identifier(pos)
+            | EGenerates::Space(_, pos)
+            | EGenerates::IndentTypeStart(pos)
+            | EGenerates::IndentTypeEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EGeneratesWith {
     Open(Position),
@@ -275,6 +453,22 @@ pub enum EGeneratesWith {
     Space(BadInputError, Position),
 }
 
+impl EGeneratesWith {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EGeneratesWith::Open(pos)
+            | EGeneratesWith::With(pos)
+            | EGeneratesWith::IndentWith(pos)
+            | EGeneratesWith::IndentListStart(pos)
+            | EGeneratesWith::IndentListEnd(pos)
+            | EGeneratesWith::ListStart(pos)
+            | EGeneratesWith::ListEnd(pos)
+            | EGeneratesWith::Identifier(pos)
+            | EGeneratesWith::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BadInputError {
     HasTab,
@@ -307,6 +501,28 @@ impl<'a, T> SourceError<'a, T> {
 }
 
 impl<'a> SyntaxError<'a> {
+    /// Where the parser got stuck, if the error knows. For errors inside expressions,
+    /// patterns and so on, this is the innermost position, which is where reports point.
+    pub fn get_region(&self) -> Option<Region> {
+        match self {
+            SyntaxError::Unexpected(region)
+            | SyntaxError::Eof(region)
+            | SyntaxError::ReservedKeyword(region)
+            | SyntaxError::ArgumentsBeforeEquals(region) => Some(*region),
+            SyntaxError::Type(problem) => Some(problem.get_region()),
+            SyntaxError::Pattern(problem) => Some(problem.get_region()),
+            SyntaxError::Expr(problem, _) => Some(problem.get_region()),
+            SyntaxError::Header(problem) => Some(problem.get_region()),
+            SyntaxError::NotEndOfFile(pos) => Some(Region::from_pos(*pos)),
+            SyntaxError::OutdentedTooFar
+            | SyntaxError::InvalidPattern
+            | SyntaxError::BadUtf8
+            | SyntaxError::NotYetImplemented(_)
+            | SyntaxError::Todo
+            | SyntaxError::Space(_) => None,
+        }
+    }
+
     pub fn into_source_error(self, state: &State<'a>) -> SourceError<'a, SyntaxError<'a>> {
         SourceError {
             problem: self,
@@ -384,6 +600,58 @@ pub enum EExpr<'a> {
     UnexpectedComma(Position),
 }
 
+impl<'a> EExpr<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpr::DefMissingFinalExpr2(inner, _) => inner.get_region(),
+            EExpr::Type(inner, _) => inner.get_region(),
+            EExpr::Pattern(inner, _) => inner.get_region(),
+            EExpr::Ability(inner, _) => inner.get_region(),
+            EExpr::When(inner, _) => inner.get_region(),
+            EExpr::If(inner, _) => inner.get_region(),
+            EExpr::Expect(inner, _) => inner.get_region(),
+            EExpr::Dbg(inner, _) => inner.get_region(),
+            EExpr::Import(inner, _) => inner.get_region(),
+            EExpr::Closure(inner, _) => inner.get_region(),
+            EExpr::InParens(inner, _) => inner.get_region(),
+            EExpr::Record(inner, _) => inner.get_region(),
+            EExpr::Str(inner, _) => inner.get_region(),
+            EExpr::List(inner, _) => inner.get_region(),
+            EExpr::ElmStyleFunction(region, _)
+            | EExpr::OptionalValueInRecordBuilder(region)
+            | EExpr::RecordUpdateBuilder(region) => *region,
+            EExpr::TrailingOperator(pos)
+            | EExpr::Start(pos)
+            | EExpr::End(pos)
+            | EExpr::BadExprEnd(pos)
+            | EExpr::Space(_, pos)
+            | EExpr::Dot(pos)
+            | EExpr::Access(pos)
+            | EExpr::UnaryNot(pos)
+            | EExpr::UnaryNegate(pos)
+            | EExpr::BadOperator(_, pos)
+            | EExpr::DefMissingFinalExpr(pos)
+            | EExpr::IndentDefBody(pos)
+            | EExpr::IndentEquals(pos)
+            | EExpr::IndentAnnotation(pos)
+            | EExpr::Equals(pos)
+            | EExpr::Colon(pos)
+            | EExpr::DoubleColon(pos)
+            | EExpr::Ident(pos)
+            | EExpr::MalformedPattern(pos)
+            | EExpr::QualifiedTag(pos)
+            | EExpr::BackpassComma(pos)
+            | EExpr::BackpassArrow(pos)
+            | EExpr::Underscore(pos)
+            | EExpr::Crash(pos)
+            | EExpr::Number(_, pos)
+            | EExpr::IndentStart(pos)
+            | EExpr::IndentEnd(pos)
+            | EExpr::UnexpectedComma(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ENumber {
     End,
@@ -409,6 +677,26 @@ pub enum EString<'a> {
     ExpectedDoubleQuoteGotSingleQuote(Position),
 }
 
+impl<'a> EString<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EString::Format(inner, _) => inner.get_region(),
+            EString::Open(pos)
+            | EString::CodePtOpen(pos)
+            | EString::CodePtEnd(pos)
+            | EString::InvalidSingleQuote(_, pos)
+            | EString::Space(_, pos)
+            | EString::EndlessSingleLine(pos)
+            | EString::EndlessMultiLine(pos)
+            | EString::EndlessSingleQuote(pos)
+            | EString::UnknownEscape(pos)
+            | EString::FormatEnd(pos)
+            | EString::MultilineInsufficientIndent(pos)
+            | EString::ExpectedDoubleQuoteGotSingleQuote(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ESingleQuote {
     Empty,
@@ -434,6 +722,23 @@ pub enum ERecord<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ERecord<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ERecord::Expr(inner, _) => inner.get_region(),
+            ERecord::End(pos)
+            | ERecord::Open(pos)
+            | ERecord::Updateable(pos)
+            | ERecord::Field(pos)
+            | ERecord::Colon(pos)
+            | ERecord::QuestionMark(pos)
+            | ERecord::Arrow(pos)
+            | ERecord::Ampersand(pos)
+            | ERecord::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EInParens<'a> {
     End(Position),
@@ -449,6 +754,18 @@ pub enum EInParens<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EInParens<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EInParens::Expr(inner, _) => inner.get_region(),
+            EInParens::End(pos)
+            | EInParens::Open(pos)
+            | EInParens::Empty(pos)
+            | EInParens::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EClosure<'a> {
     Space(BadInputError, Position),
@@ -464,6 +781,23 @@ pub enum EClosure<'a> {
     IndentArg(Position),
 }
 
+impl<'a> EClosure<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EClosure::Pattern(inner, _) => inner.get_region(),
+            EClosure::Body(inner, _) => inner.get_region(),
+            EClosure::Space(_, pos)
+            | EClosure::Start(pos)
+            | EClosure::Arrow(pos)
+            | EClosure::Comma(pos)
+            | EClosure::Arg(pos)
+            | EClosure::IndentArrow(pos)
+            | EClosure::IndentBody(pos)
+            | EClosure::IndentArg(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EList<'a> {
     Open(Position),
@@ -473,6 +807,15 @@ pub enum EList<'a> {
     Expr(&'a EExpr<'a>, Position),
 }
 
+impl<'a> EList<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EList::Expr(inner, _) => inner.get_region(),
+            EList::Open(pos) | EList::End(pos) | EList::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EWhen<'a> {
     Space(BadInputError, Position),
@@ -496,6 +839,29 @@ pub enum EWhen<'a> {
     PatternAlignment(u32, Position),
 }
 
+impl<'a> EWhen<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EWhen::Pattern(inner, _) => inner.get_region(),
+            EWhen::IfGuard(inner, _) => inner.get_region(),
+            EWhen::Condition(inner, _) => inner.get_region(),
+            EWhen::Branch(inner, _) => inner.get_region(),
+            EWhen::Space(_, pos)
+            | EWhen::When(pos)
+            | EWhen::Is(pos)
+            | EWhen::Arrow(pos)
+            | EWhen::Bar(pos)
+            | EWhen::IfToken(pos)
+            | EWhen::IndentCondition(pos)
+            | EWhen::IndentPattern(pos)
+            | EWhen::IndentArrow(pos)
+            | EWhen::IndentBranch(pos)
+            | EWhen::IndentIfGuard(pos)
+            | EWhen::PatternAlignment(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EAbility<'a> {
     Space(BadInputError, Position),
@@ -506,6 +872,18 @@ pub enum EAbility<'a> {
     DemandColon(Position),
 }
 
+impl<'a> EAbility<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EAbility::Type(inner, _) => inner.get_region(),
+            EAbility::Space(_, pos)
+            | EAbility::DemandAlignment(_, pos)
+            | EAbility::DemandName(pos)
+            | EAbility::DemandColon(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EIf<'a> {
     Space(BadInputError, Position),
@@ -525,6 +903,26 @@ pub enum EIf<'a> {
     IndentElseBranch(Position),
 }
 
+impl<'a> EIf<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EIf::Condition(inner, _) => inner.get_region(),
+            EIf::ThenBranch(inner, _) => inner.get_region(),
+            EIf::ElseBranch(inner, _) => inner.get_region(),
+            EIf::Space(_, pos)
+            | EIf::If(pos)
+            | EIf::Then(pos)
+            | EIf::Else(pos)
+            | EIf::IndentCondition(pos)
+            | EIf::IndentIf(pos)
+            | EIf::IndentThenToken(pos)
+            | EIf::IndentElseToken(pos)
+            | EIf::IndentThenBranch(pos)
+            | EIf::IndentElseBranch(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EExpect<'a> {
     Space(BadInputError, Position),
@@ -535,6 +933,19 @@ pub enum EExpect<'a> {
     IndentCondition(Position),
 }
 
+impl<'a> EExpect<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EExpect::Condition(inner, _) => inner.get_region(),
+            EExpect::Continuation(inner, _) => inner.get_region(),
+            EExpect::Space(_, pos)
+            | EExpect::Dbg(pos)
+            | EExpect::Expect(pos)
+            | EExpect::IndentCondition(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EImport<'a> {
     Import(Position),
@@ -565,6 +976,39 @@ pub enum EImport<'a> {
     EndNewline(Position),
 }
 
+impl<'a> EImport<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EImport::Params(inner, _) => inner.get_region(),
+            EImport::Annotation(inner, _) => inner.get_region(),
+            EImport::LowercaseAlias(region) => *region,
+            EImport::Import(pos)
+            | EImport::IndentStart(pos)
+            | EImport::PackageShorthand(pos)
+            | EImport::PackageShorthandDot(pos)
+            | EImport::ModuleName(pos)
+            | EImport::IndentAs(pos)
+            | EImport::As(pos)
+            | EImport::IndentAlias(pos)
+            | EImport::Alias(pos)
+            | EImport::IndentExposing(pos)
+            | EImport::Exposing(pos)
+            | EImport::ExposingListStart(pos)
+            | EImport::ExposedName(pos)
+            | EImport::ExposingListEnd(pos)
+            | EImport::IndentIngestedPath(pos)
+            | EImport::IngestedPath(pos)
+            | EImport::IndentIngestedName(pos)
+            | EImport::IngestedName(pos)
+            | EImport::IndentColon(pos)
+            | EImport::Colon(pos)
+            | EImport::IndentAnnotation(pos)
+            | EImport::Space(_, pos)
+            | EImport::EndNewline(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EImportParams<'a> {
     Indent(Position),
@@ -574,6 +1018,18 @@ pub enum EImportParams<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> EImportParams<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EImportParams::Record(inner, _) => inner.get_region(),
+            EImportParams::RecordUpdateFound(region) | EImportParams::RecordApplyFound(region) => {
+                *region
+            }
+            EImportParams::Indent(pos) | EImportParams::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EPattern<'a> {
     Record(PRecord<'a>, Position),
@@ -597,6 +1053,28 @@ pub enum EPattern<'a> {
     AccessorFunction(Position),
 }
 
+impl<'a> EPattern<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EPattern::Record(inner, _) => inner.get_region(),
+            EPattern::List(inner, _) => inner.get_region(),
+            EPattern::PInParens(inner, _) => inner.get_region(),
+            EPattern::AsKeyword(pos)
+            | EPattern::AsIdentifier(pos)
+            | EPattern::Underscore(pos)
+            | EPattern::NotAPattern(pos)
+            | EPattern::Start(pos)
+            | EPattern::End(pos)
+            | EPattern::Space(_, pos)
+            | EPattern::NumLiteral(_, pos)
+            | EPattern::IndentStart(pos)
+            | EPattern::IndentEnd(pos)
+            | EPattern::AsIndentStart(pos)
+            | EPattern::AccessorFunction(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PRecord<'a> {
     End(Position),
@@ -612,6 +1090,21 @@ pub enum PRecord<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PRecord<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            PRecord::Pattern(inner, _) => inner.get_region(),
+            PRecord::Expr(inner, _) => inner.get_region(),
+            PRecord::End(pos)
+            | PRecord::Open(pos)
+            | PRecord::Field(pos)
+            | PRecord::Colon(pos)
+            | PRecord::Optional(pos)
+            | PRecord::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PList<'a> {
     End(Position),
@@ -623,6 +1116,17 @@ pub enum PList<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PList<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            PList::Pattern(inner, _) => inner.get_region(),
+            PList::End(pos) | PList::Open(pos) | PList::Rest(pos) | PList::Space(_, pos) => {
+                Region::from_pos(*pos)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PInParens<'a> {
     Empty(Position),
@@ -633,6 +1137,18 @@ pub enum PInParens<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> PInParens<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            PInParens::Pattern(inner, _) => inner.get_region(),
+            PInParens::Empty(pos)
+            | PInParens::End(pos)
+            | PInParens::Open(pos)
+            | PInParens::Space(_, pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum EType<'a> {
     Space(BadInputError, Position),
@@ -659,6 +1175,32 @@ pub enum EType<'a> {
     TAsIndentStart(Position),
 }
 
+impl<'a> EType<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            EType::TRecord(inner, _) => inner.get_region(),
+            EType::TTagUnion(inner, _) => inner.get_region(),
+            EType::TInParens(inner, _) => inner.get_region(),
+            EType::TApply(inner, _) => inner.get_region(),
+            EType::TInlineAlias(inner, _) => inner.get_region(),
+            EType::TAbilityImpl(inner, _) => inner.get_region(),
+            EType::Space(_, pos)
+            | EType::UnderscoreSpacing(pos)
+            | EType::TBadTypeVariable(pos)
+            | EType::TWildcard(pos)
+            | EType::TInferred(pos)
+            | EType::TStart(pos)
+            | EType::TEnd(pos)
+            | EType::TFunctionArgument(pos)
+            | EType::TWhereBar(pos)
+            | EType::TImplementsClause(pos)
+            | EType::TIndentStart(pos)
+            | EType::TIndentEnd(pos)
+            | EType::TAsIndentStart(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeRecord<'a> {
     End(Position),
@@ -677,6 +1219,24 @@ pub enum ETypeRecord<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeRecord<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeRecord::Type(inner, _) => inner.get_region(),
+            ETypeRecord::End(pos)
+            | ETypeRecord::Open(pos)
+            | ETypeRecord::Field(pos)
+            | ETypeRecord::Colon(pos)
+            | ETypeRecord::Optional(pos)
+            | ETypeRecord::Space(_, pos)
+            | ETypeRecord::IndentOpen(pos)
+            | ETypeRecord::IndentColon(pos)
+            | ETypeRecord::IndentOptional(pos)
+            | ETypeRecord::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeTagUnion<'a> {
     End(Position),
@@ -687,6 +1247,17 @@ pub enum ETypeTagUnion<'a> {
     Space(BadInputError, Position),
 }
 
+impl<'a> ETypeTagUnion<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeTagUnion::Type(inner, _) => inner.get_region(),
+            ETypeTagUnion::End(pos) | ETypeTagUnion::Open(pos) | ETypeTagUnion::Space(_, pos) => {
+                Region::from_pos(*pos)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInParens<'a> {
     /// e.g. (), which isn't a valid type
@@ -704,6 +1275,20 @@ pub enum ETypeInParens<'a> {
     IndentEnd(Position),
 }
 
+impl<'a> ETypeInParens<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInParens::Type(inner, _) => inner.get_region(),
+            ETypeInParens::Empty(pos)
+            | ETypeInParens::End(pos)
+            | ETypeInParens::Open(pos)
+            | ETypeInParens::Space(_, pos)
+            | ETypeInParens::IndentOpen(pos)
+            | ETypeInParens::IndentEnd(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeApply {
     ///
@@ -716,6 +1301,19 @@ pub enum ETypeApply {
     StartIsNumber(Position),
 }
 
+impl ETypeApply {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeApply::StartNotUppercase(pos)
+            | ETypeApply::End(pos)
+            | ETypeApply::Space(_, pos)
+            | ETypeApply::DoubleDot(pos)
+            | ETypeApply::TrailingDot(pos)
+            | ETypeApply::StartIsNumber(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeInlineAlias {
     NotAnAlias(Position),
@@ -723,6 +1321,16 @@ pub enum ETypeInlineAlias {
     ArgumentNotLowercase(Position),
 }
 
+impl ETypeInlineAlias {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeInlineAlias::NotAnAlias(pos)
+            | ETypeInlineAlias::Qualified(pos)
+            | ETypeInlineAlias::ArgumentNotLowercase(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ETypeAbilityImpl<'a> {
     End(Position),
@@ -744,6 +1352,27 @@ pub enum ETypeAbilityImpl<'a> {
     IndentAmpersand(Position),
 }
 
+impl<'a> ETypeAbilityImpl<'a> {
+    pub fn get_region(&self) -> Region {
+        match self {
+            ETypeAbilityImpl::Type(inner, _) => inner.get_region(),
+            ETypeAbilityImpl::Expr(inner, _) => inner.get_region(),
+            ETypeAbilityImpl::End(pos)
+            | ETypeAbilityImpl::Open(pos)
+            | ETypeAbilityImpl::Field(pos)
+            | ETypeAbilityImpl::Colon(pos)
+            | ETypeAbilityImpl::Arrow(pos)
+            | ETypeAbilityImpl::Optional(pos)
+            | ETypeAbilityImpl::Space(_, pos)
+            | ETypeAbilityImpl::Updateable(pos)
+            | ETypeAbilityImpl::QuestionMark(pos)
+            | ETypeAbilityImpl::Ampersand(pos)
+            | ETypeAbilityImpl::IndentBar(pos)
+            | ETypeAbilityImpl::IndentAmpersand(pos) => Region::from_pos(*pos),
+        }
+    }
+}
+
 impl<'a> From<ERecord<'a>> for ETypeAbilityImpl<'a> {
     fn from(e: ERecord<'a>) -> Self {
         match e {
@@ -0,0 +1,169 @@
+//! Finding where to carry on parsing after a syntax error.
+//!
+//! The code being skipped is exactly the code that doesn't parse, so this only looks at
+//! the bytes, keeping track of brackets, strings and comments along the way.
+
+/// Where to carry on after a top-level def starting at `start` failed to parse: the start
+/// of the next line that isn't indented or blank, or the end of the file.
+pub(crate) fn next_top_level_def(bytes: &[u8], start: usize) -> usize {
+    let mut index = start;
+
+    while let Some(newline) = find_byte(bytes, index, b'\n') {
+        let line_start = newline + 1;
+
+        match bytes.get(line_start) {
+            None => return bytes.len(),
+            Some(b' ' | b'\t' | b'\r' | b'\n') => index = line_start,
+            Some(_) => return line_start,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Where the collection item starting at `start` ends, when it failed to parse: the next
+/// `,` or closing bracket outside of any brackets opened in the item itself.
+///
+/// Gives up at the start of the next top-level def, since the collection is probably
+/// never closed at all.
+pub(crate) fn end_of_collection_item(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut index = start;
+
+    while let Some(&byte) = bytes.get(index) {
+        match byte {
+            b'"' => {
+                index = end_of_string(bytes, index);
+                continue;
+            }
+            b'#' => {
+                index = find_byte(bytes, index, b'\n').unwrap_or(bytes.len());
+                continue;
+            }
+            b'(' | b'[' | b'{' => depth += 1,
+            b')' | b']' | b'}' if depth == 0 => return Some(index),
+            b')' | b']' | b'}' => depth -= 1,
+            b',' if depth == 0 => return Some(index),
+            b'\n' => {
+                if !matches!(
+                    bytes.get(index + 1),
+                    None | Some(b' ' | b'\t' | b'\r' | b'\n')
+                ) {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// The end of `bytes[start..end]`, leaving out any whitespace it ends with.
+pub(crate) fn trim_end(bytes: &[u8], start: usize, end: usize) -> usize {
+    let trailing = bytes[start..end]
+        .iter()
+        .rev()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+
+    end - trailing
+}
+
+/// The index just past the string starting at `start`, or the end of its line if it's
+/// never closed (except for block strings, which can span lines).
+fn end_of_string(bytes: &[u8], start: usize) -> usize {
+    if bytes[start..].starts_with(b"\"\"\"") {
+        let mut index = start + 3;
+
+        while index < bytes.len() {
+            if bytes[index..].starts_with(b"\"\"\"") {
+                return index + 3;
+            }
+
+            index += 1;
+        }
+
+        return bytes.len();
+    }
+
+    let mut index = start + 1;
+
+    while let Some(&byte) = bytes.get(index) {
+        match byte {
+            b'\\' => index += 2,
+            b'"' => return index + 1,
+            b'\n' => return index,
+            _ => index += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+fn find_byte(bytes: &[u8], start: usize, needle: u8) -> Option<usize> {
+    bytes
+        .get(start..)?
+        .iter()
+        .position(|byte| *byte == needle)
+        .map(|offset| start + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_def_after_broken_def() {
+        let src = b"a = 1 +\n\nb = 2\n";
+
+        assert_eq!(next_top_level_def(src, 0), 9);
+        assert_eq!(&src[9..], b"b = 2\n");
+    }
+
+    #[test]
+    fn next_def_skips_indented_lines() {
+        let src = b"a =\n    when x is\n\n        1 -> (\n  2\nb = 3\n";
+        let next = next_top_level_def(src, 0);
+
+        assert_eq!(&src[next..], b"b = 3\n");
+    }
+
+    #[test]
+    fn next_def_at_end_of_file() {
+        let src = b"a = 1 +\n    2 +\n";
+
+        assert_eq!(next_top_level_def(src, 0), src.len());
+    }
+
+    #[test]
+    fn collection_item_ends_at_comma() {
+        let src = b"[1, 2 +, 3]";
+
+        assert_eq!(end_of_collection_item(src, 4), Some(7));
+    }
+
+    #[test]
+    fn collection_item_skips_nested_brackets_strings_and_comments() {
+        let src = b"[f (1, 2) \"a, b]\" # c, d]\n    , 3]";
+        let end = end_of_collection_item(src, 1).unwrap();
+
+        assert_eq!(&src[end..], b", 3]");
+    }
+
+    #[test]
+    fn collection_item_gives_up_at_next_def() {
+        let src = b"a = [1, 2 +\n\nb = 3\n";
+
+        assert_eq!(end_of_collection_item(src, 8), None);
+    }
+
+    #[test]
+    fn trim_end_leaves_out_trailing_whitespace() {
+        let src = b"a = 1 +  \n\n";
+
+        assert_eq!(trim_end(src, 0, src.len()), 7);
+    }
+}
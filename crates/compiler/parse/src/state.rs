@@ -1,7 +1,7 @@
 use roc_region::all::{Position, Region};
 use std::fmt;
 
-use crate::parser::Progress;
+use crate::parser::{Progress, SyntaxError};
 
 /// A position in a source file.
 // NB: [Copy] is explicitly NOT derived to reduce the chance of bugs due to accidentally re-using
@@ -21,6 +21,27 @@ pub struct State<'a> {
 
     /// Position of the first non-whitespace character on the current line
     pub(crate) line_start_after_whitespace: Position,
+
+    /// Whether to skip over syntax errors where possible, rather than stopping at the first.
+    pub(crate) recover: bool,
+
+    /// The syntax errors skipped over so far, most recent first. These belong to the state
+    /// (rather than living on the side) so that they're forgotten when the parser backtracks.
+    recovered: Option<&'a RecoveredErrors<'a>>,
+}
+
+/// A syntax error the parser skipped over, to carry on parsing what comes after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveredError<'a> {
+    pub error: SyntaxError<'a>,
+    /// The code that was skipped.
+    pub region: Region,
+}
+
+#[derive(Debug)]
+struct RecoveredErrors<'a> {
+    error: RecoveredError<'a>,
+    rest: Option<&'a RecoveredErrors<'a>>,
 }
 
 impl<'a> State<'a> {
@@ -33,9 +54,62 @@ impl<'a> State<'a> {
             // Technically not correct.
             // We don't know the position of the first non-whitespace character yet.
             line_start_after_whitespace: Position::zero(),
+
+            recover: false,
+            recovered: None,
         }
     }
 
+    /// Skip over syntax errors where possible, rather than stopping at the first.
+    pub fn recovering(mut self) -> State<'a> {
+        self.recover = true;
+        self
+    }
+
+    /// Record a syntax error that was skipped over.
+    pub(crate) fn push_recovered(&mut self, arena: &'a bumpalo::Bump, error: RecoveredError<'a>) {
+        self.recovered = Some(arena.alloc(RecoveredErrors {
+            error,
+            rest: self.recovered,
+        }));
+    }
+
+    /// The syntax errors skipped over so far, in the order they appear in the source.
+    pub fn recovered_errors(&self) -> Vec<RecoveredError<'a>> {
+        let mut errors = Vec::new();
+        let mut next = self.recovered;
+
+        while let Some(node) = next {
+            errors.push(node.error.clone());
+            next = node.rest;
+        }
+
+        errors.reverse();
+        errors
+    }
+
+    /// Skip ahead to `offset`, keeping track of any lines that were skipped along the way.
+    pub(crate) fn skip_to(mut self, offset: usize) -> State<'a> {
+        debug_assert!(offset >= self.offset);
+
+        for (index, byte) in self.original_bytes[self.offset..offset].iter().enumerate() {
+            if *byte == b'\n' {
+                self.line_start = Position::new((self.offset + index + 1) as u32);
+            }
+        }
+
+        self.offset = offset;
+
+        let indent = self.original_bytes[self.line_start.offset as usize..]
+            .iter()
+            .take_while(|byte| **byte == b' ')
+            .count();
+        self.line_start_after_whitespace =
+            Position::new((self.line_start.offset + indent as u32).min(offset as u32));
+
+        self
+    }
+
     pub fn original_bytes(&self) -> &'a [u8] {
         self.original_bytes
     }
@@ -0,0 +1,229 @@
+//! A lossless view of the source code, as a table of tokens.
+//!
+//! The AST only keeps comments in [SpaceBefore](crate::ast::Expr::SpaceBefore) and
+//! [SpaceAfter](crate::ast::Expr::SpaceAfter) wrappers, and doesn't keep whitespace at all.
+//! Every byte of the source belongs to exactly one token in a [TokenTable], so tools that
+//! edit the source can see exactly what's in a region before they replace it, and check
+//! that no comments went missing afterwards.
+use roc_region::all::{Loc, Position, Region};
+
+use crate::highlight::{self, Token};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// A run of spaces and tabs.
+    Whitespace,
+    /// A line break, either `\n` or `\r\n`.
+    Newline,
+    /// Anything else, including comments.
+    Code(Token),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenTable<'a> {
+    src: &'a str,
+    tokens: Vec<Loc<TokenKind>>,
+}
+
+impl<'a> TokenTable<'a> {
+    pub fn new(src: &'a str) -> Self {
+        let mut tokens = Vec::new();
+        let mut offset = 0;
+
+        for token in highlight::highlight(src) {
+            let range = token.byte_range();
+
+            // The highlighter never produces these, but the table has to stay in order.
+            if range.start < offset || range.is_empty() {
+                continue;
+            }
+
+            push_gap(src, offset, range.start, &mut tokens);
+            tokens.push(Loc::at(token.region, TokenKind::Code(token.value)));
+
+            offset = range.end;
+        }
+
+        push_gap(src, offset, src.len(), &mut tokens);
+
+        TokenTable { src, tokens }
+    }
+
+    pub fn src(&self) -> &'a str {
+        self.src
+    }
+
+    /// All the tokens, in order. Together they cover the whole source, without overlapping.
+    pub fn tokens(&self) -> &[Loc<TokenKind>] {
+        &self.tokens
+    }
+
+    pub fn text(&self, token: &Loc<TokenKind>) -> &'a str {
+        &self.src[token.byte_range()]
+    }
+
+    /// The source, put back together from the text of every token.
+    pub fn to_source(&self) -> String {
+        self.tokens.iter().map(|token| self.text(token)).collect()
+    }
+
+    /// The token that the byte at `pos` belongs to.
+    pub fn token_at(&self, pos: Position) -> Option<&Loc<TokenKind>> {
+        let index = self
+            .tokens
+            .partition_point(|token| token.region.end() <= pos);
+
+        self.tokens
+            .get(index)
+            .filter(|token| token.region.start() <= pos)
+    }
+
+    /// Every comment (including doc comments), with its `#`s.
+    pub fn comments(&self) -> impl Iterator<Item = Loc<&'a str>> + '_ {
+        self.tokens.iter().filter_map(|token| match token.value {
+            TokenKind::Code(Token::LineComment | Token::DocComment) => {
+                Some(Loc::at(token.region, self.text(token)))
+            }
+            _ => None,
+        })
+    }
+
+    /// The comments that are entirely inside `region`, which an edit replacing the code in
+    /// that region would drop, unless it puts them back.
+    pub fn comments_within(&self, region: Region) -> impl Iterator<Item = Loc<&'a str>> + '_ {
+        self.comments()
+            .filter(move |comment| region.contains(&comment.region))
+    }
+
+    /// The comments in this source that are missing from `other`, a different version of it.
+    ///
+    /// Comments are matched up by what they say, since moving a comment, or changing how
+    /// it's spaced out, doesn't lose it.
+    pub fn missing_comments(&self, other: &TokenTable<'_>) -> Vec<Loc<&'a str>> {
+        let mut remaining: Vec<&str> = other.comments().map(|c| comment_key(c.value)).collect();
+
+        self.comments()
+            .filter(|comment| {
+                let key = comment_key(comment.value);
+
+                match remaining.iter().position(|other_key| *other_key == key) {
+                    Some(index) => {
+                        remaining.swap_remove(index);
+                        false
+                    }
+                    None => true,
+                }
+            })
+            .collect()
+    }
+}
+
+/// What a comment says, without the `#`s it starts with or the spaces around it.
+fn comment_key(comment: &str) -> &str {
+    comment.trim_start_matches('#').trim()
+}
+
+/// Split the bytes between two highlighted tokens into whitespace, line breaks, and
+/// whatever else the highlighter skipped over.
+fn push_gap(src: &str, start: usize, end: usize, tokens: &mut Vec<Loc<TokenKind>>) {
+    let bytes = src.as_bytes();
+    let mut offset = start;
+
+    while offset < end {
+        let (kind, len) = match &bytes[offset..end] {
+            [b'\n', ..] => (TokenKind::Newline, 1),
+            [b'\r', b'\n', ..] => (TokenKind::Newline, 2),
+            rest @ [b' ' | b'\t', ..] => (
+                TokenKind::Whitespace,
+                rest.iter()
+                    .take_while(|b| matches!(b, b' ' | b'\t'))
+                    .count(),
+            ),
+            // Only ASCII bytes end this, so it always ends on a char boundary.
+            rest => (
+                TokenKind::Code(Token::Error),
+                rest.iter()
+                    .take_while(|b| !matches!(b, b' ' | b'\t' | b'\n'))
+                    .count()
+                    .max(1),
+            ),
+        };
+
+        let region = Region::new(
+            Position::new(offset as u32),
+            Position::new((offset + len) as u32),
+        );
+        tokens.push(Loc::at(region, kind));
+
+        offset += len;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(src: &str) -> Vec<(TokenKind, &str)> {
+        let table = TokenTable::new(src);
+
+        table
+            .tokens()
+            .iter()
+            .map(|token| (token.value, table.text(token)))
+            .collect()
+    }
+
+    #[test]
+    fn reproduces_the_source() {
+        let src = "app [main] { pf: platform \"main.roc\" }\r\n\n# Hi!\nmain =\n\t\"héllo\" # 👋\n  |> Str.concat \"!\"  \n";
+
+        assert_eq!(TokenTable::new(src).to_source(), src);
+    }
+
+    #[test]
+    fn keeps_whitespace_and_newlines() {
+        use Token::*;
+        use TokenKind::*;
+
+        assert_eq!(
+            kinds("x =  1 # one\r\n"),
+            [
+                (Code(LowerIdent), "x"),
+                (Whitespace, " "),
+                (Code(Equals), "="),
+                (Whitespace, "  "),
+                (Code(Number), "1"),
+                (Whitespace, " "),
+                (Code(LineComment), "# one"),
+                (Newline, "\r\n"),
+            ]
+        );
+    }
+
+    #[test]
+    fn token_at() {
+        let table = TokenTable::new("foo = bar");
+
+        let text_at = |offset| table.token_at(Position::new(offset)).map(|t| table.text(t));
+
+        assert_eq!(text_at(0), Some("foo"));
+        assert_eq!(text_at(2), Some("foo"));
+        assert_eq!(text_at(3), Some(" "));
+        assert_eq!(text_at(8), Some("bar"));
+        assert_eq!(text_at(9), None);
+    }
+
+    #[test]
+    fn missing_comments() {
+        let before = TokenTable::new("# one\nx = 1 # two\n## three\ny = 2\n");
+        let after = TokenTable::new("#one\nx = 1\n\n## three\ny = 2\n");
+
+        let missing: Vec<&str> = before
+            .missing_comments(&after)
+            .into_iter()
+            .map(|comment| comment.value)
+            .collect();
+
+        assert_eq!(missing, ["# two"]);
+    }
+}
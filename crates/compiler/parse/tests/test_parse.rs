@@ -20,13 +20,15 @@ mod test_parse {
     use bumpalo::{self, Bump};
     use roc_parse::ast::Expr::{self, *};
     use roc_parse::ast::StrSegment::*;
-    use roc_parse::ast::{self, EscapedChar};
+    use roc_parse::ast::{self, EscapedChar, ExtractSpaces};
     use roc_parse::ast::{CommentOrNewline, StrLiteral::*};
-    use roc_parse::module::parse_module_defs;
+    use roc_parse::module::{
+        parse_header, parse_module_defs, parse_module_defs_recovering, recovered_error_at,
+    };
     use roc_parse::parser::SyntaxError;
     use roc_parse::state::State;
     use roc_parse::test_helpers::parse_expr_with;
-    use roc_region::all::{Loc, Region};
+    use roc_region::all::{LineInfo, Loc, Region};
     use std::{f64, i64};
 
     fn assert_parses_to<'a>(input: &'a str, expected_expr: Expr<'a>) {
@@ -404,6 +406,128 @@ mod test_parse {
 
     // PARSE ERROR
 
+    #[test]
+    fn recover_from_several_broken_defs() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+                a = 1
+
+                b = 2 +
+
+                c = 3
+
+                d = ) 4
+
+                e = 5
+            "#
+        );
+
+        let (defs, errors) =
+            parse_module_defs_recovering(&arena, State::new(src.as_bytes()), ast::Defs::default());
+
+        let error_lines: std::vec::Vec<u32> = errors
+            .iter()
+            .map(|error| LineInfo::new(src).convert_pos(error.region.start()).line)
+            .collect();
+        assert_eq!(error_lines, [2, 6]);
+
+        let bodies: std::vec::Vec<_> = defs
+            .value_defs
+            .iter()
+            .map(|def| match def {
+                ast::ValueDef::Body(_, body) => body.value.extract_spaces().item,
+                other => panic!("Expected a body, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            bodies,
+            [
+                Num("1"),
+                Malformed("2 +"),
+                Num("3"),
+                Malformed(") 4"),
+                Num("5")
+            ]
+        );
+    }
+
+    #[test]
+    fn recover_inside_list() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+                a = [1, 2 +, (3, 4 *), 5]
+
+                b = 6
+            "#
+        );
+
+        let (defs, errors) =
+            parse_module_defs_recovering(&arena, State::new(src.as_bytes()), ast::Defs::default());
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(defs.value_defs.len(), 2);
+
+        let ast::ValueDef::Body(_, body) = defs.value_defs[0] else {
+            panic!("Expected a body, got {:?}", defs.value_defs[0]);
+        };
+        let List(items) = body.value else {
+            panic!("Expected a list, got {:?}", body.value);
+        };
+        let items: std::vec::Vec<_> = items.iter().map(|item| item.value).collect();
+
+        assert_eq!(items.len(), 4);
+        assert_eq!(items[0], Num("1"));
+        assert_eq!(items[1], Malformed("2 +"));
+        assert_eq!(items[3], Num("5"));
+
+        let Tuple(tuple_items) = items[2] else {
+            panic!("Expected a tuple, got {:?}", items[2]);
+        };
+        assert_eq!(tuple_items.items[1].value, Malformed("4 *"));
+    }
+
+    #[test]
+    fn recovered_error_at_region() {
+        let arena = Bump::new();
+        let src = indoc!(
+            r#"
+                module [a, b]
+
+                a = 1 +
+
+                b = 2
+            "#
+        );
+
+        let (_, state) = parse_header(&arena, State::new(src.as_bytes())).unwrap();
+        let (_, errors) = parse_module_defs_recovering(&arena, state, ast::Defs::default());
+
+        let [recovered] = errors.as_slice() else {
+            panic!("Expected one syntax error, got {errors:?}");
+        };
+
+        assert_eq!(
+            recovered_error_at(&arena, src.as_bytes(), recovered.region),
+            Some(recovered.error.clone())
+        );
+        assert_eq!(
+            recovered_error_at(&arena, src.as_bytes(), Region::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn strict_parsing_does_not_recover() {
+        let arena = Bump::new();
+        let src = "a = [1, 2 +, 3]\n\nb = 5\n";
+
+        assert!(
+            parse_module_defs(&arena, State::new(src.as_bytes()), ast::Defs::default()).is_err()
+        );
+    }
+
     // TODO this should be parse error, but isn't!
     // #[test]
     // fn trailing_paren() {
@@ -32,6 +32,15 @@ pub enum ShadowKind {
     Ability(Symbol),
 }
 
+/// How a pattern binds a name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingKind {
+    Variable,
+    /// A field of a record destructure, like `x` in `{ x }`. The name is also the field it
+    /// takes apart, so renaming it would change which field that is.
+    RecordField,
+}
+
 /// Problems that can occur in the course of canonicalization.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Problem {
@@ -57,8 +66,8 @@ pub enum Problem {
     /// First symbol is the name of the closure with that argument
     /// Bool is whether the closure is anonymous
     /// Second symbol is the name of the argument that is unused
-    UnusedArgument(Symbol, bool, Symbol, Region),
-    UnusedBranchDef(Symbol, Region),
+    UnusedArgument(Symbol, bool, Symbol, BindingKind, Region),
+    UnusedBranchDef(Symbol, BindingKind, Region),
     DefsOnlyUsedInRecursion(usize, Region),
     PrecedenceProblem(PrecedenceProblem),
     // Example: (5 = 1 + 2) is an unsupported pattern in an assignment; Int patterns aren't allowed in assignments!
@@ -236,6 +245,13 @@ pub enum Problem {
         one_occurrence: Region,
         kind: AliasKind,
     },
+    /// A syntax error the parser skipped over, so that the rest of the module could still be
+    /// checked. Syntax errors borrow from the parser's arena, so this only holds the region
+    /// of the code that was skipped; reporting gets the error itself back with
+    /// [recovered_error_at](roc_parse::module::recovered_error_at).
+    SyntaxError {
+        region: Region,
+    },
 }
 
 impl Problem {
@@ -252,8 +268,8 @@ impl Problem {
             Problem::ImportShadowsSymbol { .. } => RuntimeError,
             Problem::ExposedButNotDefined(_) => RuntimeError,
             Problem::UnknownGeneratesWith(_) => RuntimeError,
-            Problem::UnusedArgument(_, _, _, _) => Warning,
-            Problem::UnusedBranchDef(_, _) => Warning,
+            Problem::UnusedArgument(_, _, _, _, _) => Warning,
+            Problem::UnusedBranchDef(_, _, _) => Warning,
             Problem::PrecedenceProblem(_) => RuntimeError,
             Problem::UnsupportedPattern(_, _) => RuntimeError,
             Problem::Shadowing { .. } => RuntimeError,
@@ -308,6 +324,7 @@ impl Problem {
             Problem::OverAppliedCrash { .. } => RuntimeError,
             Problem::DefsOnlyUsedInRecursion(_, _) => Warning,
             Problem::FileProblem { .. } => Fatal,
+            Problem::SyntaxError { .. } => RuntimeError,
         }
     }
 
@@ -333,9 +350,10 @@ impl Problem {
             | Problem::ExplicitBuiltinImport(_, region)
             | Problem::ExplicitBuiltinTypeImport(_, region)
             | Problem::ImportShadowsSymbol { region, .. }
+            | Problem::SyntaxError { region, .. }
             | Problem::UnknownGeneratesWith(Loc { region, .. })
-            | Problem::UnusedArgument(_, _, _, region)
-            | Problem::UnusedBranchDef(_, region)
+            | Problem::UnusedArgument(_, _, _, _, region)
+            | Problem::UnusedBranchDef(_, _, region)
             | Problem::PrecedenceProblem(PrecedenceProblem::BothNonAssociative(region, _, _))
             | Problem::UnsupportedPattern(_, region)
             | Problem::CyclicAlias(_, region, _, _)
@@ -401,6 +419,7 @@ impl Problem {
             | Problem::RuntimeError(RuntimeError::InvalidPrecedence(_, region))
             | Problem::RuntimeError(RuntimeError::MalformedIdentifier(_, _, region))
             | Problem::RuntimeError(RuntimeError::MalformedTypeName(_, region))
+            | Problem::RuntimeError(RuntimeError::MalformedExpr(region))
             | Problem::RuntimeError(RuntimeError::MalformedClosure(region))
             | Problem::RuntimeError(RuntimeError::MalformedSuffixed(region))
             | Problem::RuntimeError(RuntimeError::InvalidRecordUpdate { region })
@@ -480,6 +499,64 @@ impl Problem {
             | Problem::ExposedButNotDefined(_) => None,
         }
     }
+
+    /// Other regions that are relevant to the problem, besides the one [Problem::region] returns.
+    /// For example, where a shadowed name was originally defined.
+    pub fn related_regions(&self) -> Vec<Region> {
+        match self {
+            Problem::Shadowing { shadow, .. }
+            | Problem::RuntimeError(RuntimeError::Shadowing { shadow, .. }) => vec![shadow.region],
+            Problem::ImportNameConflict {
+                existing_import: ScopeModuleSource::Import(region),
+                ..
+            }
+            | Problem::ImportShadowsSymbol {
+                existing_symbol_region: region,
+                ..
+            }
+            | Problem::InvalidOptionalValue {
+                field_region: region,
+                ..
+            }
+            | Problem::RuntimeError(RuntimeError::InvalidOptionalValue {
+                field_region: region,
+                ..
+            })
+            | Problem::SignatureDefMismatch {
+                annotation_pattern: region,
+                ..
+            }
+            | Problem::NestedDatatype {
+                differing_recursion_region: region,
+                ..
+            }
+            | Problem::DuplicateImpl {
+                duplicate: region, ..
+            } => vec![*region],
+            Problem::DuplicateRecordFieldValue {
+                field_region,
+                replaced_region,
+                ..
+            }
+            | Problem::DuplicateRecordFieldType {
+                field_region,
+                replaced_region,
+                ..
+            } => vec![*field_region, *replaced_region],
+            Problem::DuplicateTag {
+                tag_region,
+                replaced_region,
+                ..
+            } => vec![*tag_region, *replaced_region],
+            Problem::RuntimeError(RuntimeError::CircularDef(cycle_entries))
+            | Problem::BadRecursion(cycle_entries) => cycle_entries
+                .iter()
+                .skip(1)
+                .map(|entry| entry.expr_region)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -631,6 +708,8 @@ pub enum RuntimeError {
     InvalidPrecedence(PrecedenceProblem, Region),
     MalformedIdentifier(Box<str>, roc_parse::ident::BadIdent, Region),
     MalformedTypeName(Box<str>, Region),
+    /// Code that didn't parse, which the parser skipped over.
+    MalformedExpr(Region),
     MalformedClosure(Region),
     InvalidRecordUpdate {
         region: Region,
@@ -699,6 +778,7 @@ impl RuntimeError {
             | RuntimeError::InvalidPrecedence(_, region)
             | RuntimeError::MalformedIdentifier(_, _, region)
             | RuntimeError::MalformedTypeName(_, region)
+            | RuntimeError::MalformedExpr(region)
             | RuntimeError::MalformedClosure(region)
             | RuntimeError::MalformedSuffixed(region)
             | RuntimeError::InvalidRecordUpdate { region }
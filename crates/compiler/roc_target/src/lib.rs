@@ -140,6 +140,16 @@ impl Target {
         }
     }
 
+    pub const fn dynamic_library_file_ext(&self) -> &str {
+        use Target::*;
+        match self {
+            LinuxX32 | LinuxX64 | LinuxArm64 => "so",
+            MacX64 | MacArm64 => "dylib",
+            WinX32 | WinX64 | WinArm64 => "dll",
+            Wasm32 => "wasm",
+        }
+    }
+
     pub const fn executable_file_ext(&self) -> Option<&str> {
         use Target::*;
         match self {
@@ -74,6 +74,40 @@ impl TypeError {
             TypeError::IngestedFileUnsupportedType(_, _) => None,
         }
     }
+
+    /// Other regions that are relevant to the problem, besides the one [TypeError::region]
+    /// returns. For example, the annotation an expression was checked against.
+    pub fn related_regions(&self) -> Vec<Region> {
+        let primary = self.region();
+        let mut regions = match self {
+            TypeError::BadExpr(_, _, _, expected) => match expected {
+                Expected::NoExpectation(_) => Vec::new(),
+                Expected::FromAnnotation(loc_pattern, ..) => expected
+                    .get_annotation_region()
+                    .into_iter()
+                    .chain([loc_pattern.region])
+                    .collect(),
+                Expected::ForReason(_, _, region) => vec![*region],
+            },
+            TypeError::BadPattern(_, _, _, PExpected::ForReason(_, _, region)) => vec![*region],
+            TypeError::CircularDef(cycle) => cycle
+                .iter()
+                .skip(1)
+                .map(|entry| entry.symbol_region)
+                .collect(),
+            TypeError::Exhaustive(roc_exhaustive::Error::Redundant { overall_region, .. })
+            | TypeError::Exhaustive(roc_exhaustive::Error::Unmatchable {
+                overall_region, ..
+            }) => vec![*overall_region],
+            _ => Vec::new(),
+        };
+
+        // A reason's region is often the problem's own region, which isn't worth repeating
+        regions.retain(|region| Some(*region) != primary);
+        regions.dedup();
+
+        regions
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -1,19 +1,31 @@
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to;
 
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
+
 #[cfg(feature = "gen-wasm")]
 use crate::helpers::wasm::assert_evals_to;
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 use indoc::indoc;
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 use roc_std::RocList;
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 use roc_std::RocStr;
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn hash_specialization() {
     assert_evals_to!(
         indoc!(
@@ -36,7 +48,7 @@ fn hash_specialization() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn hash_specialization_multiple_add() {
     assert_evals_to!(
         indoc!(
@@ -63,7 +75,7 @@ fn hash_specialization_multiple_add() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn alias_member_specialization() {
     assert_evals_to!(
         indoc!(
@@ -88,7 +100,7 @@ fn alias_member_specialization() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn ability_constrained_in_non_member_usage() {
     assert_evals_to!(
         indoc!(
@@ -113,7 +125,7 @@ fn ability_constrained_in_non_member_usage() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn ability_constrained_in_non_member_usage_inferred() {
     assert_evals_to!(
         indoc!(
@@ -137,7 +149,7 @@ fn ability_constrained_in_non_member_usage_inferred() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn ability_constrained_in_non_member_multiple_specializations() {
     assert_evals_to!(
         indoc!(
@@ -165,7 +177,7 @@ fn ability_constrained_in_non_member_multiple_specializations() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn ability_constrained_in_non_member_multiple_specializations_inferred() {
     assert_evals_to!(
         indoc!(
@@ -192,7 +204,7 @@ fn ability_constrained_in_non_member_multiple_specializations_inferred() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn ability_used_as_type_still_compiles() {
     assert_evals_to!(
         indoc!(
@@ -220,7 +232,7 @@ fn ability_used_as_type_still_compiles() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn bounds_to_multiple_abilities() {
     assert_evals_to!(
         indoc!(
@@ -247,7 +259,7 @@ fn bounds_to_multiple_abilities() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode() {
     assert_evals_to!(
         indoc!(
@@ -291,7 +303,7 @@ fn encode() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore = "running into weird let-generalization issue when a variable is only in output position, see #3660"]
 fn decode() {
     assert_evals_to!(
@@ -349,7 +361,7 @@ fn decode() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_use_stdlib() {
     assert_evals_to!(
         indoc!(
@@ -377,7 +389,7 @@ fn encode_use_stdlib() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_use_stdlib_without_wrapping_custom() {
     assert_evals_to!(
         indoc!(
@@ -403,7 +415,7 @@ fn encode_use_stdlib_without_wrapping_custom() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derive_to_encoder_for_opaque() {
     assert_evals_to!(
         indoc!(
@@ -427,7 +439,7 @@ fn encode_derive_to_encoder_for_opaque() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_encoder_encode_custom_has_capture() {
     assert_evals_to!(
         indoc!(
@@ -458,17 +470,26 @@ mod encode_immediate {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
 
+    #[cfg(feature = "gen-dev")]
+    use crate::helpers::dev::assert_evals_to;
+
     #[cfg(feature = "gen-wasm")]
     use crate::helpers::wasm::assert_evals_to;
 
-    #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+    #[cfg(all(
+        test,
+        any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+    ))]
     use indoc::indoc;
 
-    #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+    #[cfg(all(
+        test,
+        any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+    ))]
     use roc_std::RocStr;
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn string() {
         assert_evals_to!(
             indoc!(
@@ -487,7 +508,7 @@ mod encode_immediate {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn ranged_number() {
         assert_evals_to!(
             indoc!(
@@ -506,7 +527,7 @@ mod encode_immediate {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn bool() {
         assert_evals_to!(
             indoc!(
@@ -527,7 +548,7 @@ mod encode_immediate {
     macro_rules! num_immediate {
         ($($num:expr, $typ:ident)*) => {$(
             #[test]
-            #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+            #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
             fn $typ() {
                 assert_evals_to!(
                     &format!(indoc!(
@@ -566,7 +587,7 @@ mod encode_immediate {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_record_one_field_string() {
     assert_evals_to!(
         indoc!(
@@ -589,7 +610,7 @@ fn encode_derived_record_one_field_string() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_record_two_fields_strings() {
     assert_evals_to!(
         indoc!(
@@ -613,7 +634,7 @@ fn encode_derived_record_two_fields_strings() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_nested_record_string() {
     assert_evals_to!(
         indoc!(
@@ -637,7 +658,7 @@ fn encode_derived_nested_record_string() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_tag_one_payload_string() {
     assert_evals_to!(
         indoc!(
@@ -660,7 +681,7 @@ fn encode_derived_tag_one_payload_string() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_tag_two_payloads_string() {
     assert_evals_to!(
         indoc!(
@@ -683,7 +704,7 @@ fn encode_derived_tag_two_payloads_string() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_nested_tag_string() {
     assert_evals_to!(
         indoc!(
@@ -708,7 +729,7 @@ fn encode_derived_nested_tag_string() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_nested_record_tag_record() {
     assert_evals_to!(
         indoc!(
@@ -732,7 +753,7 @@ fn encode_derived_nested_record_tag_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_list_string() {
     assert_evals_to!(
         indoc!(
@@ -757,7 +778,7 @@ fn encode_derived_list_string() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_list_of_records() {
     assert_evals_to!(
         indoc!(
@@ -781,7 +802,7 @@ fn encode_derived_list_of_records() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_list_of_lists_of_strings() {
     assert_evals_to!(
         indoc!(
@@ -806,7 +827,7 @@ fn encode_derived_list_of_lists_of_strings() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_record_with_many_types() {
     assert_evals_to!(
         indoc!(
@@ -833,7 +854,7 @@ fn encode_derived_record_with_many_types() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_tuple_two_fields() {
     assert_evals_to!(
         indoc!(
@@ -856,7 +877,7 @@ fn encode_derived_tuple_two_fields() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_tuple_of_tuples() {
     assert_evals_to!(
         indoc!(
@@ -880,7 +901,7 @@ fn encode_derived_tuple_of_tuples() {
 
 #[test]
 #[cfg(not(debug_assertions))]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_generic_record_with_different_field_types() {
     assert_evals_to!(
         indoc!(
@@ -906,7 +927,7 @@ fn encode_derived_generic_record_with_different_field_types() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_derived_generic_tag_with_different_field_types() {
     assert_evals_to!(
         indoc!(
@@ -933,7 +954,7 @@ fn encode_derived_generic_tag_with_different_field_types() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn specialize_unique_newtype_records() {
     crate::helpers::with_larger_debug_stack(|| {
         assert_evals_to!(
@@ -958,7 +979,7 @@ fn specialize_unique_newtype_records() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn decode_use_stdlib() {
     assert_evals_to!(
         indoc!(
@@ -990,7 +1011,7 @@ fn decode_use_stdlib() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_derive_decoder_for_opaque() {
@@ -1015,7 +1036,7 @@ fn decode_derive_decoder_for_opaque() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn decode_use_stdlib_json_list() {
     assert_evals_to!(
         indoc!(
@@ -1049,10 +1070,16 @@ mod decode_immediate {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
 
+    #[cfg(feature = "gen-dev")]
+    use crate::helpers::dev::assert_evals_to;
+
     #[cfg(feature = "gen-wasm")]
     use crate::helpers::wasm::assert_evals_to;
 
-    #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+    #[cfg(all(
+        test,
+        any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+    ))]
     use indoc::indoc;
 
     #[cfg(all(test, feature = "gen-llvm"))]
@@ -1159,7 +1186,7 @@ mod decode_immediate {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn dec() {
         use roc_std::RocDec;
 
@@ -1181,7 +1208,7 @@ mod decode_immediate {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn decode_list_of_strings() {
     crate::helpers::with_larger_debug_stack(|| {
         assert_evals_to!(
@@ -1202,7 +1229,7 @@ fn decode_list_of_strings() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn encode_then_decode_list_of_strings() {
     crate::helpers::with_larger_debug_stack(|| {
         assert_evals_to!(
@@ -1246,7 +1273,7 @@ fn encode_then_decode_list_of_lists_of_strings() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_record_two_fields() {
@@ -1268,7 +1295,7 @@ fn decode_record_two_fields() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_record_two_fields_string_and_int() {
@@ -1290,7 +1317,7 @@ fn decode_record_two_fields_string_and_int() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_record_two_fields_string_and_string_infer() {
@@ -1312,7 +1339,7 @@ fn decode_record_two_fields_string_and_string_infer() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_record_two_fields_string_and_string_infer_local_var() {
@@ -1335,7 +1362,7 @@ fn decode_record_two_fields_string_and_string_infer_local_var() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_record_two_fields_string_and_string_infer_local_var_destructured() {
@@ -1357,7 +1384,7 @@ fn decode_record_two_fields_string_and_string_infer_local_var_destructured() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore = "json parsing impl must be fixed first"]
 fn decode_empty_record() {
     assert_evals_to!(
@@ -1378,7 +1405,7 @@ fn decode_empty_record() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(feature = "gen-llvm-wasm"), // hits a wasm3 stack overflow
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
@@ -1401,7 +1428,7 @@ fn decode_record_of_record() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_tuple_two_elements() {
@@ -1423,7 +1450,7 @@ fn decode_tuple_two_elements() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 fn decode_tuple_of_tuples() {
@@ -1443,11 +1470,17 @@ fn decode_tuple_of_tuples() {
     )
 }
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 mod hash {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
 
+    #[cfg(feature = "gen-dev")]
+    use crate::helpers::dev::assert_evals_to;
+
     #[cfg(feature = "gen-wasm")]
     use crate::helpers::wasm::assert_evals_to;
 
@@ -2017,11 +2050,17 @@ mod hash {
     }
 }
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 mod eq {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
 
+    #[cfg(feature = "gen-dev")]
+    use crate::helpers::dev::assert_evals_to;
+
     #[cfg(feature = "gen-wasm")]
     use crate::helpers::wasm::assert_evals_to;
 
@@ -2143,7 +2182,7 @@ mod eq {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn issue_4772_weakened_monomorphic_destructure() {
     crate::helpers::with_larger_debug_stack(|| {
         assert_evals_to!(
@@ -2181,17 +2220,26 @@ mod inspect {
     #[cfg(feature = "gen-llvm")]
     use crate::helpers::llvm::assert_evals_to;
 
+    #[cfg(feature = "gen-dev")]
+    use crate::helpers::dev::assert_evals_to;
+
     #[cfg(feature = "gen-wasm")]
     use crate::helpers::wasm::assert_evals_to;
 
-    #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+    #[cfg(all(
+        test,
+        any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+    ))]
     use indoc::indoc;
 
-    #[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+    #[cfg(all(
+        test,
+        any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+    ))]
     use roc_std::RocStr;
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn bool() {
         assert_evals_to!(
             indoc!(
@@ -2210,7 +2258,7 @@ mod inspect {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn num() {
         assert_evals_to!(
             indoc!(
@@ -2242,7 +2290,7 @@ mod inspect {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn list() {
         assert_evals_to!(
             indoc!(
@@ -2264,7 +2312,7 @@ mod inspect {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn str() {
         assert_evals_to!(
             indoc!(
@@ -2286,7 +2334,7 @@ mod inspect {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn opaque_automatic() {
         assert_evals_to!(
             indoc!(
@@ -2304,7 +2352,7 @@ mod inspect {
     }
 
     #[test]
-    #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+    #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
     fn opaque_automatic_with_polymorphic_call() {
         assert_evals_to!(
             indoc!(
@@ -1,13 +1,13 @@
 #![cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(debug_assertions) // https://github.com/roc-lang/roc/issues/3898
 ))]
 
 #[cfg(feature = "gen-llvm")]
 use crate::helpers::llvm::assert_evals_to;
 
-// #[cfg(feature = "gen-dev")]
-// use crate::helpers::dev::assert_evals_to;
+#[cfg(feature = "gen-dev")]
+use crate::helpers::dev::assert_evals_to;
 
 #[cfg(feature = "gen-wasm")]
 use crate::helpers::wasm::assert_evals_to;
@@ -16,7 +16,7 @@ use indoc::indoc;
 use roc_std::{RocList, RocStr};
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_empty_len() {
     assert_evals_to!(
         indoc!(
@@ -30,7 +30,7 @@ fn dict_empty_len() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_insert_empty() {
     assert_evals_to!(
         indoc!(
@@ -46,7 +46,7 @@ fn dict_insert_empty() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_empty_contains() {
     assert_evals_to!(
         indoc!(
@@ -63,7 +63,7 @@ fn dict_empty_contains() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_nonempty_contains() {
     assert_evals_to!(
         indoc!(
@@ -81,7 +81,7 @@ fn dict_nonempty_contains() {
 
 #[test]
 #[ignore = "TODO figure out why this is broken with llvm wasm tests"]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_empty_remove() {
     assert_evals_to!(
         indoc!(
@@ -100,7 +100,7 @@ fn dict_empty_remove() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_nonempty_remove() {
     assert_evals_to!(
         indoc!(
@@ -120,7 +120,7 @@ fn dict_nonempty_remove() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn dict_nonempty_get() {
     assert_evals_to!(
         indoc!(
@@ -163,7 +163,7 @@ fn dict_nonempty_get() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn keys() {
     assert_evals_to!(
         indoc!(
@@ -185,7 +185,7 @@ fn keys() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn values() {
     assert_evals_to!(
         indoc!(
@@ -207,7 +207,7 @@ fn values() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn from_list_with_fold_simple() {
     assert_evals_to!(
         indoc!(
@@ -226,7 +226,7 @@ fn from_list_with_fold_simple() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn from_list_with_fold_reallocates() {
     assert_evals_to!(
         indoc!(
@@ -256,7 +256,7 @@ fn from_list_with_fold_reallocates() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn small_str_keys() {
     assert_evals_to!(
         indoc!(
@@ -275,7 +275,7 @@ fn small_str_keys() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn big_str_keys() {
     assert_evals_to!(
         indoc!(
@@ -300,7 +300,7 @@ fn big_str_keys() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn big_str_values() {
     assert_evals_to!(
         indoc!(
@@ -325,7 +325,7 @@ fn big_str_values() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn unit_values() {
     assert_evals_to!(
         indoc!(
@@ -347,7 +347,7 @@ fn unit_values() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn single() {
     assert_evals_to!(
         indoc!(
@@ -365,7 +365,7 @@ fn single() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn insert_all() {
     assert_evals_to!(
         indoc!(
@@ -384,7 +384,7 @@ fn insert_all() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn insert_all_prefer_second() {
     assert_evals_to!(
         indoc!(
@@ -403,7 +403,7 @@ fn insert_all_prefer_second() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn keep_shared() {
     assert_evals_to!(
         indoc!(
@@ -435,7 +435,7 @@ fn keep_shared() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn keep_shared_value_must_match() {
     assert_evals_to!(
         indoc!(
@@ -466,7 +466,7 @@ fn keep_shared_value_must_match() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn remove_all() {
     assert_evals_to!(
         indoc!(
@@ -498,7 +498,7 @@ fn remove_all() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn remove_all_prefer_first() {
     assert_evals_to!(
         indoc!(
@@ -529,7 +529,7 @@ fn remove_all_prefer_first() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn walk_sum_keys() {
     assert_evals_to!(
         indoc!(
@@ -488,7 +488,7 @@ fn list_split_last() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn list_chunks_of() {
     assert_evals_to!(
         "List.chunksOf [1, 2, 3, 4, 5, 6, 7, 8] 3",
@@ -2993,7 +2993,7 @@ fn list_map_with_index() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = r#"Roc failed with message: "Integer addition overflowed!"#)]
 fn cleanup_because_exception() {
     assert_evals_to!(
@@ -3096,7 +3096,7 @@ fn list_all_empty_with_unknown_element_type() {
 // then it works. We don't know what the problem is here!
 #[cfg(all(
     not(target_family = "windows"),
-    any(feature = "gen-llvm", feature = "gen-wasm")
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
 ))]
 #[should_panic(expected = r#"Roc failed with message: "invalid ret_layout""#)]
 fn lists_with_incompatible_type_param_in_if() {
@@ -3308,7 +3308,7 @@ fn list_find_empty_layout() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn list_find_index() {
     assert_evals_to!(
         indoc!(
@@ -3912,7 +3912,7 @@ fn list_range_length_overflow() {
     );
 }
 
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 mod pattern_match {
     #[allow(unused_imports)]
     use crate::helpers::with_larger_debug_stack;
@@ -480,13 +480,13 @@ fn f64_sqrt_checked_0() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn f64_sqrt_checked_positive() {
     assert_evals_to!("Num.sqrtChecked 100f64", RocResult::ok(10.0), RocResult<f64, ()>);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn f64_sqrt_checked_negative() {
     assert_evals_to!("Num.sqrtChecked -1f64", RocResult::err(()), RocResult<f64, ()>);
 }
@@ -498,19 +498,19 @@ fn f64_log() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn f64_log_checked_one() {
     assert_evals_to!("Num.logChecked 1f64", RocResult::ok(0.0), RocResult<f64, ()>);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn f64_log_checked_zero() {
     assert_evals_to!("Num.logChecked 0f64", RocResult::err(()), RocResult<f64, ()>);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn f64_log_negative() {
     assert_evals_to!("Num.log -1f64", true, f64, |f: f64| f.is_nan());
 }
@@ -546,7 +546,7 @@ fn f32_abs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn i64_abs() {
     assert_evals_to!("Num.abs -6", 6, i64);
     assert_evals_to!("Num.abs 7", 7, i64);
@@ -561,7 +561,7 @@ fn i64_abs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn various_sized_abs() {
     assert_evals_to!("Num.abs -6i8", 6, i8);
     assert_evals_to!("Num.abs -6i16", 6, i16);
@@ -580,7 +580,7 @@ fn various_sized_abs() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(
     expected = r#"Roc failed with message: "Integer absolute overflowed because its argument is the minimum value"#
 )]
@@ -621,7 +621,7 @@ fn gen_if_fn() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_float_eq() {
     assert_evals_to!(
         indoc!(
@@ -635,7 +635,7 @@ fn gen_float_eq() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_add_dec() {
     assert_evals_to!(
         indoc!(
@@ -696,7 +696,7 @@ fn gen_div_f32() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_div_checked_f64() {
     assert_evals_to!(
         indoc!(
@@ -712,7 +712,7 @@ fn gen_div_checked_f64() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn gen_div_checked_by_zero_f64() {
     assert_evals_to!(
         indoc!(
@@ -1625,7 +1625,7 @@ fn int_negate() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(
     expected = r#"Roc failed with message: "Integer negation overflowed because its argument is the minimum value"#
 )]
@@ -1939,7 +1939,7 @@ fn int_add_wrap() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_add_checked_pass() {
     assert_evals_to!(
         "Num.addChecked 1.0 0.0f64",
@@ -1949,7 +1949,7 @@ fn float_add_checked_pass() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_add_checked_fail() {
     assert_evals_to!(
         "Num.addChecked 1.7976931348623157e308f64 1.7976931348623157e308",
@@ -1959,7 +1959,7 @@ fn float_add_checked_fail() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_add_overflow() {
     assert_evals_to!(
         "1.7976931348623157e308f64 + 1.7976931348623157e308",
@@ -2014,7 +2014,7 @@ fn int_sub_checked() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_sub_checked() {
     assert_evals_to!(
         "Num.subChecked 1.0 0.0f64",
@@ -2084,7 +2084,7 @@ fn int_mul_checked() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
 fn float_mul_checked() {
     assert_evals_to!(
         "Num.mulChecked 20.0 2.0f64",
@@ -2362,12 +2362,12 @@ num_conversion_tests! {
         to_i64_sign_extend_i8, "-15i8", -15, ["gen-wasm", "gen-dev"]
         to_i64_sign_extend_i16, "-15i16", -15, ["gen-wasm", "gen-dev"]
         to_i64_sign_extend_i32, "-15i32", -15, ["gen-wasm", "gen-dev"]
-        to_i64_truncate, "115i128", 115
-        to_i64_truncate_wraps, "10_000_000_000_000_000_000i128", -8446744073709551616
+        to_i64_truncate, "115i128", 115, ["gen-dev"]
+        to_i64_truncate_wraps, "10_000_000_000_000_000_000i128", -8446744073709551616, ["gen-dev"]
     )
     "Num.toI128", i128, (
         to_i128_same_width, "15u128", 15, ["gen-dev"]
-        to_i128_extend, "15i8", 15
+        to_i128_extend, "15i8", 15, ["gen-dev"]
     )
     "Num.toU8", u8, (
         to_u8_same_width, "15i8", 15, ["gen-wasm", "gen-dev"]
@@ -2389,12 +2389,12 @@ num_conversion_tests! {
     "Num.toU64", u64, (
         to_u64_same_width, "15i64", 15, ["gen-wasm", "gen-dev"]
         to_u64_extend, "15i8", 15, ["gen-wasm", "gen-dev"]
-        to_u64_truncate, "115i128", 115
-        to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128
+        to_u64_truncate, "115i128", 115, ["gen-dev"]
+        to_u64_truncate_wraps, "10_000_000_000_000_000_000_000i128", 1864712049423024128, ["gen-dev"]
     )
     "Num.toU128", u128, (
         to_u128_same_width, "15i128", 15, ["gen-dev"]
-        to_u128_extend, "15i8", 15
+        to_u128_extend, "15i8", 15, ["gen-dev"]
         to_u128_big, "11562537357600483583u64", 11562537357600483583, ["gen-dev"]
     )
     "Num.toF32", f32, (
@@ -2430,7 +2430,7 @@ num_conversion_tests! {
 macro_rules! to_int_checked_tests {
     ($($fn:expr, $typ:ty, ($($test_name:ident, $input:expr, $output:expr)*))*) => {$($(
         #[test]
-        #[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+        #[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
         fn $test_name() {
             let sentinel = 23;
             // Some n = Ok n, None = OutOfBounds
@@ -2551,35 +2551,35 @@ fn wrap_with_default(test_roc_code: &str) -> String {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_i128_checked_smaller_width_pos() {
     let test_roc_code = wrap_with_default("Num.toI128Checked 15i8");
     assert_evals_to!(&test_roc_code, I128::from(15), I128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_i128_checked_smaller_width_neg() {
     let test_roc_code = wrap_with_default("Num.toI128Checked -15i8");
     assert_evals_to!(&test_roc_code, I128::from(-15), I128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_i128_checked_same() {
     let test_roc_code = wrap_with_default("Num.toI128Checked 15i128");
     assert_evals_to!(&test_roc_code, I128::from(15), I128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_i128_checked_same_width_unsigned_fits() {
     let test_roc_code = wrap_with_default("Num.toI128Checked 15u128");
     assert_evals_to!(&test_roc_code, I128::from(15), I128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_i128_checked_same_width_unsigned_oob() {
     let test_roc_code =
         "Result.isErr (Num.toI128Checked 170141183460469231731687303715884105728u128)";
@@ -2587,35 +2587,35 @@ fn to_i128_checked_same_width_unsigned_oob() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_u128_checked_smaller_width_pos() {
     let test_roc_code = wrap_with_default("Num.toU128Checked 15i8");
     assert_evals_to!(&test_roc_code, U128::from(15), U128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_u128_checked_smaller_width_neg_oob() {
     let test_roc_code = "Result.isErr (Num.toU128Checked -15i8)";
     assert_evals_to!(&test_roc_code, true, bool)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_u128_checked_same() {
     let test_roc_code = wrap_with_default("Num.toU128Checked 15u128");
     assert_evals_to!(&test_roc_code, U128::from(15), U128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_u128_checked_same_width_signed_fits() {
     let test_roc_code = wrap_with_default("Num.toU128Checked 15i128");
     assert_evals_to!(&test_roc_code, U128::from(15), U128)
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn to_u128_checked_same_width_signed_oob() {
     let test_roc_code = "Result.isErr (Num.toU128Checked -1i128)";
     assert_evals_to!(&test_roc_code, true, bool)
@@ -3271,7 +3271,7 @@ fn upcast_of_int_is_zext() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 // https://github.com/roc-lang/roc/issues/2696
 fn upcast_of_int_checked_is_zext() {
     assert_evals_to!(
@@ -3560,7 +3560,7 @@ fn num_abs_diff_int() {
 }
 
 #[test]
-#[cfg(feature = "gen-llvm")]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
 fn num_abs_diff_large_bits() {
     assert_evals_to!(r"Num.absDiff 0u128 0u128", U128::from(0), U128);
     assert_evals_to!(r"Num.absDiff 1u128 2u128", U128::from(1), U128);
@@ -3925,3 +3925,166 @@ fn infinity_f32() {
 fn infinity_f64() {
     assert_evals_to!(r"Num.infinityF64", f64::INFINITY, f64);
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn div_rem_large_bits() {
+    assert_evals_to!(
+        "Num.divTrunc -100000000000000000000000i128 7",
+        I128::from(-100000000000000000000000i128 / 7),
+        I128
+    );
+    assert_evals_to!(
+        "Num.rem -100000000000000000000000i128 7",
+        I128::from(-100000000000000000000000i128 % 7),
+        I128
+    );
+    assert_evals_to!(
+        "Num.divTrunc Num.maxU128 3",
+        U128::from(u128::MAX / 3),
+        U128
+    );
+    assert_evals_to!(
+        "Num.rem Num.maxU128 1000",
+        U128::from(u128::MAX % 1000),
+        U128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn neg_abs_large_bits() {
+    assert_evals_to!(
+        "Num.neg 123456789012345678901234i128",
+        I128::from(-123456789012345678901234i128),
+        I128
+    );
+    assert_evals_to!(
+        "Num.neg -18446744073709551616i128",
+        I128::from(18446744073709551616i128),
+        I128
+    );
+    assert_evals_to!(
+        "Num.abs -123456789012345678901234i128",
+        I128::from(123456789012345678901234i128),
+        I128
+    );
+    assert_evals_to!("Num.abs Num.maxU128", U128::from(u128::MAX), U128);
+    assert_evals_to!("Num.abs Num.maxU64", u64::MAX, u64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn sub_wrap_large_bits() {
+    assert_evals_to!("Num.subWrap Num.minI128 1", I128::from(i128::MAX), I128);
+    assert_evals_to!("Num.subWrap 0u128 1", U128::from(u128::MAX), U128);
+    assert_evals_to!(
+        "Num.subWrap 18446744073709551616u128 1",
+        U128::from(u64::MAX as u128),
+        U128
+    );
+    assert_evals_to!(
+        "Num.subWrap 5i128 -18446744073709551616",
+        I128::from(5 + (1i128 << 64)),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn shift_large_bits() {
+    assert_evals_to!("Num.shiftLeftBy 1i128 100", I128::from(1i128 << 100), I128);
+    assert_evals_to!("Num.shiftLeftBy 3u128 127", U128::from(3u128 << 127), U128);
+    assert_evals_to!(
+        "Num.shiftRightBy Num.minI128 70",
+        I128::from(i128::MIN >> 70),
+        I128
+    );
+    assert_evals_to!(
+        "Num.shiftRightZfBy Num.minI128 70",
+        I128::from(((i128::MIN as u128) >> 70) as i128),
+        I128
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn int_casts_sign_and_zero_extend() {
+    assert_evals_to!("Num.toI16 255u8", 255, i16);
+    assert_evals_to!("Num.toI32 65535u16", 65535, i32);
+    assert_evals_to!("Num.toI64 4294967295u32", 4294967295, i64);
+    assert_evals_to!("Num.toU16 -1i8", u16::MAX, u16);
+    assert_evals_to!("Num.toU64 -2i32", -2i64 as u64, u64);
+    assert_evals_to!("Num.toI8 384i16", -128, i8);
+    assert_evals_to!("Num.toU8 -1i64", 255, u8);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn int_casts_large_bits() {
+    assert_evals_to!("Num.toI128 -7i8", I128::from(-7), I128);
+    assert_evals_to!("Num.toU64 18446744073709551617u128", 1, u64);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn dec_to_float() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : Dec
+            x = 1.5
+
+            y : F64
+            y = Num.toFrac x
+
+            y
+            "
+        ),
+        1.5,
+        f64
+    );
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : Dec
+            x = -2.25
+
+            y : F32
+            y = Num.toFrac x
+
+            y
+            "
+        ),
+        -2.25,
+        f32
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn float_to_dec() {
+    assert_evals_to!(
+        indoc!(
+            r"
+            x : F64
+            x = 2.5
+
+            y : Dec
+            y = Num.toFrac x
+
+            y
+            "
+        ),
+        RocDec::from_str("2.5").unwrap(),
+        RocDec
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn to_float_checked() {
+    assert_evals_to!("Num.toF32Checked 1.5f64", RocResult::ok(1.5), RocResult<f32, ()>);
+    assert_evals_to!("Num.toF32Checked Num.maxF64", RocResult::err(()), RocResult<f32, ()>);
+    assert_evals_to!("Num.toF64Checked 7i64", RocResult::ok(7.0), RocResult<f64, ()>);
+}
@@ -949,7 +949,7 @@ fn when_peano() {
 // We should try this out on Windows again after making adjustments to the Result C ABI!
 #[cfg(all(
     not(target_family = "windows"),
-    any(feature = "gen-llvm", feature = "gen-wasm")
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
 ))]
 #[should_panic(expected = "Roc failed with message: ")]
 fn overflow_frees_list() {
@@ -975,7 +975,7 @@ fn overflow_frees_list() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = "Roc failed with message: ")]
 fn undefined_variable() {
     assert_evals_to!(
@@ -1011,7 +1011,7 @@ fn a_crash() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = "Roc failed with message: ")]
 fn annotation_without_body() {
     assert_evals_to!(
@@ -1502,7 +1502,7 @@ fn rbtree_insert() {
 
 #[test]
 #[cfg(all(
-    any(feature = "gen-llvm", feature = "gen-wasm"),
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"),
     not(feature = "gen-llvm-wasm")
 ))]
 fn rbtree_balance_3() {
@@ -1529,7 +1529,7 @@ fn rbtree_balance_3() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 fn rbtree_layout_issue() {
     // there is a flex var in here somewhere that blows up layout creation
@@ -1571,7 +1571,7 @@ fn rbtree_layout_issue() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 fn rbtree_balance_mono_problem() {
     // because of how the function is written, only `Red` is used and so in the function's
@@ -1789,7 +1789,7 @@ fn linked_list_double_pattern_match() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 // dev backend: this test somehow corrupts the errors vector ?!
 fn binary_tree_double_pattern_match() {
     assert_evals_to!(
@@ -1970,7 +1970,7 @@ fn alias_of_alias_with_type_arguments() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 fn todo_bad_error_message() {
     assert_evals_to!(
@@ -2035,7 +2035,7 @@ fn hof_conditional() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(
     expected = "Roc failed with message: \"Shadowing { original_region: @55-56, shadow: @88-89 Ident"
 )]
@@ -2055,7 +2055,7 @@ fn pattern_shadowing() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 #[should_panic(expected = "")]
 fn unsupported_pattern_str_interp() {
@@ -2073,7 +2073,7 @@ fn unsupported_pattern_str_interp() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 fn fingertree_basic() {
     assert_evals_to!(
@@ -2136,7 +2136,7 @@ fn case_or_pattern() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore]
 fn rosetree_basic() {
     assert_evals_to!(
@@ -2513,7 +2513,7 @@ fn backpassing_result() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = "Shadowing { original_region: @55-56, shadow: @72-73 Ident")]
 fn function_malformed_pattern() {
     assert_evals_to!(
@@ -2530,7 +2530,7 @@ fn function_malformed_pattern() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore = "causes alias analysis panics, should roc_panic"]
 fn call_invalid_layout() {
     assert_evals_to!(
@@ -2914,7 +2914,7 @@ fn unresolved_tvar_when_capture_is_unused() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = "Roc failed with message: ")]
 fn value_not_exposed_hits_panic() {
     assert_evals_to!(
@@ -3590,7 +3590,7 @@ fn polymorphic_lambda_set_multiple_specializations() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn list_map2_conslist() {
     // this had an RC problem, https://github.com/roc-lang/roc/issues/2968
     assert_evals_to!(
@@ -3823,7 +3823,7 @@ fn shared_pattern_variable_in_when_branches() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn symbol_not_bound_in_all_patterns_runs_when_no_bound_symbol_used() {
     assert_evals_to!(
         indoc!(
@@ -3842,7 +3842,7 @@ fn symbol_not_bound_in_all_patterns_runs_when_no_bound_symbol_used() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn symbol_not_bound_in_all_patterns_runs_when_bound_pattern_reached() {
     assert_evals_to!(
         indoc!(
@@ -3859,7 +3859,7 @@ fn symbol_not_bound_in_all_patterns_runs_when_bound_pattern_reached() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(
     expected = r#"Roc failed with message: "Hit a branch pattern that does not bind all symbols its body needs"#
 )]
@@ -4664,3 +4664,154 @@ fn multiple_uses_of_bool_true_tag_union() {
         bool
     );
 }
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev"))]
+fn dbg_and_expect_in_a_def() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            main : I64
+            main =
+                x = 41
+                dbg x
+
+                expect x == 41
+
+                x + 1
+            "#
+        ),
+        42,
+        i64
+    );
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn loop_with_unchanged_parameter_and_known_condition() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            sumTo : U64, U64, U64 -> U64
+            sumTo = \i, n, acc ->
+                if i > n then
+                    acc
+                else
+                    sumTo (i + 1) n (acc + i)
+
+            main : U64
+            main =
+                if 2 + 3 > 4 then
+                    sumTo 0 100 0
+                else
+                    0
+            "#
+        ),
+        5050,
+        u64
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-dev")]
+fn failed_expect_clones_list_of_strings() {
+    let (result, memory) = crate::helpers::dev::run_with_expects::<i64>(indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        main : I64
+        main =
+            words : List Str
+            words = ["short", "a string that is too long to be small"]
+
+            expect List.isEmpty words
+
+            42
+        "#
+    ));
+    assert_eq!(result.ok(), Some(42));
+
+    let read = |at: usize| usize::from_ne_bytes(memory[at..at + 8].try_into().unwrap());
+
+    // one failure, whose only lookup starts after the frame header and the lookup table
+    assert_eq!(read(0), 1);
+    let words = read(24 + 12);
+
+    let (elements, len) = (read(words), read(words + 8));
+    assert_eq!(len, 2);
+    assert_eq!(&memory[elements..elements + 5], b"short");
+
+    let (bytes, len) = (read(elements + 24), read(elements + 32));
+    assert_eq!(
+        &memory[bytes..bytes + len],
+        b"a string that is too long to be small"
+    );
+}
+
+#[test]
+#[cfg(feature = "gen-dev")]
+fn failed_expect_clones_recursive_tag_union() {
+    let (result, memory) = crate::helpers::dev::run_with_expects::<i64>(indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        ConsList : [Cons I64 ConsList, Nil]
+
+        main : I64
+        main =
+            list : ConsList
+            list = Cons 1 (Cons 2 (Cons 3 Nil))
+
+            expect list == Nil
+
+            42
+        "#
+    ));
+    assert_eq!(result.ok(), Some(42));
+
+    let read = |at: usize| usize::from_ne_bytes(memory[at..at + 8].try_into().unwrap());
+
+    assert_eq!(read(0), 1);
+    let list = read(24 + 12);
+
+    // `Nil` is the null pointer, and every `Cons` points to its element and the rest of the list
+    let mut elements = Vec::new();
+    let mut cons = read(list);
+    while cons != 0 {
+        elements.push(read(cons) as i64);
+        cons = read(cons + 8);
+    }
+    assert_eq!(elements, [1, 2, 3]);
+}
+
+#[test]
+#[cfg(any(feature = "gen-llvm", feature = "gen-wasm", feature = "gen-dev"))]
+fn loop_that_swaps_its_parameters() {
+    assert_evals_to!(
+        indoc!(
+            r#"
+            app "test" provides [main] to "./platform"
+
+            swapUntil : U64, U64, U64 -> U64
+            swapUntil = \a, b, n ->
+                when n is
+                    0 -> a
+                    1 | 3 | 5 -> swapUntil b a (n - 1)
+                    _ -> swapUntil b a (n - 1)
+
+            main : U64
+            main =
+                if 1 + 1 == 2 then
+                    swapUntil 5 7 3
+                else
+                    swapUntil 1 2 3
+            "#
+        ),
+        7,
+        u64
+    );
+}
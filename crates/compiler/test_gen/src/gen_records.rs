@@ -10,7 +10,10 @@ use crate::helpers::wasm::assert_evals_to;
 // use crate::assert_wasm_evals_to as assert_evals_to;
 use indoc::indoc;
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 use roc_std::{RocList, RocStr};
 
 #[test]
@@ -108,7 +111,7 @@ fn pass_bool_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn fn_record() {
     assert_evals_to!(
         indoc!(
@@ -414,7 +417,7 @@ fn return_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_when_use_default() {
     assert_evals_to!(
         indoc!(
@@ -442,7 +445,7 @@ fn optional_field_when_use_default() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_when_use_default_nested() {
     assert_evals_to!(
         indoc!(
@@ -466,7 +469,7 @@ fn optional_field_when_use_default_nested() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_destructure_module() {
     assert_evals_to!(
         indoc!(
@@ -487,7 +490,7 @@ fn optional_field_destructure_module() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_destructure_expr() {
     assert_evals_to!(
         indoc!(
@@ -526,7 +529,7 @@ fn optional_field_let_use_default() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_let_no_use_default() {
     assert_evals_to!(
         indoc!(
@@ -547,7 +550,7 @@ fn optional_field_let_no_use_default() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_let_no_use_default_nested() {
     assert_evals_to!(
         indoc!(
@@ -582,7 +585,7 @@ fn optional_field_function_use_default() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_function_no_use_default() {
     assert_evals_to!(
         indoc!(
@@ -601,7 +604,7 @@ fn optional_field_function_no_use_default() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn optional_field_function_no_use_default_nested() {
     assert_evals_to!(
         indoc!(
@@ -732,7 +735,7 @@ fn return_record_7() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_record_float_int() {
     assert_evals_to!(
         indoc!(
@@ -746,7 +749,7 @@ fn return_record_float_int() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_record_int_float() {
     assert_evals_to!(
         indoc!(
@@ -760,7 +763,7 @@ fn return_record_int_float() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_record_float_float() {
     assert_evals_to!(
         indoc!(
@@ -774,7 +777,7 @@ fn return_record_float_float() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_record_float_float_float() {
     assert_evals_to!(
         indoc!(
@@ -802,7 +805,7 @@ fn return_nested_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn nested_record_load() {
     assert_evals_to!(
         indoc!(
@@ -820,13 +823,13 @@ fn nested_record_load() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn accessor_twice() {
     assert_evals_to!(".foo { foo: 4 }  + .foo { bar: 2.46f64, foo: 3 } ", 7, i64);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn accessor_multi_element_record() {
     assert_evals_to!(
         indoc!(
@@ -840,7 +843,7 @@ fn accessor_multi_element_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn accessor_single_element_record() {
     assert_evals_to!(
         indoc!(
@@ -854,7 +857,7 @@ fn accessor_single_element_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn update_record() {
     assert_evals_to!(
         indoc!(
@@ -886,7 +889,7 @@ fn update_single_element_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn booleans_in_record() {
     assert_evals_to!(
         indoc!("{ x: 1 == 1, y: 1 == 1 }"),
@@ -911,7 +914,7 @@ fn booleans_in_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn alignment_in_record() {
     assert_evals_to!(
         indoc!(
@@ -923,7 +926,7 @@ fn alignment_in_record() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn blue_and_present() {
     assert_evals_to!(
         indoc!(
@@ -942,7 +945,7 @@ fn blue_and_present() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn blue_and_absent() {
     assert_evals_to!(
         indoc!(
@@ -985,7 +988,7 @@ fn update_the_only_field() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 // https://github.com/roc-lang/roc/issues/1513
 fn both_have_unique_fields() {
     assert_evals_to!(
@@ -1006,7 +1009,7 @@ fn both_have_unique_fields() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 // https://github.com/roc-lang/roc/issues/2535
 fn different_proc_types_specialized_to_same_layout() {
     assert_evals_to!(
@@ -1036,7 +1039,7 @@ fn different_proc_types_specialized_to_same_layout() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = r#"Roc failed with message: "Can't create record with improper layout""#)]
 fn call_with_bad_record_runtime_error() {
     assert_evals_to!(
@@ -1058,7 +1061,7 @@ fn call_with_bad_record_runtime_error() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn generalized_accessor() {
     assert_evals_to!(
         indoc!(
@@ -1074,7 +1077,7 @@ fn generalized_accessor() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn update_record_that_is_a_thunk() {
     assert_evals_to!(
         indoc!(
@@ -1094,7 +1097,7 @@ fn update_record_that_is_a_thunk() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn update_record_that_is_a_thunk_single_field() {
     assert_evals_to!(
         indoc!(
@@ -1114,7 +1117,7 @@ fn update_record_that_is_a_thunk_single_field() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn toplevel_accessor_fn_thunk() {
     assert_evals_to!(
         indoc!(
@@ -1133,7 +1136,7 @@ fn toplevel_accessor_fn_thunk() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn pass_record_of_u8s() {
     assert_evals_to!(
         indoc!(
@@ -1754,7 +1754,7 @@ fn llvm_wasm_str_layout_small() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn when_on_strings() {
     assert_evals_to!(
         indoc!(
@@ -1882,7 +1882,7 @@ fn destructure_pattern_assigned_from_thunk_tag() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn release_excess_capacity() {
     assert_evals_to!(
         indoc!(
@@ -1898,7 +1898,7 @@ fn release_excess_capacity() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn release_excess_capacity_with_len() {
     assert_evals_to!(
         indoc!(
@@ -1915,7 +1915,7 @@ fn release_excess_capacity_with_len() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn release_excess_capacity_empty() {
     assert_evals_to!(
         indoc!(
@@ -1109,7 +1109,7 @@ fn applied_tag_function_linked_list() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn applied_tag_function_pair() {
     assert_evals_to!(
         indoc!(
@@ -1130,7 +1130,7 @@ fn applied_tag_function_pair() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[should_panic(expected = "")] // TODO: this only panics because it returns 0 instead of 1!
 fn tag_must_be_its_own_type() {
     assert_evals_to!(
@@ -1581,7 +1581,7 @@ fn issue_2777_default_branch_codegen() {
 // then it works. We don't know what the problem is here!
 #[cfg(all(
     not(target_family = "windows"),
-    any(feature = "gen-llvm", feature = "gen-wasm")
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
 ))]
 #[should_panic(expected = r#"Roc failed with message: "Tag Foo was part of a type error!""#)]
 fn issue_2900_unreachable_pattern() {
@@ -1803,7 +1803,7 @@ fn alignment_u128() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 #[ignore = "causes alias analysis panics, should roc_panic"]
 fn error_type_in_tag_union_payload() {
     assert_evals_to!(
@@ -2195,7 +2195,7 @@ fn refcount_nullable_unwrapped_needing_no_refcount_issue_5027() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn issue_5162_recast_nested_nullable_unwrapped_layout() {
     with_larger_debug_stack(|| {
         assert_evals_to!(
@@ -10,7 +10,10 @@ use crate::helpers::wasm::assert_evals_to;
 // use crate::assert_wasm_evals_to as assert_evals_to;
 use indoc::indoc;
 
-#[cfg(all(test, any(feature = "gen-llvm", feature = "gen-wasm")))]
+#[cfg(all(
+    test,
+    any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm")
+))]
 use roc_std::RocStr;
 
 #[test]
@@ -88,7 +91,7 @@ fn f64_tuple() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn fn_tuple() {
     assert_evals_to!(
         indoc!(
@@ -272,7 +275,7 @@ fn i64_tuple2_literal() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn i64_tuple3_literal() {
     assert_evals_to!(
         indoc!(
@@ -300,7 +303,7 @@ fn f64_tuple2_literal() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn bool_tuple4_literal() {
     assert_evals_to!(
         indoc!(
@@ -440,7 +443,7 @@ fn return_tuple_7() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_tuple_float_int() {
     assert_evals_to!(
         indoc!(
@@ -454,7 +457,7 @@ fn return_tuple_float_int() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_tuple_int_float() {
     assert_evals_to!(
         indoc!(
@@ -468,7 +471,7 @@ fn return_tuple_int_float() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_tuple_float_float() {
     assert_evals_to!(
         indoc!(
@@ -482,7 +485,7 @@ fn return_tuple_float_float() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn return_tuple_float_float_float() {
     assert_evals_to!(
         indoc!(
@@ -510,7 +513,7 @@ fn return_nested_tuple() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn nested_tuple_load() {
     assert_evals_to!(
         indoc!(
@@ -528,13 +531,13 @@ fn nested_tuple_load() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn tuple_accessor_twice() {
     assert_evals_to!(".0 (4, 5)  + .1 ( 2.46, 3 ) ", 7, i64);
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn tuple_accessor_multi_element_tuple() {
     assert_evals_to!(
         indoc!(
@@ -548,7 +551,7 @@ fn tuple_accessor_multi_element_tuple() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn booleans_in_tuple() {
     assert_evals_to!(indoc!("(1 == 1, 1 == 1)"), (true, true), (bool, bool));
     assert_evals_to!(indoc!("(1 != 1, 1 == 1)"), (false, true), (bool, bool));
@@ -558,7 +561,7 @@ fn booleans_in_tuple() {
 
 // TODO: this test fails for mysterious reasons
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn alignment_in_tuple() {
     assert_evals_to!(
         indoc!("(32, 1 == 1, 78u16)"),
@@ -568,7 +571,7 @@ fn alignment_in_tuple() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn tuple_length_polymorphism() {
     assert_evals_to!(
         indoc!(
@@ -588,7 +591,7 @@ fn tuple_length_polymorphism() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn generalized_tuple_accessor() {
     assert_evals_to!(
         indoc!(
@@ -604,7 +607,7 @@ fn generalized_tuple_accessor() {
 }
 
 #[test]
-#[cfg(any(feature = "gen-llvm", feature = "gen-wasm"))]
+#[cfg(any(feature = "gen-llvm", feature = "gen-dev", feature = "gen-wasm"))]
 fn generalized_explicit_tuple_accessor() {
     assert_evals_to!(
         indoc!(
@@ -34,9 +34,25 @@ fn promote_expr_to_module(src: &str) -> String {
 pub fn helper(
     arena: &bumpalo::Bump,
     src: &str,
-    _leak: bool,
+    ignore_problems: bool,
     lazy_literals: bool,
-) -> (String, Vec<roc_problem::can::Problem>, Library) {
+) -> (String, String, Library) {
+    helper_with_mode(
+        arena,
+        src,
+        ignore_problems,
+        lazy_literals,
+        roc_gen_dev::AssemblyBackendMode::Test,
+    )
+}
+
+fn helper_with_mode(
+    arena: &bumpalo::Bump,
+    src: &str,
+    ignore_problems: bool,
+    lazy_literals: bool,
+    mode: roc_gen_dev::AssemblyBackendMode,
+) -> (String, String, Library) {
     use std::path::PathBuf;
 
     let dir = tempdir().unwrap();
@@ -156,12 +172,24 @@ pub fn helper(
 
         use roc_problem::can::Problem::*;
         for problem in can_problems.into_iter() {
-            // Ignore "unused" problems
             match problem {
-                UnusedDef(_, _) | UnusedArgument(_, _, _, _) | UnusedModuleImport(_, _) => {
-                    delayed_errors.push(problem);
-                    continue;
+                // Ignore "unused" problems
+                UnusedDef(_, _)
+                | UnusedArgument(_, _, _, _, _)
+                | UnusedModuleImport(_, _)
+                | RuntimeError(_)
+                | UnsupportedPattern(_, _)
+                | ExposedButNotDefined(_) => {
+                    let report = can_problem(&alloc, &line_info, module_path.clone(), problem);
+                    let mut buf = String::new();
+
+                    report.render_color_terminal(&mut buf, &alloc, &palette);
+
+                    delayed_errors.push(buf.clone());
+                    lines.push(buf);
                 }
+                // We should be able to compile even when abilities are used as types
+                AbilityUsedAsType(..) => {}
                 _ => {
                     let report = can_problem(&alloc, &line_info, module_path.clone(), problem);
                     let mut buf = String::new();
@@ -186,7 +214,11 @@ pub fn helper(
 
     if !lines.is_empty() {
         println!("{}", lines.join("\n"));
-        assert_eq!(0, 1, "Mistakes were made");
+
+        // only crash at this point if there were no delayed_errors
+        if delayed_errors.is_empty() && !ignore_problems {
+            assert_eq!(0, 1, "Mistakes were made");
+        }
     }
 
     let env = roc_gen_dev::Env {
@@ -194,11 +226,12 @@ pub fn helper(
         module_id,
         exposed_to_host: exposed_to_host.top_level_values.keys().copied().collect(),
         lazy_literals,
-        mode: roc_gen_dev::AssemblyBackendMode::Test,
+        mode,
+        debug_sources: None,
     };
 
     let target = target_lexicon::Triple::host().into();
-    let module_object =
+    let (module_object, _) =
         roc_gen_dev::build_module(&env, &mut interns, &mut layout_interner, target, procedures);
 
     let module_out = module_object
@@ -235,6 +268,12 @@ pub fn helper(
 
     let lib = unsafe { Library::new(path) }.expect("failed to load shared library");
 
+    let delayed_errors = if ignore_problems {
+        String::new()
+    } else {
+        delayed_errors.join("\n")
+    };
+
     (main_fn_name, delayed_errors, lib)
 }
 
@@ -323,7 +362,7 @@ pub(crate) fn asm_evals_to<T, U, F>(
     src: &str,
     expected: U,
     transform: F,
-    leak: bool,
+    ignore_problems: bool,
     lazy_literals: bool,
 ) where
     U: PartialEq + std::fmt::Debug,
@@ -333,23 +372,15 @@ pub(crate) fn asm_evals_to<T, U, F>(
 
     let arena = Bump::new();
     let (_main_fn_name, errors, lib) =
-        crate::helpers::dev::helper(&arena, src, leak, lazy_literals);
+        crate::helpers::dev::helper(&arena, src, ignore_problems, lazy_literals);
 
     let result = crate::helpers::dev::run_test_main::<T>(&lib);
 
-    if !errors.is_empty() {
-        dbg!(&errors);
-
-        assert_eq!(
-            errors,
-            std::vec::Vec::new(),
-            "Encountered errors: {:?}",
-            errors
-        );
-    }
-
     match result {
         Ok(value) => {
+            // only if there are no exceptions thrown, check for errors
+            assert!(errors.is_empty(), "Encountered errors:\n{errors}");
+
             let expected = expected;
             #[allow(clippy::redundant_closure_call)]
             let given = transform(value);
@@ -362,6 +393,31 @@ pub(crate) fn asm_evals_to<T, U, F>(
     }
 }
 
+/// Runs `main` with `expect`s enabled, and returns its result and the memory that failed `expect`s were written to.
+#[allow(dead_code)]
+pub(crate) fn run_with_expects<T>(src: &str) -> (Result<T, (String, CrashTag)>, Vec<u8>) {
+    let arena = bumpalo::Bump::new();
+    let (_main_fn_name, _errors, lib) = helper_with_mode(
+        &arena,
+        src,
+        false,
+        false,
+        roc_gen_dev::AssemblyBackendMode::CliTest,
+    );
+
+    // The memory starts with the number of failures, the offset to write the next one at, and a lock.
+    let mut memory = vec![0u8; 4096];
+    memory[8..16].copy_from_slice(&24usize.to_ne_bytes());
+
+    let set_shared_buffer: libloading::Symbol<unsafe extern "C" fn(*mut u8, usize) -> usize> =
+        unsafe { lib.get(b"set_shared_buffer") }.expect("no set_shared_buffer in the dylib");
+    unsafe { set_shared_buffer(memory.as_mut_ptr(), memory.len()) };
+
+    let result = run_test_main::<T>(&lib);
+
+    (result, memory)
+}
+
 pub(crate) fn identity<T>(x: T) -> T {
     x
 }
@@ -374,24 +430,24 @@ macro_rules! assert_evals_to {
     ($src:expr, $expected:expr, $ty:ty, $transform:expr) => {
         // Same as above, except with an additional transformation argument.
         {
-            assert_evals_to!($src, $expected, $ty, $transform, true);
+            assert_evals_to!($src, $expected, $ty, $transform, false);
         }
     };
-    ($src:expr, $expected:expr, $ty:ty, $transform:expr, $leak:expr) => {
+    ($src:expr, $expected:expr, $ty:ty, $transform:expr, $ignore_problems:expr) => {
         // Run both with and without lazy literal optimization.
         {
-            assert_evals_to!($src, $expected, $ty, $transform, $leak, false);
+            assert_evals_to!($src, $expected, $ty, $transform, $ignore_problems, false);
         }
         {
-            assert_evals_to!($src, $expected, $ty, $transform, $leak, true);
+            assert_evals_to!($src, $expected, $ty, $transform, $ignore_problems, true);
         }
     };
-    ($src:expr, $expected:expr, $ty:ty, $transform:expr, $leak:expr, $lazy_literals:expr) => {
+    ($src:expr, $expected:expr, $ty:ty, $transform:expr, $ignore_problems:expr, $lazy_literals:expr) => {
         $crate::helpers::dev::asm_evals_to::<$ty, _, _>(
             $src,
             $expected,
             $transform,
-            $leak,
+            $ignore_problems,
             $lazy_literals,
         );
     };
@@ -90,6 +90,7 @@ fn create_llvm_module<'a>(
         Ok(x) => x,
         Err(LoadMonomorphizedError::LoadingProblem(roc_load::LoadingProblem::FormattedReport(
             report,
+            _,
         ))) => {
             println!("{report}");
             panic!();
@@ -134,7 +135,7 @@ fn create_llvm_module<'a>(
             match problem {
                 // Ignore "unused" problems
                 UnusedDef(_, _)
-                | UnusedArgument(_, _, _, _)
+                | UnusedArgument(_, _, _, _, _)
                 | UnusedModuleImport(_, _)
                 | RuntimeError(_)
                 | UnsupportedPattern(_, _)
@@ -229,6 +230,7 @@ fn create_llvm_module<'a>(
         mode: config.mode,
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        debug_sources: None,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
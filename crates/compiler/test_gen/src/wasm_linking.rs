@@ -17,6 +17,7 @@ use roc_mono::ir::{
     Call, CallType, Expr, Literal, Proc, ProcLayout, SelfRecursive, Stmt, UpdateModeId,
 };
 use roc_mono::layout::{LambdaName, Layout, Niche, STLayoutInterner};
+use roc_region::all::Region;
 use roc_wasm_interp::{wasi, ImportDispatcher, Instance, WasiDispatcher};
 use roc_wasm_module::{Value, WasmModule};
 
@@ -101,11 +102,16 @@ fn build_app_mono<'a>(
                         or2_expr,
                         int_layout,
                         //
-                        arena.alloc(Stmt::Ret(or2)),
+                        arena.alloc(Stmt::Ret(or2, Region::zero())),
+                        Region::zero(),
                     )),
+                    Region::zero(),
                 )),
+                Region::zero(),
             )),
+            Region::zero(),
         )),
+        Region::zero(),
     );
 
     let proc = Proc {
@@ -116,6 +122,7 @@ fn build_app_mono<'a>(
         ret_layout: int_layout,
         is_self_recursive: SelfRecursive::NotSelfRecursive,
         is_erased: false,
+        region: Region::zero(),
     };
 
     let proc_layout = ProcLayout {
@@ -159,6 +166,8 @@ impl<'a> BackendInputs<'a> {
             module_id,
             exposed_to_host,
             stack_bytes: Env::DEFAULT_STACK_BYTES,
+            features: Default::default(),
+            debug_sources: None,
         };
 
         // Identifier stuff for the backend
@@ -77,32 +77,17 @@ fn promote_expr_to_module(src: &str) -> String {
     buffer
 }
 
-fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: bool, no_check: bool) {
+fn load_module<'a>(
+    arena: &'a Bump,
+    module_src: &'a str,
+    exec_mode: ExecutionMode,
+) -> roc_load::MonomorphizedModule<'a> {
     use roc_packaging::cache::RocCacheDir;
     use std::path::PathBuf;
 
-    let exec_mode = match mode {
-        "exec" => ExecutionMode::Executable,
-        "test" => ExecutionMode::Test,
-        _ => panic!("Invalid test_mono exec mode {mode}"),
-    };
-
-    let arena = &Bump::new();
-
     let filename = PathBuf::from("Test.roc");
     let src_dir = PathBuf::from("fake/test/path");
 
-    let module_src;
-    let temp;
-    if src.starts_with("app") || src.starts_with("interface") {
-        // this is already a module
-        module_src = src;
-    } else {
-        // this is an expression, promote it to a module
-        temp = promote_expr_to_module(src);
-        module_src = &temp;
-    }
-
     let load_config = LoadConfig {
         target: TARGET,
         // TODO parameterize
@@ -121,17 +106,41 @@ fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: boo
         load_config,
     );
 
-    let mut loaded = match loaded {
+    match loaded {
         Ok(x) => x,
         Err(LoadMonomorphizedError::LoadingProblem(roc_load::LoadingProblem::FormattedReport(
             report,
+            _,
         ))) => {
             println!("{report}");
             panic!();
         }
         Err(e) => panic!("{e:?}"),
+    }
+}
+
+fn compiles_to_ir(test_name: &str, src: &str, mode: &str, allow_type_errors: bool, no_check: bool) {
+    let exec_mode = match mode {
+        "exec" => ExecutionMode::Executable,
+        "test" => ExecutionMode::Test,
+        _ => panic!("Invalid test_mono exec mode {mode}"),
     };
 
+    let arena = &Bump::new();
+
+    let module_src;
+    let temp;
+    if src.starts_with("app") || src.starts_with("interface") {
+        // this is already a module
+        module_src = src;
+    } else {
+        // this is an expression, promote it to a module
+        temp = promote_expr_to_module(src);
+        module_src = &temp;
+    }
+
+    let mut loaded = load_module(arena, module_src, exec_mode);
+
     use roc_load::MonomorphizedModule;
     let MonomorphizedModule {
         module_id: home,
@@ -3534,3 +3543,101 @@ fn issue_6606_2() {
         "
     )
 }
+
+#[test]
+fn proc_and_statement_regions() {
+    use roc_mono::ir::Stmt;
+    use roc_region::all::{LineInfo, Region};
+
+    fn statement_lines(stmt: &Stmt, line_info: &LineInfo, lines: &mut Vec<String>) {
+        let mut line = |kind, region: &Region| {
+            if *region != Region::zero() {
+                let line = line_info.convert_pos(region.start()).line;
+                lines.push(format!("{kind}@{line}"));
+            }
+        };
+
+        match stmt {
+            Stmt::Let(_, _, _, rest, region) => {
+                line("let", region);
+                statement_lines(rest, line_info, lines);
+            }
+            Stmt::Switch {
+                branches,
+                default_branch,
+                region,
+                ..
+            } => {
+                line("switch", region);
+                for (_, _, branch) in branches.iter() {
+                    statement_lines(branch, line_info, lines);
+                }
+                statement_lines(default_branch.1, line_info, lines);
+            }
+            Stmt::Ret(_, region) => line("ret", region),
+            Stmt::Refcounting(_, rest)
+            | Stmt::Expect {
+                remainder: rest, ..
+            }
+            | Stmt::ExpectFx {
+                remainder: rest, ..
+            }
+            | Stmt::Dbg {
+                remainder: rest, ..
+            } => statement_lines(rest, line_info, lines),
+            Stmt::Join {
+                body, remainder, ..
+            } => {
+                statement_lines(remainder, line_info, lines);
+                statement_lines(body, line_info, lines);
+            }
+            Stmt::Jump(..) | Stmt::Crash(..) => {}
+        }
+    }
+
+    let src = indoc!(
+        r#"
+        app "test" provides [main] to "./platform"
+
+        double = \num ->
+            twice = num * 2
+
+            if twice > 10 then
+                twice
+            else
+                0
+
+        main =
+            double 21
+        "#
+    );
+
+    let arena = &Bump::new();
+    let loaded = load_module(arena, src, ExecutionMode::Executable);
+    let line_info = LineInfo::new(src);
+
+    let mut procs: Vec<_> = loaded
+        .procedures
+        .values()
+        .filter(|proc| proc.name.name().module_id() == loaded.module_id)
+        .map(|proc| {
+            let mut lines = vec![format!(
+                "{}@{}:",
+                proc.name.name().as_str(&loaded.interns),
+                line_info.convert_pos(proc.region.start()).line
+            )];
+            statement_lines(&proc.body, &line_info, &mut lines);
+
+            lines.join(" ")
+        })
+        .collect();
+    procs.sort();
+
+    assert_eq!(
+        procs,
+        [
+            "double@2: let@3 let@3 let@5 let@5 switch@5 ret@6 let@8 ret@8",
+            "main@10: let@10 let@10 ret@10",
+        ]
+    );
+}
@@ -6,10 +6,11 @@ use roc_parse::{
     parser::{Parser, SyntaxError},
     state::State,
     test_helpers::{parse_defs_with, parse_expr_with, parse_header_with},
+    tokens::TokenTable,
 };
 use roc_test_utils::assert_multiline_str_eq;
 
-use roc_fmt::spaces::RemoveSpaces;
+use roc_fmt::spaces::{same_without_spaces, RemoveSpaces};
 use roc_fmt::Buf;
 
 /// Source code to parse. Usually in the form of a test case.
@@ -202,6 +203,20 @@ impl<'a> Input<'a> {
 
         handle_formatted_output(output.as_ref());
 
+        let missing_comments = TokenTable::new(self.as_str())
+            .missing_comments(&TokenTable::new(output.as_ref().as_str()));
+
+        if !missing_comments.is_empty() {
+            panic!(
+                "Formatting bug; formatting dropped these comments: {:?}\n\n\
+                * * * Source code before formatting:\n{}\n\n\
+                * * * Source code after formatting:\n{}\n\n",
+                missing_comments,
+                self.as_str(),
+                output.as_ref().as_str(),
+            );
+        }
+
         let reparsed_ast = output.as_ref().parse_in(&arena).unwrap_or_else(|err| {
             panic!(
                 "After formatting, the source code no longer parsed!\n\n\
@@ -216,15 +231,7 @@ impl<'a> Input<'a> {
             );
         });
 
-        let ast_normalized = actual.remove_spaces(&arena);
-        let reparsed_ast_normalized = reparsed_ast.remove_spaces(&arena);
-
-        // HACK!
-        // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-        // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-        // I don't have the patience to debug this right now, so let's leave it for another day...
-        // TODO: fix PartialEq impl on ast types
-        if format!("{ast_normalized:?}") != format!("{reparsed_ast_normalized:?}") {
+        if !same_without_spaces(&arena, &actual, &reparsed_ast) {
             panic!(
                 "Formatting bug; formatting didn't reparse to the same AST (after removing spaces)\n\n\
                 * * * Source code before formatting:\n{}\n\n\
@@ -234,7 +241,7 @@ impl<'a> Input<'a> {
                 self.as_str(),
                 output.as_ref().as_str(),
                 actual,
-                reparsed_ast_normalized
+                reparsed_ast.remove_spaces(&arena)
             );
         }
 
@@ -6,10 +6,15 @@ mod test_fmt {
     use bumpalo::Bump;
     use roc_fmt::def::fmt_defs;
     use roc_fmt::module::fmt_module;
+    use roc_fmt::range::range_edits;
+    use roc_fmt::spaces::same_without_spaces;
+    use roc_fmt::upgrade::upgrade_defs;
+    use roc_fmt::width::wrap_long_lines;
     use roc_fmt::Buf;
     use roc_parse::ast::{Defs, Module};
     use roc_parse::module::{self, parse_module_defs};
     use roc_parse::state::State;
+    use roc_region::all::{Position, Region};
     use roc_test_utils::assert_multiline_str_eq;
     use roc_test_utils_dir::workspace_root;
     use test_syntax::test_helpers::Input;
@@ -56,8 +61,6 @@ mod test_fmt {
 
         match module::parse_header(&arena, State::new(src.as_bytes())) {
             Ok((actual, state)) => {
-                use roc_fmt::spaces::RemoveSpaces;
-
                 let mut buf = Buf::new_in(&arena);
 
                 fmt_module_and_defs(&arena, src, &actual, state, &mut buf);
@@ -70,15 +73,7 @@ mod test_fmt {
                     );
                 });
 
-                let ast_normalized = actual.remove_spaces(&arena);
-                let reparsed_ast_normalized = reparsed_ast.remove_spaces(&arena);
-
-                // HACK!
-                // We compare the debug format strings of the ASTs, because I'm finding in practice that _somewhere_ deep inside the ast,
-                // the PartialEq implementation is returning `false` even when the Debug-formatted impl is exactly the same.
-                // I don't have the patience to debug this right now, so let's leave it for another day...
-                // TODO: fix PartialEq impl on ast types
-                if format!("{ast_normalized:?}") != format!("{reparsed_ast_normalized:?}") {
+                if !same_without_spaces(&arena, &actual, &reparsed_ast) {
                     panic!(
                         "Formatting bug; formatting didn't reparse to the same AST (after removing spaces)\n\n\
                         * * * Source code before formatting:\n{src}\n\n\
@@ -6057,6 +6052,474 @@ mod test_fmt {
         );
     }
 
+    // RANGE FORMATTING
+
+    /// Format the whole module, but only apply the edits for the defs that overlap the
+    /// first occurrence of `selected`.
+    fn range_formats_to(src: &str, selected: &str, expected: &str) {
+        let arena = Bump::new();
+        let (module, state) = module::parse_header(&arena, State::new(src.as_bytes())).unwrap();
+        let (module, defs) = module.upgrade_header_imports(&arena);
+        let defs = parse_module_defs(&arena, state, defs).unwrap();
+
+        let mut buf = Buf::new_in(&arena);
+        fmt_module(&mut buf, &module);
+        fmt_defs(&mut buf, &defs, 0);
+        buf.fmt_end_of_file();
+
+        let start = src.find(selected).unwrap() as u32;
+        let range = Region::new(
+            Position::new(start),
+            Position::new(start + selected.len() as u32),
+        );
+        let edits = range_edits(&arena, src, buf.as_str(), range).unwrap();
+
+        let mut output = src.to_string();
+        for edit in edits.iter().rev() {
+            output.replace_range(
+                edit.region.start().offset as usize..edit.region.end().offset as usize,
+                edit.text,
+            );
+        }
+
+        assert_multiline_str_eq!(expected, output.as_str());
+    }
+
+    #[test]
+    fn range_formats_only_selected_defs() {
+        range_formats_to(
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a =   1
+                b =   [1,2]
+                c =   3
+                "
+            ),
+            "[1,2]",
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a =   1
+                b = [1, 2]
+                c =   3
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn range_formats_defs_across_selection() {
+        range_formats_to(
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a =   1
+                b =   2
+                c =   3
+                "
+            ),
+            "1\nb",
+            indoc!(
+                r"
+                module [a, b, c]
+
+                a = 1
+                b = 2
+                c =   3
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn range_formats_with_old_header_imports() {
+        range_formats_to(
+            indoc!(
+                r#"
+                interface Foo exposes [a, b] imports [Bar]
+
+                a =   1
+                b =   2
+                "#
+            ),
+            "2",
+            indoc!(
+                r#"
+                interface Foo exposes [a, b] imports [Bar]
+
+                a =   1
+                b = 2
+                "#
+            ),
+        );
+    }
+
+    // MAXIMUM WIDTH
+
+    fn format_with_max_width(src: &str, max_width: usize) -> String {
+        let arena = Bump::new();
+        let (module, state) = module::parse_header(&arena, State::new(src.as_bytes())).unwrap();
+        let (module, defs) = module.upgrade_header_imports(&arena);
+        let defs = parse_module_defs(&arena, state, defs).unwrap();
+
+        let mut buf = Buf::new_in(&arena);
+        fmt_module(&mut buf, &module);
+        fmt_defs(&mut buf, &defs, 0);
+        buf.fmt_end_of_file();
+
+        wrap_long_lines(&arena, buf.as_str(), max_width).to_string()
+    }
+
+    fn formats_with_max_width_to(input: &str, max_width: usize, expected: &str) {
+        let output = format_with_max_width(input, max_width);
+        assert_multiline_str_eq!(expected, output.as_str());
+
+        // Breaking up long lines must be idempotent too
+        let reformatted = format_with_max_width(&output, max_width);
+        assert_multiline_str_eq!(output.as_str(), reformatted.as_str());
+    }
+
+    #[test]
+    fn max_width_breaks_long_lists() {
+        formats_with_max_width_to(
+            indoc!(
+                r#"
+                module [names]
+
+                names = ["Alice", "Bob", "Carol", "Dave", "Eve"]
+                "#
+            ),
+            30,
+            indoc!(
+                r#"
+                module [names]
+
+                names = [
+                    "Alice",
+                    "Bob",
+                    "Carol",
+                    "Dave",
+                    "Eve",
+                ]
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_records() {
+        formats_with_max_width_to(
+            indoc!(
+                r#"
+                module [person]
+
+                person = { name: "Alice", age: 42, email: "alice@example.com" }
+                "#
+            ),
+            40,
+            indoc!(
+                r#"
+                module [person]
+
+                person = {
+                    name: "Alice",
+                    age: 42,
+                    email: "alice@example.com",
+                }
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_applications() {
+        formats_with_max_width_to(
+            indoc!(
+                r#"
+                module [greeting]
+
+                greeting = Str.joinWith ["Hello", "there", "general", "Kenobi"] ", "
+                "#
+            ),
+            50,
+            indoc!(
+                r#"
+                module [greeting]
+
+                greeting = Str.joinWith
+                    ["Hello", "there", "general", "Kenobi"]
+                    ", "
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_pipelines() {
+        formats_with_max_width_to(
+            indoc!(
+                r"
+                module [total]
+
+                total = numbers |> List.map Num.toF64 |> List.sum |> Num.round
+                "
+            ),
+            40,
+            indoc!(
+                r"
+                module [total]
+
+                total =
+                    numbers
+                    |> List.map Num.toF64
+                    |> List.sum
+                    |> Num.round
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_breaks_long_annotations() {
+        formats_with_max_width_to(
+            indoc!(
+                r"
+                module [combine]
+
+                combine : List Str, Dict Str U64, { separator : Str, limit : U64 } -> Str
+                combine = \_, _, _ -> crash
+                "
+            ),
+            50,
+            indoc!(
+                r"
+                module [combine]
+
+                combine :
+                    List Str,
+                    Dict Str U64,
+                    {
+                        separator : Str,
+                        limit : U64,
+                    }
+                    -> Str
+                combine = \_, _, _ -> crash
+                "
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_leaves_short_lines_alone() {
+        formats_with_max_width_to(
+            indoc!(
+                r#"
+                module [names]
+
+                names = ["Alice", "Bob"]
+                "#
+            ),
+            80,
+            indoc!(
+                r#"
+                module [names]
+
+                names = ["Alice", "Bob"]
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn max_width_leaves_unbreakable_lines_alone() {
+        formats_with_max_width_to(
+            indoc!(
+                r#"
+                module [name]
+
+                name = "A string that is far too long to fit"
+                "#
+            ),
+            20,
+            indoc!(
+                r#"
+                module [name]
+
+                name = "A string that is far too long to fit"
+                "#
+            ),
+        );
+    }
+
+    fn upgrade(src: &str) -> String {
+        let arena = Bump::new();
+        let (module, state) = module::parse_header(&arena, State::new(src.as_bytes())).unwrap();
+        let (module, defs) = module.upgrade_header_imports(&arena);
+        let defs = parse_module_defs(&arena, state, defs).unwrap();
+        let defs = upgrade_defs(&arena, &defs);
+
+        let mut buf = Buf::new_in(&arena);
+        fmt_module(&mut buf, &module);
+        fmt_defs(&mut buf, &defs, 0);
+        buf.fmt_end_of_file();
+
+        let output = buf.as_str();
+        let (_, state) = module::parse_header(&arena, State::new(output.as_bytes())).unwrap();
+        let reparsed_defs = parse_module_defs(&arena, state, Defs::default()).unwrap();
+
+        if !same_without_spaces(&arena, &defs, &reparsed_defs) {
+            panic!("Upgrading bug; the upgraded code didn't reparse to the upgraded AST:\n\n{output}\n\n");
+        }
+
+        output.to_string()
+    }
+
+    fn upgrades_to(input: &str, expected: &str) {
+        let output = upgrade(input);
+        assert_multiline_str_eq!(expected, output.as_str());
+
+        // Upgraded code has nothing left to upgrade
+        let reupgraded = upgrade(&output);
+        assert_multiline_str_eq!(output.as_str(), reupgraded.as_str());
+    }
+
+    #[test]
+    fn upgrade_interface_header() {
+        upgrades_to(
+            indoc!(
+                r#"
+                interface Foo
+                    exposes [foo]
+                    imports [Bar.{ bar }]
+
+                foo = bar
+                "#
+            ),
+            indoc!(
+                r#"
+                module [foo]
+
+                import Bar exposing [bar]
+
+                foo = bar
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn upgrade_backpassing_task_await() {
+        upgrades_to(
+            indoc!(
+                r#"
+                module [main]
+
+                main =
+                    # Ask for a name
+                    {} <- Task.await (Stdout.line "What's your name?")
+                    name <- Task.await Stdin.line
+                    _ <- Task.await (Stdout.line "Hi, $(name)!")
+
+                    Task.ok {}
+                "#
+            ),
+            indoc!(
+                r#"
+                module [main]
+
+                main =
+                    # Ask for a name
+                    Stdout.line! "What's your name?"
+                    name = Stdin.line!
+                    Stdout.line! "Hi, $(name)!"
+
+                    Task.ok {}
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn upgrade_backpassing_after_defs() {
+        upgrades_to(
+            indoc!(
+                r#"
+                module [main]
+
+                main =
+                    greeting = "Hi"
+                    name <- Task.await Stdin.line
+
+                    Stdout.line "$(greeting), $(name)!"
+                "#
+            ),
+            indoc!(
+                r#"
+                module [main]
+
+                main =
+                    greeting = "Hi"
+                    name = Stdin.line!
+
+                    Stdout.line "$(greeting), $(name)!"
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn upgrade_backpassing_in_closures_and_branches() {
+        upgrades_to(
+            indoc!(
+                r#"
+                module [greet]
+
+                greet = \names ->
+                    when names is
+                        [] -> Task.ok {}
+                        _ ->
+                            List.walk names (Task.ok {}) \task, name ->
+                                {} <- Task.await task
+                                Stdout.line name
+                "#
+            ),
+            indoc!(
+                r#"
+                module [greet]
+
+                greet = \names ->
+                    when names is
+                        [] -> Task.ok {}
+                        _ ->
+                            List.walk names (Task.ok {}) \task, name ->
+                                task!
+                                Stdout.line name
+                "#
+            ),
+        );
+    }
+
+    #[test]
+    fn upgrade_keeps_other_backpassing() {
+        let src = indoc!(
+            r#"
+            module [pairs]
+
+            pairs =
+                x <- List.joinMap [1, 2]
+                a, b <- Task.await foo
+
+                [(x, a, b)]
+            "#
+        );
+
+        upgrades_to(src, src);
+    }
+
     // this is a parse error atm
     //    #[test]
     //    fn multiline_apply() {
@@ -595,6 +595,12 @@ mod test_snapshots {
 
         println!("{:?}", result);
 
+        assert_eq!(
+            roc_parse::tokens::TokenTable::new(&source).to_source(),
+            source,
+            "The token table didn't reproduce the source"
+        );
+
         if expect == TestExpectation::Pass {
             let tokens = roc_parse::highlight::highlight(&source);
             for token in tokens {
@@ -0,0 +1,359 @@
+//! Checks the code blocks in doc comments, so examples in the docs can't silently
+//! go out of date.
+//!
+//! Only fenced code blocks tagged `roc` are checked. Blocks tagged `unchecked` or
+//! `repl` (e.g. ```` ```roc unchecked ````) are skipped, as are blocks in any other language.
+//!
+//! Only top-level doc comments, whose `##` starts at the beginning of the line, are checked.
+//! Indented ones, like the docs of ability members, can't be turned into top-level code
+//! without moving them out of their ability, so their code blocks are not checked yet.
+use crate::load_module_for_docs;
+use bumpalo::Bump;
+use roc_load::{FunctionKind, LoadingProblem};
+use roc_packaging::cache::{self, RocCacheDir};
+use roc_parse::expr::test_parse_expr;
+use roc_parse::module::parse_header;
+use roc_parse::state::State;
+use roc_reporting::cli::{report_problems, ErrorFormat, Problems};
+use roc_reporting::report::{to_file_problem_report_string, RenderTarget, DEFAULT_PALETTE};
+use roc_reporting::warnings::WarningConfig;
+use std::path::{Path, PathBuf};
+
+/// Type-check the code blocks in the doc comments of every module the package exposes,
+/// reporting any problems in them the same way `roc check` would.
+pub fn check_docs(root_file: PathBuf) -> Problems {
+    let loaded_module = load_module_for_docs(root_file);
+    let mut problems = Problems::default();
+
+    for module_id in loaded_module.exposed_modules.iter() {
+        let (module_path, src) = &loaded_module.sources[module_id];
+
+        let doc_tests_src = match doc_tests_to_source(src) {
+            Some(doc_tests_src) => doc_tests_src,
+            None => continue,
+        };
+
+        let arena = Bump::new();
+        let src_dir = module_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+
+        let load_result = roc_load::load_and_typecheck_str(
+            &arena,
+            module_path.clone(),
+            arena.alloc_str(&doc_tests_src),
+            src_dir,
+            roc_target::Target::LinuxX64, // This is just type-checking, so "target" doesn't matter
+            FunctionKind::LambdaSet,
+            RenderTarget::ColorTerminal,
+            RocCacheDir::Persistent(cache::roc_cache_dir().as_path()),
+            DEFAULT_PALETTE,
+        );
+
+        match load_result {
+            Ok(mut loaded) => {
+                // Only report problems in the module whose docs we're checking.
+                // Problems in its dependencies are reported by `roc check`.
+                let home = loaded.module_id;
+
+                loaded.sources.retain(|module_id, _| *module_id == home);
+                loaded
+                    .can_problems
+                    .retain(|module_id, _| *module_id == home);
+                loaded
+                    .type_problems
+                    .retain(|module_id, _| *module_id == home);
+
+                let module_problems = report_problems(
+                    &loaded.sources,
+                    &loaded.interns,
+                    &mut loaded.can_problems,
+                    &mut loaded.type_problems,
+                    ErrorFormat::Human,
+                    &WarningConfig::default(),
+                );
+
+                problems.fatally_errored |= module_problems.fatally_errored;
+                problems.errors += module_problems.errors;
+                problems.warnings += module_problems.warnings;
+            }
+            Err(LoadingProblem::FormattedReport(report, _)) => {
+                print!("{report}");
+
+                problems.errors += 1;
+            }
+            Err(LoadingProblem::FileProblem { filename, error }) => {
+                print!("{}", to_file_problem_report_string(filename, error));
+
+                problems.errors += 1;
+            }
+            Err(other) => {
+                eprintln!(
+                    "Could not check the doc comments in {}: {other:?}",
+                    module_path.display()
+                );
+
+                problems.errors += 1;
+            }
+        }
+    }
+
+    problems
+}
+
+/// Rewrite a module's source so that every checked code block in its top-level doc
+/// comments becomes ordinary top-level code, or returns `None` if there are no such blocks.
+///
+/// The code keeps the exact line and column it had inside the doc comment, so any
+/// problem in it gets reported at the doc comment's region. A block whose last
+/// statement is an expression becomes the body of a function that a top-level `expect`
+/// calls; any other block becomes the body of a top-level `expect`. Either way,
+/// `roc test` runs the `expect`s written in the block.
+pub fn doc_tests_to_source(src: &str) -> Option<String> {
+    let arena = Bump::new();
+
+    // Doc comments before the end of the header document the module itself,
+    // and can't be turned into code without breaking the header.
+    let header_end = match parse_header(&arena, State::new(src.as_bytes())) {
+        Ok((_, state)) => state.pos().offset as usize,
+        Err(_) => return None,
+    };
+    let first_line = src[..header_end].matches('\n').count() + 1;
+
+    let mut lines: Vec<String> = src.split('\n').map(str::to_string).collect();
+    let mut found_code_block = false;
+
+    for (open, close) in code_blocks(&lines, first_line) {
+        let body = &mut lines[open + 1..close];
+
+        for line in body.iter_mut() {
+            if line.trim_start_matches('#').trim().is_empty() {
+                line.clear();
+            } else {
+                // "## foo" becomes "   foo", so the code stays at the same column
+                line.replace_range(..2, "  ");
+            }
+        }
+
+        let indent = match body
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+        {
+            Some(indent) => indent,
+            None => continue,
+        };
+
+        let snippet = body
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Line numbers are unique per module, so they make for unique names.
+        let line_number = open + 1;
+
+        if ends_in_expr(&arena, &snippet) {
+            lines[open] = format!("docTest{line_number} = \\{{}} ->");
+            lines[close] = format!("expect (\\_ -> Bool.true) (docTest{line_number} {{}})");
+        } else {
+            lines[open] = "expect".to_string();
+            lines[close] = format!("{}Bool.true", " ".repeat(indent));
+        }
+
+        found_code_block = true;
+    }
+
+    if found_code_block {
+        Some(lines.join("\n"))
+    } else {
+        None
+    }
+}
+
+/// The (opening fence, closing fence) line indices of each checked code block
+/// in the top-level doc comments, starting from `first_line`.
+fn code_blocks(lines: &[String], first_line: usize) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::new();
+    // The line the current block was opened on, and whether it gets checked.
+    let mut open_block: Option<(usize, bool)> = None;
+
+    for (index, line) in lines.iter().enumerate().skip(first_line) {
+        let content = match doc_comment_content(line) {
+            Some(content) => content.trim(),
+            None => {
+                // A code block can't outlive its doc comment.
+                open_block = None;
+
+                continue;
+            }
+        };
+
+        match (open_block, content.strip_prefix("```")) {
+            (None, Some(info)) => {
+                open_block = Some((index, is_checked(info)));
+            }
+            (Some((open, checked)), Some("")) => {
+                if checked {
+                    blocks.push((open, index));
+                }
+
+                open_block = None;
+            }
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+/// The text after the `##` of a top-level doc comment line, if this line is one.
+/// An indented `##` isn't a top-level doc comment.
+fn doc_comment_content(line: &str) -> Option<&str> {
+    let content = line.strip_prefix("##")?;
+
+    if content.starts_with('#') {
+        // `###` starts an ordinary comment, not a doc comment.
+        None
+    } else {
+        Some(content)
+    }
+}
+
+fn is_checked(info: &str) -> bool {
+    let mut words = info
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|word| !word.is_empty());
+
+    words.next() == Some("roc") && words.all(|word| word != "unchecked" && word != "repl")
+}
+
+/// Whether this code block ends in an expression (as opposed to a def, an `expect`,
+/// etc.), meaning the block has a value.
+///
+/// If the block doesn't parse at all, that gets reported once it's part of the module.
+fn ends_in_expr(arena: &Bump, snippet: &str) -> bool {
+    test_parse_expr(0, arena, State::new(snippet.as_bytes())).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn no_code_blocks() {
+        let src = indoc!(
+            r#"
+            module [len]
+
+            ## Returns the length.
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        assert_eq!(doc_tests_to_source(src), None);
+    }
+
+    #[test]
+    fn expects_become_toplevel_expect() {
+        let src = indoc!(
+            r#"
+            module [len]
+
+            ## Returns the length.
+            ## ```roc
+            ## expect len "abc" == 3
+            ## ```
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        let expected = indoc!(
+            r#"
+            module [len]
+
+            ## Returns the length.
+            expect
+               expect len "abc" == 3
+               Bool.true
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        assert_eq!(doc_tests_to_source(src).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn final_expression_becomes_function_body() {
+        let src = indoc!(
+            r#"
+            module [len]
+
+            ## ```roc
+            ## abc = "abc"
+            ##
+            ## len abc
+            ## ```
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        let expected = indoc!(
+            r#"
+            module [len]
+
+            docTest3 = \{} ->
+               abc = "abc"
+
+               len abc
+            expect (\_ -> Bool.true) (docTest3 {})
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        assert_eq!(doc_tests_to_source(src).as_deref(), Some(expected));
+    }
+
+    #[test]
+    fn skips_unchecked_and_other_languages() {
+        let src = indoc!(
+            r#"
+            module [len]
+
+            ## ```roc unchecked
+            ## len 1 2 3
+            ## ```
+            ## ```sh
+            ## roc test
+            ## ```
+            ## ```
+            ## » len "abc"
+            ## ```
+            len = \str -> Str.countUtf8Bytes str
+            "#
+        );
+
+        assert_eq!(doc_tests_to_source(src), None);
+    }
+
+    #[test]
+    fn skips_indented_doc_comments() {
+        let src = indoc!(
+            r#"
+            module [Sized, size]
+
+            Sized implements
+                ## The size of a value.
+                ## ```roc
+                ## expect size [1, 2] == 2
+                ## ```
+                size : a -> U64 where a implements Sized
+            "#
+        );
+
+        assert_eq!(doc_tests_to_source(src), None);
+    }
+}
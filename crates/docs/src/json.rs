@@ -0,0 +1,385 @@
+//! Generates a single `docs.json` file describing every exposed module, for other tools
+//! (API diff checkers, editor hovers, custom doc sites, etc.) to build on.
+//!
+//! Each exposed entry has its type annotation both as Roc source code (`annotation`) and
+//! as a tree (`type`), plus the type the compiler inferred for it (`inferred_type`) if it's a value.
+use crate::{
+    all_exposed_symbols, get_exposed_module_docs, inferred_type, load_module_for_docs,
+    reset_build_dir, type_annotation_to_string,
+};
+use roc_load::docs::{AbilityMember, DocEntry, RecordField, TypeAnnotation};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn generate_docs_json(root_file: PathBuf, build_dir: &Path) {
+    let mut loaded_module = load_module_for_docs(root_file);
+    let exposed_module_docs = get_exposed_module_docs(&mut loaded_module);
+    let all_exposed_symbols = all_exposed_symbols(&exposed_module_docs);
+    let mut modules = Vec::with_capacity(exposed_module_docs.len());
+
+    for (module_id, module_docs) in exposed_module_docs.iter() {
+        let mut entries = Vec::with_capacity(module_docs.entries.len());
+
+        for entry in module_docs.entries.iter() {
+            match entry {
+                DocEntry::DocDef(doc_def) => {
+                    // Only include entries that are exposed
+                    if !all_exposed_symbols.contains(&doc_def.symbol) {
+                        continue;
+                    }
+
+                    let annotation = match doc_def.type_annotation {
+                        TypeAnnotation::NoTypeAnn => None,
+                        ref type_ann => {
+                            Some(type_annotation_to_string(type_ann).trim_start().to_string())
+                        }
+                    };
+
+                    entries.push(json!({
+                        "kind": "def",
+                        "name": doc_def.name,
+                        "qualified_name": format!("{}.{}", module_docs.name, doc_def.name),
+                        "type_vars": doc_def.type_vars,
+                        "annotation": annotation,
+                        "type": type_annotation_to_json(&doc_def.type_annotation),
+                        "inferred_type": inferred_type(&mut loaded_module, *module_id, doc_def.symbol),
+                        "docs": doc_def.docs,
+                    }));
+                }
+                DocEntry::ModuleDoc(docs) => {
+                    entries.push(json!({ "kind": "module_doc", "docs": docs }));
+                }
+                DocEntry::DetachedDoc(docs) => {
+                    entries.push(json!({ "kind": "detached_doc", "docs": docs }));
+                }
+            }
+        }
+
+        modules.push(json!({
+            "name": module_docs.name,
+            "entries": entries,
+        }));
+    }
+
+    let contents = serde_json::to_string_pretty(&json!({ "modules": modules }))
+        .expect("docs JSON should always serialize");
+
+    reset_build_dir(build_dir);
+
+    fs::write(build_dir.join("docs.json"), contents).unwrap_or_else(|error| {
+        panic!("Attempted to write docs.json but failed with this error: {error}")
+    });
+
+    println!("🎉 Docs generated in {}", build_dir.display());
+}
+
+fn type_annotation_to_json(type_ann: &TypeAnnotation) -> Value {
+    match type_ann {
+        TypeAnnotation::TagUnion { tags, extension } => json!({
+            "kind": "tag_union",
+            "tags": tags
+                .iter()
+                .map(|tag| json!({
+                    "name": tag.name,
+                    "values": tag.values.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+            "extension": type_annotation_to_json(extension),
+        }),
+        TypeAnnotation::Function { args, output } => json!({
+            "kind": "function",
+            "args": args.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+            "output": type_annotation_to_json(output),
+        }),
+        TypeAnnotation::ObscuredTagUnion => json!({ "kind": "obscured_tag_union" }),
+        TypeAnnotation::ObscuredRecord => json!({ "kind": "obscured_record" }),
+        TypeAnnotation::BoundVariable(name) => json!({
+            "kind": "bound_variable",
+            "name": name,
+        }),
+        TypeAnnotation::Apply { name, parts } => json!({
+            "kind": "apply",
+            "name": name,
+            "parts": parts.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+        }),
+        TypeAnnotation::Record { fields, extension } => json!({
+            "kind": "record",
+            "fields": fields.iter().map(record_field_to_json).collect::<Vec<_>>(),
+            "extension": type_annotation_to_json(extension),
+        }),
+        TypeAnnotation::Tuple { elems, extension } => json!({
+            "kind": "tuple",
+            "elems": elems.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+            "extension": type_annotation_to_json(extension),
+        }),
+        TypeAnnotation::Ability { members } => json!({
+            "kind": "ability",
+            "members": members.iter().map(ability_member_to_json).collect::<Vec<_>>(),
+        }),
+        TypeAnnotation::Wildcard => json!({ "kind": "wildcard" }),
+        TypeAnnotation::NoTypeAnn => Value::Null,
+        TypeAnnotation::Where { ann, implements } => json!({
+            "kind": "where",
+            "ann": type_annotation_to_json(ann),
+            "implements": implements
+                .iter()
+                .map(|clause| json!({
+                    "name": clause.name,
+                    "abilities": clause.abilities.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+                }))
+                .collect::<Vec<_>>(),
+        }),
+        TypeAnnotation::As { ann, name, vars } => json!({
+            "kind": "as",
+            "ann": type_annotation_to_json(ann),
+            "name": name,
+            "vars": vars,
+        }),
+    }
+}
+
+fn record_field_to_json(field: &RecordField) -> Value {
+    match field {
+        RecordField::RecordField {
+            name,
+            type_annotation,
+        } => json!({
+            "kind": "required",
+            "name": name,
+            "type": type_annotation_to_json(type_annotation),
+        }),
+        RecordField::OptionalField {
+            name,
+            type_annotation,
+        } => json!({
+            "kind": "optional",
+            "name": name,
+            "type": type_annotation_to_json(type_annotation),
+        }),
+        RecordField::LabelOnly { name } => json!({
+            "kind": "label_only",
+            "name": name,
+        }),
+    }
+}
+
+fn ability_member_to_json(member: &AbilityMember) -> Value {
+    json!({
+        "name": member.name,
+        "type": type_annotation_to_json(&member.type_annotation),
+        "able_variables": member
+            .able_variables
+            .iter()
+            .map(|(name, abilities)| json!({
+                "name": name,
+                "abilities": abilities.iter().map(type_annotation_to_json).collect::<Vec<_>>(),
+            }))
+            .collect::<Vec<_>>(),
+        "docs": member.docs,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes_package() {
+        let root_file =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/shapes/main.roc");
+        let build_dir = tempfile::tempdir().unwrap();
+
+        generate_docs_json(root_file, build_dir.path());
+
+        let contents = fs::read_to_string(build_dir.path().join("docs.json")).unwrap();
+
+        insta::assert_snapshot!(contents, @r###"
+        {
+          "modules": [
+            {
+              "entries": [
+                {
+                  "docs": "Simple geometric shapes.\n",
+                  "kind": "module_doc"
+                },
+                {
+                  "annotation": "{\n        x : a,\n        y : a\n    }",
+                  "docs": "A position on a plane.\n",
+                  "inferred_type": null,
+                  "kind": "def",
+                  "name": "Point",
+                  "qualified_name": "Shape.Point",
+                  "type": {
+                    "extension": null,
+                    "fields": [
+                      {
+                        "kind": "required",
+                        "name": "x",
+                        "type": {
+                          "kind": "bound_variable",
+                          "name": "a"
+                        }
+                      },
+                      {
+                        "kind": "required",
+                        "name": "y",
+                        "type": {
+                          "kind": "bound_variable",
+                          "name": "a"
+                        }
+                      }
+                    ],
+                    "kind": "record"
+                  },
+                  "type_vars": [
+                    "a"
+                  ]
+                },
+                {
+                  "annotation": null,
+                  "docs": "A shape with a [Color].\n",
+                  "inferred_type": null,
+                  "kind": "def",
+                  "name": "Shape",
+                  "qualified_name": "Shape.Shape",
+                  "type": null,
+                  "type_vars": []
+                },
+                {
+                  "annotation": "F64, Color -> Shape",
+                  "docs": "Makes a circle with the given radius.\n\n```roc\ncircle 2 Red\n```\n",
+                  "inferred_type": "F64, Color.Color -> Shape",
+                  "kind": "def",
+                  "name": "circle",
+                  "qualified_name": "Shape.circle",
+                  "type": {
+                    "args": [
+                      {
+                        "kind": "apply",
+                        "name": "F64",
+                        "parts": []
+                      },
+                      {
+                        "kind": "apply",
+                        "name": "Color",
+                        "parts": []
+                      }
+                    ],
+                    "kind": "function",
+                    "output": {
+                      "kind": "apply",
+                      "name": "Shape",
+                      "parts": []
+                    }
+                  },
+                  "type_vars": []
+                },
+                {
+                  "annotation": "Shape -> F64",
+                  "docs": "How much of the plane the shape covers.\n",
+                  "inferred_type": "Shape -> F64",
+                  "kind": "def",
+                  "name": "area",
+                  "qualified_name": "Shape.area",
+                  "type": {
+                    "args": [
+                      {
+                        "kind": "apply",
+                        "name": "Shape",
+                        "parts": []
+                      }
+                    ],
+                    "kind": "function",
+                    "output": {
+                      "kind": "apply",
+                      "name": "F64",
+                      "parts": []
+                    }
+                  },
+                  "type_vars": []
+                },
+                {
+                  "annotation": "Point F64",
+                  "docs": null,
+                  "inferred_type": "Point F64",
+                  "kind": "def",
+                  "name": "origin",
+                  "qualified_name": "Shape.origin",
+                  "type": {
+                    "kind": "apply",
+                    "name": "Point",
+                    "parts": [
+                      {
+                        "kind": "apply",
+                        "name": "F64",
+                        "parts": []
+                      }
+                    ]
+                  },
+                  "type_vars": []
+                }
+              ],
+              "name": "Shape"
+            },
+            {
+              "entries": [
+                {
+                  "annotation": "[\n        Red,\n        Green,\n        Blue\n    ]",
+                  "docs": "One of the colors a [Shape] can have.\n",
+                  "inferred_type": null,
+                  "kind": "def",
+                  "name": "Color",
+                  "qualified_name": "Color.Color",
+                  "type": {
+                    "extension": null,
+                    "kind": "tag_union",
+                    "tags": [
+                      {
+                        "name": "Red",
+                        "values": []
+                      },
+                      {
+                        "name": "Green",
+                        "values": []
+                      },
+                      {
+                        "name": "Blue",
+                        "values": []
+                      }
+                    ]
+                  },
+                  "type_vars": []
+                },
+                {
+                  "annotation": "Color -> Str",
+                  "docs": null,
+                  "inferred_type": "Color -> Str",
+                  "kind": "def",
+                  "name": "toStr",
+                  "qualified_name": "Color.toStr",
+                  "type": {
+                    "args": [
+                      {
+                        "kind": "apply",
+                        "name": "Color",
+                        "parts": []
+                      }
+                    ],
+                    "kind": "function",
+                    "output": {
+                      "kind": "apply",
+                      "name": "Str",
+                      "parts": []
+                    }
+                  },
+                  "type_vars": []
+                }
+              ],
+              "name": "Color"
+            }
+          ]
+        }
+        "###);
+    }
+}
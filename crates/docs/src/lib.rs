@@ -15,11 +15,42 @@ use roc_parse::keyword;
 use roc_parse::state::State;
 use roc_problem::Severity;
 use roc_region::all::Region;
+use roc_reporting::report::to_file_problem_report_string;
+use roc_types::pretty_print::{name_and_print_var, DebugPrint};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+mod doc_tests;
+mod json;
+mod markdown;
+mod package_diff;
+pub use doc_tests::{check_docs, doc_tests_to_source};
+pub use json::generate_docs_json;
+pub use markdown::generate_docs_markdown;
+pub use package_diff::{diff_packages, required_bump, ApiChange, SemverBump};
+
 const LINK_SVG: &str = include_str!("./static/link.svg");
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocsFormat {
+    Html,
+    Markdown,
+    Json,
+}
+
+impl<'a> TryFrom<&'a str> for DocsFormat {
+    type Error = ();
+
+    fn try_from(format: &'a str) -> Result<Self, Self::Error> {
+        match format {
+            "html" => Ok(DocsFormat::Html),
+            "markdown" => Ok(DocsFormat::Markdown),
+            "json" => Ok(DocsFormat::Json),
+            _ => Err(()),
+        }
+    }
+}
+
 pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
     let mut loaded_module = load_module_for_docs(root_file);
     let exposed_module_docs = get_exposed_module_docs(&mut loaded_module);
@@ -28,12 +59,7 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
     // github.com/roc-lang/roc/issues/5712
     let package_name = "Documentation".to_string();
 
-    // Clear out the generated-docs dir (we'll create a fresh one at the end)
-    if build_dir.exists() {
-        fs::remove_dir_all(build_dir)
-            .expect("TODO gracefully handle being unable to delete build dir");
-    }
-    fs::create_dir_all(build_dir).expect("TODO gracefully handle being unable to create build dir");
+    reset_build_dir(build_dir);
 
     // Copy over the assets
     // For debug builds, read assets from fs to speed up build
@@ -114,15 +140,7 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
             render_sidebar(exposed_module_docs.iter().map(|(_, docs)| docs)).as_str(),
         );
 
-    let all_exposed_symbols = {
-        let mut set = VecSet::default();
-
-        for (_, docs) in exposed_module_docs.iter() {
-            set.insert_all(docs.exposed_symbols.iter().copied());
-        }
-
-        set
-    };
+    let all_exposed_symbols = all_exposed_symbols(&exposed_module_docs);
 
     // TODO fix: as is, this overrides an existing index.html
     // Write index.html for package (/index.html)
@@ -181,6 +199,60 @@ pub fn generate_docs_html(root_file: PathBuf, build_dir: &Path) {
     println!("🎉 Docs generated in {}", build_dir.display());
 }
 
+/// Clear out the generated-docs dir, and create a fresh one.
+fn reset_build_dir(build_dir: &Path) {
+    if build_dir.exists() {
+        fs::remove_dir_all(build_dir)
+            .expect("TODO gracefully handle being unable to delete build dir");
+    }
+    fs::create_dir_all(build_dir).expect("TODO gracefully handle being unable to create build dir");
+}
+
+fn all_exposed_symbols(docs_by_module: &[(ModuleId, ModuleDocumentation)]) -> VecSet<Symbol> {
+    let mut set = VecSet::default();
+
+    for (_, docs) in docs_by_module.iter() {
+        set.insert_all(docs.exposed_symbols.iter().copied());
+    }
+
+    set
+}
+
+/// The type the compiler inferred for a value, printed the way `roc repl` prints types.
+/// Types, aliases, and abilities don't have one.
+fn inferred_type(
+    loaded_module: &mut LoadedModule,
+    module_id: ModuleId,
+    symbol: Symbol,
+) -> Option<String> {
+    let (subs, decls) = if module_id == loaded_module.module_id {
+        (
+            loaded_module.solved.inner_mut(),
+            loaded_module.declarations_by_id.get(&module_id)?,
+        )
+    } else {
+        let checked = loaded_module.typechecked.get_mut(&module_id)?;
+
+        (checked.solved_subs.inner_mut(), &checked.decls)
+    };
+
+    let index = decls.symbols.iter().position(|loc| loc.value == symbol)?;
+    let var = decls.variables[index];
+
+    // Naming the type variables modifies the Subs, so undo that afterwards.
+    let snapshot = subs.snapshot();
+    let type_str = name_and_print_var(
+        var,
+        subs,
+        module_id,
+        &loaded_module.interns,
+        DebugPrint::NOTHING,
+    );
+    subs.rollback_to(snapshot);
+
+    Some(type_str)
+}
+
 /// Gives only the module docs for modules that are exposed by the platform or package.
 fn get_exposed_module_docs(
     loaded_module: &mut LoadedModule,
@@ -486,11 +558,18 @@ pub fn load_module_for_docs(filename: PathBuf) -> LoadedModule {
         load_config,
     ) {
         Ok(loaded) => loaded,
-        Err(LoadingProblem::FormattedReport(report)) => {
+        Err(LoadingProblem::FormattedReport(report, _)) => {
             eprintln!("{report}");
             std::process::exit(1);
         }
-        Err(e) => panic!("{e:?}"),
+        Err(LoadingProblem::FileProblem { filename, error }) => {
+            eprintln!("{}", to_file_problem_report_string(filename, error));
+            std::process::exit(1);
+        }
+        Err(other) => {
+            eprintln!("Could not load the package for its docs: {other:?}");
+            std::process::exit(1);
+        }
     }
 }
 
@@ -506,6 +585,15 @@ fn new_line(buf: &mut String) {
     buf.push('\n');
 }
 
+/// The annotation as Roc source code, laid out the same way as in the HTML docs.
+fn type_annotation_to_string(type_ann: &TypeAnnotation) -> String {
+    let mut buf = String::new();
+
+    type_annotation_to_html(0, &mut buf, type_ann, false);
+
+    buf
+}
+
 // html is written to buf
 fn type_annotation_to_html(
     indent_level: usize,
@@ -1198,6 +1286,7 @@ fn report_markdown_link_problem(
     link_markdown: &str,
     problem: LinkProblem,
 ) {
+    use roc_reporting::error_code;
     use roc_reporting::report::{Report, RocDocAllocator, DEFAULT_PALETTE};
     use ven_pretty::DocAllocator;
 
@@ -1237,7 +1326,9 @@ fn report_markdown_link_problem(
             filename,
             doc,
             title: "INVALID DOCS LINK".to_string(),
+            error_code: Some(error_code::R0400),
             severity: Severity::Warning,
+            fixes: Vec::new(),
         }
     };
 
@@ -0,0 +1,216 @@
+//! Generates Markdown documentation, for publishing somewhere that renders Markdown
+//! (a wiki, a git forge, etc.) rather than hosting the HTML docs.
+use crate::{
+    all_exposed_symbols, get_exposed_module_docs, load_module_for_docs, reset_build_dir,
+    type_annotation_to_string,
+};
+use roc_collections::VecSet;
+use roc_load::docs::{DocEntry, ModuleDocumentation, TypeAnnotation};
+use roc_module::symbol::Symbol;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub fn generate_docs_markdown(root_file: PathBuf, build_dir: &Path) {
+    let mut loaded_module = load_module_for_docs(root_file);
+    let exposed_module_docs = get_exposed_module_docs(&mut loaded_module);
+    let all_exposed_symbols = all_exposed_symbols(&exposed_module_docs);
+
+    reset_build_dir(build_dir);
+
+    // Write index.md for the package, which links to each module's file
+    {
+        let mut index = String::from("# Exposed Modules\n\n");
+
+        for (_, module_docs) in exposed_module_docs.iter() {
+            let module_name = module_docs.name.as_str();
+
+            index.push_str(&format!(
+                "- [{module_name}]({})\n",
+                module_filename(module_name)
+            ));
+        }
+
+        fs::write(build_dir.join("index.md"), index).unwrap_or_else(|error| {
+            panic!("Attempted to write index.md but failed with this error: {error}")
+        });
+    }
+
+    for (_, module_docs) in exposed_module_docs.iter() {
+        let path = build_dir.join(module_filename(module_docs.name.as_str()));
+
+        fs::write(
+            &path,
+            render_module_markdown(module_docs, &all_exposed_symbols),
+        )
+        .unwrap_or_else(|error| {
+            panic!(
+                "Attempted to write {} but failed with this error: {}",
+                path.display(),
+                error
+            )
+        });
+    }
+
+    println!("🎉 Docs generated in {}", build_dir.display());
+}
+
+/// Modules get one flat file each (e.g. `Foo.Bar.md`), because wikis tend not to
+/// handle nested directories well.
+fn module_filename(module_name: &str) -> String {
+    format!("{module_name}.md")
+}
+
+fn render_module_markdown(
+    module: &ModuleDocumentation,
+    all_exposed_symbols: &VecSet<Symbol>,
+) -> String {
+    let mut buf = format!("# {}\n\n", module.name);
+
+    for entry in &module.entries {
+        match entry {
+            DocEntry::DocDef(doc_def) => {
+                // Only render entries that are exposed
+                if !all_exposed_symbols.contains(&doc_def.symbol) {
+                    continue;
+                }
+
+                buf.push_str("## ");
+                buf.push_str(&doc_def.name);
+                buf.push_str("\n\n```roc\n");
+                buf.push_str(&doc_def.name);
+
+                for type_var in &doc_def.type_vars {
+                    buf.push(' ');
+                    buf.push_str(type_var);
+                }
+
+                let type_ann = &doc_def.type_annotation;
+
+                if !matches!(type_ann, TypeAnnotation::NoTypeAnn) {
+                    // Ability declarations don't have ":" after the name, just `implements`
+                    if !matches!(type_ann, TypeAnnotation::Ability { .. }) {
+                        buf.push_str(" :");
+                    }
+
+                    buf.push(' ');
+                    buf.push_str(type_annotation_to_string(type_ann).trim_start());
+                }
+
+                buf.push_str("\n```\n\n");
+
+                if let Some(docs) = &doc_def.docs {
+                    push_docs(&mut buf, docs);
+                }
+            }
+            DocEntry::ModuleDoc(docs) | DocEntry::DetachedDoc(docs) => {
+                push_docs(&mut buf, docs);
+            }
+        }
+    }
+
+    buf
+}
+
+/// Doc comments are already Markdown; this just drops the space after each `##`.
+fn push_docs(buf: &mut String, docs: &str) {
+    for line in docs.lines() {
+        buf.push_str(line.strip_prefix(' ').unwrap_or(line));
+        buf.push('\n');
+    }
+
+    buf.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shapes_package() {
+        let root_file =
+            Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/shapes/main.roc");
+        let build_dir = tempfile::tempdir().unwrap();
+
+        generate_docs_markdown(root_file, build_dir.path());
+
+        let read = |name: &str| fs::read_to_string(build_dir.path().join(name)).unwrap();
+
+        insta::assert_snapshot!(read("index.md"), @r###"
+        # Exposed Modules
+
+        - [Shape](Shape.md)
+        - [Color](Color.md)
+        "###);
+        insta::assert_snapshot!(read("Shape.md"), @r###"
+        # Shape
+
+        Simple geometric shapes.
+
+        ## Point
+
+        ```roc
+        Point a : {
+                x : a,
+                y : a
+            }
+        ```
+
+        A position on a plane.
+
+        ## Shape
+
+        ```roc
+        Shape
+        ```
+
+        A shape with a [Color].
+
+        ## circle
+
+        ```roc
+        circle : F64, Color -> Shape
+        ```
+
+        Makes a circle with the given radius.
+
+        ```roc
+        circle 2 Red
+        ```
+
+        ## area
+
+        ```roc
+        area : Shape -> F64
+        ```
+
+        How much of the plane the shape covers.
+
+        ## origin
+
+        ```roc
+        origin : Point F64
+        ```
+        "###);
+        insta::assert_snapshot!(read("Color.md"), @r###"
+        # Color
+
+        ## Color
+
+        ```roc
+        Color : [
+                Red,
+                Green,
+                Blue
+            ]
+        ```
+
+        One of the colors a [Shape] can have.
+
+        ## toStr
+
+        ```roc
+        toStr : Color -> Str
+        ```
+        "###);
+    }
+}
@@ -0,0 +1,615 @@
+//! Compares the public API of two versions of a package, and classifies each change
+//! according to [semantic versioning](https://semver.org), so package authors know
+//! whether a release could break their users.
+use crate::{
+    all_exposed_symbols, get_exposed_module_docs, inferred_type, load_module_for_docs,
+    type_annotation_to_string,
+};
+use roc_can::abilities::AbilitiesStore;
+use roc_collections::VecMap;
+use roc_load::docs::{DocEntry, TypeAnnotation};
+use roc_load::LoadedModule;
+use roc_module::symbol::{ModuleId, Symbol};
+use std::fmt;
+use std::path::PathBuf;
+
+/// How big a version bump a change requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SemverBump {
+    /// Nothing users can depend on changed (e.g. docs).
+    Patch,
+    /// Something was added, so code written against the old version still works.
+    Minor,
+    /// Code written against the old version may no longer compile.
+    Major,
+}
+
+impl fmt::Display for SemverBump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SemverBump::Patch => write!(f, "patch"),
+            SemverBump::Minor => write!(f, "minor"),
+            SemverBump::Major => write!(f, "major"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiChange {
+    pub bump: SemverBump,
+    /// e.g. `Str.join`, or just `Str` if a whole module was added or removed
+    pub name: String,
+    pub description: String,
+}
+
+/// The public API of a package: for each exposed module, everything it exposes.
+#[derive(Debug, Default)]
+struct PackageApi {
+    modules: VecMap<String, VecMap<String, ApiEntry>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ApiEntry {
+    item: ApiItem,
+    docs: Option<String>,
+}
+
+/// Types are stored as strings with their type variables renamed in order of appearance,
+/// so that renaming a type variable doesn't count as a change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ApiItem {
+    Value {
+        type_str: String,
+    },
+    Type {
+        arity: usize,
+        /// `None` for opaque types, and aliases of types that aren't exposed
+        annotation: Option<String>,
+        /// Sorted, fully-qualified names of the abilities this type implements
+        abilities: Vec<String>,
+    },
+    Ability {
+        /// (member name, member type)
+        members: Vec<(String, String)>,
+    },
+}
+
+impl ApiItem {
+    fn kind(&self) -> &'static str {
+        match self {
+            ApiItem::Value { .. } => "value",
+            ApiItem::Type {
+                annotation: None, ..
+            } => "opaque type",
+            ApiItem::Type { .. } => "type alias",
+            ApiItem::Ability { .. } => "ability",
+        }
+    }
+}
+
+/// Load both versions of the package, and list every change to its public API,
+/// biggest changes first.
+pub fn diff_packages(old_root_file: PathBuf, new_root_file: PathBuf) -> Vec<ApiChange> {
+    let old = PackageApi::load(old_root_file);
+    let new = PackageApi::load(new_root_file);
+
+    diff_apis(&old, &new)
+}
+
+/// The smallest version bump that covers all of these changes, or `None` if there are none.
+pub fn required_bump(changes: &[ApiChange]) -> Option<SemverBump> {
+    changes.iter().map(|change| change.bump).max()
+}
+
+impl PackageApi {
+    fn load(root_file: PathBuf) -> Self {
+        let mut loaded_module = load_module_for_docs(root_file);
+        let exposed_module_docs = get_exposed_module_docs(&mut loaded_module);
+        let all_exposed_symbols = all_exposed_symbols(&exposed_module_docs);
+        let mut modules = VecMap::with_capacity(exposed_module_docs.len());
+
+        for (module_id, module_docs) in exposed_module_docs.iter() {
+            let mut entries = VecMap::default();
+
+            for entry in module_docs.entries.iter() {
+                let doc_def = match entry {
+                    DocEntry::DocDef(doc_def) if all_exposed_symbols.contains(&doc_def.symbol) => {
+                        doc_def
+                    }
+                    _ => continue,
+                };
+
+                let item = match &doc_def.type_annotation {
+                    TypeAnnotation::Ability { members } => ApiItem::Ability {
+                        members: members
+                            .iter()
+                            .map(|member| {
+                                let mut type_str =
+                                    type_annotation_to_string(&member.type_annotation);
+
+                                for (var, abilities) in member.able_variables.iter() {
+                                    let abilities = abilities
+                                        .iter()
+                                        .map(type_annotation_to_string)
+                                        .collect::<Vec<_>>();
+
+                                    type_str.push_str(&format!(
+                                        " where {var} implements {}",
+                                        abilities.join(" & ")
+                                    ));
+                                }
+
+                                (member.name.clone(), normalize_type(&type_str, &[]))
+                            })
+                            .collect(),
+                    },
+                    type_ann => {
+                        match inferred_type(&mut loaded_module, *module_id, doc_def.symbol) {
+                            Some(type_str) => ApiItem::Value {
+                                type_str: normalize_type(&type_str, &[]),
+                            },
+                            None => ApiItem::Type {
+                                arity: doc_def.type_vars.len(),
+                                annotation: match type_ann {
+                                    TypeAnnotation::NoTypeAnn => None,
+                                    type_ann => Some(normalize_type(
+                                        &type_annotation_to_string(type_ann),
+                                        &doc_def.type_vars,
+                                    )),
+                                },
+                                abilities: implemented_abilities(
+                                    &loaded_module,
+                                    *module_id,
+                                    doc_def.symbol,
+                                ),
+                            },
+                        }
+                    }
+                };
+
+                entries.insert(
+                    doc_def.name.clone(),
+                    ApiEntry {
+                        item,
+                        docs: doc_def.docs.clone(),
+                    },
+                );
+            }
+
+            modules.insert(module_docs.name.clone(), entries);
+        }
+
+        Self { modules }
+    }
+}
+
+fn abilities_store(loaded_module: &LoadedModule, module_id: ModuleId) -> Option<&AbilitiesStore> {
+    if module_id == loaded_module.module_id {
+        Some(&loaded_module.abilities_store)
+    } else {
+        loaded_module
+            .typechecked
+            .get(&module_id)
+            .map(|checked| &checked.abilities_store)
+    }
+}
+
+fn implemented_abilities(
+    loaded_module: &LoadedModule,
+    module_id: ModuleId,
+    opaque: Symbol,
+) -> Vec<String> {
+    let store = match abilities_store(loaded_module, module_id) {
+        Some(store) => store,
+        None => return Vec::new(),
+    };
+    let interns = &loaded_module.interns;
+
+    let mut abilities = store
+        .iter_declared_implementations()
+        .filter(|(impl_key, _)| impl_key.opaque == opaque)
+        .filter_map(|(impl_key, _)| store.member_def(impl_key.ability_member))
+        .map(|member| {
+            let ability = member.parent_ability;
+
+            format!(
+                "{}.{}",
+                ability.module_string(interns).as_str(),
+                ability.as_str(interns)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    abilities.sort();
+    abilities.dedup();
+
+    abilities
+}
+
+/// Rename the type variables in a printed type to `a`, `b`, `c`... in order of first
+/// appearance (starting with the given type parameters, in order), and collapse whitespace.
+/// That way, two types are the same exactly when their normalized strings are equal.
+fn normalize_type(type_str: &str, type_params: &[String]) -> String {
+    let mut names: VecMap<&str, String> = VecMap::default();
+
+    for param in type_params {
+        let name = canonical_var_name(names.len());
+
+        names.insert(param.as_str(), name);
+    }
+
+    let type_str = type_str.split_whitespace().collect::<Vec<_>>().join(" ");
+    let bytes = type_str.as_bytes();
+    let mut answer = String::with_capacity(type_str.len());
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let start = index;
+
+        if !bytes[index].is_ascii_alphanumeric() && bytes[index] != b'_' {
+            answer.push(bytes[index] as char);
+            index += 1;
+
+            continue;
+        }
+
+        while index < bytes.len() && (bytes[index].is_ascii_alphanumeric() || bytes[index] == b'_')
+        {
+            index += 1;
+        }
+
+        let word = &type_str[start..index];
+        let rest = type_str[index..].trim_start();
+        let is_type_var = bytes[start].is_ascii_lowercase()
+            // Qualified names like `Num.U64` are never type variables
+            && (start == 0 || bytes[start - 1] != b'.')
+            // Record field labels are followed by `:` or `?`
+            && !rest.starts_with(':')
+            && !rest.starts_with('?')
+            && word != "where"
+            && word != "implements";
+
+        if is_type_var {
+            if !names.contains_key(&word) {
+                let name = canonical_var_name(names.len());
+
+                names.insert(word, name);
+            }
+
+            answer.push_str(names.get(&word).unwrap());
+        } else {
+            answer.push_str(word);
+        }
+    }
+
+    answer
+}
+
+fn canonical_var_name(index: usize) -> String {
+    let letter = (b'a' + (index % 26) as u8) as char;
+
+    if index < 26 {
+        letter.to_string()
+    } else {
+        format!("{letter}{}", index / 26)
+    }
+}
+
+fn diff_apis(old: &PackageApi, new: &PackageApi) -> Vec<ApiChange> {
+    let mut changes = Vec::new();
+
+    for (module_name, old_entries) in old.modules.iter() {
+        let new_entries = match new.modules.get(module_name) {
+            Some(new_entries) => new_entries,
+            None => {
+                changes.push(ApiChange {
+                    bump: SemverBump::Major,
+                    name: module_name.clone(),
+                    description: "module is no longer exposed".to_string(),
+                });
+
+                continue;
+            }
+        };
+
+        for (name, old_entry) in old_entries.iter() {
+            let qualified_name = format!("{module_name}.{name}");
+
+            match new_entries.get(name) {
+                Some(new_entry) => {
+                    diff_entries(&mut changes, qualified_name, old_entry, new_entry);
+                }
+                None => changes.push(ApiChange {
+                    bump: SemverBump::Major,
+                    name: qualified_name,
+                    description: format!("{} was removed", old_entry.item.kind()),
+                }),
+            }
+        }
+
+        for (name, new_entry) in new_entries.iter() {
+            if !old_entries.contains_key(name) {
+                changes.push(ApiChange {
+                    bump: SemverBump::Minor,
+                    name: format!("{module_name}.{name}"),
+                    description: format!("{} was added", new_entry.item.kind()),
+                });
+            }
+        }
+    }
+
+    for module_name in new.modules.keys() {
+        if !old.modules.contains_key(module_name) {
+            changes.push(ApiChange {
+                bump: SemverBump::Minor,
+                name: module_name.clone(),
+                description: "module is newly exposed".to_string(),
+            });
+        }
+    }
+
+    // Biggest changes first; otherwise keep the order they appear in the package.
+    changes.sort_by_key(|change| std::cmp::Reverse(change.bump));
+
+    changes
+}
+
+fn diff_entries(changes: &mut Vec<ApiChange>, name: String, old: &ApiEntry, new: &ApiEntry) {
+    let mut change = |bump, description| {
+        changes.push(ApiChange {
+            bump,
+            name: name.clone(),
+            description,
+        })
+    };
+
+    match (&old.item, &new.item) {
+        (ApiItem::Value { type_str: old_type }, ApiItem::Value { type_str: new_type }) => {
+            if old_type != new_type {
+                // Even a more general type can break callers (e.g. by making a
+                // type ambiguous), so any change to a value's type is breaking.
+                change(
+                    SemverBump::Major,
+                    format!("type changed from `{old_type}` to `{new_type}`"),
+                );
+            }
+        }
+        (
+            ApiItem::Type {
+                arity: old_arity,
+                annotation: old_ann,
+                abilities: old_abilities,
+            },
+            ApiItem::Type {
+                arity: new_arity,
+                annotation: new_ann,
+                abilities: new_abilities,
+            },
+        ) => {
+            if old_arity != new_arity {
+                change(
+                    SemverBump::Major,
+                    format!("number of type parameters changed from {old_arity} to {new_arity}"),
+                );
+            }
+
+            match (old_ann, new_ann) {
+                (Some(old_ann), Some(new_ann)) if old_ann != new_ann => change(
+                    SemverBump::Major,
+                    format!("type alias changed from `{old_ann}` to `{new_ann}`"),
+                ),
+                (Some(_), None) => change(
+                    SemverBump::Major,
+                    "type alias became an opaque type".to_string(),
+                ),
+                (None, Some(_)) => change(
+                    SemverBump::Major,
+                    "opaque type became a type alias".to_string(),
+                ),
+                // Changes to an opaque type's internals aren't visible outside the package.
+                _ => {}
+            }
+
+            for ability in old_abilities.iter() {
+                if !new_abilities.contains(ability) {
+                    change(SemverBump::Major, format!("no longer implements {ability}"));
+                }
+            }
+
+            for ability in new_abilities.iter() {
+                if !old_abilities.contains(ability) {
+                    change(SemverBump::Minor, format!("now implements {ability}"));
+                }
+            }
+        }
+        (
+            ApiItem::Ability {
+                members: old_members,
+            },
+            ApiItem::Ability {
+                members: new_members,
+            },
+        ) => {
+            for (member, old_type) in old_members.iter() {
+                match new_members.iter().find(|(name, _)| name == member) {
+                    Some((_, new_type)) if new_type != old_type => change(
+                        SemverBump::Major,
+                        format!("type of ability member {member} changed from `{old_type}` to `{new_type}`"),
+                    ),
+                    Some(_) => {}
+                    None => change(
+                        SemverBump::Major,
+                        format!("ability member {member} was removed"),
+                    ),
+                }
+            }
+
+            for (member, _) in new_members.iter() {
+                if !old_members.iter().any(|(name, _)| name == member) {
+                    // Every type that implements the ability now has to implement this too.
+                    change(
+                        SemverBump::Major,
+                        format!("ability member {member} was added"),
+                    );
+                }
+            }
+        }
+        (old_item, new_item) => change(
+            SemverBump::Major,
+            format!(
+                "changed from a {} to a {}",
+                old_item.kind(),
+                new_item.kind()
+            ),
+        ),
+    }
+
+    if old.docs != new.docs {
+        change(SemverBump::Patch, "docs changed".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(type_str: &str) -> ApiEntry {
+        ApiEntry {
+            item: ApiItem::Value {
+                type_str: normalize_type(type_str, &[]),
+            },
+            docs: None,
+        }
+    }
+
+    fn opaque(arity: usize, abilities: &[&str]) -> ApiEntry {
+        ApiEntry {
+            item: ApiItem::Type {
+                arity,
+                annotation: None,
+                abilities: abilities.iter().map(|name| name.to_string()).collect(),
+            },
+            docs: None,
+        }
+    }
+
+    fn package(modules: &[(&str, &[(&str, ApiEntry)])]) -> PackageApi {
+        let mut api = PackageApi::default();
+
+        for (module_name, entries) in modules {
+            let mut module = VecMap::default();
+
+            for (name, entry) in entries.iter() {
+                module.insert(name.to_string(), entry.clone());
+            }
+
+            api.modules.insert(module_name.to_string(), module);
+        }
+
+        api
+    }
+
+    #[test]
+    fn normalize_renames_type_vars_in_order() {
+        assert_eq!(
+            normalize_type("List elem, (elem -> state) -> state", &[]),
+            normalize_type("List x, (x -> a) -> a", &[])
+        );
+        assert_eq!(
+            normalize_type("List x, (x -> a) -> a", &[]),
+            "List a, (a -> b) -> b"
+        );
+    }
+
+    #[test]
+    fn normalize_keeps_field_labels_and_qualified_names() {
+        assert_eq!(
+            normalize_type(
+                "{ count : Num.U64, key ? k }\n    -> k where k implements Hash",
+                &[]
+            ),
+            "{ count : Num.U64, key ? a } -> a where a implements Hash"
+        );
+    }
+
+    #[test]
+    fn normalize_starts_with_type_params() {
+        assert_eq!(
+            normalize_type("[Ok ok, Err err]", &["ok".to_string(), "err".to_string()]),
+            "[Ok a, Err b]"
+        );
+    }
+
+    #[test]
+    fn no_changes() {
+        let old = package(&[("Foo", &[("bar", value("Str -> Str"))])]);
+        let new = package(&[("Foo", &[("bar", value("Str -> Str"))])]);
+
+        assert_eq!(diff_apis(&old, &new), Vec::new());
+        assert_eq!(required_bump(&diff_apis(&old, &new)), None);
+    }
+
+    #[test]
+    fn renaming_type_vars_is_not_a_change() {
+        let old = package(&[("Foo", &[("id", value("a -> a"))])]);
+        let new = package(&[("Foo", &[("id", value("val -> val"))])]);
+
+        assert_eq!(diff_apis(&old, &new), Vec::new());
+    }
+
+    #[test]
+    fn classifies_changes() {
+        let old = package(&[(
+            "Foo",
+            &[
+                ("bar", value("Str -> Str")),
+                ("baz", value("Str")),
+                ("Thing", opaque(1, &["Bool.Eq", "Hash.Hash"])),
+            ],
+        )]);
+        let new = package(&[(
+            "Foo",
+            &[
+                ("bar", value("Str, U64 -> Str")),
+                ("qux", value("Str")),
+                ("Thing", opaque(1, &["Bool.Eq", "Inspect.Inspect"])),
+            ],
+        )]);
+
+        let changes = diff_apis(&old, &new);
+        let summary = changes
+            .iter()
+            .map(|change| format!("{} {}: {}", change.bump, change.name, change.description))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            summary,
+            vec![
+                "major Foo.bar: type changed from `Str -> Str` to `Str, U64 -> Str`",
+                "major Foo.baz: value was removed",
+                "major Foo.Thing: no longer implements Hash.Hash",
+                "minor Foo.Thing: now implements Inspect.Inspect",
+                "minor Foo.qux: value was added",
+            ]
+        );
+        assert_eq!(required_bump(&changes), Some(SemverBump::Major));
+    }
+
+    #[test]
+    fn docs_changes_are_patches() {
+        let old = package(&[("Foo", &[("bar", value("Str"))])]);
+        let mut new_bar = value("Str");
+        new_bar.docs = Some(" Now with docs!\n".to_string());
+        let new = package(&[("Foo", &[("bar", new_bar)]), ("Baz", &[])]);
+
+        let changes = diff_apis(&old, &new);
+
+        assert_eq!(
+            changes
+                .iter()
+                .map(|change| (change.bump, change.name.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(SemverBump::Minor, "Baz"), (SemverBump::Patch, "Foo.bar")]
+        );
+    }
+}
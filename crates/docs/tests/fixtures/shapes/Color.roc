@@ -0,0 +1,11 @@
+module [Color, toStr]
+
+## One of the colors a [Shape] can have.
+Color : [Red, Green, Blue]
+
+toStr : Color -> Str
+toStr = \color ->
+    when color is
+        Red -> "red"
+        Green -> "green"
+        Blue -> "blue"
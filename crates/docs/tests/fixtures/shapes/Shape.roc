@@ -0,0 +1,31 @@
+## Simple geometric shapes.
+module [Shape, Point, circle, area, origin]
+
+import Color exposing [Color]
+
+## A position on a plane.
+Point a : { x : a, y : a }
+
+## A shape with a [Color].
+Shape := { kind : [Circle F64, Square F64], color : Color }
+
+## Makes a circle with the given radius.
+##
+## ```roc
+## circle 2 Red
+## ```
+circle : F64, Color -> Shape
+circle = \radius, color -> @Shape { kind: Circle radius, color }
+
+## How much of the plane the shape covers.
+area : Shape -> F64
+area = \@Shape { kind } ->
+    when kind is
+        Circle radius -> Num.pi * square radius
+        Square side -> square side
+
+origin : Point F64
+origin = { x: 0, y: 0 }
+
+# Not exposed, so it doesn't show up in the docs.
+square = \n -> n * n
@@ -0,0 +1,2 @@
+## Shapes and the colors to paint them with.
+package [Shape, Color] {}
@@ -16,7 +16,9 @@ use roc_load::{ExecutionMode, FunctionKind, LoadConfig, LoadedModule, LoadingPro
 use roc_mono::ir::{generate_glue_procs, CrashTag, GlueProc, OptLevel};
 use roc_mono::layout::{GlobalLayoutInterner, LayoutCache, LayoutInterner};
 use roc_packaging::cache::{self, RocCacheDir};
+use roc_reporting::cli::ErrorFormat;
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
+use roc_reporting::warnings::WarningConfig;
 use roc_target::{Architecture, Target, TargetFromTripleError::TripleUnsupported};
 use roc_types::subs::{Subs, Variable};
 use std::fs::File;
@@ -59,6 +61,9 @@ pub fn generate(
                 emit_debug_info: false,
                 emit_llvm_ir: false,
                 fuzz: false,
+                opt_report: false,
+                size_report: None,
+                wasm_features: Default::default(),
             };
 
             let load_config = standard_load_config(
@@ -69,11 +74,9 @@ pub fn generate(
 
             let arena = ManuallyDrop::new(Bump::new());
             let link_type = LinkType::Dylib;
-            let linking_strategy = if roc_linker::supported(link_type, target) {
-                LinkingStrategy::Surgical
-            } else {
-                LinkingStrategy::Legacy
-            };
+            // The glue spec is loaded as a plain library of Roc functions, there is no host
+            // for the surgical linker to link it into.
+            let linking_strategy = LinkingStrategy::Legacy;
 
             let tempdir_res = tempfile::tempdir();
 
@@ -84,6 +87,8 @@ pub fn generate(
                     spec_path.to_path_buf(),
                     code_gen_options,
                     false,
+                    ErrorFormat::Human,
+                    &WarningConfig::default(),
                     link_type,
                     linking_strategy,
                     true,
@@ -186,10 +191,17 @@ pub fn generate(
 
                     Ok(0)
                 }
-                Err(BuildFileError::ErrorModule { module, total_time }) => {
-                    handle_error_module(module, total_time, spec_path.as_os_str(), true)
+                Err(BuildFileError::ErrorModule { module, total_time }) => handle_error_module(
+                    module,
+                    total_time,
+                    spec_path.as_os_str(),
+                    true,
+                    ErrorFormat::Human,
+                    &WarningConfig::default(),
+                ),
+                Err(BuildFileError::LoadingProblem(problem)) => {
+                    handle_loading_problem(problem, ErrorFormat::Human)
                 }
-                Err(BuildFileError::LoadingProblem(problem)) => handle_loading_problem(problem),
             };
 
             // Extend the lifetime of the tempdir to after we're done with everything,
@@ -425,7 +437,7 @@ pub fn load_types(
         },
     )
     .unwrap_or_else(|problem| match problem {
-        LoadingProblem::FormattedReport(report) => {
+        LoadingProblem::FormattedReport(report, _) => {
             eprintln!("{report}");
 
             process::exit(1);
@@ -1,4 +1,5 @@
 use roc_parse::highlight::Token;
+use roc_parse::tokens::{TokenKind, TokenTable};
 
 pub fn highlight_roc_code(code: &str) -> String {
     let buf = highlight(code);
@@ -14,7 +15,6 @@ pub fn highlight_roc_code_inline(code: &str) -> String {
 
 pub fn highlight(code: &str) -> Vec<String> {
     let mut buf: Vec<String> = Vec::new();
-    let mut offset = 0;
 
     // Sometimes code snippets start with "»" in order to show that they're in the repl.
     // Special-case that even though it's normally not a valid highlight.
@@ -28,10 +28,20 @@ pub fn highlight(code: &str) -> Vec<String> {
         code
     };
 
-    for location in roc_parse::highlight::highlight(code) {
-        let current_text = &code[offset..location.byte_range().end];
+    let table = TokenTable::new(code);
 
-        match location.value {
+    for location in table.tokens() {
+        let current_text = table.text(location);
+
+        let token = match location.value {
+            TokenKind::Code(token) => token,
+            TokenKind::Whitespace | TokenKind::Newline => {
+                buf = push_html(buf, current_text);
+                continue;
+            }
+        };
+
+        match token {
             // Comments `#` and Documentation comments `##`
             Token::LineComment | Token::DocComment => {
                 buf = push_html_span(buf, current_text, "comment");
@@ -100,8 +110,6 @@ pub fn highlight(code: &str) -> Vec<String> {
                 buf = push_html(buf, current_text);
             }
         }
-
-        offset = location.byte_range().end;
     }
 
     buf
@@ -5,7 +5,8 @@ use bumpalo::Bump;
 
 use roc_module::symbol::{ModuleId, Symbol};
 
-use roc_region::all::LineInfo;
+use roc_fmt::range::range_edits;
+use roc_region::all::{LineInfo, Region};
 
 use tower_lsp::lsp_types::{
     CompletionItem, Diagnostic, GotoDefinitionResponse, Hover, HoverContents, LanguageString,
@@ -14,7 +15,7 @@ use tower_lsp::lsp_types::{
 
 use crate::{
     analysis::completion::{field_completion, get_completion_items, get_module_completion_items},
-    convert::{ToRange, ToRocPosition},
+    convert::{ToRange, ToRegion, ToRocPosition},
 };
 
 use super::{
@@ -100,11 +101,50 @@ impl DocInfo {
         }
     }
 
-    pub fn semantic_tokens(&self) -> Option<SemanticTokensResult> {
+    /// Format only the top-level defs that overlap `range`.
+    pub fn format_range(&self, range: Range) -> Option<Vec<TextEdit>> {
+        let region = range.to_region(&self.line_info);
+
+        self.format_region(region, |_| true)
+    }
+
+    /// Format the defs on the line that was just finished by typing a newline at `position`,
+    /// as long as they end before it. A def that is still being typed is left alone.
+    pub fn format_on_type(&self, position: Position) -> Option<Vec<TextEdit>> {
+        let previous_line = position.line.checked_sub(1)?;
+        let cursor = position.to_roc_position(&self.line_info);
+        let region =
+            Range::new(Position::new(previous_line, 0), position).to_region(&self.line_info);
+
+        self.format_region(region, |edit| edit.end() <= cursor)
+    }
+
+    fn format_region(
+        &self,
+        region: Region,
+        include: impl Fn(Region) -> bool,
+    ) -> Option<Vec<TextEdit>> {
         let source = &self.source;
         let arena = &Bump::new();
 
         let ast = Ast::parse(arena, source).ok()?;
+        let fmt = ast.fmt();
+        let edits = range_edits(arena, source, fmt.as_str(), region).ok()?;
+
+        let text_edits: Vec<TextEdit> = edits
+            .into_iter()
+            .filter(|edit| include(edit.region))
+            .map(|edit| TextEdit::new(edit.region.to_range(&self.line_info), edit.text.to_string()))
+            .collect();
+
+        (!text_edits.is_empty()).then_some(text_edits)
+    }
+
+    pub fn semantic_tokens(&self) -> Option<SemanticTokensResult> {
+        let source = &self.source;
+        let arena = &Bump::new();
+
+        let ast = Ast::parse_recovering(arena, source).ok()?;
         let tokens = ast.semantic_tokens();
 
         let data = arrange_semantic_tokens(tokens, &self.line_info);
@@ -2,7 +2,7 @@ use bumpalo::Bump;
 use roc_fmt::Buf;
 use roc_parse::{
     ast::{Defs, Module},
-    module::parse_module_defs,
+    module::{parse_module_defs, parse_module_defs_recovering},
     parser::SyntaxError,
 };
 use roc_region::all::Loc;
@@ -37,6 +37,25 @@ impl<'a> Ast<'a> {
         })
     }
 
+    /// Like [Ast::parse], but code in the body that doesn't parse is skipped over rather
+    /// than failing, so the rest of the file is still available while it's being edited.
+    pub fn parse_recovering(arena: &'a Bump, src: &'a str) -> Result<Ast<'a>, SyntaxError<'a>> {
+        use roc_parse::{module::parse_header, state::State};
+
+        let (module, state) = parse_header(arena, State::new(src.as_bytes()))
+            .map_err(|e| SyntaxError::Header(e.problem))?;
+
+        let (module, defs) = module.upgrade_header_imports(arena);
+
+        let (defs, _) = parse_module_defs_recovering(arena, state, defs);
+
+        Ok(Ast {
+            module,
+            defs,
+            arena,
+        })
+    }
+
     pub fn fmt(&self) -> FormattedAst<'a> {
         let mut buf = Buf::new_in(self.arena);
 
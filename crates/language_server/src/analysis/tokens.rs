@@ -731,6 +731,7 @@ impl IterTokens for Loc<Expr<'_>> {
             Expr::UnappliedRecordBuilder(e) => e.iter_tokens(arena),
             Expr::MalformedIdent(_, _)
             | Expr::MalformedClosure
+            | Expr::Malformed(_)
             | Expr::PrecedenceConflict(_)
             | Expr::EmptyDefsFinal
             | Expr::MalformedSuffixed(_) => {
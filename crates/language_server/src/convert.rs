@@ -42,7 +42,7 @@ impl ToRegion for Range {
             },
             end: LineColumn {
                 line: self.end.line,
-                column: self.end.line,
+                column: self.end.character,
             },
         };
 
@@ -76,8 +76,14 @@ pub(crate) mod diag {
     use roc_solve_problem::TypeError;
 
     use roc_problem::Severity;
+    use roc_reporting::fix::{Applicability, Fix};
     use roc_reporting::report::RocDocAllocator;
-    use tower_lsp::lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use tower_lsp::lsp_types::{
+        CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, DiagnosticSeverity,
+        NumberOrString, Position, Range, TextEdit, Url, WorkspaceEdit,
+    };
 
     use super::ToRange;
 
@@ -152,7 +158,7 @@ pub(crate) mod diag {
                 LoadingProblem::TriedToImportAppModule => {
                     "Attempted to import app module".to_string()
                 }
-                LoadingProblem::FormattedReport(report) => report.clone(),
+                LoadingProblem::FormattedReport(report, _) => report.clone(),
                 LoadingProblem::ImportCycle(_, _) => {
                     "Circular dependency between modules".to_string()
                 }
@@ -179,6 +185,72 @@ pub(crate) mod diag {
         }
     }
 
+    /// A fix for a diagnostic, kept in the diagnostic's `data`. Editors send diagnostics back
+    /// when asking for code actions, so the fixes come along without re-analysing anything.
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct QuickFix {
+        title: String,
+        edit: TextEdit,
+        is_preferred: bool,
+    }
+
+    fn fixes_to_data(fixes: Vec<Fix>, line_info: &LineInfo) -> Option<serde_json::Value> {
+        if fixes.is_empty() {
+            return None;
+        }
+
+        // With a single suggestion, it's very likely to be what was meant
+        let only_one = fixes.len() == 1;
+        let quick_fixes: Vec<QuickFix> = fixes
+            .into_iter()
+            .map(|fix| QuickFix {
+                title: fix.title,
+                edit: TextEdit {
+                    range: fix.region.to_range(line_info),
+                    new_text: fix.replacement,
+                },
+                is_preferred: only_one || fix.applicability == Applicability::Safe,
+            })
+            .collect();
+
+        serde_json::to_value(quick_fixes).ok()
+    }
+
+    /// The quick fix code actions for these diagnostics, from the fixes stored in their `data`.
+    pub(crate) fn quick_fixes(url: &Url, diagnostics: Vec<Diagnostic>) -> Vec<CodeActionOrCommand> {
+        let mut actions = Vec::new();
+
+        for diagnostic in diagnostics {
+            let Some(data) = diagnostic.data.clone() else {
+                continue;
+            };
+            let Ok(quick_fixes) = serde_json::from_value::<Vec<QuickFix>>(data) else {
+                continue;
+            };
+
+            for quick_fix in quick_fixes {
+                let changes = HashMap::from([(url.clone(), vec![quick_fix.edit])]);
+
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: quick_fix.title,
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(changes),
+                        ..WorkspaceEdit::default()
+                    }),
+                    command: None,
+                    is_preferred: Some(quick_fix.is_preferred),
+                    disabled: None,
+                    data: None,
+                }));
+            }
+        }
+
+        actions
+    }
+
     pub struct ProblemFmt<'a> {
         pub alloc: &'a RocDocAllocator<'a>,
         pub line_info: &'a LineInfo,
@@ -194,7 +266,7 @@ pub(crate) mod diag {
                 .unwrap_or_else(Region::zero)
                 .to_range(fmt.line_info);
 
-            let report = roc_reporting::report::can_problem(
+            let mut report = roc_reporting::report::can_problem(
                 fmt.alloc,
                 fmt.line_info,
                 fmt.path.to_path_buf(),
@@ -202,19 +274,23 @@ pub(crate) mod diag {
             );
 
             let severity = report.severity.into_lsp_severity();
+            let code = report
+                .code()
+                .map(|code| NumberOrString::String(code.to_string()));
+            let data = fixes_to_data(std::mem::take(&mut report.fixes), fmt.line_info);
             let mut msg = String::new();
             report.render_language_server(&mut msg, fmt.alloc);
 
             Some(Diagnostic {
                 range,
                 severity: Some(severity),
-                code: None,
+                code,
                 code_description: None,
                 source: None,
                 message: msg,
                 related_information: None,
                 tags: None,
-                data: None,
+                data,
             })
         }
     }
@@ -228,7 +304,7 @@ pub(crate) mod diag {
                 .unwrap_or_else(Region::zero)
                 .to_range(fmt.line_info);
 
-            let report = roc_reporting::report::type_problem(
+            let mut report = roc_reporting::report::type_problem(
                 fmt.alloc,
                 fmt.line_info,
                 fmt.path.to_path_buf(),
@@ -236,20 +312,24 @@ pub(crate) mod diag {
             )?;
 
             let severity = report.severity.into_lsp_severity();
+            let code = report
+                .code()
+                .map(|code| NumberOrString::String(code.to_string()));
 
+            let data = fixes_to_data(std::mem::take(&mut report.fixes), fmt.line_info);
             let mut msg = String::new();
             report.render_language_server(&mut msg, fmt.alloc);
 
             Some(Diagnostic {
                 range,
                 severity: Some(severity),
-                code: None,
+                code,
                 code_description: None,
                 source: None,
                 message: msg,
                 related_information: None,
                 tags: None,
-                data: None,
+                data,
             })
         }
     }
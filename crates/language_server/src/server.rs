@@ -11,6 +11,7 @@ use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
 
 use crate::analysis::{global_analysis, DocInfo};
+use crate::convert::diag::quick_fixes;
 
 mod analysis;
 mod convert;
@@ -83,6 +84,15 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let document_range_formatting_provider = DocumentRangeFormattingOptions {
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        };
+        let document_on_type_formatting_provider = DocumentOnTypeFormattingOptions {
+            first_trigger_character: "\n".to_string(),
+            more_trigger_character: None,
+        };
         let semantic_tokens_provider =
             SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
                 work_done_progress_options: WorkDoneProgressOptions {
@@ -103,13 +113,25 @@ impl RocServer {
                 work_done_progress: None,
             },
         };
+        let code_action_provider = CodeActionProviderCapability::Options(CodeActionOptions {
+            code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+            resolve_provider: None,
+        });
         ServerCapabilities {
             text_document_sync: Some(text_document_sync),
             hover_provider: Some(hover_provider),
             definition_provider: Some(OneOf::Right(definition_provider)),
             document_formatting_provider: Some(OneOf::Right(document_formatting_provider)),
+            document_range_formatting_provider: Some(OneOf::Right(
+                document_range_formatting_provider,
+            )),
+            document_on_type_formatting_provider: Some(document_on_type_formatting_provider),
             semantic_tokens_provider: Some(semantic_tokens_provider),
             completion_provider: Some(completion_provider),
+            code_action_provider: Some(code_action_provider),
             ..ServerCapabilities::default()
         }
     }
@@ -308,6 +330,47 @@ impl LanguageServer for RocServer {
         unwind_async(self.state.registry.formatting(&text_document.uri)).await
     }
 
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentRangeFormattingParams {
+            text_document,
+            range,
+            options: _,
+            work_done_progress_params: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .range_formatting(&text_document.uri, range),
+        )
+        .await
+    }
+
+    async fn on_type_formatting(
+        &self,
+        params: DocumentOnTypeFormattingParams,
+    ) -> Result<Option<Vec<TextEdit>>> {
+        let DocumentOnTypeFormattingParams {
+            text_document_position:
+                TextDocumentPositionParams {
+                    text_document,
+                    position,
+                },
+            ch: _,
+            options: _,
+        } = params;
+
+        unwind_async(
+            self.state
+                .registry
+                .on_type_formatting(&text_document.uri, position),
+        )
+        .await
+    }
+
     async fn semantic_tokens_full(
         &self,
         params: SemanticTokensParams,
@@ -332,6 +395,20 @@ impl LanguageServer for RocServer {
         )
         .await
     }
+
+    async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        let CodeActionParams {
+            text_document,
+            range: _,
+            context,
+            work_done_progress_params: _,
+            partial_result_params: _,
+        } = params;
+
+        let actions = quick_fixes(&text_document.uri, context.diagnostics);
+
+        Ok((!actions.is_empty()).then_some(actions))
+    }
 }
 
 async fn unwind_async<Fut, T>(future: Fut) -> tower_lsp::jsonrpc::Result<T>
@@ -612,4 +689,25 @@ mod tests {
         "#]]
         .assert_debug_eq(&actual);
     }
+
+    #[tokio::test]
+    async fn test_range_formatting() {
+        let doc = DOC_LIT.to_string()
+            + indoc! {r"
+            a =   1
+            b =   2
+            "};
+
+        let (inner, url) = test_setup(doc).await;
+        let range = Range::new(Position::new(4, 0), Position::new(4, 3));
+        let edits = inner.registry.range_formatting(&url, range).await;
+
+        assert_eq!(
+            edits,
+            Some(vec![TextEdit::new(
+                Range::new(Position::new(4, 0), Position::new(4, 7)),
+                "b = 2".to_string()
+            )])
+        );
+    }
 }
@@ -1,4 +1,3 @@
-use bincode::{deserialize_from, serialize_into};
 use iced_x86::{Decoder, DecoderOptions, Instruction, OpCodeOperandKind, OpKind};
 use memmap2::MmapMut;
 use object::{elf, endian};
@@ -12,15 +11,17 @@ use roc_error_macros::{internal_error, user_error};
 use serde::{Deserialize, Serialize};
 use std::{
     ffi::{c_char, CStr},
-    io::{BufReader, BufWriter},
     mem,
     path::Path,
     time::{Duration, Instant},
 };
 
+mod aarch64;
+
 use crate::{
     align_by_constraint, align_to_offset_by_constraint, load_struct_inplace,
-    load_struct_inplace_mut, load_structs_inplace_mut, open_mmap, open_mmap_mut,
+    load_struct_inplace_mut, load_structs_inplace, load_structs_inplace_mut, open_mmap,
+    open_mmap_mut, HostProblem, LinkType,
 };
 
 const MIN_SECTION_ALIGNMENT: usize = 0x40;
@@ -28,6 +29,63 @@ const MIN_SECTION_ALIGNMENT: usize = 0x40;
 // TODO: Analyze if this offset is always correct.
 const PLT_ADDRESS_OFFSET: u64 = 0x10;
 
+/// The instruction sets the ELF surgical linker understands.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum ElfMachine {
+    X86_64,
+    Aarch64,
+}
+
+impl ElfMachine {
+    fn from_header(data: &[u8]) -> Self {
+        let header = load_struct_inplace::<elf::FileHeader64<LE>>(data, 0);
+        match header.e_machine.get(LE) {
+            elf::EM_X86_64 => ElfMachine::X86_64,
+            elf::EM_AARCH64 => ElfMachine::Aarch64,
+            other => internal_error!("Surgical linking does not support ELF machine {other}"),
+        }
+    }
+
+    /// The offset from the start of the PLT to the entry of the `index`th JUMP_SLOT relocation.
+    fn plt_entry_offset(self, index: u64) -> u64 {
+        match self {
+            ElfMachine::X86_64 => (index + 1) * PLT_ADDRESS_OFFSET,
+            ElfMachine::Aarch64 => aarch64::PLT_HEADER_SIZE + index * aarch64::PLT_ENTRY_SIZE,
+        }
+    }
+
+    /// Preprocessing moves everything after the program headers by a multiple of this.
+    fn shift_alignment(self) -> u64 {
+        match self {
+            ElfMachine::X86_64 => MIN_SECTION_ALIGNMENT as u64,
+            // `adrp` + `add` address data by its 4KiB page and the offset within it,
+            // which only both stay correct when the data moves by whole pages.
+            ElfMachine::Aarch64 => aarch64::PAGE_SIZE,
+        }
+    }
+
+    fn r_relative(self) -> u32 {
+        match self {
+            ElfMachine::X86_64 => elf::R_X86_64_RELATIVE,
+            ElfMachine::Aarch64 => elf::R_AARCH64_RELATIVE,
+        }
+    }
+
+    fn r_glob_dat(self) -> u32 {
+        match self {
+            ElfMachine::X86_64 => elf::R_X86_64_GLOB_DAT,
+            ElfMachine::Aarch64 => elf::R_AARCH64_GLOB_DAT,
+        }
+    }
+
+    fn r_jump_slot(self) -> u32 {
+        match self {
+            ElfMachine::X86_64 => elf::R_X86_64_JUMP_SLOT,
+            ElfMachine::Aarch64 => elf::R_AARCH64_JUMP_SLOT,
+        }
+    }
+}
+
 struct ElfDynamicDeps {
     got_app_syms: Vec<(String, usize)>,
     got_sections: Vec<(usize, usize)>,
@@ -40,6 +98,8 @@ struct ElfDynamicDeps {
 enum VirtualOffset {
     Absolute,
     Relative(u64),
+    // An aarch64 `b` or `bl` at this address, whose immediate has to be re-encoded.
+    Aarch64Branch(u64),
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Eq, Debug)]
@@ -76,30 +136,11 @@ struct Metadata {
 
 impl Metadata {
     fn write_to_file(&self, metadata_filename: &Path) {
-        let metadata_file =
-            std::fs::File::create(metadata_filename).unwrap_or_else(|e| internal_error!("{}", e));
-
-        serialize_into(BufWriter::new(metadata_file), self)
-            .unwrap_or_else(|err| internal_error!("Failed to serialize metadata: {err}"));
+        crate::write_metadata(self, metadata_filename)
     }
 
     fn read_from_file(metadata_filename: &Path) -> Self {
-        let input = std::fs::File::open(metadata_filename).unwrap_or_else(|e| {
-            internal_error!(
-                r#"
-
-                Error:
-                    {}\n"#,
-                e
-            )
-        });
-
-        match deserialize_from(BufReader::new(input)) {
-            Ok(data) => data,
-            Err(err) => {
-                internal_error!("Failed to deserialize metadata: {}", err);
-            }
-        }
+        crate::read_metadata_for_surgery(metadata_filename)
     }
 }
 
@@ -162,13 +203,18 @@ fn collect_roc_definitions<'a>(object: &object::File<'a, &'a [u8]>) -> MutMap<St
 }
 
 struct Surgeries<'a> {
+    machine: ElfMachine,
     surgeries: MutMap<String, Vec<SurgeryEntry>>,
     app_func_addresses: MutMap<u64, &'a str>,
     indirect_warning_given: bool,
 }
 
 impl<'a> Surgeries<'a> {
-    fn new(application_symbols: &[Symbol], app_func_addresses: MutMap<u64, &'a str>) -> Self {
+    fn new(
+        machine: ElfMachine,
+        application_symbols: &[Symbol],
+        app_func_addresses: MutMap<u64, &'a str>,
+    ) -> Self {
         let mut surgeries = MutMap::default();
 
         // for each symbol that the host expects from the application
@@ -179,6 +225,7 @@ impl<'a> Surgeries<'a> {
         }
 
         Self {
+            machine,
             surgeries,
             app_func_addresses,
             indirect_warning_given: false,
@@ -211,12 +258,30 @@ impl<'a> Surgeries<'a> {
             println!("Analyzing instuctions for branches");
         }
 
+        // aarch64 keeps literal pools in its text sections.
+        // Mapping symbols tell them apart from code: `$d` starts data and `$x` starts code again.
+        let mut data_markers: Vec<(u64, bool)> = object
+            .symbols()
+            .filter_map(|sym| match sym.name() {
+                Ok(name) if name == "$d" || name.starts_with("$d.") => Some((sym.address(), true)),
+                Ok(name) if name == "$x" || name.starts_with("$x.") => Some((sym.address(), false)),
+                _ => None,
+            })
+            .collect();
+        data_markers.sort_unstable();
+
         for text_section in text_sections {
-            self.append_text_section(object_bytes, &text_section, verbose)
+            self.append_text_section(object_bytes, &text_section, &data_markers, verbose)
         }
     }
 
-    fn append_text_section(&mut self, object_bytes: &[u8], sec: &Section, verbose: bool) {
+    fn append_text_section(
+        &mut self,
+        object_bytes: &[u8],
+        sec: &Section,
+        data_markers: &[(u64, bool)],
+        verbose: bool,
+    ) {
         let (file_offset, compressed) = match sec.compressed_file_range() {
             Ok(CompressedFileRange {
                 format: CompressionFormat::None,
@@ -239,6 +304,42 @@ impl<'a> Surgeries<'a> {
                 internal_error!("Failed to load text section, {:+x?}: {}", sec, err);
             }
         };
+
+        if self.machine == ElfMachine::Aarch64 {
+            let branches = aarch64::find_branches(
+                &data,
+                sec.address(),
+                data_markers,
+                &self.app_func_addresses,
+            );
+
+            for (address, func_name) in branches {
+                if compressed {
+                    internal_error!(
+                        "Surgical linking does not work with compressed text sections: {:+x?}",
+                        sec
+                    );
+                }
+
+                let offset = address - sec.address() + file_offset;
+                if verbose {
+                    println!("Found branch from {address:+x} to {func_name}");
+                    println!("\tNeed to surgically replace 4 bytes at file offset {offset:+x}");
+                }
+
+                self.surgeries
+                    .get_mut(func_name)
+                    .unwrap()
+                    .push(SurgeryEntry {
+                        file_offset: offset,
+                        virtual_offset: VirtualOffset::Aarch64Branch(address),
+                        size: 4,
+                    });
+            }
+
+            return;
+        }
+
         let mut decoder = Decoder::with_ip(64, &data, sec.address(), DecoderOptions::NONE);
         let mut inst = Instruction::default();
 
@@ -333,6 +434,7 @@ impl<'a> Surgeries<'a> {
 
 /// Constructs a `Metadata` from a host executable binary, and writes it to disk
 pub(crate) fn preprocess_elf_le(
+    link_type: LinkType,
     host_exe_path: &Path,
     metadata_path: &Path,
     preprocessed_path: &Path,
@@ -350,6 +452,9 @@ pub(crate) fn preprocess_elf_le(
         }
     };
 
+    check_host_kind(exec_data, link_type);
+    let machine = ElfMachine::from_header(exec_data);
+
     let mut md = Metadata {
         roc_symbol_vaddresses: collect_roc_definitions(&exec_obj),
         ..Default::default()
@@ -426,7 +531,7 @@ pub(crate) fn preprocess_elf_le(
                 }
             })
             .filter_map(|(_, reloc)| {
-                if let RelocationKind::Elf(7) = reloc.kind() {
+                if reloc.kind() == RelocationKind::Elf(machine.r_jump_slot()) {
                     Some(reloc)
                 } else {
                     None
@@ -435,8 +540,8 @@ pub(crate) fn preprocess_elf_le(
     for (i, reloc) in plt_relocs.enumerate() {
         for symbol in app_syms.iter() {
             if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
-                let func_address = (i as u64 + 1) * PLT_ADDRESS_OFFSET + plt_address;
-                let func_offset = (i as u64 + 1) * PLT_ADDRESS_OFFSET + plt_offset;
+                let func_address = machine.plt_entry_offset(i as u64) + plt_address;
+                let func_offset = machine.plt_entry_offset(i as u64) + plt_offset;
                 app_func_addresses.insert(func_address, symbol.name().unwrap());
                 md.plt_addresses.insert(
                     symbol.name().unwrap().to_string(),
@@ -472,7 +577,7 @@ pub(crate) fn preprocess_elf_le(
     // look at the text (i.e. code) sections and see collect work needs to be done
     let text_disassembly_start = Instant::now();
 
-    let mut surgeries = Surgeries::new(&app_syms, app_func_addresses);
+    let mut surgeries = Surgeries::new(machine, &app_syms, app_func_addresses);
     surgeries.append_text_sections(exec_data, &exec_obj, verbose);
     md.surgeries = surgeries.surgeries;
 
@@ -487,7 +592,7 @@ pub(crate) fn preprocess_elf_le(
         dynamic_lib_count,
         shared_lib_index,
     } = scan_elf_dynamic_deps(
-        &exec_obj, &mut md, &app_syms, shared_lib, exec_data, verbose,
+        &exec_obj, machine, &mut md, &app_syms, shared_lib, exec_data, verbose,
     );
 
     let scanning_dynamic_deps_duration = scanning_dynamic_deps_start.elapsed();
@@ -495,6 +600,7 @@ pub(crate) fn preprocess_elf_le(
     let platform_gen_start = Instant::now();
 
     let out_mmap = gen_elf_le(
+        machine,
         exec_data,
         &mut md,
         preprocessed_path,
@@ -555,8 +661,108 @@ pub(crate) fn preprocess_elf_le(
     }
 }
 
+/// Whether the host was linked with `-shared`. PIE executables are `ET_DYN` too, but unlike
+/// shared libraries they ask for a program interpreter.
+fn is_shared_library(exec_data: &[u8]) -> bool {
+    let exec_header = load_struct_inplace::<elf::FileHeader64<LE>>(exec_data, 0);
+    let program_headers = load_structs_inplace::<elf::ProgramHeader64<LE>>(
+        exec_data,
+        exec_header.e_phoff.get(LE) as usize,
+        exec_header.e_phnum.get(LE) as usize,
+    );
+
+    exec_header.e_type.get(LE) == elf::ET_DYN
+        && !program_headers
+            .iter()
+            .any(|ph| ph.p_type.get(LE) == elf::PT_INTERP)
+}
+
+fn check_host_kind(exec_data: &[u8], link_type: LinkType) {
+    match link_type {
+        LinkType::Dylib if !is_shared_library(exec_data) => {
+            user_error!("Building a shared library needs a host that is a shared library too.\nProbably the platform built its host as an executable, try building it with `-shared`.");
+        }
+        LinkType::None => {
+            internal_error!("There is nothing to preprocess when not linking");
+        }
+        _ => {}
+    }
+}
+
+/// Finds what would go wrong when an app whose stub library exports `stub_dll_symbols` is
+/// surgically linked into the preprocessed host `preprocessed_data`.
+pub(crate) fn verify_elf(
+    link_type: LinkType,
+    metadata_path: &Path,
+    preprocessed_data: &[u8],
+    stub_dll_symbols: &[String],
+) -> Vec<HostProblem> {
+    let md: Metadata = match crate::read_metadata(metadata_path) {
+        Ok(md) => md,
+        Err(problem) => return vec![problem],
+    };
+
+    if md.exec_len != preprocessed_data.len() as u64 {
+        return vec![HostProblem::StaleMetadata {
+            expected_len: md.exec_len,
+            actual_len: preprocessed_data.len() as u64,
+        }];
+    }
+
+    let mut problems = Vec::new();
+
+    if link_type == LinkType::Dylib && !is_shared_library(preprocessed_data) {
+        problems.push(HostProblem::NotASharedLibrary);
+    }
+
+    problems.extend(crate::verify::symbol_problems(
+        &md.app_functions,
+        stub_dll_symbols,
+    ));
+
+    // Preprocessing turns the relocations of app functions it understands into surgeries,
+    // anything that still refers to one is left for the dynamic linker, which can't resolve it.
+    let exec_obj = object::File::parse(preprocessed_data).unwrap();
+    let app_syms: MutMap<SymbolIndex, &str> = exec_obj
+        .dynamic_symbols()
+        .filter(is_roc_undefined)
+        .map(|sym| (sym.index(), sym.name().unwrap()))
+        .collect();
+    for (_, reloc) in exec_obj.dynamic_relocations().into_iter().flatten() {
+        match (reloc.target(), reloc.kind()) {
+            // R_AARCH64_NONE is 0 as well
+            (_, RelocationKind::Elf(elf::R_X86_64_NONE)) => {}
+            (RelocationTarget::Symbol(index), kind) => {
+                if let Some(name) = app_syms.get(&index) {
+                    problems.push(HostProblem::UnsupportedRelocation {
+                        symbol: name.to_string(),
+                        kind: format!("{kind:?}"),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut surgeries: Vec<_> = md.surgeries.iter().collect();
+    surgeries.sort_by_key(|(name, _)| *name);
+    for (name, entries) in surgeries {
+        for entry in entries {
+            if !matches!(entry.size, 4 | 8) {
+                problems.push(HostProblem::UnsupportedSurgery {
+                    symbol: name.clone(),
+                    size: entry.size,
+                });
+            }
+        }
+    }
+
+    problems
+}
+
 #[allow(clippy::too_many_arguments)]
 fn gen_elf_le(
+    machine: ElfMachine,
     exec_data: &[u8],
     md: &mut Metadata,
     preprocessed_path: &Path,
@@ -587,9 +793,10 @@ fn gen_elf_le(
 
     // Copy header and shift everything to enable more program sections.
     let added_header_count = 3;
+    let shift_alignment = machine.shift_alignment();
     md.added_byte_count = ph_ent_size as u64 * added_header_count;
-    md.added_byte_count = md.added_byte_count
-        + (MIN_SECTION_ALIGNMENT as u64 - md.added_byte_count % MIN_SECTION_ALIGNMENT as u64);
+    md.added_byte_count =
+        md.added_byte_count + (shift_alignment - md.added_byte_count % shift_alignment);
     let ph_end = ph_offset as usize + ph_num as usize * ph_ent_size as usize;
     let physical_shift_start = ph_end as u64;
 
@@ -727,18 +934,18 @@ fn gen_elf_le(
                 rel.r_offset.set(LE, r_offset + md.added_byte_count);
                 // Deal with potential adjusts to absolute jumps.
                 // TODO: Verify other relocation types.
-                if rel.r_type(LE, false) == elf::R_X86_64_RELATIVE {
+                if rel.r_type(LE, false) == machine.r_relative() {
                     let r_addend = rel.r_addend.get(LE);
                     rel.r_addend.set(LE, r_addend + md.added_byte_count as i64);
                 }
             }
             // If the relocation goes to a roc function, we need to surgically link it and change it to relative.
             let r_type = rel.r_type(LE, false);
-            if r_type == elf::R_X86_64_GLOB_DAT {
+            if r_type == machine.r_glob_dat() {
                 let r_sym = rel.r_sym(LE, false);
                 for (name, index) in got_app_syms.iter() {
                     if *index as u32 == r_sym {
-                        rel.set_r_info(LE, false, 0, elf::R_X86_64_RELATIVE);
+                        rel.set_r_info(LE, false, 0, machine.r_relative());
                         let addend_addr = sec_offset as usize
                             + i * mem::size_of::<elf::Rela64<LE>>()
                             // This 16 skips the first 2 fields and gets to the addend field.
@@ -763,8 +970,7 @@ fn gen_elf_le(
             .filter_map(|(i, rel)| {
                 let r_type = rel.r_type(LE, false);
                 let r_sym = rel.r_sym(LE, false);
-                if r_type == elf::R_X86_64_JUMP_SLOT && app_sym_indices.contains(&(r_sym as usize))
-                {
+                if r_type == machine.r_jump_slot() && app_sym_indices.contains(&(r_sym as usize)) {
                     Some(i)
                 } else {
                     None
@@ -780,6 +986,7 @@ fn gen_elf_le(
         for i in to_remove.iter() {
             relocations.swap(*i, j);
             let r_sym = relocations[j].r_sym(LE, false);
+            // R_AARCH64_NONE is 0 as well
             relocations[j].set_r_info(LE, false, r_sym, elf::R_X86_64_NONE);
             j -= 1;
         }
@@ -945,6 +1152,7 @@ fn gen_elf_le(
 
 fn scan_elf_dynamic_deps(
     exec_obj: &object::File,
+    machine: ElfMachine,
     md: &mut Metadata,
     app_syms: &[Symbol],
     shared_lib: &Path,
@@ -1088,7 +1296,7 @@ fn scan_elf_dynamic_deps(
         }
     })
     .filter_map(|(_, reloc)| {
-        if let RelocationKind::Elf(elf::R_X86_64_GLOB_DAT) = reloc.kind() {
+        if reloc.kind() == RelocationKind::Elf(machine.r_glob_dat()) {
             for symbol in app_syms.iter() {
                 if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
                     return Some((symbol.name().unwrap().to_string(), symbol.index().0));
@@ -1107,7 +1315,7 @@ fn scan_elf_dynamic_deps(
         }
     })
     .filter_map(|(_, reloc)| {
-        if let RelocationKind::Elf(elf::R_X86_64_JUMP_SLOT) = reloc.kind() {
+        if reloc.kind() == RelocationKind::Elf(machine.r_jump_slot()) {
             for symbol in app_syms.iter() {
                 if reloc.target() == RelocationTarget::Symbol(symbol.index()) {
                     return Some(symbol.index().0);
@@ -1227,6 +1435,7 @@ fn surgery_elf_help(
     if !elf64 || !litte_endian {
         internal_error!("Only 64bit little endian elf currently supported for surgery");
     }
+    let machine = ElfMachine::from_header(exec_mmap);
     let exec_header = load_struct_inplace::<elf::FileHeader64<LE>>(exec_mmap, 0);
 
     let ph_offset = exec_header.e_phoff.get(LE);
@@ -1238,6 +1447,7 @@ fn surgery_elf_help(
 
     if verbose {
         println!();
+        println!("Machine: {machine:?}");
         println!("Is Elf64: {elf64}");
         println!("Is Little Endian: {litte_endian}");
         println!("PH Offset: {ph_offset:+x}");
@@ -1337,6 +1547,37 @@ fn surgery_elf_help(
             virt_offset += sec.size() as usize;
         }
     }
+
+    // An aarch64 branch from the app to the host can be out of range, then it goes through a veneer.
+    // Reserve room for one behind the app's code for every host function it branches to.
+    let veneer_count = match machine {
+        ElfMachine::X86_64 => 0,
+        ElfMachine::Aarch64 => text_sections
+            .iter()
+            .flat_map(|sec| sec.relocations())
+            .filter_map(|(_, rel)| match rel.target() {
+                RelocationTarget::Symbol(index)
+                    if aarch64::is_branch26(aarch64::relocation_type(&rel))
+                        && app_obj
+                            .symbol_by_index(index)
+                            .map_or(false, |sym| sym.is_undefined()) =>
+                {
+                    Some((index, rel.addend()))
+                }
+                _ => None,
+            })
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+    };
+    if veneer_count > 0 {
+        offset = align_by_constraint(offset, aarch64::VENEER_SIZE);
+        virt_offset =
+            align_to_offset_by_constraint(virt_offset, offset, md.load_align_constraint as usize);
+    }
+    let (veneers_offset, veneers_vaddr) = (offset, virt_offset);
+    offset += veneer_count * aarch64::VENEER_SIZE;
+    let mut veneer_vaddrs: MutMap<u64, u64> = MutMap::default();
+
     if verbose {
         println!("Data Relocation Offsets: {symbol_vaddr_map:+x?}");
         println!("Found App Function Symbols: {app_func_vaddr_map:+x?}");
@@ -1440,6 +1681,65 @@ fn surgery_elf_help(
                     if let Some(target_offset) = target_offset {
                         let virt_base = section_virtual_offset + rel.0 as usize;
                         let base = section_offset + rel.0 as usize;
+                        if machine == ElfMachine::Aarch64 {
+                            let r_type = aarch64::relocation_type(&rel.1);
+                            let target = (target_offset + rel.1.addend()) as u64;
+                            if verbose {
+                                println!(
+                                    "\t\tRelocation base location: {base:+x} (virt: {virt_base:+x})",
+                                );
+                                println!("\t\tFinal relocation target: {target:+x}");
+                            }
+
+                            let location = &mut exec_mmap[base..];
+                            if aarch64::apply_relocation(location, r_type, virt_base as u64, target)
+                                .is_some()
+                            {
+                                continue;
+                            }
+
+                            if !aarch64::is_branch26(r_type) {
+                                internal_error!("Relocation target out of range: {:+x?}", rel);
+                            }
+
+                            let next_veneer = veneer_vaddrs.len();
+                            let veneer_vaddr = *veneer_vaddrs.entry(target).or_insert_with(|| {
+                                if next_veneer == veneer_count {
+                                    internal_error!("No veneer reserved for: {:+x?}", rel);
+                                }
+
+                                let veneer_offset =
+                                    veneers_offset + next_veneer * aarch64::VENEER_SIZE;
+                                let veneer_vaddr =
+                                    (veneers_vaddr + next_veneer * aarch64::VENEER_SIZE) as u64;
+                                let veneer =
+                                    aarch64::veneer(veneer_vaddr, target).unwrap_or_else(|| {
+                                        internal_error!("Veneer target out of range: {target:+x}")
+                                    });
+                                exec_mmap[veneer_offset..][..aarch64::VENEER_SIZE]
+                                    .copy_from_slice(&veneer);
+
+                                veneer_vaddr
+                            });
+                            if verbose {
+                                println!("\t\tBranching through veneer at: {veneer_vaddr:+x}");
+                            }
+
+                            let location = &mut exec_mmap[base..];
+                            if aarch64::apply_relocation(
+                                location,
+                                r_type,
+                                virt_base as u64,
+                                veneer_vaddr,
+                            )
+                            .is_none()
+                            {
+                                internal_error!("Veneer out of range of: {:+x?}", rel);
+                            }
+
+                            continue;
+                        }
+
                         let target: i64 = match rel.1.kind() {
                             RelocationKind::Relative | RelocationKind::PltRelative => {
                                 target_offset - virt_base as i64 + rel.1.addend()
@@ -1638,6 +1938,20 @@ fn surgery_elf_help(
             let surgery_virt_offset = match s.virtual_offset {
                 VirtualOffset::Relative(vs) => (vs + md.added_byte_count) as i64,
                 VirtualOffset::Absolute => 0,
+                VirtualOffset::Aarch64Branch(vs) => {
+                    let displacement = func_virt_offset as i64 - (vs + md.added_byte_count) as i64;
+                    let location = &mut exec_mmap[(s.file_offset + md.added_byte_count) as usize..];
+                    match aarch64::encode_branch26(aarch64::read_inst(location), displacement) {
+                        Some(inst) => aarch64::write_inst(location, inst),
+                        None => {
+                            // Out of range, so keep branching to the plt, which becomes a veneer.
+                            if verbose {
+                                println!("\tOut of range, keeping the branch to the plt");
+                            }
+                        }
+                    }
+                    continue;
+                }
             };
             match s.size {
                 4 => {
@@ -1669,18 +1983,28 @@ fn surgery_elf_help(
         if let Some((plt_off, plt_vaddr)) = md.plt_addresses.get(func_name) {
             let plt_off = (*plt_off + md.added_byte_count) as usize;
             let plt_vaddr = *plt_vaddr + md.added_byte_count;
-            let jmp_inst_len = 5;
-            let target =
-                (func_virt_offset as i64 - (plt_vaddr as i64 + jmp_inst_len as i64)) as i32;
-            if verbose {
-                println!("\tPLT: {plt_off:+x}, {plt_vaddr:+x}");
-                println!("\tTarget Jump: {target:+x}");
-            }
-            let data = target.to_le_bytes();
-            exec_mmap[plt_off] = 0xE9;
-            exec_mmap[plt_off + 1..plt_off + jmp_inst_len].copy_from_slice(&data);
-            for i in jmp_inst_len..PLT_ADDRESS_OFFSET as usize {
-                exec_mmap[plt_off + i] = 0x90;
+            if machine == ElfMachine::Aarch64 {
+                let veneer = aarch64::veneer(plt_vaddr, func_virt_offset).unwrap_or_else(|| {
+                    internal_error!("{func_name} is out of range of its plt entry")
+                });
+                if verbose {
+                    println!("\tPLT: {plt_off:+x}, {plt_vaddr:+x}");
+                }
+                exec_mmap[plt_off..][..aarch64::VENEER_SIZE].copy_from_slice(&veneer);
+            } else {
+                let jmp_inst_len = 5;
+                let target =
+                    (func_virt_offset as i64 - (plt_vaddr as i64 + jmp_inst_len as i64)) as i32;
+                if verbose {
+                    println!("\tPLT: {plt_off:+x}, {plt_vaddr:+x}");
+                    println!("\tTarget Jump: {target:+x}");
+                }
+                let data = target.to_le_bytes();
+                exec_mmap[plt_off] = 0xE9;
+                exec_mmap[plt_off + 1..plt_off + jmp_inst_len].copy_from_slice(&data);
+                for i in jmp_inst_len..PLT_ADDRESS_OFFSET as usize {
+                    exec_mmap[plt_off + i] = 0x90;
+                }
             }
         }
 
@@ -1726,8 +2050,9 @@ fn surgery_elf_help(
 mod tests {
     use super::*;
 
-    use crate::preprocessed_host_filename;
+    use crate::{preprocessed_dylib_host_filename, preprocessed_host_filename};
     use indoc::indoc;
+    use object::ObjectSegment;
     use roc_target::Target;
 
     const ELF64_DYNHOST: &[u8] = include_bytes!("../dynhost_benchmarks_elf64") as &[_];
@@ -1781,20 +2106,45 @@ mod tests {
         )
     }
 
+    #[test]
+    fn executable_is_not_a_shared_library() {
+        assert!(!is_shared_library(ELF64_DYNHOST));
+    }
+
+    /// Tests build for the machine they run on, except when they are about another architecture.
     #[allow(dead_code)]
-    fn zig_host_app_help(dir: &Path, target: Target) {
-        let host_zig = indoc!(
-            r#"
-            const std = @import("std");
+    fn zig_target_args(target: Target) -> &'static [&'static str] {
+        match target {
+            Target::LinuxArm64 => &["-target", "aarch64-linux-gnu"],
+            _ => &[],
+        }
+    }
 
-            extern fn roc_magic1(usize) callconv(.C) [*]const u8;
+    #[allow(dead_code)]
+    fn zig_host_app_help(dir: &Path, target: Target, link_type: LinkType) {
+        let host_zig = match link_type {
+            LinkType::Dylib => indoc!(
+                r#"
+                extern fn roc_magic1(usize) callconv(.C) [*]const u8;
 
-            pub fn main() !void {
-                const stdout = std.io.getStdOut().writer();
-                try stdout.print("Hello {s}\n", .{roc_magic1(0)[0..3]});
-            }
-            "#
-        );
+                export fn host_magic() [*]const u8 {
+                    return roc_magic1(0);
+                }
+                "#
+            ),
+            _ => indoc!(
+                r#"
+                const std = @import("std");
+
+                extern fn roc_magic1(usize) callconv(.C) [*]const u8;
+
+                pub fn main() !void {
+                    const stdout = std.io.getStdOut().writer();
+                    try stdout.print("Hello {s}\n", .{roc_magic1(0)[0..3]});
+                }
+                "#
+            ),
+        };
 
         let app_zig = indoc!(
             r#"
@@ -1815,6 +2165,7 @@ mod tests {
         let output = std::process::Command::new(&zig)
             .current_dir(dir)
             .args(["build-obj", "app.zig", "-fPIC", "-OReleaseFast"])
+            .args(zig_target_args(target))
             .output()
             .unwrap();
 
@@ -1841,20 +2192,28 @@ mod tests {
                 .collect()
         };
 
-        let dylib_bytes = crate::generate_dylib::create_dylib_elf64(&names).unwrap();
+        let dylib_bytes = crate::generate_dylib::create_dylib_elf64(&names, target).unwrap();
         std::fs::write(dir.join("libapp.so"), dylib_bytes).unwrap();
 
         // now we can compile the host (it uses libapp.so, hence the order here)
+        let (build_args, host_file_name, preprocessed_host_filename) = match link_type {
+            LinkType::Dylib => (
+                &["build-lib", "-dynamic", "-fPIC"][..],
+                "libhost.so",
+                preprocessed_dylib_host_filename(target),
+            ),
+            _ => (
+                &["build-exe", "-fPIE"][..],
+                "host",
+                preprocessed_host_filename(target),
+            ),
+        };
+
         let output = std::process::Command::new(&zig)
             .current_dir(dir)
-            .args([
-                "build-exe",
-                "libapp.so",
-                "host.zig",
-                "-fPIE",
-                "-lc",
-                "-OReleaseFast",
-            ])
+            .args(build_args)
+            .args(["libapp.so", "host.zig", "-lc", "-OReleaseFast"])
+            .args(zig_target_args(target))
             .output()
             .unwrap();
 
@@ -1864,13 +2223,14 @@ mod tests {
             std::io::stdout().write_all(&output.stdout).unwrap();
             std::io::stderr().write_all(&output.stderr).unwrap();
 
-            panic!("zig build-exe failed");
+            panic!("zig {} failed", build_args[0]);
         }
 
-        let preprocessed_host_filename = dir.join(preprocessed_host_filename(target));
+        let preprocessed_host_filename = dir.join(preprocessed_host_filename);
 
         preprocess_elf_le(
-            &dir.join("host"),
+            link_type,
+            &dir.join(host_file_name),
             &dir.join("metadata"),
             &preprocessed_host_filename,
             &dir.join("libapp.so"),
@@ -1895,7 +2255,7 @@ mod tests {
         let dir = tempfile::tempdir().unwrap();
         let dir = dir.path();
 
-        zig_host_app_help(dir, Target::LinuxX64);
+        zig_host_app_help(dir, Target::LinuxX64, LinkType::Executable);
 
         let output = std::process::Command::new(dir.join("final"))
             .current_dir(dir)
@@ -1915,4 +2275,159 @@ mod tests {
 
         assert_eq!("Hello foo\n", output);
     }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn zig_host_dylib() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        zig_host_app_help(dir, Target::LinuxX64, LinkType::Dylib);
+
+        // the linked library takes the place of the host, which has the soname we link against
+        std::fs::copy(dir.join("final"), dir.join("libhost.so")).unwrap();
+
+        let main_zig = indoc!(
+            r#"
+            const std = @import("std");
+
+            extern fn host_magic() [*]const u8;
+
+            pub fn main() !void {
+                const stdout = std.io.getStdOut().writer();
+                try stdout.print("Hello {s}\n", .{host_magic()[0..3]});
+            }
+            "#
+        );
+
+        std::fs::write(dir.join("main.zig"), main_zig.as_bytes()).unwrap();
+
+        let zig = std::env::var("ROC_ZIG").unwrap_or_else(|_| "zig".into());
+        let output = std::process::Command::new(zig)
+            .current_dir(dir)
+            .args(["build-exe", "main.zig", "libhost.so", "-lc"])
+            .output()
+            .unwrap();
+
+        if !output.status.success() {
+            use std::io::Write;
+
+            std::io::stdout().write_all(&output.stdout).unwrap();
+            std::io::stderr().write_all(&output.stderr).unwrap();
+
+            panic!("zig build-exe failed");
+        }
+
+        let output = std::process::Command::new(dir.join("main"))
+            .current_dir(dir)
+            .env("LD_LIBRARY_PATH", dir)
+            .output()
+            .unwrap();
+
+        if !output.status.success() {
+            use std::io::Write;
+
+            std::io::stdout().write_all(&output.stdout).unwrap();
+            std::io::stderr().write_all(&output.stderr).unwrap();
+
+            panic!("main failed");
+        }
+
+        let output = String::from_utf8_lossy(&output.stdout);
+
+        assert_eq!("Hello foo\n", output);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn zig_host_app_aarch64() {
+        let dir = tempfile::tempdir().unwrap();
+        let dir = dir.path();
+
+        zig_host_app_help(dir, Target::LinuxArm64, LinkType::Executable);
+
+        let data = std::fs::read(dir.join("final")).unwrap();
+        let object = object::File::parse(data.as_slice()).unwrap();
+
+        assert_eq!(object.architecture(), object::Architecture::Aarch64);
+
+        // the app function is now defined, in a new executable segment
+        let magic = object
+            .dynamic_symbols()
+            .find(|sym| sym.name() == Ok("roc_magic1"))
+            .unwrap();
+        assert!(!magic.is_undefined());
+
+        let magic_address = magic.address();
+        let segment = object
+            .segments()
+            .find(|seg| (seg.address()..seg.address() + seg.size()).contains(&magic_address))
+            .unwrap();
+        assert!(matches!(
+            segment.flags(),
+            object::SegmentFlags::Elf { p_flags } if p_flags & elf::PF_X != 0
+        ));
+
+        // the host no longer depends on libapp.so
+        let dynamic = object.section_by_name(".dynamic").unwrap();
+        let dynstr = object.section_by_name(".dynstr").unwrap();
+        let dynstr = dynstr.data().unwrap();
+        let dynamic_data = dynamic.data().unwrap();
+        let needed: Vec<_> = load_structs_inplace::<elf::Dyn64<LE>>(
+            dynamic_data,
+            0,
+            dynamic_data.len() / mem::size_of::<elf::Dyn64<LE>>(),
+        )
+        .iter()
+        .take_while(|d| d.d_tag.get(LE) != elf::DT_NULL as u64)
+        .filter(|d| d.d_tag.get(LE) == elf::DT_NEEDED as u64)
+        .map(|d| {
+            let name = &dynstr[d.d_val.get(LE) as usize..];
+            let end = name.iter().position(|b| *b == 0).unwrap();
+            String::from_utf8_lossy(&name[..end]).to_string()
+        })
+        .collect();
+        assert!(!needed.iter().any(|name| name == "libapp.so"), "{needed:?}");
+
+        // and the host branches straight to the app function
+        let mut targets = MutMap::default();
+        targets.insert(magic_address, "roc_magic1");
+        let branches: Vec<_> = object
+            .sections()
+            .filter(|sec| sec.kind() == SectionKind::Text && sec.address() < magic_address)
+            .flat_map(|sec| {
+                aarch64::find_branches(sec.data().unwrap(), sec.address(), &[], &targets)
+            })
+            .collect();
+        assert!(!branches.is_empty());
+
+        // run it when an emulator is around
+        let qemu_available = std::process::Command::new("qemu-aarch64")
+            .arg("--version")
+            .output()
+            .is_ok();
+        if !qemu_available {
+            return;
+        }
+
+        let ld_prefix =
+            std::env::var("QEMU_LD_PREFIX").unwrap_or_else(|_| "/usr/aarch64-linux-gnu".into());
+        let output = std::process::Command::new("qemu-aarch64")
+            .current_dir(dir)
+            .env("QEMU_LD_PREFIX", ld_prefix)
+            .arg(dir.join("final"))
+            .output()
+            .unwrap();
+
+        if !output.status.success() {
+            use std::io::Write;
+
+            std::io::stdout().write_all(&output.stdout).unwrap();
+            std::io::stderr().write_all(&output.stderr).unwrap();
+
+            panic!("final failed under qemu-aarch64");
+        }
+
+        assert_eq!("Hello foo\n", String::from_utf8_lossy(&output.stdout));
+    }
 }
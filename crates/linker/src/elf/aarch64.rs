@@ -0,0 +1,279 @@
+//! The AArch64 parts of the ELF surgical linker.
+//!
+//! Unlike x86_64, AArch64 instructions hold their immediates in bit fields, so every relocation
+//! and surgery has to re-encode the instruction it patches rather than overwrite a plain integer.
+use object::elf;
+use object::{Relocation, RelocationEncoding, RelocationKind};
+use roc_collections::all::MutMap;
+use roc_error_macros::internal_error;
+
+/// The PLT starts with a header (PLT0) that does lazy binding, followed by an entry per symbol.
+pub(super) const PLT_HEADER_SIZE: u64 = 0x20;
+pub(super) const PLT_ENTRY_SIZE: u64 = 0x10;
+
+/// `adrp` always works in 4KiB pages, independent of the page size of the system.
+pub(super) const PAGE_SIZE: u64 = 0x1000;
+
+/// A veneer is exactly as large as a PLT entry, so PLT entries can be turned into veneers.
+pub(super) const VENEER_SIZE: usize = PLT_ENTRY_SIZE as usize;
+
+/// `b` and `bl` reach +-128MiB.
+const BRANCH26_RANGE: i64 = 1 << 27;
+
+const ADRP_X16: u32 = 0x9000_0010;
+const ADD_X16_X16: u32 = 0x9100_0210;
+const BR_X16: u32 = 0xD61F_0200;
+const NOP: u32 = 0xD503_201F;
+
+pub(super) fn read_inst(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes[..4].try_into().unwrap())
+}
+
+pub(super) fn write_inst(bytes: &mut [u8], inst: u32) {
+    bytes[..4].copy_from_slice(&inst.to_le_bytes());
+}
+
+fn page(address: u64) -> u64 {
+    address & !(PAGE_SIZE - 1)
+}
+
+/// The target of the `b` or `bl` instruction `inst` at `address`, if it is one.
+pub(super) fn branch26_target(inst: u32, address: u64) -> Option<u64> {
+    if inst & 0x7C00_0000 != 0x1400_0000 {
+        return None;
+    }
+
+    // sign extend the 26 bit immediate, and scale it by the instruction size
+    let offset = ((inst as i64) << 38) >> 36;
+
+    Some(address.wrapping_add(offset as u64))
+}
+
+/// Re-targets the `b` or `bl` instruction `inst` by `displacement` bytes.
+/// Returns `None` when the displacement is out of range.
+pub(super) fn encode_branch26(inst: u32, displacement: i64) -> Option<u32> {
+    if displacement % 4 != 0 || !(-BRANCH26_RANGE..BRANCH26_RANGE).contains(&displacement) {
+        return None;
+    }
+
+    Some((inst & 0xFC00_0000) | ((displacement >> 2) as u32 & 0x03FF_FFFF))
+}
+
+/// Points the `adrp` instruction `inst` at `pc` to the page of `target`.
+fn encode_adrp(inst: u32, pc: u64, target: u64) -> Option<u32> {
+    let pages = (page(target) as i64).wrapping_sub(page(pc) as i64) >> 12;
+    if !(-(1 << 20)..(1 << 20)).contains(&pages) {
+        return None;
+    }
+
+    let imm = pages as u32 & 0x1F_FFFF;
+    let (immlo, immhi) = (imm & 0b11, imm >> 2);
+
+    Some((inst & 0x9F00_001F) | (immlo << 29) | (immhi << 5))
+}
+
+/// Sets the unsigned 12 bit immediate of an `add` or a load/store to the low bits of `target`.
+/// Loads and stores scale their immediate by the access size, which is `1 << shift` bytes.
+fn encode_lo12(inst: u32, target: u64, shift: u32) -> Option<u32> {
+    let lo12 = target & 0xFFF;
+    if lo12 & ((1 << shift) - 1) != 0 {
+        return None;
+    }
+
+    Some((inst & !(0xFFF << 10)) | (((lo12 >> shift) as u32) << 10))
+}
+
+/// There is no GOT in the final binary, so the `ldr xt, [xn, :got_lo12:sym]` that loads the address
+/// of `sym` from its GOT slot becomes `add xt, xn, :lo12:sym`, which computes it directly.
+fn relax_got_load(inst: u32, target: u64) -> Option<u32> {
+    if inst & 0xFFC0_0000 != 0xF940_0000 {
+        return None;
+    }
+
+    let registers = inst & 0x3FF;
+
+    encode_lo12(0x9100_0000 | registers, target, 0)
+}
+
+/// `adrp x16, target; add x16, x16, :lo12:target; br x16`, padded with a `nop`.
+/// x16 is the intra-procedure-call scratch register, which the ABI lets veneers clobber.
+pub(super) fn veneer(address: u64, target: u64) -> Option<[u8; VENEER_SIZE]> {
+    let adrp = encode_adrp(ADRP_X16, address, target)?;
+    let add = encode_lo12(ADD_X16_X16, target, 0)?;
+
+    let mut bytes = [0; VENEER_SIZE];
+    for (chunk, inst) in bytes.chunks_exact_mut(4).zip([adrp, add, BR_X16, NOP]) {
+        write_inst(chunk, inst);
+    }
+
+    Some(bytes)
+}
+
+/// The ELF relocation type behind an `object` relocation of an AArch64 object file.
+pub(super) fn relocation_type(rel: &Relocation) -> u32 {
+    match (rel.kind(), rel.encoding(), rel.size()) {
+        (RelocationKind::PltRelative, RelocationEncoding::AArch64Call, 26) => elf::R_AARCH64_CALL26,
+        (RelocationKind::Relative, _, 32) => elf::R_AARCH64_PREL32,
+        (RelocationKind::Relative, _, 64) => elf::R_AARCH64_PREL64,
+        (RelocationKind::Elf(r_type), _, _) => r_type,
+        _ => internal_error!("Relocation Kind not yet support: {:?}", rel),
+    }
+}
+
+pub(super) fn is_branch26(r_type: u32) -> bool {
+    matches!(r_type, elf::R_AARCH64_CALL26 | elf::R_AARCH64_JUMP26)
+}
+
+/// Resolves the relocation of type `r_type` at virtual address `pc` to `target`, which already
+/// includes the addend. Returns `None` when the target is out of range of the instruction.
+pub(super) fn apply_relocation(bytes: &mut [u8], r_type: u32, pc: u64, target: u64) -> Option<()> {
+    let inst = read_inst(bytes);
+    let displacement = (target as i64).wrapping_sub(pc as i64);
+
+    let patched = match r_type {
+        elf::R_AARCH64_CALL26 | elf::R_AARCH64_JUMP26 => encode_branch26(inst, displacement)?,
+        elf::R_AARCH64_ADR_PREL_PG_HI21
+        | elf::R_AARCH64_ADR_PREL_PG_HI21_NC
+        | elf::R_AARCH64_ADR_GOT_PAGE => encode_adrp(inst, pc, target)?,
+        elf::R_AARCH64_ADD_ABS_LO12_NC | elf::R_AARCH64_LDST8_ABS_LO12_NC => {
+            encode_lo12(inst, target, 0)?
+        }
+        elf::R_AARCH64_LDST16_ABS_LO12_NC => encode_lo12(inst, target, 1)?,
+        elf::R_AARCH64_LDST32_ABS_LO12_NC => encode_lo12(inst, target, 2)?,
+        elf::R_AARCH64_LDST64_ABS_LO12_NC => encode_lo12(inst, target, 3)?,
+        elf::R_AARCH64_LDST128_ABS_LO12_NC => encode_lo12(inst, target, 4)?,
+        elf::R_AARCH64_LD64_GOT_LO12_NC => relax_got_load(inst, target)?,
+        elf::R_AARCH64_PREL32 => {
+            let data = i32::try_from(displacement).ok()?;
+            bytes[..4].copy_from_slice(&data.to_le_bytes());
+            return Some(());
+        }
+        elf::R_AARCH64_PREL64 => {
+            bytes[..8].copy_from_slice(&displacement.to_le_bytes());
+            return Some(());
+        }
+        other => internal_error!("AArch64 relocation type not yet supported: {other}"),
+    };
+
+    write_inst(bytes, patched);
+
+    Some(())
+}
+
+/// Finds the `b` and `bl` instructions in `data`, which is loaded at `address`, that branch to
+/// one of `targets`. `data_markers` are the `$d` (true) and `$x` (false) mapping symbols of the
+/// executable sorted by address; the ranges they mark as data are not decoded.
+pub(super) fn find_branches<'a>(
+    data: &[u8],
+    address: u64,
+    data_markers: &[(u64, bool)],
+    targets: &MutMap<u64, &'a str>,
+) -> Vec<(u64, &'a str)> {
+    let mut branches = vec![];
+
+    let mut markers = data_markers.iter().peekable();
+    let mut in_data = false;
+
+    for (i, bytes) in data.chunks_exact(4).enumerate() {
+        let inst_address = address + 4 * i as u64;
+
+        while let Some((_, is_data)) = markers.next_if(|(at, _)| *at <= inst_address) {
+            in_data = *is_data;
+        }
+
+        if in_data {
+            continue;
+        }
+
+        let target = branch26_target(read_inst(bytes), inst_address);
+        if let Some(name) = target.and_then(|target| targets.get(&target)) {
+            branches.push((inst_address, *name));
+        }
+    }
+
+    branches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // bl #0
+    const BL: u32 = 0x9400_0000;
+    // adrp x0, #0
+    const ADRP_X0: u32 = 0x9000_0000;
+    // ldr x1, [x0]
+    const LDR_X1_X0: u32 = 0xF940_0001;
+
+    #[test]
+    fn branch_round_trip() {
+        let inst = encode_branch26(BL, -0x40).unwrap();
+
+        assert_eq!(branch26_target(inst, 0x1000), Some(0x1000 - 0x40));
+        assert_eq!(branch26_target(ADRP_X0, 0x1000), None);
+    }
+
+    #[test]
+    fn branch_out_of_range() {
+        assert!(encode_branch26(BL, BRANCH26_RANGE - 4).is_some());
+        assert!(encode_branch26(BL, BRANCH26_RANGE).is_none());
+        assert!(encode_branch26(BL, -BRANCH26_RANGE - 4).is_none());
+        assert!(encode_branch26(BL, 2).is_none());
+    }
+
+    #[test]
+    fn adrp_counts_pages() {
+        // from anywhere in page 0x1000 to anywhere in page 0x4000 is 3 pages
+        assert_eq!(encode_adrp(ADRP_X0, 0x1ffc, 0x4123), Some(0xF000_0000));
+        assert_eq!(encode_adrp(ADRP_X0, 0x1000, 0x5000), Some(0x9000_0020));
+        assert_eq!(encode_adrp(ADRP_X0, 0x5000, 0x1000), Some(0x90FF_FFE0));
+        assert_eq!(encode_adrp(ADRP_X0, 0, 1 << 32), None);
+    }
+
+    #[test]
+    fn lo12_is_scaled_by_access_size() {
+        let ldr = encode_lo12(LDR_X1_X0, 0x4128, 3).unwrap();
+        assert_eq!((ldr >> 10) & 0xFFF, 0x128 >> 3);
+
+        assert!(encode_lo12(LDR_X1_X0, 0x4124, 3).is_none());
+    }
+
+    #[test]
+    fn got_load_becomes_add() {
+        let add = relax_got_load(LDR_X1_X0, 0x4123).unwrap();
+
+        // add x1, x0, #0x123
+        assert_eq!(add, 0x9100_0000 | (0x123 << 10) | 1);
+        assert!(relax_got_load(ADRP_X0, 0x4123).is_none());
+    }
+
+    #[test]
+    fn veneer_reaches_target() {
+        let bytes = veneer(0x10_0000, 0x1234_5678).unwrap();
+
+        let adrp = read_inst(&bytes[0..]);
+        let add = read_inst(&bytes[4..]);
+
+        assert_eq!(adrp, encode_adrp(ADRP_X16, 0x10_0000, 0x1234_5000).unwrap());
+        assert_eq!((add >> 10) & 0xFFF, 0x678);
+        assert_eq!(read_inst(&bytes[8..]), BR_X16);
+    }
+
+    #[test]
+    fn skips_data_in_text() {
+        let bl = encode_branch26(BL, 0x100).unwrap();
+        let data: Vec<u8> = [bl, bl, bl].iter().flat_map(|i| i.to_le_bytes()).collect();
+
+        let mut targets = MutMap::default();
+        targets.insert(0x1100, "first");
+        targets.insert(0x1108, "third");
+
+        // the second word is a literal that happens to look like a branch
+        let markers = [(0x1000, false), (0x1004, true), (0x1008, false)];
+
+        assert_eq!(
+            find_branches(&data, 0x1000, &markers, &targets),
+            [(0x1000, "first"), (0x1008, "third")]
+        );
+    }
+}
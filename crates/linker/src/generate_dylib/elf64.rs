@@ -1,8 +1,21 @@
 use object::{elf, Endianness};
+use roc_target::{Architecture, Target};
 
 use crate::pe::next_multiple_of;
 
-pub fn create_dylib_elf64(custom_names: &[String]) -> object::read::Result<Vec<u8>> {
+pub fn create_dylib_elf64(
+    custom_names: &[String],
+    target: Target,
+) -> object::read::Result<Vec<u8>> {
+    let e_machine = match target.architecture() {
+        Architecture::X86_64 => elf::EM_X86_64,
+        Architecture::Aarch64 => elf::EM_AARCH64,
+        _ => {
+            // We should have verified this via supported() before calling this function
+            unreachable!()
+        }
+    };
+
     let endian = Endianness::Little;
 
     let mut out_data = Vec::new();
@@ -145,7 +158,7 @@ pub fn create_dylib_elf64(custom_names: &[String]) -> object::read::Result<Vec<u
             os_abi: 0,
             abi_version: 0,
             e_type: 3,
-            e_machine: 62,
+            e_machine,
             e_entry: 0x1000,
             e_flags: 0,
         })
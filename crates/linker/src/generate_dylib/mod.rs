@@ -14,7 +14,7 @@ pub(crate) use pe::APP_DLL;
 
 pub fn generate(target: Target, custom_names: &[String]) -> object::read::Result<Vec<u8>> {
     match target.operating_system() {
-        OperatingSystem::Linux => elf64::create_dylib_elf64(custom_names),
+        OperatingSystem::Linux => elf64::create_dylib_elf64(custom_names, target),
         OperatingSystem::Mac => macho::create_dylib_macho(custom_names, target),
         OperatingSystem::Windows => Ok(pe::synthetic_dll(custom_names)),
         other => unimplemented!("dylib creation for {:?}", other),
@@ -57,6 +57,17 @@ mod tests {
         check_exports(&target);
     }
 
+    #[test]
+    fn check_exports_elf64_aarch64() {
+        let custom_names = ["foo".to_string(), "bar".to_string()];
+
+        let bytes = generate(Target::LinuxArm64, &custom_names).unwrap();
+        let object = object::File::parse(bytes.as_slice()).unwrap();
+
+        assert_eq!(object.architecture(), object::Architecture::Aarch64);
+        assert_eq!(object.exports().unwrap().len(), custom_names.len());
+    }
+
     #[test]
     fn check_exports_coff() {
         // NOTE: this does not work
@@ -12,7 +12,9 @@ use roc_packaging::cache::RocCacheDir;
 use roc_reporting::report::{RenderTarget, DEFAULT_PALETTE};
 use roc_solve::FunctionKind;
 use roc_target::{Architecture, OperatingSystem, Target};
+use serde::{de::DeserializeOwned, Serialize};
 use std::cmp::Ordering;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::mem;
 use std::path::{Path, PathBuf};
 
@@ -21,6 +23,9 @@ mod macho;
 mod pe;
 
 mod generate_dylib;
+mod verify;
+
+pub use verify::{rebuild_host_hint, HostProblem};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum LinkType {
@@ -31,16 +36,18 @@ pub enum LinkType {
 }
 
 pub fn supported(link_type: LinkType, target: Target) -> bool {
-    if let LinkType::Executable = link_type {
-        match target {
+    match link_type {
+        LinkType::Executable => match target {
             Target::LinuxX64 => true,
+            Target::LinuxArm64 => true,
             Target::WinX64 => true,
             // macho support is incomplete
             Target::MacX64 => false,
             _ => false,
-        }
-    } else {
-        false
+        },
+        // A shared library host goes through the same surgery as a position independent executable
+        LinkType::Dylib => matches!(target, Target::LinuxX64),
+        LinkType::None => false,
     }
 }
 
@@ -50,17 +57,37 @@ pub fn preprocessed_host_filename(target: Target) -> String {
     format!("{target}.{PRECOMPILED_HOST_EXT}")
 }
 
-fn metadata_file_name(target: Target) -> String {
-    format!("metadata_{}.rm", target)
+/// The preprocessed host of a platform that builds apps into shared libraries with `roc build --lib`
+pub fn preprocessed_dylib_host_filename(target: Target) -> String {
+    format!("{target}.lib.{PRECOMPILED_HOST_EXT}")
+}
+
+fn metadata_file_name(target: Target, link_type: LinkType) -> String {
+    match link_type {
+        LinkType::Dylib => format!("metadata_{}.lib.rm", target),
+        _ => format!("metadata_{}.rm", target),
+    }
+}
+
+/// The host binary that was linked against the stub app library, which `preprocess_host` reads
+pub fn dynhost_file_name(target: Target, link_type: LinkType) -> String {
+    match link_type {
+        LinkType::Dylib => format!("libdynhost.{}", target.dynamic_library_file_ext()),
+        _ => match target.executable_file_ext() {
+            Some(ext) => format!("dynhost.{ext}"),
+            None => "dynhost".to_string(),
+        },
+    }
 }
 
 pub fn link_preprocessed_host(
     target: Target,
+    link_type: LinkType,
     platform_path: &Path,
     roc_app_bytes: &[u8],
     binary_path: &Path,
 ) {
-    let metadata = platform_path.with_file_name(metadata_file_name(target));
+    let metadata = platform_path.with_file_name(metadata_file_name(target, link_type));
     surgery(roc_app_bytes, &metadata, binary_path, false, false, target)
 }
 
@@ -288,8 +315,6 @@ fn generate_import_library(stub_lib_path: &Path, custom_names: &[String]) {
         .unwrap();
 
     if !output.status.success() {
-        use std::io::Write;
-
         std::io::stdout().write_all(&output.stdout).unwrap();
         std::io::stderr().write_all(&output.stderr).unwrap();
 
@@ -364,20 +389,18 @@ fn stub_lib_is_up_to_date(target: Target, stub_lib_path: &Path, custom_names: &[
 
 pub fn preprocess_host(
     target: Target,
+    link_type: LinkType,
     platform_main_roc: &Path,
     preprocessed_path: &Path,
     shared_lib: &Path,
     stub_dll_symbols: &[String],
 ) {
-    let metadata_path = platform_main_roc.with_file_name(metadata_file_name(target));
-    let host_exe_path = if target.operating_system() == OperatingSystem::Windows {
-        platform_main_roc.with_file_name("dynhost.exe")
-    } else {
-        platform_main_roc.with_file_name("dynhost")
-    };
+    let metadata_path = platform_main_roc.with_file_name(metadata_file_name(target, link_type));
+    let host_exe_path = platform_main_roc.with_file_name(dynhost_file_name(target, link_type));
 
     preprocess(
         target,
+        link_type,
         &host_exe_path,
         &metadata_path,
         preprocessed_path,
@@ -388,10 +411,56 @@ pub fn preprocess_host(
     )
 }
 
+/// Checks that the preprocessed host at `preprocessed_path`, and the metadata next to it, fit an
+/// app whose stub library exports `stub_dll_symbols`.
+pub fn verify_preprocessed_host(
+    target: Target,
+    link_type: LinkType,
+    platform_main_roc: &Path,
+    preprocessed_path: &Path,
+    stub_dll_symbols: &[String],
+) -> Vec<HostProblem> {
+    let metadata_path = platform_main_roc.with_file_name(metadata_file_name(target, link_type));
+
+    let missing: Vec<_> = [preprocessed_path, &metadata_path]
+        .into_iter()
+        .filter(|path| !path.exists())
+        .map(|path| HostProblem::MissingFile(path.to_path_buf()))
+        .collect();
+    if !missing.is_empty() {
+        return missing;
+    }
+
+    let preprocessed_data = &*open_mmap(preprocessed_path);
+    match object::File::parse(preprocessed_data) {
+        Ok(object) if !object_matches_target(target, &object) => {
+            return vec![HostProblem::WrongTarget(target)];
+        }
+        Ok(_) => {}
+        Err(err) => {
+            return vec![HostProblem::UnparsableHost {
+                path: preprocessed_path.to_path_buf(),
+                reason: err.to_string(),
+            }];
+        }
+    }
+
+    match target.operating_system() {
+        OperatingSystem::Linux => crate::elf::verify_elf(
+            link_type,
+            &metadata_path,
+            preprocessed_data,
+            stub_dll_symbols,
+        ),
+        _ => vec![HostProblem::UnsupportedTarget(target)],
+    }
+}
+
 /// Constructs a `Metadata` from a host executable binary, and writes it to disk
 #[allow(clippy::too_many_arguments)]
 fn preprocess(
     target: Target,
+    link_type: LinkType,
     host_exe_path: &Path,
     metadata_path: &Path,
     preprocessed_path: &Path,
@@ -407,6 +476,7 @@ fn preprocess(
     match target.arch_os() {
         (_, OperatingSystem::Linux) => {
             crate::elf::preprocess_elf_le(
+                link_type,
                 host_exe_path,
                 metadata_path,
                 preprocessed_path,
@@ -496,6 +566,64 @@ fn surgery(
     }
 }
 
+/// Bumped whenever the layout of the metadata of any binary format changes, so that metadata
+/// written by another version of roc is rejected instead of misread.
+pub(crate) const METADATA_VERSION: u32 = 1;
+
+// Unversioned metadata starts with the 8 byte length of a `Vec`, which never looks like this.
+const METADATA_MAGIC: [u8; 4] = *b"RocM";
+
+pub(crate) fn write_metadata<T: Serialize>(metadata: &T, metadata_path: &Path) {
+    let metadata_file =
+        std::fs::File::create(metadata_path).unwrap_or_else(|e| internal_error!("{}", e));
+    let mut writer = BufWriter::new(metadata_file);
+
+    writer
+        .write_all(&METADATA_MAGIC)
+        .and_then(|_| writer.write_all(&METADATA_VERSION.to_le_bytes()))
+        .unwrap_or_else(|e| internal_error!("Failed to write metadata: {e}"));
+
+    bincode::serialize_into(writer, metadata)
+        .unwrap_or_else(|err| internal_error!("Failed to serialize metadata: {err}"));
+}
+
+pub(crate) fn read_metadata<T: DeserializeOwned>(metadata_path: &Path) -> Result<T, HostProblem> {
+    let unreadable = |reason: String| HostProblem::UnreadableMetadata {
+        path: metadata_path.to_path_buf(),
+        reason,
+    };
+
+    let metadata_file = std::fs::File::open(metadata_path)
+        .map_err(|_| HostProblem::MissingFile(metadata_path.to_path_buf()))?;
+    let mut reader = BufReader::new(metadata_file);
+
+    let mut header = [0; 8];
+    reader
+        .read_exact(&mut header)
+        .map_err(|e| unreadable(e.to_string()))?;
+
+    let (magic, version) = header.split_at(4);
+    let version = u32::from_le_bytes(version.try_into().unwrap());
+    if magic != METADATA_MAGIC || version != METADATA_VERSION {
+        return Err(HostProblem::MetadataVersion {
+            path: metadata_path.to_path_buf(),
+            found: (magic == METADATA_MAGIC).then_some(version),
+        });
+    }
+
+    bincode::deserialize_from(reader).map_err(|err| unreadable(err.to_string()))
+}
+
+/// Reads the metadata for a surgical link, and stops with an explanation when it is unusable.
+pub(crate) fn read_metadata_for_surgery<T: DeserializeOwned>(metadata_path: &Path) -> T {
+    read_metadata(metadata_path).unwrap_or_else(|problem| {
+        eprintln!("Error:");
+        eprintln!("\n\t{problem}");
+        eprintln!("\n\t{}", rebuild_host_hint());
+        std::process::exit(1);
+    })
+}
+
 pub(crate) fn align_by_constraint(offset: usize, constraint: usize) -> usize {
     if offset % constraint == 0 {
         offset
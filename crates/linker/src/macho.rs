@@ -1,4 +1,3 @@
-use bincode::{deserialize_from, serialize_into};
 use iced_x86::{Decoder, DecoderOptions, Instruction, OpCodeOperandKind, OpKind};
 use memmap2::MmapMut;
 use object::macho;
@@ -12,7 +11,6 @@ use roc_error_macros::internal_error;
 use serde::{Deserialize, Serialize};
 use std::{
     ffi::{c_char, CStr},
-    io::{BufReader, BufWriter},
     mem,
     path::Path,
     time::{Duration, Instant},
@@ -77,30 +75,11 @@ struct Metadata {
 
 impl Metadata {
     fn write_to_file(&self, metadata_filename: &Path) {
-        let metadata_file =
-            std::fs::File::create(metadata_filename).unwrap_or_else(|e| internal_error!("{}", e));
-
-        serialize_into(BufWriter::new(metadata_file), self)
-            .unwrap_or_else(|err| internal_error!("Failed to serialize metadata: {err}"));
+        crate::write_metadata(self, metadata_filename)
     }
 
     fn read_from_file(metadata_filename: &Path) -> Self {
-        let input = std::fs::File::open(metadata_filename).unwrap_or_else(|e| {
-            internal_error!(
-                r#"
-
-                Error:
-                    {}\n"#,
-                e
-            )
-        });
-
-        match deserialize_from(BufReader::new(input)) {
-            Ok(data) => data,
-            Err(err) => {
-                internal_error!("Failed to deserialize metadata: {}", err);
-            }
-        }
+        crate::read_metadata_for_surgery(metadata_filename)
     }
 }
 
@@ -1,9 +1,5 @@
-use std::{
-    io::{BufReader, BufWriter},
-    path::Path,
-};
+use std::path::Path;
 
-use bincode::{deserialize_from, serialize_into};
 use memmap2::MmapMut;
 use object::{
     pe::{
@@ -75,23 +71,11 @@ struct PeMetadata {
 
 impl PeMetadata {
     fn write_to_file(&self, metadata_filename: &Path) {
-        let metadata_file =
-            std::fs::File::create(metadata_filename).unwrap_or_else(|e| internal_error!("{}", e));
-
-        serialize_into(BufWriter::new(metadata_file), self)
-            .unwrap_or_else(|err| internal_error!("Failed to serialize metadata: {err}"));
+        crate::write_metadata(self, metadata_filename)
     }
 
     fn read_from_file(metadata_filename: &Path) -> Self {
-        let input =
-            std::fs::File::open(metadata_filename).unwrap_or_else(|e| internal_error!("{}", e));
-
-        match deserialize_from(BufReader::new(input)) {
-            Ok(data) => data,
-            Err(err) => {
-                internal_error!("Failed to deserialize metadata: {}", err);
-            }
-        }
+        crate::read_metadata_for_surgery(metadata_filename)
     }
 
     fn from_preprocessed_host(preprocessed_data: &[u8], new_sections: &[[u8; 8]]) -> Self {
@@ -0,0 +1,216 @@
+//! Checks that a preprocessed host fits an app before we try to surgically link them.
+//!
+//! A host that was built against an older version of a platform, or preprocessed by a different
+//! version of roc, usually makes the surgery panic or, worse, produce a binary that crashes.
+//! Catching that up front lets us tell the platform author how to fix it.
+use std::fmt;
+use std::path::PathBuf;
+
+use roc_target::Target;
+
+/// Something about a preprocessed host that would break the surgical link.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostProblem {
+    MissingFile(PathBuf),
+    UnreadableMetadata {
+        path: PathBuf,
+        reason: String,
+    },
+    /// `found` is `None` for metadata that was written before it was versioned.
+    MetadataVersion {
+        path: PathBuf,
+        found: Option<u32>,
+    },
+    /// The metadata describes a different preprocessed host than the one on disk.
+    StaleMetadata {
+        expected_len: u64,
+        actual_len: u64,
+    },
+    UnparsableHost {
+        path: PathBuf,
+        reason: String,
+    },
+    WrongTarget(Target),
+    NotASharedLibrary,
+    /// The host calls a roc function that the app does not provide.
+    UnknownSymbol(String),
+    /// The app exposes a value to the host, but the host never calls it.
+    UnusedExposedValue(String),
+    UnsupportedRelocation {
+        symbol: String,
+        kind: String,
+    },
+    UnsupportedSurgery {
+        symbol: String,
+        size: u8,
+    },
+    UnsupportedTarget(Target),
+}
+
+impl fmt::Display for HostProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HostProblem::MissingFile(path) => write!(f, "{} does not exist.", path.display()),
+            HostProblem::UnreadableMetadata { path, reason } => {
+                write!(f, "The metadata in {} is corrupt: {reason}", path.display())
+            }
+            HostProblem::MetadataVersion { path, found: None } => write!(
+                f,
+                "The metadata in {} was written by an older version of roc.",
+                path.display()
+            ),
+            HostProblem::MetadataVersion {
+                path,
+                found: Some(found),
+            } => write!(
+                f,
+                "The metadata in {} has version {found}, but this version of roc needs version {}.",
+                path.display(),
+                crate::METADATA_VERSION
+            ),
+            HostProblem::StaleMetadata {
+                expected_len,
+                actual_len,
+            } => write!(
+                f,
+                "The metadata belongs to a different preprocessed host: it expects a host of {expected_len} bytes, but the host has {actual_len} bytes."
+            ),
+            HostProblem::UnparsableHost { path, reason } => {
+                write!(f, "{} is not a valid host: {reason}", path.display())
+            }
+            HostProblem::WrongTarget(target) => {
+                write!(f, "The preprocessed host was not built for {target}.")
+            }
+            HostProblem::NotASharedLibrary => write!(
+                f,
+                "The app is built with `--lib`, but the preprocessed host is not a shared library."
+            ),
+            HostProblem::UnknownSymbol(symbol) => write!(
+                f,
+                "The host calls `{symbol}`, which the app does not provide."
+            ),
+            HostProblem::UnusedExposedValue(value) => write!(
+                f,
+                "The app exposes `{value}` to the host, but the host never calls it."
+            ),
+            HostProblem::UnsupportedRelocation { symbol, kind } => write!(
+                f,
+                "The host refers to `{symbol}` with a {kind} relocation, which the surgical linker does not support."
+            ),
+            HostProblem::UnsupportedSurgery { symbol, size } => write!(
+                f,
+                "The host refers to `{symbol}` with a {size} byte address, which the surgical linker does not support."
+            ),
+            HostProblem::UnsupportedTarget(target) => write!(
+                f,
+                "Verifying preprocessed hosts is not supported for {target} yet."
+            ),
+        }
+    }
+}
+
+/// What to tell the platform author when the host does not fit the app.
+pub fn rebuild_host_hint() -> &'static str {
+    concat!(
+        "To fix this, rebuild the host against the stub library of the app, which ",
+        "`roc gen-stub-lib <app.roc>` writes next to the platform's main.roc. ",
+        "Then preprocess it again with `roc preprocess-host <app.roc>` using this version of roc, ",
+        "adding `--lib` if the host is a shared library."
+    )
+}
+
+/// Compares the roc symbols the host calls with the ones the stub library of the app exports.
+pub(crate) fn symbol_problems(
+    host_symbols: &[String],
+    stub_dll_symbols: &[String],
+) -> Vec<HostProblem> {
+    let mut problems: Vec<_> = host_symbols
+        .iter()
+        .filter(|symbol| !stub_dll_symbols.contains(symbol))
+        .map(|symbol| HostProblem::UnknownSymbol(symbol.clone()))
+        .collect();
+
+    // Every value that is exposed to the host gets a `roc__{value}_1_exposed` symbol.
+    let exposed_values = stub_dll_symbols.iter().filter_map(|symbol| {
+        symbol
+            .strip_prefix("roc__")
+            .and_then(|s| s.strip_suffix("_1_exposed"))
+    });
+
+    for value in exposed_values {
+        let prefix = format!("roc__{value}_");
+        if !host_symbols
+            .iter()
+            .any(|symbol| symbol.starts_with(&prefix))
+        {
+            problems.push(HostProblem::UnusedExposedValue(value.to_string()));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn stub_symbols() -> Vec<String> {
+        strings(&[
+            "roc__mainForHost_1_exposed",
+            "roc__mainForHost_1_exposed_generic",
+            "roc__mainForHost_size",
+        ])
+    }
+
+    #[test]
+    fn matching_host() {
+        let host = strings(&["roc__mainForHost_1_exposed_generic"]);
+
+        assert_eq!(symbol_problems(&host, &stub_symbols()), []);
+    }
+
+    #[test]
+    fn host_calls_unknown_symbol() {
+        let host = strings(&["roc__mainForHost_1_exposed", "roc__main_1_exposed"]);
+
+        assert_eq!(
+            symbol_problems(&host, &stub_symbols()),
+            [HostProblem::UnknownSymbol(
+                "roc__main_1_exposed".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn host_never_calls_exposed_value() {
+        let host = strings(&["roc__main_1_exposed"]);
+
+        assert_eq!(
+            symbol_problems(&host, &stub_symbols()),
+            [
+                HostProblem::UnknownSymbol("roc__main_1_exposed".to_string()),
+                HostProblem::UnusedExposedValue("mainForHost".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn metadata_version_is_checked() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("metadata.rm");
+
+        crate::write_metadata(&vec![1u64, 2, 3], &path);
+        assert_eq!(crate::read_metadata::<Vec<u64>>(&path), Ok(vec![1, 2, 3]));
+
+        // metadata from before versioning starts with the length of its first `Vec`
+        std::fs::write(&path, bincode::serialize(&vec![1u64, 2, 3]).unwrap()).unwrap();
+        assert_eq!(
+            crate::read_metadata::<Vec<u64>>(&path),
+            Err(HostProblem::MetadataVersion { path, found: None })
+        );
+    }
+}
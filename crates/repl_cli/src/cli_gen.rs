@@ -217,6 +217,7 @@ fn mono_module_to_dylib_llvm<'a>(
         mode: LlvmBackendMode::GenTest, // so roc_panic is generated
         // important! we don't want any procedures to get the C calling convention
         exposed_to_host: MutSet::default(),
+        debug_sources: None,
     };
 
     // Add roc_alloc, roc_realloc, and roc_dealloc, since the repl has no
@@ -309,9 +310,10 @@ fn mono_module_to_dylib_asm<'a>(
         exposed_to_host: exposed_to_host.top_level_values.keys().copied().collect(),
         lazy_literals,
         mode: roc_gen_dev::AssemblyBackendMode::Repl,
+        debug_sources: None,
     };
 
-    let module_object =
+    let (module_object, _) =
         roc_gen_dev::build_module(&env, &mut interns, &mut layout_interner, target, procedures);
 
     let module_out = module_object
@@ -648,7 +648,7 @@ fn too_few_args() {
         "Num.add 2",
         indoc!(
             r"
-                ── TOO FEW ARGS ────────────────────────────────────────────────────────────────
+                ── TOO FEW ARGS [R0305] ────────────────────────────────────────────────────────
 
                 The add function expects 2 arguments, but it got only 1:
 
@@ -669,7 +669,7 @@ fn type_problem_function() {
         "Num.add 1 \"not a num\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
                 This 2nd argument to add has an unexpected type:
 
@@ -695,7 +695,7 @@ fn type_problem_binary_operator() {
         "1 + \"\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
                 This 2nd argument to + has an unexpected type:
 
@@ -721,7 +721,7 @@ fn type_problem_unary_operator() {
         "!\"not a bool\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
                 This 1st argument to ! has an unexpected type:
 
@@ -747,7 +747,7 @@ fn type_problem_string_interpolation() {
         "\"This is not a string -> $(1)\"",
         indoc!(
             r#"
-                ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+                ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
                 This argument to this string interpolation has an unexpected type:
 
@@ -773,7 +773,7 @@ fn list_drop_at_negative_index() {
         "List.dropAt [1, 2, 3] -1",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
             This 2nd argument to dropAt has an unexpected type:
 
@@ -799,7 +799,7 @@ fn list_get_negative_index() {
         "List.get [1, 2, 3] -1",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
             This 2nd argument to get has an unexpected type:
 
@@ -825,7 +825,7 @@ fn invalid_string_interpolation() {
         "\"$(123)\"",
         indoc!(
             r#"
-            ── TYPE MISMATCH ───────────────────────────────────────────────────────────────
+            ── TYPE MISMATCH [R0300] ───────────────────────────────────────────────────────
 
             This argument to this string interpolation has an unexpected type:
 
@@ -1123,7 +1123,7 @@ fn parse_problem() {
         "add m n = m + n",
         indoc!(
             r#"
-                ── ARGUMENTS BEFORE EQUALS ─────────────────────────────────────────────────────
+                ── ARGUMENTS BEFORE EQUALS [R0103] ─────────────────────────────────────────────
 
                 I am partway through parsing a definition, but I got stuck here:
 
@@ -1157,7 +1157,7 @@ fn issue_2343_complete_mono_with_shadowed_vars() {
         ),
         indoc!(
             r"
-                ── DUPLICATE NAME ──────────────────────────────────────────────────────────────
+                ── DUPLICATE NAME [R0217] ──────────────────────────────────────────────────────
 
                 The b name is first defined here:
 
@@ -1568,7 +1568,7 @@ fn interpolation_with_nested_interpolation() {
         ),
         indoc!(
             r#"
-                ── SYNTAX PROBLEM ──────────────────────────────────────────────────────────────
+                ── SYNTAX PROBLEM [R0100] ──────────────────────────────────────────────────────
 
                 This string interpolation is invalid:
 
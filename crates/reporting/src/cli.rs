@@ -1,12 +1,16 @@
 use std::path::PathBuf;
 
 use roc_collections::MutMap;
+use roc_lint::Lint;
 use roc_module::symbol::{Interns, ModuleId};
 use roc_problem::can::Problem;
-use roc_region::all::LineInfo;
+use roc_region::all::{LineInfo, Region};
 use roc_solve_problem::TypeError;
 
-use crate::report::ANSI_STYLE_CODES;
+use crate::diagnostic::{to_sarif, Diagnostic, DiagnosticSeverity};
+use crate::fix::{self, Fix};
+use crate::report::{Palette, RenderTarget, Report, RocDocAllocator, ANSI_STYLE_CODES};
+use crate::warnings::{local_warning_levels, LocalWarningLevels, WarningConfig, WarningLevel};
 
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct Problems {
@@ -60,13 +64,76 @@ impl Problems {
     }
 }
 
+/// How to write out the problems found while compiling.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// Reports for people to read, in color, on stdout
+    #[default]
+    Human,
+    /// One JSON object per problem, each on its own line, on stderr
+    Json,
+    /// A single SARIF log containing every problem, on stderr
+    Sarif,
+}
+
+impl ErrorFormat {
+    pub fn is_human(self) -> bool {
+        matches!(self, ErrorFormat::Human)
+    }
+
+    /// How loading should render any reports it formats itself (e.g. for parse errors).
+    pub fn render_target(self) -> RenderTarget {
+        match self {
+            ErrorFormat::Human => RenderTarget::ColorTerminal,
+            ErrorFormat::Json | ErrorFormat::Sarif => RenderTarget::Generic,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ErrorFormat {
+    type Error = ();
+
+    fn try_from(format: &'a str) -> Result<Self, Self::Error> {
+        match format {
+            "human" => Ok(ErrorFormat::Human),
+            "json" => Ok(ErrorFormat::Json),
+            "sarif" => Ok(ErrorFormat::Sarif),
+            _ => Err(()),
+        }
+    }
+}
+
 pub fn report_problems(
     sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
     interns: &Interns,
     can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
     type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
 ) -> Problems {
-    use crate::report::{can_problem, type_problem, Report, RocDocAllocator, DEFAULT_PALETTE};
+    report_problems_and_lints(
+        sources,
+        interns,
+        can_problems,
+        type_problems,
+        &mut MutMap::default(),
+        error_format,
+        warning_config,
+    )
+}
+
+/// Like [report_problems], but also reports the lints that `roc lint` found. Lints are
+/// warnings like any other, so the warning configuration applies to them too.
+pub fn report_problems_and_lints(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &mut MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    type_problems: &mut MutMap<ModuleId, Vec<TypeError>>,
+    lints: &mut MutMap<ModuleId, Vec<Lint>>,
+    error_format: ErrorFormat,
+    warning_config: &WarningConfig,
+) -> Problems {
+    use crate::report::{can_problem, lint_problem, type_problem, DEFAULT_PALETTE};
     use roc_problem::Severity::*;
 
     let palette = DEFAULT_PALETTE;
@@ -80,11 +147,35 @@ pub fn report_problems(
         total_problems += problems.len();
     }
 
+    for module_lints in lints.values() {
+        total_problems += module_lints.len();
+    }
+
     // This will often over-allocate total memory, but it means we definitely
     // never need to re-allocate either the warnings or the errors vec!
     let mut warnings = Vec::with_capacity(total_problems);
     let mut errors = Vec::with_capacity(total_problems);
     let mut fatally_errored = false;
+    let mut allowed = 0;
+
+    // Machine-readable formats include every problem, warnings and errors alike.
+    let mut diagnostics = Vec::new();
+    let mut push_report = |severity, buf: String, diagnostic: Option<Diagnostic>| {
+        diagnostics.extend(diagnostic);
+
+        match severity {
+            Warning => {
+                warnings.push(buf);
+            }
+            RuntimeError => {
+                errors.push(buf);
+            }
+            Fatal => {
+                fatally_errored = true;
+                errors.push(buf);
+            }
+        }
+    };
 
     for (home, (module_path, src)) in sources.iter() {
         let mut src_lines: Vec<&str> = Vec::new();
@@ -95,28 +186,38 @@ pub fn report_problems(
 
         // Report parsing and canonicalization problems
         let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+        let local_levels = local_warning_levels(&src_lines);
 
         let problems = type_problems.remove(home).unwrap_or_default();
 
         for problem in problems {
-            if let Some(report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
-                let severity = report.severity;
-                let mut buf = String::new();
+            let region = problem.region();
+            let related_regions = problem.related_regions();
 
-                report.render_color_terminal(&mut buf, &alloc, &palette);
+            if let Some(mut report) = type_problem(&alloc, &lines, module_path.clone(), problem) {
+                let Some(severity) = configured_severity(
+                    report.severity,
+                    report.code(),
+                    warning_config,
+                    &local_levels,
+                    &lines,
+                    region,
+                ) else {
+                    allowed += 1;
+                    continue;
+                };
+                report.severity = severity;
+                let (buf, diagnostic) = render_report(
+                    report,
+                    &alloc,
+                    &palette,
+                    error_format,
+                    &lines,
+                    region,
+                    related_regions,
+                );
 
-                match severity {
-                    Warning => {
-                        warnings.push(buf);
-                    }
-                    RuntimeError => {
-                        errors.push(buf);
-                    }
-                    Fatal => {
-                        fatally_errored = true;
-                        errors.push(buf);
-                    }
-                }
+                push_report(severity, buf, diagnostic);
             }
         }
 
@@ -137,60 +238,255 @@ pub fn report_problems(
         ordered.extend(shadowing_errs);
 
         for problem in ordered.into_iter() {
-            let report = can_problem(&alloc, &lines, module_path.clone(), problem);
-            let severity = report.severity;
-            let mut buf = String::new();
+            let region = problem.region();
+            let related_regions = problem.related_regions();
+            let mut report = can_problem(&alloc, &lines, module_path.clone(), problem);
+            let Some(severity) = configured_severity(
+                report.severity,
+                report.code(),
+                warning_config,
+                &local_levels,
+                &lines,
+                region,
+            ) else {
+                allowed += 1;
+                continue;
+            };
+            report.severity = severity;
+            let (buf, diagnostic) = render_report(
+                report,
+                &alloc,
+                &palette,
+                error_format,
+                &lines,
+                region,
+                related_regions,
+            );
 
-            report.render_color_terminal(&mut buf, &alloc, &palette);
+            push_report(severity, buf, diagnostic);
+        }
 
-            match severity {
-                Warning => {
-                    warnings.push(buf);
-                }
-                RuntimeError => {
-                    errors.push(buf);
+        for lint in lints.remove(home).unwrap_or_default() {
+            let region = Some(lint.region());
+            let mut report = lint_problem(&alloc, &lines, module_path.clone(), lint);
+            let Some(severity) = configured_severity(
+                report.severity,
+                report.code(),
+                warning_config,
+                &local_levels,
+                &lines,
+                region,
+            ) else {
+                allowed += 1;
+                continue;
+            };
+            report.severity = severity;
+            let (buf, diagnostic) = render_report(
+                report,
+                &alloc,
+                &palette,
+                error_format,
+                &lines,
+                region,
+                Vec::new(),
+            );
+
+            push_report(severity, buf, diagnostic);
+        }
+    }
+
+    debug_assert!(
+        lints.is_empty(),
+        "Some lints were for modules without entries in `sources`."
+    );
+    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
+    debug_assert_eq!(errors.len() + warnings.len() + allowed, total_problems);
+
+    match error_format {
+        ErrorFormat::Human => {
+            let problems_reported;
+
+            // Only print warnings if there are no errors
+            if errors.is_empty() {
+                problems_reported = warnings.len();
+
+                for warning in warnings.iter() {
+                    println!("\n{warning}\n");
                 }
-                Fatal => {
-                    fatally_errored = true;
-                    errors.push(buf);
+            } else {
+                problems_reported = errors.len();
+
+                for error in errors.iter() {
+                    println!("\n{error}\n");
                 }
             }
+
+            // If we printed any problems, print a horizontal rule at the end,
+            // and then clear any ANSI escape codes (e.g. colors) we've used.
+            //
+            // The horizontal rule is nice when running the program right after
+            // compiling it, as it lets you clearly see where the compiler
+            // errors/warnings end and the program output begins.
+            if problems_reported > 0 {
+                println!("{}\u{001B}[0m\n", Report::horizontal_rule(&palette));
+            }
+        }
+        ErrorFormat::Json => {
+            for diagnostic in diagnostics.iter() {
+                eprintln!("{}", diagnostic.to_json());
+            }
+        }
+        ErrorFormat::Sarif => {
+            eprintln!("{}", to_sarif(&diagnostics));
         }
     }
 
-    debug_assert!(can_problems.is_empty() && type_problems.is_empty(), "After reporting problems, there were {:?} can_problems and {:?} type_problems that could not be reported because they did not have corresponding entries in `sources`.", can_problems.len(), type_problems.len());
-    debug_assert_eq!(errors.len() + warnings.len(), total_problems);
+    Problems {
+        fatally_errored,
+        errors: errors.len(),
+        warnings: warnings.len(),
+    }
+}
+
+/// The severity to report a problem with, after applying any warning levels that have
+/// been configured for it. Returns `None` if the problem is an allowed warning.
+fn configured_severity(
+    severity: roc_problem::Severity,
+    code: Option<&str>,
+    warning_config: &WarningConfig,
+    local_levels: &[LocalWarningLevels],
+    lines: &LineInfo,
+    region: Option<Region>,
+) -> Option<roc_problem::Severity> {
+    use roc_problem::Severity::*;
 
-    let problems_reported;
+    if severity != Warning {
+        return Some(severity);
+    }
 
-    // Only print warnings if there are no errors
-    if errors.is_empty() {
-        problems_reported = warnings.len();
+    let level = match region {
+        Some(region) => {
+            let line = lines.convert_pos(region.start()).line;
 
-        for warning in warnings.iter() {
-            println!("\n{warning}\n");
+            warning_config.level_at(local_levels, code, line)
         }
-    } else {
-        problems_reported = errors.len();
+        None => warning_config.level(code),
+    };
+
+    match level {
+        WarningLevel::Allow => None,
+        WarningLevel::Warn => Some(Warning),
+        WarningLevel::Deny => Some(RuntimeError),
+    }
+}
+
+/// Apply the safe fixes for every problem in these modules, returning each module's path
+/// along with its fixed source and how many fixes went into it. Modules with nothing to fix
+/// are left out. This doesn't touch the problems, so they can still be reported afterwards.
+///
+/// Type errors are left alone, since the fixes for those are only ever suggestions.
+pub fn fix_problems(
+    sources: &MutMap<ModuleId, (PathBuf, Box<str>)>,
+    interns: &Interns,
+    can_problems: &MutMap<ModuleId, Vec<roc_problem::can::Problem>>,
+    warning_config: &WarningConfig,
+) -> Vec<(PathBuf, String, usize)> {
+    use crate::error::canonicalize::safe_fixes;
+
+    let mut fixed = Vec::new();
 
-        for error in errors.iter() {
-            println!("\n{error}\n");
+    for (home, (module_path, src)) in sources.iter() {
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let alloc = RocDocAllocator::new(&src_lines, *home, interns);
+        let local_levels = local_warning_levels(&src_lines);
+        let mut fixes: Vec<Fix> = Vec::new();
+
+        for problem in can_problems.get(home).into_iter().flatten() {
+            let Some((error_code, problem_fixes)) = safe_fixes(&alloc, &lines, problem) else {
+                continue;
+            };
+
+            // Allowed warnings aren't reported, so they shouldn't be fixed behind anyone's back
+            // either
+            if configured_severity(
+                problem.severity(),
+                Some(error_code.code),
+                warning_config,
+                &local_levels,
+                &lines,
+                problem.region(),
+            )
+            .is_some()
+            {
+                fixes.extend(problem_fixes);
+            }
+        }
+
+        let (fixed_src, applied) = fix::apply_safe_fixes(src, &fixes);
+
+        if applied > 0 {
+            fixed.push((module_path.clone(), fixed_src, applied));
         }
     }
 
-    // If we printed any problems, print a horizontal rule at the end,
-    // and then clear any ANSI escape codes (e.g. colors) we've used.
-    //
-    // The horizontal rule is nice when running the program right after
-    // compiling it, as it lets you clearly see where the compiler
-    // errors/warnings end and the program output begins.
-    if problems_reported > 0 {
-        println!("{}\u{001B}[0m\n", Report::horizontal_rule(&palette));
+    fixed
+}
+
+/// Renders a report for the terminal, or (for machine-readable formats) as a [Diagnostic]
+/// whose message is the report's plain-text body.
+fn render_report<'b>(
+    report: Report<'b>,
+    alloc: &'b RocDocAllocator<'b>,
+    palette: &'b Palette,
+    error_format: ErrorFormat,
+    lines: &LineInfo,
+    region: Option<Region>,
+    related_regions: Vec<Region>,
+) -> (String, Option<Diagnostic>) {
+    if error_format.is_human() {
+        let mut buf = String::new();
+        report.render_color_terminal(&mut buf, alloc, palette);
+
+        return (buf, None);
     }
 
-    Problems {
-        fatally_errored,
-        errors: errors.len(),
-        warnings: warnings.len(),
+    let diagnostic = Diagnostic::from_report(&report, lines, region, &related_regions);
+
+    (diagnostic.message.clone(), Some(diagnostic))
+}
+
+/// Report a problem that stopped loading before any modules could be checked (for example,
+/// a parse error). Loading already formatted these, so all that's left is to write them out,
+/// or their diagnostic for machine-readable formats.
+pub fn report_loading_problem(
+    report: &str,
+    diagnostic: Option<Box<Diagnostic>>,
+    error_format: ErrorFormat,
+) {
+    match error_format {
+        ErrorFormat::Human => print!("{report}"),
+        ErrorFormat::Json | ErrorFormat::Sarif => {
+            // A few problems only ever get formatted as text, so the text is all they have.
+            let diagnostic = diagnostic.map_or_else(
+                || Diagnostic {
+                    code: None,
+                    severity: DiagnosticSeverity::Error,
+                    title: String::new(),
+                    file: PathBuf::new(),
+                    region: None,
+                    related_regions: Vec::new(),
+                    message: report.trim().to_string(),
+                    fixes: Vec::new(),
+                },
+                |diagnostic| *diagnostic,
+            );
+
+            if error_format == ErrorFormat::Json {
+                eprintln!("{}", diagnostic.to_json());
+            } else {
+                eprintln!("{}", to_sarif(&[diagnostic]));
+            }
+        }
     }
 }
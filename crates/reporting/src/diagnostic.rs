@@ -0,0 +1,299 @@
+//! Problems in a machine-readable form, for CI annotators, code review bots, and editors
+//! that don't speak the language server protocol.
+use crate::fix::{Applicability, Fix};
+use crate::report::Report;
+use roc_problem::Severity;
+use roc_region::all::{LineInfo, Region};
+use serde::Serialize;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// A stable identifier for this kind of problem, if it has one.
+    pub code: Option<String>,
+    pub severity: DiagnosticSeverity,
+    pub title: String,
+    pub file: PathBuf,
+    /// Some problems (for example, a file that couldn't be read) have no region.
+    pub region: Option<Span>,
+    /// Other places relevant to the problem, e.g. where a shadowed name was first defined.
+    pub related_regions: Vec<Span>,
+    /// The explanation `roc check` would print, without the title or colors.
+    pub message: String,
+    /// Edits that would resolve the problem. Only `safe` ones are applied by `roc check --fix`.
+    pub fixes: Vec<DiagnosticFix>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DiagnosticFix {
+    pub title: String,
+    pub region: Span,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+impl DiagnosticFix {
+    pub fn from_fix(line_info: &LineInfo, fix: Fix) -> Self {
+        DiagnosticFix {
+            title: fix.title,
+            region: Span::from_region(line_info, fix.region),
+            replacement: fix.replacement,
+            applicability: fix.applicability,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+}
+
+impl From<Severity> for DiagnosticSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::RuntimeError | Severity::Fatal => DiagnosticSeverity::Error,
+            Severity::Warning => DiagnosticSeverity::Warning,
+        }
+    }
+}
+
+/// A region of a source file. Lines and columns start at 1 (as they do in editors),
+/// and the end is exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+}
+
+impl Span {
+    pub fn from_region(line_info: &LineInfo, region: Region) -> Self {
+        let region = line_info.convert_region(region);
+
+        Span {
+            start_line: region.start.line + 1,
+            start_column: region.start.column + 1,
+            end_line: region.end.line + 1,
+            end_column: region.end.column + 1,
+        }
+    }
+}
+
+impl Diagnostic {
+    /// The machine-readable form of `report`, taken from its fields rather than its rendered text.
+    /// `region`, `related_regions` and the report's fixes are all in the source `lines` is for.
+    pub fn from_report(
+        report: &Report<'_>,
+        lines: &LineInfo,
+        region: Option<Region>,
+        related_regions: &[Region],
+    ) -> Self {
+        let mut message = String::new();
+        report.render_body(&mut message);
+
+        Diagnostic {
+            code: report.code().map(str::to_string),
+            severity: report.severity.into(),
+            title: report.title.clone(),
+            file: report.filename.clone(),
+            region: region.map(|region| Span::from_region(lines, region)),
+            related_regions: related_regions
+                .iter()
+                .map(|region| Span::from_region(lines, *region))
+                .collect(),
+            message: message.trim_end().to_string(),
+            fixes: report
+                .fixes
+                .iter()
+                .map(|fix| DiagnosticFix::from_fix(lines, fix.clone()))
+                .collect(),
+        }
+    }
+
+    /// One line of JSON, with no trailing newline.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("diagnostics should always serialize to JSON")
+    }
+}
+
+/// A [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log
+/// with one result per diagnostic, which is what GitHub code scanning and similar tools ingest.
+pub fn to_sarif(diagnostics: &[Diagnostic]) -> String {
+    fn region(span: &Span) -> serde_json::Value {
+        json!({
+            "startLine": span.start_line,
+            "startColumn": span.start_column,
+            "endLine": span.end_line,
+            "endColumn": span.end_column,
+        })
+    }
+
+    fn location(file: &Path, span: &Span) -> serde_json::Value {
+        json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": file.to_string_lossy() },
+                "region": region(span),
+            },
+        })
+    }
+
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut result = json!({
+                "level": match diagnostic.severity {
+                    DiagnosticSeverity::Error => "error",
+                    DiagnosticSeverity::Warning => "warning",
+                },
+                "message": { "text": format!("{}\n\n{}", diagnostic.title, diagnostic.message) },
+                "locations": match &diagnostic.region {
+                    Some(span) => vec![location(&diagnostic.file, span)],
+                    None => vec![json!({
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": diagnostic.file.to_string_lossy() },
+                        },
+                    })],
+                },
+                "relatedLocations": diagnostic
+                    .related_regions
+                    .iter()
+                    .map(|span| location(&diagnostic.file, span))
+                    .collect::<Vec<_>>(),
+            });
+
+            if let Some(code) = &diagnostic.code {
+                result["ruleId"] = json!(code);
+            }
+
+            if !diagnostic.fixes.is_empty() {
+                result["fixes"] = diagnostic
+                    .fixes
+                    .iter()
+                    .map(|fix| {
+                        json!({
+                            "description": { "text": fix.title },
+                            "artifactChanges": [{
+                                "artifactLocation": { "uri": diagnostic.file.to_string_lossy() },
+                                "replacements": [{
+                                    "deletedRegion": region(&fix.region),
+                                    "insertedContent": { "text": fix.replacement },
+                                }],
+                            }],
+                        })
+                    })
+                    .collect();
+            }
+
+            result
+        })
+        .collect::<Vec<_>>();
+
+    let log = json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "roc",
+                    "informationUri": "https://www.roc-lang.org",
+                },
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).expect("diagnostics should always serialize to SARIF")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic() -> Diagnostic {
+        Diagnostic {
+            code: None,
+            severity: DiagnosticSeverity::Warning,
+            title: "UNUSED DEFINITION".to_string(),
+            file: PathBuf::from("main.roc"),
+            region: Some(Span {
+                start_line: 3,
+                start_column: 1,
+                end_line: 3,
+                end_column: 4,
+            }),
+            related_regions: Vec::new(),
+            message: "`foo` is not used anywhere in your code.".to_string(),
+            fixes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn span_is_one_based() {
+        let line_info = LineInfo::new("x = 1\n\nfoo = 2\n");
+        let region = Region::new(
+            roc_region::all::Position::new(7),
+            roc_region::all::Position::new(10),
+        );
+
+        assert_eq!(
+            Span::from_region(&line_info, region),
+            Span {
+                start_line: 3,
+                start_column: 1,
+                end_line: 3,
+                end_column: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn json_is_one_line() {
+        assert_eq!(
+            diagnostic().to_json(),
+            r#"{"code":null,"severity":"warning","title":"UNUSED DEFINITION","file":"main.roc","region":{"start_line":3,"start_column":1,"end_line":3,"end_column":4},"related_regions":[],"message":"`foo` is not used anywhere in your code.","fixes":[]}"#
+        );
+    }
+
+    #[test]
+    fn sarif_result() {
+        let log: serde_json::Value = serde_json::from_str(&to_sarif(&[diagnostic()])).unwrap();
+        let result = &log["runs"][0]["results"][0];
+
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(result["level"], "warning");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            3
+        );
+        assert_eq!(result.get("ruleId"), None);
+        assert_eq!(result.get("fixes"), None);
+    }
+
+    #[test]
+    fn sarif_fixes() {
+        let mut diagnostic = diagnostic();
+        diagnostic.fixes.push(DiagnosticFix {
+            title: "Remove unused definition".to_string(),
+            region: diagnostic.region.unwrap(),
+            replacement: String::new(),
+            applicability: Applicability::Safe,
+        });
+
+        let log: serde_json::Value = serde_json::from_str(&to_sarif(&[diagnostic])).unwrap();
+        let fix = &log["runs"][0]["results"][0]["fixes"][0];
+
+        assert_eq!(fix["description"]["text"], "Remove unused definition");
+        assert_eq!(
+            fix["artifactChanges"][0]["replacements"][0]["deletedRegion"]["endColumn"],
+            4
+        );
+        assert_eq!(
+            fix["artifactChanges"][0]["replacements"][0]["insertedContent"]["text"],
+            ""
+        );
+    }
+}
@@ -1,17 +1,22 @@
+use bumpalo::Bump;
 use roc_collections::all::MutSet;
 use roc_module::ident::{Ident, Lowercase, ModuleName};
-use roc_module::symbol::{ScopeModuleSource, DERIVABLE_ABILITIES};
+use roc_module::symbol::{ScopeModuleSource, Symbol, DERIVABLE_ABILITIES};
+use roc_parse::module::recovered_error_at;
 use roc_problem::can::PrecedenceProblem::BothNonAssociative;
 use roc_problem::can::{
-    BadPattern, CycleEntry, ExtensionTypeKind, FloatErrorKind, IntErrorKind, Problem, RuntimeError,
-    ShadowKind,
+    BadPattern, BindingKind, CycleEntry, ExtensionTypeKind, FloatErrorKind, IntErrorKind, Problem,
+    RuntimeError, ShadowKind,
 };
 use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Loc, Region};
 use roc_types::types::AliasKind;
 use std::path::PathBuf;
 
+use crate::error::parse::to_syntax_report;
 use crate::error::r#type::suggest;
+use crate::error_code::{self, ErrorCode};
+use crate::fix::{self, Applicability, Fix};
 use crate::report::{to_file_problem_report, Annotation, Report, RocDocAllocator, RocDocBuilder};
 use ven_pretty::{text, DocAllocator};
 
@@ -73,7 +78,10 @@ pub fn can_problem<'b>(
 ) -> Report<'b> {
     let doc;
     let title;
+    let error_code;
     let severity = problem.severity();
+    let safe = safe_fixes(alloc, lines, &problem);
+    let mut fixes = Vec::new();
 
     match problem {
         Problem::UnusedDef(symbol, region) => {
@@ -92,6 +100,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            error_code = error_code::R0202;
         }
         Problem::UnusedImport(symbol, region) => {
             doc = alloc.stack([
@@ -108,6 +117,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_IMPORT.to_string();
+            error_code = error_code::R0203;
         }
         Problem::UnusedModuleImport(module_id, region) => {
             doc = alloc.stack([
@@ -124,6 +134,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_IMPORT.to_string();
+            error_code = error_code::R0203;
         }
         Problem::ImportNameConflict {
             name,
@@ -184,6 +195,7 @@ pub fn can_problem<'b>(
                 },
             ]);
             title = IMPORT_NAME_CONFLICT.to_string();
+            error_code = error_code::R0204;
         }
 
         Problem::ExplicitBuiltinImport(module_id, region) => {
@@ -199,6 +211,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = EXPLICIT_BUILTIN_IMPORT.to_string();
+            error_code = error_code::R0205;
         }
 
         Problem::ExplicitBuiltinTypeImport(symbol, region) => {
@@ -217,6 +230,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = EXPLICIT_BUILTIN_IMPORT.to_string();
+            error_code = error_code::R0205;
         }
 
         Problem::ImportShadowsSymbol {
@@ -244,6 +258,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_NAME.to_string();
+            error_code = error_code::R0217;
         }
 
         Problem::DefsOnlyUsedInRecursion(1, region) => {
@@ -256,6 +271,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "DEFINITION ONLY USED IN RECURSION".to_string();
+            error_code = error_code::R0230;
         }
         Problem::DefsOnlyUsedInRecursion(n, region) => {
             doc = alloc.stack([
@@ -271,6 +287,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = "DEFINITIONs ONLY USED IN RECURSION".to_string();
+            error_code = error_code::R0230;
         }
         Problem::ExposedButNotDefined(symbol) => {
             doc = alloc.stack([
@@ -286,6 +303,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = MISSING_DEFINITION.to_string();
+            error_code = error_code::R0211;
         }
         Problem::UnknownGeneratesWith(loc_ident) => {
             doc = alloc.stack([
@@ -300,8 +318,9 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNKNOWN_GENERATES_WITH.to_string();
+            error_code = error_code::R0212;
         }
-        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, region) => {
+        Problem::UnusedArgument(closure_symbol, is_anonymous, argument_symbol, _, region) => {
             let line = "\". Adding an underscore at the start of a variable name is a way of saying that the variable is not used.";
 
             doc = alloc.stack([
@@ -334,8 +353,9 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ARG.to_string();
+            error_code = error_code::R0210;
         }
-        Problem::UnusedBranchDef(symbol, region) => {
+        Problem::UnusedBranchDef(symbol, _, region) => {
             doc = alloc.stack([
                 alloc.concat([
                     alloc.symbol_unqualified(symbol),
@@ -354,6 +374,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_DEF.to_string();
+            error_code = error_code::R0202;
         }
         Problem::PrecedenceProblem(BothNonAssociative(region, left_bin_op, right_bin_op)) => {
             doc = alloc.stack([
@@ -382,6 +403,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            error_code = error_code::R0100;
         }
         Problem::UnsupportedPattern(BadPattern::Unsupported(pattern_type), region) => {
             use roc_parse::pattern::PatternType::*;
@@ -412,6 +434,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            error_code = error_code::R0100;
         }
         Problem::Shadowing {
             original_region,
@@ -423,6 +446,7 @@ pub fn can_problem<'b>(
 
             doc = res_doc;
             title = res_title.to_string();
+            error_code = error_code::R0217;
         }
         Problem::CyclicAlias(symbol, region, others, alias_kind) => {
             let answer = crate::error::r#type::cyclic_alias(
@@ -431,6 +455,7 @@ pub fn can_problem<'b>(
 
             doc = answer.0;
             title = answer.1;
+            error_code = error_code::R0303;
         }
         Problem::PhantomTypeArgument {
             typ: alias,
@@ -458,6 +483,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = UNUSED_ALIAS_PARAM.to_string();
+            error_code = error_code::R0206;
         }
         Problem::WildcardNotAllowed {
             typ: alias,
@@ -498,6 +524,7 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = WILDCARD_NOT_ALLOWED.to_string();
+            error_code = error_code::R0208;
         }
         Problem::UnderscoreNotAllowed {
             typ: alias,
@@ -539,6 +566,7 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = UNDERSCORE_NOT_ALLOWED.to_string();
+            error_code = error_code::R0209;
         }
         Problem::UndeclaredTypeVar {
             typ: alias,
@@ -585,10 +613,12 @@ pub fn can_problem<'b>(
             doc = alloc.stack(stack);
 
             title = UNDECLARED_TYPE_VARIABLE.to_string();
+            error_code = error_code::R0207;
         }
         Problem::BadRecursion(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries);
             title = CIRCULAR_DEF.to_string();
+            error_code = error_code::R0216;
         }
         Problem::DuplicateRecordFieldValue {
             field_name,
@@ -623,6 +653,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            error_code = error_code::R0213;
         }
         Problem::InvalidOptionalValue {
             field_name,
@@ -671,6 +702,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_FIELD_NAME.to_string();
+            error_code = error_code::R0213;
         }
         Problem::DuplicateTag {
             tag_name,
@@ -705,6 +737,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = DUPLICATE_TAG_NAME.to_string();
+            error_code = error_code::R0214;
         }
         Problem::SignatureDefMismatch {
             ref annotation_pattern,
@@ -721,6 +754,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NAMING_PROBLEM.to_string();
+            error_code = error_code::R0200;
         }
         Problem::InvalidAliasRigid {
             alias_name: type_name,
@@ -743,6 +777,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            error_code = error_code::R0100;
         }
         Problem::InvalidHexadecimal(region) => {
             doc = alloc.stack([
@@ -759,6 +794,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            error_code = error_code::R0215;
         }
         Problem::InvalidUnicodeCodePt(region) => {
             doc = alloc.stack([
@@ -768,6 +804,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_UNICODE.to_string();
+            error_code = error_code::R0215;
         }
         Problem::InvalidInterpolation(region) => {
             doc = alloc.stack([
@@ -778,12 +815,16 @@ pub fn can_problem<'b>(
             ]);
 
             title = SYNTAX_PROBLEM.to_string();
+            error_code = error_code::R0100;
         }
         Problem::RuntimeError(runtime_error) => {
+            fixes = runtime_error_fixes(alloc, lines, &runtime_error);
+
             let answer = pretty_runtime_error(alloc, lines, runtime_error);
 
             doc = answer.0;
             title = answer.1.to_string();
+            error_code = answer.2;
         }
         Problem::NestedDatatype {
             alias,
@@ -811,6 +852,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = NESTED_DATATYPE.to_string();
+            error_code = error_code::R0221;
         }
 
         Problem::InvalidExtensionType { region, kind } => {
@@ -838,6 +880,7 @@ pub fn can_problem<'b>(
             ]);
 
             title = INVALID_EXTENSION_TYPE.to_string();
+            error_code = error_code::R0229;
         }
 
         Problem::AbilityHasTypeVariables {
@@ -856,6 +899,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = ABILITY_HAS_TYPE_VARIABLES.to_string();
+            error_code = error_code::R0250;
         }
 
         Problem::ImplementsClauseIsNotAbility {
@@ -868,6 +912,7 @@ pub fn can_problem<'b>(
                 alloc.region(lines.convert_region(clause_region)),
             ]);
             title = IMPLEMENTS_CLAUSE_IS_NOT_AN_ABILITY.to_string();
+            error_code = error_code::R0251;
         }
 
         Problem::IllegalImplementsClause { region } => {
@@ -886,6 +931,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = ILLEGAL_IMPLEMENTS_CLAUSE.to_string();
+            error_code = error_code::R0252;
         }
 
         Problem::DuplicateImplementsAbility { ability, region } => {
@@ -903,6 +949,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "DUPLICATE BOUND ABILITY".to_string();
+            error_code = error_code::R0253;
         }
 
         Problem::AbilityMemberMissingImplementsClause {
@@ -937,6 +984,7 @@ pub fn can_problem<'b>(
                     .reflow("Otherwise, the function does not need to be part of the ability!")]),
             ]);
             title = ABILITY_MEMBER_MISSING_IMPLEMENTS_CLAUSE.to_string();
+            error_code = error_code::R0254;
         }
 
         Problem::AbilityMemberMultipleBoundVars {
@@ -964,6 +1012,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = ABILITY_MEMBER_BINDS_MULTIPLE_VARIABLES.to_string();
+            error_code = error_code::R0255;
         }
 
         Problem::AbilityNotOnToplevel { region } => {
@@ -975,6 +1024,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Abilities can only be defined on the top-level of a Roc module."),
             ]);
             title = ABILITY_NOT_ON_TOPLEVEL.to_string();
+            error_code = error_code::R0256;
         }
 
         Problem::AbilityUsedAsType(suggested_var_name, ability, region) => {
@@ -1002,6 +1052,7 @@ pub fn can_problem<'b>(
                 ])),
             ]);
             title = ABILITY_USED_AS_TYPE.to_string();
+            error_code = error_code::R0258;
         }
         Problem::NestedSpecialization(member, region) => {
             doc = alloc.stack([
@@ -1014,6 +1065,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Specializations can only be defined on the top-level of a module."),
             ]);
             title = SPECIALIZATION_NOT_ON_TOPLEVEL.to_string();
+            error_code = error_code::R0257;
         }
         Problem::IllegalDerivedAbility(region) => {
             doc = alloc.stack([
@@ -1025,6 +1077,7 @@ pub fn can_problem<'b>(
                     .append(list_builtin_abilities(alloc)),
             ]);
             title = ILLEGAL_DERIVE.to_string();
+            error_code = error_code::R0259;
         }
         Problem::NotAnAbility(region) => {
             doc = alloc.stack([
@@ -1033,6 +1086,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only abilities can be implemented."),
             ]);
             title = NOT_AN_ABILITY.to_string();
+            error_code = error_code::R0262;
         }
         Problem::NotAnAbilityMember {
             ability,
@@ -1047,6 +1101,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Only implementations for members an ability has can be specified in this location.")
             ]);
             title = NOT_AN_ABILITY_MEMBER.to_string();
+            error_code = error_code::R0261;
         }
         Problem::ImplementationNotFound { member, region } => {
             let member_str = member.as_str(alloc.interns);
@@ -1058,6 +1113,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.concat([alloc.reflow("consider adding a value of name "), alloc.symbol_unqualified(member), alloc.reflow(" in this scope, or using another variable that implements this ability member, like "), alloc.type_str(&format!("{{ {member_str}: my{member_str} }}"))]))
             ]);
             title = IMPLEMENTATION_NOT_FOUND.to_string();
+            error_code = error_code::R0260;
         }
         Problem::OptionalAbilityImpl { ability, region } => {
             let hint = if ability.is_builtin() {
@@ -1076,6 +1132,7 @@ pub fn can_problem<'b>(
                 hint,
             ]);
             title = OPTIONAL_ABILITY_IMPLEMENTATION.to_string();
+            error_code = error_code::R0263;
         }
         Problem::QualifiedAbilityImpl { region } => {
             doc = alloc.stack([
@@ -1086,6 +1143,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = QUALIFIED_ABILITY_IMPLEMENTATION.to_string();
+            error_code = error_code::R0264;
         }
         Problem::AbilityImplNotIdent { region } => {
             doc = alloc.stack([
@@ -1097,6 +1155,7 @@ pub fn can_problem<'b>(
                 alloc.tip().append(alloc.reflow("consider defining this expression as a variable."))
             ]);
             title = ABILITY_IMPLEMENTATION_NOT_IDENTIFIER.to_string();
+            error_code = error_code::R0265;
         }
         Problem::DuplicateImpl {
             original,
@@ -1111,6 +1170,7 @@ pub fn can_problem<'b>(
                     .reflow("Only one custom implementation can be defined for an ability member."),
             ]);
             title = DUPLICATE_IMPLEMENTATION.to_string();
+            error_code = error_code::R0266;
         }
         Problem::ImplementsNonRequired {
             region,
@@ -1135,6 +1195,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = UNNECESSARY_IMPLEMENTATIONS.to_string();
+            error_code = error_code::R0267;
         }
         Problem::DoesNotImplementAbility {
             region,
@@ -1159,6 +1220,7 @@ pub fn can_problem<'b>(
                 ),
             ]);
             title = INCOMPLETE_ABILITY_IMPLEMENTATION.to_string();
+            error_code = error_code::R0268;
         }
         Problem::NotBoundInAllPatterns {
             unbound_symbol,
@@ -1179,6 +1241,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "NAME NOT BOUND IN ALL PATTERNS".to_string();
+            error_code = error_code::R0231;
         }
         Problem::NoIdentifiersIntroduced(region) => {
             doc = alloc.stack([
@@ -1187,6 +1250,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("If you don't need to use the value on the right-hand-side of this assignment, consider removing the assignment. Since Roc is purely functional, assignments that don't introduce variables cannot affect a program's behavior!"),
             ]);
             title = "UNNECESSARY DEFINITION".to_string();
+            error_code = error_code::R0232;
         }
         Problem::OverloadedSpecialization {
             ability_member,
@@ -1206,6 +1270,7 @@ pub fn can_problem<'b>(
                 alloc.reflow("Ability specializations can only provide implementations for one opaque type, since all opaque types are different!"),
             ]);
             title = "OVERLOADED SPECIALIZATION".to_string();
+            error_code = error_code::R0269;
         }
         Problem::UnnecessaryOutputWildcard { region } => {
             doc = alloc.stack([
@@ -1224,7 +1289,9 @@ pub fn can_problem<'b>(
                 ]),
                 alloc.reflow("You can safely remove this to make the code more concise without changing what it means."),
             ]);
+
             title = "UNNECESSARY WILDCARD".to_string();
+            error_code = error_code::R0233;
         }
         Problem::MultipleListRestPattern { region } => {
             doc = alloc.stack([
@@ -1237,6 +1304,7 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "MULTIPLE LIST REST PATTERNS".to_string();
+            error_code = error_code::R0234;
         }
         Problem::BadTypeArguments {
             symbol,
@@ -1271,10 +1339,10 @@ pub fn can_problem<'b>(
                 alloc.reflow("Are there missing parentheses?"),
             ]);
 
-            title = if type_got > alias_needs {
-                "TOO MANY TYPE ARGUMENTS".to_string()
+            (title, error_code) = if type_got > alias_needs {
+                ("TOO MANY TYPE ARGUMENTS".to_string(), error_code::R0235)
             } else {
-                "TOO FEW TYPE ARGUMENTS".to_string()
+                ("TOO FEW TYPE ARGUMENTS".to_string(), error_code::R0236)
             };
         }
         Problem::UnappliedCrash { region } => {
@@ -1289,6 +1357,7 @@ pub fn can_problem<'b>(
                 ])
             ]);
             title = "UNAPPLIED CRASH".to_string();
+            error_code = error_code::R0237;
         }
         Problem::OverAppliedCrash { region } => {
             doc = alloc.stack([
@@ -1304,20 +1373,230 @@ pub fn can_problem<'b>(
                 ]),
             ]);
             title = "OVERAPPLIED CRASH".to_string();
+            error_code = error_code::R0238;
         }
-        Problem::FileProblem { filename, error } => {
-            let report = to_file_problem_report(alloc, filename, error);
-            doc = report.doc;
-            title = report.title;
+        Problem::FileProblem {
+            filename: path,
+            error,
+        } => {
+            let report = to_file_problem_report(alloc, path, error);
+
+            return Report {
+                filename,
+                severity,
+                ..report
+            };
+        }
+        Problem::SyntaxError { region } => {
+            let src = alloc.src_lines.join("\n");
+            let arena = Bump::new();
+
+            match recovered_error_at(&arena, src.as_bytes(), region) {
+                Some(error) => {
+                    let report = to_syntax_report(alloc, lines, filename, &error);
+
+                    return Report { severity, ..report };
+                }
+                None => {
+                    // The source must have changed since it was parsed
+                    doc = alloc.stack([
+                        alloc.reflow("I got stuck parsing this code:"),
+                        alloc.region(lines.convert_region(region)),
+                    ]);
+                    title = "PARSE PROBLEM".to_string();
+                    error_code = error_code::R0101;
+                }
+            }
         }
     };
 
+    if let Some((safe_code, safe_fixes)) = safe {
+        debug_assert_eq!(safe_code.code, error_code.code);
+
+        fixes.extend(safe_fixes);
+    }
+
     Report {
         title,
         filename,
         doc,
         severity,
+        fixes,
+        error_code: Some(error_code),
+    }
+}
+
+/// The fixes for a problem that never change what the program means, along with the code of
+/// the report they belong to. Only warnings have any.
+///
+/// These are worked out from the problem alone, so `roc check --fix` doesn't need to build
+/// a report for every problem just to find them.
+pub fn safe_fixes(
+    alloc: &RocDocAllocator<'_>,
+    lines: &LineInfo,
+    problem: &Problem,
+) -> Option<(&'static ErrorCode, Vec<Fix>)> {
+    let (error_code, fix) = match *problem {
+        Problem::UnusedImport(_, region) => (
+            error_code::R0203,
+            fix::list_element(alloc.src_lines, lines, region)
+                .map(|region| Fix::delete("Remove unused import", region, Applicability::Safe)),
+        ),
+        Problem::UnusedModuleImport(_, region) => (
+            error_code::R0203,
+            fix::whole_lines(alloc.src_lines, lines, region)
+                .map(|region| Fix::delete("Remove unused import", region, Applicability::Safe)),
+        ),
+        Problem::ExplicitBuiltinImport(_, region) => (
+            error_code::R0205,
+            fix::whole_lines(alloc.src_lines, lines, region)
+                .map(|region| Fix::delete("Remove builtin import", region, Applicability::Safe)),
+        ),
+        Problem::ExplicitBuiltinTypeImport(_, region) => (
+            error_code::R0205,
+            fix::list_element(alloc.src_lines, lines, region)
+                .map(|region| Fix::delete("Remove builtin import", region, Applicability::Safe)),
+        ),
+        Problem::UnusedArgument(_, _, symbol, kind, region) => (
+            error_code::R0210,
+            ignore_unused(alloc, lines, symbol, kind, region),
+        ),
+        Problem::UnusedBranchDef(symbol, kind, region) => (
+            error_code::R0202,
+            ignore_unused(alloc, lines, symbol, kind, region),
+        ),
+        Problem::UnnecessaryOutputWildcard { region } => (
+            error_code::R0233,
+            (fix::region_text(alloc.src_lines, lines, region) == Some("*"))
+                .then(|| Fix::delete("Remove wildcard", region, Applicability::Safe)),
+        ),
+        _ => return None,
+    };
+
+    Some((error_code, fix.into_iter().collect()))
+}
+
+/// Prefix an unused name with an underscore, unless doing so would rename a record field.
+fn ignore_unused(
+    alloc: &RocDocAllocator<'_>,
+    lines: &LineInfo,
+    symbol: Symbol,
+    kind: BindingKind,
+    region: Region,
+) -> Option<Fix> {
+    let name = symbol.as_str(alloc.interns);
+
+    if kind == BindingKind::RecordField
+        || fix::region_text(alloc.src_lines, lines, region) != Some(name)
+    {
+        return None;
+    }
+
+    Some(Fix::insert(
+        format!("Rename to _{name}"),
+        region.start(),
+        "_",
+        Applicability::Safe,
+    ))
+}
+
+fn runtime_error_fixes(
+    alloc: &RocDocAllocator<'_>,
+    lines: &LineInfo,
+    runtime_error: &RuntimeError,
+) -> Vec<Fix> {
+    let mut fixes = Vec::new();
+
+    match runtime_error {
+        RuntimeError::LookupNotInScope {
+            loc_name,
+            suggestion_options,
+            underscored_suggestion_region,
+        } => {
+            let name = loc_name.value.as_str();
+
+            if fix::region_text(alloc.src_lines, lines, loc_name.region) != Some(name) {
+                return fixes;
+            }
+
+            if let Some(underscored_region) = underscored_suggestion_region {
+                if fix::region_text(alloc.src_lines, lines, *underscored_region)
+                    == Some(&format!("_{name}"))
+                {
+                    fixes.push(Fix::replace(
+                        format!("Rename _{name} to {name}"),
+                        *underscored_region,
+                        name,
+                        Applicability::Suggestion,
+                    ));
+                }
+            }
+
+            for suggestion in name_suggestions(name, suggestion_options) {
+                fixes.push(Fix::replace(
+                    format!("Change to {suggestion}"),
+                    loc_name.region,
+                    suggestion,
+                    Applicability::Suggestion,
+                ));
+            }
+        }
+        RuntimeError::ValueNotExposed {
+            ident,
+            region,
+            exposed_values,
+            ..
+        } => {
+            let Some(qualifier) = fix::region_text(alloc.src_lines, lines, *region)
+                .and_then(|text| text.strip_suffix(ident.as_str()))
+                .filter(|qualifier| qualifier.ends_with('.'))
+            else {
+                return fixes;
+            };
+
+            let mut suggestions = suggest::sort(ident.as_ref(), exposed_values.iter().collect());
+            suggestions.truncate(4);
+
+            for suggestion in suggestions {
+                fixes.push(Fix::replace(
+                    format!("Change to {qualifier}{suggestion}"),
+                    *region,
+                    format!("{qualifier}{suggestion}"),
+                    Applicability::Suggestion,
+                ));
+            }
+        }
+        RuntimeError::ModuleNotImported {
+            module_name,
+            module_exists: true,
+            ..
+        } => {
+            // Add the import right after the last top-level one, if there is any
+            let last_import = alloc
+                .src_lines
+                .iter()
+                .rposition(|line| line.starts_with("import "));
+
+            if let Some(line) = last_import {
+                if line + 1 < lines.num_lines() as usize {
+                    let pos = lines.convert_line_column(LineColumn {
+                        line: line as u32 + 1,
+                        column: 0,
+                    });
+
+                    fixes.push(Fix::insert(
+                        format!("Import {module_name}"),
+                        pos,
+                        format!("import {module_name}\n"),
+                        Applicability::Suggestion,
+                    ));
+                }
+            }
+        }
+        _ => {}
     }
+
+    fixes
 }
 
 fn list_builtin_abilities<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<'a> {
@@ -1347,9 +1626,11 @@ fn to_invalid_optional_value_report<'b>(
 
     Report {
         title: "BAD OPTIONAL VALUE".to_string(),
+        error_code: Some(error_code::R0239),
         filename,
         doc,
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -1804,9 +2085,10 @@ fn pretty_runtime_error<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
     runtime_error: RuntimeError,
-) -> (RocDocBuilder<'b>, &'static str) {
+) -> (RocDocBuilder<'b>, &'static str, &'static ErrorCode) {
     let doc;
     let title;
+    let error_code;
 
     match runtime_error {
         RuntimeError::VoidValue => {
@@ -1826,6 +2108,7 @@ fn pretty_runtime_error<'b>(
             kind,
         } => {
             (title, doc) = report_shadowing(alloc, lines, original_region, shadow, kind);
+            error_code = error_code::R0217;
         }
 
         RuntimeError::LookupNotInScope {
@@ -1842,10 +2125,12 @@ fn pretty_runtime_error<'b>(
                 underscored_suggestion_region,
             );
             title = UNRECOGNIZED_NAME;
+            error_code = error_code::R0201;
         }
         RuntimeError::CircularDef(entries) => {
             doc = to_circular_def_doc(alloc, lines, &entries);
             title = CIRCULAR_DEF;
+            error_code = error_code::R0216;
         }
         RuntimeError::MalformedPattern(problem, region) => {
             use roc_parse::ast::Base;
@@ -1860,9 +2145,10 @@ fn pretty_runtime_error<'b>(
                 MalformedBase(Base::Decimal) => " integer ",
                 BadIdent(bad_ident) => {
                     title = NAMING_PROBLEM;
+                    error_code = error_code::R0200;
                     doc = to_bad_ident_pattern_report(alloc, lines, bad_ident, region);
 
-                    return (doc, title);
+                    return (doc, title, error_code);
                 }
                 Unknown => " ",
                 QualifiedIdentifier => " qualified ",
@@ -1897,6 +2183,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::UnsupportedPattern(_) => {
             todo!("unsupported patterns are currently not parsed!")
@@ -1938,6 +2225,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = VALUE_NOT_EXPOSED;
+            error_code = error_code::R0218;
         }
 
         RuntimeError::ModuleNotImported {
@@ -1956,6 +2244,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = MODULE_NOT_IMPORTED;
+            error_code = error_code::R0219;
         }
         RuntimeError::ReadIngestedFileError {
             filename,
@@ -1966,6 +2255,7 @@ fn pretty_runtime_error<'b>(
 
             doc = report.doc;
             title = INGESTED_FILE_ERROR;
+            error_code = error_code::R0220;
         }
         RuntimeError::InvalidPrecedence(_, _) => {
             // do nothing, reported with PrecedenceProblem
@@ -1975,6 +2265,7 @@ fn pretty_runtime_error<'b>(
             doc = to_bad_ident_expr_report(alloc, lines, bad_ident, surroundings);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::MalformedTypeName(_box_str, surroundings) => {
             doc = alloc.stack([
@@ -1991,6 +2282,16 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
+        }
+        RuntimeError::MalformedExpr(region) => {
+            doc = alloc.stack([
+                alloc.reflow("This code has a syntax error:"),
+                alloc.region(lines.convert_region(region)),
+            ]);
+
+            title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::MalformedClosure(_) => {
             todo!("");
@@ -2028,6 +2329,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidFloat(FloatErrorKind::Error, region, _raw_str) => {
             let tip = alloc
@@ -2046,6 +2348,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidFloat(FloatErrorKind::IntSuffix, region, _raw_str) => {
             doc = alloc.stack([
@@ -2056,6 +2359,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            error_code = error_code::R0222;
         }
         RuntimeError::InvalidInt(error @ IntErrorKind::InvalidDigit, base, region, _raw_str)
         | RuntimeError::InvalidInt(error @ IntErrorKind::Empty, base, region, _raw_str) => {
@@ -2117,6 +2421,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidInt(error_kind @ IntErrorKind::Underflow, _base, region, _raw_str)
         | RuntimeError::InvalidInt(error_kind @ IntErrorKind::Overflow, _base, region, _raw_str) => {
@@ -2160,6 +2465,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidInt(IntErrorKind::FloatSuffix, _base, region, _raw_str) => {
             doc = alloc.stack([
@@ -2170,6 +2476,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = CONFLICTING_NUMBER_SUFFIX;
+            error_code = error_code::R0222;
         }
         RuntimeError::InvalidInt(
             IntErrorKind::OverflowsSuffix {
@@ -2194,6 +2501,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_OVERFLOWS_SUFFIX;
+            error_code = error_code::R0223;
         }
         RuntimeError::InvalidInt(
             IntErrorKind::UnderflowsSuffix {
@@ -2218,6 +2526,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = NUMBER_UNDERFLOWS_SUFFIX;
+            error_code = error_code::R0224;
         }
         RuntimeError::InvalidOptionalValue {
             field_name,
@@ -2233,6 +2542,7 @@ fn pretty_runtime_error<'b>(
             );
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidRecordUpdate { region } => {
             doc = alloc.stack([
@@ -2245,6 +2555,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::InvalidHexadecimal(region) => {
             todo!(
@@ -2278,6 +2589,7 @@ fn pretty_runtime_error<'b>(
                 .append(alloc.reflow(", but it was not defined anywhere in that module."))]);
 
             title = MISSING_DEFINITION;
+            error_code = error_code::R0211;
         }
         RuntimeError::EmptySingleQuote(region) => {
             let tip = alloc
@@ -2291,6 +2603,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::MultipleCharsInSingleQuote(region) => {
             let tip = alloc
@@ -2307,6 +2620,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = SYNTAX_PROBLEM;
+            error_code = error_code::R0100;
         }
         RuntimeError::OpaqueNotDefined {
             usage:
@@ -2357,6 +2671,7 @@ fn pretty_runtime_error<'b>(
             doc = alloc.stack(stack);
 
             title = OPAQUE_NOT_DEFINED;
+            error_code = error_code::R0225;
         }
         RuntimeError::OpaqueOutsideScope {
             opaque,
@@ -2378,6 +2693,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_DECLARED_OUTSIDE_SCOPE;
+            error_code = error_code::R0226;
         }
         RuntimeError::OpaqueNotApplied(loc_ident) => {
             doc = alloc.stack([
@@ -2387,6 +2703,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_NOT_APPLIED;
+            error_code = error_code::R0227;
         }
         RuntimeError::OpaqueAppliedToMultipleArgs(region) => {
             doc = alloc.stack([
@@ -2396,6 +2713,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = OPAQUE_OVER_APPLIED;
+            error_code = error_code::R0228;
         }
         RuntimeError::DegenerateBranch(region) => {
             doc = alloc.stack([
@@ -2404,6 +2722,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "DEGENERATE BRANCH";
+            error_code = error_code::R0240;
         }
         RuntimeError::MultipleRecordBuilders(region) => {
             let tip = alloc
@@ -2418,6 +2737,7 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "MULTIPLE RECORD BUILDERS";
+            error_code = error_code::R0241;
         }
         RuntimeError::UnappliedRecordBuilder(region) => {
             doc = alloc.stack([
@@ -2430,10 +2750,11 @@ fn pretty_runtime_error<'b>(
             ]);
 
             title = "UNAPPLIED RECORD BUILDER";
+            error_code = error_code::R0242;
         }
     }
 
-    (doc, title)
+    (doc, title, error_code)
 }
 
 pub fn to_circular_def_doc<'b>(
@@ -2485,6 +2806,13 @@ pub fn to_circular_def_doc<'b>(
     }
 }
 
+/// The (at most four) names in scope that are closest to a name which isn't.
+fn name_suggestions<'a>(name: &str, options: &'a MutSet<Box<str>>) -> Vec<&'a str> {
+    let mut suggestions = suggest::sort(name, options.iter().map(|v| v.as_ref()).collect());
+    suggestions.truncate(4);
+    suggestions
+}
+
 fn not_found<'b>(
     alloc: &'b RocDocAllocator<'b>,
     lines: &LineInfo,
@@ -2493,11 +2821,7 @@ fn not_found<'b>(
     options: MutSet<Box<str>>,
     underscored_suggestion_region: Option<Region>,
 ) -> RocDocBuilder<'b> {
-    let mut suggestions = suggest::sort(
-        name.as_inline_str().as_str(),
-        options.iter().map(|v| v.as_ref()).collect(),
-    );
-    suggestions.truncate(4);
+    let suggestions = name_suggestions(name.as_str(), &options);
 
     let default_no = alloc.concat([
         alloc.reflow("Is there an "),
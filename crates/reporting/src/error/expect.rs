@@ -10,6 +10,7 @@ use roc_types::{
     types::{ErrorType, Polarity},
 };
 
+use crate::error_code;
 use crate::report::{RenderTarget, RocDocAllocator, RocDocBuilder};
 
 pub struct Renderer<'a> {
@@ -153,9 +154,11 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT FAILED".into(),
+            error_code: Some(error_code::R0600),
             doc,
             filename: self.filename.clone(),
             severity: Severity::RuntimeError,
+            fixes: Vec::new(),
         };
 
         let mut buf = String::new();
@@ -224,9 +227,11 @@ impl<'a> Renderer<'a> {
 
         let report = Report {
             title: "EXPECT PANICKED".into(),
+            error_code: Some(error_code::R0601),
             doc,
             filename: self.filename.clone(),
             severity: Severity::RuntimeError,
+            fixes: Vec::new(),
         };
 
         let mut buf = String::new();
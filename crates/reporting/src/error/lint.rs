@@ -0,0 +1,229 @@
+use roc_lint::{Lint, TryCallback, TryKind, TryRewrite};
+use roc_module::symbol::Symbol;
+use roc_problem::Severity;
+use roc_region::all::LineInfo;
+use std::path::PathBuf;
+
+use crate::error_code;
+use crate::fix::{Applicability, Fix};
+use crate::report::{Annotation, Report, RocDocAllocator};
+use ven_pretty::DocAllocator;
+
+const SHADOWED_BUILTIN: &str = "SHADOWED BUILTIN";
+const LEFTOVER_DBG: &str = "LEFTOVER DBG";
+const CRASH_IN_LIBRARY: &str = "CRASH IN LIBRARY";
+const REDUNDANT_TRY: &str = "REDUNDANT TRY";
+const TRY_CHAIN: &str = "TRY CHAIN";
+const REPEATED_WHEN: &str = "REPEATED WHEN";
+const CATCH_ALL_ON_TAG_UNION: &str = "CATCH-ALL ON TAG UNION";
+
+pub fn lint_problem<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    filename: PathBuf,
+    lint: Lint,
+) -> Report<'b> {
+    let doc;
+    let title;
+    let error_code;
+    let mut fixes = Vec::new();
+
+    match lint {
+        Lint::ShadowedBuiltin {
+            symbol,
+            builtin,
+            region,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" has the same name as "),
+                    alloc.symbol_qualified(builtin),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("Since "),
+                    alloc.symbol_qualified(builtin),
+                    alloc.reflow(" is only in scope with its module name, this is a different value that just looks like the builtin. Give it a name that doesn't, so nobody mixes the two up."),
+                ]),
+            ]);
+
+            title = SHADOWED_BUILTIN;
+            error_code = error_code::R0500;
+        }
+        Lint::LeftoverDbg { region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("dbg"),
+                    alloc.reflow(" is still in the code:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.keyword("dbg"),
+                    alloc.reflow(" is meant for debugging. Remove it once you're done, so it doesn't print every time this code runs."),
+                ]),
+            ]);
+
+            title = LEFTOVER_DBG;
+            error_code = error_code::R0501;
+        }
+        Lint::CrashInLibrary { region } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("crash"),
+                    alloc.reflow(" is in a library module:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.reflow("A library can't know whether crashing is acceptable for the apps that use it. Return an error instead, and let each app decide what to do about it."),
+            ]);
+
+            title = CRASH_IN_LIBRARY;
+            error_code = error_code::R0502;
+        }
+        Lint::RedundantTry { region, callback } => {
+            let hint = match callback {
+                TryCallback::Identity => alloc.reflow("Its callback only wraps what it's given in an `Ok` again, so this is the same as the `Result` it's called with. Use that instead."),
+                TryCallback::Map => alloc.concat([
+                    alloc.reflow("Its callback always returns an `Ok`, so this is the same as calling "),
+                    alloc.symbol_qualified(Symbol::RESULT_MAP),
+                    alloc.reflow(" with a callback that doesn't wrap its answer in `Ok`."),
+                ]),
+            };
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.symbol_qualified(Symbol::RESULT_TRY),
+                    alloc.reflow(" can never fail in its callback:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                hint,
+            ]);
+
+            title = REDUNDANT_TRY;
+            error_code = error_code::R0503;
+        }
+        Lint::TryChain {
+            region,
+            kind,
+            steps,
+            rewrite,
+        } => {
+            let (call, suffix) = match kind {
+                TryKind::Result => (alloc.symbol_qualified(Symbol::RESULT_TRY), "?"),
+                TryKind::Task => (alloc.keyword("Task.await"), "!"),
+            };
+            let hint = match rewrite {
+                Some(TryRewrite {
+                    lines: defs,
+                    region: fix_region,
+                    replacement,
+                }) => {
+                    fixes.push(Fix::replace(
+                        format!("Use `{suffix}`"),
+                        fix_region,
+                        replacement,
+                        Applicability::Suggestion,
+                    ));
+
+                    alloc.stack([
+                        alloc.concat([
+                            alloc.reflow("Each call can be a def that uses "),
+                            alloc.keyword(suffix),
+                            alloc.reflow(" instead:"),
+                        ]),
+                        alloc
+                            .vcat(defs.into_iter().map(|line| {
+                                alloc.string(line).annotate(Annotation::ParserSuggestion)
+                            }))
+                            .indent(4),
+                    ])
+                }
+                None => alloc.concat([
+                    alloc.reflow("Each call can be a def that uses "),
+                    alloc.keyword(suffix),
+                    alloc.reflow(" instead, with the rest of the chain after it."),
+                ]),
+            };
+
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("These "),
+                    alloc.text(steps.to_string()),
+                    alloc.reflow(" "),
+                    call,
+                    alloc.reflow(" calls are chained together:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                hint,
+            ]);
+
+            title = TRY_CHAIN;
+            error_code = error_code::R0506;
+        }
+        Lint::RepeatedWhen {
+            region,
+            symbol,
+            whens,
+        } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("when"),
+                    alloc.reflow(" on "),
+                    alloc.symbol_unqualified(symbol),
+                    alloc.reflow(" has "),
+                    match whens {
+                        2 => alloc.reflow("another one"),
+                        _ => alloc.text(format!("{} more", whens - 1)),
+                    },
+                    alloc.reflow(" nested in its catch-all branch:"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("These can all be a single "),
+                    alloc.keyword("when"),
+                    alloc.reflow(", which keeps every case in one place."),
+                ]),
+            ]);
+
+            title = REPEATED_WHEN;
+            error_code = error_code::R0504;
+        }
+        Lint::CatchAllOnTagUnion { region, tags } => {
+            doc = alloc.stack([
+                alloc.concat([
+                    alloc.reflow("This "),
+                    alloc.keyword("_"),
+                    alloc.reflow(" matches "),
+                    alloc.intersperse(tags.into_iter().map(|tag| alloc.tag_name(tag)), ", "),
+                    alloc.reflow(":"),
+                ]),
+                alloc.region(lines.convert_region(region)),
+                alloc.concat([
+                    alloc.reflow("If a tag is added to this union later, it will end up in this branch too, without "),
+                    alloc.keyword("roc check"),
+                    alloc.reflow(" pointing out that this "),
+                    alloc.keyword("when"),
+                    alloc.reflow(" might need to handle it. Consider matching each tag explicitly instead."),
+                ]),
+            ]);
+
+            title = CATCH_ALL_ON_TAG_UNION;
+            error_code = error_code::R0505;
+        }
+    }
+
+    Report {
+        title: title.to_string(),
+        error_code: Some(error_code),
+        filename,
+        doc,
+        severity: Severity::Warning,
+        fixes,
+    }
+}
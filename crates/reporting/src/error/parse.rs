@@ -3,6 +3,7 @@ use roc_problem::Severity;
 use roc_region::all::{LineColumn, LineColumnRegion, LineInfo, Position, Region};
 use std::path::PathBuf;
 
+use crate::error_code;
 use crate::report::{Report, RocDocAllocator, RocDocBuilder};
 use ven_pretty::DocAllocator;
 
@@ -52,11 +53,11 @@ fn list_patterns_look_like<'a>(alloc: &'a RocDocAllocator<'a>) -> RocDocBuilder<
     ])
 }
 
-fn to_syntax_report<'a>(
+pub(crate) fn to_syntax_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::SyntaxError<'a>,
+    parse_problem: &roc_parse::parser::SyntaxError<'_>,
 ) -> Report<'a> {
     use SyntaxError::*;
 
@@ -64,7 +65,9 @@ fn to_syntax_report<'a>(
         filename: filename.clone(),
         doc,
         title: "PARSE PROBLEM".to_string(),
+        error_code: Some(error_code::R0101),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     };
 
     match parse_problem {
@@ -78,7 +81,9 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                error_code: Some(error_code::R0101),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         Unexpected(region) => {
@@ -110,7 +115,9 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "NOT END OF FILE".to_string(),
+                error_code: Some(error_code::R0102),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         SyntaxError::Eof(region) => {
@@ -123,7 +130,9 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                error_code: Some(error_code::R0101),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         SyntaxError::OutdentedTooFar => {
@@ -133,7 +142,9 @@ fn to_syntax_report<'a>(
                 filename,
                 doc,
                 title: "PARSE PROBLEM".to_string(),
+                error_code: Some(error_code::R0101),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         Type(typ) => to_type_report(alloc, lines, filename, typ, Position::default()),
@@ -178,7 +189,7 @@ fn to_expr_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EExpr<'a>,
+    parse_problem: &roc_parse::parser::EExpr<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EExpr;
@@ -214,7 +225,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                error_code: Some(error_code::R0103),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -328,7 +341,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNKNOWN OPERATOR".to_string(),
+                error_code: Some(error_code::R0104),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -354,14 +369,17 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IDENTIFIER".to_string(),
+                error_code: Some(error_code::R0105),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
         EExpr::Start(pos) | EExpr::IndentStart(pos) => {
-            let (title, expecting) = match &context {
+            let (title, error_code, expecting) = match &context {
                 Context::InNode { .. } | Context::InDef { .. } => (
                     "MISSING EXPRESSION",
+                    error_code::R0106,
                     alloc.concat([
                         alloc.reflow("I was expecting to see an expression like "),
                         alloc.parser_suggestion("42"),
@@ -372,6 +390,7 @@ fn to_expr_report<'a>(
                 ),
                 Context::InDefFinalExpr { .. } => (
                     "MISSING FINAL EXPRESSION",
+                    error_code::R0107,
                     alloc.stack([
                         alloc.concat([
                             alloc.reflow("This definition is missing a final expression."),
@@ -436,7 +455,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: title.to_string(),
+                error_code: Some(error_code),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -464,7 +485,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "MISSING FINAL EXPRESSION".to_string(),
+                error_code: Some(error_code::R0107),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -495,7 +518,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "SYNTAX PROBLEM".to_string(),
+                error_code: Some(error_code::R0100),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -518,7 +543,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "ARGUMENTS BEFORE EQUALS".to_string(),
+                error_code: Some(error_code::R0103),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -536,7 +563,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD BACKPASSING ARROW".to_string(),
+                error_code: Some(error_code::R0108),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -560,7 +589,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD RECORD BUILDER".to_string(),
+                error_code: Some(error_code::R0109),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -580,7 +611,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "BAD RECORD UPDATE".to_string(),
+                error_code: Some(error_code::R0110),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -646,7 +679,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "INDENT ENDS AFTER EXPRESSION".to_string(),
+                error_code: Some(error_code::R0111),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EExpr::Expect(e_expect, _position) => {
@@ -673,7 +708,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING OPERATOR".to_string(),
+                error_code: Some(error_code::R0112),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EExpr::UnexpectedComma(pos) => {
@@ -690,7 +727,9 @@ fn to_expr_report<'a>(
                 filename,
                 doc,
                 title: "UNEXPECTED COMMA".to_string(),
+                error_code: Some(error_code::R0113),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         _ => todo!("unhandled parse error: {:?}", parse_problem),
@@ -701,7 +740,7 @@ fn to_record_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    _parse_problem: &roc_parse::parser::ERecord<'a>,
+    _parse_problem: &roc_parse::parser::ERecord<'_>,
     pos: Position,
     start: Position,
 ) -> Report<'a> {
@@ -718,7 +757,9 @@ fn to_record_report<'a>(
         filename,
         doc,
         title: "RECORD PARSE PROBLEM".to_string(),
+        error_code: Some(error_code::R0114),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -727,7 +768,7 @@ fn to_lambda_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     _context: Context,
-    parse_problem: &roc_parse::parser::EClosure<'a>,
+    parse_problem: &roc_parse::parser::EClosure<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EClosure;
@@ -753,7 +794,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    error_code: Some(error_code::R0115),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -775,7 +818,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    error_code: Some(error_code::R0116),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -800,7 +845,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD ARROW".to_string(),
+                    error_code: Some(error_code::R0115),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -822,7 +869,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    error_code: Some(error_code::R0116),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -846,7 +895,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED ARGUMENT LIST".to_string(),
+                    error_code: Some(error_code::R0117),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -867,7 +918,9 @@ fn to_lambda_report<'a>(
                     filename,
                     doc,
                     title: "MISSING ARROW".to_string(),
+                    error_code: Some(error_code::R0116),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -948,7 +1001,9 @@ fn to_unfinished_lambda_report<'a>(
         filename,
         doc,
         title: "UNFINISHED FUNCTION".to_string(),
+        error_code: Some(error_code::R0118),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -957,7 +1012,7 @@ fn to_str_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EString<'a>,
+    parse_problem: &roc_parse::parser::EString<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EString;
@@ -1014,7 +1069,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD ESCAPE".to_string(),
+                error_code: Some(error_code::R0119),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::CodePtOpen(pos) | EString::CodePtEnd(pos) => {
@@ -1040,7 +1097,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD CODE POINT".to_string(),
+                error_code: Some(error_code::R0120),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::FormatEnd(pos) => {
@@ -1061,7 +1120,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS FORMAT".to_string(),
+                error_code: Some(error_code::R0121),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::EndlessSingleQuote(pos) => {
@@ -1084,7 +1145,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS SCALAR".to_string(),
+                error_code: Some(error_code::R0122),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::InvalidSingleQuote(e, pos) => {
@@ -1150,7 +1213,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INVALID SCALAR".to_string(),
+                error_code: Some(error_code::R0123),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::EndlessSingleLine(pos) => {
@@ -1173,7 +1238,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                error_code: Some(error_code::R0124),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::ExpectedDoubleQuoteGotSingleQuote(pos) => {
@@ -1197,7 +1264,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "EXPECTED STRING".to_string(),
+                error_code: Some(error_code::R0125),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::EndlessMultiLine(pos) => {
@@ -1220,7 +1289,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "ENDLESS STRING".to_string(),
+                error_code: Some(error_code::R0124),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EString::MultilineInsufficientIndent(pos) => {
@@ -1241,7 +1312,9 @@ fn to_str_report<'a>(
                 filename,
                 doc,
                 title: "INSUFFICIENT INDENT IN MULTI-LINE STRING".to_string(),
+                error_code: Some(error_code::R0126),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
     }
@@ -1251,7 +1324,7 @@ fn to_expr_in_parens_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EInParens<'a>,
+    parse_problem: &roc_parse::parser::EInParens<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EInParens;
@@ -1283,7 +1356,9 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                error_code: Some(error_code::R0127),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EInParens::End(pos) => {
@@ -1307,7 +1382,9 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                error_code: Some(error_code::R0128),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EInParens::Open(pos) => {
@@ -1332,7 +1409,9 @@ fn to_expr_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                error_code: Some(error_code::R0128),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
     }
@@ -1343,7 +1422,7 @@ fn to_list_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EList<'a>,
+    parse_problem: &roc_parse::parser::EList<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EList;
@@ -1382,7 +1461,9 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        error_code: Some(error_code::R0129),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -1415,7 +1496,9 @@ fn to_list_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED LIST".to_string(),
+                        error_code: Some(error_code::R0129),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -1429,7 +1512,7 @@ fn to_dbg_or_expect_report<'a>(
     filename: PathBuf,
     context: Context,
     node: Node,
-    parse_problem: &roc_parse::parser::EExpect<'a>,
+    parse_problem: &roc_parse::parser::EExpect<'_>,
     start: Position,
 ) -> Report<'a> {
     match parse_problem {
@@ -1457,7 +1540,7 @@ fn to_import_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::EImport<'a>,
+    parse_problem: &roc_parse::parser::EImport<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EImport::*;
@@ -1538,7 +1621,9 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "RECORD BUILDER IN MODULE PARAMS".to_string(),
+                error_code: Some(error_code::R0130),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         Params(EImportParams::RecordUpdateFound(region), _) => {
@@ -1555,7 +1640,9 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "RECORD UPDATE IN MODULE PARAMS".to_string(),
+                error_code: Some(error_code::R0131),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         IndentAlias(pos) | Alias(pos) => to_unfinished_import_report(
@@ -1584,7 +1671,9 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "LOWERCASE ALIAS".to_string(),
+                error_code: Some(error_code::R0132),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ExposingListStart(pos) => to_unfinished_import_report(
@@ -1619,7 +1708,9 @@ fn to_import_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSING".to_string(),
+                error_code: Some(error_code::R0133),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         IndentIngestedName(pos) | IngestedName(pos) => to_unfinished_import_report(
@@ -1680,7 +1771,9 @@ fn to_unfinished_import_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IMPORT".to_string(),
+        error_code: Some(error_code::R0134),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -1689,7 +1782,7 @@ fn to_if_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EIf<'a>,
+    parse_problem: &roc_parse::parser::EIf<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EIf;
@@ -1793,7 +1886,9 @@ fn to_unfinished_if_report<'a>(
         filename,
         doc,
         title: "UNFINISHED IF".to_string(),
+        error_code: Some(error_code::R0135),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -1802,7 +1897,7 @@ fn to_when_report<'a>(
     lines: &LineInfo,
     filename: PathBuf,
     context: Context,
-    parse_problem: &roc_parse::parser::EWhen<'a>,
+    parse_problem: &roc_parse::parser::EWhen<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EWhen;
@@ -1826,7 +1921,9 @@ fn to_when_report<'a>(
                         filename,
                         doc,
                         title: "IF GUARD NO CONDITION".to_string(),
+                        error_code: Some(error_code::R0136),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => to_expr_report(
@@ -1858,7 +1955,9 @@ fn to_when_report<'a>(
                 filename,
                 doc,
                 title: "MISSING ARROW".to_string(),
+                error_code: Some(error_code::R0116),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2013,7 +2112,9 @@ fn to_unfinished_when_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED WHEN".to_string(),
+                error_code: Some(error_code::R0137),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
     }
@@ -2053,7 +2154,9 @@ fn to_unexpected_arrow_report<'a>(
         filename,
         doc,
         title: "UNEXPECTED ARROW".to_string(),
+        error_code: Some(error_code::R0138),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -2109,7 +2212,7 @@ fn to_pattern_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::EPattern<'a>,
+    parse_problem: &roc_parse::parser::EPattern<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EPattern;
@@ -2129,7 +2232,9 @@ fn to_pattern_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PATTERN".to_string(),
+                error_code: Some(error_code::R0139),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EPattern::Record(record, pos) => to_precord_report(alloc, lines, filename, record, *pos),
@@ -2148,7 +2253,7 @@ fn to_precord_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::PRecord<'a>,
+    parse_problem: &roc_parse::parser::PRecord<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::PRecord;
@@ -2173,7 +2278,9 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    error_code: Some(error_code::R0140),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -2190,7 +2297,9 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    error_code: Some(error_code::R0140),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -2215,7 +2324,9 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        error_code: Some(error_code::R0140),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -2235,7 +2346,9 @@ fn to_precord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD PATTERN".to_string(),
+                        error_code: Some(error_code::R0140),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -2260,7 +2373,9 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD PATTERN".to_string(),
+                    error_code: Some(error_code::R0140),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             Next::Other(Some(',')) => todo!(),
@@ -2285,7 +2400,9 @@ fn to_precord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD PATTERN".to_string(),
+                    error_code: Some(error_code::R0141),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -2320,7 +2437,7 @@ fn to_plist_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &PList<'a>,
+    parse_problem: &PList<'_>,
     start: Position,
 ) -> Report<'a> {
     match *parse_problem {
@@ -2338,7 +2455,9 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                error_code: Some(error_code::R0142),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2360,7 +2479,9 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED LIST PATTERN".to_string(),
+                error_code: Some(error_code::R0142),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2382,7 +2503,9 @@ fn to_plist_report<'a>(
                 filename,
                 doc,
                 title: "INCORRECT REST PATTERN".to_string(),
+                error_code: Some(error_code::R0143),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2396,7 +2519,7 @@ fn to_pattern_in_parens_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::PInParens<'a>,
+    parse_problem: &roc_parse::parser::PInParens<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::PInParens;
@@ -2425,7 +2548,9 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                error_code: Some(error_code::R0128),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2446,7 +2571,9 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                error_code: Some(error_code::R0127),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2470,7 +2597,9 @@ fn to_pattern_in_parens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                error_code: Some(error_code::R0128),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2498,7 +2627,9 @@ fn to_malformed_number_literal_report<'a>(
         filename,
         doc,
         title: "INVALID NUMBER LITERAL".to_string(),
+        error_code: Some(error_code::R0144),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
@@ -2506,7 +2637,7 @@ fn to_type_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::EType<'a>,
+    parse_problem: &roc_parse::parser::EType<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EType;
@@ -2538,7 +2669,9 @@ fn to_type_report<'a>(
                         filename,
                         doc,
                         title: "DOUBLE COMMA".to_string(),
+                        error_code: Some(error_code::R0145),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => todo!(),
@@ -2565,7 +2698,9 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                error_code: Some(error_code::R0146),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2583,7 +2718,9 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                error_code: Some(error_code::R0146),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2601,7 +2738,9 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED TYPE".to_string(),
+                error_code: Some(error_code::R0146),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2619,7 +2758,9 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED INLINE ALIAS".to_string(),
+                error_code: Some(error_code::R0147),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2636,7 +2777,9 @@ fn to_type_report<'a>(
                 filename,
                 doc,
                 title: "BAD TYPE VARIABLE".to_string(),
+                error_code: Some(error_code::R0148),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2648,7 +2791,7 @@ fn to_trecord_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::ETypeRecord<'a>,
+    parse_problem: &roc_parse::parser::ETypeRecord<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::ETypeRecord;
@@ -2673,7 +2816,9 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    error_code: Some(error_code::R0149),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -2694,7 +2839,9 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    error_code: Some(error_code::R0149),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -2719,7 +2866,9 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        error_code: Some(error_code::R0149),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -2739,7 +2888,9 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        error_code: Some(error_code::R0149),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -2764,7 +2915,9 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED RECORD TYPE".to_string(),
+                    error_code: Some(error_code::R0149),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             Next::Other(Some(',')) => todo!(),
@@ -2789,7 +2942,9 @@ fn to_trecord_report<'a>(
                     filename,
                     doc,
                     title: "PROBLEM IN RECORD TYPE".to_string(),
+                    error_code: Some(error_code::R0150),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -2822,7 +2977,9 @@ fn to_trecord_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED RECORD TYPE".to_string(),
+                error_code: Some(error_code::R0149),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -2846,7 +3003,9 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        error_code: Some(error_code::R0151),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 None => {
@@ -2871,7 +3030,9 @@ fn to_trecord_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED RECORD TYPE".to_string(),
+                        error_code: Some(error_code::R0149),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -2893,7 +3054,7 @@ fn to_ttag_union_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::ETypeTagUnion<'a>,
+    parse_problem: &roc_parse::parser::ETypeTagUnion<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::ETypeTagUnion;
@@ -2918,7 +3079,9 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    error_code: Some(error_code::R0152),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             Next::Other(Some(c)) if c.is_alphabetic() => {
@@ -2940,7 +3103,9 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "WEIRD TAG NAME".to_string(),
+                    error_code: Some(error_code::R0153),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
             _ => {
@@ -2961,7 +3126,9 @@ fn to_ttag_union_report<'a>(
                     filename,
                     doc,
                     title: "UNFINISHED TAG UNION TYPE".to_string(),
+                    error_code: Some(error_code::R0152),
                     severity: Severity::RuntimeError,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -2987,7 +3154,9 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        error_code: Some(error_code::R0153),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -3007,7 +3176,9 @@ fn to_ttag_union_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED TAG UNION TYPE".to_string(),
+                        error_code: Some(error_code::R0152),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -3023,7 +3194,7 @@ fn to_tinparens_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::ETypeInParens<'a>,
+    parse_problem: &roc_parse::parser::ETypeInParens<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::ETypeInParens;
@@ -3050,7 +3221,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        error_code: Some(error_code::R0128),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 Next::Other(Some(c)) if c.is_alphabetic() => {
@@ -3072,7 +3245,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        error_code: Some(error_code::R0153),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -3095,7 +3270,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        error_code: Some(error_code::R0128),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -3118,7 +3295,9 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "EMPTY PARENTHESES".to_string(),
+                error_code: Some(error_code::R0127),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3144,7 +3323,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "WEIRD TAG NAME".to_string(),
+                        error_code: Some(error_code::R0153),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 _ => {
@@ -3164,7 +3345,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        error_code: Some(error_code::R0128),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -3192,7 +3375,9 @@ fn to_tinparens_report<'a>(
                 filename,
                 doc,
                 title: "UNFINISHED PARENTHESES".to_string(),
+                error_code: Some(error_code::R0128),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3216,7 +3401,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "NEED MORE INDENTATION".to_string(),
+                        error_code: Some(error_code::R0151),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
                 None => {
@@ -3241,7 +3428,9 @@ fn to_tinparens_report<'a>(
                         filename,
                         doc,
                         title: "UNFINISHED PARENTHESES".to_string(),
+                        error_code: Some(error_code::R0128),
                         severity: Severity::RuntimeError,
+                        fixes: Vec::new(),
                     }
                 }
             }
@@ -3274,7 +3463,9 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "DOUBLE DOT".to_string(),
+                error_code: Some(error_code::R0154),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ETypeApply::TrailingDot(pos) => {
@@ -3296,7 +3487,9 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "TRAILING DOT".to_string(),
+                error_code: Some(error_code::R0155),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ETypeApply::StartIsNumber(pos) => {
@@ -3318,7 +3511,9 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                error_code: Some(error_code::R0156),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ETypeApply::StartNotUppercase(pos) => {
@@ -3340,7 +3535,9 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD QUALIFIED NAME".to_string(),
+                error_code: Some(error_code::R0156),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3358,7 +3555,9 @@ fn to_tapply_report<'a>(
                 filename,
                 doc,
                 title: "END OF FILE".to_string(),
+                error_code: Some(error_code::R0157),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3398,7 +3597,9 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "NOT AN INLINE ALIAS".to_string(),
+                error_code: Some(error_code::R0158),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ETypeInlineAlias::Qualified(pos) => {
@@ -3414,7 +3615,9 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "QUALIFIED ALIAS NAME".to_string(),
+                error_code: Some(error_code::R0159),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         ETypeInlineAlias::ArgumentNotLowercase(pos) => {
@@ -3430,7 +3633,9 @@ fn to_talias_report<'a>(
                 filename,
                 doc,
                 title: "TYPE ARGUMENT NOT LOWERCASE".to_string(),
+                error_code: Some(error_code::R0160),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
     }
@@ -3440,7 +3645,7 @@ fn to_header_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::EHeader<'a>,
+    parse_problem: &roc_parse::parser::EHeader<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EHeader;
@@ -3478,7 +3683,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INCOMPLETE HEADER".to_string(),
+                error_code: Some(error_code::R0161),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3516,7 +3723,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "MISSING HEADER".to_string(),
+                error_code: Some(error_code::R0162),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3540,7 +3749,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                error_code: Some(error_code::R0163),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3567,7 +3778,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                error_code: Some(error_code::R0163),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3591,7 +3804,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD APP NAME".to_string(),
+                error_code: Some(error_code::R0164),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3613,7 +3828,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PACKAGE NAME".to_string(),
+                error_code: Some(error_code::R0165),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3636,7 +3853,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "INVALID PLATFORM NAME".to_string(),
+                error_code: Some(error_code::R0166),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3659,7 +3878,9 @@ fn to_header_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATED TYPE NAME".to_string(),
+                error_code: Some(error_code::R0167),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EHeader::GeneratesWith(generates_with, pos) => {
@@ -3699,7 +3920,9 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                error_code: Some(error_code::R0168),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3724,7 +3947,9 @@ fn to_generates_with_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD GENERATES".to_string(),
+                error_code: Some(error_code::R0168),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3765,7 +3990,9 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                error_code: Some(error_code::R0169),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3790,7 +4017,9 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                error_code: Some(error_code::R0169),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3817,7 +4046,9 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                error_code: Some(error_code::R0169),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3838,7 +4069,9 @@ fn to_provides_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PROVIDES".to_string(),
+                error_code: Some(error_code::R0169),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3850,7 +4083,7 @@ fn to_params_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::EParams<'a>,
+    parse_problem: &roc_parse::parser::EParams<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EParams;
@@ -3880,7 +4113,9 @@ fn to_params_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE PARAMS".to_string(),
+                error_code: Some(error_code::R0170),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3918,7 +4153,9 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                error_code: Some(error_code::R0171),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3943,7 +4180,9 @@ fn to_exposes_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD EXPOSES".to_string(),
+                error_code: Some(error_code::R0171),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -3982,7 +4221,9 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                error_code: Some(error_code::R0172),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4007,7 +4248,9 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                error_code: Some(error_code::R0172),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4033,7 +4276,9 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD MODULE NAME".to_string(),
+                error_code: Some(error_code::R0163),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4052,7 +4297,9 @@ fn to_imports_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD IMPORTS".to_string(),
+                error_code: Some(error_code::R0172),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4064,7 +4311,7 @@ fn to_requires_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    parse_problem: &roc_parse::parser::ERequires<'a>,
+    parse_problem: &roc_parse::parser::ERequires<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::ERequires;
@@ -4091,7 +4338,9 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                error_code: Some(error_code::R0173),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4118,7 +4367,9 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "MISSING REQUIRES".to_string(),
+                error_code: Some(error_code::R0173),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4147,7 +4398,9 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES RIGIDS".to_string(),
+                error_code: Some(error_code::R0174),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4176,7 +4429,9 @@ fn to_requires_report<'a>(
                 filename,
                 doc,
                 title: "BAD REQUIRES".to_string(),
+                error_code: Some(error_code::R0175),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4213,7 +4468,9 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "MISSING PACKAGES".to_string(),
+                error_code: Some(error_code::R0176),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
         EPackages::ListEnd(pos) => {
@@ -4235,7 +4492,9 @@ fn to_packages_report<'a>(
                 filename,
                 doc,
                 title: "WEIRD PACKAGES LIST".to_string(),
+                error_code: Some(error_code::R0177),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4270,7 +4529,9 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "TAB CHARACTER".to_string(),
+                error_code: Some(error_code::R0178),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4287,7 +4548,9 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "ASCII CONTROL CHARACTER".to_string(),
+                error_code: Some(error_code::R0179),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4304,7 +4567,9 @@ fn to_space_report<'a>(
                 filename,
                 doc,
                 title: "MISPLACED CARRIAGE RETURN".to_string(),
+                error_code: Some(error_code::R0180),
                 severity: Severity::RuntimeError,
+                fixes: Vec::new(),
             }
         }
 
@@ -4316,7 +4581,7 @@ fn to_ability_def_report<'a>(
     alloc: &'a RocDocAllocator<'a>,
     lines: &LineInfo,
     filename: PathBuf,
-    problem: &roc_parse::parser::EAbility<'a>,
+    problem: &roc_parse::parser::EAbility<'_>,
     start: Position,
 ) -> Report<'a> {
     use roc_parse::parser::EAbility;
@@ -4385,7 +4650,9 @@ fn to_unfinished_ability_report<'a>(
         filename,
         doc,
         title: "UNFINISHED ABILITY".to_string(),
+        error_code: Some(error_code::R0181),
         severity: Severity::RuntimeError,
+        fixes: Vec::new(),
     }
 }
 
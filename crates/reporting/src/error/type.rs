@@ -1,6 +1,8 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::error::canonicalize::{to_circular_def_doc, CIRCULAR_DEF};
+use crate::error_code::{self, ErrorCode};
+use crate::fix::{self, Applicability, Fix};
 use crate::report::{Annotation, Report, RocDocAllocator, RocDocBuilder};
 use itertools::EitherOrBoth;
 use itertools::Itertools;
@@ -44,20 +46,32 @@ pub fn type_problem<'b>(
 
     let severity = problem.severity();
 
-    let report =
-        move |title: String, doc: RocDocBuilder<'b>, filename: PathBuf| -> Option<Report<'b>> {
-            Some(Report {
-                title,
-                filename,
-                doc,
-                severity,
-            })
-        };
+    let report = move |title: String,
+                       error_code: &'static ErrorCode,
+                       doc: RocDocBuilder<'b>,
+                       filename: PathBuf|
+          -> Option<Report<'b>> {
+        Some(Report {
+            title,
+            error_code: Some(error_code),
+            filename,
+            doc,
+            severity,
+            fixes: Vec::new(),
+        })
+    };
 
     match problem {
-        BadExpr(region, category, found, expected) => Some(to_expr_report(
-            alloc, lines, filename, severity, region, category, found, expected,
-        )),
+        BadExpr(region, category, found, expected) => {
+            let fixes = typo_fixes(alloc, lines, region, &found, expected.get_type_ref());
+            let mut report = to_expr_report(
+                alloc, lines, filename, severity, region, category, found, expected,
+            );
+
+            report.fixes = fixes;
+
+            Some(report)
+        }
         BadPattern(region, category, found, expected) => Some(to_pattern_report(
             alloc, lines, filename, severity, region, category, found, expected,
         )),
@@ -80,14 +94,14 @@ pub fn type_problem<'b>(
                     .append(alloc.symbol_unqualified(symbol))])
                 .append(alloc.reflow("."));
 
-            report(title, doc, filename)
+            report(title, error_code::R0201, doc, filename)
         }
         UnfulfilledAbility(incomplete) => {
             let title = "INCOMPLETE ABILITY IMPLEMENTATION".to_string();
 
             let doc = report_unfulfilled_ability(alloc, lines, incomplete);
 
-            report(title, doc, filename)
+            report(title, error_code::R0268, doc, filename)
         }
         BadExprMissingAbility(region, _category, _found, incomplete) => {
             if region == roc_can::DERIVED_REGION {
@@ -109,9 +123,11 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                error_code: Some(error_code::R0300),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             };
             Some(report)
         }
@@ -131,9 +147,11 @@ pub fn type_problem<'b>(
 
             let report = Report {
                 title: "TYPE MISMATCH".to_string(),
+                error_code: Some(error_code::R0300),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             };
             Some(report)
         }
@@ -144,9 +162,11 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title,
+                error_code: Some(error_code::R0216),
                 filename,
                 doc,
                 severity,
+                fixes: Vec::new(),
             })
         }
         StructuralSpecialization {
@@ -174,9 +194,11 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "ILLEGAL SPECIALIZATION".to_string(),
+                error_code: Some(error_code::R0270),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             })
         }
         WrongSpecialization {
@@ -203,9 +225,11 @@ pub fn type_problem<'b>(
 
             Some(Report {
                 title: "WRONG SPECIALIZATION TYPE".to_string(),
+                error_code: Some(error_code::R0271),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             })
         }
         IngestedFileBadUtf8(file_path, utf8_err) => {
@@ -219,9 +243,11 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID UTF-8".to_string(),
+                error_code: Some(error_code::R0301),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             })
         }
         IngestedFileUnsupportedType(file_path, typ) => {
@@ -242,9 +268,11 @@ pub fn type_problem<'b>(
             ];
             Some(Report {
                 title: "INVALID TYPE FOR INGESTED FILE".to_string(),
+                error_code: Some(error_code::R0302),
                 filename,
                 doc: alloc.stack(stack),
                 severity,
+                fixes: Vec::new(),
             })
         }
     }
@@ -535,9 +563,11 @@ fn report_mismatch<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        error_code: Some(error_code::R0300),
         filename,
         doc: alloc.stack(lines),
         severity,
+        fixes: Vec::new(),
     }
 }
 
@@ -578,9 +608,11 @@ fn report_bad_type<'b>(
 
     Report {
         title: "TYPE MISMATCH".to_string(),
+        error_code: Some(error_code::R0300),
         filename,
         doc: alloc.stack(lines),
         severity,
+        fixes: Vec::new(),
     }
 }
 
@@ -662,12 +694,14 @@ fn to_expr_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                error_code: Some(error_code::R0300),
                 doc: alloc.stack([
                     alloc.text("This expression is used in an unexpected way:"),
                     alloc.region(lines.convert_region(expr_region)),
                     comparison,
                 ]),
                 severity,
+                fixes: Vec::new(),
             }
         }
         Expected::FromAnnotation(name, _arity, annotation_source, expected_type) => {
@@ -776,6 +810,7 @@ fn to_expr_report<'b>(
 
             Report {
                 title: "TYPE MISMATCH".to_string(),
+                error_code: Some(error_code::R0300),
                 filename,
                 doc: alloc.stack([
                     alloc.text("Something is off with the ").append(thing),
@@ -791,6 +826,7 @@ fn to_expr_report<'b>(
                     comparison,
                 ]),
                 severity,
+                fixes: Vec::new(),
             }
         }
         Expected::ForReason(reason, expected_type, region) => match reason {
@@ -1180,8 +1216,10 @@ fn to_expr_report<'b>(
                     Report {
                         filename,
                         title: "TOO MANY ARGS".to_string(),
+                        error_code: Some(error_code::R0304),
                         doc,
                         severity,
+                        fixes: Vec::new(),
                     }
                 }
                 DescribedFunction::Arguments(n) => {
@@ -1215,8 +1253,10 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO MANY ARGS".to_string(),
+                            error_code: Some(error_code::R0304),
                             doc: alloc.stack(lines),
                             severity,
+                            fixes: Vec::new(),
                         }
                     } else {
                         let lines = vec![
@@ -1242,8 +1282,10 @@ fn to_expr_report<'b>(
                         Report {
                             filename,
                             title: "TOO FEW ARGS".to_string(),
+                            error_code: Some(error_code::R0305),
                             doc: alloc.stack(lines),
                             severity,
+                            fixes: Vec::new(),
                         }
                     }
                 }
@@ -1442,9 +1484,11 @@ fn to_expr_report<'b>(
 
                 Report {
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     filename,
                     doc: alloc.stack(lines),
                     severity,
+                    fixes: Vec::new(),
                 }
             }
 
@@ -1480,8 +1524,10 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
 
@@ -1516,8 +1562,10 @@ fn to_expr_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     doc: alloc.stack(lines),
                     severity,
+                    fixes: Vec::new(),
                 }
             }
 
@@ -1929,8 +1977,10 @@ fn to_pattern_report<'b>(
             Report {
                 filename,
                 title: "TYPE MISMATCH".to_string(),
+                error_code: Some(error_code::R0300),
                 doc,
                 severity,
+                fixes: Vec::new(),
             }
         }
 
@@ -1972,8 +2022,10 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
             PReason::WhenMatch { index, sub_pattern } => {
@@ -2052,8 +2104,10 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
             PReason::ListElem => {
@@ -2079,8 +2133,10 @@ fn to_pattern_report<'b>(
                 Report {
                     filename,
                     title: "TYPE MISMATCH".to_string(),
+                    error_code: Some(error_code::R0300),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
             PReason::TagArg { .. } | PReason::PatternGuard => {
@@ -2163,6 +2219,7 @@ fn to_circular_report<'b>(
 ) -> Report<'b> {
     Report {
         title: "CIRCULAR TYPE".to_string(),
+        error_code: Some(error_code::R0306),
         filename,
         doc: {
             alloc.stack([
@@ -2182,9 +2239,56 @@ fn to_circular_report<'b>(
             ])
         },
         severity,
+        fixes: Vec::new(),
     }
 }
 
+/// Suggest replacing a misspelled record field or tag with the closest one the type expected.
+fn typo_fixes<'b>(
+    alloc: &'b RocDocAllocator<'b>,
+    lines: &LineInfo,
+    region: Region,
+    found: &ErrorType,
+    expected: &ErrorType,
+) -> Vec<Fix> {
+    let Comparison { problems, .. } = to_comparison(alloc, found.clone(), expected.clone());
+
+    problems
+        .into_iter()
+        .filter_map(|problem| {
+            let (typo, nearest) = match problem {
+                Problem::FieldTypo(typo, possibilities) => {
+                    let nearest = suggest::sort(typo.as_str(), possibilities)
+                        .into_iter()
+                        .next()?;
+
+                    (typo.to_string(), nearest.to_string())
+                }
+                Problem::TagTypo(typo, possibilities) => {
+                    let typo = typo.as_ident_str().to_string();
+                    let possibilities = possibilities
+                        .into_iter()
+                        .map(|tag_name| tag_name.as_ident_str())
+                        .collect();
+                    let nearest = suggest::sort(&typo, possibilities).into_iter().next()?;
+
+                    (typo, nearest.to_string())
+                }
+                _ => return None,
+            };
+
+            let typo_region = fix::unique_identifier(alloc.src_lines, lines, region, &typo)?;
+
+            Some(Fix::replace(
+                format!("Change {typo} to {nearest}"),
+                typo_region,
+                nearest,
+                Applicability::Suggestion,
+            ))
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub enum Problem {
     IntFloat,
@@ -4854,8 +4958,10 @@ fn report_record_field_typo<'b>(
     Report {
         filename,
         title: "TYPE MISMATCH".to_string(),
+        error_code: Some(error_code::R0300),
         doc,
         severity,
+        fixes: Vec::new(),
     }
 }
 
@@ -4891,8 +4997,10 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    error_code: Some(error_code::R0307),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
             BadDestruct => {
@@ -4915,8 +5023,10 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    error_code: Some(error_code::R0307),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
             BadCase => {
@@ -4939,8 +5049,10 @@ fn exhaustive_problem<'a>(
                 Report {
                     filename,
                     title: "UNSAFE PATTERN".to_string(),
+                    error_code: Some(error_code::R0307),
                     doc,
                     severity,
+                    fixes: Vec::new(),
                 }
             }
         },
@@ -4968,8 +5080,10 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "REDUNDANT PATTERN".to_string(),
+                error_code: Some(error_code::R0308),
                 doc,
                 severity,
+                fixes: Vec::new(),
             }
         }
         Unmatchable {
@@ -4996,8 +5110,10 @@ fn exhaustive_problem<'a>(
             Report {
                 filename,
                 title: "UNMATCHABLE PATTERN".to_string(),
+                error_code: Some(error_code::R0309),
                 doc,
                 severity,
+                fixes: Vec::new(),
             }
         }
     }
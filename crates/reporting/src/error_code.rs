@@ -0,0 +1,300 @@
+//! Stable codes for each kind of report, like `R0300` for a type mismatch, and the
+//! long-form explanations that `roc explain` prints for them.
+//!
+//! Each report is given its code where it's constructed, and every report with the same
+//! title has the same code. Once assigned, a code must never be changed or reused (even if
+//! its report is removed), because codes end up in logs, docs, and warning configuration.
+//! New codes go at the end of their range.
+
+#[derive(Debug)]
+pub struct ErrorCode {
+    pub code: &'static str,
+    /// The titles of the reports that have this code. This is usually just one title.
+    pub titles: &'static [&'static str],
+    /// A long-form explanation with examples, in Markdown.
+    pub explanation: &'static str,
+}
+
+macro_rules! error_codes {
+    ($($code:ident: $($title:literal)|+,)*) => {
+        $(
+            pub const $code: &ErrorCode = &ErrorCode {
+                code: stringify!($code),
+                titles: &[$($title),+],
+                explanation: include_str!(concat!("error_codes/", stringify!($code), ".md")),
+            };
+        )*
+
+        pub const ERROR_CODES: &[&ErrorCode] = &[$($code),*];
+    };
+}
+
+error_codes! {
+    // Loading modules
+    R0001: "FILE NOT FOUND",
+    R0002: "FILE PERMISSION DENIED",
+    R0003: "NOT A ROC FILE",
+    R0004: "FILE PROBLEM",
+    R0005: "FILE TOO LARGE",
+    R0006: "IMPORT CYCLE",
+    R0007: "INCORRECT MODULE NAME",
+    R0008: "UNSPECIFIED PLATFORM",
+    R0009: "MULTIPLE PLATFORMS",
+    R0010: "NO PLATFORM",
+
+    // Downloading packages
+    R0050: "UNSUPPORTED ENCODING",
+    R0051: "MULTIPLE ENCODINGS",
+    R0052: "INVALID CONTENT HASH",
+    R0053: "NOTFOUND",
+    R0054: "IO ERROR",
+    R0055: "HTTP ERROR",
+    R0056: "INVALID EXTENSION SUFFIX",
+    R0057: "INVALID EXTENSION",
+    R0058: "INVALID FRAGMENT",
+    R0059: "MISSING PACKAGE HASH",
+    R0060: "HTTPS MANDATORY",
+    R0061: "MISLEADING CHARACTERS",
+
+    // Parsing
+    R0100: "SYNTAX PROBLEM",
+    R0101: "PARSE PROBLEM",
+    R0102: "NOT END OF FILE",
+    R0103: "ARGUMENTS BEFORE EQUALS",
+    R0104: "UNKNOWN OPERATOR",
+    R0105: "WEIRD IDENTIFIER",
+    R0106: "MISSING EXPRESSION",
+    R0107: "MISSING FINAL EXPRESSION",
+    R0108: "BAD BACKPASSING ARROW",
+    R0109: "BAD RECORD BUILDER",
+    R0110: "BAD RECORD UPDATE",
+    R0111: "INDENT ENDS AFTER EXPRESSION",
+    R0112: "TRAILING OPERATOR",
+    R0113: "UNEXPECTED COMMA",
+    R0114: "RECORD PARSE PROBLEM",
+    R0115: "WEIRD ARROW",
+    R0116: "MISSING ARROW",
+    R0117: "UNFINISHED ARGUMENT LIST",
+    R0118: "UNFINISHED FUNCTION",
+    R0119: "WEIRD ESCAPE",
+    R0120: "WEIRD CODE POINT",
+    R0121: "ENDLESS FORMAT",
+    R0122: "ENDLESS SCALAR",
+    R0123: "INVALID SCALAR",
+    R0124: "ENDLESS STRING",
+    R0125: "EXPECTED STRING",
+    R0126: "INSUFFICIENT INDENT IN MULTI-LINE STRING",
+    R0127: "EMPTY PARENTHESES",
+    R0128: "UNFINISHED PARENTHESES",
+    R0129: "UNFINISHED LIST",
+    R0130: "RECORD BUILDER IN MODULE PARAMS",
+    R0131: "RECORD UPDATE IN MODULE PARAMS",
+    R0132: "LOWERCASE ALIAS",
+    R0133: "WEIRD EXPOSING",
+    R0134: "UNFINISHED IMPORT",
+    R0135: "UNFINISHED IF",
+    R0136: "IF GUARD NO CONDITION",
+    R0137: "UNFINISHED WHEN",
+    R0138: "UNEXPECTED ARROW",
+    R0139: "UNFINISHED PATTERN",
+    R0140: "UNFINISHED RECORD PATTERN",
+    R0141: "PROBLEM IN RECORD PATTERN",
+    R0142: "UNFINISHED LIST PATTERN",
+    R0143: "INCORRECT REST PATTERN",
+    R0144: "INVALID NUMBER LITERAL",
+    R0145: "DOUBLE COMMA",
+    R0146: "UNFINISHED TYPE",
+    R0147: "UNFINISHED INLINE ALIAS",
+    R0148: "BAD TYPE VARIABLE",
+    R0149: "UNFINISHED RECORD TYPE",
+    R0150: "PROBLEM IN RECORD TYPE",
+    R0151: "NEED MORE INDENTATION",
+    R0152: "UNFINISHED TAG UNION TYPE",
+    R0153: "WEIRD TAG NAME",
+    R0154: "DOUBLE DOT",
+    R0155: "TRAILING DOT",
+    R0156: "WEIRD QUALIFIED NAME",
+    R0157: "END OF FILE",
+    R0158: "NOT AN INLINE ALIAS",
+    R0159: "QUALIFIED ALIAS NAME",
+    R0160: "TYPE ARGUMENT NOT LOWERCASE",
+    R0161: "INCOMPLETE HEADER",
+    R0162: "MISSING HEADER",
+    R0163: "WEIRD MODULE NAME",
+    R0164: "WEIRD APP NAME",
+    R0165: "INVALID PACKAGE NAME",
+    R0166: "INVALID PLATFORM NAME",
+    R0167: "WEIRD GENERATED TYPE NAME",
+    R0168: "WEIRD GENERATES",
+    R0169: "WEIRD PROVIDES",
+    R0170: "WEIRD MODULE PARAMS",
+    R0171: "WEIRD EXPOSES",
+    R0172: "WEIRD IMPORTS",
+    R0173: "MISSING REQUIRES",
+    R0174: "BAD REQUIRES RIGIDS",
+    R0175: "BAD REQUIRES",
+    R0176: "MISSING PACKAGES",
+    R0177: "WEIRD PACKAGES LIST",
+    R0178: "TAB CHARACTER",
+    R0179: "ASCII CONTROL CHARACTER",
+    R0180: "MISPLACED CARRIAGE RETURN",
+    R0181: "UNFINISHED ABILITY",
+
+    // Canonicalization
+    R0200: "NAMING PROBLEM",
+    R0201: "UNRECOGNIZED NAME",
+    R0202: "UNUSED DEFINITION",
+    R0203: "UNUSED IMPORT",
+    R0204: "IMPORT NAME CONFLICT",
+    R0205: "EXPLICIT BUILTIN IMPORT",
+    R0206: "UNUSED TYPE ALIAS PARAMETER",
+    R0207: "UNDECLARED TYPE VARIABLE",
+    R0208: "WILDCARD NOT ALLOWED HERE",
+    R0209: "UNDERSCORE NOT ALLOWED HERE",
+    R0210: "UNUSED ARGUMENT",
+    R0211: "MISSING DEFINITION",
+    R0212: "UNKNOWN GENERATES FUNCTION",
+    R0213: "DUPLICATE FIELD NAME",
+    R0214: "DUPLICATE TAG NAME",
+    R0215: "INVALID UNICODE",
+    R0216: "CIRCULAR DEFINITION",
+    R0217: "DUPLICATE NAME",
+    R0218: "NOT EXPOSED",
+    R0219: "MODULE NOT IMPORTED",
+    R0220: "INGESTED FILE ERROR",
+    R0221: "NESTED DATATYPE",
+    R0222: "CONFLICTING NUMBER SUFFIX",
+    R0223: "NUMBER OVERFLOWS SUFFIX",
+    R0224: "NUMBER UNDERFLOWS SUFFIX",
+    R0225: "OPAQUE TYPE NOT DEFINED",
+    R0226: "OPAQUE TYPE DECLARED OUTSIDE SCOPE",
+    R0227: "OPAQUE TYPE NOT APPLIED",
+    R0228: "OPAQUE TYPE APPLIED TO TOO MANY ARGS",
+    R0229: "INVALID_EXTENSION_TYPE",
+    R0230: "DEFINITION ONLY USED IN RECURSION" | "DEFINITIONs ONLY USED IN RECURSION",
+    R0231: "NAME NOT BOUND IN ALL PATTERNS",
+    R0232: "UNNECESSARY DEFINITION",
+    R0233: "UNNECESSARY WILDCARD",
+    R0234: "MULTIPLE LIST REST PATTERNS",
+    R0235: "TOO MANY TYPE ARGUMENTS",
+    R0236: "TOO FEW TYPE ARGUMENTS",
+    R0237: "UNAPPLIED CRASH",
+    R0238: "OVERAPPLIED CRASH",
+    R0239: "BAD OPTIONAL VALUE",
+    R0240: "DEGENERATE BRANCH",
+    R0241: "MULTIPLE RECORD BUILDERS",
+    R0242: "UNAPPLIED RECORD BUILDER",
+
+    // Abilities
+    R0250: "ABILITY HAS TYPE VARIABLES",
+    R0251: "IMPLEMENTS CLAUSE IS NOT AN ABILITY",
+    R0252: "ILLEGAL IMPLEMENTS CLAUSE",
+    R0253: "DUPLICATE BOUND ABILITY",
+    R0254: "ABILITY MEMBER MISSING IMPLEMENTS CLAUSE",
+    R0255: "ABILITY MEMBER BINDS MULTIPLE VARIABLES",
+    R0256: "ABILITY NOT ON TOP-LEVEL",
+    R0257: "SPECIALIZATION NOT ON TOP-LEVEL",
+    R0258: "ABILITY USED AS TYPE",
+    R0259: "ILLEGAL DERIVE",
+    R0260: "IMPLEMENTATION NOT FOUND",
+    R0261: "NOT AN ABILITY MEMBER",
+    R0262: "NOT AN ABILITY",
+    R0263: "OPTIONAL ABILITY IMPLEMENTATION",
+    R0264: "QUALIFIED ABILITY IMPLEMENTATION",
+    R0265: "ABILITY IMPLEMENTATION NOT IDENTIFIER",
+    R0266: "DUPLICATE IMPLEMENTATION",
+    R0267: "UNNECESSARY IMPLEMENTATIONS",
+    R0268: "INCOMPLETE ABILITY IMPLEMENTATION",
+    R0269: "OVERLOADED SPECIALIZATION",
+    R0270: "ILLEGAL SPECIALIZATION",
+    R0271: "WRONG SPECIALIZATION TYPE",
+
+    // Type checking
+    R0300: "TYPE MISMATCH",
+    R0301: "INVALID UTF-8",
+    R0302: "INVALID TYPE FOR INGESTED FILE",
+    R0303: "CYCLIC ALIAS",
+    R0304: "TOO MANY ARGS",
+    R0305: "TOO FEW ARGS",
+    R0306: "CIRCULAR TYPE",
+    R0307: "UNSAFE PATTERN",
+    R0308: "REDUNDANT PATTERN",
+    R0309: "UNMATCHABLE PATTERN",
+
+    // Docs
+    R0400: "INVALID DOCS LINK",
+
+    // Lints
+    R0500: "SHADOWED BUILTIN",
+    R0501: "LEFTOVER DBG",
+    R0502: "CRASH IN LIBRARY",
+    R0503: "REDUNDANT TRY",
+    R0504: "REPEATED WHEN",
+    R0505: "CATCH-ALL ON TAG UNION",
+    R0506: "TRY CHAIN",
+
+    // Expects
+    R0600: "EXPECT FAILED",
+    R0601: "EXPECT PANICKED",
+}
+
+/// Look up a code as the user typed it, e.g. `R0300` or `r0300`.
+pub fn lookup(code: &str) -> Option<&'static ErrorCode> {
+    let code = code.trim();
+
+    ERROR_CODES
+        .iter()
+        .find(|error_code| error_code.code.eq_ignore_ascii_case(code))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use roc_collections::MutSet;
+
+    #[test]
+    fn codes_are_well_formed() {
+        for error_code in ERROR_CODES {
+            let digits = error_code.code.strip_prefix('R').unwrap();
+
+            assert_eq!(digits.len(), 4, "{}", error_code.code);
+            assert!(
+                digits.chars().all(|c| c.is_ascii_digit()),
+                "{}",
+                error_code.code
+            );
+            assert!(!error_code.titles.is_empty(), "{}", error_code.code);
+            assert!(
+                error_code.explanation.contains("```roc"),
+                "{} has no example",
+                error_code.code
+            );
+        }
+    }
+
+    #[test]
+    fn codes_and_titles_are_unique() {
+        let mut codes = MutSet::default();
+        let mut titles = MutSet::default();
+
+        for error_code in ERROR_CODES {
+            assert!(
+                codes.insert(error_code.code),
+                "duplicate code {}",
+                error_code.code
+            );
+
+            for title in error_code.titles {
+                assert!(titles.insert(*title), "{title} has more than one code");
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        assert_eq!(lookup("r0300").unwrap().titles, &["TYPE MISMATCH"]);
+        assert_eq!(lookup("R0300").unwrap().code, R0300.code);
+        assert!(lookup("R9999").is_none());
+    }
+}
@@ -0,0 +1,346 @@
+//! Machine-applicable edits attached to reports.
+//!
+//! A [`Fix`] is a region of the original source plus the text that should replace it.
+//! Fixes marked [`Applicability::Safe`] never change what a program means, so tools
+//! like `roc check --fix` can apply them without asking. Everything else is only a
+//! suggestion, and should be confirmed by a person (e.g. through an editor quick fix).
+use roc_region::all::{LineColumn, LineInfo, Position, Region};
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Applicability {
+    /// Applying the edit keeps the meaning of the program the same.
+    Safe,
+    /// The edit is probably what was meant, but it might not be.
+    Suggestion,
+}
+
+impl Applicability {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Applicability::Safe => "safe",
+            Applicability::Suggestion => "suggestion",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fix {
+    /// A short, imperative description of the edit, e.g. "Remove unused import".
+    pub title: String,
+    pub region: Region,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+impl Fix {
+    pub fn replace(
+        title: impl Into<String>,
+        region: Region,
+        replacement: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Fix {
+            title: title.into(),
+            region,
+            replacement: replacement.into(),
+            applicability,
+        }
+    }
+
+    pub fn insert(
+        title: impl Into<String>,
+        pos: Position,
+        text: impl Into<String>,
+        applicability: Applicability,
+    ) -> Self {
+        Self::replace(title, Region::new(pos, pos), text, applicability)
+    }
+
+    pub fn delete(title: impl Into<String>, region: Region, applicability: Applicability) -> Self {
+        Self::replace(title, region, String::new(), applicability)
+    }
+
+    pub fn is_safe(&self) -> bool {
+        self.applicability == Applicability::Safe
+    }
+}
+
+/// Apply every safe fix to `src`, returning the new source and how many fixes were applied.
+///
+/// Fixes are applied in source order. A fix that overlaps one which was already applied
+/// is skipped, so running this again on the result will pick up whatever was left behind.
+pub fn apply_safe_fixes<'a>(
+    src: &str,
+    fixes: impl IntoIterator<Item = &'a Fix>,
+) -> (String, usize) {
+    let mut safe: Vec<&Fix> = fixes.into_iter().filter(|fix| fix.is_safe()).collect();
+
+    safe.sort_by_key(|fix| (fix.region.start().offset, fix.region.end().offset));
+    safe.dedup();
+
+    let mut output = String::with_capacity(src.len());
+    let mut cursor = 0;
+    let mut applied = 0;
+
+    for fix in safe {
+        let start = fix.region.start().offset as usize;
+        let end = fix.region.end().offset as usize;
+
+        if start < cursor || end > src.len() {
+            continue;
+        }
+
+        output.push_str(&src[cursor..start]);
+        output.push_str(&fix.replacement);
+        cursor = end;
+        applied += 1;
+    }
+
+    output.push_str(&src[cursor..]);
+
+    (output, applied)
+}
+
+/// The source text covered by a region, if it fits on a single line.
+pub(crate) fn region_text<'a>(
+    src_lines: &[&'a str],
+    lines: &LineInfo,
+    region: Region,
+) -> Option<&'a str> {
+    let lc = lines.convert_region(region);
+
+    if lc.start.line != lc.end.line {
+        return None;
+    }
+
+    src_lines
+        .get(lc.start.line as usize)?
+        .get(lc.start.column as usize..lc.end.column as usize)
+}
+
+/// Grow a region so that it covers the whole lines it is on, including the final newline.
+///
+/// Returns `None` if anything other than whitespace shares those lines with the region.
+pub(crate) fn whole_lines(src_lines: &[&str], lines: &LineInfo, region: Region) -> Option<Region> {
+    let lc = lines.convert_region(region);
+    let first = src_lines.get(lc.start.line as usize)?;
+    let last = src_lines.get(lc.end.line as usize)?;
+
+    if !first.get(..lc.start.column as usize)?.trim().is_empty()
+        || !last.get(lc.end.column as usize..)?.trim().is_empty()
+    {
+        return None;
+    }
+
+    let start = lines.convert_line_column(LineColumn {
+        line: lc.start.line,
+        column: 0,
+    });
+
+    let end = if lc.end.line + 1 < lines.num_lines() {
+        lines.convert_line_column(LineColumn {
+            line: lc.end.line + 1,
+            column: 0,
+        })
+    } else {
+        lines.convert_line_column(LineColumn {
+            line: lc.end.line,
+            column: last.len() as u32,
+        })
+    };
+
+    Some(Region::new(start, end))
+}
+
+/// The region to delete in order to drop one element from a comma-separated list,
+/// taking a neighbouring comma along with it.
+///
+/// Returns `None` when the element is alone in its list, or when its neighbours
+/// are not on the same line.
+pub(crate) fn list_element(src_lines: &[&str], lines: &LineInfo, region: Region) -> Option<Region> {
+    let lc = lines.convert_region(region);
+
+    if lc.start.line != lc.end.line {
+        return None;
+    }
+
+    let line = *src_lines.get(lc.start.line as usize)?;
+    let before = line.get(..lc.start.column as usize)?;
+    let after = line.get(lc.end.column as usize..)?;
+
+    let (start_column, end_column) = if let Some(rest) = after.trim_start().strip_prefix(',') {
+        let trailing = rest.len() - rest.trim_start().len();
+
+        (lc.start.column, line.len() - rest.len() + trailing)
+    } else if let Some(rest) = before.trim_end().strip_suffix(',') {
+        (rest.len() as u32, lc.end.column as usize)
+    } else {
+        return None;
+    };
+
+    let element = Region::new(
+        lines.convert_line_column(LineColumn {
+            line: lc.start.line,
+            column: start_column,
+        }),
+        lines.convert_line_column(LineColumn {
+            line: lc.start.line,
+            column: end_column as u32,
+        }),
+    );
+
+    // An element on a line of its own takes the whole line with it
+    Some(whole_lines(src_lines, lines, element).unwrap_or(element))
+}
+
+/// The region of the only place where `ident` occurs as a whole word within `region`.
+///
+/// Returns `None` if it occurs zero times, or more than once.
+pub(crate) fn unique_identifier(
+    src_lines: &[&str],
+    lines: &LineInfo,
+    region: Region,
+    ident: &str,
+) -> Option<Region> {
+    let is_ident_char = |ch: char| ch.is_alphanumeric() || ch == '_';
+    let lc = lines.convert_region(region);
+    let mut found = None;
+
+    for line_index in lc.start.line..=lc.end.line {
+        let line = *src_lines.get(line_index as usize)?;
+        let start = if line_index == lc.start.line {
+            lc.start.column as usize
+        } else {
+            0
+        };
+        let end = if line_index == lc.end.line {
+            lc.end.column as usize
+        } else {
+            line.len()
+        };
+        let text = line.get(start..end)?;
+
+        for (offset, _) in text.match_indices(ident) {
+            let before = text[..offset].chars().next_back();
+            let after = text[offset + ident.len()..].chars().next();
+
+            if before.map_or(false, is_ident_char) || after.map_or(false, is_ident_char) {
+                continue;
+            }
+
+            if found.is_some() {
+                return None;
+            }
+
+            let column = (start + offset) as u32;
+
+            found = Some(Region::new(
+                lines.convert_line_column(LineColumn {
+                    line: line_index,
+                    column,
+                }),
+                lines.convert_line_column(LineColumn {
+                    line: line_index,
+                    column: column + ident.len() as u32,
+                }),
+            ));
+        }
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod test_fix {
+    use super::*;
+
+    fn region(start: u32, end: u32) -> Region {
+        Region::new(Position::new(start), Position::new(end))
+    }
+
+    fn check_list_element(src: &str, element: &str, expected: &str) {
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let start = src.find(element).unwrap() as u32;
+        let fix = Fix::delete(
+            "Remove",
+            list_element(
+                &src_lines,
+                &lines,
+                region(start, start + element.len() as u32),
+            )
+            .unwrap(),
+            Applicability::Safe,
+        );
+
+        assert_eq!(apply_safe_fixes(src, [&fix]).0, expected);
+    }
+
+    #[test]
+    fn applies_only_safe_fixes_in_order() {
+        let src = "abc def ghi";
+        let fixes = [
+            Fix::replace("Rename", region(8, 11), "xyz", Applicability::Safe),
+            Fix::replace("Rename", region(4, 7), "uvw", Applicability::Suggestion),
+            Fix::insert("Prefix", Position::new(0), "_", Applicability::Safe),
+        ];
+
+        assert_eq!(
+            apply_safe_fixes(src, &fixes),
+            ("_abc def xyz".to_string(), 2)
+        );
+    }
+
+    #[test]
+    fn skips_overlapping_fixes() {
+        let src = "abcdef";
+        let fixes = [
+            Fix::delete("Delete", region(1, 4), Applicability::Safe),
+            Fix::delete("Delete", region(3, 5), Applicability::Safe),
+        ];
+
+        assert_eq!(apply_safe_fixes(src, &fixes), ("aef".to_string(), 1));
+    }
+
+    #[test]
+    fn removes_list_elements() {
+        check_list_element(
+            "import Foo exposing [a, b, c]",
+            "a",
+            "import Foo exposing [b, c]",
+        );
+        check_list_element(
+            "import Foo exposing [a, b, c]",
+            "b",
+            "import Foo exposing [a, c]",
+        );
+        check_list_element(
+            "import Foo exposing [a, b, c]",
+            "c",
+            "import Foo exposing [a, b]",
+        );
+        check_list_element(
+            "import Foo exposing [\n    a,\n    b,\n]\n",
+            "b",
+            "import Foo exposing [\n    a,\n]\n",
+        );
+    }
+
+    #[test]
+    fn finds_unique_identifiers() {
+        let src = "{ name: \"x\",\n  nmae: 1, nm: nmae_2, n: nm }";
+        let src_lines: Vec<&str> = src.split('\n').collect();
+        let lines = LineInfo::new(src);
+        let whole = region(0, src.len() as u32);
+        let start = src.find("nmae").unwrap() as u32;
+
+        assert_eq!(
+            unique_identifier(&src_lines, &lines, whole, "nmae"),
+            Some(region(start, start + 4))
+        );
+        assert_eq!(unique_identifier(&src_lines, &lines, whole, "nm"), None);
+        assert_eq!(unique_identifier(&src_lines, &lines, whole, "age"), None);
+    }
+}
@@ -4,5 +4,9 @@
 #![allow(clippy::large_enum_variant)]
 
 pub mod cli;
+pub mod diagnostic;
 pub mod error;
+pub mod error_code;
+pub mod fix;
 pub mod report;
+pub mod warnings;
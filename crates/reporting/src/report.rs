@@ -1,8 +1,11 @@
+use crate::diagnostic::Diagnostic;
+use crate::error_code::{self, ErrorCode};
+use crate::fix::Fix;
 use roc_module::ident::Ident;
 use roc_module::ident::{Lowercase, ModuleName, TagName, Uppercase};
 use roc_module::symbol::{Interns, ModuleId, ModuleIds, PQModuleName, PackageQualified, Symbol};
 use roc_problem::Severity;
-use roc_region::all::LineColumnRegion;
+use roc_region::all::{LineColumnRegion, LineInfo};
 use std::path::{Path, PathBuf};
 use std::{fmt, io};
 use ven_pretty::{text, BoxAllocator, DocAllocator, DocBuilder, Render, RenderAnnotated};
@@ -13,6 +16,7 @@ use byte_unit::Byte;
 use roc_packaging::https::Problem;
 
 pub use crate::error::canonicalize::can_problem;
+pub use crate::error::lint::lint_problem;
 pub use crate::error::parse::parse_problem;
 pub use crate::error::r#type::type_problem;
 
@@ -118,9 +122,13 @@ pub enum RenderTarget {
 /// A textual report.
 pub struct Report<'b> {
     pub title: String,
+    /// The stable code for this kind of report, which must list [Report::title].
+    pub error_code: Option<&'static ErrorCode>,
     pub filename: PathBuf,
     pub doc: RocDocBuilder<'b>,
     pub severity: Severity,
+    /// Edits that would resolve (or help resolve) the problem being reported.
+    pub fixes: Vec<Fix>,
 }
 
 impl<'b> Report<'b> {
@@ -164,20 +172,51 @@ impl<'b> Report<'b> {
             .expect(err_msg);
     }
 
+    /// The stable code for this kind of report, like `R0300`, if it has one.
+    pub fn code(&self) -> Option<&'static str> {
+        let error_code = self.error_code?;
+
+        debug_assert!(
+            error_code.titles.contains(&self.title.as_str()),
+            "{} was given to a report titled {:?}",
+            error_code.code,
+            self.title
+        );
+
+        Some(error_code.code)
+    }
+
     pub fn pretty(self, alloc: &'b RocDocAllocator<'b>) -> RocDocBuilder<'b> {
         if self.title.is_empty() {
             self.doc
         } else {
+            let title = match self.code() {
+                Some(code) => format!("{} [{}]", self.title, code),
+                None => self.title.clone(),
+            };
+
             let header = if self.filename == PathBuf::from("replfile.roc") {
-                crate::report::pretty_header(&self.title)
+                crate::report::pretty_header(&title)
             } else {
-                crate::report::pretty_header_with_path(&self.title, &self.filename)
+                crate::report::pretty_header_with_path(&title, &self.filename)
             };
 
             alloc.stack([alloc.text(header).annotate(Annotation::Header), self.doc])
         }
     }
 
+    /// Render only the body of the report, without colors, for tools that show
+    /// the title and path separately.
+    pub fn render_body(&self, buf: &mut String) {
+        let err_msg = "<buffer is not a utf-8 encoded string>";
+
+        self.doc
+            .clone()
+            .1
+            .render_raw(70, &mut CiWrite::new(buf))
+            .expect(err_msg);
+    }
+
     /// Render report for the language server, where the window is narrower.
     /// Path is not included, and the header is not emphasized with "─".
     pub fn render_language_server(self, buf: &mut String, alloc: &'b RocDocAllocator<'b>) {
@@ -1121,11 +1160,12 @@ where
 }
 
 #[cfg(not(target_family = "wasm"))]
+/// The rendered report for a package that couldn't be downloaded, along with its [Diagnostic].
 pub fn to_https_problem_report_string(
     url: &str,
     https_problem: Problem,
     filename: PathBuf,
-) -> String {
+) -> (String, Diagnostic) {
     let src_lines: Vec<&str> = Vec::new();
 
     let mut module_ids = ModuleIds::default();
@@ -1140,9 +1180,10 @@ pub fn to_https_problem_report_string(
     let mut buf = String::new();
     let palette = DEFAULT_PALETTE;
     let report = to_https_problem_report(&alloc, url, https_problem, filename);
+    let diagnostic = Diagnostic::from_report(&report, &LineInfo::new(""), None, &[]);
     report.render_color_terminal(&mut buf, &alloc, &palette);
 
-    buf
+    (buf, diagnostic)
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -1182,7 +1223,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "UNSUPPORTED ENCODING".to_string(),
+                error_code: Some(error_code::R0050),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::MultipleEncodings(multiple_encodings) => {
@@ -1217,7 +1260,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MULTIPLE ENCODINGS".to_string(),
+                error_code: Some(error_code::R0051),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidContentHash { expected, actual } => {
@@ -1252,7 +1297,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID CONTENT HASH".to_string(),
+                error_code: Some(error_code::R0052),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::NotFound => {
@@ -1269,7 +1316,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "NOTFOUND".to_string(),
+                error_code: Some(error_code::R0053),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         // TODO: The reporting text for IoErr and FsExtraErr could probably be unified
@@ -1296,7 +1345,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "IO ERROR".to_string(),
+                error_code: Some(error_code::R0054),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         // TODO: The reporting text for IoErr and FsExtraErr could probably be unified
@@ -1323,7 +1374,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "IO ERROR".to_string(),
+                error_code: Some(error_code::R0054),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::HttpErr(reqwest_error) => {
@@ -1352,7 +1405,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "HTTP ERROR".to_string(),
+                error_code: Some(error_code::R0055),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::InvalidExtensionSuffix(
@@ -1393,7 +1448,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID EXTENSION SUFFIX".to_string(),
+                error_code: Some(error_code::R0056),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingTarExt) => {
@@ -1426,7 +1483,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID EXTENSION".to_string(),
+                error_code: Some(error_code::R0057),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::InvalidFragment(
@@ -1464,7 +1523,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "INVALID FRAGMENT".to_string(),
+                error_code: Some(error_code::R0058),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingHash) => {
@@ -1502,7 +1563,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MISSING PACKAGE HASH".to_string(),
+                error_code: Some(error_code::R0059),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MissingHttps) => {
@@ -1528,7 +1591,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "HTTPS MANDATORY".to_string(),
+                error_code: Some(error_code::R0060),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::InvalidUrl(roc_packaging::https::UrlProblem::MisleadingCharacter) => {
@@ -1571,7 +1636,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "MISLEADING CHARACTERS".to_string(),
+                error_code: Some(error_code::R0061),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         Problem::DownloadTooBig(content_len) => {
@@ -1601,7 +1668,9 @@ pub fn to_https_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE TOO LARGE".to_string(),
+                error_code: Some(error_code::R0005),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
     }
@@ -1624,6 +1693,18 @@ pub fn to_file_problem_report_string(filename: PathBuf, error: io::ErrorKind) ->
     buf
 }
 
+/// The [Diagnostic] for the report [to_file_problem_report_string] renders.
+pub fn to_file_problem_diagnostic(filename: PathBuf, error: io::ErrorKind) -> Diagnostic {
+    let mut module_ids = ModuleIds::default();
+    let module_id = module_ids.get_or_insert(&"find module name somehow?".into());
+    let interns = Interns::default();
+    let alloc = RocDocAllocator::new(&[], module_id, &interns);
+
+    let report = to_file_problem_report(&alloc, filename, error);
+
+    Diagnostic::from_report(&report, &LineInfo::new(""), None, &[])
+}
+
 pub fn to_file_problem_report<'b>(
     alloc: &'b RocDocAllocator<'b>,
     filename: PathBuf,
@@ -1648,7 +1729,9 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE NOT FOUND".to_string(),
+                error_code: Some(error_code::R0001),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         io::ErrorKind::PermissionDenied => {
@@ -1666,7 +1749,9 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE PERMISSION DENIED".to_string(),
+                error_code: Some(error_code::R0002),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         io::ErrorKind::Unsupported => {
@@ -1702,7 +1787,9 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "NOT A ROC FILE".to_string(),
+                error_code: Some(error_code::R0003),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
         _ => {
@@ -1722,7 +1809,9 @@ pub fn to_file_problem_report<'b>(
                 filename,
                 doc,
                 title: "FILE PROBLEM".to_string(),
+                error_code: Some(error_code::R0004),
                 severity: Severity::Fatal,
+                fixes: Vec::new(),
             }
         }
     }
@@ -0,0 +1,365 @@
+//! Which warnings to report, which to silence, and which to treat as errors.
+//!
+//! Warnings are named by their error code (e.g. `R0202` for an unused definition), or all at
+//! once as `warnings`. Levels come from three places, from weakest to strongest:
+//!
+//! 1. A project-wide `.roc-warnings` file next to the app (or package) being checked,
+//!    with one `allow`, `warn` or `deny` line per code.
+//! 2. The `--allow`, `--warn` and `--deny` flags.
+//! 3. A `# roc: allow R0202` (or `deny`) comment on the line before a def, which covers
+//!    every line of that def.
+//!
+//! A level for a specific code always beats a level for `warnings`, so `deny warnings`
+//! along with `allow R0210` turns every warning but unused arguments into an error.
+//!
+//! The `.roc-warnings` file can also change the settings of lint rules, with lines like
+//! `set repeated-when-length 4`.
+use crate::error_code;
+use roc_collections::VecMap;
+use roc_lint::LintSettings;
+use std::path::Path;
+
+/// The name of the project-wide warning configuration file.
+pub const WARNINGS_FILE_NAME: &str = ".roc-warnings";
+
+/// Stands for every warning, rather than one particular code.
+const ALL_WARNINGS: &str = "warnings";
+
+/// The prefix of a comment that sets warning levels for the def after it.
+const LOCAL_PREFIX: &str = "# roc:";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WarningLevel {
+    /// Don't report the warning at all.
+    Allow,
+    /// Report it as a warning, which is the default.
+    Warn,
+    /// Report it as an error.
+    Deny,
+}
+
+impl<'a> TryFrom<&'a str> for WarningLevel {
+    type Error = ();
+
+    fn try_from(level: &'a str) -> Result<Self, Self::Error> {
+        match level {
+            "allow" => Ok(WarningLevel::Allow),
+            "warn" => Ok(WarningLevel::Warn),
+            "deny" => Ok(WarningLevel::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct WarningConfig {
+    all: Option<WarningLevel>,
+    codes: VecMap<&'static str, WarningLevel>,
+    lint_settings: LintSettings,
+}
+
+impl WarningConfig {
+    /// Set the level for a code (like `R0202`) or for `warnings`. Setting it again later
+    /// replaces what was there before.
+    pub fn set(&mut self, target: &str, level: WarningLevel) -> Result<(), String> {
+        if target == ALL_WARNINGS {
+            self.all = Some(level);
+
+            return Ok(());
+        }
+
+        match error_code::lookup(target) {
+            Some(error_code) => {
+                self.codes.insert(error_code.code, level);
+
+                Ok(())
+            }
+            None => Err(format!(
+                "`{target}` is not an error code. Codes look like R0202, and `roc explain` lists them."
+            )),
+        }
+    }
+
+    /// Parse a `.roc-warnings` file: one `allow`, `warn` or `deny` per line, followed by
+    /// a code or `warnings`, or `set` followed by a lint setting and its value. Blank lines
+    /// and lines starting with `#` are ignored.
+    pub fn parse(src: &str) -> Result<Self, String> {
+        let mut config = WarningConfig::default();
+
+        for (index, line) in src.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_error = |problem: String| format!("line {}: {problem}", index + 1);
+            let mut words = line.split_whitespace();
+            let level = words.next().unwrap_or_default();
+
+            if level == "set" {
+                let (Some(name), Some(value), None) = (words.next(), words.next(), words.next())
+                else {
+                    return Err(line_error(
+                        "`set` takes a lint setting and its value, like `set repeated-when-length 4`"
+                            .to_string(),
+                    ));
+                };
+
+                config.lint_settings.set(name, value).map_err(line_error)?;
+
+                continue;
+            }
+
+            let level = WarningLevel::try_from(level).map_err(|()| {
+                line_error(format!(
+                    "expected `allow`, `warn`, `deny` or `set`, but found `{level}`"
+                ))
+            })?;
+
+            for target in words {
+                config.set(target, level).map_err(line_error)?;
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Read the `.roc-warnings` file in this directory, if there is one.
+    pub fn from_project_dir(dir: &Path) -> Result<Self, String> {
+        let path = dir.join(WARNINGS_FILE_NAME);
+
+        match std::fs::read_to_string(&path) {
+            Ok(src) => Self::parse(&src).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("{}: {err}", path.display())),
+        }
+    }
+
+    /// Layer another configuration on top of this one; its levels win.
+    pub fn extend(&mut self, other: &WarningConfig) {
+        if let Some(level) = other.all {
+            self.all = Some(level);
+        }
+
+        for (code, level) in other.codes.iter() {
+            self.codes.insert(*code, *level);
+        }
+
+        self.lint_settings.extend(&other.lint_settings);
+    }
+
+    /// The settings for lint rules
+    pub fn lint_settings(&self) -> &LintSettings {
+        &self.lint_settings
+    }
+
+    fn code_level(&self, code: Option<&str>) -> Option<WarningLevel> {
+        let code = code?;
+
+        self.codes
+            .iter()
+            .find(|(key, _)| **key == code)
+            .map(|(_, level)| *level)
+    }
+
+    /// The level for a warning with this code, not counting local comments.
+    pub fn level(&self, code: Option<&str>) -> WarningLevel {
+        self.code_level(code)
+            .or(self.all)
+            .unwrap_or(WarningLevel::Warn)
+    }
+
+    /// The level for a warning with this code that starts on the given (0-based) line,
+    /// taking `# roc:` comments in the module's source into account.
+    pub fn level_at(
+        &self,
+        local: &[LocalWarningLevels],
+        code: Option<&str>,
+        line: u32,
+    ) -> WarningLevel {
+        let covering = local
+            .iter()
+            .filter(|levels| levels.first_line <= line && line <= levels.last_line);
+
+        let mut for_code = None;
+        let mut for_all = None;
+
+        // Comments on inner defs come after the ones on the defs around them, so they win.
+        for levels in covering {
+            if let Some(level) = levels.config.code_level(code) {
+                for_code = Some(level);
+            }
+
+            if levels.config.all.is_some() {
+                for_all = levels.config.all;
+            }
+        }
+
+        for_code
+            .or_else(|| self.code_level(code))
+            .or(for_all)
+            .or(self.all)
+            .unwrap_or(WarningLevel::Warn)
+    }
+}
+
+/// Warning levels set by a `# roc:` comment, and the lines of the def they apply to.
+#[derive(Clone, Debug)]
+pub struct LocalWarningLevels {
+    pub first_line: u32,
+    pub last_line: u32,
+    pub config: WarningConfig,
+}
+
+/// Find the `# roc: allow R0202` comments in a module. Each one covers the def that starts on
+/// the next line, up to the next line that is indented no further than the def itself.
+///
+/// Comments that don't parse are ignored, since this runs while reporting other problems.
+pub fn local_warning_levels(src_lines: &[&str]) -> Vec<LocalWarningLevels> {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let mut found = Vec::new();
+
+    for (index, line) in src_lines.iter().enumerate() {
+        let Some(directive) = line.trim_start().strip_prefix(LOCAL_PREFIX) else {
+            continue;
+        };
+        let Ok(config) = WarningConfig::parse(directive) else {
+            continue;
+        };
+
+        // The def is the next line that isn't blank or another comment
+        let Some(first_line) = (index + 1..src_lines.len()).find(|&i| {
+            let trimmed = src_lines[i].trim_start();
+
+            !trimmed.is_empty() && !trimmed.starts_with('#')
+        }) else {
+            continue;
+        };
+
+        let def_indent = indent(src_lines[first_line]);
+        let def_end = |start: usize| {
+            (start + 1..src_lines.len())
+                .find(|&i| !src_lines[i].trim().is_empty() && indent(src_lines[i]) <= def_indent)
+                .unwrap_or(src_lines.len())
+        };
+        let mut end = def_end(first_line);
+
+        // A comment above a type annotation also covers the def it annotates
+        if let Some((name, _)) = src_lines[first_line].trim().split_once(" : ") {
+            let annotated = src_lines.get(end).map_or(false, |line| {
+                indent(line) == def_indent && line.trim_start().starts_with(&format!("{name} "))
+            });
+
+            if annotated {
+                end = def_end(end);
+            }
+        }
+
+        let last_line = end - 1;
+
+        found.push(LocalWarningLevels {
+            first_line: first_line as u32,
+            last_line: last_line as u32,
+            config,
+        });
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod test_warnings {
+    use super::*;
+
+    #[test]
+    fn specific_codes_beat_warnings() {
+        let config = WarningConfig::parse("# Be strict\ndeny warnings\nallow R0210\n").unwrap();
+
+        assert_eq!(config.level(Some("R0202")), WarningLevel::Deny);
+        assert_eq!(config.level(Some("R0210")), WarningLevel::Allow);
+        assert_eq!(config.level(None), WarningLevel::Deny);
+        assert_eq!(
+            WarningConfig::default().level(Some("R0202")),
+            WarningLevel::Warn
+        );
+    }
+
+    #[test]
+    fn later_levels_win() {
+        let mut config = WarningConfig::parse("deny R0202").unwrap();
+        let mut cli = WarningConfig::default();
+        cli.set("r0202", WarningLevel::Allow).unwrap();
+        config.extend(&cli);
+
+        assert_eq!(config.level(Some("R0202")), WarningLevel::Allow);
+    }
+
+    #[test]
+    fn lint_settings() {
+        let config = WarningConfig::parse("deny R0504\nset repeated-when-length 4\n").unwrap();
+        let mut expected = LintSettings::default();
+        expected.set("repeated-when-length", "4").unwrap();
+
+        assert_eq!(config.lint_settings(), &expected);
+        assert!(WarningConfig::parse("set repeated-when-length 1")
+            .unwrap_err()
+            .starts_with("line 1:"));
+        assert!(WarningConfig::parse("set unknown-setting 4").is_err());
+        assert!(WarningConfig::parse("set repeated-when-length").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_codes_and_levels() {
+        assert!(WarningConfig::parse("deny R9999")
+            .unwrap_err()
+            .starts_with("line 1:"));
+        assert!(WarningConfig::parse("\nforbid R0202")
+            .unwrap_err()
+            .starts_with("line 2:"));
+    }
+
+    #[test]
+    fn local_comments_cover_their_def() {
+        let src = [
+            "# roc: allow R0202",
+            "main =",
+            "    unused = 1",
+            "",
+            "    2",
+            "",
+            "other = 3",
+        ];
+        let local = local_warning_levels(&src);
+        let config = WarningConfig::parse("deny warnings").unwrap();
+
+        assert_eq!(local.len(), 1);
+        assert_eq!((local[0].first_line, local[0].last_line), (1, 5));
+        assert_eq!(
+            config.level_at(&local, Some("R0202"), 2),
+            WarningLevel::Allow
+        );
+        assert_eq!(
+            config.level_at(&local, Some("R0210"), 2),
+            WarningLevel::Deny
+        );
+        assert_eq!(
+            config.level_at(&local, Some("R0202"), 6),
+            WarningLevel::Deny
+        );
+    }
+
+    #[test]
+    fn local_comments_cover_annotated_defs() {
+        let src = [
+            "# roc: deny R0210",
+            "f : Str -> Str",
+            "f = \\x ->",
+            "    \"\"",
+            "g = 1",
+        ];
+        let local = local_warning_levels(&src);
+
+        assert_eq!((local[0].first_line, local[0].last_line), (1, 3));
+    }
+}
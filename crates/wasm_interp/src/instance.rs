@@ -170,7 +170,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
     where
         A: IntoIterator<Item = Value>,
     {
-        let (fn_index, param_type_iter, ret_type) =
+        let (fn_index, param_type_iter, return_count) =
             self.call_export_help_before_arg_load(self.module, fn_name)?;
         let n_args = param_type_iter.len();
 
@@ -184,7 +184,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             self.value_store.push(value);
         }
 
-        self.call_export_help_after_arg_load(self.module, fn_index, n_args, ret_type)
+        self.call_export_help_after_arg_load(self.module, fn_index, n_args, return_count)
     }
 
     pub fn call_export_from_cli(
@@ -206,7 +206,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
 
         // Implement the "basic numbers" CLI
         // Check if the called Wasm function takes numeric arguments, and if so, try to parse them from the CLI.
-        let (fn_index, param_type_iter, ret_type) =
+        let (fn_index, param_type_iter, return_count) =
             self.call_export_help_before_arg_load(module, fn_name)?;
         let n_args = param_type_iter.len();
         for (value_bytes, value_type) in arg_strings
@@ -225,14 +225,14 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             self.value_store.push(value);
         }
 
-        self.call_export_help_after_arg_load(module, fn_index, n_args, ret_type)
+        self.call_export_help_after_arg_load(module, fn_index, n_args, return_count)
     }
 
     fn call_export_help_before_arg_load<'m>(
         &mut self,
         module: &'m WasmModule<'a>,
         fn_name: &str,
-    ) -> Result<(usize, SignatureParamsIter<'m>, Option<ValueType>), String> {
+    ) -> Result<(usize, SignatureParamsIter<'m>, usize), String> {
         let fn_index = {
             let mut export_iter = module.export.exports.iter();
             export_iter
@@ -272,7 +272,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             cursor
         };
 
-        let (param_type_iter, return_type) = {
+        let (param_type_iter, return_type_iter) = {
             let signature_index = module.function.signatures[internal_fn_index];
             module.types.look_up(signature_index)
         };
@@ -286,7 +286,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             );
         }
 
-        Ok((fn_index, param_type_iter, return_type))
+        Ok((fn_index, param_type_iter, return_type_iter.len()))
     }
 
     fn call_export_help_after_arg_load(
@@ -294,7 +294,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         module: &WasmModule<'a>,
         fn_index: usize,
         n_args: usize,
-        return_type: Option<ValueType>,
+        return_count: usize,
     ) -> Result<Option<Value>, String> {
         self.previous_frames.clear();
         self.blocks.clear();
@@ -307,7 +307,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             0, // return_addr
             self.blocks.len(),
             n_args,
-            return_type,
+            return_count,
             &module.code.bytes,
             &mut self.value_store,
             &mut self.program_counter,
@@ -355,21 +355,15 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         let Frame {
             return_addr,
             body_block_index,
-            return_type,
+            return_count,
             ..
         } = self.current_frame;
 
-        // Throw away all locals and values except the return value
+        // Throw away all locals and values except the return values
         let locals_block_index = body_block_index - 1;
         let locals_block = &self.blocks[locals_block_index];
-        let new_stack_depth = if return_type.is_some() {
-            self.value_store
-                .set(locals_block.vstack, self.value_store.peek());
-            locals_block.vstack + 1
-        } else {
-            locals_block.vstack
-        };
-        self.value_store.truncate(new_stack_depth);
+        self.value_store
+            .move_top_to(return_count, locals_block.vstack);
 
         // Resume executing at the next instruction in the caller function
         let new_block_len = locals_block_index; // don't need a -1 because one is a length and the other is an index!
@@ -487,8 +481,9 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         &mut self,
         expected_signature: Option<u32>,
         fn_index: usize,
+        is_tail_call: bool,
         module: &WasmModule<'a>,
-    ) -> Result<(), Error> {
+    ) -> Result<Action, Error> {
         // self.debug_values_and_blocks(&format!("start do_call {}", fn_index));
 
         let (signature_index, opt_import) = if fn_index < self.import_count {
@@ -512,10 +507,11 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             );
         }
 
-        let (arg_type_iter, ret_type) = module.types.look_up(signature_index);
+        let (arg_type_iter, return_type_iter) = module.types.look_up(signature_index);
         let n_args = arg_type_iter.len();
+        let return_count = return_type_iter.len();
         if self.debug_string.is_some() {
-            self.debug_call(n_args, ret_type);
+            self.debug_call(n_args, return_count);
         }
 
         if let Some(import) = opt_import {
@@ -543,8 +539,26 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             if let Some(debug_string) = self.debug_string.as_mut() {
                 write!(debug_string, " {}.{}", import.module, import.name).unwrap();
             }
+            if is_tail_call {
+                // We can't jump into the host, so just return whatever it returned
+                return Ok(self.do_return());
+            }
         } else {
-            let return_addr = self.program_counter;
+            let return_addr = if is_tail_call {
+                // Reuse the caller's frame. Move the args down over its locals and drop its blocks.
+                let Frame {
+                    return_addr,
+                    body_block_index,
+                    ..
+                } = self.current_frame;
+                let locals_block_index = body_block_index - 1;
+                let frame_start = self.blocks[locals_block_index].vstack;
+                self.value_store.move_top_to(n_args, frame_start);
+                self.blocks.truncate(locals_block_index);
+                return_addr
+            } else {
+                self.program_counter
+            };
             // set PC to start of function bytes
             let internal_fn_index = fn_index - self.import_count;
             self.program_counter = module.code.function_offsets[internal_fn_index] as usize;
@@ -562,13 +576,26 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 return_addr,
                 body_block_index,
                 n_args,
-                ret_type,
+                return_count,
                 &module.code.bytes,
                 &mut self.value_store,
                 &mut self.program_counter,
             );
             std::mem::swap(&mut swap_frame, &mut self.current_frame);
-            self.previous_frames.push(swap_frame);
+            if !is_tail_call {
+                self.previous_frames.push(swap_frame);
+            }
+
+            if self.debug_string.is_some() {
+                let fn_name = self.debug_fn_name(fn_index);
+                let location = self.debug_source_location(fn_index, self.program_counter);
+                if let Some(debug_string) = self.debug_string.as_mut() {
+                    write!(debug_string, " {fn_name}").unwrap();
+                    if let Some(location) = location {
+                        write!(debug_string, " ({location})").unwrap();
+                    }
+                }
+            }
 
             self.blocks.push(Block {
                 ty: BlockType::FunctionBody(fn_index),
@@ -577,10 +604,30 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         }
         // self.debug_values_and_blocks("end do_call");
 
-        Ok(())
+        Ok(Action::Continue)
+    }
+
+    /// The function's name from the Name section, or an empty string if it doesn't have one
+    fn debug_fn_name(&self, fn_index: usize) -> &'a str {
+        self.module
+            .names
+            .function_names
+            .iter()
+            .find(|(idx, _)| *idx == fn_index as u32)
+            .map(|(_, name)| *name)
+            .unwrap_or("")
     }
 
-    fn debug_call(&mut self, n_args: usize, return_type: Option<ValueType>) {
+    /// Where the code at `address` came from, according to the module's source map
+    fn debug_source_location(&self, fn_index: usize, address: usize) -> Option<String> {
+        let internal_fn_index = fn_index.checked_sub(self.import_count)?;
+        let fn_start = *self.module.code.function_offsets.get(internal_fn_index)? as usize;
+        let offset = address.checked_sub(fn_start)? as u32;
+        let (path, line, column) = self.module.source_map.find(fn_index as u32, offset)?;
+        Some(format!("{path}:{}:{}", line + 1, column + 1))
+    }
+
+    fn debug_call(&mut self, n_args: usize, return_count: usize) {
         if let Some(debug_string) = self.debug_string.as_mut() {
             write!(debug_string, "         args=[").unwrap();
             let arg_iter = self
@@ -596,7 +643,7 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 }
                 write!(debug_string, "{arg:x?}").unwrap();
             }
-            writeln!(debug_string, "] return_type={return_type:?}").unwrap();
+            writeln!(debug_string, "] return_count={return_count}").unwrap();
         }
     }
 
@@ -730,11 +777,11 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
             RETURN => {
                 action = self.do_return();
             }
-            CALL => {
+            CALL | RETURNCALL => {
                 let fn_index = self.fetch_immediate_u32(module) as usize;
-                self.do_call(None, fn_index, module)?;
+                action = self.do_call(None, fn_index, op_code == RETURNCALL, module)?;
             }
-            CALLINDIRECT => {
+            CALLINDIRECT | RETURNCALLINDIRECT => {
                 let expected_signature = self.fetch_immediate_u32(module);
                 let table_index = self.fetch_immediate_u32(module);
                 let element_index = self.value_store.pop_u32()?;
@@ -752,7 +799,12 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                     )
                 });
 
-                self.do_call(Some(expected_signature), fn_index as usize, module)?;
+                action = self.do_call(
+                    Some(expected_signature),
+                    fn_index as usize,
+                    op_code == RETURNCALLINDIRECT,
+                    module,
+                )?;
             }
             DROP => {
                 self.value_store.pop();
@@ -1751,7 +1803,10 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
         }
 
         if let Some(debug_string) = &self.debug_string {
-            if matches!(op_code, CALL | CALLINDIRECT) {
+            if matches!(
+                op_code,
+                CALL | CALLINDIRECT | RETURNCALL | RETURNCALLINDIRECT
+            ) {
                 eprintln!("\n{file_offset:06x} {debug_string}");
             } else {
                 // For calls, we print special debug stuff in do_call
@@ -1806,10 +1861,11 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
 
     /// Dump a stack trace when an error occurs
     /// --------------
-    /// func[123]
+    /// func[123]  my_function
     ///   address  0x12345
-    ///   args     0: I64(234), 1: F64(7.15)
-    ///   locals   2: I32(412), 3: F64(3.14)
+    ///   source   main.roc:12:5
+    ///   args     0 x: I64(234), 1 y: F64(7.15)
+    ///   locals   2 z: I32(412), 3: F64(3.14)
     ///   stack    [I64(111), F64(3.14)]
     /// --------------
     fn debug_stack_trace(&self, buffer: &mut String) -> fmt::Result {
@@ -1853,18 +1909,15 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 self.module.types.look_up(signature_index).0.len()
             };
 
-            let fn_name = self
-                .module
-                .names
-                .function_names
-                .iter()
-                .find(|(idx, _)| *idx == *fn_index as u32)
-                .map(|(_, name)| *name)
-                .unwrap_or("");
+            let fn_name = self.debug_fn_name(*fn_index);
 
             // Function and address match wasm-objdump formatting, for easy copy & find
             writeln!(buffer, "func[{fn_index}]  {fn_name}")?;
-            writeln!(buffer, "  address  {:06x}", execution_addrs.next().unwrap())?;
+            let execution_addr = execution_addrs.next().unwrap();
+            writeln!(buffer, "  address  {execution_addr:06x}")?;
+            if let Some(location) = self.debug_source_location(*fn_index, execution_addr) {
+                writeln!(buffer, "  source   {location}")?;
+            }
 
             write!(buffer, "  args     ")?;
             for local_index in 0..*locals_count {
@@ -1874,7 +1927,15 @@ impl<'a, I: ImportDispatcher> Instance<'a, I> {
                 } else if local_index != 0 {
                     write!(buffer, ", ")?;
                 }
-                write!(buffer, "{local_index}: {value:?}")?;
+                write!(buffer, "{local_index}")?;
+                let local_name = self
+                    .module
+                    .names
+                    .local_name(*fn_index as u32, local_index as u32);
+                if let Some(local_name) = local_name {
+                    write!(buffer, " {local_name}")?;
+                }
+                write!(buffer, ": {value:?}")?;
             }
 
             write!(buffer, "\n  stack    [")?;
@@ -10,7 +10,7 @@ use bumpalo::{collections::Vec, Bump};
 use roc_wasm_module::sections::{Import, ImportDesc};
 use roc_wasm_module::{
     opcodes::OpCode, sections::ElementSegment, Export, ExportType, SerialBuffer, Serialize,
-    Signature, Value, ValueType, WasmModule,
+    Signature, SourceMapping, Value, ValueType, WasmModule,
 };
 
 #[test]
@@ -97,7 +97,7 @@ fn test_loop_help(end: i32, expected: i32) {
 
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: "test",
@@ -125,7 +125,7 @@ fn test_if_else_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(&mut module, "test", signature, &local_types, |buf| {
@@ -188,7 +188,7 @@ fn test_br() {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -288,7 +288,7 @@ fn test_br_if_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -393,7 +393,7 @@ fn test_br_table_help(condition: i32, expected: i32) {
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     let local_types = [(1, ValueType::I32)];
     create_exported_function_with_locals(
@@ -527,7 +527,7 @@ fn test_call_import() {
     });
     module.types.insert(Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
 
     // Function 1, which calls the import
@@ -536,7 +536,7 @@ fn test_call_import() {
     module.code.function_offsets.push(func0_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: start_fn_name,
@@ -586,7 +586,7 @@ fn test_call_return_no_args() {
     module.code.function_offsets.push(func0_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     module.export.append(Export {
         name: start_fn_name,
@@ -616,7 +616,7 @@ fn test_call_return_no_args() {
     module.code.function_offsets.push(func1_offset);
     module.add_function_signature(Signature {
         param_types: Vec::new_in(&arena),
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     [
         0, // no locals
@@ -650,7 +650,7 @@ fn test_call_return_with_args() {
     // Function 0: calculate 2+2
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, "two_plus_two", signature0, |buf| {
         buf.push(OpCode::I32CONST as u8);
@@ -667,7 +667,7 @@ fn test_call_return_with_args() {
     module.code.function_offsets.push(func1_offset);
     module.add_function_signature(Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     });
     [
         0, // no locals
@@ -682,7 +682,7 @@ fn test_call_return_with_args() {
 
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I32],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, "add", signature0, |buf| {
         buf.push(OpCode::GETLOCAL as u8);
@@ -734,7 +734,7 @@ fn test_call_indirect_help(table_index: u32, elem_index: u32) -> Value {
     // function 0: caller
     let signature0 = || Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::I32),
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
     };
     create_exported_function_no_locals(&mut module, start_fn_name, signature0(), |buf| {
         buf.append_u8(OpCode::I32CONST as u8);
@@ -755,7 +755,7 @@ fn test_call_indirect_help(table_index: u32, elem_index: u32) -> Value {
     // function 2: callee, wrong signature
     let signature1 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::F32),
+        ret_types: bumpalo::vec![in &arena; ValueType::F32],
     };
     create_exported_function_no_locals(&mut module, "callee2", signature1, |buf| {
         buf.append_u8(OpCode::F32CONST as u8);
@@ -786,6 +786,112 @@ fn test_call_indirect_help(table_index: u32, elem_index: u32) -> Value {
     inst.call_export(start_fn_name, []).unwrap().unwrap()
 }
 
+#[test]
+fn test_return_call() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    // Function 0: sum the numbers up to 1000
+    let signature0 = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
+    };
+    create_exported_function_no_locals(&mut module, "test", signature0, |buf| {
+        buf.append_u8(OpCode::I32CONST as u8);
+        buf.encode_i32(1000);
+        buf.append_u8(OpCode::I32CONST as u8);
+        buf.encode_i32(0);
+        buf.append_u8(OpCode::CALL as u8);
+        buf.encode_u32(1);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    // Function 1: tail-recursive sum(n, total)
+    let signature1 = Signature {
+        param_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I32],
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
+    };
+    create_exported_function_no_locals(&mut module, "sum", signature1, |buf| {
+        buf.append_u8(OpCode::GETLOCAL as u8);
+        buf.encode_u32(0);
+        buf.append_u8(OpCode::IF as u8);
+        buf.append_u8(ValueType::VOID);
+        {
+            buf.append_u8(OpCode::GETLOCAL as u8);
+            buf.encode_u32(0);
+            buf.append_u8(OpCode::I32CONST as u8);
+            buf.encode_i32(1);
+            buf.append_u8(OpCode::I32SUB as u8);
+
+            buf.append_u8(OpCode::GETLOCAL as u8);
+            buf.encode_u32(1);
+            buf.append_u8(OpCode::GETLOCAL as u8);
+            buf.encode_u32(0);
+            buf.append_u8(OpCode::I32ADD as u8);
+
+            buf.append_u8(OpCode::RETURNCALL as u8);
+            buf.encode_u32(1);
+        }
+        buf.append_u8(OpCode::END as u8);
+        buf.append_u8(OpCode::GETLOCAL as u8);
+        buf.encode_u32(1);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+    let result = inst.call_export("test", []).unwrap().unwrap();
+
+    assert_eq!(result, Value::I32(500500));
+}
+
+#[test]
+fn test_multi_value_return() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    // Function 0: subtract the two values returned by function 2
+    let signature0 = Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::I32],
+    };
+    create_exported_function_no_locals(&mut module, "test", signature0, |buf| {
+        buf.append_u8(OpCode::CALL as u8);
+        buf.encode_u32(2);
+        buf.append_u8(OpCode::I32WRAPI64 as u8);
+        buf.append_u8(OpCode::I32SUB as u8);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    // Function 1: return two values
+    let signature1 = || Signature {
+        param_types: bumpalo::vec![in &arena],
+        ret_types: bumpalo::vec![in &arena; ValueType::I32, ValueType::I64],
+    };
+    create_exported_function_no_locals(&mut module, "pair", signature1(), |buf| {
+        buf.append_u8(OpCode::I32CONST as u8);
+        buf.encode_i32(7);
+        buf.append_u8(OpCode::I64CONST as u8);
+        buf.encode_i32(3); // same LEB-128 encoding as i64
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    // Function 2: forward both values with a tail call, leaving junk on the stack below it
+    create_exported_function_no_locals(&mut module, "forward", signature1(), |buf| {
+        buf.append_u8(OpCode::F64CONST as u8);
+        buf.encode_f64(1.5);
+        buf.append_u8(OpCode::RETURNCALL as u8);
+        buf.encode_u32(1);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    let mut inst =
+        Instance::for_module(&arena, &module, DefaultImportDispatcher::default(), false).unwrap();
+    let result = inst.call_export("test", []).unwrap().unwrap();
+
+    assert_eq!(result, Value::I32(4));
+}
+
 // #[test]
 // fn test_drop() {}
 
@@ -802,7 +908,7 @@ fn test_select_help(first: Value, second: Value, condition: i32, expected: Value
     // Function 0: calculate 2+2
     let signature0 = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ValueType::from(expected)),
+        ret_types: bumpalo::vec![in &arena; ValueType::from(expected)],
     };
     create_exported_function_no_locals(&mut module, "test", signature0, |buf| {
         const_value(buf, first);
@@ -839,13 +945,13 @@ fn test_set_get_local() {
     let return_addr = 0x1234;
     let return_block_depth = 0;
     let n_args = 0;
-    let ret_type = Some(ValueType::I32);
+    let return_count = 1;
     inst.current_frame = Frame::enter(
         fn_index,
         return_addr,
         return_block_depth,
         n_args,
-        ret_type,
+        return_count,
         &buffer,
         &mut inst.value_store,
         &mut cursor,
@@ -886,13 +992,13 @@ fn test_tee_get_local() {
     let return_addr = 0x1234;
     let return_block_depth = 0;
     let n_args = 0;
-    let ret_type = Some(ValueType::I32);
+    let return_count = 1;
     inst.current_frame = Frame::enter(
         fn_index,
         return_addr,
         return_block_depth,
         n_args,
-        ret_type,
+        return_count,
         &buffer,
         &mut inst.value_store,
         &mut cursor,
@@ -992,3 +1098,48 @@ fn test_f64const() {
     state.execute_next_instruction(&module).unwrap();
     assert_eq!(state.value_store.pop(), Value::F64(12345.67890))
 }
+
+#[test]
+fn test_stack_trace_debug_info() {
+    let arena = Bump::new();
+    let mut module = WasmModule::new(&arena);
+
+    let signature = Signature {
+        param_types: bumpalo::vec![in &arena; ValueType::I32],
+        ret_types: bumpalo::vec![in &arena],
+    };
+    create_exported_function_no_locals(&mut module, "test", signature, |buf| {
+        buf.append_u8(OpCode::NOP as u8);
+        buf.append_u8(OpCode::UNREACHABLE as u8);
+        buf.append_u8(OpCode::END as u8);
+    });
+
+    module.names.append_function(0, "crash");
+    module
+        .names
+        .append_locals(0, bumpalo::vec![in &arena; (0, "input")]);
+
+    // Function header is a padded length and a locals count, then the NOP is at offset 6
+    let source_index = module.source_map.add_source("main.roc", None);
+    for (offset, line) in [(0, 1), (7, 2)] {
+        module.source_map.append(SourceMapping {
+            fn_index: 0,
+            offset,
+            source_index,
+            line,
+            column: 4,
+        });
+    }
+
+    // Go through the binary, to check that the debug info survives serialization
+    let mut bytes = std::vec::Vec::with_capacity(module.size());
+    module.serialize(&mut bytes);
+    let mut inst =
+        Instance::from_bytes(&arena, &bytes, DefaultImportDispatcher::default(), false).unwrap();
+
+    let error = inst.call_export("test", [Value::I32(7)]).unwrap_err();
+
+    assert!(error.contains("func[0]  crash"), "{error}");
+    assert!(error.contains("source   main.roc:3:5"), "{error}");
+    assert!(error.contains("args     0 input: I32(7)"), "{error}");
+}
@@ -137,7 +137,7 @@ fn test_load(load_op: OpCode, ty: ValueType, data: &[u8], addr: u32, offset: u32
 
     let signature = Signature {
         param_types: bumpalo::vec![in &arena],
-        ret_type: Some(ty),
+        ret_types: bumpalo::vec![in &arena; ty],
     };
 
     create_exported_function_no_locals(&mut module, start_fn_name, signature, |buf| {
@@ -325,7 +325,7 @@ fn test_store<'a>(
 
     let signature = Signature {
         param_types: bumpalo::vec![in arena],
-        ret_type: None,
+        ret_types: bumpalo::vec![in arena],
     };
 
     create_exported_function_no_locals(module, start_fn_name, signature, |buf| {
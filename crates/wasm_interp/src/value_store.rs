@@ -112,6 +112,13 @@ impl<'a> ValueStore<'a> {
         self.values.truncate(depth)
     }
 
+    /// Move the top `count` values down to `depth`, discarding everything in between
+    pub(crate) fn move_top_to(&mut self, count: usize, depth: usize) {
+        let start = self.values.len() - count;
+        self.values.copy_within(start.., depth);
+        self.values.truncate(depth + count);
+    }
+
     pub(crate) fn get_slice(&mut self, from: usize) -> &[Value] {
         &self.values[from..]
     }
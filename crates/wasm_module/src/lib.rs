@@ -9,7 +9,7 @@ use std::iter::repeat;
 pub use linking::{OffsetRelocType, RelocationEntry, SymInfo};
 use opcodes::OpCode;
 use roc_error_macros::internal_error;
-pub use sections::{ConstExpr, Export, ExportType, Global, GlobalType, Signature};
+pub use sections::{ConstExpr, Export, ExportType, Global, GlobalType, Signature, SourceMapping};
 
 use bitvec::vec::BitVec;
 use bumpalo::{collections::Vec, Bump};
@@ -19,7 +19,7 @@ use self::parse::{Parse, ParseError};
 use self::sections::{
     CodeSection, DataSection, ElementSection, ExportSection, FunctionSection, GlobalSection,
     ImportDesc, ImportSection, MemorySection, NameSection, OpaqueSection, Section, SectionId,
-    TableSection, TypeSection,
+    SourceMapSection, TableSection, TypeSection,
 };
 pub use self::serialize::{SerialBuffer, Serialize};
 
@@ -45,6 +45,7 @@ pub struct WasmModule<'a> {
     pub reloc_code: RelocationSection<'a>,
     pub reloc_data: RelocationSection<'a>,
     pub names: NameSection<'a>,
+    pub source_map: SourceMapSection<'a>,
 }
 
 impl<'a> WasmModule<'a> {
@@ -67,6 +68,7 @@ impl<'a> WasmModule<'a> {
             reloc_code: RelocationSection::new(arena, "reloc.CODE"),
             reloc_data: RelocationSection::new(arena, "reloc.DATA"),
             names: NameSection::new(arena),
+            source_map: SourceMapSection::new(arena),
         }
     }
 
@@ -93,9 +95,16 @@ impl<'a> WasmModule<'a> {
         self.export.serialize(buffer);
         self.start.serialize(buffer);
         self.element.serialize(buffer);
+        let code_section_start = buffer.size();
         self.code.serialize(buffer);
         self.data.serialize(buffer);
         self.names.serialize(buffer);
+        if !self.source_map.is_empty() {
+            let function_offsets = self
+                .code
+                .serialized_function_offsets(self.import.function_count(), code_section_start);
+            self.source_map.serialize(buffer, &function_offsets);
+        }
     }
 
     /// Module size in bytes (assuming no linker data)
@@ -113,6 +122,7 @@ impl<'a> WasmModule<'a> {
             + self.code.size()
             + self.data.size()
             + self.names.size()
+            + self.source_map.size()
     }
 
     pub fn preload(
@@ -150,6 +160,7 @@ impl<'a> WasmModule<'a> {
         let mut reloc_code = RelocationSection::new(arena, "reloc.CODE");
         let mut reloc_data = RelocationSection::new(arena, "reloc.DATA");
         let mut names = NameSection::new(arena);
+        let mut source_map = SourceMapSection::new(arena);
 
         // Consume all remaining Custom sections
         while let Ok((section_name, section_end)) = Self::peek_custom_section(arena, bytes, cursor)
@@ -169,6 +180,14 @@ impl<'a> WasmModule<'a> {
                 "name" => {
                     names = NameSection::parse(arena, bytes, &mut cursor)?;
                 }
+                "sourceMappingURL" => {
+                    let import_fn_count = import.function_count();
+                    source_map = SourceMapSection::parse(
+                        (arena, &code, import_fn_count),
+                        bytes,
+                        &mut cursor,
+                    )?;
+                }
                 _ => {
                     cursor = section_end;
                 }
@@ -230,6 +249,7 @@ impl<'a> WasmModule<'a> {
             reloc_code,
             reloc_data,
             names,
+            source_map,
         })
     }
 
@@ -354,6 +374,13 @@ impl<'a> WasmModule<'a> {
             self.names.function_names[old_index].1 = new_name;
         }
 
+        // Dead functions are replaced with dummies, which have no locals or source locations
+        let is_live = |fn_index: u32| live_flags.get(fn_index as usize).map_or(true, |f| *f);
+        self.names
+            .local_names
+            .retain(|(fn_index, _)| is_live(*fn_index));
+        self.source_map.mappings.retain(|m| is_live(m.fn_index));
+
         // Relocate calls to JS imports
         // This must happen *before* we run dead code elimination on the code section,
         // so that byte offsets in the linking data will still be valid.
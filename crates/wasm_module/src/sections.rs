@@ -198,7 +198,7 @@ pub fn update_section_size<T: SerialBuffer>(buffer: &mut T, header_indices: Sect
 #[derive(PartialEq, Eq, Debug)]
 pub struct Signature<'a> {
     pub param_types: Vec<'a, ValueType>,
-    pub ret_type: Option<ValueType>,
+    pub ret_types: Vec<'a, ValueType>,
 }
 
 impl Signature<'_> {
@@ -209,7 +209,7 @@ impl<'a> Serialize for Signature<'a> {
     fn serialize<T: SerialBuffer>(&self, buffer: &mut T) {
         buffer.append_u8(Self::SEPARATOR);
         self.param_types.serialize(buffer);
-        self.ret_type.serialize(buffer);
+        self.ret_types.serialize(buffer);
     }
 }
 
@@ -299,23 +299,24 @@ impl<'a> TypeSection<'a> {
         self.bytes.is_empty()
     }
 
-    pub fn look_up(&'a self, sig_index: u32) -> (SignatureParamsIter<'a>, Option<ValueType>) {
+    /// Look up the parameter and return types of a signature
+    pub fn look_up(&'a self, sig_index: u32) -> (SignatureParamsIter<'a>, SignatureParamsIter<'a>) {
         let mut offset = self.offsets[sig_index as usize];
         offset += 1; // separator
-        let param_count = u32::parse((), &self.bytes, &mut offset).unwrap() as usize;
-        let params_iter = SignatureParamsIter {
-            bytes: &self.bytes[offset..][..param_count],
-            index: 0,
-            end: param_count,
-        };
-        offset += param_count;
+        let params_iter = self.value_types_at(&mut offset);
+        let returns_iter = self.value_types_at(&mut offset);
+        (params_iter, returns_iter)
+    }
 
-        let return_type = if self.bytes[offset] == 0 {
-            None
-        } else {
-            Some(ValueType::from(self.bytes[offset + 1]))
+    fn value_types_at(&'a self, offset: &mut usize) -> SignatureParamsIter<'a> {
+        let count = u32::parse((), &self.bytes, offset).unwrap() as usize;
+        let iter = SignatureParamsIter {
+            bytes: &self.bytes[*offset..][..count],
+            index: 0,
+            end: count,
         };
-        (params_iter, return_type)
+        *offset += count;
+        iter
     }
 }
 
@@ -1450,6 +1451,64 @@ impl<'a> CodeSection<'a> {
     }
 }
 
+impl<'a> CodeSection<'a> {
+    /// Where each function will start in the serialized module, indexed by function index.
+    /// Imported functions have no code, so their entries are zero.
+    pub fn serialized_function_offsets(
+        &self,
+        import_fn_count: usize,
+        section_start: usize,
+    ) -> std::vec::Vec<u32> {
+        let total_count = self.dead_import_dummy_count + self.function_count;
+        let mut offsets = std::vec::Vec::with_capacity(import_fn_count + total_count as usize);
+        offsets.resize(import_fn_count, 0);
+
+        let mut count_bytes = std::vec::Vec::with_capacity(MAX_SIZE_ENCODED_U32);
+        count_bytes.encode_u32(total_count);
+        let mut file_offset = section_start + 1 + MAX_SIZE_ENCODED_U32 + count_bytes.len();
+
+        // Dummies for dead imports come first. Each is a length byte plus its code.
+        for _ in 0..self.dead_import_dummy_count {
+            offsets.push(file_offset as u32);
+            file_offset += 1 + DUMMY_FUNCTION.len();
+        }
+
+        // Function sizes may have changed since `function_offsets` was built, so walk the bytes.
+        if let Some(first_fn_start) = self.function_offsets.first() {
+            let mut cursor = *first_fn_start as usize;
+            while cursor < self.bytes.len() {
+                offsets.push((file_offset + cursor - *first_fn_start as usize) as u32);
+                let fn_length = u32::parse((), &self.bytes, &mut cursor).unwrap();
+                cursor += fn_length as usize;
+            }
+        }
+
+        offsets
+    }
+
+    /// The serialized size of each function defined in this section, including its length prefix,
+    /// indexed from the first function after the dead import dummies.
+    /// Functions replaced by a dummy in dead code elimination are `None`.
+    pub fn function_sizes(&self) -> std::vec::Vec<Option<u32>> {
+        let mut sizes = std::vec::Vec::with_capacity(self.function_count as usize);
+
+        if let Some(first_fn_start) = self.function_offsets.first() {
+            let mut cursor = *first_fn_start as usize;
+            while cursor < self.bytes.len() {
+                let fn_start = cursor;
+                let fn_length = u32::parse((), &self.bytes, &mut cursor).unwrap();
+                let body = &self.bytes[cursor..][..fn_length as usize];
+                cursor += fn_length as usize;
+
+                let is_dummy = body == DUMMY_FUNCTION;
+                sizes.push((!is_dummy).then_some((cursor - fn_start) as u32));
+            }
+        }
+
+        sizes
+    }
+}
+
 impl<'a> Serialize for CodeSection<'a> {
     fn serialize<T: SerialBuffer>(&self, buffer: &mut T) {
         let header_indices = write_section_header(buffer, SectionId::Code);
@@ -1713,6 +1772,8 @@ enum NameSubSections {
 
 pub struct NameSection<'a> {
     pub function_names: Vec<'a, (u32, &'a str)>,
+    /// For each function that has them, the names of its local variables, in order of local index
+    pub local_names: Vec<'a, (u32, Vec<'a, (u32, &'a str)>)>,
 }
 
 impl<'a> NameSection<'a> {
@@ -1720,19 +1781,43 @@ impl<'a> NameSection<'a> {
     const NAME: &'static str = "name";
 
     pub fn size(&self) -> usize {
-        self.function_names
+        let function_names_size: usize = self
+            .function_names
             .iter()
             .map(|(_, s)| MAX_SIZE_ENCODED_U32 + s.len())
-            .sum()
+            .sum();
+        let local_names_size: usize = self
+            .local_names
+            .iter()
+            .flat_map(|(_, locals)| locals.iter())
+            .map(|(_, s)| MAX_SIZE_ENCODED_U32 + s.len())
+            .sum();
+        function_names_size + local_names_size + 2 * MAX_SIZE_ENCODED_U32 * self.local_names.len()
     }
 
     pub fn append_function(&mut self, index: u32, name: &'a str) {
         self.function_names.push((index, name));
     }
 
+    pub fn append_locals(&mut self, fn_index: u32, locals: Vec<'a, (u32, &'a str)>) {
+        if !locals.is_empty() {
+            self.local_names.push((fn_index, locals));
+        }
+    }
+
+    /// Look up the name of a local variable in a function, if we have one
+    pub fn local_name(&self, fn_index: u32, local_index: u32) -> Option<&'a str> {
+        let (_, locals) = self.local_names.iter().find(|(i, _)| *i == fn_index)?;
+        locals
+            .iter()
+            .find(|(i, _)| *i == local_index)
+            .map(|(_, name)| *name)
+    }
+
     pub fn new(arena: &'a Bump) -> Self {
         NameSection {
             function_names: bumpalo::vec![in arena],
+            local_names: bumpalo::vec![in arena],
         }
     }
 
@@ -1758,7 +1843,10 @@ impl<'a> NameSection<'a> {
         let mut function_names = Vec::from_iter_in(names, arena);
         function_names.sort_by_key(|(idx, _name)| *idx);
 
-        NameSection { function_names }
+        NameSection {
+            function_names,
+            local_names: bumpalo::vec![in arena],
+        }
     }
 }
 
@@ -1789,23 +1877,48 @@ impl<'a> Parse<&'a Bump> for NameSection<'a> {
             return Ok(Self::new(arena));
         }
 
-        // Find function names subsection
+        let mut section = Self::new(arena);
+
+        // Read the subsections we know about, and skip the rest
         let mut found_function_names = false;
-        for _possible_subsection_id in 0..2 {
+        while *cursor < section_end {
             let subsection_id = module_bytes[*cursor];
             *cursor += 1;
             let subsection_size = u32::parse((), module_bytes, cursor)?;
+            let subsection_end = *cursor + subsection_size as usize;
+
             if subsection_id == NameSubSections::FunctionNames as u8 {
                 found_function_names = true;
-                break;
+                let count = u32::parse((), module_bytes, cursor)?;
+                section.function_names.reserve(count as usize);
+                for _ in 0..count {
+                    let index = u32::parse((), module_bytes, cursor)?;
+                    let name = <&'a str>::parse(arena, module_bytes, cursor)?;
+                    section.function_names.push((index, name));
+                }
+            } else if subsection_id == NameSubSections::LocalNames as u8 {
+                let fn_count = u32::parse((), module_bytes, cursor)?;
+                section.local_names.reserve(fn_count as usize);
+                for _ in 0..fn_count {
+                    let fn_index = u32::parse((), module_bytes, cursor)?;
+                    let local_count = u32::parse((), module_bytes, cursor)?;
+                    let mut locals = Vec::with_capacity_in(local_count as usize, arena);
+                    for _ in 0..local_count {
+                        let local_index = u32::parse((), module_bytes, cursor)?;
+                        let name = <&'a str>::parse(arena, module_bytes, cursor)?;
+                        locals.push((local_index, name));
+                    }
+                    section.local_names.push((fn_index, locals));
+                }
             }
-            *cursor += subsection_size as usize;
-            if *cursor >= section_end {
+
+            if *cursor > subsection_end || subsection_end > section_end {
                 return Err(ParseError {
                     message: "Failed to parse Name section".into(),
                     offset: *cursor,
                 });
             }
+            *cursor = subsection_end;
         }
         if !found_function_names {
             return Err(ParseError {
@@ -1814,41 +1927,49 @@ impl<'a> Parse<&'a Bump> for NameSection<'a> {
             });
         }
 
-        let count = u32::parse((), module_bytes, cursor)?;
-        let mut section = NameSection {
-            function_names: Vec::with_capacity_in(count as usize, arena),
-        };
-
-        // Function names
-        for _ in 0..count {
-            let index = u32::parse((), module_bytes, cursor)?;
-            let name = <&'a str>::parse(arena, module_bytes, cursor)?;
-            section.function_names.push((index, name));
-        }
-
         *cursor = section_end;
 
         Ok(section)
     }
 }
 
+/// Write a subsection of the Name section, with its size in front of it
+fn serialize_name_subsection<T: SerialBuffer>(
+    buffer: &mut T,
+    id: NameSubSections,
+    write_body: impl FnOnce(&mut T),
+) {
+    (id as u8).serialize(buffer);
+
+    let subsection_size_index = buffer.encode_padded_u32(0);
+    let subsection_start = buffer.size();
+
+    write_body(buffer);
+
+    buffer.overwrite_padded_u32(
+        subsection_size_index,
+        (buffer.size() - subsection_start) as u32,
+    );
+}
+
 impl<'a> Serialize for NameSection<'a> {
     fn serialize<T: SerialBuffer>(&self, buffer: &mut T) {
         if !self.function_names.is_empty() {
             let header_indices = write_custom_section_header(buffer, Self::NAME);
 
-            let subsection_id = NameSubSections::FunctionNames as u8;
-            subsection_id.serialize(buffer);
-
-            let subsection_size_index = buffer.encode_padded_u32(0);
-            let subsection_start = buffer.size();
-
-            self.function_names.serialize(buffer);
+            serialize_name_subsection(buffer, NameSubSections::FunctionNames, |buffer| {
+                self.function_names.serialize(buffer);
+            });
 
-            buffer.overwrite_padded_u32(
-                subsection_size_index,
-                (buffer.size() - subsection_start) as u32,
-            );
+            if !self.local_names.is_empty() {
+                serialize_name_subsection(buffer, NameSubSections::LocalNames, |buffer| {
+                    buffer.encode_u32(self.local_names.len() as u32);
+                    for (fn_index, locals) in self.local_names.iter() {
+                        buffer.encode_u32(*fn_index);
+                        locals.serialize(buffer);
+                    }
+                });
+            }
 
             update_section_size(buffer, header_indices);
         }
@@ -1863,10 +1984,339 @@ impl<'a> Debug for NameSection<'a> {
             writeln!(f, "  {index:4}: {name}")?;
         }
 
+        for (fn_index, locals) in self.local_names.iter() {
+            writeln!(f, "  locals of {fn_index}:")?;
+            for (index, name) in locals.iter() {
+                writeln!(f, "    {index:4}: {name}")?;
+            }
+        }
+
         Ok(())
     }
 }
 
+/*******************************************************************
+ *
+ * Source map
+ * https://github.com/WebAssembly/tool-conventions/blob/main/Debugging.md#source-maps
+ *
+ *******************************************************************/
+
+/// Where the code starting at `offset` in a function came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceMapping {
+    pub fn_index: u32,
+    /// Byte offset from the start of the function in the Code section (its length prefix)
+    pub offset: u32,
+    pub source_index: u32,
+    /// 0-based, like source maps want it
+    pub line: u32,
+    /// 0-based, like source maps want it
+    pub column: u32,
+}
+
+/// A source map, mapping code offsets to lines and columns in the source files.
+/// We embed the whole map in the module as a data URL, so there is no separate file to keep track of.
+/// Browser devtools find it through the `sourceMappingURL` custom section.
+#[derive(Debug)]
+pub struct SourceMapSection<'a> {
+    /// Paths of the source files, with their contents if we have them
+    pub sources: Vec<'a, (&'a str, Option<&'a str>)>,
+    pub mappings: Vec<'a, SourceMapping>,
+}
+
+impl<'a> SourceMapSection<'a> {
+    const NAME: &'static str = "sourceMappingURL";
+    const DATA_URL_PREFIX: &'static str = "data:application/json;base64,";
+
+    pub fn new(arena: &'a Bump) -> Self {
+        SourceMapSection {
+            sources: bumpalo::vec![in arena],
+            mappings: bumpalo::vec![in arena],
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    /// Estimated size. Base64 encoding adds a third.
+    pub fn size(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+        let sources_size: usize = self
+            .sources
+            .iter()
+            .map(|(path, content)| path.len() + content.map_or(0, str::len) + 8)
+            .sum();
+        let json_size = 64 + sources_size + self.mappings.len() * 16;
+        MAX_SIZE_SECTION_HEADER + Self::NAME.len() + Self::DATA_URL_PREFIX.len() + json_size * 4 / 3
+    }
+
+    /// Add a source file, returning its index
+    pub fn add_source(&mut self, path: &'a str, content: Option<&'a str>) -> u32 {
+        self.sources.push((path, content));
+        self.sources.len() as u32 - 1
+    }
+
+    pub fn append(&mut self, mapping: SourceMapping) {
+        self.mappings.push(mapping);
+    }
+
+    /// Find the source location of the code at `offset` in a function.
+    /// Returns the path of the source file, and the 0-based line and column.
+    pub fn find(&self, fn_index: u32, offset: u32) -> Option<(&'a str, u32, u32)> {
+        let mapping = self
+            .mappings
+            .iter()
+            .filter(|m| m.fn_index == fn_index && m.offset <= offset)
+            .max_by_key(|m| m.offset)?;
+        let (path, _) = self.sources.get(mapping.source_index as usize)?;
+        Some((path, mapping.line, mapping.column))
+    }
+
+    /// Serialize the section, given the offset in the file of each function in the Code section,
+    /// indexed by function index (see `CodeSection::serialized_function_offsets`).
+    pub fn serialize<T: SerialBuffer>(&self, buffer: &mut T, function_offsets: &[u32]) {
+        if self.is_empty() {
+            return;
+        }
+
+        let json = self.to_json(function_offsets);
+        let mut url = String::with_capacity(Self::DATA_URL_PREFIX.len() + json.len() * 4 / 3 + 4);
+        url.push_str(Self::DATA_URL_PREFIX);
+        base64_encode(json.as_bytes(), &mut url);
+
+        let header_indices = write_custom_section_header(buffer, Self::NAME);
+        url.as_str().serialize(buffer);
+        update_section_size(buffer, header_indices);
+    }
+
+    fn to_json(&self, function_offsets: &[u32]) -> String {
+        // Source map columns are offsets in the whole Wasm file, and they must be in order
+        let mut file_mappings: std::vec::Vec<(u32, &SourceMapping)> = self
+            .mappings
+            .iter()
+            .filter_map(|m| {
+                let fn_offset = function_offsets.get(m.fn_index as usize)?;
+                Some((fn_offset + m.offset, m))
+            })
+            .collect();
+        file_mappings.sort_by_key(|(file_offset, _)| *file_offset);
+
+        // Every field of a segment is relative to the same field in the previous one
+        let mut mappings = String::with_capacity(file_mappings.len() * 8);
+        let mut prev = [0i64; 4];
+        for (file_offset, m) in file_mappings {
+            let fields = [
+                file_offset as i64,
+                m.source_index as i64,
+                m.line as i64,
+                m.column as i64,
+            ];
+            if !mappings.is_empty() {
+                mappings.push(',');
+            }
+            for (field, prev_field) in fields.iter().zip(prev.iter_mut()) {
+                vlq_encode(field - *prev_field, &mut mappings);
+                *prev_field = *field;
+            }
+        }
+
+        let sources: std::vec::Vec<&str> = self.sources.iter().map(|(path, _)| *path).collect();
+        let sources_content: std::vec::Vec<Option<&str>> =
+            self.sources.iter().map(|(_, content)| *content).collect();
+
+        serde_json::json!({
+            "version": 3,
+            "sources": sources,
+            "sourcesContent": sources_content,
+            "names": [],
+            "mappings": mappings,
+        })
+        .to_string()
+    }
+}
+
+/// Parse a source map, using the Code section to turn file offsets back into function offsets.
+/// We can only read maps embedded as data URLs. If the map is in another file, we ignore it.
+impl<'a> Parse<(&'a Bump, &CodeSection<'a>, usize)> for SourceMapSection<'a> {
+    fn parse(
+        (arena, code, import_fn_count): (&'a Bump, &CodeSection<'a>, usize),
+        module_bytes: &[u8],
+        cursor: &mut usize,
+    ) -> Result<Self, ParseError> {
+        let mut section = Self::new(arena);
+
+        if *cursor >= module_bytes.len() || module_bytes[*cursor] != SectionId::Custom as u8 {
+            return Ok(section);
+        }
+        let section_start = *cursor;
+        *cursor += 1;
+        let section_size = u32::parse((), module_bytes, cursor)? as usize;
+        let section_end = *cursor + section_size;
+
+        let section_name = <&'a str>::parse(arena, module_bytes, cursor)?;
+        if section_name != Self::NAME {
+            *cursor = section_start;
+            return Ok(section);
+        }
+
+        let url = <&'a str>::parse(arena, module_bytes, cursor)?;
+        *cursor = section_end;
+
+        let encoded = match url.strip_prefix(Self::DATA_URL_PREFIX) {
+            Some(encoded) => encoded,
+            None => return Ok(section),
+        };
+
+        let error = |message: &str| ParseError {
+            offset: section_start,
+            message: format!("Failed to parse source map: {message}"),
+        };
+
+        let json_bytes = base64_decode(encoded).ok_or_else(|| error("invalid base64"))?;
+        let json: serde_json::Value =
+            serde_json::from_slice(&json_bytes).map_err(|e| error(&e.to_string()))?;
+
+        let sources = json["sources"]
+            .as_array()
+            .ok_or_else(|| error("missing sources"))?;
+        for (i, path) in sources.iter().enumerate() {
+            let path = path.as_str().ok_or_else(|| error("invalid source path"))?;
+            let content = json["sourcesContent"][i]
+                .as_str()
+                .map(|content| &*arena.alloc_str(content));
+            section.add_source(arena.alloc_str(path), content);
+        }
+
+        let mappings = json["mappings"]
+            .as_str()
+            .ok_or_else(|| error("missing mappings"))?;
+
+        let mut fields = [0i64; 4];
+        for line in mappings.split(';') {
+            // The generated column starts again from zero on each line. (Wasm only has one.)
+            fields[0] = 0;
+            for segment in line.split(',').filter(|seg| !seg.is_empty()) {
+                let deltas = vlq_decode(segment).ok_or_else(|| error("invalid mappings"))?;
+                for (field, delta) in fields.iter_mut().zip(deltas.iter()) {
+                    *field += delta;
+                }
+                if deltas.len() < 4 {
+                    // No source location for this code
+                    continue;
+                }
+
+                let code_offset = fields[0] - code.section_offset as i64;
+                if code_offset < 0 {
+                    continue;
+                }
+                let code_offset = code_offset as u32;
+                let fn_position = code
+                    .function_offsets
+                    .partition_point(|fn_offset| *fn_offset <= code_offset);
+                if fn_position == 0 {
+                    continue;
+                }
+                let code_fn_index = fn_position - 1;
+
+                section.append(SourceMapping {
+                    fn_index: (import_fn_count + code_fn_index) as u32,
+                    offset: code_offset - code.function_offsets[code_fn_index],
+                    source_index: fields[1] as u32,
+                    line: fields[2] as u32,
+                    column: fields[3] as u32,
+                });
+            }
+        }
+
+        Ok(section)
+    }
+}
+
+const BASE64_CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn base64_value(c: u8) -> Option<u32> {
+    BASE64_CHARS.iter().position(|b| *b == c).map(|v| v as u32)
+}
+
+fn base64_encode(bytes: &[u8], out: &mut String) {
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        let n = (b[0] as u32) << 16 | (b[1] as u32) << 8 | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(BASE64_CHARS[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
+
+fn base64_decode(text: &str) -> Option<std::vec::Vec<u8>> {
+    let text = text.trim_end_matches('=').as_bytes();
+    let mut bytes = std::vec::Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.chunks(4) {
+        let mut n = 0;
+        for (i, c) in chunk.iter().enumerate() {
+            n |= base64_value(*c)? << (18 - 6 * i);
+        }
+        let byte_count = chunk.len() * 3 / 4;
+        bytes.extend((0..byte_count).map(|i| (n >> (16 - 8 * i)) as u8));
+    }
+    Some(bytes)
+}
+
+/// Base64 variable-length quantity, the number format in source map `mappings`.
+/// The lowest bit of the first digit is the sign, and bit 5 of each digit means there's another one.
+fn vlq_encode(value: i64, out: &mut String) {
+    let mut rest = if value < 0 {
+        ((-value) << 1) | 1
+    } else {
+        value << 1
+    };
+    loop {
+        let mut digit = rest & 31;
+        rest >>= 5;
+        if rest > 0 {
+            digit |= 32;
+        }
+        out.push(BASE64_CHARS[digit as usize] as char);
+        if rest == 0 {
+            break;
+        }
+    }
+}
+
+fn vlq_decode(segment: &str) -> Option<std::vec::Vec<i64>> {
+    let mut values = std::vec::Vec::with_capacity(4);
+    let mut value: i64 = 0;
+    let mut shift = 0;
+    for c in segment.bytes() {
+        let digit = base64_value(c)? as i64;
+        value |= (digit & 31) << shift;
+        shift += 5;
+        if digit & 32 == 0 {
+            let magnitude = value >> 1;
+            values.push(if value & 1 == 1 {
+                -magnitude
+            } else {
+                magnitude
+            });
+            value = 0;
+            shift = 0;
+        }
+    }
+    Some(values)
+}
+
 /*******************************************************************
  *
  * Unit tests
@@ -1898,15 +2348,19 @@ mod tests {
         let signatures = [
             Signature {
                 param_types: bumpalo::vec![in arena],
-                ret_type: None,
+                ret_types: bumpalo::vec![in arena],
             },
             Signature {
                 param_types: bumpalo::vec![in arena; I32, I64, F32, F64],
-                ret_type: None,
+                ret_types: bumpalo::vec![in arena],
             },
             Signature {
                 param_types: bumpalo::vec![in arena; I32, I32, I32],
-                ret_type: Some(I32),
+                ret_types: bumpalo::vec![in arena; I32],
+            },
+            Signature {
+                param_types: bumpalo::vec![in arena; I32],
+                ret_types: bumpalo::vec![in arena; I64, I64],
             },
         ];
         let capacity = signatures.len();
@@ -1920,5 +2374,135 @@ mod tests {
             section.insert(sig);
         }
         test_assert_types_preload(arena, &section);
+
+        let (params, returns) = section.look_up(3);
+        assert!(params.eq([I32]));
+        assert!(returns.eq([I64, I64]));
+    }
+
+    #[test]
+    fn test_name_section_with_locals() {
+        let arena = &Bump::new();
+        let mut section = NameSection::new(arena);
+        section.append_function(0, "imported");
+        section.append_function(1, "add");
+        section.append_locals(1, bumpalo::vec![in arena; (0, "x"), (1, "y"), (2, "sum")]);
+
+        let mut bytes = Vec::with_capacity_in(section.size() + 16, arena);
+        section.serialize(&mut bytes);
+
+        let mut cursor = 0;
+        let parsed = NameSection::parse(arena, &bytes, &mut cursor).unwrap();
+
+        assert_eq!(cursor, bytes.len());
+        assert_eq!(parsed.function_names, section.function_names);
+        assert_eq!(parsed.local_names, section.local_names);
+        assert_eq!(parsed.local_name(1, 2), Some("sum"));
+        assert_eq!(parsed.local_name(0, 0), None);
+    }
+
+    #[test]
+    fn test_source_map_section() {
+        let arena = &Bump::new();
+
+        // Two functions: `(nop nop end)` and `(end)`, after 2 imports and 1 dummy
+        let mut code = CodeSection::new(arena);
+        code.bytes.extend_from_slice(&[
+            2, // function count
+            4, // length
+            0, // no locals
+            OpCode::NOP as u8,
+            OpCode::NOP as u8,
+            OpCode::END as u8,
+            2, // length
+            0, // no locals
+            OpCode::END as u8,
+        ]);
+        code.function_offsets.extend_from_slice(&[1, 6]);
+        code.function_count = 2;
+        code.dead_import_dummy_count = 1;
+        let import_fn_count = 2;
+
+        let mut source_map = SourceMapSection::new(arena);
+        let main_roc = source_map.add_source("/src/main.roc", Some("main = 1 + 2\n"));
+        let lib_roc = source_map.add_source("/src/Lib.roc", None);
+        let mappings = [
+            SourceMapping {
+                fn_index: 3,
+                offset: 2,
+                source_index: main_roc,
+                line: 0,
+                column: 7,
+            },
+            SourceMapping {
+                fn_index: 3,
+                offset: 3,
+                source_index: main_roc,
+                line: 0,
+                column: 11,
+            },
+            SourceMapping {
+                fn_index: 4,
+                offset: 1,
+                source_index: lib_roc,
+                line: 42,
+                column: 0,
+            },
+        ];
+        for mapping in mappings {
+            source_map.append(mapping);
+        }
+
+        let mut bytes = Vec::with_capacity_in(code.size() + source_map.size(), arena);
+        let code_section_start = bytes.len();
+        code.serialize(&mut bytes);
+        let function_offsets =
+            code.serialized_function_offsets(import_fn_count, code_section_start);
+        source_map.serialize(&mut bytes, &function_offsets);
+
+        let mut cursor = 0;
+        let parsed_code = CodeSection::parse(arena, &bytes, &mut cursor).unwrap();
+        // The dummy is parsed like any other function
+        assert_eq!(parsed_code.function_offsets.len(), 3);
+
+        let parsed =
+            SourceMapSection::parse((arena, &parsed_code, import_fn_count), &bytes, &mut cursor)
+                .unwrap();
+
+        assert_eq!(cursor, bytes.len());
+        assert_eq!(parsed.sources, source_map.sources);
+        assert_eq!(&parsed.mappings[..], &mappings[..]);
+        assert_eq!(parsed.find(3, 4), Some(("/src/main.roc", 0, 11)));
+        assert_eq!(parsed.find(4, 0), None);
+    }
+
+    #[test]
+    fn test_code_section_function_sizes() {
+        let arena = &Bump::new();
+        let mut code = CodeSection::new(arena);
+
+        let live = [
+            0,
+            OpCode::NOP as u8,
+            OpCode::I32CONST as u8,
+            7,
+            OpCode::END as u8,
+        ];
+        code.function_offsets.push(code.bytes.len() as u32);
+        live[..].serialize(&mut code.bytes);
+        code.function_offsets.push(code.bytes.len() as u32);
+        DUMMY_FUNCTION.serialize(&mut code.bytes);
+        code.function_count = 2;
+
+        assert_eq!(code.function_sizes(), vec![Some(6), None]);
+    }
+
+    #[test]
+    fn test_vlq() {
+        for value in [0, 1, -1, 15, 16, -16, 31, 32, 1000, -123456] {
+            let mut text = String::new();
+            vlq_encode(value, &mut text);
+            assert_eq!(vlq_decode(&text), Some(vec![value]));
+        }
     }
 }